@@ -0,0 +1,380 @@
+#!/usr/bin/env python3
+"""Compare feedparser-rs against Python feedparser over a fixture corpus
+
+Both parsers run over every feed file in the corpus. feedparser-rs output
+comes from `feedparse batch --canonical`; Python feedparser results are
+converted to the same canonical schema (`feedparser-rs/canonical/1`, see
+`crates/feedparser-rs-core/src/writer/canonical.rs`). The two documents are
+flattened to `path -> value` pairs and compared field by field.
+
+The report lists, per fixture, fields that differ or that only one parser
+produced, followed by a per-field summary across the corpus and an overall
+compatibility score: the share of fields present in either output whose
+values agree.
+
+Usage:
+    python compare.py                          # tests/fixtures
+    python compare.py ../benchmarks/fixtures --verbose
+    python compare.py --json report.json --fail-under 90
+"""
+
+import argparse
+import calendar
+import json
+import re
+import subprocess
+import sys
+import time
+from collections import Counter
+from pathlib import Path
+
+import feedparser
+
+
+ROOT = Path(__file__).resolve().parent.parent
+DEFAULT_CORPUS = ROOT / "tests" / "fixtures"
+SCHEMA = "feedparser-rs/canonical/1"
+
+# Fields that are not expected to match: error messages are worded
+# differently, and encodings are reported for JSON input only by us
+DEFAULT_IGNORE = ["bozo_exception", "encoding"]
+
+# Python feedparser version strings that differ from FeedVersion::as_str
+VERSIONS = {
+    "json1": "json10",
+    "rss091u": "rss091",
+    "rss091n": "rss091",
+}
+
+TEXT_TYPES = {
+    "text/plain": "text",
+    "text/html": "html",
+    "application/xhtml+xml": "xhtml",
+}
+
+
+# === Python feedparser -> canonical schema ===
+
+
+def put(out, key, value):
+    """Set `key` unless the value is absent or empty, like the Rust writer"""
+    if value is None or value == "" or value == [] or value == {}:
+        return
+    out[key] = value
+
+
+def number(value):
+    """Integers are strings in Python feedparser; numbers in the schema"""
+    if isinstance(value, int):
+        return value
+    if isinstance(value, str) and value.strip().isdigit():
+        return int(value.strip())
+    return None
+
+
+def date(parsed):
+    """`time.struct_time` in UTC as RFC 3339 with second precision"""
+    if not parsed:
+        return None
+    return time.strftime("%Y-%m-%dT%H:%M:%SZ", time.gmtime(calendar.timegm(parsed)))
+
+
+def text(out, source, key):
+    value = source.get(key)
+    if not value:
+        return
+    put(out, key, value)
+    detail = source.get(f"{key}_detail") or {}
+    out[f"{key}_type"] = TEXT_TYPES.get(detail.get("type"), "text")
+
+
+def link(value):
+    out = {}
+    put(out, "href", value.get("href"))
+    put(out, "rel", value.get("rel"))
+    put(out, "type", value.get("type"))
+    put(out, "title", value.get("title"))
+    put(out, "length", number(value.get("length")))
+    put(out, "hreflang", value.get("hreflang"))
+    return out
+
+
+def person(value):
+    out = {}
+    put(out, "name", value.get("name"))
+    put(out, "email", value.get("email"))
+    put(out, "uri", value.get("href"))
+    return out
+
+
+def people(source):
+    """Primary author (if not already listed) followed by all listed authors"""
+    authors = [a for a in source.get("authors", []) if a]
+    detail = source.get("author_detail")
+    if detail and not any(
+        a.get("name") == detail.get("name") and a.get("email") == detail.get("email")
+        for a in authors
+    ):
+        authors.insert(0, detail)
+    return [person(a) for a in authors]
+
+
+def tag(value):
+    out = {}
+    put(out, "term", value.get("term"))
+    put(out, "scheme", value.get("scheme"))
+    put(out, "label", value.get("label"))
+    return out
+
+
+def image(value):
+    out = {}
+    put(out, "url", value.get("href") or value.get("url"))
+    put(out, "title", value.get("title"))
+    put(out, "link", value.get("link"))
+    put(out, "width", number(value.get("width")))
+    put(out, "height", number(value.get("height")))
+    return out
+
+
+def content(value):
+    out = {"value": value.get("value", "")}
+    put(out, "type", value.get("type"))
+    put(out, "language", value.get("language"))
+    return out
+
+
+def enclosure(value):
+    out = {}
+    put(out, "url", value.get("href") or value.get("url"))
+    put(out, "type", value.get("type"))
+    put(out, "length", number(value.get("length")))
+    return out
+
+
+def feed_meta(meta):
+    out = {}
+    put(out, "id", meta.get("id"))
+    text(out, meta, "title")
+    text(out, meta, "subtitle")
+    put(out, "link", meta.get("link"))
+    put(out, "links", [link(l) for l in meta.get("links", [])])
+    put(out, "updated", date(meta.get("updated_parsed")))
+    put(out, "published", date(meta.get("published_parsed")))
+    put(out, "authors", people(meta))
+    put(out, "contributors", [person(c) for c in meta.get("contributors", [])])
+    put(out, "language", meta.get("language"))
+    put(out, "rights", meta.get("rights"))
+    put(out, "generator", meta.get("generator"))
+    if meta.get("image"):
+        put(out, "image", image(meta["image"]))
+    put(out, "icon", meta.get("icon"))
+    put(out, "logo", meta.get("logo"))
+    put(out, "tags", [tag(t) for t in meta.get("tags", [])])
+    put(out, "ttl", number(meta.get("ttl")))
+    return out
+
+
+def entry(value):
+    out = {}
+    put(out, "id", value.get("id"))
+    text(out, value, "title")
+    put(out, "link", value.get("link"))
+    put(out, "links", [link(l) for l in value.get("links", [])])
+    text(out, value, "summary")
+    put(out, "content", [content(c) for c in value.get("content", [])])
+    put(out, "published", date(value.get("published_parsed")))
+    put(out, "updated", date(value.get("updated_parsed")))
+    put(out, "authors", people(value))
+    put(out, "contributors", [person(c) for c in value.get("contributors", [])])
+    put(out, "tags", [tag(t) for t in value.get("tags", [])])
+    put(out, "enclosures", [enclosure(e) for e in value.get("enclosures", [])])
+    put(out, "comments", value.get("comments"))
+    return out
+
+
+def canonical(result):
+    """Python feedparser result in the canonical schema"""
+    out = {"schema": SCHEMA}
+    version = result.get("version") or ""
+    put(out, "version", VERSIONS.get(version, version))
+    out["bozo"] = bool(result.get("bozo"))
+    if result.get("bozo_exception") is not None:
+        put(out, "bozo_exception", str(result["bozo_exception"]))
+    put(out, "encoding", result.get("encoding"))
+    put(out, "href", result.get("href"))
+    put(out, "feed", feed_meta(result.get("feed", {})))
+    out["entries"] = [entry(e) for e in result.get("entries", [])]
+    return out
+
+
+# === Running both parsers ===
+
+
+def corpus_files(paths):
+    files = []
+    for path in paths:
+        path = Path(path).resolve()
+        if path.is_dir():
+            files.extend(p for p in sorted(path.rglob("*")) if p.is_file())
+        else:
+            files.append(path)
+    return files
+
+
+def run_rust(files, feedparse):
+    """Canonical documents from `feedparse batch`, keyed by input path"""
+    command = feedparse or [
+        "cargo", "run", "--quiet", "--release", "-p", "feedparser-rs-cli", "--",
+    ]
+    output = subprocess.run(
+        [*command, "batch", "--canonical", *map(str, files)],
+        cwd=ROOT,
+        capture_output=True,
+        text=True,
+        check=False,
+    )
+    results = {}
+    for line in output.stdout.splitlines():
+        document = json.loads(line)
+        results[document.pop("input")] = document
+    if not results and output.returncode != 0:
+        sys.exit(f"feedparse failed:\n{output.stderr}")
+    return results
+
+
+def run_python(path):
+    return canonical(feedparser.parse(path.read_bytes()))
+
+
+# === Comparison ===
+
+
+def flatten(value, prefix="", out=None):
+    """`{"a": [{"b": 1}]}` -> `{"a[0].b": 1}`"""
+    if out is None:
+        out = {}
+    if isinstance(value, dict):
+        for key, item in value.items():
+            flatten(item, f"{prefix}.{key}" if prefix else key, out)
+    elif isinstance(value, list):
+        for index, item in enumerate(value):
+            flatten(item, f"{prefix}[{index}]", out)
+    else:
+        out[prefix] = value
+    return out
+
+
+def field_name(path):
+    """Aggregation key: `entries[3].links[0].href` -> `entries[].links[].href`"""
+    return re.sub(r"\[\d+\]", "[]", path)
+
+
+def ignored(path, patterns):
+    name = field_name(path)
+    return any(name == p or name.startswith(f"{p}.") or name.startswith(f"{p}[") for p in patterns)
+
+
+def compare(rust, python, ignore):
+    rust = {k: v for k, v in flatten(rust).items() if not ignored(k, ignore)}
+    python = {k: v for k, v in flatten(python).items() if not ignored(k, ignore)}
+    matched, differences = 0, []
+    for path in sorted(rust.keys() | python.keys()):
+        ours, theirs = rust.get(path), python.get(path)
+        if path in rust and path in python and ours == theirs:
+            matched += 1
+        elif path not in python:
+            differences.append({"path": path, "kind": "only_rust", "rust": ours})
+        elif path not in rust:
+            differences.append({"path": path, "kind": "only_python", "python": theirs})
+        else:
+            differences.append(
+                {"path": path, "kind": "mismatch", "rust": ours, "python": theirs}
+            )
+    return matched, differences
+
+
+def shorten(value, limit=60):
+    text = json.dumps(value, ensure_ascii=False)
+    return text if len(text) <= limit else f"{text[: limit - 3]}..."
+
+
+def display(path):
+    """Fixture path relative to the repository when inside it"""
+    return str(path.relative_to(ROOT) if path.is_relative_to(ROOT) else path)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("corpus", nargs="*", default=[DEFAULT_CORPUS],
+                        help="fixture files or directories (default: tests/fixtures)")
+    parser.add_argument("--feedparse", nargs="+", metavar="CMD",
+                        help="feedparse command (default: cargo run -p feedparser-rs-cli)")
+    parser.add_argument("--ignore", action="append", default=list(DEFAULT_IGNORE),
+                        metavar="FIELD", help="field to skip, e.g. entries[].summary")
+    parser.add_argument("--json", type=Path, metavar="FILE",
+                        help="also write the full report as JSON")
+    parser.add_argument("--verbose", action="store_true",
+                        help="list every difference, not only the summary")
+    parser.add_argument("--fail-under", type=float, metavar="PERCENT",
+                        help="exit with status 1 below this compatibility score")
+    args = parser.parse_args()
+
+    files = corpus_files(args.corpus)
+    if not files:
+        sys.exit("no fixtures found")
+    rust = run_rust(files, args.feedparse)
+
+    report = {"feedparser": feedparser.__version__, "fixtures": []}
+    total_matched = total_fields = 0
+    by_field = Counter()
+    for path in files:
+        key = str(path)
+        if key not in rust:
+            report["fixtures"].append({"fixture": display(path), "error": "feedparse failed"})
+            continue
+        matched, differences = compare(rust[key], run_python(path), args.ignore)
+        total_matched += matched
+        total_fields += matched + len(differences)
+        by_field.update(field_name(d["path"]) for d in differences)
+        report["fixtures"].append(
+            {"fixture": display(path), "matched": matched, "differences": differences}
+        )
+
+    score = 100.0 * total_matched / total_fields if total_fields else 100.0
+    report["score"] = round(score, 2)
+    report["by_field"] = dict(by_field.most_common())
+
+    print(f"Compatibility with Python feedparser {feedparser.__version__}")
+    print("=" * 60)
+    for fixture in report["fixtures"]:
+        if "error" in fixture:
+            print(f"{fixture['fixture']}: {fixture['error']}")
+            continue
+        differences = fixture["differences"]
+        print(f"{fixture['fixture']}: {fixture['matched']} fields match, {len(differences)} differ")
+        if args.verbose:
+            for d in differences:
+                if d["kind"] == "mismatch":
+                    detail = f"rust {shorten(d['rust'])} != python {shorten(d['python'])}"
+                elif d["kind"] == "only_rust":
+                    detail = f"only rust: {shorten(d['rust'])}"
+                else:
+                    detail = f"only python: {shorten(d['python'])}"
+                print(f"    {d['path']}: {detail}")
+    if by_field:
+        print()
+        print("Differences by field:")
+        for field, count in by_field.most_common():
+            print(f"  {count:5d}  {field}")
+    print()
+    print(f"Score: {score:.2f}% of {total_fields} fields match")
+
+    if args.json:
+        args.json.write_text(json.dumps(report, indent=2, ensure_ascii=False) + "\n")
+    if args.fail_under is not None and score < args.fail_under:
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
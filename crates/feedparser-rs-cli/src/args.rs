@@ -0,0 +1,219 @@
+//! Command-line arguments
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use feedparser_rs::ParserLimits;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+#[cfg(feature = "http")]
+use std::time::Duration;
+
+/// Parse RSS, Atom and JSON feeds and print them as JSON
+#[derive(Debug, Parser)]
+#[command(
+    name = "feedparse",
+    version,
+    about,
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    /// Subcommand; parses a single feed when omitted
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Parse options
+    #[command(flatten)]
+    pub parse: ParseArgs,
+}
+
+/// Subcommands
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Parse many feeds concurrently and print one JSON document per line
+    Batch(BatchArgs),
+    /// Poll a feed URL and print new or updated entries as they appear
+    #[cfg(feature = "http")]
+    Watch(WatchArgs),
+    /// Fetch every feed in an OPML subscription list
+    #[cfg(feature = "http")]
+    Opml(OpmlArgs),
+}
+
+/// Options for parsing a single feed
+#[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)] // Independent command-line flags
+pub struct ParseArgs {
+    /// File path, http(s) URL, or `-` for stdin
+    #[arg(default_value = "-")]
+    pub input: String,
+
+    /// Print compact single-line JSON instead of pretty JSON
+    #[arg(short, long)]
+    pub compact: bool,
+
+    /// Only detect the feed format and print it (e.g. `rss20`)
+    #[arg(short, long)]
+    pub detect: bool,
+
+    /// Print the canonical normalized JSON schema instead of every field
+    #[arg(long)]
+    pub canonical: bool,
+
+    /// Exit with status 3 if the feed is malformed (bozo)
+    #[arg(short, long)]
+    pub strict: bool,
+
+    /// Shared options
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Options for `feedparse batch`
+#[derive(Debug, Args)]
+pub struct BatchArgs {
+    /// Files, directories, glob patterns (e.g. `feeds/*.xml`) or http(s) URLs
+    pub inputs: Vec<String>,
+
+    /// Read additional inputs from a file, one per line (`-` for stdin)
+    #[arg(long, value_name = "FILE")]
+    pub files_from: Option<PathBuf>,
+
+    /// Number of feeds to parse concurrently [default: number of CPUs]
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Exit with status 3 if any feed is malformed (bozo)
+    #[arg(short, long)]
+    pub strict: bool,
+
+    /// Print the canonical normalized JSON schema instead of every field
+    #[arg(long)]
+    pub canonical: bool,
+
+    /// Shared options
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Options for `feedparse watch`
+#[cfg(feature = "http")]
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Feed URL to poll
+    pub url: String,
+
+    /// Time between polls, e.g. `90s`, `5m`, `1h`
+    #[arg(short, long, default_value = "5m", value_parser = parse_interval)]
+    pub interval: Duration,
+
+    /// Do not print the entries present on the first poll
+    #[arg(long)]
+    pub skip_existing: bool,
+
+    /// Stop after this many polls [default: poll forever]
+    #[arg(long, value_name = "N")]
+    pub count: Option<NonZeroUsize>,
+
+    /// Shared options
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Options for `feedparse opml`
+#[cfg(feature = "http")]
+#[derive(Debug, Args)]
+pub struct OpmlArgs {
+    /// OPML file, or `-` for stdin
+    pub file: String,
+
+    /// Maximum number of feeds fetched at once
+    #[arg(short = 'j', long, default_value = "8", value_name = "N")]
+    pub concurrency: NonZeroUsize,
+
+    /// Pause between requests to the same host, in milliseconds
+    #[arg(long, default_value_t = 1000, value_name = "MS")]
+    pub host_delay_ms: u64,
+
+    /// Print a single merged feed instead of one status line per feed
+    #[arg(short, long)]
+    pub merge: bool,
+
+    /// With `--merge`, print compact single-line JSON
+    #[arg(short, long, requires = "merge")]
+    pub compact: bool,
+
+    /// Shared options
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Options shared by all commands
+#[derive(Debug, Args)]
+pub struct CommonArgs {
+    /// Parser limits preset
+    #[arg(long, value_enum, default_value_t = LimitsPreset::Default)]
+    pub limits: LimitsPreset,
+
+    /// Maximum number of entries to parse (overrides the preset)
+    #[arg(long, value_name = "N")]
+    pub max_entries: Option<usize>,
+
+    /// Maximum input size in bytes (overrides the preset)
+    #[arg(long, value_name = "BYTES")]
+    pub max_feed_size: Option<usize>,
+
+    /// User-Agent header for URL inputs
+    #[arg(long, value_name = "AGENT")]
+    pub user_agent: Option<String>,
+}
+
+/// Named [`ParserLimits`] presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LimitsPreset {
+    /// `ParserLimits::default()`
+    Default,
+    /// `ParserLimits::strict()`
+    Strict,
+    /// `ParserLimits::permissive()`
+    Permissive,
+}
+
+/// Parses an interval such as `30s`, `5m`, `1h`, `1d` or plain seconds
+#[cfg(feature = "http")]
+pub fn parse_interval(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: u64 = value
+        .parse()
+        .map_err(|_| format!("invalid interval `{s}`"))?;
+    let scale = match unit.trim() {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        other => {
+            return Err(format!(
+                "unknown interval unit `{other}` (use s, m, h or d)"
+            ));
+        }
+    };
+    match value.checked_mul(scale) {
+        Some(0) => Err("interval must be greater than zero".to_string()),
+        Some(secs) => Ok(Duration::from_secs(secs)),
+        None => Err(format!("interval `{s}` is too large")),
+    }
+}
+
+impl CommonArgs {
+    /// Parser limits from the preset and individual overrides
+    pub fn parser_limits(&self) -> ParserLimits {
+        let limits = match self.limits {
+            LimitsPreset::Default => ParserLimits::default(),
+            LimitsPreset::Strict => ParserLimits::strict(),
+            LimitsPreset::Permissive => ParserLimits::permissive(),
+        };
+        limits
+            .with_max_entries(self.max_entries.unwrap_or(limits.max_entries))
+            .with_max_feed_size_bytes(self.max_feed_size.unwrap_or(limits.max_feed_size_bytes))
+    }
+}
@@ -0,0 +1,185 @@
+//! `feedparse batch`: parse many feeds concurrently into JSON Lines
+//!
+//! Each successfully parsed feed is written to stdout as one compact JSON
+//! document, in the canonical schema with `--canonical`, with an extra
+//! `input` key naming where it came from. Lines are
+//! written as feeds finish, so their order may differ from the input order.
+//! Failures are collected and reported in a summary on stderr.
+
+use crate::args::BatchArgs;
+use crate::{EXIT_BOZO, input};
+use anyhow::{Context, Result, bail};
+use feedparser_rs::ParsedFeed;
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs the `batch` subcommand
+pub fn run(args: &BatchArgs) -> Result<ExitCode> {
+    let mut failures = Vec::new();
+    let mut inputs = Vec::new();
+    for input in &args.inputs {
+        expand(input, &mut inputs, &mut failures);
+    }
+    if let Some(path) = &args.files_from {
+        for line in read_list(path)? {
+            expand(&line, &mut inputs, &mut failures);
+        }
+    }
+    if inputs.is_empty() && failures.is_empty() {
+        bail!("no inputs given");
+    }
+
+    let limits = args.common.parser_limits();
+    let user_agent = args.common.user_agent.as_deref();
+    let jobs = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, usize::from)
+        .min(inputs.len().max(1));
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut parsed = 0usize;
+    let mut malformed = Vec::new();
+
+    thread::scope(|scope| -> Result<()> {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let (inputs, next) = (&inputs, &next);
+            scope.spawn(move || {
+                while let Some(input) = inputs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = input::load(input, limits, user_agent);
+                    if tx.send((input, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut stdout = std::io::stdout().lock();
+        for (input, result) in rx {
+            match result
+                .and_then(|feed| to_line(input, &feed, args.canonical).map(|line| (feed, line)))
+            {
+                Ok((feed, line)) => {
+                    writeln!(stdout, "{line}").context("failed to write output")?;
+                    parsed += 1;
+                    if feed.bozo {
+                        let reason = feed.bozo_exception.unwrap_or_default();
+                        malformed.push((input.clone(), format!("malformed feed: {reason}")));
+                    }
+                }
+                Err(err) => failures.push((input.clone(), format!("{err:#}"))),
+            }
+        }
+        stdout.flush().context("failed to write output")
+    })?;
+
+    let total = parsed + failures.len();
+    eprintln!(
+        "feedparse: parsed {parsed} of {total} inputs ({} failed, {} malformed)",
+        failures.len(),
+        malformed.len()
+    );
+    failures.sort();
+    for (input, error) in &failures {
+        eprintln!("  {input}: {error}");
+    }
+    if args.strict {
+        malformed.sort();
+        for (input, error) in &malformed {
+            eprintln!("  {input}: {error}");
+        }
+    }
+
+    Ok(if !failures.is_empty() {
+        ExitCode::FAILURE
+    } else if args.strict && !malformed.is_empty() {
+        ExitCode::from(EXIT_BOZO)
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Serializes a feed as one JSON line with an `input` key
+fn to_line(input: &str, feed: &ParsedFeed, canonical: bool) -> Result<String> {
+    let mut value = if canonical {
+        serde_json::from_str(&feed.to_canonical_json())?
+    } else {
+        serde_json::to_value(feed)?
+    };
+    if let Value::Object(map) = &mut value {
+        map.insert("input".to_string(), Value::from(input));
+    }
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Expands a directory or glob pattern into the files it names
+///
+/// URLs and plain paths are passed through unchanged. Directories expand to
+/// the regular files they contain (non-recursively) in sorted order.
+fn expand(input: &str, inputs: &mut Vec<String>, failures: &mut Vec<(String, String)>) {
+    if input::is_url(input) || input == "-" {
+        inputs.push(input.to_string());
+    } else if input.contains(['*', '?', '[']) {
+        match glob::glob(input) {
+            Ok(paths) => {
+                let before = inputs.len();
+                inputs.extend(
+                    paths
+                        .flatten()
+                        .filter(|path| path.is_file())
+                        .map(|path| path.display().to_string()),
+                );
+                if inputs.len() == before {
+                    failures.push((input.to_string(), "no files match pattern".to_string()));
+                }
+            }
+            Err(err) => failures.push((input.to_string(), format!("invalid pattern: {err}"))),
+        }
+    } else if std::path::Path::new(input).is_dir() {
+        match std::fs::read_dir(input) {
+            Ok(entries) => {
+                let mut files: Vec<String> = entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_file())
+                    .map(|path| path.display().to_string())
+                    .collect();
+                files.sort();
+                inputs.extend(files);
+            }
+            Err(err) => failures.push((
+                input.to_string(),
+                format!("failed to read directory: {err}"),
+            )),
+        }
+    } else {
+        inputs.push(input.to_string());
+    }
+}
+
+/// Reads a list of inputs, one per line, skipping blanks and `#` comments
+fn read_list(path: &std::path::Path) -> Result<Vec<String>> {
+    let reader: Box<dyn BufRead> = if path.as_os_str() == "-" {
+        Box::new(std::io::stdin().lock())
+    } else {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Box::new(std::io::BufReader::new(file))
+    };
+    let mut lines = Vec::new();
+    for line in reader.lines() {
+        let line = line.with_context(|| format!("failed to read {}", path.display()))?;
+        let line = line.trim();
+        if !line.is_empty() && !line.starts_with('#') {
+            lines.push(line.to_string());
+        }
+    }
+    Ok(lines)
+}
@@ -0,0 +1,50 @@
+//! Reading feeds from files, URLs and stdin
+
+use anyhow::{Context, Result};
+use feedparser_rs::{ParsedFeed, ParserLimits, parse_with_limits};
+use std::io::Read;
+
+/// Returns true if `input` should be fetched over HTTP
+pub fn is_url(input: &str) -> bool {
+    input.starts_with("http://") || input.starts_with("https://")
+}
+
+/// Reads raw bytes from a file path or stdin (`-`)
+pub fn read_bytes(input: &str, limits: &ParserLimits) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    // Read one byte past the limit so oversized input is reported by the parser
+    let cap = u64::try_from(limits.max_feed_size_bytes)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    if input == "-" {
+        std::io::stdin()
+            .take(cap)
+            .read_to_end(&mut data)
+            .context("failed to read stdin")?;
+    } else {
+        std::fs::File::open(input)
+            .and_then(|file| file.take(cap).read_to_end(&mut data))
+            .with_context(|| format!("failed to read {input}"))?;
+    }
+    Ok(data)
+}
+
+/// Parses a feed from a file path, URL, or stdin (`-`)
+pub fn load(input: &str, limits: ParserLimits, user_agent: Option<&str>) -> Result<ParsedFeed> {
+    if is_url(input) {
+        return fetch(input, limits, user_agent);
+    }
+    let data = read_bytes(input, &limits)?;
+    parse_with_limits(&data, limits).with_context(|| format!("failed to parse {input}"))
+}
+
+#[cfg(feature = "http")]
+fn fetch(url: &str, limits: ParserLimits, user_agent: Option<&str>) -> Result<ParsedFeed> {
+    feedparser_rs::parse_url_with_limits(url, None, None, user_agent, limits)
+        .with_context(|| format!("failed to fetch {url}"))
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch(url: &str, _limits: ParserLimits, _user_agent: Option<&str>) -> Result<ParsedFeed> {
+    anyhow::bail!("cannot fetch {url}: feedparse was built without the `http` feature")
+}
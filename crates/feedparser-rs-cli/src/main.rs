@@ -0,0 +1,89 @@
+#![cfg_attr(test, allow(clippy::unwrap_used, clippy::expect_used, clippy::panic))]
+//! `feedparse` - parse RSS, Atom and JSON feeds from the command line
+//!
+//! Reads a feed from a file, an http(s) URL or stdin and prints the parsed
+//! result as JSON. `feedparse batch` parses many feeds concurrently and
+//! prints one JSON document per line, and `feedparse watch` polls a URL
+//! and prints new entries as they appear. `feedparse opml` fetches every
+//! feed in a subscription list.
+//!
+//! Exit codes:
+//! - `0`: success
+//! - `1`: an input could not be read, fetched or parsed
+//! - `2`: invalid command-line arguments
+//! - `3`: `--strict` was given and the feed is malformed (bozo)
+
+mod args;
+mod batch;
+mod input;
+#[cfg(feature = "http")]
+mod opml;
+#[cfg(feature = "http")]
+mod watch;
+
+use anyhow::Result;
+use args::{Cli, Command, ParseArgs};
+use clap::Parser;
+use feedparser_rs::detect_format;
+use std::process::ExitCode;
+
+/// Exit status for malformed feeds under `--strict`
+pub(crate) const EXIT_BOZO: u8 = 3;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Some(Command::Batch(args)) => batch::run(args),
+        #[cfg(feature = "http")]
+        Some(Command::Watch(args)) => watch::run(args),
+        #[cfg(feature = "http")]
+        Some(Command::Opml(args)) => opml::run(args),
+        None => run_parse(&cli.parse),
+    };
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("feedparse: error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_parse(args: &ParseArgs) -> Result<ExitCode> {
+    let limits = args.common.parser_limits();
+
+    if args.detect {
+        let version = if input::is_url(&args.input) {
+            input::load(&args.input, limits, args.common.user_agent.as_deref())?.version
+        } else {
+            detect_format(&input::read_bytes(&args.input, &limits)?)
+        };
+        if version.as_str().is_empty() {
+            println!("unknown");
+            return Ok(ExitCode::FAILURE);
+        }
+        println!("{version}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let feed = input::load(&args.input, limits, args.common.user_agent.as_deref())?;
+    let json = match (args.canonical, args.compact) {
+        (true, true) => feed.to_canonical_json(),
+        (true, false) => {
+            let value: serde_json::Value = serde_json::from_str(&feed.to_canonical_json())?;
+            serde_json::to_string_pretty(&value)?
+        }
+        (false, true) => serde_json::to_string(&feed)?,
+        (false, false) => serde_json::to_string_pretty(&feed)?,
+    };
+    println!("{json}");
+
+    if args.strict && feed.bozo {
+        eprintln!(
+            "feedparse: malformed feed: {}",
+            feed.bozo_exception.as_deref().unwrap_or("unknown error")
+        );
+        return Ok(ExitCode::from(EXIT_BOZO));
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,230 @@
+//! `feedparse opml`: fetch every feed in an OPML subscription list
+//!
+//! Feeds are grouped by host. Up to `--concurrency` hosts are fetched in
+//! parallel, but requests to the same host are made one at a time with
+//! `--host-delay-ms` between them, so large subscription lists do not hammer
+//! a single server.
+//!
+//! By default one JSON status line is printed per feed as it finishes.
+//! With `--merge` the fetched feeds are combined with [`merge_feeds`] and
+//! printed as a single document. Failures are summarized on stderr.
+
+use crate::args::OpmlArgs;
+use crate::input;
+use anyhow::{Context, Result, bail};
+use feedparser_rs::opml::parse_opml_with_limits;
+use feedparser_rs::{MergeOptions, ParsedFeed, merge_feeds};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// A feed to fetch
+struct Subscription {
+    url: String,
+    name: Option<String>,
+}
+
+/// Runs the `opml` subcommand
+pub fn run(args: &OpmlArgs) -> Result<ExitCode> {
+    let limits = args.common.parser_limits();
+    let data = input::read_bytes(&args.file, &limits)?;
+    let opml = parse_opml_with_limits(&data, limits)
+        .with_context(|| format!("failed to parse {}", args.file))?;
+    if opml.bozo {
+        eprintln!(
+            "feedparse: malformed OPML: {}",
+            opml.bozo_exception.as_deref().unwrap_or("unknown error")
+        );
+    }
+
+    let mut seen = HashSet::new();
+    let subscriptions: Vec<Subscription> = opml
+        .feeds()
+        .into_iter()
+        .filter_map(|outline| {
+            let url = outline.xml_url.as_deref()?.trim();
+            seen.insert(url.to_string()).then(|| Subscription {
+                url: url.to_string(),
+                name: outline.display_name().map(str::to_string),
+            })
+        })
+        .collect();
+    if subscriptions.is_empty() {
+        bail!("no feeds found in {}", args.file);
+    }
+    let total = subscriptions.len();
+    let hosts = group_by_host(subscriptions);
+
+    let user_agent = args.common.user_agent.as_deref();
+    let delay = Duration::from_millis(args.host_delay_ms);
+    let jobs = args.concurrency.get().min(hosts.len());
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    let mut feeds = Vec::new();
+    let mut failures = Vec::new();
+    thread::scope(|scope| -> Result<()> {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let (hosts, next) = (&hosts, &next);
+            scope.spawn(move || {
+                while let Some(queue) = hosts.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    for (i, subscription) in queue.iter().enumerate() {
+                        if i > 0 {
+                            thread::sleep(delay);
+                        }
+                        let result = input::load(&subscription.url, limits, user_agent);
+                        if tx.send((subscription, result)).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut stdout = std::io::stdout().lock();
+        for (subscription, result) in rx {
+            let line = status_line(subscription, &result);
+            match result {
+                Ok(feed) => feeds.push(feed),
+                Err(err) => failures.push((subscription.url.clone(), format!("{err:#}"))),
+            }
+            if !args.merge {
+                writeln!(stdout, "{line}").context("failed to write output")?;
+            }
+        }
+        stdout.flush().context("failed to write output")
+    })?;
+
+    if args.merge {
+        let options = MergeOptions {
+            title: opml.head.title,
+            ..MergeOptions::default()
+        };
+        let merged = merge_feeds(&feeds, &options);
+        let json = if args.compact {
+            serde_json::to_string(&merged)?
+        } else {
+            serde_json::to_string_pretty(&merged)?
+        };
+        println!("{json}");
+    }
+
+    eprintln!(
+        "feedparse: fetched {} of {total} feeds ({} failed)",
+        feeds.len(),
+        failures.len()
+    );
+    failures.sort();
+    for (url, error) in &failures {
+        eprintln!("  {url}: {error}");
+    }
+    Ok(if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Groups subscriptions into per-host queues, largest queue first
+///
+/// Starting the longest queues first keeps one slow host from becoming the
+/// tail of the whole run.
+fn group_by_host(subscriptions: Vec<Subscription>) -> Vec<Vec<Subscription>> {
+    let mut by_host: BTreeMap<String, Vec<Subscription>> = BTreeMap::new();
+    for subscription in subscriptions {
+        let host = url::Url::parse(&subscription.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+            .unwrap_or_default();
+        by_host.entry(host).or_default().push(subscription);
+    }
+    let mut queues: Vec<_> = by_host.into_values().collect();
+    queues.sort_by_key(|queue| std::cmp::Reverse(queue.len()));
+    queues
+}
+
+/// One-line JSON status for a fetched feed
+fn status_line(subscription: &Subscription, result: &Result<ParsedFeed>) -> Value {
+    let mut line = json!({
+        "url": subscription.url,
+        "name": subscription.name,
+    });
+    let Value::Object(map) = &mut line else {
+        return line;
+    };
+    match result {
+        Ok(feed) => {
+            map.insert("ok".into(), Value::Bool(true));
+            map.insert("status".into(), json!(feed.status));
+            map.insert("version".into(), Value::from(feed.version.as_str()));
+            map.insert("title".into(), json!(feed.feed.title));
+            map.insert("entries".into(), Value::from(feed.entries.len()));
+            map.insert("bozo".into(), Value::Bool(feed.bozo));
+            if let Some(reason) = &feed.bozo_exception {
+                map.insert("bozo_exception".into(), Value::from(reason.as_str()));
+            }
+        }
+        Err(err) => {
+            map.insert("ok".into(), Value::Bool(false));
+            map.insert("error".into(), Value::from(format!("{err:#}")));
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(url: &str) -> Subscription {
+        Subscription {
+            url: url.to_string(),
+            name: None,
+        }
+    }
+
+    #[test]
+    fn test_group_by_host() {
+        let queues = group_by_host(vec![
+            subscription("https://a.example/1.xml"),
+            subscription("https://B.example/1.xml"),
+            subscription("https://b.example/2.xml"),
+            subscription("not a url"),
+        ]);
+        let urls: Vec<Vec<&str>> = queues
+            .iter()
+            .map(|queue| queue.iter().map(|s| s.url.as_str()).collect())
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                vec!["https://B.example/1.xml", "https://b.example/2.xml"],
+                vec!["not a url"],
+                vec!["https://a.example/1.xml"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_line() {
+        let feed = ParsedFeed {
+            status: Some(200),
+            ..ParsedFeed::default()
+        };
+        let line = status_line(&subscription("https://a.example/"), &Ok(feed));
+        assert_eq!(line["ok"], true);
+        assert_eq!(line["status"], 200);
+        assert_eq!(line["entries"], 0);
+
+        let line = status_line(&subscription("x"), &Err(anyhow::anyhow!("boom")));
+        assert_eq!(line["ok"], false);
+        assert_eq!(line["error"], "boom");
+    }
+}
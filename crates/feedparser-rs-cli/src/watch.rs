@@ -0,0 +1,144 @@
+//! `feedparse watch`: poll a feed and print new entries
+//!
+//! Each poll is a conditional GET using the `ETag` and `Last-Modified`
+//! values of the previous response. Entries that are new or changed since
+//! the last poll (see [`FeedTracker`]) are printed as one compact JSON
+//! document per line.
+//!
+//! The delay between polls is the configured interval, stretched to the
+//! feed's `<ttl>` and to any `Retry-After` header sent by the server.
+
+use crate::args::WatchArgs;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use feedparser_rs::util::parse_date;
+use feedparser_rs::{FeedHttpClient, FeedTracker, parse_with_limits};
+use std::io::Write;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Runs the `watch` subcommand
+pub fn run(args: &WatchArgs) -> Result<ExitCode> {
+    let mut client = FeedHttpClient::new()?;
+    if let Some(agent) = &args.common.user_agent {
+        client = client.with_user_agent(agent.clone());
+    }
+    let limits = args.common.parser_limits();
+
+    let mut tracker = FeedTracker::new();
+    let mut etag: Option<String> = None;
+    let mut modified: Option<String> = None;
+    let mut ttl: Option<Duration> = None;
+    let mut first = true;
+    let mut polls = 0usize;
+
+    loop {
+        let mut retry_after = None;
+        match client.get(&args.url, etag.as_deref(), modified.as_deref(), None) {
+            Ok(response) if response.status == 304 => {}
+            Ok(response) if response.status >= 400 => {
+                eprintln!("feedparse: HTTP {} for {}", response.status, response.url);
+                retry_after = response
+                    .headers
+                    .get("retry-after")
+                    .and_then(|value| parse_retry_after(value, Utc::now()));
+            }
+            Ok(response) => match parse_with_limits(&response.body, limits) {
+                Ok(feed) => {
+                    etag = response.etag;
+                    modified = response.last_modified;
+                    ttl = feed
+                        .feed
+                        .ttl
+                        .map(|minutes| Duration::from_secs(u64::from(minutes) * 60));
+
+                    let fresh = tracker.update(&feed);
+                    if !(first && args.skip_existing) {
+                        let mut stdout = std::io::stdout().lock();
+                        for entry in fresh {
+                            writeln!(stdout, "{}", serde_json::to_string(entry)?)
+                                .context("failed to write output")?;
+                        }
+                        stdout.flush().context("failed to write output")?;
+                    }
+                    tracker.retain_current(&feed);
+                    first = false;
+                }
+                Err(err) => eprintln!("feedparse: failed to parse {}: {err}", args.url),
+            },
+            Err(err) => eprintln!("feedparse: failed to fetch {}: {err}", args.url),
+        }
+
+        polls += 1;
+        if args.count.is_some_and(|count| polls >= count.get()) {
+            return Ok(ExitCode::SUCCESS);
+        }
+        std::thread::sleep(next_delay(args.interval, ttl, retry_after));
+    }
+}
+
+/// Delay before the next poll: the longest of the interval, ttl and `Retry-After`
+fn next_delay(
+    interval: Duration,
+    ttl: Option<Duration>,
+    retry_after: Option<Duration>,
+) -> Duration {
+    interval
+        .max(ttl.unwrap_or_default())
+        .max(retry_after.unwrap_or_default())
+}
+
+/// Parses a `Retry-After` value: delay in seconds or an HTTP date
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    (parse_date(value)? - now).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::parse_interval;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_interval("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_interval("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(parse_interval("2h"), Ok(Duration::from_secs(7200)));
+        assert_eq!(parse_interval("1d"), Ok(Duration::from_secs(86_400)));
+        assert!(parse_interval("0").is_err());
+        assert!(parse_interval("5x").is_err());
+        assert!(parse_interval("m").is_err());
+    }
+
+    #[test]
+    fn test_next_delay() {
+        let interval = Duration::from_secs(60);
+        assert_eq!(next_delay(interval, None, None), interval);
+        let ttl = Some(Duration::from_secs(600));
+        assert_eq!(next_delay(interval, ttl, None), Duration::from_secs(600));
+        let retry = Some(Duration::from_secs(3600));
+        assert_eq!(next_delay(interval, ttl, retry), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = parse_date("Mon, 01 Jan 2024 00:00:00 GMT").unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Mon, 01 Jan 2024 00:10:00 GMT", now),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            parse_retry_after("Sun, 31 Dec 2023 00:00:00 GMT", now),
+            None
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
@@ -0,0 +1,177 @@
+#![allow(missing_docs, clippy::unwrap_used, clippy::expect_used)]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const FIXTURES: &str = "../../tests/fixtures";
+
+fn feedparse(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_feedparse"))
+        .args(args)
+        .output()
+        .expect("failed to run feedparse")
+}
+
+fn fixture(path: &str) -> String {
+    format!("{FIXTURES}/{path}")
+}
+
+#[test]
+fn test_parse_file_pretty() {
+    let output = feedparse(&[&fixture("rss/basic.xml")]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().count() > 1);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rss20");
+    assert!(json["entries"].as_array().is_some_and(|e| !e.is_empty()));
+}
+
+#[test]
+fn test_parse_stdin_compact() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_feedparse"))
+        .arg("--compact")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(br#"{"version":"https://jsonfeed.org/version/1.1","title":"T","items":[]}"#)
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["feed"]["title"], "T");
+}
+
+#[test]
+fn test_detect_only() {
+    let output = feedparse(&["--detect", &fixture("atom/basic.xml")]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "atom10");
+}
+
+#[test]
+fn test_canonical_output() {
+    let output = feedparse(&["--canonical", "--compact", &fixture("atom/basic.xml")]);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["schema"], "feedparser-rs/canonical/1");
+    assert_eq!(json["version"], "atom10");
+
+    let output = feedparse(&["batch", "--canonical", &fixture("rss/basic.xml")]);
+    let lines = json_lines(&output);
+    assert_eq!(lines[0]["schema"], "feedparser-rs/canonical/1");
+    assert_eq!(lines[0]["input"], fixture("rss/basic.xml"));
+}
+
+#[test]
+fn test_limits_override() {
+    let output = feedparse(&["--compact", "--max-entries", "1", &fixture("rss/basic.xml")]);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["entries"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_exit_codes() {
+    let malformed = fixture("malformed/missing-closing-tag.xml");
+    assert_eq!(feedparse(&[&malformed]).status.code(), Some(0));
+    assert_eq!(feedparse(&["--strict", &malformed]).status.code(), Some(3));
+    assert_eq!(feedparse(&["does/not/exist.xml"]).status.code(), Some(1));
+    assert_eq!(feedparse(&["--limits", "bogus"]).status.code(), Some(2));
+}
+
+fn json_lines(output: &Output) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_batch_jsonl() {
+    let output = feedparse(&[
+        "batch",
+        "--jobs",
+        "2",
+        &fixture("rss/basic.xml"),
+        &fixture("atom/*.xml"),
+        &fixture("json"),
+    ]);
+    assert!(output.status.success());
+    let lines = json_lines(&output);
+    assert_eq!(lines.len(), 6);
+    let mut inputs: Vec<&str> = lines.iter().map(|l| l["input"].as_str().unwrap()).collect();
+    inputs.sort_unstable();
+    assert_eq!(inputs[0], fixture("atom/basic.xml"));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("parsed 6 of 6 inputs (0 failed"));
+}
+
+#[test]
+fn test_batch_failure_summary() {
+    let list = std::env::temp_dir().join("feedparse-batch-list.txt");
+    std::fs::write(
+        &list,
+        format!("# feeds\n{}\n\nmissing.xml\n", fixture("rss/basic.xml")),
+    )
+    .unwrap();
+    let output = feedparse(&[
+        "batch",
+        "--files-from",
+        list.to_str().unwrap(),
+        &fixture("nothing/*.xml"),
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(json_lines(&output).len(), 1);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("parsed 1 of 3 inputs (2 failed"));
+    assert!(stderr.contains("missing.xml: failed to read"));
+    assert!(stderr.contains("no files match pattern"));
+}
+
+#[test]
+fn test_batch_strict() {
+    let output = feedparse(&[
+        "batch",
+        "--strict",
+        &fixture("rss/basic.xml"),
+        &fixture("malformed/missing-closing-tag.xml"),
+    ]);
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(json_lines(&output).len(), 2);
+}
+
+#[test]
+fn test_opml_reports_per_feed_status() {
+    let opml = std::env::temp_dir().join("feedparse-subscriptions.opml");
+    std::fs::write(
+        &opml,
+        r#"<?xml version="1.0"?>
+<opml version="2.0"><head><title>Subs</title></head><body>
+  <outline text="Local">
+    <outline text="One" xmlUrl="http://127.0.0.1:1/one.xml"/>
+    <outline text="Two" xmlUrl="http://127.0.0.1:1/two.xml"/>
+  </outline>
+  <outline text="Dup" xmlUrl="http://127.0.0.1:1/one.xml"/>
+</body></opml>"#,
+    )
+    .unwrap();
+    let output = feedparse(&["opml", "--host-delay-ms", "0", opml.to_str().unwrap()]);
+    // Loopback URLs are rejected by the SSRF guard, so both feeds fail
+    assert_eq!(output.status.code(), Some(1));
+    let lines = json_lines(&output);
+    assert_eq!(lines.len(), 2);
+    assert!(
+        lines
+            .iter()
+            .all(|l| l["ok"] == false && l["error"].is_string())
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("fetched 0 of 2 feeds (2 failed)"));
+}
@@ -0,0 +1,275 @@
+//! Throughput and allocation benchmarks over representative corpora
+//!
+//! Each corpus stands for a workload the parser sees in practice:
+//!
+//! - `rss/small`: a five-item blog feed, dominated by per-parse overhead
+//! - `atom/huge`: 10,000 entries with HTML content, the archive-feed worst case
+//! - `podcast/namespaced`: episodes carrying iTunes, Podcasting 2.0, Media RSS
+//!   and Dublin Core elements, exercising the namespace handlers
+//! - `json/feed`: a JSON Feed 1.1 document of comparable size
+//!
+//! Timings are reported as bytes per second so corpora of different sizes
+//! compare directly. Before timing, each corpus is parsed once under a
+//! counting allocator and the allocation count, bytes allocated and peak heap
+//! use are printed; Criterion cannot track these itself.
+//!
+//! The large corpora are generated here rather than checked in. Run with
+//! `cargo bench -p feedparser-rs --bench corpora`.
+
+#![allow(missing_docs)]
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use feedparser_rs::parse;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const SMALL_RSS: &[u8] = include_bytes!("../../../benchmarks/fixtures/small.xml");
+
+/// System allocator that counts allocations and tracks peak heap use
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+impl Counting {
+    fn record(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded unchanged to the system allocator
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record(layout.size());
+        // SAFETY: same contract as the caller's
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        // SAFETY: same contract as the caller's
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::record(new_size);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        // SAFETY: same contract as the caller's
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+/// Prints allocations made by a single parse of `data`
+///
+/// A first, uncounted parse initializes lazily built tables so they are not
+/// charged to the corpus.
+fn report_allocations(name: &str, data: &[u8]) {
+    drop(parse(data));
+    let base = LIVE.load(Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED.store(0, Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+
+    let feed = parse(data);
+
+    let peak = PEAK.load(Ordering::Relaxed) - base;
+    let (count, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED.load(Ordering::Relaxed),
+    );
+    let entries = feed.map_or(0, |feed| feed.entries.len());
+    println!(
+        "{name:<20} {:>7} KiB input {entries:>6} entries {count:>9} allocs \
+         {:>9} KiB allocated {:>7} KiB peak",
+        data.len() / 1024,
+        bytes / 1024,
+        peak / 1024,
+    );
+}
+
+const PARAGRAPH: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. \
+    Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+/// Atom feed with `entries` entries carrying HTML summaries and content
+fn huge_atom(entries: usize) -> Vec<u8> {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="en">
+  <title>Archive</title>
+  <id>urn:uuid:60a76c80-d399-11d9-b93c-0003939e0af6</id>
+  <updated>2025-01-01T00:00:00Z</updated>
+  <link rel="self" href="https://example.com/atom.xml"/>
+  <author><name>Archivist</name></author>
+"#,
+    );
+    for i in 0..entries {
+        let _ = write!(
+            xml,
+            r#"  <entry>
+    <title type="html">Entry {i} &amp; more</title>
+    <id>https://example.com/entry/{i}</id>
+    <link rel="alternate" type="text/html" href="/entry/{i}"/>
+    <updated>2025-01-01T{:02}:{:02}:00Z</updated>
+    <published>2024-12-31T{:02}:{:02}:00+01:00</published>
+    <category term="tag{}" scheme="https://example.com/tags"/>
+    <summary type="html">&lt;p&gt;{PARAGRAPH}&lt;/p&gt;</summary>
+    <content type="html">&lt;p&gt;{PARAGRAPH}&lt;/p&gt;&lt;p&gt;&lt;a href="/more/{i}"&gt;More&lt;/a&gt; &lt;img src="/img/{i}.png"&gt;&lt;/p&gt;</content>
+  </entry>
+"#,
+            i % 24,
+            i % 60,
+            i % 24,
+            (i * 7) % 60,
+            i % 16,
+        );
+    }
+    xml.push_str("</feed>\n");
+    xml.into_bytes()
+}
+
+/// Podcast RSS feed whose episodes use every namespace the parser knows
+fn podcast(episodes: usize) -> Vec<u8> {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0"
+  xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"
+  xmlns:podcast="https://podcastindex.org/namespace/1.0"
+  xmlns:media="http://search.yahoo.com/mrss/"
+  xmlns:dc="http://purl.org/dc/elements/1.1/"
+  xmlns:content="http://purl.org/rss/1.0/modules/content/"
+  xmlns:atom="http://www.w3.org/2005/Atom">
+<channel>
+  <title>Benchmark Podcast</title>
+  <link>https://podcast.example.com</link>
+  <description>A show about parsing</description>
+  <language>en-us</language>
+  <atom:link rel="self" href="https://podcast.example.com/feed.xml" type="application/rss+xml"/>
+  <itunes:author>Host Name</itunes:author>
+  <itunes:owner><itunes:name>Host Name</itunes:name><itunes:email>host@example.com</itunes:email></itunes:owner>
+  <itunes:image href="https://podcast.example.com/cover.jpg"/>
+  <itunes:category text="Technology"><itunes:category text="Software How-To"/></itunes:category>
+  <itunes:explicit>false</itunes:explicit>
+  <itunes:type>episodic</itunes:type>
+  <podcast:guid>917393e3-1b1e-5cef-ace4-edaa54e1f810</podcast:guid>
+  <podcast:locked owner="host@example.com">yes</podcast:locked>
+  <podcast:funding url="https://example.com/donate">Support the show</podcast:funding>
+  <podcast:person role="host" img="https://example.com/host.jpg" href="https://example.com/host">Host Name</podcast:person>
+  <podcast:location geo="geo:30.2672,97.7431" osm="R113314">Austin, TX</podcast:location>
+"#,
+    );
+    for i in 0..episodes {
+        let _ = write!(
+            xml,
+            r#"  <item>
+    <title>Episode {i}: Parsing at scale</title>
+    <link>https://podcast.example.com/episodes/{i}</link>
+    <guid isPermaLink="false">episode-{i}</guid>
+    <pubDate>Mon, {:02} Jan 2024 10:00:00 +0000</pubDate>
+    <description>{PARAGRAPH}</description>
+    <content:encoded><![CDATA[<p>{PARAGRAPH}</p><ul><li>Topic one</li><li>Topic two</li></ul>]]></content:encoded>
+    <dc:creator>Host Name</dc:creator>
+    <enclosure url="https://cdn.example.com/ep{i}.mp3" length="{}" type="audio/mpeg"/>
+    <itunes:duration>01:{:02}:{:02}</itunes:duration>
+    <itunes:episode>{i}</itunes:episode>
+    <itunes:season>{}</itunes:season>
+    <itunes:episodeType>full</itunes:episodeType>
+    <itunes:explicit>no</itunes:explicit>
+    <itunes:image href="https://cdn.example.com/ep{i}.jpg"/>
+    <podcast:transcript url="https://cdn.example.com/ep{i}.vtt" type="text/vtt"/>
+    <podcast:chapters url="https://cdn.example.com/ep{i}.json" type="application/json+chapters"/>
+    <podcast:soundbite startTime="60" duration="30">Highlight</podcast:soundbite>
+    <podcast:person role="guest" href="https://example.com/guest{i}">Guest {i}</podcast:person>
+    <media:content url="https://cdn.example.com/ep{i}.mp4" type="video/mp4" medium="video" duration="3600"/>
+    <media:thumbnail url="https://cdn.example.com/ep{i}-thumb.jpg" width="640" height="360"/>
+  </item>
+"#,
+            i % 28 + 1,
+            50_000_000 + i,
+            i % 60,
+            (i * 13) % 60,
+            i / 50 + 1,
+        );
+    }
+    xml.push_str("</channel>\n</rss>\n");
+    xml.into_bytes()
+}
+
+/// JSON Feed 1.1 document with `items` items
+fn json_feed(items: usize) -> Vec<u8> {
+    let mut json = String::from(
+        r#"{
+  "version": "https://jsonfeed.org/version/1.1",
+  "title": "JSON Benchmark",
+  "home_page_url": "https://example.com/",
+  "feed_url": "https://example.com/feed.json",
+  "language": "en",
+  "authors": [{"name": "Author", "url": "https://example.com/author"}],
+  "items": ["#,
+    );
+    for i in 0..items {
+        if i > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            r#"
+    {{
+      "id": "https://example.com/item/{i}",
+      "url": "https://example.com/item/{i}",
+      "title": "Item {i}",
+      "content_html": "<p>{PARAGRAPH}</p>",
+      "summary": "{PARAGRAPH}",
+      "date_published": "2025-01-01T{:02}:{:02}:00Z",
+      "tags": ["rust", "feeds"],
+      "attachments": [{{"url": "https://cdn.example.com/{i}.mp3", "mime_type": "audio/mpeg", "size_in_bytes": 1000000}}]
+    }}"#,
+            i % 24,
+            i % 60,
+        );
+    }
+    json.push_str("\n  ]\n}\n");
+    json.into_bytes()
+}
+
+fn corpora() -> Vec<(&'static str, &'static str, Vec<u8>)> {
+    vec![
+        ("rss", "small", SMALL_RSS.to_vec()),
+        ("atom", "huge", huge_atom(10_000)),
+        ("podcast", "namespaced", podcast(500)),
+        ("json", "feed", json_feed(2_000)),
+    ]
+}
+
+fn bench_corpora(c: &mut Criterion) {
+    let corpora = corpora();
+
+    println!("Allocations per parse:");
+    for (format, size, data) in &corpora {
+        report_allocations(&format!("{format}/{size}"), data);
+    }
+
+    let mut group = c.benchmark_group("corpus");
+    for (format, size, data) in &corpora {
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        // Keep the multi-megabyte corpus to a tolerable run time
+        group.sample_size(if data.len() > 1024 * 1024 { 10 } else { 100 });
+        group.bench_with_input(BenchmarkId::new(*format, size), data, |b, data| {
+            b.iter(|| parse(black_box(data)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_corpora);
+criterion_main!(benches);
@@ -45,6 +45,12 @@ fn bench_detect_format(c: &mut Criterion) {
         |b, data| b.iter(|| detect_format(black_box(data))),
     );
 
+    group.bench_with_input(
+        BenchmarkId::new("detect", "large"),
+        &LARGE_FEED,
+        |b, data| b.iter(|| detect_format(black_box(data))),
+    );
+
     group.finish();
 }
 
@@ -0,0 +1,9 @@
+#![no_main]
+
+use feedparser_rs::{detect_format, detect_format_detailed};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let version = detect_format(data);
+    assert_eq!(detect_format_detailed(data).version, version);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use feedparser_rs::{ParseOptions, ParserLimits, parse, parse_with_options};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse(data);
+    // Strict limits and URL resolution take different paths
+    let _ = parse_with_options(
+        data,
+        &ParseOptions {
+            limits: ParserLimits::strict(),
+            base_url: Some("https://example.com/feed.xml".to_string()),
+            ..ParseOptions::default()
+        },
+    );
+});
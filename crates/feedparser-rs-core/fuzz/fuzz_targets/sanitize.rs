@@ -0,0 +1,33 @@
+#![no_main]
+
+use feedparser_rs::util::sanitize::{SanitizeOutput, SanitizePolicy, strip_tags};
+use feedparser_rs::util::text::HtmlToTextOptions;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // The first byte picks the policy; the rest is the markup
+    let Some((&flags, rest)) = data.split_first() else {
+        return;
+    };
+    let input = String::from_utf8_lossy(rest);
+    let policy = SanitizePolicy {
+        allow_mathml: flags & 1 != 0,
+        allow_svg: flags & 2 != 0,
+        iframe_hosts: if flags & 4 != 0 {
+            SanitizePolicy::COMMON_EMBED_HOSTS
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            Vec::new()
+        },
+        strip_tracking_pixels: flags & 16 != 0,
+        output: if flags & 8 != 0 {
+            SanitizeOutput::PlainText(HtmlToTextOptions::default())
+        } else {
+            SanitizeOutput::Html
+        },
+    };
+    let _ = policy.clean(&input);
+    let _ = strip_tags(&input);
+});
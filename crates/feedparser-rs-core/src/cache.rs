@@ -0,0 +1,141 @@
+//! Binary caching of parse results
+//!
+//! Encodes a [`ParsedFeed`] as CBOR behind a small header so aggregators can
+//! persist parsed feeds and skip re-parsing unchanged content on restart.
+//!
+//! The header is the 4-byte magic `FPRS`, a little-endian `u16` cache format
+//! version, a `u8` length followed by the crate version string, and then the
+//! CBOR payload. Cached bytes are only accepted by the exact crate version and
+//! format version that wrote them; anything else is rejected so the caller can
+//! fall back to parsing the original feed.
+
+use crate::{
+    error::{FeedError, Result},
+    types::ParsedFeed,
+};
+
+/// Magic bytes identifying a cached `ParsedFeed`
+const MAGIC: &[u8; 4] = b"FPRS";
+
+/// Cache format version, bumped whenever the header or encoding changes
+const FORMAT_VERSION: u16 = 1;
+
+/// Crate version that wrote the cache; type layouts may change between releases
+const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl ParsedFeed {
+    /// Serialize this feed to a compact, version-tagged binary form
+    ///
+    /// # Errors
+    ///
+    /// Returns `FeedError::IoError` if CBOR encoding fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParsedFeed, parse};
+    ///
+    /// let feed = parse(b"<rss version='2.0'><channel><title>T</title></channel></rss>").unwrap();
+    /// let bytes = feed.to_bytes().unwrap();
+    /// let cached = ParsedFeed::from_bytes(&bytes).unwrap();
+    /// assert_eq!(cached.feed.title.as_deref(), Some("T"));
+    /// ```
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(1024);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        // Crate versions are short semver strings, always well under 256 bytes
+        #[allow(clippy::cast_possible_truncation)]
+        out.push(CRATE_VERSION.len() as u8);
+        out.extend_from_slice(CRATE_VERSION.as_bytes());
+        ciborium::into_writer(self, &mut out).map_err(|e| FeedError::IoError(e.to_string()))?;
+        Ok(out)
+    }
+
+    /// Restore a feed previously produced by [`ParsedFeed::to_bytes`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `FeedError::InvalidFormat` if the data is not a cached feed,
+    /// was written by a different crate or format version, or is corrupt.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let rest = data
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| FeedError::InvalidFormat("Not a cached feed".to_string()))?;
+
+        let (format, rest) = rest
+            .split_first_chunk::<2>()
+            .ok_or_else(|| FeedError::InvalidFormat("Truncated cache header".to_string()))?;
+        let format = u16::from_le_bytes(*format);
+        if format != FORMAT_VERSION {
+            return Err(FeedError::InvalidFormat(format!(
+                "Cache format version {format} is not supported (expected {FORMAT_VERSION})"
+            )));
+        }
+
+        let (&len, rest) = rest
+            .split_first()
+            .ok_or_else(|| FeedError::InvalidFormat("Truncated cache header".to_string()))?;
+        let (version, payload) = rest
+            .split_at_checked(usize::from(len))
+            .ok_or_else(|| FeedError::InvalidFormat("Truncated cache header".to_string()))?;
+        if version != CRATE_VERSION.as_bytes() {
+            return Err(FeedError::InvalidFormat(format!(
+                "Cache written by feedparser-rs {}, expected {CRATE_VERSION}",
+                String::from_utf8_lossy(version)
+            )));
+        }
+
+        ciborium::from_reader(payload).map_err(|e| FeedError::InvalidFormat(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const XML: &[u8] = br#"<rss version="2.0"><channel>
+        <title>Cached</title>
+        <item><title>One</title><guid>1</guid>
+            <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate></item>
+    </channel></rss>"#;
+
+    #[test]
+    fn test_round_trip() {
+        let feed = parse(XML).unwrap();
+        let bytes = feed.to_bytes().unwrap();
+        assert!(bytes.starts_with(MAGIC));
+
+        let restored = ParsedFeed::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.version, feed.version);
+        assert_eq!(restored.feed.title, feed.feed.title);
+        assert_eq!(restored.entries[0].id, feed.entries[0].id);
+        assert_eq!(restored.entries[0].published, feed.entries[0].published);
+    }
+
+    #[test]
+    fn test_rejects_foreign_data() {
+        assert!(ParsedFeed::from_bytes(b"").is_err());
+        assert!(ParsedFeed::from_bytes(XML).is_err());
+        assert!(ParsedFeed::from_bytes(b"FPRS").is_err());
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let mut bytes = parse(XML).unwrap().to_bytes().unwrap();
+        bytes[4] = bytes[4].wrapping_add(1);
+        assert!(ParsedFeed::from_bytes(&bytes).is_err());
+
+        let mut bytes = parse(XML).unwrap().to_bytes().unwrap();
+        bytes[7] = b'x';
+        let err = ParsedFeed::from_bytes(&bytes).unwrap_err();
+        assert!(err.to_string().contains("expected"));
+    }
+
+    #[test]
+    fn test_rejects_truncated_payload() {
+        let bytes = parse(XML).unwrap().to_bytes().unwrap();
+        assert!(ParsedFeed::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 //! Compatibility utilities for feedparser API
 //!
 //! This module provides utilities to ensure API compatibility with
-//! Python's feedparser library.
+//! Python's feedparser library, including the table of legacy key names
+//! (`channel`, `description`, `modified`, ...) that feedparser accepts as
+//! aliases for the modern fields; see [`resolve_alias`](crate::compat::resolve_alias).
 
 use crate::types::FeedVersion;
 
@@ -105,6 +107,125 @@ pub fn is_valid_version(version: &str) -> bool {
     )
 }
 
+/// Object a feedparser-style key is looked up on
+///
+/// Python feedparser accepts legacy key names on its result dictionaries
+/// and the set of accepted names depends on which dictionary is indexed:
+/// `description` means the subtitle on a feed but the summary on an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AliasScope {
+    /// The top-level result (`feed`, `entries`, ...)
+    Container,
+    /// Feed metadata (`result.feed`)
+    Feed,
+    /// A single entry (`result.entries[i]`)
+    Entry,
+    /// A link (`feed.links[i]`, `entry.links[i]`)
+    Link,
+}
+
+/// Legacy container keys and the fields they resolve to
+const CONTAINER_ALIASES: &[(&str, &[&str])] = &[
+    // RSS uses <channel>, Atom uses <feed>
+    ("channel", &["feed"]),
+    // RSS uses <item>, Atom uses <entry>
+    ("items", &["entries"]),
+];
+
+/// Legacy feed keys and the fields they resolve to, tried in order
+const FEED_ALIASES: &[(&str, &[&str])] = &[
+    ("description", &["subtitle", "summary"]),
+    ("description_detail", &["subtitle_detail", "summary_detail"]),
+    // Atom 0.3
+    ("tagline", &["subtitle"]),
+    ("tagline_detail", &["subtitle_detail"]),
+    // RSS 1.0
+    ("info", &["subtitle"]),
+    ("info_detail", &["subtitle_detail"]),
+    ("copyright", &["rights"]),
+    ("copyright_detail", &["rights_detail"]),
+    ("modified", &["updated"]),
+    ("modified_parsed", &["updated_parsed"]),
+    ("date", &["updated", "published"]),
+    ("date_parsed", &["updated_parsed", "published_parsed"]),
+    ("url", &["link"]),
+    // GeoRSS location
+    ("where", &["geo"]),
+    // Syndication module, flattened as in feedparser
+    ("sy_updateperiod", &["syndication"]),
+    ("sy_updatefrequency", &["syndication"]),
+    ("sy_updatebase", &["syndication"]),
+    // blogChannel module, flattened as in feedparser
+    ("blogchannel_blogroll", &["blog_channel"]),
+    ("blogchannel_mysubscriptions", &["blog_channel"]),
+    ("blogchannel_blink", &["blog_channel"]),
+];
+
+/// Legacy entry keys and the fields they resolve to, tried in order
+const ENTRY_ALIASES: &[(&str, &[&str])] = &[
+    ("guid", &["id"]),
+    ("description", &["summary"]),
+    ("description_detail", &["summary_detail"]),
+    ("issued", &["published"]),
+    ("issued_parsed", &["published_parsed"]),
+    ("modified", &["updated"]),
+    ("modified_parsed", &["updated_parsed"]),
+    ("date", &["updated", "published"]),
+    ("date_parsed", &["updated_parsed", "published_parsed"]),
+];
+
+/// Legacy link keys and the fields they resolve to
+const LINK_ALIASES: &[(&str, &[&str])] = &[("url", &["href"])];
+
+/// Every legacy key accepted in `scope`, with the fields it resolves to
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::compat::{AliasScope, aliases};
+///
+/// assert!(aliases(AliasScope::Entry).iter().any(|(key, _)| *key == "guid"));
+/// ```
+#[must_use]
+pub const fn aliases(scope: AliasScope) -> &'static [(&'static str, &'static [&'static str])] {
+    match scope {
+        AliasScope::Container => CONTAINER_ALIASES,
+        AliasScope::Feed => FEED_ALIASES,
+        AliasScope::Entry => ENTRY_ALIASES,
+        AliasScope::Link => LINK_ALIASES,
+    }
+}
+
+/// Resolve a feedparser-style key to the field names it stands for
+///
+/// Returns `None` when `key` is not a legacy alias in `scope`. Otherwise the
+/// returned fields should be tried in order and the first one that has a
+/// value used, which is how feedparser falls back from `subtitle` to
+/// `summary` for a feed's `description`. The Python and Node bindings both
+/// resolve aliases through this table so they accept the same keys.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::compat::{AliasScope, resolve_alias};
+///
+/// assert_eq!(resolve_alias(AliasScope::Feed, "copyright"), Some(&["rights"][..]));
+/// assert_eq!(
+///     resolve_alias(AliasScope::Feed, "description"),
+///     Some(&["subtitle", "summary"][..])
+/// );
+/// assert_eq!(resolve_alias(AliasScope::Entry, "description"), Some(&["summary"][..]));
+/// assert_eq!(resolve_alias(AliasScope::Container, "items"), Some(&["entries"][..]));
+/// assert_eq!(resolve_alias(AliasScope::Entry, "title"), None);
+/// ```
+#[must_use]
+pub fn resolve_alias(scope: AliasScope, key: &str) -> Option<&'static [&'static str]> {
+    aliases(scope)
+        .iter()
+        .find(|(alias, _)| *alias == key)
+        .map(|(_, fields)| *fields)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +305,57 @@ mod tests {
         assert!(!is_valid_version("rss20 "));
         assert!(!is_valid_version("rss 20"));
     }
+
+    #[test]
+    fn test_resolve_alias_feed() {
+        assert_eq!(
+            resolve_alias(AliasScope::Feed, "description"),
+            Some(&["subtitle", "summary"][..])
+        );
+        assert_eq!(
+            resolve_alias(AliasScope::Feed, "modified"),
+            Some(&["updated"][..])
+        );
+        assert_eq!(resolve_alias(AliasScope::Feed, "url"), Some(&["link"][..]));
+        assert_eq!(resolve_alias(AliasScope::Feed, "guid"), None);
+    }
+
+    #[test]
+    fn test_resolve_alias_entry() {
+        assert_eq!(resolve_alias(AliasScope::Entry, "guid"), Some(&["id"][..]));
+        assert_eq!(
+            resolve_alias(AliasScope::Entry, "issued"),
+            Some(&["published"][..])
+        );
+        assert_eq!(resolve_alias(AliasScope::Entry, "copyright"), None);
+    }
+
+    #[test]
+    fn test_resolve_alias_container_and_link() {
+        assert_eq!(
+            resolve_alias(AliasScope::Container, "channel"),
+            Some(&["feed"][..])
+        );
+        assert_eq!(resolve_alias(AliasScope::Link, "url"), Some(&["href"][..]));
+        assert_eq!(resolve_alias(AliasScope::Link, "href"), None);
+    }
+
+    #[test]
+    fn test_aliases_are_unique() {
+        for scope in [
+            AliasScope::Container,
+            AliasScope::Feed,
+            AliasScope::Entry,
+            AliasScope::Link,
+        ] {
+            let table = aliases(scope);
+            for (i, (key, fields)) in table.iter().enumerate() {
+                assert!(!fields.is_empty(), "{key} has no targets");
+                assert!(
+                    table[i + 1..].iter().all(|(other, _)| other != key),
+                    "{key} listed twice"
+                );
+            }
+        }
+    }
 }
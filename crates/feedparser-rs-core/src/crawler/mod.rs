@@ -0,0 +1,629 @@
+//! Polling a set of feed subscriptions
+//!
+//! [`Crawler`] owns a list of subscribed URLs and fetches the ones that are
+//! due with a pool of worker threads, at most
+//! [`per_host`](CrawlerConfig::per_host) at a time against one host. Each
+//! subscription keeps its `ETag`/`Last-Modified` validators for conditional
+//! GET and is rescheduled from `Cache-Control: max-age`, the feed's `<ttl>`
+//! or `sy:updatePeriod`, or the configured default. Failures back off
+//! exponentially and honour `Retry-After`. Subscriptions and the latest
+//! snapshot of each feed live in a [`FeedStore`], in memory by default or
+//! on disk with [`FileStore`]. Callbacks registered with
+//! [`Crawler::on_event`] hear about new entries, metadata changes,
+//! permanent redirects and failing feeds as they are found.
+//!
+//! Requires the `crawler` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use feedparser_rs::FeedHttpClient;
+//! use feedparser_rs::crawler::{Crawler, CrawlerConfig};
+//! use std::sync::atomic::AtomicBool;
+//!
+//! let mut crawler = Crawler::new(FeedHttpClient::new()?, CrawlerConfig::default());
+//! crawler.subscribe("https://example.com/feed.xml")?;
+//! crawler.subscribe("https://blog.example.org/atom.xml")?;
+//!
+//! // One pass over everything that is due
+//! let report = crawler.crawl_due(|id, feed| {
+//!     println!("{id}: {} entries", feed.entries.len());
+//! })?;
+//! println!("{} updated, {} failed", report.updated, report.failed);
+//!
+//! // Or keep polling until `stop` is set
+//! let stop = AtomicBool::new(false);
+//! crawler.run(&stop, |id, feed| println!("{id}: {:?}", feed.feed.title))?;
+//! # Ok::<(), feedparser_rs::FeedError>(())
+//! ```
+
+mod schedule;
+mod store;
+
+use crate::error::Result;
+use crate::http::{FeedCacheState, FeedHttpClient, FeedHttpResponse};
+use crate::options::ParseOptions;
+use crate::types::{Entry, ParsedFeed};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+pub use store::{FeedStore, FileStore, MemoryStore};
+
+/// Longest sleep in [`Crawler::run`] before checking the stop flag again
+const STOP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Identifier of a subscription, returned by [`Crawler::subscribe`]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct FeedId(pub u64);
+
+impl fmt::Display for FeedId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "feed#{}", self.0)
+    }
+}
+
+/// Concurrency and scheduling settings for a [`Crawler`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrawlerConfig {
+    /// Number of worker threads fetching in parallel
+    ///
+    /// Default: `4`
+    pub workers: usize,
+    /// Maximum concurrent requests to one host
+    ///
+    /// Default: `2`
+    pub per_host: usize,
+    /// Poll interval when neither the server nor the feed suggests one
+    ///
+    /// Default: 1 hour
+    pub default_interval: Duration,
+    /// Shortest poll interval, whatever the feed or server asks for
+    ///
+    /// Default: 15 minutes
+    pub min_interval: Duration,
+    /// Longest poll interval, also the cap on retry backoff
+    ///
+    /// Default: 24 hours
+    pub max_interval: Duration,
+    /// Delay before the first retry; doubles with each further failure
+    ///
+    /// Default: 5 minutes
+    pub retry_delay: Duration,
+    /// Consecutive failures after which [`CrawlEvent::RepeatedFailures`]
+    /// is reported, on that failure and each one after it
+    ///
+    /// Default: `3`
+    pub failure_threshold: u32,
+}
+
+impl Default for CrawlerConfig {
+    fn default() -> Self {
+        Self {
+            workers: 4,
+            per_host: 2,
+            default_interval: Duration::from_secs(3600),
+            min_interval: Duration::from_secs(15 * 60),
+            max_interval: Duration::from_secs(24 * 3600),
+            retry_delay: Duration::from_secs(5 * 60),
+            failure_threshold: 3,
+        }
+    }
+}
+
+/// A subscribed feed and its polling state
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Subscription {
+    /// Feed URL
+    pub url: String,
+    /// Validators sent with the next request
+    pub cache: FeedCacheState,
+    /// When the feed was last fetched, successfully or not
+    pub last_fetched: Option<DateTime<Utc>>,
+    /// When the feed is next due
+    pub next_fetch: DateTime<Utc>,
+    /// Poll interval chosen after the last successful fetch
+    pub interval: Duration,
+    /// Consecutive failed fetches
+    pub failures: u32,
+    /// Error of the last failed fetch, cleared on success
+    pub last_error: Option<String>,
+}
+
+/// Counts of what one [`Crawler::crawl_due`] pass did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CrawlReport {
+    /// Feeds fetched and parsed
+    pub updated: usize,
+    /// Feeds the server reported unchanged (304 Not Modified)
+    pub not_modified: usize,
+    /// Feeds whose fetch or parse failed
+    pub failed: usize,
+}
+
+/// A change noticed while crawling a subscription, see
+/// [`Crawler::on_event`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrawlEvent {
+    /// Entries not seen in earlier fetches, or seen with different content,
+    /// in feed order
+    ///
+    /// On the first fetch of a feed every entry is new.
+    NewEntries(Vec<Entry>),
+    /// Names of feed-level metadata fields that changed since the last
+    /// download, as reported by [`diff`](fn@crate::diff)
+    MetadataChanged(Vec<&'static str>),
+    /// The feed was reached only through permanent (301 or 308) redirects;
+    /// the subscription now points at `to`
+    PermanentRedirect {
+        /// URL the subscription had
+        from: String,
+        /// URL the subscription was moved to
+        to: String,
+    },
+    /// The feed failed [`failure_threshold`](CrawlerConfig::failure_threshold)
+    /// or more times in a row
+    RepeatedFailures {
+        /// Consecutive failures so far
+        failures: u32,
+        /// Error of the latest failure
+        error: String,
+    },
+}
+
+/// Callback registered with [`Crawler::on_event`]
+type EventCallback = Box<dyn FnMut(FeedId, &CrawlEvent)>;
+
+/// Polls feed subscriptions on a schedule
+///
+/// See the [module documentation](self) for an overview.
+pub struct Crawler {
+    client: FeedHttpClient,
+    config: CrawlerConfig,
+    options: ParseOptions,
+    /// In-memory copy of the subscriptions in `store`
+    subscriptions: BTreeMap<FeedId, Subscription>,
+    store: Box<dyn FeedStore>,
+    next_id: u64,
+    listeners: Vec<EventCallback>,
+}
+
+/// A fetch handed to a worker thread
+struct Job {
+    id: FeedId,
+    url: String,
+    host: String,
+    cache: FeedCacheState,
+}
+
+/// Jobs not yet started and the number of requests in flight per host
+struct Queue {
+    jobs: VecDeque<Job>,
+    active: HashMap<String, usize>,
+}
+
+impl Crawler {
+    /// Creates a crawler with no subscriptions, kept in a [`MemoryStore`]
+    ///
+    /// Every request goes through `client`, so its proxy, headers, redirect
+    /// policy and DNS settings apply.
+    #[must_use]
+    pub fn new(client: FeedHttpClient, config: CrawlerConfig) -> Self {
+        Self {
+            client,
+            config,
+            options: ParseOptions::default(),
+            subscriptions: BTreeMap::new(),
+            store: Box::new(MemoryStore::new()),
+            next_id: 0,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Keeps subscriptions and feed snapshots in `store`
+    ///
+    /// Replaces the current subscriptions with those already in the store,
+    /// so a crawler restarted on the same store carries on where it left
+    /// off, validators and schedule included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored subscriptions cannot be read.
+    pub fn with_store(mut self, store: impl FeedStore + 'static) -> Result<Self> {
+        self.subscriptions = store.subscriptions()?.into_iter().collect();
+        self.next_id = self
+            .subscriptions
+            .keys()
+            .next_back()
+            .map_or(0, |id| id.0.saturating_add(1));
+        self.store = Box::new(store);
+        Ok(self)
+    }
+
+    /// The store holding subscriptions and the latest feed snapshots
+    #[must_use]
+    pub fn store(&self) -> &dyn FeedStore {
+        self.store.as_ref()
+    }
+
+    /// Sets the options fetched feeds are parsed with
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Registers a callback for changes found while crawling
+    ///
+    /// Callbacks run on the thread calling [`crawl_due`](Self::crawl_due),
+    /// in registration order, after the subscription and snapshot are
+    /// stored. New entries are tracked through the store's seen-entry state
+    /// only while at least one callback is registered, so entries already
+    /// present when the first callback is added are reported as new once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use feedparser_rs::FeedHttpClient;
+    /// use feedparser_rs::crawler::{CrawlEvent, Crawler, CrawlerConfig};
+    ///
+    /// let mut crawler = Crawler::new(FeedHttpClient::new()?, CrawlerConfig::default());
+    /// crawler.on_event(|id, event| match event {
+    ///     CrawlEvent::NewEntries(entries) => println!("{id}: {} new", entries.len()),
+    ///     CrawlEvent::PermanentRedirect { to, .. } => println!("{id} moved to {to}"),
+    ///     _ => {}
+    /// });
+    /// crawler.subscribe("https://example.com/feed.xml")?;
+    /// crawler.crawl_due(|_, _| {})?;
+    /// # Ok::<(), feedparser_rs::FeedError>(())
+    /// ```
+    pub fn on_event(&mut self, callback: impl FnMut(FeedId, &CrawlEvent) + 'static) {
+        self.listeners.push(Box::new(callback));
+    }
+
+    /// Adds a feed, due immediately
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be stored.
+    pub fn subscribe(&mut self, url: impl Into<String>) -> Result<FeedId> {
+        let id = FeedId(self.next_id);
+        let subscription = Subscription {
+            url: url.into(),
+            cache: FeedCacheState::default(),
+            last_fetched: None,
+            next_fetch: DateTime::<Utc>::MIN_UTC,
+            interval: self.config.default_interval,
+            failures: 0,
+            last_error: None,
+        };
+        self.store.put_subscription(id, &subscription)?;
+        self.subscriptions.insert(id, subscription);
+        self.next_id += 1;
+        Ok(id)
+    }
+
+    /// Removes a feed and its stored data, returning its state
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the feed cannot be removed from the store.
+    pub fn unsubscribe(&mut self, id: FeedId) -> Result<Option<Subscription>> {
+        self.store.remove(id)?;
+        Ok(self.subscriptions.remove(&id))
+    }
+
+    /// Polling state of a feed
+    #[must_use]
+    pub fn subscription(&self, id: FeedId) -> Option<&Subscription> {
+        self.subscriptions.get(&id)
+    }
+
+    /// All subscriptions, in subscription order
+    pub fn subscriptions(&self) -> impl Iterator<Item = (FeedId, &Subscription)> {
+        self.subscriptions.iter().map(|(&id, sub)| (id, sub))
+    }
+
+    /// When the next feed is due, or `None` without subscriptions
+    #[must_use]
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.subscriptions.values().map(|sub| sub.next_fetch).min()
+    }
+
+    /// Fetches every feed that is due now
+    ///
+    /// `on_feed` runs on the calling thread as each fetch completes, with
+    /// feeds that were downloaded and parsed; unchanged feeds and failures
+    /// only update the subscription state. Downloaded feeds are saved as
+    /// snapshots in the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first store error. Fetch failures are not errors; they
+    /// are counted in the report and recorded on the subscription.
+    pub fn crawl_due(&mut self, on_feed: impl FnMut(FeedId, ParsedFeed)) -> Result<CrawlReport> {
+        self.crawl_due_at(Utc::now(), on_feed)
+    }
+
+    /// Like [`crawl_due`](Self::crawl_due), treating `now` as the current
+    /// time when picking due feeds and scheduling the next fetches
+    ///
+    /// # Errors
+    ///
+    /// As for [`crawl_due`](Self::crawl_due).
+    pub fn crawl_due_at(
+        &mut self,
+        now: DateTime<Utc>,
+        mut on_feed: impl FnMut(FeedId, ParsedFeed),
+    ) -> Result<CrawlReport> {
+        let jobs: VecDeque<Job> = self
+            .subscriptions
+            .iter()
+            .filter(|(_, sub)| sub.next_fetch <= now)
+            .map(|(&id, sub)| Job {
+                id,
+                host: url::Url::parse(&sub.url)
+                    .ok()
+                    .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+                    .unwrap_or_else(|| sub.url.clone()),
+                url: sub.url.clone(),
+                cache: sub.cache.clone(),
+            })
+            .collect();
+        let mut report = CrawlReport::default();
+        if jobs.is_empty() {
+            return Ok(report);
+        }
+
+        let workers = self.config.workers.clamp(1, jobs.len());
+        let queue = Mutex::new(Queue {
+            jobs,
+            active: HashMap::new(),
+        });
+        let ready = Condvar::new();
+        let (results, received) = mpsc::channel();
+        // Clones share the connection pool; this one keeps `self` free for
+        // updating subscriptions while workers run
+        let client = self.client.clone();
+        let per_host = self.config.per_host.max(1);
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let results = results.clone();
+                let (queue, ready, client) = (&queue, &ready, &client);
+                scope.spawn(move || {
+                    while let Some(job) = next_job(queue, ready, per_host) {
+                        let response = client.get(
+                            &job.url,
+                            job.cache.etag.as_deref(),
+                            job.cache.last_modified.as_deref(),
+                            None,
+                        );
+                        finish_job(queue, ready, &job.host);
+                        if results.send((job, response)).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+            drop(results);
+
+            // Keep draining results after a store error so every
+            // subscription is rescheduled in memory
+            let mut stored = Ok(());
+            for (job, response) in received {
+                let mut events = Vec::new();
+                let result = self.handle_response(now, &job, response, &mut events);
+                if stored.is_ok() {
+                    let feed = result.as_ref().ok().and_then(Option::as_ref);
+                    stored = self.save(job.id, feed, &mut events);
+                }
+                for event in &events {
+                    for listener in &mut self.listeners {
+                        listener(job.id, event);
+                    }
+                }
+                match result {
+                    Ok(Some(feed)) => {
+                        report.updated += 1;
+                        on_feed(job.id, feed);
+                    }
+                    Ok(None) => report.not_modified += 1,
+                    Err(_) => report.failed += 1,
+                }
+            }
+            stored
+        })?;
+        Ok(report)
+    }
+
+    /// Polls until `stop` is set, sleeping until the next feed is due
+    ///
+    /// `stop` is checked at least once a second.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first store error and returns it.
+    pub fn run(
+        &mut self,
+        stop: &AtomicBool,
+        mut on_feed: impl FnMut(FeedId, ParsedFeed),
+    ) -> Result<()> {
+        while !stop.load(Ordering::Relaxed) {
+            self.crawl_due(&mut on_feed)?;
+            let wait = self
+                .next_due()
+                .and_then(|due| (due - Utc::now()).to_std().ok())
+                .unwrap_or(STOP_POLL_INTERVAL)
+                .min(STOP_POLL_INTERVAL);
+            thread::sleep(wait);
+        }
+        Ok(())
+    }
+
+    /// Writes a subscription and, when downloaded, its feed to the store
+    ///
+    /// With callbacks registered, first compares the feed with the stored
+    /// snapshot and seen entries and adds what changed to `events`.
+    fn save(
+        &mut self,
+        id: FeedId,
+        feed: Option<&ParsedFeed>,
+        events: &mut Vec<CrawlEvent>,
+    ) -> Result<()> {
+        let Some(sub) = self.subscriptions.get(&id) else {
+            return Ok(());
+        };
+        self.store.put_subscription(id, sub)?;
+        let Some(feed) = feed else {
+            return Ok(());
+        };
+        if !self.listeners.is_empty() {
+            if let Some(previous) = self.store.snapshot(id)? {
+                let changed = crate::diff::diff(&previous, feed).feed_changes;
+                if !changed.is_empty() {
+                    events.push(CrawlEvent::MetadataChanged(changed));
+                }
+            }
+            let mut tracker = self.store.seen(id)?.unwrap_or_default();
+            let new: Vec<Entry> = tracker.update(feed).into_iter().cloned().collect();
+            tracker.retain_current(feed);
+            self.store.put_seen(id, &tracker)?;
+            if !new.is_empty() {
+                events.push(CrawlEvent::NewEntries(new));
+            }
+        }
+        self.store.put_snapshot(id, feed)
+    }
+
+    /// Parses a response and reschedules its subscription
+    ///
+    /// Returns the feed when it was downloaded and `None` when unchanged.
+    fn handle_response(
+        &mut self,
+        now: DateTime<Utc>,
+        job: &Job,
+        response: Result<FeedHttpResponse>,
+        events: &mut Vec<CrawlEvent>,
+    ) -> Result<Option<ParsedFeed>> {
+        let mut retry_after = None;
+        let mut moved = None;
+        let result = response.and_then(|mut response| {
+            if matches!(response.status, 429 | 503) {
+                retry_after = schedule::retry_after(&response.headers, now);
+            }
+            moved = response.permanent_redirect.take();
+            FeedHttpClient::parse_response(
+                response,
+                job.cache.etag.as_deref(),
+                job.cache.last_modified.as_deref(),
+                &self.options,
+            )
+        });
+        let Some(sub) = self.subscriptions.get_mut(&job.id) else {
+            // Unsubscribed while the fetch was running
+            return result.map(Some);
+        };
+        sub.last_fetched = Some(now);
+        if let Some(to) = moved.filter(|to| *to != sub.url) {
+            let from = std::mem::replace(&mut sub.url, to.clone());
+            events.push(CrawlEvent::PermanentRedirect { from, to });
+        }
+
+        match result {
+            Ok(feed) => {
+                let hint = (feed.status != Some(304))
+                    .then(|| schedule::feed_interval(&feed))
+                    .flatten();
+                sub.interval = schedule::next_interval(
+                    &self.config,
+                    feed.headers.as_ref(),
+                    hint,
+                    sub.interval,
+                );
+                sub.next_fetch = after(now, sub.interval);
+                sub.failures = 0;
+                sub.last_error = None;
+                if feed.status == Some(304) {
+                    return Ok(None);
+                }
+                sub.cache = FeedCacheState::from_feed(&feed);
+                Ok(Some(feed))
+            }
+            Err(err) => {
+                sub.failures = sub.failures.saturating_add(1);
+                sub.last_error = Some(err.to_string());
+                if sub.failures >= self.config.failure_threshold {
+                    events.push(CrawlEvent::RepeatedFailures {
+                        failures: sub.failures,
+                        error: err.to_string(),
+                    });
+                }
+                let delay = schedule::retry_delay(&self.config, sub.failures, retry_after);
+                sub.next_fetch = after(now, delay);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Crawler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Crawler")
+            .field("config", &self.config)
+            .field("subscriptions", &self.subscriptions)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Takes the next job whose host is below the per-host limit
+///
+/// Waits while every queued job targets a busy host; returns `None` once
+/// the queue is empty.
+fn next_job(queue: &Mutex<Queue>, ready: &Condvar, per_host: usize) -> Option<Job> {
+    let mut queue = queue
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    loop {
+        if queue.jobs.is_empty() {
+            return None;
+        }
+        let free = queue
+            .jobs
+            .iter()
+            .position(|job| queue.active.get(&job.host).copied().unwrap_or(0) < per_host);
+        if let Some(job) = free.and_then(|index| queue.jobs.remove(index)) {
+            *queue.active.entry(job.host.clone()).or_default() += 1;
+            return Some(job);
+        }
+        queue = ready
+            .wait(queue)
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+    }
+}
+
+/// Releases a job's per-host slot and wakes waiting workers
+fn finish_job(queue: &Mutex<Queue>, ready: &Condvar, host: &str) {
+    let mut queue = queue
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(count) = queue.active.get_mut(host) {
+        *count = count.saturating_sub(1);
+    }
+    drop(queue);
+    ready.notify_all();
+}
+
+/// `now` plus `delay`, saturating at the latest representable time
+fn after(now: DateTime<Utc>, delay: Duration) -> DateTime<Utc> {
+    TimeDelta::from_std(delay)
+        .ok()
+        .and_then(|delay| now.checked_add_signed(delay))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
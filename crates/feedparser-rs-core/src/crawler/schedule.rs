@@ -0,0 +1,191 @@
+//! Poll interval and retry delay calculation
+
+use super::CrawlerConfig;
+use crate::http::parse_http_date;
+use crate::namespace::syndication::UpdatePeriod;
+use crate::types::ParsedFeed;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Delay before the next poll after a successful fetch
+///
+/// `Cache-Control: max-age` wins over the feed's own hints, which win over
+/// `previous`, the interval chosen last time. The result is clamped to the
+/// configured minimum and maximum.
+pub(super) fn next_interval(
+    config: &CrawlerConfig,
+    headers: Option<&HashMap<String, String>>,
+    feed_hint: Option<Duration>,
+    previous: Duration,
+) -> Duration {
+    headers
+        .and_then(cache_max_age)
+        .or(feed_hint)
+        .unwrap_or(previous)
+        .clamp(config.min_interval, config.max_interval)
+}
+
+/// Delay before retrying after the `failures`-th consecutive failure
+///
+/// Doubles from [`CrawlerConfig::retry_delay`] up to
+/// [`CrawlerConfig::max_interval`], or follows `Retry-After` when the
+/// server sent it.
+pub(super) fn retry_delay(
+    config: &CrawlerConfig,
+    failures: u32,
+    retry_after: Option<Duration>,
+) -> Duration {
+    let backoff = 2u32
+        .checked_pow(failures.saturating_sub(1))
+        .and_then(|factor| config.retry_delay.checked_mul(factor))
+        .unwrap_or(config.max_interval);
+    retry_after.unwrap_or(backoff).min(config.max_interval)
+}
+
+/// Poll interval the feed asks for through `<ttl>` or `sy:updatePeriod`
+pub(super) fn feed_interval(feed: &ParsedFeed) -> Option<Duration> {
+    if let Some(ttl) = feed.feed.ttl.filter(|&ttl| ttl > 0) {
+        return Some(Duration::from_secs(u64::from(ttl) * 60));
+    }
+    let syndication = feed.feed.syndication.as_deref()?;
+    let period = match syndication.update_period? {
+        UpdatePeriod::Hourly => 3600,
+        UpdatePeriod::Daily => 86_400,
+        UpdatePeriod::Weekly => 7 * 86_400,
+        UpdatePeriod::Monthly => 30 * 86_400,
+        UpdatePeriod::Yearly => 365 * 86_400,
+    };
+    let frequency = syndication.update_frequency.unwrap_or(1).max(1);
+    Some(Duration::from_secs(period / u64::from(frequency)))
+}
+
+/// `max-age` from a `Cache-Control` header, unless caching is disallowed
+fn cache_max_age(headers: &HashMap<String, String>) -> Option<Duration> {
+    let mut max_age = None;
+    for directive in headers.get("cache-control")?.split(',') {
+        let directive = directive.trim();
+        if directive.eq_ignore_ascii_case("no-cache") || directive.eq_ignore_ascii_case("no-store")
+        {
+            return None;
+        }
+        if let Some((name, value)) = directive.split_once('=')
+            && name.trim().eq_ignore_ascii_case("max-age")
+        {
+            max_age = value.trim().trim_matches('"').parse().ok();
+        }
+    }
+    max_age.map(Duration::from_secs)
+}
+
+/// Delay requested by a `Retry-After` header, in seconds or as a date
+pub(super) fn retry_after(
+    headers: &HashMap<String, String>,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let value = headers.get("retry-after")?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    (parse_http_date(value)? - now).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::namespace::syndication::SyndicationMeta;
+    use chrono::TimeZone;
+
+    fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_next_interval_prefers_cache_control() {
+        let config = CrawlerConfig::default();
+        let hint = Duration::from_secs(7200);
+        let previous = Duration::from_secs(3600);
+
+        let cached = headers(&[("cache-control", "public, max-age=1800")]);
+        assert_eq!(
+            next_interval(&config, Some(&cached), Some(hint), previous),
+            Duration::from_secs(1800)
+        );
+        let no_cache = headers(&[("cache-control", "no-cache, max-age=1800")]);
+        assert_eq!(
+            next_interval(&config, Some(&no_cache), Some(hint), previous),
+            hint
+        );
+        assert_eq!(next_interval(&config, None, None, previous), previous);
+    }
+
+    #[test]
+    fn test_next_interval_is_clamped() {
+        let config = CrawlerConfig::default();
+        let short = headers(&[("cache-control", "max-age=5")]);
+        assert_eq!(
+            next_interval(&config, Some(&short), None, config.default_interval),
+            config.min_interval
+        );
+        let long = Some(Duration::from_secs(365 * 86_400));
+        assert_eq!(
+            next_interval(&config, None, long, config.default_interval),
+            config.max_interval
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        let config = CrawlerConfig::default();
+        assert_eq!(retry_delay(&config, 1, None), config.retry_delay);
+        assert_eq!(retry_delay(&config, 3, None), config.retry_delay * 4);
+        assert_eq!(retry_delay(&config, 40, None), config.max_interval);
+        assert_eq!(
+            retry_delay(&config, 1, Some(Duration::from_secs(120))),
+            Duration::from_secs(120)
+        );
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            retry_after(&headers(&[("retry-after", "90")]), now),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            retry_after(
+                &headers(&[("retry-after", "Mon, 01 Jan 2024 12:10:00 GMT")]),
+                now
+            ),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            retry_after(
+                &headers(&[("retry-after", "Mon, 01 Jan 2024 11:00:00 GMT")]),
+                now
+            ),
+            None
+        );
+        assert_eq!(retry_after(&headers(&[]), now), None);
+    }
+
+    #[test]
+    fn test_feed_interval() {
+        let mut feed = ParsedFeed::default();
+        assert_eq!(feed_interval(&feed), None);
+
+        feed.feed.syndication = Some(Box::new(SyndicationMeta {
+            update_period: Some(UpdatePeriod::Daily),
+            update_frequency: Some(4),
+            ..SyndicationMeta::default()
+        }));
+        assert_eq!(feed_interval(&feed), Some(Duration::from_secs(6 * 3600)));
+
+        feed.feed.ttl = Some(30);
+        assert_eq!(feed_interval(&feed), Some(Duration::from_secs(1800)));
+    }
+}
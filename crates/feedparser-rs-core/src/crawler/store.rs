@@ -0,0 +1,325 @@
+//! Persistence for crawler state
+
+use super::{FeedId, Subscription};
+use crate::error::Result;
+use crate::tracker::FeedTracker;
+use crate::types::ParsedFeed;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Storage for subscriptions, feed snapshots and seen entries
+///
+/// A [`Crawler`](super::Crawler) keeps every subscription, including its
+/// cache validators and schedule, in its store and saves the latest
+/// snapshot of each feed it downloads. Seen-entry state is kept for
+/// [`FeedTracker`] users that want new-entry detection to survive restarts.
+///
+/// [`MemoryStore`] keeps everything in memory and [`FileStore`] writes JSON
+/// files to a directory; implement the trait to use a database instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use feedparser_rs::FeedHttpClient;
+/// use feedparser_rs::crawler::{Crawler, CrawlerConfig, FileStore};
+///
+/// // Subscriptions made in an earlier run are picked up again
+/// let store = FileStore::open("/var/lib/aggregator/feeds")?;
+/// let mut crawler = Crawler::new(FeedHttpClient::new()?, CrawlerConfig::default())
+///     .with_store(store)?;
+/// if crawler.subscriptions().next().is_none() {
+///     crawler.subscribe("https://example.com/feed.xml")?;
+/// }
+/// # Ok::<(), feedparser_rs::FeedError>(())
+/// ```
+pub trait FeedStore {
+    /// All stored subscriptions, in any order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    fn subscriptions(&self) -> Result<Vec<(FeedId, Subscription)>>;
+
+    /// Stores the state of a subscription, replacing any previous one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn put_subscription(&mut self, id: FeedId, subscription: &Subscription) -> Result<()>;
+
+    /// Deletes a subscription with its snapshot and seen entries
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn remove(&mut self, id: FeedId) -> Result<()>;
+
+    /// Latest stored snapshot of a feed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    fn snapshot(&self, id: FeedId) -> Result<Option<ParsedFeed>>;
+
+    /// Stores the latest snapshot of a feed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn put_snapshot(&mut self, id: FeedId, feed: &ParsedFeed) -> Result<()>;
+
+    /// Seen-entry fingerprints of a feed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    fn seen(&self, id: FeedId) -> Result<Option<FeedTracker>>;
+
+    /// Stores the seen-entry fingerprints of a feed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    fn put_seen(&mut self, id: FeedId, tracker: &FeedTracker) -> Result<()>;
+}
+
+/// [`FeedStore`] that keeps everything in memory
+///
+/// The default store of a [`Crawler`](super::Crawler); its state is lost
+/// when the process exits.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    subscriptions: HashMap<FeedId, Subscription>,
+    snapshots: HashMap<FeedId, ParsedFeed>,
+    seen: HashMap<FeedId, FeedTracker>,
+}
+
+impl MemoryStore {
+    /// Creates an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FeedStore for MemoryStore {
+    fn subscriptions(&self) -> Result<Vec<(FeedId, Subscription)>> {
+        Ok(self
+            .subscriptions
+            .iter()
+            .map(|(&id, sub)| (id, sub.clone()))
+            .collect())
+    }
+
+    fn put_subscription(&mut self, id: FeedId, subscription: &Subscription) -> Result<()> {
+        self.subscriptions.insert(id, subscription.clone());
+        Ok(())
+    }
+
+    fn remove(&mut self, id: FeedId) -> Result<()> {
+        self.subscriptions.remove(&id);
+        self.snapshots.remove(&id);
+        self.seen.remove(&id);
+        Ok(())
+    }
+
+    fn snapshot(&self, id: FeedId) -> Result<Option<ParsedFeed>> {
+        Ok(self.snapshots.get(&id).cloned())
+    }
+
+    fn put_snapshot(&mut self, id: FeedId, feed: &ParsedFeed) -> Result<()> {
+        self.snapshots.insert(id, feed.clone());
+        Ok(())
+    }
+
+    fn seen(&self, id: FeedId) -> Result<Option<FeedTracker>> {
+        Ok(self.seen.get(&id).cloned())
+    }
+
+    fn put_seen(&mut self, id: FeedId, tracker: &FeedTracker) -> Result<()> {
+        self.seen.insert(id, tracker.clone());
+        Ok(())
+    }
+}
+
+/// [`FeedStore`] that writes JSON files to a directory
+///
+/// Each feed gets `{id}.subscription.json`, `{id}.feed.json` and
+/// `{id}.seen.json`. Files are written to a temporary name and renamed into
+/// place, so a crash never leaves a half-written file behind.
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+/// File name suffixes of the three kinds of stored data
+const SUBSCRIPTION_SUFFIX: &str = ".subscription.json";
+const SNAPSHOT_SUFFIX: &str = ".feed.json";
+const SEEN_SUFFIX: &str = ".seen.json";
+
+impl FileStore {
+    /// Opens a store in `dir`, creating the directory if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns `FeedError::IoError` if the directory cannot be created.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Directory the store writes to
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, id: FeedId, suffix: &str) -> PathBuf {
+        self.dir.join(format!("{}{suffix}", id.0))
+    }
+
+    fn read<T: DeserializeOwned>(&self, id: FeedId, suffix: &str) -> Result<Option<T>> {
+        match fs::read(self.path(id, suffix)) {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write<T: Serialize>(&self, id: FeedId, suffix: &str, value: &T) -> Result<()> {
+        let path = self.path(id, suffix);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(value)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    fn delete(&self, id: FeedId, suffix: &str) -> Result<()> {
+        match fs::remove_file(self.path(id, suffix)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FeedStore for FileStore {
+    fn subscriptions(&self) -> Result<Vec<(FeedId, Subscription)>> {
+        let mut subscriptions = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let name = entry?.file_name();
+            let Some(id) = name
+                .to_str()
+                .and_then(|name| name.strip_suffix(SUBSCRIPTION_SUFFIX))
+                .and_then(|id| id.parse().ok())
+                .map(FeedId)
+            else {
+                continue;
+            };
+            if let Some(sub) = self.read(id, SUBSCRIPTION_SUFFIX)? {
+                subscriptions.push((id, sub));
+            }
+        }
+        Ok(subscriptions)
+    }
+
+    fn put_subscription(&mut self, id: FeedId, subscription: &Subscription) -> Result<()> {
+        self.write(id, SUBSCRIPTION_SUFFIX, subscription)
+    }
+
+    fn remove(&mut self, id: FeedId) -> Result<()> {
+        self.delete(id, SUBSCRIPTION_SUFFIX)?;
+        self.delete(id, SNAPSHOT_SUFFIX)?;
+        self.delete(id, SEEN_SUFFIX)
+    }
+
+    fn snapshot(&self, id: FeedId) -> Result<Option<ParsedFeed>> {
+        self.read(id, SNAPSHOT_SUFFIX)
+    }
+
+    fn put_snapshot(&mut self, id: FeedId, feed: &ParsedFeed) -> Result<()> {
+        self.write(id, SNAPSHOT_SUFFIX, feed)
+    }
+
+    fn seen(&self, id: FeedId) -> Result<Option<FeedTracker>> {
+        self.read(id, SEEN_SUFFIX)
+    }
+
+    fn put_seen(&mut self, id: FeedId, tracker: &FeedTracker) -> Result<()> {
+        self.write(id, SEEN_SUFFIX, tracker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::FeedCacheState;
+    use crate::parse;
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    fn subscription() -> Subscription {
+        Subscription {
+            url: "https://example.com/feed.xml".to_string(),
+            cache: FeedCacheState {
+                etag: Some("\"v1\"".to_string()),
+                ..FeedCacheState::default()
+            },
+            last_fetched: None,
+            next_fetch: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            interval: Duration::from_secs(3600),
+            failures: 0,
+            last_error: None,
+        }
+    }
+
+    fn exercise(store: &mut dyn FeedStore) {
+        let id = FeedId(7);
+        assert!(store.subscriptions().unwrap().is_empty());
+        assert!(store.snapshot(id).unwrap().is_none());
+
+        store.put_subscription(id, &subscription()).unwrap();
+        assert_eq!(store.subscriptions().unwrap(), [(id, subscription())]);
+
+        let feed = parse(b"<rss version='2.0'><channel><title>T</title><item><guid>1</guid></item></channel></rss>").unwrap();
+        store.put_snapshot(id, &feed).unwrap();
+        let snapshot = store.snapshot(id).unwrap().unwrap();
+        assert_eq!(snapshot.feed.title.as_deref(), Some("T"));
+
+        let mut tracker = FeedTracker::new();
+        tracker.update(&feed);
+        store.put_seen(id, &tracker).unwrap();
+        assert_eq!(store.seen(id).unwrap(), Some(tracker));
+
+        store.remove(id).unwrap();
+        assert!(store.subscriptions().unwrap().is_empty());
+        assert!(store.snapshot(id).unwrap().is_none());
+        assert!(store.seen(id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_memory_store() {
+        exercise(&mut MemoryStore::new());
+    }
+
+    #[test]
+    fn test_file_store() {
+        let dir = std::env::temp_dir().join(format!("feedparser-rs-store-{}", std::process::id()));
+        let mut store = FileStore::open(&dir).unwrap();
+        exercise(&mut store);
+
+        // State survives reopening the directory
+        store.put_subscription(FeedId(1), &subscription()).unwrap();
+        let reopened = FileStore::open(&dir).unwrap();
+        assert_eq!(
+            reopened.subscriptions().unwrap(),
+            [(FeedId(1), subscription())]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,443 @@
+//! Comparing two parses of the same feed
+//!
+//! [`diff`] reports which entries were added, removed or updated between two
+//! fetches of a feed, and which feed-level metadata fields changed.
+//!
+//! Entries are matched by identity: `id`, falling back to `link`, then
+//! `title`. Entries with none of these are matched by full equality, so an
+//! edited anonymous entry shows up as one removal plus one addition.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{diff, parse};
+//!
+//! let old = parse(br#"<rss version="2.0"><channel><title>Blog</title>
+//!     <item><guid>1</guid><title>First</title></item>
+//! </channel></rss>"#).unwrap();
+//! let new = parse(br#"<rss version="2.0"><channel><title>Blog</title>
+//!     <item><guid>2</guid><title>Second</title></item>
+//!     <item><guid>1</guid><title>First (edited)</title></item>
+//! </channel></rss>"#).unwrap();
+//!
+//! let changes = diff(&old, &new);
+//! assert_eq!(changes.added.len(), 1);
+//! assert_eq!(changes.updated[0].fields, ["title"]);
+//! assert!(changes.feed_changes.is_empty());
+//! ```
+
+use crate::types::{Entry, FeedMeta, ParsedFeed};
+use std::collections::HashMap;
+
+/// Differences between two parses of a feed
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FeedDiff<'a> {
+    /// Entries present only in the new feed, in new-feed order
+    pub added: Vec<&'a Entry>,
+    /// Entries present only in the old feed, in old-feed order
+    pub removed: Vec<&'a Entry>,
+    /// Entries present in both feeds whose content changed, in new-feed order
+    pub updated: Vec<EntryChange<'a>>,
+    /// Names of `FeedMeta` fields whose values differ
+    pub feed_changes: Vec<&'static str>,
+}
+
+impl FeedDiff<'_> {
+    /// Returns true if nothing changed between the two feeds
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.updated.is_empty()
+            && self.feed_changes.is_empty()
+    }
+}
+
+/// An entry present in both feeds with different content
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryChange<'a> {
+    /// Entry as it appeared in the old feed
+    pub old: &'a Entry,
+    /// Entry as it appears in the new feed
+    pub new: &'a Entry,
+    /// Names of `Entry` fields whose values differ
+    pub fields: Vec<&'static str>,
+}
+
+/// Compare two parses of the same feed
+///
+/// See the [module documentation](self) for how entries are matched.
+#[must_use]
+pub fn diff<'a>(old: &'a ParsedFeed, new: &'a ParsedFeed) -> FeedDiff<'a> {
+    let mut old_by_key: HashMap<&str, usize> = HashMap::new();
+    for (index, entry) in old.entries.iter().enumerate() {
+        if let Some(key) = entry_key(entry) {
+            old_by_key.entry(key).or_insert(index);
+        }
+    }
+
+    let mut result = FeedDiff {
+        feed_changes: meta_changes(&old.feed, &new.feed),
+        ..FeedDiff::default()
+    };
+    let mut matched = vec![false; old.entries.len()];
+
+    for entry in &new.entries {
+        let previous = entry_key(entry).map_or_else(
+            || {
+                old.entries
+                    .iter()
+                    .enumerate()
+                    .position(|(index, e)| !matched[index] && entry_key(e).is_none() && e == entry)
+            },
+            |key| old_by_key.get(key).copied(),
+        );
+        let Some(index) = previous else {
+            result.added.push(entry);
+            continue;
+        };
+        matched[index] = true;
+        let previous = &old.entries[index];
+        let fields = entry_changes(previous, entry);
+        if !fields.is_empty() {
+            result.updated.push(EntryChange {
+                old: previous,
+                new: entry,
+                fields,
+            });
+        }
+    }
+
+    result.removed = old
+        .entries
+        .iter()
+        .zip(matched)
+        .filter_map(|(entry, matched)| (!matched).then_some(entry))
+        .collect();
+    result
+}
+
+/// Identity key used to match entries across fetches
+fn entry_key(entry: &Entry) -> Option<&str> {
+    entry
+        .id
+        .as_deref()
+        .or(entry.link.as_deref())
+        .or(entry.title.as_deref())
+        .filter(|key| !key.is_empty())
+}
+
+fn check<T: PartialEq>(out: &mut Vec<&'static str>, name: &'static str, old: &T, new: &T) {
+    if old != new {
+        out.push(name);
+    }
+}
+
+fn meta_changes(old: &FeedMeta, new: &FeedMeta) -> Vec<&'static str> {
+    let mut out = Vec::new();
+    check(&mut out, "title", &old.title, &new.title);
+    check(
+        &mut out,
+        "title_detail",
+        &old.title_detail,
+        &new.title_detail,
+    );
+    check(&mut out, "link", &old.link, &new.link);
+    check(&mut out, "links", &old.links, &new.links);
+    check(&mut out, "subtitle", &old.subtitle, &new.subtitle);
+    check(
+        &mut out,
+        "subtitle_detail",
+        &old.subtitle_detail,
+        &new.subtitle_detail,
+    );
+    check(&mut out, "updated", &old.updated, &new.updated);
+    check(&mut out, "published", &old.published, &new.published);
+    check(&mut out, "author", &old.author, &new.author);
+    check(
+        &mut out,
+        "author_detail",
+        &old.author_detail,
+        &new.author_detail,
+    );
+    check(&mut out, "authors", &old.authors, &new.authors);
+    check(
+        &mut out,
+        "contributors",
+        &old.contributors,
+        &new.contributors,
+    );
+    check(
+        &mut out,
+        "managing_editor",
+        &old.managing_editor,
+        &new.managing_editor,
+    );
+    check(&mut out, "publisher", &old.publisher, &new.publisher);
+    check(
+        &mut out,
+        "publisher_detail",
+        &old.publisher_detail,
+        &new.publisher_detail,
+    );
+    check(&mut out, "language", &old.language, &new.language);
+    check(&mut out, "rights", &old.rights, &new.rights);
+    check(
+        &mut out,
+        "rights_detail",
+        &old.rights_detail,
+        &new.rights_detail,
+    );
+    check(&mut out, "generator", &old.generator, &new.generator);
+    check(
+        &mut out,
+        "generator_detail",
+        &old.generator_detail,
+        &new.generator_detail,
+    );
+    check(&mut out, "image", &old.image, &new.image);
+    check(&mut out, "icon", &old.icon, &new.icon);
+    check(&mut out, "logo", &old.logo, &new.logo);
+    check(&mut out, "raw_icon", &old.raw_icon, &new.raw_icon);
+    check(&mut out, "raw_logo", &old.raw_logo, &new.raw_logo);
+    check(
+        &mut out,
+        "raw_image_url",
+        &old.raw_image_url,
+        &new.raw_image_url,
+    );
+    check(&mut out, "tags", &old.tags, &new.tags);
+    check(&mut out, "id", &old.id, &new.id);
+    check(&mut out, "ttl", &old.ttl, &new.ttl);
+    check(&mut out, "expired", &old.expired, &new.expired);
+    #[cfg(feature = "itunes")]
+    check(&mut out, "itunes", &old.itunes, &new.itunes);
+    #[cfg(feature = "podcast20")]
+    check(&mut out, "podcast", &old.podcast, &new.podcast);
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_creator", &old.dc_creator, &new.dc_creator);
+    #[cfg(feature = "dublincore")]
+    check(
+        &mut out,
+        "dc_publisher",
+        &old.dc_publisher,
+        &new.dc_publisher,
+    );
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_rights", &old.dc_rights, &new.dc_rights);
+    check(&mut out, "license", &old.license, &new.license);
+    check(&mut out, "syndication", &old.syndication, &new.syndication);
+    #[cfg(feature = "georss")]
+    check(&mut out, "geo", &old.geo, &new.geo);
+    check(
+        &mut out,
+        "blog_channel",
+        &old.blog_channel,
+        &new.blog_channel,
+    );
+    out
+}
+
+fn entry_changes(old: &Entry, new: &Entry) -> Vec<&'static str> {
+    let mut out = Vec::new();
+    check(&mut out, "id", &old.id, &new.id);
+    check(&mut out, "title", &old.title, &new.title);
+    check(
+        &mut out,
+        "title_detail",
+        &old.title_detail,
+        &new.title_detail,
+    );
+    check(&mut out, "link", &old.link, &new.link);
+    check(&mut out, "links", &old.links, &new.links);
+    check(&mut out, "summary", &old.summary, &new.summary);
+    check(
+        &mut out,
+        "summary_detail",
+        &old.summary_detail,
+        &new.summary_detail,
+    );
+    check(&mut out, "content", &old.content, &new.content);
+    check(&mut out, "published", &old.published, &new.published);
+    check(&mut out, "updated", &old.updated, &new.updated);
+    check(&mut out, "created", &old.created, &new.created);
+    check(&mut out, "expired", &old.expired, &new.expired);
+    check(&mut out, "author", &old.author, &new.author);
+    check(
+        &mut out,
+        "author_detail",
+        &old.author_detail,
+        &new.author_detail,
+    );
+    check(&mut out, "authors", &old.authors, &new.authors);
+    check(
+        &mut out,
+        "contributors",
+        &old.contributors,
+        &new.contributors,
+    );
+    check(&mut out, "publisher", &old.publisher, &new.publisher);
+    check(
+        &mut out,
+        "publisher_detail",
+        &old.publisher_detail,
+        &new.publisher_detail,
+    );
+    check(&mut out, "tags", &old.tags, &new.tags);
+    check(&mut out, "enclosures", &old.enclosures, &new.enclosures);
+    check(&mut out, "comments", &old.comments, &new.comments);
+    check(&mut out, "thr_total", &old.thr_total, &new.thr_total);
+    check(
+        &mut out,
+        "slash_comments",
+        &old.slash_comments,
+        &new.slash_comments,
+    );
+    check(
+        &mut out,
+        "external_url",
+        &old.external_url,
+        &new.external_url,
+    );
+    check(
+        &mut out,
+        "banner_image",
+        &old.banner_image,
+        &new.banner_image,
+    );
+    check(&mut out, "source", &old.source, &new.source);
+    #[cfg(feature = "itunes")]
+    check(&mut out, "itunes", &old.itunes, &new.itunes);
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_creator", &old.dc_creator, &new.dc_creator);
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_date", &old.dc_date, &new.dc_date);
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_subject", &old.dc_subject, &new.dc_subject);
+    #[cfg(feature = "dublincore")]
+    check(&mut out, "dc_rights", &old.dc_rights, &new.dc_rights);
+    check(
+        &mut out,
+        "media_thumbnails",
+        &old.media_thumbnails,
+        &new.media_thumbnails,
+    );
+    check(
+        &mut out,
+        "media_content",
+        &old.media_content,
+        &new.media_content,
+    );
+    #[cfg(feature = "podcast20")]
+    check(
+        &mut out,
+        "podcast_transcripts",
+        &old.podcast_transcripts,
+        &new.podcast_transcripts,
+    );
+    #[cfg(feature = "podcast20")]
+    check(
+        &mut out,
+        "podcast_persons",
+        &old.podcast_persons,
+        &new.podcast_persons,
+    );
+    #[cfg(feature = "podcast20")]
+    check(&mut out, "podcast", &old.podcast, &new.podcast);
+    #[cfg(feature = "georss")]
+    check(&mut out, "geo", &old.geo, &new.geo);
+    check(&mut out, "license", &old.license, &new.license);
+    check(&mut out, "prism", &old.prism, &new.prism);
+    check(&mut out, "gdata", &old.gdata, &new.gdata);
+    check(&mut out, "activity", &old.activity, &new.activity);
+    check(
+        &mut out,
+        "id_synthetic",
+        &old.id_synthetic,
+        &new.id_synthetic,
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn rss(items: &str) -> ParsedFeed {
+        parse(
+            format!(r#"<rss version="2.0"><channel><title>T</title>{items}</channel></rss>"#)
+                .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_identical_feeds() {
+        let old = rss("<item><guid>1</guid><title>A</title></item>");
+        let new = rss("<item><guid>1</guid><title>A</title></item>");
+        assert!(diff(&old, &new).is_empty());
+        assert_eq!(old, new);
+    }
+
+    #[test]
+    fn test_added_removed_updated() {
+        let old = rss("<item><guid>1</guid><title>A</title></item>\
+             <item><guid>2</guid><title>B</title></item>");
+        let new = rss("<item><guid>3</guid><title>C</title></item>\
+             <item><guid>1</guid><title>A2</title></item>");
+        let changes = diff(&old, &new);
+        assert_eq!(changes.added.len(), 1);
+        assert_eq!(changes.added[0].id.as_deref(), Some("3"));
+        assert_eq!(changes.removed.len(), 1);
+        assert_eq!(changes.removed[0].id.as_deref(), Some("2"));
+        assert_eq!(changes.updated.len(), 1);
+        assert_eq!(changes.updated[0].old.title.as_deref(), Some("A"));
+        assert_eq!(changes.updated[0].new.title.as_deref(), Some("A2"));
+        assert!(changes.updated[0].fields.contains(&"title"));
+    }
+
+    #[test]
+    fn test_matches_by_link_without_id() {
+        let old = rss("<item><link>https://example.com/a</link><title>A</title></item>");
+        let new = rss("<item><link>https://example.com/a</link><title>B</title></item>");
+        let changes = diff(&old, &new);
+        assert!(changes.added.is_empty());
+        assert!(changes.removed.is_empty());
+        assert_eq!(changes.updated.len(), 1);
+    }
+
+    #[test]
+    fn test_anonymous_entries_by_equality() {
+        let old = rss("<item><description>x</description></item>");
+        let same = rss("<item><description>x</description></item>");
+        let edited = rss("<item><description>y</description></item>");
+        assert!(diff(&old, &same).is_empty());
+
+        let changes = diff(&old, &edited);
+        assert_eq!(changes.added.len(), 1);
+        assert_eq!(changes.removed.len(), 1);
+    }
+
+    #[test]
+    fn test_feed_metadata_changes() {
+        let old =
+            parse(b"<rss version='2.0'><channel><title>A</title><ttl>60</ttl></channel></rss>")
+                .unwrap();
+        let new =
+            parse(b"<rss version='2.0'><channel><title>B</title><ttl>30</ttl></channel></rss>")
+                .unwrap();
+        let changes = diff(&old, &new);
+        assert!(changes.feed_changes.contains(&"title"));
+        assert!(changes.feed_changes.contains(&"ttl"));
+        assert!(!changes.feed_changes.contains(&"link"));
+    }
+
+    #[test]
+    fn test_entry_hash_types() {
+        use std::collections::HashSet;
+        let feed = rss("<item><guid>1</guid><category>a</category><category>a</category></item>");
+        let tags: HashSet<_> = feed.entries[0].tags.iter().collect();
+        assert_eq!(tags.len(), 1);
+    }
+}
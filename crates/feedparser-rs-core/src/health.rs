@@ -0,0 +1,347 @@
+//! Feed quality scoring
+//!
+//! [`score`] rates a parsed feed from 0 to 100 and lists what cost it
+//! points: missing metadata, stale content, parse problems and markup the
+//! sanitizer had to rewrite. Feed directories can rank by the number and
+//! monitoring can alert on the [`HealthIssue`]s.
+//!
+//! Each issue carries a fixed [`penalty`](HealthIssue::penalty); the score
+//! is 100 minus the sum of penalties, floored at zero. Completeness issues
+//! scale with the share of entries affected, so one undated entry in a
+//! hundred costs far less than a feed with no dates at all.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{HealthIssue, parse, score};
+//!
+//! let feed = parse(br#"<rss version="2.0"><channel>
+//!     <title>Blog</title><link>https://example.com/</link>
+//!     <item><title>No id, no date</title></item>
+//! </channel></rss>"#).unwrap();
+//!
+//! let health = score(&feed);
+//! assert!(health.score < 100);
+//! assert!(health.issues.contains(&HealthIssue::MissingEntryIds { missing: 1, total: 1 }));
+//! ```
+
+use crate::types::ParsedFeed;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// Entries older than this many days make a feed stale
+const STALE_DAYS: i64 = 90;
+
+/// Quality score of a feed with the reasons behind it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedHealth {
+    /// 0 (unusable) to 100 (no issues found)
+    pub score: u8,
+    /// Issues found, in decreasing order of penalty
+    pub issues: Vec<HealthIssue>,
+}
+
+impl FeedHealth {
+    /// Returns true if no issues were found
+    #[must_use]
+    pub const fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// A problem lowering a feed's [`FeedHealth::score`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthIssue {
+    /// The feed has no title
+    MissingTitle,
+    /// The feed has no link to its website
+    MissingLink,
+    /// The feed has no entries
+    NoEntries,
+    /// Entries without an `id`
+    MissingEntryIds {
+        /// Entries affected
+        missing: usize,
+        /// Entries in the feed
+        total: usize,
+    },
+    /// Entries without a `published` or `updated` date
+    MissingEntryDates {
+        /// Entries affected
+        missing: usize,
+        /// Entries in the feed
+        total: usize,
+    },
+    /// Entries without an author, with no feed-level author to fall back on
+    MissingAuthors {
+        /// Entries affected
+        missing: usize,
+        /// Entries in the feed
+        total: usize,
+    },
+    /// The newest dated entry is older than 90 days
+    Stale {
+        /// Age of the newest entry in whole days
+        days: u64,
+    },
+    /// The document was malformed (`bozo`)
+    Malformed {
+        /// `bozo_exception`, if one was recorded
+        reason: Option<String>,
+    },
+    /// The sanitizer rewrote HTML fields
+    ///
+    /// Only reported when the feed was parsed with
+    /// [`ParseOptions::collect_stats`](crate::ParseOptions::collect_stats).
+    SanitizedFields {
+        /// Number of fields rewritten
+        count: usize,
+    },
+}
+
+impl HealthIssue {
+    /// Points this issue subtracts from the score
+    #[must_use]
+    pub fn penalty(&self) -> u8 {
+        match self {
+            Self::NoEntries => 40,
+            Self::Malformed { .. } => 20,
+            Self::MissingEntryIds { missing, total }
+            | Self::MissingEntryDates { missing, total } => share(15, *missing, *total),
+            Self::MissingAuthors { missing, total } => share(5, *missing, *total),
+            Self::Stale { days } => match days {
+                0..=364 => 10,
+                _ => 20,
+            },
+            Self::MissingTitle => 10,
+            Self::MissingLink => 5,
+            Self::SanitizedFields { count } => u8::try_from(*count).unwrap_or(u8::MAX).min(10),
+        }
+    }
+}
+
+impl fmt::Display for HealthIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTitle => f.write_str("feed has no title"),
+            Self::MissingLink => f.write_str("feed has no link"),
+            Self::NoEntries => f.write_str("feed has no entries"),
+            Self::MissingEntryIds { missing, total } => {
+                write!(f, "{missing} of {total} entries have no id")
+            }
+            Self::MissingEntryDates { missing, total } => {
+                write!(f, "{missing} of {total} entries have no date")
+            }
+            Self::MissingAuthors { missing, total } => {
+                write!(f, "{missing} of {total} entries have no author")
+            }
+            Self::Stale { days } => write!(f, "newest entry is {days} days old"),
+            Self::Malformed {
+                reason: Some(reason),
+            } => write!(f, "malformed feed: {reason}"),
+            Self::Malformed { reason: None } => f.write_str("malformed feed"),
+            Self::SanitizedFields { count } => write!(f, "{count} fields contained unsafe HTML"),
+        }
+    }
+}
+
+/// `max` scaled by `missing / total`, rounded up so any gap costs a point
+fn share(max: u8, missing: usize, total: usize) -> u8 {
+    if total == 0 {
+        return 0;
+    }
+    let scaled = (usize::from(max) * missing).div_ceil(total);
+    u8::try_from(scaled).unwrap_or(max).min(max)
+}
+
+/// Scores a feed as of now
+///
+/// See the [module documentation](self) for how the score is computed.
+#[must_use]
+pub fn score(feed: &ParsedFeed) -> FeedHealth {
+    score_at(feed, Utc::now())
+}
+
+/// Scores a feed as of `now`, for reproducible freshness checks
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use feedparser_rs::{HealthIssue, parse, score_at};
+///
+/// let feed = parse(br#"<rss version="2.0"><channel>
+///     <title>Blog</title><link>https://example.com/</link>
+///     <item><guid>1</guid><author>a@example.com</author>
+///         <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+/// </channel></rss>"#).unwrap();
+///
+/// let fresh = score_at(&feed, Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap());
+/// assert_eq!(fresh.score, 100);
+///
+/// let stale = score_at(&feed, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+/// assert_eq!(stale.issues, [HealthIssue::Stale { days: 366 }]);
+/// ```
+#[must_use]
+pub fn score_at(feed: &ParsedFeed, now: DateTime<Utc>) -> FeedHealth {
+    let mut issues = Vec::new();
+
+    if feed.bozo {
+        issues.push(HealthIssue::Malformed {
+            reason: feed.bozo_exception.clone(),
+        });
+    }
+    if feed.feed.title.as_deref().is_none_or(str::is_empty) {
+        issues.push(HealthIssue::MissingTitle);
+    }
+    if feed.feed.link.as_deref().is_none_or(str::is_empty) {
+        issues.push(HealthIssue::MissingLink);
+    }
+
+    let total = feed.entries.len();
+    if total == 0 {
+        issues.push(HealthIssue::NoEntries);
+    } else {
+        let count = |missing: &dyn Fn(&crate::Entry) -> bool| {
+            feed.entries.iter().filter(|e| missing(e)).count()
+        };
+
+        let missing = count(&|e| e.id.as_deref().is_none_or(str::is_empty));
+        if missing > 0 {
+            issues.push(HealthIssue::MissingEntryIds { missing, total });
+        }
+        let missing = count(&|e| e.published.or(e.updated).is_none());
+        if missing > 0 {
+            issues.push(HealthIssue::MissingEntryDates { missing, total });
+        }
+        if feed.feed.author.is_none() {
+            let missing = count(&|e| e.author.is_none() && e.authors.is_empty());
+            if missing > 0 {
+                issues.push(HealthIssue::MissingAuthors { missing, total });
+            }
+        }
+
+        let newest = feed
+            .entries
+            .iter()
+            .filter_map(|e| e.published.or(e.updated))
+            .max();
+        if let Some(newest) = newest {
+            let days = (now - newest).num_days();
+            if days > STALE_DAYS {
+                issues.push(HealthIssue::Stale {
+                    days: days.unsigned_abs(),
+                });
+            }
+        }
+    }
+
+    if let Some(count) = feed.stats.map(|s| s.sanitized_fields)
+        && count > 0
+    {
+        issues.push(HealthIssue::SanitizedFields { count });
+    }
+
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.penalty()));
+    let penalty: u32 = issues.iter().map(|issue| u32::from(issue.penalty())).sum();
+    FeedHealth {
+        score: u8::try_from(100u32.saturating_sub(penalty)).unwrap_or(0),
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseOptions, parse, parse_with_options};
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_complete_feed_scores_full() {
+        let feed = parse(
+            br#"<rss version="2.0"><channel><title>T</title><link>https://example.com/</link>
+            <managingEditor>ed@example.com</managingEditor>
+            <item><guid>1</guid><pubDate>Wed, 10 Jan 2024 00:00:00 GMT</pubDate></item>
+            </channel></rss>"#,
+        )
+        .unwrap();
+        let health = score_at(&feed, now());
+        assert!(health.is_healthy(), "{:?}", health.issues);
+        assert_eq!(health.score, 100);
+    }
+
+    #[test]
+    fn test_partial_completeness() {
+        let feed = parse(
+            br#"<rss version="2.0"><channel><title>T</title><link>https://example.com/</link>
+            <item><guid>1</guid><author>a@example.com</author>
+                <pubDate>Wed, 10 Jan 2024 00:00:00 GMT</pubDate></item>
+            <item><guid>2</guid><author>a@example.com</author></item>
+            <item><author>a@example.com</author></item>
+            <item><guid>4</guid><author>a@example.com</author></item>
+            </channel></rss>"#,
+        )
+        .unwrap();
+        let health = score_at(&feed, now());
+        assert_eq!(
+            health.issues,
+            [
+                HealthIssue::MissingEntryDates {
+                    missing: 3,
+                    total: 4
+                },
+                HealthIssue::MissingEntryIds {
+                    missing: 1,
+                    total: 4
+                },
+            ]
+        );
+        // ceil(15 * 3 / 4) + ceil(15 / 4)
+        assert_eq!(health.score, 100 - 12 - 4);
+    }
+
+    #[test]
+    fn test_malformed_and_empty() {
+        let feed = parse(b"<rss version=\"2.0\"><channel><title>T</channel>").unwrap();
+        let health = score_at(&feed, now());
+        assert!(matches!(health.issues[0], HealthIssue::NoEntries));
+        assert!(matches!(health.issues[1], HealthIssue::Malformed { .. }));
+        assert!(health.issues.contains(&HealthIssue::MissingLink));
+        assert!(health.issues.contains(&HealthIssue::MissingTitle));
+        assert_eq!(health.score, 100 - 40 - 20 - 10 - 5);
+    }
+
+    #[test]
+    fn test_sanitized_fields_need_stats() {
+        let xml = br#"<rss version="2.0"><channel><title>T</title><link>https://example.com/</link>
+            <item><guid>1</guid><author>a@example.com</author>
+            <pubDate>Wed, 10 Jan 2024 00:00:00 GMT</pubDate>
+            <description>&lt;script&gt;x()&lt;/script&gt;Hi</description></item>
+            </channel></rss>"#;
+        assert!(score_at(&parse(xml).unwrap(), now()).is_healthy());
+
+        let options = ParseOptions {
+            collect_stats: true,
+            ..ParseOptions::default()
+        };
+        let feed = parse_with_options(xml, &options).unwrap();
+        assert_eq!(
+            score_at(&feed, now()).issues,
+            [HealthIssue::SanitizedFields { count: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_issue_display() {
+        let issue = HealthIssue::MissingEntryIds {
+            missing: 2,
+            total: 5,
+        };
+        assert_eq!(issue.to_string(), "2 of 5 entries have no id");
+        assert_eq!(HealthIssue::Stale { days: 120 }.penalty(), 10);
+    }
+}
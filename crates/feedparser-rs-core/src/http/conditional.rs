@@ -0,0 +1,293 @@
+//! Conditional request headers
+//!
+//! Servers only answer `304 Not Modified` when `If-None-Match` and
+//! `If-Modified-Since` are well formed. Feeds and caches often store them
+//! sloppily: unquoted `ETag`s, or dates in whatever format the previous
+//! parse produced. These helpers turn stored values back into valid headers.
+//!
+//! [`FeedCacheState`] keeps the validators of the last fetch so
+//! [`FeedHttpClient::check_modified`](super::FeedHttpClient::check_modified)
+//! can tell whether a feed changed without downloading it.
+
+use crate::types::ParsedFeed;
+use crate::util::date::parse_date;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Validators from a previous fetch of a feed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeedCacheState {
+    /// `ETag` response header
+    pub etag: Option<String>,
+    /// `Last-Modified` response header
+    pub last_modified: Option<String>,
+    /// `Content-Length` response header
+    pub content_length: Option<u64>,
+}
+
+/// Outcome of [`FeedCacheState::compare`] and
+/// [`FeedHttpClient::check_modified`](super::FeedHttpClient::check_modified)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeStatus {
+    /// The server confirmed the feed is unchanged; skip the download
+    Unchanged,
+    /// The validators differ; fetch the feed
+    Changed,
+    /// No validator could be compared; fetch the feed to find out
+    Unknown,
+}
+
+impl FeedCacheState {
+    /// Validators of a feed returned by
+    /// [`FeedHttpClient::fetch_feed`](super::FeedHttpClient::fetch_feed)
+    #[must_use]
+    pub fn from_feed(feed: &ParsedFeed) -> Self {
+        Self {
+            etag: feed.etag.clone(),
+            last_modified: feed.modified.clone(),
+            content_length: feed
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get("content-length"))
+                .and_then(|length| length.trim().parse().ok()),
+        }
+    }
+
+    /// Compares these validators with those of a new response
+    ///
+    /// `ETag` decides when both sides have one, compared weakly (`W/` is
+    /// ignored). Otherwise `Last-Modified` decides, compared as dates when
+    /// both parse. `Content-Length` alone can only show a change: equal
+    /// lengths give [`ChangeStatus::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::http::{ChangeStatus, FeedCacheState};
+    ///
+    /// let state = FeedCacheState {
+    ///     etag: Some("\"v1\"".to_string()),
+    ///     last_modified: Some("Sun, 07 Jan 2024 08:09:10 GMT".to_string()),
+    ///     content_length: Some(512),
+    /// };
+    /// assert_eq!(state.compare(Some("W/\"v1\""), None, None), ChangeStatus::Unchanged);
+    /// assert_eq!(state.compare(Some("\"v2\""), None, None), ChangeStatus::Changed);
+    /// assert_eq!(
+    ///     state.compare(None, Some("Sun, 07 Jan 2024 08:09:10 +0000"), None),
+    ///     ChangeStatus::Unchanged
+    /// );
+    /// assert_eq!(state.compare(None, None, Some(512)), ChangeStatus::Unknown);
+    /// assert_eq!(state.compare(None, None, Some(640)), ChangeStatus::Changed);
+    /// ```
+    #[must_use]
+    pub fn compare(
+        &self,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+        content_length: Option<u64>,
+    ) -> ChangeStatus {
+        let opaque =
+            |etag: &str| normalize_etag(etag).map(|tag| tag.trim_start_matches("W/").to_string());
+        if let (Some(old), Some(new)) =
+            (self.etag.as_deref().and_then(opaque), etag.and_then(opaque))
+        {
+            return Self::status(old == new);
+        }
+
+        if let (Some(old), Some(new)) = (self.last_modified.as_deref(), last_modified) {
+            return match (parse_http_date(old), parse_http_date(new)) {
+                (Some(old), Some(new)) => Self::status(old == new),
+                _ => Self::status(old.trim() == new.trim()),
+            };
+        }
+
+        match (self.content_length, content_length) {
+            (Some(old), Some(new)) if old != new => ChangeStatus::Changed,
+            _ => ChangeStatus::Unknown,
+        }
+    }
+
+    const fn status(unchanged: bool) -> ChangeStatus {
+        if unchanged {
+            ChangeStatus::Unchanged
+        } else {
+            ChangeStatus::Changed
+        }
+    }
+}
+
+/// Normalizes an `ETag` for use in `If-None-Match`
+///
+/// Strong and weak (`W/"..."`) validators are kept as they are; bare values
+/// are quoted. Returns `None` for a blank value.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::http::normalize_etag;
+///
+/// assert_eq!(normalize_etag("\"abc\"").as_deref(), Some("\"abc\""));
+/// assert_eq!(normalize_etag("W/\"abc\"").as_deref(), Some("W/\"abc\""));
+/// assert_eq!(normalize_etag("abc").as_deref(), Some("\"abc\""));
+/// assert_eq!(normalize_etag("W/abc").as_deref(), Some("W/\"abc\""));
+/// assert_eq!(normalize_etag("  "), None);
+/// ```
+#[must_use]
+pub fn normalize_etag(etag: &str) -> Option<String> {
+    let etag = etag.trim();
+    let (weak, opaque) = etag
+        .strip_prefix("W/")
+        .map_or((false, etag), |rest| (true, rest.trim_start()));
+    let opaque = opaque.trim_matches('"');
+    if opaque.is_empty() {
+        return None;
+    }
+    // `"` is not allowed inside an entity tag
+    let opaque = opaque.replace('"', "");
+    Some(if weak {
+        format!("W/\"{opaque}\"")
+    } else {
+        format!("\"{opaque}\"")
+    })
+}
+
+/// Formats a timestamp as an HTTP date (RFC 9110 IMF-fixdate)
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use feedparser_rs::http::format_http_date;
+///
+/// let date = Utc.with_ymd_and_hms(2024, 1, 7, 8, 9, 10).unwrap();
+/// assert_eq!(format_http_date(date), "Sun, 07 Jan 2024 08:09:10 GMT");
+/// ```
+#[must_use]
+pub fn format_http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Obsolete HTTP date formats servers must still accept (RFC 9110 §5.6.7)
+const OBSOLETE_HTTP_DATE_FORMATS: &[&str] = &[
+    "%A, %d-%b-%y %H:%M:%S GMT", // RFC 850: Sunday, 07-Jan-24 08:09:10 GMT
+    "%a %b %e %H:%M:%S %Y",      // asctime: Sun Jan  7 08:09:10 2024
+];
+
+/// Parses an HTTP date header such as `Last-Modified`
+///
+/// Accepts the obsolete RFC 850 and asctime forms as well as IMF-fixdate,
+/// then falls back to the tolerant feed date parser.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::http::{format_http_date, parse_http_date};
+///
+/// let date = parse_http_date("Sun Jan  7 08:09:10 2024").unwrap();
+/// assert_eq!(format_http_date(date), "Sun, 07 Jan 2024 08:09:10 GMT");
+/// ```
+#[must_use]
+pub fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    OBSOLETE_HTTP_DATE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .map(|date| date.and_utc())
+        .or_else(|| parse_date(value))
+}
+
+/// Value for `If-Modified-Since` from a stored `Last-Modified`
+///
+/// Dates that parse are re-emitted as IMF-fixdate; anything else is passed
+/// through unchanged, since the server may still recognize its own format.
+pub fn if_modified_since(modified: &str) -> String {
+    parse_http_date(modified).map_or_else(|| modified.trim().to_string(), format_http_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_etag_strips_stray_quotes() {
+        assert_eq!(normalize_etag("\"a\"b\"").as_deref(), Some("\"ab\""));
+        assert_eq!(normalize_etag("W/ \"x\"").as_deref(), Some("W/\"x\""));
+        assert_eq!(normalize_etag("\"\""), None);
+    }
+
+    #[test]
+    fn test_if_modified_since() {
+        assert_eq!(
+            if_modified_since("2024-01-07T08:09:10Z"),
+            "Sun, 07 Jan 2024 08:09:10 GMT"
+        );
+        assert_eq!(
+            if_modified_since("Sun, 07 Jan 2024 10:09:10 +0200"),
+            "Sun, 07 Jan 2024 08:09:10 GMT"
+        );
+        assert_eq!(if_modified_since(" whenever "), "whenever");
+    }
+
+    #[test]
+    fn test_cache_state_from_feed() {
+        let feed = ParsedFeed {
+            etag: Some("\"abc\"".to_string()),
+            modified: Some("Sun, 07 Jan 2024 08:09:10 GMT".to_string()),
+            headers: Some([("content-length".to_string(), "2048".to_string())].into()),
+            ..Default::default()
+        };
+        let state = FeedCacheState::from_feed(&feed);
+        assert_eq!(state.etag.as_deref(), Some("\"abc\""));
+        assert_eq!(state.content_length, Some(2048));
+        assert_eq!(
+            FeedCacheState::from_feed(&ParsedFeed::default()),
+            FeedCacheState::default()
+        );
+    }
+
+    #[test]
+    fn test_compare_precedence() {
+        let state = FeedCacheState {
+            etag: Some("abc".to_string()),
+            last_modified: Some("Sun, 07 Jan 2024 08:09:10 GMT".to_string()),
+            content_length: Some(10),
+        };
+        // ETag wins over a changed date and length
+        assert_eq!(
+            state.compare(
+                Some("\"abc\""),
+                Some("Mon, 08 Jan 2024 00:00:00 GMT"),
+                Some(11)
+            ),
+            ChangeStatus::Unchanged
+        );
+        // Without an ETag in the response the date decides
+        assert_eq!(
+            state.compare(None, Some("Mon, 08 Jan 2024 00:00:00 GMT"), Some(10)),
+            ChangeStatus::Changed
+        );
+        // Unparseable dates compare as strings
+        let state = FeedCacheState {
+            last_modified: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            state.compare(None, Some(" yesterday"), None),
+            ChangeStatus::Unchanged
+        );
+        assert_eq!(state.compare(None, None, None), ChangeStatus::Unknown);
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        for value in [
+            "Sun, 07 Jan 2024 08:09:10 GMT",
+            "Sunday, 07-Jan-24 08:09:10 GMT",
+            "Sun Jan  7 08:09:10 2024",
+        ] {
+            let date = parse_http_date(value).unwrap();
+            assert_eq!(format_http_date(date), "Sun, 07 Jan 2024 08:09:10 GMT");
+        }
+        assert_eq!(parse_http_date("not a date"), None);
+    }
+}
@@ -2,9 +2,11 @@
 ///
 /// This module provides HTTP fetching capabilities with support for:
 /// - Conditional GET (`ETag` and `Last-Modified` headers)
+/// - `HEAD`-based change checks that skip the download of unchanged feeds
 /// - Automatic decompression (gzip, deflate, brotli)
-/// - Redirect following
+/// - Redirect following, with a configurable limit and host policy
 /// - Custom User-Agent and headers
+/// - Download progress reporting
 ///
 /// # Examples
 ///
@@ -24,11 +26,18 @@
 /// }
 /// ```
 mod client;
+/// Helpers for conditional GET headers
+pub mod conditional;
+mod redirect;
 mod response;
 
 /// URL validation module for SSRF protection
 pub mod validation;
 
-pub use client::FeedHttpClient;
+pub use client::{DnsResolver, FeedHttpClient, ProgressCallback};
+pub use conditional::{
+    ChangeStatus, FeedCacheState, format_http_date, normalize_etag, parse_http_date,
+};
+pub use redirect::RedirectPolicy;
 pub use response::FeedHttpResponse;
 pub use validation::validate_url;
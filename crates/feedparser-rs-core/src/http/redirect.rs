@@ -0,0 +1,177 @@
+//! Redirect handling for feed requests
+
+use super::validation::validate_url;
+use url::Url;
+
+/// Which redirects [`FeedHttpClient`](super::FeedHttpClient) follows
+///
+/// Redirects to private addresses, localhost and non-HTTP schemes are
+/// refused whatever the policy, since they would bypass the SSRF checks
+/// applied to the requested URL.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::http::{FeedHttpClient, RedirectPolicy};
+///
+/// let client = FeedHttpClient::new()
+///     .unwrap()
+///     .with_redirect_policy(RedirectPolicy {
+///         max_redirects: 3,
+///         same_host_only: true,
+///         ..RedirectPolicy::default()
+///     })
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedirectPolicy {
+    /// Maximum number of redirects to follow; `0` follows none
+    ///
+    /// Default: `10`
+    pub max_redirects: usize,
+    /// Refuse redirects to a host other than the one requested
+    ///
+    /// Default: `false`
+    pub same_host_only: bool,
+    /// Refuse redirects from `https` to plain `http`
+    ///
+    /// Default: `false`
+    pub deny_https_downgrade: bool,
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            max_redirects: 10,
+            same_host_only: false,
+            deny_https_downgrade: false,
+        }
+    }
+}
+
+impl RedirectPolicy {
+    /// Strict policy for security-sensitive fetchers
+    ///
+    /// At most 5 redirects, same host only, no downgrade to plain HTTP.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            max_redirects: 5,
+            same_host_only: true,
+            deny_https_downgrade: true,
+        }
+    }
+
+    /// Decides whether to follow a redirect to `next`
+    ///
+    /// `previous` holds the requested URL followed by the redirects taken
+    /// so far.
+    pub(crate) fn check(&self, next: &Url, previous: &[Url]) -> Result<(), String> {
+        // The first URL is the request itself, not a redirect
+        if previous.len() > self.max_redirects {
+            return Err(format!("Too many redirects (limit {})", self.max_redirects));
+        }
+        validate_url(next.as_str()).map_err(|e| format!("Redirect refused: {e}"))?;
+        if self.same_host_only
+            && let Some(requested) = previous.first()
+            && requested.host_str() != next.host_str()
+        {
+            return Err(format!("Redirect to another host refused: {next}"));
+        }
+        if self.deny_https_downgrade
+            && next.scheme() == "http"
+            && previous.last().is_some_and(|prev| prev.scheme() == "https")
+        {
+            return Err(format!("Redirect from HTTPS to HTTP refused: {next}"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(urls: &[&str]) -> Vec<Url> {
+        urls.iter().map(|url| Url::parse(url).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_redirect_limit() {
+        let policy = RedirectPolicy {
+            max_redirects: 1,
+            ..RedirectPolicy::default()
+        };
+        let next = Url::parse("https://example.com/c").unwrap();
+        assert!(
+            policy
+                .check(&next, &urls(&["https://example.com/a"]))
+                .is_ok()
+        );
+        let err = policy
+            .check(
+                &next,
+                &urls(&["https://example.com/a", "https://example.com/b"]),
+            )
+            .unwrap_err();
+        assert!(err.contains("Too many redirects"));
+
+        let none = RedirectPolicy {
+            max_redirects: 0,
+            ..RedirectPolicy::default()
+        };
+        assert!(
+            none.check(&next, &urls(&["https://example.com/a"]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_redirect_to_private_host_refused() {
+        let previous = urls(&["https://example.com/feed"]);
+        for target in [
+            "http://127.0.0.1/",
+            "http://localhost/",
+            "http://169.254.169.254/",
+        ] {
+            let next = Url::parse(target).unwrap();
+            let err = RedirectPolicy::default()
+                .check(&next, &previous)
+                .unwrap_err();
+            assert!(err.starts_with("Redirect refused"), "{target}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_same_host_only() {
+        let previous = urls(&["https://example.com/feed", "https://example.com/feed/"]);
+        let same = Url::parse("https://example.com/rss").unwrap();
+        let other = Url::parse("https://cdn.example.net/rss").unwrap();
+
+        let policy = RedirectPolicy::strict();
+        assert!(policy.check(&same, &previous).is_ok());
+        assert!(policy.check(&other, &previous).is_err());
+        assert!(RedirectPolicy::default().check(&other, &previous).is_ok());
+    }
+
+    #[test]
+    fn test_https_downgrade() {
+        let previous = urls(&["https://example.com/feed"]);
+        let downgrade = Url::parse("http://example.com/feed").unwrap();
+
+        assert!(
+            RedirectPolicy::strict()
+                .check(&downgrade, &previous)
+                .is_err()
+        );
+        assert!(
+            RedirectPolicy::default()
+                .check(&downgrade, &previous)
+                .is_ok()
+        );
+
+        let upgrade = Url::parse("https://example.com/feed").unwrap();
+        let plain = urls(&["http://example.com/feed"]);
+        assert!(RedirectPolicy::strict().check(&upgrade, &plain).is_ok());
+    }
+}
@@ -1,3 +1,7 @@
+use super::conditional::parse_http_date;
+use crate::util::base_url::resolve_url;
+use crate::util::language::normalize_language_tag;
+use chrono::{DateTime, TimeDelta, Utc};
 use std::collections::HashMap;
 
 /// HTTP response from feed fetch
@@ -15,13 +19,108 @@ pub struct FeedHttpResponse {
     pub etag: Option<String>,
     /// Last-Modified header value
     pub last_modified: Option<String>,
+    /// `Last-Modified` header parsed as a timestamp
+    pub last_modified_parsed: Option<DateTime<Utc>>,
+    /// `Date` header parsed as a timestamp
+    pub date_parsed: Option<DateTime<Utc>>,
     /// Content-Type header value
     pub content_type: Option<String>,
     /// Encoding extracted from Content-Type
     pub encoding: Option<String>,
+    /// URL the feed moved to, when it was reached only through permanent
+    /// (301 or 308) redirects
+    ///
+    /// Subscribers should update their stored URL to this one.
+    pub permanent_redirect: Option<String>,
 }
 
 impl FeedHttpResponse {
+    /// URL that relative references in the body resolve against
+    ///
+    /// This is the `Content-Location` header, itself resolved against the
+    /// final URL, or the final URL when the header is absent.
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        self.headers
+            .get("content-location")
+            .map(|location| location.trim())
+            .filter(|location| !location.is_empty())
+            .map_or_else(
+                || self.url.clone(),
+                |location| resolve_url(location, Some(&self.url)),
+            )
+    }
+
+    /// Primary language of the body, from the `Content-Language` header
+    ///
+    /// The header may list several languages; only the first is returned,
+    /// normalized like feed languages.
+    #[must_use]
+    pub fn content_language(&self) -> Option<String> {
+        let language = self.headers.get("content-language")?.split(',').next()?;
+        normalize_language_tag(language)
+    }
+
+    /// `Last-Modified` header as a timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::http::{FeedHttpResponse, format_http_date};
+    ///
+    /// let response = FeedHttpResponse {
+    ///     status: 200,
+    ///     url: "https://example.com/feed.xml".to_string(),
+    ///     headers: Default::default(),
+    ///     body: Vec::new(),
+    ///     etag: None,
+    ///     last_modified: Some("Sun, 07 Jan 2024 08:09:10 GMT".to_string()),
+    ///     last_modified_parsed: None,
+    ///     date_parsed: None,
+    ///     content_type: None,
+    ///     encoding: None,
+    ///     permanent_redirect: None,
+    /// };
+    /// let modified = response.last_modified_date().unwrap();
+    /// assert_eq!(format_http_date(modified), "Sun, 07 Jan 2024 08:09:10 GMT");
+    /// ```
+    #[must_use]
+    pub fn last_modified_date(&self) -> Option<DateTime<Utc>> {
+        self.last_modified.as_deref().and_then(parse_http_date)
+    }
+
+    /// How far the server clock is ahead of `now`, from the `Date` header
+    ///
+    /// Negative when the server is behind. Useful for judging
+    /// `Last-Modified` and feed dates against the local clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    /// use feedparser_rs::http::FeedHttpResponse;
+    ///
+    /// let response = FeedHttpResponse {
+    ///     status: 200,
+    ///     url: "https://example.com/feed.xml".to_string(),
+    ///     headers: Default::default(),
+    ///     body: Vec::new(),
+    ///     etag: None,
+    ///     last_modified: None,
+    ///     last_modified_parsed: None,
+    ///     date_parsed: Some(Utc.with_ymd_and_hms(2024, 1, 7, 8, 0, 30).unwrap()),
+    ///     content_type: None,
+    ///     encoding: None,
+    ///     permanent_redirect: None,
+    /// };
+    /// let now = Utc.with_ymd_and_hms(2024, 1, 7, 8, 0, 0).unwrap();
+    /// assert_eq!(response.clock_skew(now), Some(TimeDelta::seconds(30)));
+    /// ```
+    #[must_use]
+    pub fn clock_skew(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        self.date_parsed.map(|date| date - now)
+    }
+
     /// Extract charset from Content-Type header
     ///
     /// Parses header like "text/xml; charset=utf-8" and returns "utf-8"
@@ -38,6 +137,46 @@ impl FeedHttpResponse {
 mod tests {
     use super::*;
 
+    fn response_with_headers(headers: &[(&str, &str)]) -> FeedHttpResponse {
+        FeedHttpResponse {
+            status: 200,
+            url: "https://example.com/feeds/main.xml".to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+                .collect(),
+            body: Vec::new(),
+            etag: None,
+            last_modified: None,
+            last_modified_parsed: None,
+            date_parsed: None,
+            content_type: None,
+            encoding: None,
+            permanent_redirect: None,
+        }
+    }
+
+    #[test]
+    fn test_base_url_from_content_location() {
+        let response = response_with_headers(&[("content-location", "archive/feed.xml")]);
+        assert_eq!(
+            response.base_url(),
+            "https://example.com/feeds/archive/feed.xml"
+        );
+
+        let response = response_with_headers(&[]);
+        assert_eq!(response.base_url(), "https://example.com/feeds/main.xml");
+    }
+
+    #[test]
+    fn test_content_language() {
+        let response = response_with_headers(&[("content-language", "en_us, fr")]);
+        assert_eq!(response.content_language().as_deref(), Some("en-US"));
+
+        let response = response_with_headers(&[("content-language", " ")]);
+        assert_eq!(response.content_language(), None);
+    }
+
     #[test]
     fn test_extract_charset_simple() {
         let ct = "text/xml; charset=utf-8";
@@ -196,7 +196,8 @@ fn validate_ipv6(ip: Ipv6Addr) -> Result<()> {
 
 /// Validates a domain name to prevent SSRF
 fn validate_domain(domain: &str) -> Result<()> {
-    let domain_lower = domain.to_lowercase();
+    // A trailing dot names the same host in absolute form
+    let domain_lower = domain.trim_end_matches('.').to_lowercase();
 
     // Block localhost variations
     if LOCALHOST_VARIANTS.contains(&domain_lower.as_str()) {
@@ -334,6 +335,14 @@ mod tests {
         assert!(validate_url("http://localhost/").is_err());
     }
 
+    #[test]
+    fn test_reject_localhost_spellings() {
+        assert!(validate_url("http://localhost./").is_err());
+        assert!(validate_url("http://ｌｏｃａｌｈｏｓｔ/").is_err());
+        assert!(validate_url("http://myserver.local./").is_err());
+        assert!(validate_url("http://bücher.example/").is_ok());
+    }
+
     #[test]
     fn test_reject_local_tld() {
         assert!(validate_url("http://myserver.local/").is_err());
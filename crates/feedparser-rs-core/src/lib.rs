@@ -52,6 +52,15 @@
 //! - **Syndication** (`sy:`) - Update frequency hints
 //! - **`GeoRSS`** (`georss:`) - Geographic coordinates
 //! - **Creative Commons** (`cc:`, `creativeCommons:`) - License information
+//! - **PRISM** (`prism:`) - Journal name, volume, issue, pages and DOI
+//! - **`GData`** (`gd:`) - Event times and places, ratings, extended properties
+//! - **Activity Streams** (`activity:`) - Verb and object of social feed entries
+//! - **blogChannel** (`blogChannel:`) - Blogroll and subscription list links
+//!
+//! iTunes, Podcast 2.0, Media RSS, `GeoRSS` and Dublin Core support sit behind
+//! the default `itunes`, `podcast20`, `mediarss`, `georss` and `dublincore`
+//! features; disabling one skips those elements and removes the fields only
+//! they fill.
 //!
 //! ## Type-Safe URL and MIME Handling
 //!
@@ -91,11 +100,12 @@
 //! ```
 //! use feedparser_rs::parse;
 //!
-//! // XML with undefined entity - triggers bozo
+//! // A character reference XML does not allow - triggers bozo
 //! let xml_with_entity = b"<rss version='2.0'><channel><title>Test &#xFFFF;</title></channel></rss>";
 //!
 //! let feed = parse(xml_with_entity).unwrap();
 //! // Parser handles invalid characters gracefully
+//! assert!(feed.bozo);
 //! assert!(feed.feed.title.is_some());
 //! ```
 //!
@@ -109,11 +119,9 @@
 //! use feedparser_rs::{parse_with_limits, ParserLimits};
 //!
 //! // Customize limits for untrusted input
-//! let limits = ParserLimits {
-//!     max_entries: 100,
-//!     max_text_length: 50_000,
-//!     ..Default::default()
-//! };
+//! let limits = ParserLimits::strict()
+//!     .with_max_entries(100)
+//!     .with_max_text_length(50_000);
 //!
 //! let xml = b"<rss version='2.0'><channel><title>Safe</title></channel></rss>";
 //! let feed = parse_with_limits(xml, limits).unwrap();
@@ -143,6 +151,42 @@
 //! # Ok::<(), feedparser_rs::FeedError>(())
 //! ```
 //!
+//! ## Serialization
+//!
+//! With the `serde` feature, [`ParsedFeed`] and every type it contains
+//! implement `serde::Serialize` and `serde::Deserialize`, so parse results
+//! can be cached as JSON or stored without a conversion layer. Dates use
+//! RFC 3339 and [`FeedVersion`] serializes as its [`FeedVersion::as_str`] form.
+//!
+//! The `cache` feature builds on this with `ParsedFeed::to_bytes()` and
+//! `ParsedFeed::from_bytes()`, a compact version-tagged binary encoding for
+//! persisting parse results.
+//!
+//! ## Dates
+//!
+//! Dates are `chrono::DateTime<Utc>`. With the `time` feature, every date
+//! field of `FeedMeta` and `Entry` also has a `time::OffsetDateTime`
+//! accessor (`published_time()`, `updated_time()`, ...), the
+//! `ToOffsetDateTime` trait converts any other date, and
+//! `util::date::from_offset_date_time` converts back, for projects that
+//! standardize on the `time` crate. The feature adds accessors rather than
+//! swapping the field types, so it stays additive and can be enabled
+//! alongside crates that expect chrono.
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature, parsing emits [`tracing`](https://docs.rs/tracing)
+//! spans so a subscriber can show where time goes on slow feeds:
+//!
+//! - `feedparser.parse` - the whole parse, with `bytes`, `version` and `entries`
+//! - `feedparser.detect` - format detection, with `bytes`, `version` and `confidence`
+//! - `feedparser.parse_rss`, `feedparser.parse_atom`, `feedparser.parse_rss10`,
+//!   `feedparser.parse_json` - the format parser, with `bytes` and `entries`
+//! - `feedparser.channel` / `feedparser.item` (RSS) and `feedparser.feed` /
+//!   `feedparser.entry` (Atom) - per-section parsing, at `DEBUG` and `TRACE`
+//! - `feedparser.sanitize` - HTML sanitization, with `entries`
+//! - `feedparser.http.get` - HTTP fetches, with `url`, `status` and `bytes`
+//!
 //! ## Core Types
 //!
 //! - [`ParsedFeed`] - Complete parsed feed with metadata and entries
@@ -157,19 +201,45 @@
 //! - [`namespace`] - Handlers for namespace extensions (iTunes, Podcast 2.0, etc.)
 //! - [`util`] - Helper functions for dates, HTML sanitization, encoding
 //! - [`compat`] - Python feedparser API compatibility layer
+//! - [`pipeline`] - Batch fetch, clean-up and merge of many feeds
 //! - [`http`] - HTTP client for fetching feeds (requires `http` feature)
+//! - `crawler` - Scheduled polling of many feeds (requires `crawler` feature)
+
+/// Records fields on the current span when the `tracing` feature is enabled
+///
+/// Expands to nothing otherwise, so call sites need no `cfg` of their own.
+macro_rules! record_span {
+    ($($field:ident = $value:expr),+ $(,)?) => {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            $(span.record(stringify!($field), $value);)+
+        }
+    };
+}
 
+#[cfg(feature = "cache")]
+mod cache;
 /// Compatibility utilities for Python feedparser API
 pub mod compat;
+#[cfg(feature = "crawler")]
+pub mod crawler;
+pub mod diff;
 mod error;
+pub mod health;
 #[cfg(feature = "http")]
 /// HTTP client module for fetching feeds from URLs
 pub mod http;
 mod limits;
+pub mod lookup;
+pub mod merge;
 /// Namespace handlers for extended feed formats
 pub mod namespace;
+pub mod opml;
 mod options;
 mod parser;
+pub mod pipeline;
+pub mod tracker;
 
 /// Type definitions for feed data structures
 ///
@@ -182,20 +252,44 @@ pub mod types;
 /// This module provides helper functions for date parsing, HTML sanitization,
 /// and encoding detection that are useful for feed processing.
 pub mod util;
+pub mod writer;
 
+pub use diff::{EntryChange, FeedDiff, diff};
 pub use error::{FeedError, Result};
+pub use health::{FeedHealth, HealthIssue, score, score_at};
 pub use limits::{LimitError, ParserLimits};
-pub use options::ParseOptions;
-pub use parser::{detect_format, parse, parse_with_limits};
+pub use lookup::{FieldValue, Node};
+pub use merge::{MergeOptions, merge_feeds};
+pub use options::{FutureDatePolicy, ParseOptions};
+pub use parser::{
+    FormatDetection, detect_format, detect_format_detailed, parse, parse_with_base_url,
+    parse_with_limits, parse_with_options,
+};
+pub use tracker::FeedTracker;
+pub use types::{
+    AuthorSource, Content, Email, Enclosure, Entry, FeedFamily, FeedMeta, FeedVersion, Generator,
+    Image, ImageSource, LicenseInfo, LimitedCollectionExt, Link, MediaContent, MediaThumbnail,
+    MimeType, ParseFeedVersionError, ParseStats, ParsedFeed, Person, Source, Tag, TextConstruct,
+    TextType, Url, parse_duration, parse_explicit, podcast_guid_for,
+};
+#[cfg(feature = "itunes")]
+pub use types::{InvalidCategory, ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner};
+#[cfg(feature = "podcast20")]
 pub use types::{
-    Content, Email, Enclosure, Entry, FeedMeta, FeedVersion, Generator, Image, ItunesCategory,
-    ItunesEntryMeta, ItunesFeedMeta, ItunesOwner, LimitedCollectionExt, Link, MediaContent,
-    MediaThumbnail, MimeType, ParsedFeed, Person, PodcastChapters, PodcastEntryMeta,
-    PodcastFunding, PodcastMeta, PodcastPerson, PodcastSoundbite, PodcastTranscript, PodcastValue,
-    PodcastValueRecipient, Source, Tag, TextConstruct, TextType, Url, parse_duration,
-    parse_explicit,
+    PodcastAlternateEnclosure, PodcastChapters, PodcastEntryMeta, PodcastFunding, PodcastMeta,
+    PodcastPerson, PodcastSoundbite, PodcastTranscript, PodcastValue, PodcastValueRecipient,
 };
+#[cfg(feature = "time")]
+pub use util::date::ToOffsetDateTime;
+pub use util::language::LanguageTag;
+pub use util::mime::MediaKind;
+pub use writer::{convert, write_feed};
 
+pub use namespace::activity::{ActivityMeta, ActivityObject};
+pub use namespace::blog_channel::BlogChannelMeta;
+pub use namespace::feed_history::FeedHistory;
+pub use namespace::gdata::GDataMeta;
+pub use namespace::prism::PrismMeta;
 pub use namespace::syndication::{SyndicationMeta, UpdatePeriod};
 
 #[cfg(feature = "http")]
@@ -205,6 +299,8 @@ pub use http::{FeedHttpClient, FeedHttpResponse};
 ///
 /// Fetches the feed from the given URL and parses it. Supports conditional GET
 /// using `ETag` and `Last-Modified` headers for bandwidth-efficient caching.
+/// Requests go through [`FeedHttpClient::shared`], so repeated calls reuse
+/// connections; configure it once with [`FeedHttpClient::init_shared`].
 ///
 /// # Arguments
 ///
@@ -260,57 +356,7 @@ pub fn parse_url(
     modified: Option<&str>,
     user_agent: Option<&str>,
 ) -> Result<ParsedFeed> {
-    use http::FeedHttpClient;
-
-    // Create HTTP client
-    let mut client = FeedHttpClient::new()?;
-    if let Some(agent) = user_agent {
-        client = client.with_user_agent(agent.to_string());
-    }
-
-    // Fetch feed
-    let response = client.get(url, etag, modified, None)?;
-
-    // Handle 304 Not Modified
-    if response.status == 304 {
-        return Ok(ParsedFeed {
-            status: Some(304),
-            href: Some(response.url),
-            etag: etag.map(String::from),
-            modified: modified.map(String::from),
-            #[cfg(feature = "http")]
-            headers: Some(response.headers),
-            encoding: String::from("utf-8"),
-            ..Default::default()
-        });
-    }
-
-    // Handle error status codes
-    if response.status >= 400 {
-        return Err(FeedError::Http {
-            message: format!("HTTP {} for URL: {}", response.status, response.url),
-        });
-    }
-
-    // Parse feed from response body
-    let mut feed = parse(&response.body)?;
-
-    // Add HTTP metadata
-    feed.status = Some(response.status);
-    feed.href = Some(response.url);
-    feed.etag = response.etag;
-    feed.modified = response.last_modified;
-    #[cfg(feature = "http")]
-    {
-        feed.headers = Some(response.headers);
-    }
-
-    // Override encoding if HTTP header specifies
-    if let Some(http_encoding) = response.encoding {
-        feed.encoding = http_encoding;
-    }
-
-    Ok(feed)
+    parse_url_with_limits(url, etag, modified, user_agent, ParserLimits::default())
 }
 
 /// Parse feed from URL with custom parser limits
@@ -345,48 +391,57 @@ pub fn parse_url_with_limits(
 ) -> Result<ParsedFeed> {
     use http::FeedHttpClient;
 
-    let mut client = FeedHttpClient::new()?;
-    if let Some(agent) = user_agent {
-        client = client.with_user_agent(agent.to_string());
-    }
-
-    let response = client.get(url, etag, modified, None)?;
-
-    if response.status == 304 {
-        return Ok(ParsedFeed {
-            status: Some(304),
-            href: Some(response.url),
-            etag: etag.map(String::from),
-            modified: modified.map(String::from),
-            #[cfg(feature = "http")]
-            headers: Some(response.headers),
-            encoding: String::from("utf-8"),
-            ..Default::default()
-        });
-    }
-
-    if response.status >= 400 {
-        return Err(FeedError::Http {
-            message: format!("HTTP {} for URL: {}", response.status, response.url),
-        });
-    }
-
-    let mut feed = parse_with_limits(&response.body, limits)?;
-
-    feed.status = Some(response.status);
-    feed.href = Some(response.url);
-    feed.etag = response.etag;
-    feed.modified = response.last_modified;
-    #[cfg(feature = "http")]
-    {
-        feed.headers = Some(response.headers);
-    }
+    let shared = FeedHttpClient::shared()?;
+    // The user agent is set per client, so a custom one needs a clone; clones
+    // share the connection pool
+    let custom = user_agent.map(|agent| shared.clone().with_user_agent(agent.to_string()));
+    custom
+        .as_ref()
+        .unwrap_or(shared)
+        .fetch_feed(url, etag, modified, limits)
+}
 
-    if let Some(http_encoding) = response.encoding {
-        feed.encoding = http_encoding;
-    }
+/// Parse feed from URL with custom parse options
+///
+/// Like `parse_url` but parses with [`parse_with_options`], so options such
+/// as [`ParseOptions::keep_raw_body`] and `sanitize_html` apply.
+///
+/// # Errors
+///
+/// Returns `FeedError::Http` if the request fails or `FeedError::Parse` if parsing fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use feedparser_rs::{ParseOptions, parse_url_with_options};
+///
+/// let options = ParseOptions {
+///     keep_raw_body: true,
+///     ..ParseOptions::default()
+/// };
+/// let feed = parse_url_with_options("https://example.com/feed.xml", None, None, None, &options)
+///     .unwrap();
+/// let payload = feed.raw_body.unwrap();
+/// println!("Archived {} bytes", payload.len());
+/// ```
+#[cfg(feature = "http")]
+pub fn parse_url_with_options(
+    url: &str,
+    etag: Option<&str>,
+    modified: Option<&str>,
+    user_agent: Option<&str>,
+    options: &ParseOptions,
+) -> Result<ParsedFeed> {
+    use http::FeedHttpClient;
 
-    Ok(feed)
+    let shared = FeedHttpClient::shared()?;
+    // The user agent is set per client, so a custom one needs a clone; clones
+    // share the connection pool
+    let custom = user_agent.map(|agent| shared.clone().with_user_agent(agent.to_string()));
+    custom
+        .as_ref()
+        .unwrap_or(shared)
+        .fetch_feed_with_options(url, etag, modified, options)
 }
 
 #[cfg(test)]
@@ -14,12 +14,15 @@
 /// assert_eq!(limits.max_entries, 10_000);
 ///
 /// // Custom limits for restricted environments
-/// let strict = ParserLimits {
-///     max_entries: 1_000,
-///     max_feed_size_bytes: 10 * 1024 * 1024, // 10MB
-///     ..Default::default()
-/// };
+/// let strict = ParserLimits::default()
+///     .with_max_entries(1_000)
+///     .with_max_feed_size_bytes(10 * 1024 * 1024); // 10MB
+/// assert_eq!(strict.max_entries, 1_000);
 /// ```
+///
+/// Start from [`default`](Self::default), [`strict`](Self::strict) or
+/// [`permissive`](Self::permissive) and adjust individual limits with the
+/// `with_*` methods rather than writing out the full struct.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParserLimits {
     /// Maximum number of entries/items in a feed
@@ -108,6 +111,15 @@ pub struct ParserLimits {
     /// Default: 64 KB
     pub max_attribute_length: usize,
 
+    /// Maximum number of attributes read from a single element
+    ///
+    /// Values are length-capped by `max_attribute_length`, but an element
+    /// can still carry any number of them; attributes past this count are
+    /// ignored.
+    ///
+    /// Default: 128 attributes
+    pub max_attributes_per_element: usize,
+
     /// Maximum number of podcast soundbites per entry
     ///
     /// Podcast 2.0 soundbite elements for shareable clips.
@@ -143,6 +155,41 @@ pub struct ParserLimits {
     ///
     /// Default: 20 recipients
     pub max_value_recipients: usize,
+
+    /// Maximum number of `media:content` elements per entry
+    ///
+    /// Default: 20 media objects
+    pub max_media_content: usize,
+
+    /// Maximum number of `media:thumbnail` elements per entry
+    ///
+    /// Default: 20 thumbnails
+    pub max_media_thumbnails: usize,
+
+    /// Maximum number of `itunes:keywords` values kept per feed
+    ///
+    /// A single comma-separated element can hold an arbitrary number of
+    /// keywords; the rest are dropped.
+    ///
+    /// Default: 100 keywords
+    pub max_itunes_keywords: usize,
+
+    /// Maximum number of `itunes:category` elements per feed
+    ///
+    /// Default: 20 categories
+    pub max_itunes_categories: usize,
+
+    /// Maximum estimated memory held by parsed entries and feed metadata,
+    /// in bytes
+    ///
+    /// Every per-field limit can pass while the feed as a whole still adds
+    /// up to gigabytes; once the running total of stored text and
+    /// collections crosses this limit, further entries are dropped and the
+    /// feed is marked bozo. Feed metadata is charged once the channel or
+    /// feed element is parsed.
+    ///
+    /// Default: 512 MB
+    pub max_total_memory_bytes: usize,
 }
 
 impl Default for ParserLimits {
@@ -165,11 +212,17 @@ impl Default for ParserLimits {
             max_text_length: 10 * 1024 * 1024,      // 10 MB
             max_feed_size_bytes: 100 * 1024 * 1024, // 100 MB
             max_attribute_length: 64 * 1024,        // 64 KB
+            max_attributes_per_element: 128,
             max_podcast_soundbites: 10,
             max_podcast_transcripts: 20,
             max_podcast_funding: 20,
             max_podcast_persons: 50,
             max_value_recipients: 20,
+            max_media_content: 20,
+            max_media_thumbnails: 20,
+            max_itunes_keywords: 100,
+            max_itunes_categories: 20,
+            max_total_memory_bytes: 512 * 1024 * 1024, // 512 MB
         }
     }
 }
@@ -204,11 +257,17 @@ impl ParserLimits {
             max_text_length: 1024 * 1024,          // 1 MB
             max_feed_size_bytes: 10 * 1024 * 1024, // 10 MB
             max_attribute_length: 8 * 1024,        // 8 KB
+            max_attributes_per_element: 32,
             max_podcast_soundbites: 5,
             max_podcast_transcripts: 5,
             max_podcast_funding: 5,
             max_podcast_persons: 10,
             max_value_recipients: 5,
+            max_media_content: 5,
+            max_media_thumbnails: 5,
+            max_itunes_keywords: 20,
+            max_itunes_categories: 5,
+            max_total_memory_bytes: 64 * 1024 * 1024, // 64 MB
         }
     }
 
@@ -241,14 +300,207 @@ impl ParserLimits {
             max_text_length: 50 * 1024 * 1024,      // 50 MB
             max_feed_size_bytes: 500 * 1024 * 1024, // 500 MB
             max_attribute_length: 256 * 1024,       // 256 KB
+            max_attributes_per_element: 1024,
             max_podcast_soundbites: 50,
             max_podcast_transcripts: 100,
             max_podcast_funding: 50,
             max_podcast_persons: 200,
             max_value_recipients: 50,
+            max_media_content: 100,
+            max_media_thumbnails: 100,
+            max_itunes_keywords: 500,
+            max_itunes_categories: 100,
+            max_total_memory_bytes: 2 * 1024 * 1024 * 1024, // 2 GB
         }
     }
 
+    /// Sets the maximum number of entries
+    ///
+    /// The `with_*` methods start from a preset and override individual
+    /// limits, so new fields pick up the preset's value automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::ParserLimits;
+    ///
+    /// let limits = ParserLimits::strict()
+    ///     .with_max_entries(50)
+    ///     .with_max_text_length(64 * 1024);
+    /// assert_eq!(limits.max_entries, 50);
+    /// assert_eq!(limits.max_text_length, 64 * 1024);
+    /// assert_eq!(limits.max_tags, ParserLimits::strict().max_tags);
+    /// ```
+    #[must_use]
+    pub const fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets the maximum number of feed-level links
+    #[must_use]
+    pub const fn with_max_links_per_feed(mut self, max_links_per_feed: usize) -> Self {
+        self.max_links_per_feed = max_links_per_feed;
+        self
+    }
+
+    /// Sets the maximum number of links per entry
+    #[must_use]
+    pub const fn with_max_links_per_entry(mut self, max_links_per_entry: usize) -> Self {
+        self.max_links_per_entry = max_links_per_entry;
+        self
+    }
+
+    /// Sets the maximum number of authors per feed or entry
+    #[must_use]
+    pub const fn with_max_authors(mut self, max_authors: usize) -> Self {
+        self.max_authors = max_authors;
+        self
+    }
+
+    /// Sets the maximum number of contributors per feed or entry
+    #[must_use]
+    pub const fn with_max_contributors(mut self, max_contributors: usize) -> Self {
+        self.max_contributors = max_contributors;
+        self
+    }
+
+    /// Sets the maximum number of tags per feed or entry
+    #[must_use]
+    pub const fn with_max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = max_tags;
+        self
+    }
+
+    /// Sets the maximum number of content blocks per entry
+    #[must_use]
+    pub const fn with_max_content_blocks(mut self, max_content_blocks: usize) -> Self {
+        self.max_content_blocks = max_content_blocks;
+        self
+    }
+
+    /// Sets the maximum number of enclosures per entry
+    #[must_use]
+    pub const fn with_max_enclosures(mut self, max_enclosures: usize) -> Self {
+        self.max_enclosures = max_enclosures;
+        self
+    }
+
+    /// Sets the maximum number of XML namespace declarations
+    #[must_use]
+    pub const fn with_max_namespaces(mut self, max_namespaces: usize) -> Self {
+        self.max_namespaces = max_namespaces;
+        self
+    }
+
+    /// Sets the maximum XML nesting depth
+    #[must_use]
+    pub const fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Sets the maximum length of a text field, in bytes
+    #[must_use]
+    pub const fn with_max_text_length(mut self, max_text_length: usize) -> Self {
+        self.max_text_length = max_text_length;
+        self
+    }
+
+    /// Sets the maximum feed size, in bytes
+    #[must_use]
+    pub const fn with_max_feed_size_bytes(mut self, max_feed_size_bytes: usize) -> Self {
+        self.max_feed_size_bytes = max_feed_size_bytes;
+        self
+    }
+
+    /// Sets the maximum length of an attribute value, in bytes
+    #[must_use]
+    pub const fn with_max_attribute_length(mut self, max_attribute_length: usize) -> Self {
+        self.max_attribute_length = max_attribute_length;
+        self
+    }
+
+    /// Sets the maximum number of attributes read from a single element
+    #[must_use]
+    pub const fn with_max_attributes_per_element(
+        mut self,
+        max_attributes_per_element: usize,
+    ) -> Self {
+        self.max_attributes_per_element = max_attributes_per_element;
+        self
+    }
+
+    /// Sets the maximum number of podcast soundbites per entry
+    #[must_use]
+    pub const fn with_max_podcast_soundbites(mut self, max_podcast_soundbites: usize) -> Self {
+        self.max_podcast_soundbites = max_podcast_soundbites;
+        self
+    }
+
+    /// Sets the maximum number of podcast transcripts per entry
+    #[must_use]
+    pub const fn with_max_podcast_transcripts(mut self, max_podcast_transcripts: usize) -> Self {
+        self.max_podcast_transcripts = max_podcast_transcripts;
+        self
+    }
+
+    /// Sets the maximum number of podcast funding links
+    #[must_use]
+    pub const fn with_max_podcast_funding(mut self, max_podcast_funding: usize) -> Self {
+        self.max_podcast_funding = max_podcast_funding;
+        self
+    }
+
+    /// Sets the maximum number of podcast persons
+    #[must_use]
+    pub const fn with_max_podcast_persons(mut self, max_podcast_persons: usize) -> Self {
+        self.max_podcast_persons = max_podcast_persons;
+        self
+    }
+
+    /// Sets the maximum number of podcast value recipients
+    #[must_use]
+    pub const fn with_max_value_recipients(mut self, max_value_recipients: usize) -> Self {
+        self.max_value_recipients = max_value_recipients;
+        self
+    }
+
+    /// Sets the maximum number of `media:content` elements per entry
+    #[must_use]
+    pub const fn with_max_media_content(mut self, max_media_content: usize) -> Self {
+        self.max_media_content = max_media_content;
+        self
+    }
+
+    /// Sets the maximum number of `media:thumbnail` elements per entry
+    #[must_use]
+    pub const fn with_max_media_thumbnails(mut self, max_media_thumbnails: usize) -> Self {
+        self.max_media_thumbnails = max_media_thumbnails;
+        self
+    }
+
+    /// Sets the maximum number of iTunes keywords
+    #[must_use]
+    pub const fn with_max_itunes_keywords(mut self, max_itunes_keywords: usize) -> Self {
+        self.max_itunes_keywords = max_itunes_keywords;
+        self
+    }
+
+    /// Sets the maximum number of iTunes categories
+    #[must_use]
+    pub const fn with_max_itunes_categories(mut self, max_itunes_categories: usize) -> Self {
+        self.max_itunes_categories = max_itunes_categories;
+        self
+    }
+
+    /// Sets the maximum estimated memory held by parsed entries, in bytes
+    #[must_use]
+    pub const fn with_max_total_memory_bytes(mut self, max_total_memory_bytes: usize) -> Self {
+        self.max_total_memory_bytes = max_total_memory_bytes;
+        self
+    }
+
     /// Validates that a feed size is within limits
     ///
     /// Call this before starting to parse a feed.
@@ -347,6 +599,48 @@ pub enum LimitError {
     /// Text field is too long
     #[error("Text field length ({length} bytes) exceeds maximum ({max} bytes)")]
     TextTooLong { length: usize, max: usize },
+
+    /// Parsed data held in memory exceeds maximum allowed
+    #[error("Parsed data ({used} bytes) exceeds memory limit ({max} bytes)")]
+    MemoryExceeded { used: usize, max: usize },
+}
+
+/// Running total of memory held by parsed data
+///
+/// Parsers charge each entry as it is stored; the first charge that crosses
+/// [`ParserLimits::max_total_memory_bytes`] fails, and so does every charge
+/// after it.
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryBudget {
+    used: usize,
+    max: usize,
+}
+
+impl MemoryBudget {
+    /// Creates an empty budget bounded by `limits.max_total_memory_bytes`
+    pub const fn new(limits: &ParserLimits) -> Self {
+        Self {
+            used: 0,
+            max: limits.max_total_memory_bytes,
+        }
+    }
+
+    /// Adds `bytes` to the running total
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the total exceeds the limit.
+    pub const fn charge(&mut self, bytes: usize) -> Result<(), LimitError> {
+        self.used = self.used.saturating_add(bytes);
+        if self.used > self.max {
+            Err(LimitError::MemoryExceeded {
+                used: self.used,
+                max: self.max,
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -374,6 +668,65 @@ mod tests {
         assert!(limits.max_entries > ParserLimits::default().max_entries);
     }
 
+    #[test]
+    fn test_with_methods() {
+        let limits = ParserLimits::permissive()
+            .with_max_entries(5)
+            .with_max_nesting_depth(8)
+            .with_max_value_recipients(1);
+        assert_eq!(limits.max_entries, 5);
+        assert_eq!(limits.max_nesting_depth, 8);
+        assert_eq!(limits.max_value_recipients, 1);
+        assert_eq!(
+            limits.with_max_entries(100_000),
+            ParserLimits::permissive()
+                .with_max_nesting_depth(8)
+                .with_max_value_recipients(1)
+        );
+    }
+
+    #[test]
+    fn test_presets_are_ordered() {
+        let as_array = |l: ParserLimits| {
+            [
+                l.max_entries,
+                l.max_links_per_feed,
+                l.max_links_per_entry,
+                l.max_authors,
+                l.max_contributors,
+                l.max_tags,
+                l.max_content_blocks,
+                l.max_enclosures,
+                l.max_namespaces,
+                l.max_nesting_depth,
+                l.max_text_length,
+                l.max_feed_size_bytes,
+                l.max_attribute_length,
+                l.max_attributes_per_element,
+                l.max_podcast_soundbites,
+                l.max_podcast_transcripts,
+                l.max_podcast_funding,
+                l.max_podcast_persons,
+                l.max_value_recipients,
+                l.max_media_content,
+                l.max_media_thumbnails,
+                l.max_itunes_keywords,
+                l.max_itunes_categories,
+                l.max_total_memory_bytes,
+            ]
+        };
+        let strict = as_array(ParserLimits::strict());
+        let default = as_array(ParserLimits::default());
+        let permissive = as_array(ParserLimits::permissive());
+        for i in 0..default.len() {
+            assert!(strict[i] <= default[i], "strict exceeds default at {i}");
+            assert!(
+                default[i] <= permissive[i],
+                "default exceeds permissive at {i}"
+            );
+        }
+    }
+
     #[test]
     fn test_check_feed_size_ok() {
         let limits = ParserLimits::default();
@@ -434,6 +787,22 @@ mod tests {
         assert!(matches!(result, Err(LimitError::TextTooLong { .. })));
     }
 
+    #[test]
+    fn test_memory_budget() {
+        let limits = ParserLimits::default().with_max_total_memory_bytes(100);
+        let mut budget = MemoryBudget::new(&limits);
+        assert!(budget.charge(60).is_ok());
+        assert!(budget.charge(40).is_ok());
+        assert_eq!(
+            budget.charge(1),
+            Err(LimitError::MemoryExceeded {
+                used: 101,
+                max: 100
+            })
+        );
+        assert!(budget.charge(0).is_err());
+    }
+
     #[test]
     fn test_limit_error_display() {
         let err = LimitError::FeedTooLarge {
@@ -0,0 +1,838 @@
+//! Looking up fields by path
+//!
+//! [`ParsedFeed::get`] and [`Entry::get`] find a field from a dotted path
+//! such as `feed.title` or `entries[0].itunes.duration`, so tools driven by
+//! configuration files can extract values without a match arm per field.
+//!
+//! - Segments are separated by `.`; names are the Rust field names
+//! - `[n]` after a segment selects the n-th element of a list
+//! - feedparser's spellings are accepted too: `type` for a link's or
+//!   enclosure's MIME type, `href` for a person's URI, `updated_parsed` and
+//!   the other `*_parsed` dates, and the flattened `sy_*` and `blogchannel_*`
+//!   keys
+//! - Legacy keys resolve through [`compat::resolve_alias`], the table the
+//!   Python and Node.js bindings use, so `channel.description` finds the same
+//!   value as `feed.subtitle`
+//!
+//! Values are borrowed from the feed. Missing fields, unknown names,
+//! out-of-range indexes and malformed paths all give `None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{FieldValue, parse};
+//!
+//! let feed = parse(br#"<rss version="2.0"
+//!     xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+//!     <title>Show</title>
+//!     <description>Weekly episodes</description>
+//!     <item><title>Pilot</title><itunes:duration>1:30</itunes:duration></item>
+//! </channel></rss>"#).unwrap();
+//!
+//! assert_eq!(feed.get("feed.title"), Some(FieldValue::Str("Show")));
+//! assert_eq!(feed.get("channel.description"), Some(FieldValue::Str("Weekly episodes")));
+//! # #[cfg(feature = "itunes")]
+//! assert_eq!(feed.get("entries[0].itunes.duration"), Some(FieldValue::Int(90)));
+//! assert_eq!(feed.get("entries[1].title"), None);
+//! ```
+//!
+//! [`compat::resolve_alias`]: crate::compat::resolve_alias
+
+use crate::compat::{AliasScope, resolve_alias};
+use crate::namespace::{
+    activity::ActivityMeta,
+    blog_channel::BlogChannelMeta,
+    prism::PrismMeta,
+    syndication::{SyndicationMeta, UpdatePeriod},
+};
+use crate::types::{
+    Content, Enclosure, Entry, FeedMeta, Generator, Image, LicenseInfo, Link, MediaContent,
+    MediaThumbnail, ParsedFeed, Person, Source, Tag, TextConstruct, TextType,
+};
+#[cfg(feature = "itunes")]
+use crate::types::{ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner};
+#[cfg(feature = "podcast20")]
+use crate::types::{
+    PodcastEntryMeta, PodcastFunding, PodcastMeta, PodcastPerson, PodcastTranscript,
+};
+use chrono::{DateTime, Utc};
+
+/// Value found by a path lookup
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue<'a> {
+    /// Flag such as `bozo` or `itunes.explicit`
+    Bool(bool),
+    /// Count, size, duration in seconds or pixel dimension
+    Int(u64),
+    /// Text, URL or identifier
+    Str(&'a str),
+    /// Date and time
+    Date(DateTime<Utc>),
+    /// List such as `entries`, `tags` or `dc_subject`
+    List(Vec<Self>),
+    /// Structure with fields of its own, such as an entry or a link
+    Node(Node<'a>),
+}
+
+impl<'a> FieldValue<'a> {
+    /// Returns the text, if this is a string value
+    #[must_use]
+    pub const fn as_str(&self) -> Option<&'a str> {
+        match self {
+            Self::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the number, if this is an integer value
+    #[must_use]
+    pub const fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the flag, if this is a boolean value
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the date, if this is a date value
+    #[must_use]
+    pub const fn as_date(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Date(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements, if this is a list
+    #[must_use]
+    pub fn as_list(&self) -> Option<&[Self]> {
+        match self {
+            Self::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn field(self, name: &str) -> Option<Self> {
+        match self {
+            Self::Node(node) => node.field(name),
+            _ => None,
+        }
+    }
+
+    fn index(self, index: usize) -> Option<Self> {
+        match self {
+            Self::List(items) => items.into_iter().nth(index),
+            _ => None,
+        }
+    }
+}
+
+/// Structure a path can descend into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Node<'a> {
+    /// Parse result (`entries`, `bozo`, `version`, ...)
+    ParsedFeed(&'a ParsedFeed),
+    /// Feed metadata
+    FeedMeta(&'a FeedMeta),
+    /// Entry
+    Entry(&'a Entry),
+    /// Link
+    Link(&'a Link),
+    /// Author, contributor or publisher
+    Person(&'a Person),
+    /// Tag or category
+    Tag(&'a Tag),
+    /// Feed image
+    Image(&'a Image),
+    /// Enclosure
+    Enclosure(&'a Enclosure),
+    /// Content block
+    Content(&'a Content),
+    /// Text construct (`title_detail`, `summary_detail`, ...)
+    Text(&'a TextConstruct),
+    /// Generator
+    Generator(&'a Generator),
+    /// Source feed of an entry
+    Source(&'a Source),
+    /// License of a feed or entry
+    License(&'a LicenseInfo),
+    /// Media RSS thumbnail
+    MediaThumbnail(&'a MediaThumbnail),
+    /// Media RSS content
+    MediaContent(&'a MediaContent),
+    #[cfg(feature = "itunes")]
+    /// iTunes podcast metadata
+    ItunesFeed(&'a ItunesFeedMeta),
+    #[cfg(feature = "itunes")]
+    /// iTunes episode metadata
+    ItunesEntry(&'a ItunesEntryMeta),
+    #[cfg(feature = "itunes")]
+    /// iTunes podcast owner
+    ItunesOwner(&'a ItunesOwner),
+    #[cfg(feature = "itunes")]
+    /// iTunes category
+    ItunesCategory(&'a ItunesCategory),
+    #[cfg(feature = "podcast20")]
+    /// Podcast 2.0 feed metadata
+    PodcastFeed(&'a PodcastMeta),
+    #[cfg(feature = "podcast20")]
+    /// Podcast 2.0 episode metadata
+    PodcastEntry(&'a PodcastEntryMeta),
+    #[cfg(feature = "podcast20")]
+    /// Podcast 2.0 transcript
+    PodcastTranscript(&'a PodcastTranscript),
+    #[cfg(feature = "podcast20")]
+    /// Podcast 2.0 person
+    PodcastPerson(&'a PodcastPerson),
+    #[cfg(feature = "podcast20")]
+    /// Podcast 2.0 funding link
+    PodcastFunding(&'a PodcastFunding),
+    /// Syndication module metadata
+    Syndication(&'a SyndicationMeta),
+    /// blogChannel module metadata
+    BlogChannel(&'a BlogChannelMeta),
+    /// PRISM publishing metadata
+    Prism(&'a PrismMeta),
+    /// Activity Streams metadata
+    Activity(&'a ActivityMeta),
+}
+
+impl<'a> Node<'a> {
+    /// Looks up a dotted path below this structure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{FieldValue, Node, parse};
+    ///
+    /// let feed = parse(br#"<feed xmlns="http://www.w3.org/2005/Atom">
+    ///     <link rel="self" href="https://example.com/atom"/>
+    /// </feed>"#).unwrap();
+    /// let meta = Node::FeedMeta(&feed.feed);
+    ///
+    /// assert_eq!(meta.get("links[0].rel"), Some(FieldValue::Str("self")));
+    /// assert_eq!(meta.get("links[0].url"), Some(FieldValue::Str("https://example.com/atom")));
+    /// ```
+    #[must_use]
+    pub fn get(self, path: &str) -> Option<FieldValue<'a>> {
+        let mut current = FieldValue::Node(self);
+        for segment in path.split('.') {
+            let (name, mut indexes) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+            if name.is_empty() && indexes.is_empty() {
+                return None;
+            }
+            if !name.is_empty() {
+                current = current.field(name)?;
+            }
+            while let Some(rest) = indexes.strip_prefix('[') {
+                let (index, tail) = rest.split_once(']')?;
+                current = current.index(index.parse().ok()?)?;
+                indexes = tail;
+            }
+            if !indexes.is_empty() {
+                return None;
+            }
+        }
+        Some(current)
+    }
+
+    /// Looks up a single field, falling back to feedparser's legacy aliases
+    #[must_use]
+    pub fn field(self, name: &str) -> Option<FieldValue<'a>> {
+        self.own_field(name).or_else(|| {
+            resolve_alias(self.alias_scope()?, name)?
+                .iter()
+                .find_map(|field| self.own_field(field))
+        })
+    }
+
+    const fn alias_scope(self) -> Option<AliasScope> {
+        match self {
+            Self::ParsedFeed(_) => Some(AliasScope::Container),
+            Self::FeedMeta(_) => Some(AliasScope::Feed),
+            Self::Entry(_) => Some(AliasScope::Entry),
+            Self::Link(_) => Some(AliasScope::Link),
+            _ => None,
+        }
+    }
+
+    fn own_field(self, name: &str) -> Option<FieldValue<'a>> {
+        match self {
+            Self::ParsedFeed(v) => parsed_feed_field(v, name),
+            Self::FeedMeta(v) => feed_field(v, name),
+            Self::Entry(v) => entry_field(v, name),
+            Self::Link(v) => match name {
+                "href" => text(Some(&v.href)),
+                "rel" => text(v.rel.as_deref()),
+                "type" | "link_type" => text(v.link_type.as_deref()),
+                "title" => text(v.title.as_deref()),
+                "length" => v.length.map(int),
+                "hreflang" => text(v.hreflang.as_deref()),
+                "thr_count" => v.thr_count.map(int),
+                _ => None,
+            },
+            Self::Person(v) => match name {
+                "name" => text(v.name.as_deref()),
+                "email" => text(v.email.as_deref()),
+                "uri" | "href" => text(v.uri.as_deref()),
+                _ => None,
+            },
+            Self::Tag(v) => match name {
+                "term" => text(Some(&v.term)),
+                "scheme" => text(v.scheme.as_deref()),
+                "label" => text(v.label.as_deref()),
+                _ => None,
+            },
+            Self::Image(v) => match name {
+                "url" | "href" => text(Some(&v.url)),
+                "title" => text(v.title.as_deref()),
+                "link" => text(v.link.as_deref()),
+                "width" => v.width.map(int),
+                "height" => v.height.map(int),
+                "description" => text(v.description.as_deref()),
+                _ => None,
+            },
+            Self::Enclosure(v) => match name {
+                "url" | "href" => text(Some(&v.url)),
+                "length" => v.length.map(int),
+                "type" | "enclosure_type" => text(v.enclosure_type.as_deref()),
+                _ => None,
+            },
+            Self::Content(v) => match name {
+                "value" => text(Some(&v.value)),
+                "type" | "content_type" => text(v.content_type.as_deref()),
+                "language" => text(v.language.as_deref()),
+                "base" => text(v.base.as_deref()),
+                _ => None,
+            },
+            Self::Text(v) => match name {
+                "value" => text(Some(&v.value)),
+                "type" | "content_type" => text(Some(match v.content_type {
+                    TextType::Text => "text",
+                    TextType::Html => "html",
+                    TextType::Xhtml => "xhtml",
+                })),
+                "language" => text(v.language.as_deref()),
+                "base" => text(v.base.as_deref()),
+                _ => None,
+            },
+            Self::Generator(v) => match name {
+                "value" | "name" => text(Some(&v.value)),
+                "uri" | "href" => text(v.uri.as_deref()),
+                "version" => text(v.version.as_deref()),
+                _ => None,
+            },
+            Self::Source(v) => match name {
+                "title" => text(v.title.as_deref()),
+                "link" | "href" => text(v.link.as_deref()),
+                "id" => text(v.id.as_deref()),
+                _ => None,
+            },
+            Self::License(v) => match name {
+                "url" | "href" => text(v.url.as_deref()),
+                "name" => text(v.name.as_deref()),
+                "spdx_guess" => text(v.spdx_guess.as_deref()),
+                _ => None,
+            },
+            Self::MediaThumbnail(v) => match name {
+                "url" => text(Some(&v.url)),
+                "width" => v.width.map(int),
+                "height" => v.height.map(int),
+                "time" => text(v.time.as_deref()),
+                _ => None,
+            },
+            Self::MediaContent(v) => match name {
+                "url" => text(Some(&v.url)),
+                "type" | "content_type" => text(v.content_type.as_deref()),
+                "filesize" => v.filesize.map(int),
+                "width" => v.width.map(int),
+                "height" => v.height.map(int),
+                "duration" => v.duration.map(int),
+                "thumbnails" => Some(list(&v.thumbnails, Node::MediaThumbnail)),
+                "group" => v.group.map(|g| FieldValue::Int(g as u64)),
+                _ => None,
+            },
+            #[cfg(feature = "itunes")]
+            Self::ItunesFeed(v) => match name {
+                "author" => text(v.author.as_deref()),
+                "owner" => v
+                    .owner
+                    .as_ref()
+                    .map(Node::ItunesOwner)
+                    .map(FieldValue::Node),
+                "categories" => Some(list(&v.categories, Node::ItunesCategory)),
+                "explicit" => v.explicit.map(FieldValue::Bool),
+                "image" => text(v.image.as_deref()),
+                "keywords" => Some(strings(&v.keywords)),
+                "podcast_type" | "type" => text(v.podcast_type.as_deref()),
+                "complete" => v.complete.map(FieldValue::Bool),
+                "new_feed_url" => text(v.new_feed_url.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "itunes")]
+            Self::ItunesEntry(v) => match name {
+                "title" => text(v.title.as_deref()),
+                "author" => text(v.author.as_deref()),
+                "duration" => v.duration.map(int),
+                "explicit" => v.explicit.map(FieldValue::Bool),
+                "image" => text(v.image.as_deref()),
+                "episode" => v.episode.map(int),
+                "season" => v.season.map(int),
+                "episode_type" => text(v.episode_type.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "itunes")]
+            Self::ItunesOwner(v) => match name {
+                "name" => text(v.name.as_deref()),
+                "email" => text(v.email.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "itunes")]
+            Self::ItunesCategory(v) => match name {
+                "text" => text(Some(&v.text)),
+                "subcategory" => text(v.subcategory.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "podcast20")]
+            Self::PodcastFeed(v) => match name {
+                "transcripts" => Some(list(&v.transcripts, Node::PodcastTranscript)),
+                "funding" => Some(list(&v.funding, Node::PodcastFunding)),
+                "persons" => Some(list(&v.persons, Node::PodcastPerson)),
+                "guid" => text(v.guid.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "podcast20")]
+            Self::PodcastEntry(v) => match name {
+                "transcript" => Some(list(&v.transcript, Node::PodcastTranscript)),
+                "person" => Some(list(&v.person, Node::PodcastPerson)),
+                _ => None,
+            },
+            #[cfg(feature = "podcast20")]
+            Self::PodcastTranscript(v) => match name {
+                "url" => text(Some(&v.url)),
+                "type" | "transcript_type" => text(v.transcript_type.as_deref()),
+                "language" => text(v.language.as_deref()),
+                "rel" => text(v.rel.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "podcast20")]
+            Self::PodcastPerson(v) => match name {
+                "name" => text(Some(&v.name)),
+                "role" => text(v.role.as_deref()),
+                "group" => text(v.group.as_deref()),
+                "img" => text(v.img.as_deref()),
+                "href" => text(v.href.as_deref()),
+                _ => None,
+            },
+            #[cfg(feature = "podcast20")]
+            Self::PodcastFunding(v) => match name {
+                "url" => text(Some(&v.url)),
+                "message" => text(v.message.as_deref()),
+                _ => None,
+            },
+            Self::Syndication(v) => match name {
+                "update_period" => text(v.update_period.as_ref().map(UpdatePeriod::as_str)),
+                "update_frequency" => v.update_frequency.map(int),
+                "update_base" => text(v.update_base.as_deref()),
+                _ => None,
+            },
+            Self::BlogChannel(v) => match name {
+                "blog_roll" => text(v.blog_roll.as_deref()),
+                "my_subscriptions" => text(v.my_subscriptions.as_deref()),
+                "blink" => text(v.blink.as_deref()),
+                _ => None,
+            },
+            Self::Prism(v) => match name {
+                "publication_name" => text(v.publication_name.as_deref()),
+                "volume" => text(v.volume.as_deref()),
+                "number" => text(v.number.as_deref()),
+                "starting_page" => text(v.starting_page.as_deref()),
+                "doi" => text(v.doi.as_deref()),
+                "publication_date" => v.publication_date.map(FieldValue::Date),
+                _ => None,
+            },
+            Self::Activity(v) => match name {
+                "verb" => text(v.verb.as_deref()),
+                "object_type" => text(v.object_type.as_deref()),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl ParsedFeed {
+    /// Looks up a field by dotted path, such as `entries[0].title`
+    ///
+    /// See the [`lookup`](crate::lookup) module for the path syntax.
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<FieldValue<'_>> {
+        Node::ParsedFeed(self).get(path)
+    }
+}
+
+impl Entry {
+    /// Looks up a field of this entry by dotted path, such as `itunes.duration`
+    ///
+    /// See the [`lookup`](crate::lookup) module for the path syntax.
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<FieldValue<'_>> {
+        Node::Entry(self).get(path)
+    }
+}
+
+fn parsed_feed_field<'a>(v: &'a ParsedFeed, name: &str) -> Option<FieldValue<'a>> {
+    match name {
+        "feed" => Some(FieldValue::Node(Node::FeedMeta(&v.feed))),
+        "entries" => Some(list(&v.entries, Node::Entry)),
+        "bozo" => Some(FieldValue::Bool(v.bozo)),
+        "bozo_exception" => text(v.bozo_exception.as_deref()),
+        "encoding" => text(Some(&v.encoding)),
+        "version" => text(Some(v.version.as_str())),
+        "raw_version" => text(v.raw_version.as_deref()),
+        "status" => v.status.map(int),
+        "href" => text(v.href.as_deref()),
+        "etag" => text(v.etag.as_deref()),
+        "modified" => text(v.modified.as_deref()),
+        _ => None,
+    }
+}
+
+fn feed_field<'a>(v: &'a FeedMeta, name: &str) -> Option<FieldValue<'a>> {
+    match name {
+        "title" => text(v.title.as_deref()),
+        "title_detail" => v
+            .title_detail
+            .as_ref()
+            .map(Node::Text)
+            .map(FieldValue::Node),
+        "link" => text(v.link.as_deref()),
+        "links" => Some(list(&v.links, Node::Link)),
+        "subtitle" => text(v.subtitle.as_deref()),
+        "subtitle_detail" => v
+            .subtitle_detail
+            .as_ref()
+            .map(Node::Text)
+            .map(FieldValue::Node),
+        "updated" | "updated_parsed" => v.updated.map(FieldValue::Date),
+        "published" | "published_parsed" => v.published.map(FieldValue::Date),
+        "author" => text(v.author.as_deref()),
+        "author_detail" => v
+            .author_detail
+            .as_ref()
+            .map(Node::Person)
+            .map(FieldValue::Node),
+        "authors" => Some(list(&v.authors, Node::Person)),
+        "contributors" => Some(list(&v.contributors, Node::Person)),
+        "managing_editor" => text(v.managing_editor.as_deref()),
+        "publisher" => text(v.publisher.as_deref()),
+        "publisher_detail" => v
+            .publisher_detail
+            .as_ref()
+            .map(Node::Person)
+            .map(FieldValue::Node),
+        "language" => text(v.language.as_deref()),
+        "rights" => text(v.rights.as_deref()),
+        "rights_detail" => v
+            .rights_detail
+            .as_ref()
+            .map(Node::Text)
+            .map(FieldValue::Node),
+        "generator" => text(v.generator.as_deref()),
+        "generator_detail" => v
+            .generator_detail
+            .as_ref()
+            .map(Node::Generator)
+            .map(FieldValue::Node),
+        "image" => v.image.as_ref().map(Node::Image).map(FieldValue::Node),
+        "icon" => text(v.icon.as_deref()),
+        "logo" => text(v.logo.as_deref()),
+        "raw_icon" => text(v.raw_icon.as_deref()),
+        "raw_logo" => text(v.raw_logo.as_deref()),
+        "raw_image_url" => text(v.raw_image_url.as_deref()),
+        "tags" => Some(list(&v.tags, Node::Tag)),
+        "id" => text(v.id.as_deref()),
+        "ttl" => v.ttl.map(int),
+        "expired" => Some(FieldValue::Bool(v.expired)),
+        #[cfg(feature = "itunes")]
+        "itunes" => v
+            .itunes
+            .as_deref()
+            .map(Node::ItunesFeed)
+            .map(FieldValue::Node),
+        #[cfg(feature = "podcast20")]
+        "podcast" => v
+            .podcast
+            .as_deref()
+            .map(Node::PodcastFeed)
+            .map(FieldValue::Node),
+        #[cfg(feature = "dublincore")]
+        "dc_creator" => text(v.dc_creator.as_deref()),
+        #[cfg(feature = "dublincore")]
+        "dc_publisher" => text(v.dc_publisher.as_deref()),
+        #[cfg(feature = "dublincore")]
+        "dc_rights" => text(v.dc_rights.as_deref()),
+        "license" => text(v.license.as_deref()),
+        "license_detail" => v
+            .license_detail
+            .as_ref()
+            .map(Node::License)
+            .map(FieldValue::Node),
+        "syndication" => v
+            .syndication
+            .as_deref()
+            .map(Node::Syndication)
+            .map(FieldValue::Node),
+        "blog_channel" => v
+            .blog_channel
+            .as_deref()
+            .map(Node::BlogChannel)
+            .map(FieldValue::Node),
+        "sy_updateperiod" => Node::FeedMeta(v).get("syndication.update_period"),
+        "sy_updatefrequency" => Node::FeedMeta(v).get("syndication.update_frequency"),
+        "sy_updatebase" => Node::FeedMeta(v).get("syndication.update_base"),
+        "blogchannel_blogroll" => Node::FeedMeta(v).get("blog_channel.blog_roll"),
+        "blogchannel_mysubscriptions" => Node::FeedMeta(v).get("blog_channel.my_subscriptions"),
+        "blogchannel_blink" => Node::FeedMeta(v).get("blog_channel.blink"),
+        _ => None,
+    }
+}
+
+fn entry_field<'a>(v: &'a Entry, name: &str) -> Option<FieldValue<'a>> {
+    match name {
+        "id" => text(v.id.as_deref()),
+        "title" => text(v.title.as_deref()),
+        "title_detail" => v
+            .title_detail
+            .as_ref()
+            .map(Node::Text)
+            .map(FieldValue::Node),
+        "link" => text(v.link.as_deref()),
+        "links" => Some(list(&v.links, Node::Link)),
+        "summary" => text(v.summary.as_deref()),
+        "summary_detail" => v
+            .summary_detail
+            .as_ref()
+            .map(Node::Text)
+            .map(FieldValue::Node),
+        "content" => Some(list(&v.content, Node::Content)),
+        "published" | "published_parsed" => v.published.map(FieldValue::Date),
+        "updated" | "updated_parsed" => v.updated.map(FieldValue::Date),
+        "created" | "created_parsed" => v.created.map(FieldValue::Date),
+        "expired" | "expired_parsed" => v.expired.map(FieldValue::Date),
+        "author" => text(v.author.as_deref()),
+        "author_detail" => v
+            .author_detail
+            .as_ref()
+            .map(Node::Person)
+            .map(FieldValue::Node),
+        "authors" => Some(list(&v.authors, Node::Person)),
+        "contributors" => Some(list(&v.contributors, Node::Person)),
+        "publisher" => text(v.publisher.as_deref()),
+        "publisher_detail" => v
+            .publisher_detail
+            .as_ref()
+            .map(Node::Person)
+            .map(FieldValue::Node),
+        "tags" => Some(list(&v.tags, Node::Tag)),
+        "enclosures" => Some(list(&v.enclosures, Node::Enclosure)),
+        "comments" => text(v.comments.as_deref()),
+        "comments_count" => v.comments_count.map(int),
+        "thr_total" => v.thr_total.map(int),
+        "slash_comments" => v.slash_comments.map(int),
+        "external_url" => text(v.external_url.as_deref()),
+        "banner_image" => text(v.banner_image.as_deref()),
+        "source" => v.source.as_ref().map(Node::Source).map(FieldValue::Node),
+        #[cfg(feature = "itunes")]
+        "itunes" => v
+            .itunes
+            .as_deref()
+            .map(Node::ItunesEntry)
+            .map(FieldValue::Node),
+        #[cfg(feature = "dublincore")]
+        "dc_creator" => text(v.dc_creator.as_deref()),
+        #[cfg(feature = "dublincore")]
+        "dc_date" => v.dc_date.map(FieldValue::Date),
+        #[cfg(feature = "dublincore")]
+        "dc_subject" => Some(strings(&v.dc_subject)),
+        #[cfg(feature = "dublincore")]
+        "dc_rights" => text(v.dc_rights.as_deref()),
+        "media_thumbnails" => Some(list(&v.media_thumbnails, Node::MediaThumbnail)),
+        "media_content" => Some(list(&v.media_content, Node::MediaContent)),
+        "image" => v.image.as_ref().map(Node::Image).map(FieldValue::Node),
+        #[cfg(feature = "podcast20")]
+        "podcast_transcripts" => Some(list(&v.podcast_transcripts, Node::PodcastTranscript)),
+        #[cfg(feature = "podcast20")]
+        "podcast_persons" => Some(list(&v.podcast_persons, Node::PodcastPerson)),
+        #[cfg(feature = "podcast20")]
+        "podcast" => v
+            .podcast
+            .as_deref()
+            .map(Node::PodcastEntry)
+            .map(FieldValue::Node),
+        "license" => text(v.license.as_deref()),
+        "license_detail" => v
+            .license_detail
+            .as_ref()
+            .map(Node::License)
+            .map(FieldValue::Node),
+        "prism" => v.prism.as_deref().map(Node::Prism).map(FieldValue::Node),
+        "activity" => v
+            .activity
+            .as_deref()
+            .map(Node::Activity)
+            .map(FieldValue::Node),
+        "id_synthetic" => Some(FieldValue::Bool(v.id_synthetic)),
+        _ => None,
+    }
+}
+
+fn text(value: Option<&str>) -> Option<FieldValue<'_>> {
+    value.map(FieldValue::Str)
+}
+
+fn int<'a>(n: impl Into<u64>) -> FieldValue<'a> {
+    FieldValue::Int(n.into())
+}
+
+fn list<'a, T>(items: &'a [T], wrap: fn(&'a T) -> Node<'a>) -> FieldValue<'a> {
+    FieldValue::List(items.iter().map(|v| FieldValue::Node(wrap(v))).collect())
+}
+
+#[cfg(any(feature = "itunes", feature = "dublincore"))]
+fn strings<T: AsRef<str>>(items: &[T]) -> FieldValue<'_> {
+    FieldValue::List(items.iter().map(|v| FieldValue::Str(v.as_ref())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const ATOM: &[u8] = br#"<feed xmlns="http://www.w3.org/2005/Atom">
+        <title>Example</title>
+        <subtitle>All the news</subtitle>
+        <updated>2024-01-02T03:04:05Z</updated>
+        <author><name>Jane</name><uri>https://example.com/jane</uri></author>
+        <entry>
+            <id>urn:1</id>
+            <title type="html">First &lt;b&gt;post&lt;/b&gt;</title>
+            <link href="https://example.com/1"/>
+            <link rel="enclosure" href="https://example.com/1.mp3" type="audio/mpeg" length="42"/>
+            <category term="rust"/>
+            <category term="feeds"/>
+        </entry>
+    </feed>"#;
+
+    #[test]
+    fn test_get_paths() {
+        let feed = parse(ATOM).unwrap();
+
+        assert_eq!(feed.get("version"), Some(FieldValue::Str("atom10")));
+        assert_eq!(feed.get("bozo"), Some(FieldValue::Bool(false)));
+        assert_eq!(feed.get("feed.title"), Some(FieldValue::Str("Example")));
+        assert_eq!(
+            feed.get("feed.author_detail.href"),
+            Some(FieldValue::Str("https://example.com/jane"))
+        );
+        assert_eq!(
+            feed.get("entries[0].title_detail.type"),
+            Some(FieldValue::Str("html"))
+        );
+        assert_eq!(
+            feed.get("entries[0].enclosures[0].length"),
+            Some(FieldValue::Int(42))
+        );
+        assert_eq!(
+            feed.get("entries[0].tags[1].term"),
+            Some(FieldValue::Str("feeds"))
+        );
+        assert_eq!(feed.get("entries").unwrap().as_list().unwrap().len(), 1);
+        assert!(matches!(
+            feed.get("entries[0]"),
+            Some(FieldValue::Node(Node::Entry(_)))
+        ));
+    }
+
+    #[test]
+    fn test_get_aliases() {
+        let feed = parse(ATOM).unwrap();
+
+        assert_eq!(
+            feed.get("channel.description"),
+            Some(FieldValue::Str("All the news"))
+        );
+        assert_eq!(feed.get("items[0].guid"), Some(FieldValue::Str("urn:1")));
+        assert_eq!(
+            feed.get("entries[0].links[0].url"),
+            Some(FieldValue::Str("https://example.com/1"))
+        );
+        assert_eq!(feed.get("feed.modified_parsed"), feed.get("feed.updated"));
+        assert!(feed.get("feed.date").unwrap().as_date().is_some());
+    }
+
+    #[test]
+    fn test_get_flattened_namespace_keys() {
+        let feed = parse(
+            br#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                xmlns="http://purl.org/rss/1.0/"
+                xmlns:syn="http://purl.org/rss/1.0/modules/syndication/">
+                <channel rdf:about="https://example.com/">
+                    <syn:updatePeriod>daily</syn:updatePeriod>
+                    <syn:updateFrequency>2</syn:updateFrequency>
+                </channel>
+            </rdf:RDF>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            feed.get("feed.sy_updateperiod"),
+            Some(FieldValue::Str("daily"))
+        );
+        assert_eq!(
+            feed.get("feed.syndication.update_frequency"),
+            Some(FieldValue::Int(2))
+        );
+        assert_eq!(feed.get("feed.blogchannel_blink"), None);
+    }
+
+    #[test]
+    fn test_get_invalid_paths() {
+        let feed = parse(ATOM).unwrap();
+
+        for path in [
+            "",
+            "feed.",
+            ".feed",
+            "feed.nope",
+            "entries[1]",
+            "entries[x]",
+            "entries[0",
+            "entries[0]x",
+            "feed.title.value",
+            "feed[0]",
+        ] {
+            assert_eq!(feed.get(path), None, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_entry_get() {
+        let feed = parse(ATOM).unwrap();
+        let entry = &feed.entries[0];
+
+        assert_eq!(entry.get("id"), Some(FieldValue::Str("urn:1")));
+        assert_eq!(
+            entry.get("links[1].type"),
+            Some(FieldValue::Str("audio/mpeg"))
+        );
+        assert_eq!(entry.get("itunes.duration"), None);
+    }
+}
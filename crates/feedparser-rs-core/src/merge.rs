@@ -0,0 +1,174 @@
+//! Merging several feeds into one "river of news"
+//!
+//! [`merge_feeds`] combines the entries of many parsed feeds into a single
+//! [`ParsedFeed`], newest first, with duplicates removed by
+//! [`Entry::fingerprint`] and each entry's `source` pointing back at the feed
+//! it came from.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{MergeOptions, merge_feeds, parse};
+//!
+//! let a = parse(br#"<rss version="2.0"><channel><title>A</title>
+//!     <item><guid>a1</guid><pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+//! </channel></rss>"#).unwrap();
+//! let b = parse(br#"<rss version="2.0"><channel><title>B</title>
+//!     <item><guid>b1</guid><pubDate>Tue, 02 Jan 2024 00:00:00 GMT</pubDate></item>
+//! </channel></rss>"#).unwrap();
+//!
+//! let river = merge_feeds(&[a, b], &MergeOptions::default());
+//! assert_eq!(river.entries[0].id.as_deref(), Some("b1"));
+//! assert_eq!(
+//!     river.entries[1].source.as_ref().and_then(|s| s.title.as_deref()),
+//!     Some("A")
+//! );
+//! ```
+
+use crate::types::{Entry, ParsedFeed, Source};
+use std::collections::HashSet;
+
+/// Options for [`merge_feeds`]
+#[derive(Debug, Clone)]
+pub struct MergeOptions {
+    /// Maximum number of entries in the merged feed (newest are kept)
+    ///
+    /// Default: `None` (no cap)
+    pub max_entries: Option<usize>,
+
+    /// Whether to drop entries whose fingerprint was already seen
+    ///
+    /// The newest copy of a duplicated entry is kept.
+    ///
+    /// Default: `true`
+    pub dedup: bool,
+
+    /// Title of the merged feed
+    ///
+    /// Default: `None`
+    pub title: Option<String>,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: None,
+            dedup: true,
+            title: None,
+        }
+    }
+}
+
+/// Merge entries from several feeds into one, newest first
+///
+/// Entries are ordered by `published`, falling back to `updated`; undated
+/// entries go last in their original order. Entries without a `source` get
+/// one describing the feed they came from. The merged feed is `bozo` if any
+/// input was.
+#[must_use]
+pub fn merge_feeds(feeds: &[ParsedFeed], options: &MergeOptions) -> ParsedFeed {
+    let total = feeds.iter().map(|f| f.entries.len()).sum();
+    let mut entries: Vec<Entry> = Vec::with_capacity(total);
+
+    for feed in feeds {
+        let source = Source {
+            title: feed.feed.title.clone(),
+            link: feed.feed.link.clone().or_else(|| feed.href.clone()),
+            id: feed.feed.id.clone(),
+        };
+        entries.extend(feed.entries.iter().map(|entry| {
+            let mut entry = entry.clone();
+            if entry.source.is_none() {
+                entry.source = Some(source.clone());
+            }
+            entry
+        }));
+    }
+
+    // Stable sort keeps feed order for equal or missing dates
+    entries.sort_by(|a, b| {
+        let (a, b) = (a.published.or(a.updated), b.published.or(b.updated));
+        b.is_some().cmp(&a.is_some()).then(b.cmp(&a))
+    });
+
+    if options.dedup {
+        let mut seen = HashSet::with_capacity(entries.len());
+        entries.retain(|entry| seen.insert(entry.fingerprint()));
+    }
+    if let Some(max) = options.max_entries {
+        entries.truncate(max);
+    }
+
+    let mut merged = ParsedFeed::new();
+    merged.feed.title.clone_from(&options.title);
+    merged.entries = entries;
+    merged.bozo = feeds.iter().any(|f| f.bozo);
+    merged.bozo_exception = feeds.iter().find_map(|f| f.bozo_exception.clone());
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn rss(title: &str, items: &str) -> ParsedFeed {
+        parse(
+            format!(
+                r#"<rss version="2.0"><channel><title>{title}</title>
+                <link>https://{title}.example/</link>{items}</channel></rss>"#
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    fn item(guid: &str, day: u32) -> String {
+        format!("<item><guid>{guid}</guid><pubDate>{day:02} Jan 2024 00:00:00 GMT</pubDate></item>")
+    }
+
+    fn ids(feed: &ParsedFeed) -> Vec<&str> {
+        feed.entries
+            .iter()
+            .filter_map(|e| e.id.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_interleaves_by_date() {
+        let a = rss("a", &(item("a1", 1) + &item("a3", 3)));
+        let b = rss("b", &(item("b2", 2) + "<item><guid>b0</guid></item>"));
+        let merged = merge_feeds(&[a, b], &MergeOptions::default());
+        assert_eq!(ids(&merged), ["a3", "b2", "a1", "b0"]);
+    }
+
+    #[test]
+    fn test_annotates_source() {
+        let merged = merge_feeds(&[rss("a", &item("a1", 1))], &MergeOptions::default());
+        let source = merged.entries[0].source.as_ref().unwrap();
+        assert_eq!(source.title.as_deref(), Some("a"));
+        assert_eq!(source.link.as_deref(), Some("https://a.example/"));
+    }
+
+    #[test]
+    fn test_dedup_and_cap() {
+        let a = rss("a", &(item("x", 1) + &item("y", 2)));
+        let b = rss("b", &(item("x", 3) + &item("z", 4)));
+        let merged = merge_feeds(&[a.clone(), b.clone()], &MergeOptions::default());
+        assert_eq!(ids(&merged), ["z", "x", "y"]);
+        // Newest copy of the duplicate wins
+        assert_eq!(
+            merged.entries[1].source.as_ref().unwrap().title.as_deref(),
+            Some("b")
+        );
+
+        let options = MergeOptions {
+            max_entries: Some(2),
+            dedup: false,
+            title: Some("River".to_string()),
+        };
+        let merged = merge_feeds(&[a, b], &options);
+        assert_eq!(ids(&merged), ["z", "x"]);
+        assert_eq!(merged.feed.title.as_deref(), Some("River"));
+    }
+}
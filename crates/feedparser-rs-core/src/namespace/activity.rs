@@ -0,0 +1,177 @@
+//! Activity Streams Atom extension support
+//!
+//! Social and federated feeds (`OStatus`, GNU social, older Mastodon
+//! exports) mark each Atom entry with what happened: a post, a like, a
+//! share. The verb and the type of the affected object are URIs from the
+//! Activity Streams schema, sometimes shortened to their last segment.
+//!
+//! # Supported Elements
+//!
+//! - `activity:verb` - What the actor did (`post`, `share`, `favorite`, ...)
+//! - `activity:object-type` - Type of the entry's own object (`note`, `comment`, ...)
+//! - `activity:object` - The object acted on, with its Atom `id`, `title`,
+//!   `link`, `content` and `activity:object-type`
+//!
+//! # Specification
+//!
+//! Atom Activity Streams 1.0: <http://activitystrea.ms/specs/atom/1.0/>
+
+use crate::types::Entry;
+
+/// Activity Streams namespace URI
+pub const ACTIVITY_STREAMS: &str = "http://activitystrea.ms/spec/1.0/";
+
+/// Base URI of the Activity Streams verb and object type schema
+pub const SCHEMA_BASE: &str = "http://activitystrea.ms/schema/1.0/";
+
+/// Object acted on by an activity (`activity:object`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActivityObject {
+    /// Object identifier (`id`)
+    pub id: Option<String>,
+    /// Object title (`title`)
+    pub title: Option<String>,
+    /// Alternate link to the object, falling back to the first link
+    pub link: Option<String>,
+    /// Object type URI (`activity:object-type`)
+    pub object_type: Option<String>,
+    /// Object body (`content`, falling back to `summary`)
+    pub content: Option<String>,
+}
+
+/// Activity Streams metadata of an entry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActivityMeta {
+    /// Verb URI as written (`activity:verb`)
+    pub verb: Option<String>,
+    /// Object type URI of the entry itself (`activity:object-type`)
+    pub object_type: Option<String>,
+    /// Object the verb applies to (`activity:object`)
+    pub object: Option<ActivityObject>,
+}
+
+impl ActivityMeta {
+    /// Returns the verb without the schema base URI
+    ///
+    /// Entries without `activity:verb` are posts, as the specification
+    /// defines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::ActivityMeta;
+    ///
+    /// let like = ActivityMeta {
+    ///     verb: Some("http://activitystrea.ms/schema/1.0/favorite".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(like.verb_name(), "favorite");
+    /// assert_eq!(ActivityMeta::default().verb_name(), "post");
+    /// ```
+    #[must_use]
+    pub fn verb_name(&self) -> &str {
+        self.verb.as_deref().map_or("post", short_name)
+    }
+
+    /// Returns the entry's object type without the schema base URI
+    #[must_use]
+    pub fn object_type_name(&self) -> Option<&str> {
+        self.object_type.as_deref().map(short_name)
+    }
+}
+
+/// Strips the Activity Streams schema base from a verb or object type URI
+///
+/// Values outside the schema are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::namespace::activity::short_name;
+///
+/// assert_eq!(short_name("http://activitystrea.ms/schema/1.0/share"), "share");
+/// assert_eq!(short_name("note"), "note");
+/// assert_eq!(short_name("urn:x:unfollow"), "urn:x:unfollow");
+/// ```
+#[must_use]
+pub fn short_name(uri: &str) -> &str {
+    uri.strip_prefix(SCHEMA_BASE).unwrap_or(uri)
+}
+
+/// Handle Activity Streams text element at entry level
+///
+/// The first `activity:verb` wins; `activity:object` is parsed by the Atom
+/// parser since it nests Atom elements.
+///
+/// # Arguments
+///
+/// * `element` - Local name of the element (without namespace prefix)
+/// * `text` - Text content of the element
+/// * `entry` - Entry to update
+pub fn handle_entry_element(element: &str, text: &str, entry: &mut Entry) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    match element {
+        "verb" => {
+            activity_mut(entry)
+                .verb
+                .get_or_insert_with(|| text.to_string());
+        }
+        "object-type" => {
+            activity_mut(entry)
+                .object_type
+                .get_or_insert_with(|| text.to_string());
+        }
+        _ => {
+            // Ignore unknown Activity Streams elements
+        }
+    }
+}
+
+/// Returns the entry's activity metadata, creating it if needed
+pub(crate) fn activity_mut(entry: &mut Entry) -> &mut ActivityMeta {
+    entry
+        .activity
+        .get_or_insert_with(|| Box::new(ActivityMeta::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_verb_and_object_type() {
+        let mut entry = Entry::default();
+
+        handle_entry_element(
+            "verb",
+            "http://activitystrea.ms/schema/1.0/share",
+            &mut entry,
+        );
+        handle_entry_element(
+            "verb",
+            "http://activitystrea.ms/schema/1.0/post",
+            &mut entry,
+        );
+        handle_entry_element("object-type", "note", &mut entry);
+
+        let activity = entry.activity.unwrap();
+        assert_eq!(activity.verb_name(), "share");
+        assert_eq!(activity.object_type_name(), Some("note"));
+    }
+
+    #[test]
+    fn test_handle_empty_and_unknown() {
+        let mut entry = Entry::default();
+
+        handle_entry_element("verb", " ", &mut entry);
+        handle_entry_element("actor", "someone", &mut entry);
+
+        assert!(entry.activity.is_none());
+    }
+}
@@ -0,0 +1,96 @@
+/// blogChannel RSS module
+///
+/// Namespace: <http://backend.userland.com/blogChannelModule>
+/// Prefix: blogChannel
+///
+/// This module provides parsing support for the blogChannel elements that
+/// classic weblog tools (Radio `UserLand`, Manila, early `WordPress`) put in
+/// the RSS channel to point at the author's reading lists.
+///
+/// Elements:
+/// - `blogChannel:blogRoll` → URL of an OPML file listing the blogs the author reads
+/// - `blogChannel:mySubscriptions` → URL of an OPML file of the author's subscriptions
+/// - `blogChannel:blink` → URL of a blog the author recommends
+use crate::types::FeedMeta;
+
+/// blogChannel namespace URI
+pub const BLOG_CHANNEL_NAMESPACE: &str = "http://backend.userland.com/blogChannelModule";
+
+/// blogChannel metadata
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlogChannelMeta {
+    /// URL of the author's blogroll in OPML (`blogChannel:blogRoll`)
+    pub blog_roll: Option<String>,
+    /// URL of the author's subscription list in OPML (`blogChannel:mySubscriptions`)
+    pub my_subscriptions: Option<String>,
+    /// URL of a recommended blog (`blogChannel:blink`)
+    pub blink: Option<String>,
+}
+
+/// Handle blogChannel namespace element at feed level
+///
+/// # Arguments
+///
+/// * `element` - Local name of the element (without namespace prefix)
+/// * `text` - Text content of the element
+/// * `feed` - Feed metadata to update
+pub fn handle_feed_element(element: &str, text: &str, feed: &mut FeedMeta) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    match element {
+        "blogRoll" => blog_channel_mut(feed).blog_roll = Some(text.to_string()),
+        "mySubscriptions" => blog_channel_mut(feed).my_subscriptions = Some(text.to_string()),
+        "blink" => blog_channel_mut(feed).blink = Some(text.to_string()),
+        _ => {
+            // Ignore unknown blogChannel elements
+        }
+    }
+}
+
+fn blog_channel_mut(feed: &mut FeedMeta) -> &mut BlogChannelMeta {
+    feed.blog_channel
+        .get_or_insert_with(|| Box::new(BlogChannelMeta::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_elements() {
+        let mut feed = FeedMeta::default();
+
+        handle_feed_element("blogRoll", "http://example.com/blogroll.opml", &mut feed);
+        handle_feed_element(
+            "mySubscriptions",
+            " http://example.com/subs.opml ",
+            &mut feed,
+        );
+        handle_feed_element("blink", "http://friend.example.org/", &mut feed);
+
+        let meta = feed.blog_channel.unwrap();
+        assert_eq!(
+            meta.blog_roll.as_deref(),
+            Some("http://example.com/blogroll.opml")
+        );
+        assert_eq!(
+            meta.my_subscriptions.as_deref(),
+            Some("http://example.com/subs.opml")
+        );
+        assert_eq!(meta.blink.as_deref(), Some("http://friend.example.org/"));
+    }
+
+    #[test]
+    fn test_handle_empty_and_unknown() {
+        let mut feed = FeedMeta::default();
+
+        handle_feed_element("blogRoll", "", &mut feed);
+        handle_feed_element("changes", "http://weblogs.com/changes.xml", &mut feed);
+
+        assert!(feed.blog_channel.is_none());
+    }
+}
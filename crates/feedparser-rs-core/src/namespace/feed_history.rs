@@ -0,0 +1,165 @@
+/// Feed Paging and Archiving (RFC 5005)
+///
+/// Namespace: <http://purl.org/syndication/history/1.0>
+/// Prefix: fh
+///
+/// This module provides parsing support for the Feed History extension,
+/// which lets publishers mark a feed document as complete or as an archive
+/// and link archive documents together so that clients can reconstruct the
+/// full history of a feed.
+///
+/// Elements:
+/// - `fh:complete` → Feed document contains every entry of the feed
+/// - `fh:archive` → Feed document is an archive and will not change
+///
+/// Link relations:
+/// - `prev-archive` → Previous (older) archive document
+/// - `next-archive` → Next (newer) archive document
+/// - `current` → Subscription document the archive belongs to
+use crate::types::ParsedFeed;
+
+/// Feed History namespace URI
+pub const FEED_HISTORY_NAMESPACE: &str = "http://purl.org/syndication/history/1.0";
+
+/// Archive and paging information from RFC 5005
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeedHistory {
+    /// `fh:complete` is present: the document holds the complete feed
+    pub complete: bool,
+    /// `fh:archive` is present: the document is an archive document
+    pub archive: bool,
+    /// URL of the previous (older) archive document
+    pub prev_archive: Option<String>,
+    /// URL of the next (newer) archive document
+    pub next_archive: Option<String>,
+    /// URL of the current subscription document
+    pub current: Option<String>,
+}
+
+/// Handle Feed History namespace element at feed level
+///
+/// Both `fh:complete` and `fh:archive` are marker elements, so only the
+/// element name is needed.
+///
+/// # Arguments
+///
+/// * `element` - Local name of the element (without namespace prefix)
+/// * `feed` - Parsed feed to update
+pub fn handle_feed_element(element: &str, feed: &mut ParsedFeed) {
+    match element {
+        "complete" => {
+            feed.history
+                .get_or_insert_with(FeedHistory::default)
+                .complete = true;
+        }
+        "archive" => {
+            feed.history
+                .get_or_insert_with(FeedHistory::default)
+                .archive = true;
+        }
+        _ => {
+            // Ignore unknown feed history elements
+        }
+    }
+}
+
+/// Handle a feed-level link that may carry an RFC 5005 archive relation
+///
+/// Only the first link of each relation is kept.
+///
+/// # Arguments
+///
+/// * `rel` - Link relation
+/// * `href` - Link target
+/// * `feed` - Parsed feed to update
+///
+/// # Returns
+///
+/// `true` if `rel` is an archive relation, `false` otherwise
+pub fn handle_link(rel: &str, href: &str, feed: &mut ParsedFeed) -> bool {
+    if !is_archive_rel(rel) {
+        return false;
+    }
+
+    let history = feed.history.get_or_insert_with(FeedHistory::default);
+    let slot = match rel {
+        "prev-archive" => &mut history.prev_archive,
+        "next-archive" => &mut history.next_archive,
+        _ => &mut history.current,
+    };
+    if slot.is_none() {
+        *slot = Some(href.to_string());
+    }
+    true
+}
+
+/// Check whether a link relation is one of the RFC 5005 archive relations
+#[inline]
+#[must_use]
+pub fn is_archive_rel(rel: &str) -> bool {
+    matches!(rel, "prev-archive" | "next-archive" | "current")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers() {
+        let mut feed = ParsedFeed::default();
+        handle_feed_element("complete", &mut feed);
+        let history = feed.history.as_ref().unwrap();
+        assert!(history.complete);
+        assert!(!history.archive);
+
+        handle_feed_element("archive", &mut feed);
+        assert!(feed.history.as_ref().unwrap().archive);
+    }
+
+    #[test]
+    fn test_unknown_element_ignored() {
+        let mut feed = ParsedFeed::default();
+        handle_feed_element("unknown", &mut feed);
+        assert!(feed.history.is_none());
+    }
+
+    #[test]
+    fn test_archive_links() {
+        let mut feed = ParsedFeed::default();
+        assert!(handle_link(
+            "prev-archive",
+            "http://example.com/2",
+            &mut feed
+        ));
+        assert!(handle_link(
+            "next-archive",
+            "http://example.com/4",
+            &mut feed
+        ));
+        assert!(handle_link("current", "http://example.com/feed", &mut feed));
+        assert!(!handle_link("alternate", "http://example.com/", &mut feed));
+
+        let history = feed.history.unwrap();
+        assert_eq!(
+            history.prev_archive.as_deref(),
+            Some("http://example.com/2")
+        );
+        assert_eq!(
+            history.next_archive.as_deref(),
+            Some("http://example.com/4")
+        );
+        assert_eq!(history.current.as_deref(), Some("http://example.com/feed"));
+    }
+
+    #[test]
+    fn test_first_link_wins() {
+        let mut feed = ParsedFeed::default();
+        handle_link("prev-archive", "http://example.com/1", &mut feed);
+        handle_link("prev-archive", "http://example.com/0", &mut feed);
+        assert_eq!(
+            feed.history.unwrap().prev_archive.as_deref(),
+            Some("http://example.com/1")
+        );
+    }
+}
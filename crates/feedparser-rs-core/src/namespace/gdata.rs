@@ -0,0 +1,436 @@
+//! Google Data (`GData`) namespace support
+//!
+//! Handles the `gd:` "kinds" elements that Google-originated Atom feeds
+//! (Calendar, Blogger, Picasa, `YouTube`) attach to entries. All of them
+//! carry their data in attributes.
+//!
+//! # Supported Elements
+//!
+//! - `gd:when` - Event time span (`startTime`, `endTime`, `valueString`)
+//! - `gd:where` - Event location (`valueString`, `label`, `rel`)
+//! - `gd:rating` - Rating with bounds and rater count
+//! - `gd:extendedProperty` - Application-defined `name`/`value` pair
+//!
+//! Child elements such as `gd:reminder` and XML-valued extended properties
+//! are skipped.
+//!
+//! # Specification
+//!
+//! `GData` kinds: <https://developers.google.com/gdata/docs/2.0/elements>
+
+use crate::limits::ParserLimits;
+use crate::types::Entry;
+use crate::types::generics::{FromAttributes, LimitedCollectionExt};
+use crate::util::date::parse_date;
+use crate::util::entities::decode_attribute;
+use chrono::{DateTime, Utc};
+use quick_xml::events::attributes::Attribute;
+
+/// `GData` namespace URI
+pub const GDATA: &str = "http://schemas.google.com/g/2005";
+
+/// Time span from `gd:when`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GdWhen {
+    /// Start of the event; a date without time marks an all-day event
+    pub start_time: Option<DateTime<Utc>>,
+    /// End of the event, if given
+    pub end_time: Option<DateTime<Utc>>,
+    /// Human-readable description of the time span
+    pub value_string: Option<String>,
+}
+
+/// Location from `gd:where`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GdWhere {
+    /// Human-readable location, e.g. `"Conference Room 2"`
+    pub value_string: Option<String>,
+    /// Label distinguishing several locations of one entry
+    pub label: Option<String>,
+    /// Relation of the location to the entry, e.g.
+    /// `http://schemas.google.com/g/2005#event`
+    pub rel: Option<String>,
+}
+
+/// Rating from `gd:rating`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GdRating {
+    /// Rating given by the feed author
+    pub value: Option<u32>,
+    /// Lowest possible rating
+    pub min: Option<u32>,
+    /// Highest possible rating
+    pub max: Option<u32>,
+    /// Average of all ratings
+    pub average: Option<f64>,
+    /// Number of ratings behind `average`
+    pub num_raters: Option<u32>,
+    /// Aspect being rated, e.g. `http://schemas.google.com/g/2005#price`
+    pub rel: Option<String>,
+}
+
+impl GdRating {
+    /// Returns the average rating scaled to `0.0..=1.0` between `min` and `max`
+    ///
+    /// Falls back to `value` when no average is given. Returns `None`
+    /// without both bounds or when `max` is not above `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::namespace::gdata::GdRating;
+    ///
+    /// let rating = GdRating {
+    ///     min: Some(1),
+    ///     max: Some(5),
+    ///     average: Some(4.0),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(rating.normalized(), Some(0.75));
+    /// ```
+    #[must_use]
+    pub fn normalized(&self) -> Option<f64> {
+        let (min, max) = (f64::from(self.min?), f64::from(self.max?));
+        let score = self.average.or_else(|| self.value.map(f64::from))?;
+        (max > min).then(|| ((score - min) / (max - min)).clamp(0.0, 1.0))
+    }
+}
+
+/// Application-defined property from `gd:extendedProperty`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GdExtendedProperty {
+    /// Property name
+    pub name: String,
+    /// Property value; `None` for XML-valued properties
+    pub value: Option<String>,
+}
+
+/// `GData` elements of an entry
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GDataMeta {
+    /// Time spans (`gd:when`)
+    pub when: Vec<GdWhen>,
+    /// Locations (`gd:where`)
+    pub locations: Vec<GdWhere>,
+    /// Rating (`gd:rating`)
+    pub rating: Option<GdRating>,
+    /// Extended properties (`gd:extendedProperty`)
+    pub extended_properties: Vec<GdExtendedProperty>,
+}
+
+impl GDataMeta {
+    /// Returns the value of the first extended property named `name`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::namespace::gdata::{GDataMeta, GdExtendedProperty};
+    ///
+    /// let gdata = GDataMeta {
+    ///     extended_properties: vec![GdExtendedProperty {
+    ///         name: "color".to_string(),
+    ///         value: Some("blue".to_string()),
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(gdata.extended_property("color"), Some("blue"));
+    /// assert_eq!(gdata.extended_property("size"), None);
+    /// ```
+    #[must_use]
+    pub fn extended_property(&self, name: &str) -> Option<&str> {
+        self.extended_properties
+            .iter()
+            .find(|p| p.name == name)
+            .and_then(|p| p.value.as_deref())
+    }
+
+    /// Returns the start time of the first `gd:when`
+    #[must_use]
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.when.iter().find_map(|w| w.start_time)
+    }
+
+    /// Returns the text of the first `gd:where` that has one
+    #[must_use]
+    pub fn location(&self) -> Option<&str> {
+        self.locations
+            .iter()
+            .find_map(|w| w.value_string.as_deref())
+    }
+}
+
+impl FromAttributes for GdWhen {
+    fn from_attributes<'a, I>(attrs: I, max_attr_length: usize) -> Option<Self>
+    where
+        I: Iterator<Item = Attribute<'a>>,
+    {
+        let mut when = Self::default();
+
+        for attr in attrs {
+            if attr.value.len() > max_attr_length {
+                continue;
+            }
+
+            match attr.key.as_ref() {
+                b"startTime" => when.start_time = parse_date(&decode_attribute(&attr.value)),
+                b"endTime" => when.end_time = parse_date(&decode_attribute(&attr.value)),
+                b"valueString" => when.value_string = Some(decode_attribute(&attr.value)),
+                _ => {}
+            }
+        }
+
+        when.start_time.is_some().then_some(when)
+    }
+}
+
+impl FromAttributes for GdWhere {
+    fn from_attributes<'a, I>(attrs: I, max_attr_length: usize) -> Option<Self>
+    where
+        I: Iterator<Item = Attribute<'a>>,
+    {
+        let mut place = Self::default();
+
+        for attr in attrs {
+            if attr.value.len() > max_attr_length {
+                continue;
+            }
+
+            match attr.key.as_ref() {
+                b"valueString" => place.value_string = Some(decode_attribute(&attr.value)),
+                b"label" => place.label = Some(decode_attribute(&attr.value)),
+                b"rel" => place.rel = Some(decode_attribute(&attr.value)),
+                _ => {}
+            }
+        }
+
+        (place != Self::default()).then_some(place)
+    }
+}
+
+impl FromAttributes for GdRating {
+    fn from_attributes<'a, I>(attrs: I, max_attr_length: usize) -> Option<Self>
+    where
+        I: Iterator<Item = Attribute<'a>>,
+    {
+        let mut rating = Self::default();
+
+        for attr in attrs {
+            if attr.value.len() > max_attr_length {
+                continue;
+            }
+
+            let value = decode_attribute(&attr.value);
+            match attr.key.as_ref() {
+                b"value" => rating.value = value.trim().parse().ok(),
+                b"min" => rating.min = value.trim().parse().ok(),
+                b"max" => rating.max = value.trim().parse().ok(),
+                b"average" => {
+                    rating.average = value.trim().parse().ok().filter(|a: &f64| a.is_finite());
+                }
+                b"numRaters" => rating.num_raters = value.trim().parse().ok(),
+                b"rel" => rating.rel = Some(value),
+                _ => {}
+            }
+        }
+
+        (rating.value.is_some() || rating.average.is_some()).then_some(rating)
+    }
+}
+
+impl FromAttributes for GdExtendedProperty {
+    fn from_attributes<'a, I>(attrs: I, max_attr_length: usize) -> Option<Self>
+    where
+        I: Iterator<Item = Attribute<'a>>,
+    {
+        let mut name = None;
+        let mut value = None;
+
+        for attr in attrs {
+            if attr.value.len() > max_attr_length {
+                continue;
+            }
+
+            match attr.key.as_ref() {
+                b"name" => name = Some(decode_attribute(&attr.value)),
+                b"value" => value = Some(decode_attribute(&attr.value)),
+                _ => {}
+            }
+        }
+
+        name.filter(|n| !n.is_empty())
+            .map(|name| Self { name, value })
+    }
+}
+
+/// Handle `GData` element at entry level
+///
+/// Repeated elements are bounded by [`ParserLimits::max_tags`], like
+/// categories. The caller skips any element content.
+///
+/// # Arguments
+///
+/// * `element` - Local name of the element (without namespace prefix)
+/// * `attrs` - Attributes of the element
+/// * `entry` - Entry to update
+/// * `limits` - Parser limits for bounded collections
+///
+/// # Returns
+///
+/// `true` if element was recognized and handled, `false` otherwise
+pub fn handle_entry_element<'a, I>(
+    element: &str,
+    attrs: I,
+    entry: &mut Entry,
+    limits: &ParserLimits,
+) -> bool
+where
+    I: Iterator<Item = Attribute<'a>>,
+{
+    let max_len = limits.max_attribute_length;
+    match element {
+        "when" => {
+            if let Some(when) = GdWhen::from_attributes(attrs, max_len) {
+                gdata_mut(entry)
+                    .when
+                    .try_push_limited(when, limits.max_tags);
+            }
+        }
+        "where" => {
+            if let Some(place) = GdWhere::from_attributes(attrs, max_len) {
+                gdata_mut(entry)
+                    .locations
+                    .try_push_limited(place, limits.max_tags);
+            }
+        }
+        "rating" => {
+            if let Some(rating) = GdRating::from_attributes(attrs, max_len) {
+                gdata_mut(entry).rating = Some(rating);
+            }
+        }
+        "extendedProperty" => {
+            if let Some(property) = GdExtendedProperty::from_attributes(attrs, max_len) {
+                gdata_mut(entry)
+                    .extended_properties
+                    .try_push_limited(property, limits.max_tags);
+            }
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn gdata_mut(entry: &mut Entry) -> &mut GDataMeta {
+    entry
+        .gdata
+        .get_or_insert_with(|| Box::new(GDataMeta::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::events::BytesStart;
+
+    fn handle(xml: &str, entry: &mut Entry) -> bool {
+        let (name, _) = xml.split_once(' ').unwrap_or((xml, ""));
+        let start = BytesStart::from_content(xml, name.len());
+        let local = name.trim_start_matches("gd:");
+        handle_entry_element(
+            local,
+            start.attributes().flatten(),
+            entry,
+            &ParserLimits::default(),
+        )
+    }
+
+    #[test]
+    fn test_handle_when() {
+        let mut entry = Entry::default();
+        handle(
+            r#"gd:when startTime="2024-06-01T09:00:00-07:00" endTime="2024-06-01T10:00:00-07:00""#,
+            &mut entry,
+        );
+        handle(r#"gd:when startTime="2024-06-02""#, &mut entry);
+        handle(r#"gd:when valueString="someday""#, &mut entry);
+
+        let gdata = entry.gdata.unwrap();
+        assert_eq!(gdata.when.len(), 2);
+        assert_eq!(
+            gdata.start_time().map(|t| t.to_rfc3339()).as_deref(),
+            Some("2024-06-01T16:00:00+00:00")
+        );
+        assert!(gdata.when[0].end_time.is_some());
+        assert!(gdata.when[1].end_time.is_none());
+    }
+
+    #[test]
+    fn test_handle_where() {
+        let mut entry = Entry::default();
+        handle(
+            r#"gd:where valueString="Room 2" rel="http://schemas.google.com/g/2005#event""#,
+            &mut entry,
+        );
+        handle("gd:where", &mut entry);
+
+        let gdata = entry.gdata.unwrap();
+        assert_eq!(gdata.locations.len(), 1);
+        assert_eq!(gdata.location(), Some("Room 2"));
+    }
+
+    #[test]
+    fn test_handle_rating() {
+        let mut entry = Entry::default();
+        handle(
+            r#"gd:rating min="1" max="5" numRaters="12" average="3.5""#,
+            &mut entry,
+        );
+
+        let rating = entry.gdata.unwrap().rating.unwrap();
+        assert_eq!(rating.num_raters, Some(12));
+        assert_eq!(rating.average, Some(3.5));
+        assert_eq!(rating.value, None);
+        assert_eq!(rating.normalized(), Some(0.625));
+    }
+
+    #[test]
+    fn test_handle_rating_invalid() {
+        let mut entry = Entry::default();
+        handle(r#"gd:rating min="1" max="5" average="NaN""#, &mut entry);
+        assert!(entry.gdata.is_none());
+
+        let rating = GdRating {
+            value: Some(3),
+            min: Some(5),
+            max: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(rating.normalized(), None);
+    }
+
+    #[test]
+    fn test_handle_extended_property() {
+        let mut entry = Entry::default();
+        handle(
+            r#"gd:extendedProperty name="color" value="blue""#,
+            &mut entry,
+        );
+        handle(r#"gd:extendedProperty name="payload""#, &mut entry);
+        handle(r#"gd:extendedProperty value="orphan""#, &mut entry);
+
+        let gdata = entry.gdata.unwrap();
+        assert_eq!(gdata.extended_properties.len(), 2);
+        assert_eq!(gdata.extended_property("color"), Some("blue"));
+        assert_eq!(gdata.extended_property("payload"), None);
+    }
+
+    #[test]
+    fn test_handle_unknown_element() {
+        let mut entry = Entry::default();
+        assert!(!handle(r#"gd:email address="a@example.com""#, &mut entry));
+        assert!(entry.gdata.is_none());
+    }
+}
@@ -23,6 +23,8 @@ pub const GEORSS: &str = "http://www.georss.org/georss";
 
 /// Type of geographic shape
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum GeoType {
     /// Single point (latitude, longitude)
     #[default]
@@ -37,6 +39,7 @@ pub enum GeoType {
 
 /// Geographic location data from `GeoRSS`
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeoLocation {
     /// Type of geographic shape
     pub geo_type: GeoType,
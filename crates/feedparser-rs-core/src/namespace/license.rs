@@ -0,0 +1,345 @@
+/// License declarations from every namespace that carries one
+///
+/// Elements:
+/// - `cc:license` (`rdf:resource` attribute) and `creativeCommons:license`
+///   (text) from the Creative Commons namespaces
+/// - `podcast:license` (license name as text, `url` attribute)
+/// - `dcterms:license` (`rdf:resource` attribute or text)
+/// - bare `license` elements
+///
+/// Together with Atom `rel="license"` links, these are merged into one
+/// `license_detail` per feed and entry, and `license` holds its URL, or its
+/// name when no URL was given. The first source to provide a URL or a name
+/// keeps it.
+use crate::types::LicenseInfo;
+
+/// DCMI Metadata Terms namespace URI
+pub const DC_TERMS_NAMESPACE: &str = "http://purl.org/dc/terms/";
+
+/// Creative Commons license kinds, as they appear in license URLs
+const CC_KINDS: [&str; 6] = ["by", "by-sa", "by-nd", "by-nc", "by-nc-sa", "by-nc-nd"];
+
+/// Lowercase license names and URL slugs with their SPDX identifiers
+const SPDX_ALIASES: &[(&str, &str)] = &[
+    ("mit", "MIT"),
+    ("apache-2", "Apache-2.0"),
+    ("apache-2.0", "Apache-2.0"),
+    ("bsd-2-clause", "BSD-2-Clause"),
+    ("bsd-3-clause", "BSD-3-Clause"),
+    ("isc", "ISC"),
+    ("mpl-2.0", "MPL-2.0"),
+    ("gpl-2.0", "GPL-2.0-only"),
+    ("gpl-2.0-only", "GPL-2.0-only"),
+    ("gpl-2.0-or-later", "GPL-2.0-or-later"),
+    ("gpl-3.0", "GPL-3.0-only"),
+    ("gpl-3.0-only", "GPL-3.0-only"),
+    ("gpl-3.0-or-later", "GPL-3.0-or-later"),
+    ("lgpl-2.1", "LGPL-2.1-only"),
+    ("lgpl-2.1-only", "LGPL-2.1-only"),
+    ("lgpl-2.1-or-later", "LGPL-2.1-or-later"),
+    ("lgpl-3.0", "LGPL-3.0-only"),
+    ("lgpl-3.0-only", "LGPL-3.0-only"),
+    ("lgpl-3.0-or-later", "LGPL-3.0-or-later"),
+    ("agpl-3.0", "AGPL-3.0-only"),
+    ("agpl-3.0-only", "AGPL-3.0-only"),
+    ("agpl-3.0-or-later", "AGPL-3.0-or-later"),
+    ("fdl-1.3", "GFDL-1.3-only"),
+    ("gfdl-1.3", "GFDL-1.3-only"),
+    ("unlicense", "Unlicense"),
+    ("wtfpl", "WTFPL"),
+    ("odbl-1.0", "ODbL-1.0"),
+    ("pddl-1.0", "PDDL-1.0"),
+    ("cc0", "CC0-1.0"),
+    ("cc0-1.0", "CC0-1.0"),
+];
+
+/// Whether `tag` is an element declaring a license
+#[inline]
+#[must_use]
+pub fn is_license_tag(tag: &[u8]) -> bool {
+    matches!(
+        tag,
+        b"license"
+            | b"cc:license"
+            | b"creativeCommons:license"
+            | b"podcast:license"
+            | b"dcterms:license"
+    )
+}
+
+/// License declared by a license element
+///
+/// `podcast:license` names the license in its text and links to it with a
+/// `url` attribute. The other elements point at the license with an
+/// `rdf:resource` attribute or give it as text, which is taken as a URL when
+/// it looks like one and as a name otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::namespace::license::license_from_element;
+///
+/// let attrs = vec![(b"url".to_vec(), "https://example.com/license".to_string())];
+/// let license = license_from_element(b"podcast:license", &attrs, "my-license-v1");
+/// assert_eq!(license.url.as_deref(), Some("https://example.com/license"));
+/// assert_eq!(license.name.as_deref(), Some("my-license-v1"));
+///
+/// let attrs = vec![(
+///     b"rdf:resource".to_vec(),
+///     "http://creativecommons.org/licenses/by/4.0/".to_string(),
+/// )];
+/// let license = license_from_element(b"cc:license", &attrs, "");
+/// assert_eq!(license.spdx_guess.as_deref(), Some("CC-BY-4.0"));
+/// ```
+#[must_use]
+pub fn license_from_element(tag: &[u8], attrs: &[(Vec<u8>, String)], text: &str) -> LicenseInfo {
+    let attr = |matches: fn(&[u8]) -> bool| {
+        attrs
+            .iter()
+            .find(|(name, value)| matches(name) && !value.trim().is_empty())
+            .map(|(_, value)| value.trim().to_string())
+    };
+    let text = text.trim();
+    let text = (!text.is_empty()).then(|| text.to_string());
+
+    let (url, name) = if tag == b"podcast:license" {
+        (attr(|name| name == b"url"), text)
+    } else if let Some(resource) = attr(|name| name == b"resource" || name.ends_with(b":resource"))
+    {
+        (Some(resource), text)
+    } else if text.as_deref().is_some_and(looks_like_url) {
+        (text, None)
+    } else {
+        (None, text)
+    };
+
+    let spdx_guess = guess(url.as_deref(), name.as_deref());
+    LicenseInfo {
+        url,
+        name,
+        spdx_guess,
+    }
+}
+
+/// Merge a newly found license into the one collected so far
+///
+/// Fields already set are kept, so the first source to provide a URL or a
+/// name wins. `license` is updated to the merged URL, or the name when there
+/// is no URL.
+pub(crate) fn merge(
+    detail: &mut Option<LicenseInfo>,
+    license: &mut Option<String>,
+    found: LicenseInfo,
+) {
+    if found.url.is_none() && found.name.is_none() {
+        return;
+    }
+    let detail = detail.get_or_insert_with(LicenseInfo::default);
+    if detail.url.is_none() {
+        detail.url = found.url;
+    }
+    if detail.name.is_none() {
+        detail.name = found.name;
+    }
+    detail.spdx_guess = guess(detail.url.as_deref(), detail.name.as_deref());
+    *license = detail.url.clone().or_else(|| detail.name.clone());
+}
+
+/// SPDX guess from a license URL, falling back to its name
+fn guess(url: Option<&str>, name: Option<&str>) -> Option<String> {
+    url.and_then(guess_spdx)
+        .or_else(|| name.and_then(guess_spdx))
+}
+
+/// Guess the SPDX identifier of a license from its URL or name
+///
+/// Recognizes Creative Commons license and CC0 URLs, license pages on
+/// `opensource.org`, `spdx.org` and `gnu.org`, and common license names and
+/// identifiers such as `CC BY-SA 4.0`, `MIT` or `GPL-3.0`. Identifiers
+/// without an explicit `-only` or `-or-later` are taken as `-only`.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::namespace::license::guess_spdx;
+///
+/// assert_eq!(
+///     guess_spdx("https://creativecommons.org/licenses/by-nc-sa/4.0/").as_deref(),
+///     Some("CC-BY-NC-SA-4.0")
+/// );
+/// assert_eq!(
+///     guess_spdx("http://creativecommons.org/publicdomain/zero/1.0/").as_deref(),
+///     Some("CC0-1.0")
+/// );
+/// assert_eq!(guess_spdx("https://www.gnu.org/licenses/gpl-3.0.html").as_deref(), Some("GPL-3.0-only"));
+/// assert_eq!(guess_spdx("CC BY 4.0").as_deref(), Some("CC-BY-4.0"));
+/// assert_eq!(guess_spdx("mit").as_deref(), Some("MIT"));
+/// assert_eq!(guess_spdx("All rights reserved"), None);
+/// ```
+#[must_use]
+pub fn guess_spdx(license: &str) -> Option<String> {
+    let license = license.trim();
+    let Some(location) = strip_scheme(license) else {
+        return guess_from_name(license);
+    };
+    let location = location.strip_prefix("www.").unwrap_or(location);
+    let (host, path) = location.split_once('/').unwrap_or((location, ""));
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+    match host.to_ascii_lowercase().as_str() {
+        "creativecommons.org" => match (segments.next()?, segments.next()?, segments.next()?) {
+            ("licenses", kind, version) if CC_KINDS.contains(&kind) && is_cc_version(version) => {
+                Some(format!("CC-{}-{version}", kind.to_ascii_uppercase()))
+            }
+            ("publicdomain", "zero", "1.0") => Some("CC0-1.0".to_string()),
+            _ => None,
+        },
+        "opensource.org" | "spdx.org" | "gnu.org" => {
+            let slug = segments.next_back()?;
+            let slug = [".html", ".txt", ".php"]
+                .iter()
+                .find_map(|ext| slug.strip_suffix(ext))
+                .unwrap_or(slug);
+            let slug = slug.strip_suffix(".en").unwrap_or(slug);
+            guess_from_name(slug)
+        }
+        _ => None,
+    }
+}
+
+/// SPDX identifier for a license name such as `CC BY-SA 4.0` or `apache 2.0`
+fn guess_from_name(name: &str) -> Option<String> {
+    let normalized = name
+        .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_ascii_lowercase();
+
+    if let Some((kind, version)) = normalized
+        .strip_prefix("cc-")
+        .and_then(|rest| rest.rsplit_once('-'))
+        && CC_KINDS.contains(&kind)
+        && is_cc_version(version)
+    {
+        return Some(format!("CC-{}-{version}", kind.to_ascii_uppercase()));
+    }
+
+    SPDX_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == normalized)
+        .map(|(_, spdx)| (*spdx).to_string())
+}
+
+/// Whether `version` is a published Creative Commons license version
+fn is_cc_version(version: &str) -> bool {
+    matches!(version, "1.0" | "2.0" | "2.5" | "3.0" | "4.0")
+}
+
+/// `value` without its `http://` or `https://` scheme, if it has one
+fn strip_scheme(value: &str) -> Option<&str> {
+    let (scheme, rest) = value.split_once("://")?;
+    (scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")).then_some(rest)
+}
+
+/// Whether license text is a URL rather than a name
+fn looks_like_url(text: &str) -> bool {
+    text.contains("://") || text.starts_with("urn:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_spdx() {
+        for (license, spdx) in [
+            ("http://creativecommons.org/licenses/by/4.0/", "CC-BY-4.0"),
+            (
+                "https://creativecommons.org/licenses/by-sa/3.0/us/",
+                "CC-BY-SA-3.0",
+            ),
+            (
+                "https://creativecommons.org/licenses/by-nd/2.5",
+                "CC-BY-ND-2.5",
+            ),
+            ("https://opensource.org/licenses/MIT", "MIT"),
+            ("https://spdx.org/licenses/Apache-2.0.html", "Apache-2.0"),
+            (
+                "https://www.gnu.org/licenses/old-licenses/gpl-2.0.en.html",
+                "GPL-2.0-only",
+            ),
+            ("cc-by-nc-nd-4.0", "CC-BY-NC-ND-4.0"),
+            ("CC0", "CC0-1.0"),
+            ("Apache 2", "Apache-2.0"),
+            ("GPL-3.0-or-later", "GPL-3.0-or-later"),
+        ] {
+            assert_eq!(guess_spdx(license).as_deref(), Some(spdx), "{license}");
+        }
+        for license in [
+            "https://creativecommons.org/licenses/by/9.9/",
+            "https://creativecommons.org/publicdomain/mark/1.0/",
+            "https://example.com/licenses/mit",
+            "cc-by-sa",
+            "",
+        ] {
+            assert_eq!(guess_spdx(license), None, "{license}");
+        }
+    }
+
+    #[test]
+    fn test_license_from_element() {
+        let license = license_from_element(
+            b"creativeCommons:license",
+            &[],
+            " http://creativecommons.org/licenses/by-sa/3.0/ ",
+        );
+        assert_eq!(
+            license.url.as_deref(),
+            Some("http://creativecommons.org/licenses/by-sa/3.0/")
+        );
+        assert_eq!(license.name, None);
+
+        let license = license_from_element(b"license", &[], "All rights reserved");
+        assert_eq!(license.url, None);
+        assert_eq!(license.name.as_deref(), Some("All rights reserved"));
+        assert_eq!(license.spdx_guess, None);
+
+        let license = license_from_element(b"podcast:license", &[], "cc-by-4.0");
+        assert_eq!(license.url, None);
+        assert_eq!(license.spdx_guess.as_deref(), Some("CC-BY-4.0"));
+    }
+
+    #[test]
+    fn test_merge_keeps_first_url_and_name() {
+        let mut detail = None;
+        let mut license = None;
+
+        merge(
+            &mut detail,
+            &mut license,
+            license_from_element(b"podcast:license", &[], "cc-by-4.0"),
+        );
+        assert_eq!(license.as_deref(), Some("cc-by-4.0"));
+
+        let url = "https://creativecommons.org/licenses/by/4.0/";
+        merge(
+            &mut detail,
+            &mut license,
+            license_from_element(b"cc:license", &[], url),
+        );
+        merge(
+            &mut detail,
+            &mut license,
+            license_from_element(b"license", &[], "https://example.com/other"),
+        );
+        merge(&mut detail, &mut license, LicenseInfo::default());
+
+        let detail = detail.unwrap();
+        assert_eq!(license.as_deref(), Some(url));
+        assert_eq!(detail.url.as_deref(), Some(url));
+        assert_eq!(detail.name.as_deref(), Some("cc-by-4.0"));
+        assert_eq!(detail.spdx_guess.as_deref(), Some("CC-BY-4.0"));
+    }
+}
@@ -7,8 +7,10 @@
 /// used in video/audio feeds and podcasts.
 ///
 /// Common elements:
-/// - `media:content` → enclosures
-/// - `media:thumbnail` → (could add thumbnails field)
+/// - `media:content` → `media_content` (and enclosures)
+/// - `media:thumbnail` → `media_thumbnails`, and the thumbnails of the
+///   enclosing `media:content` or `media:group`
+/// - `media:group` → renditions sharing a `MediaContent::group` index
 /// - `media:title` → title (fallback)
 /// - `media:description` → summary (fallback)
 /// - `media:keywords` → tags (comma-separated)
@@ -57,6 +59,7 @@ pub const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
 /// assert_eq!(content.url, "https://example.com/video.mp4");
 /// ```
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub struct MediaContent {
     /// URL of the media object (url attribute)
@@ -116,6 +119,7 @@ pub struct MediaContent {
 /// assert_eq!(thumbnail.url, "https://example.com/thumb.jpg");
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaThumbnail {
     /// URL of the thumbnail image (url attribute)
     ///
@@ -8,6 +8,11 @@
 /// - **Media RSS** (`media:`) - Multimedia content
 /// - **GeoRSS** (`georss:`) - Geographic location data
 /// - **Creative Commons** (`cc:`) - License information
+/// - **Feed History** (`fh:`) - Archived feeds (RFC 5005)
+/// - **PRISM** (`prism:`) - Journal publishing metadata
+/// - **GData** (`gd:`) - Event times, places and ratings in Google feeds
+/// - **Activity Streams** (`activity:`) - Verbs and objects in social feeds
+/// - **blogChannel** (`blogChannel:`) - Blogroll and subscription lists
 ///
 /// # Usage
 ///
@@ -18,25 +23,42 @@
 /// # Example
 ///
 /// ```
-/// use feedparser_rs::namespace::dublin_core;
+/// use feedparser_rs::namespace::blog_channel;
 /// use feedparser_rs::FeedMeta;
 ///
 /// let mut feed = FeedMeta::default();
-/// dublin_core::handle_feed_element("creator", "John Doe", &mut feed);
-/// assert_eq!(feed.author.as_deref(), Some("John Doe"));
+/// blog_channel::handle_feed_element("blogRoll", "https://example.com/roll.opml", &mut feed);
+/// assert!(feed.blog_channel.is_some());
 /// ```
+/// Activity Streams Atom extension
+pub mod activity;
+/// blogChannel RSS module
+pub mod blog_channel;
 /// Creative Commons license information
 pub mod cc;
 /// Content Module for RSS 1.0
 pub mod content;
+#[cfg(feature = "dublincore")]
 /// Dublin Core Metadata Element Set
 pub mod dublin_core;
+/// Feed Paging and Archiving (RFC 5005)
+pub mod feed_history;
+/// Google Data (`GData`) kinds
+pub mod gdata;
+#[cfg(feature = "georss")]
 /// GeoRSS geographic location data
 pub mod georss;
+/// License declarations merged across namespaces
+pub mod license;
+#[cfg(feature = "mediarss")]
 /// Media RSS specification
 pub mod media_rss;
+/// PRISM publishing metadata
+pub mod prism;
 /// Syndication Module for RSS 1.0
 pub mod syndication;
+/// Comment counts from Atom Threading Extensions and the Slash module
+pub mod threading;
 
 /// Common namespace URIs used in feeds
 pub mod namespaces {
@@ -75,6 +97,30 @@ pub mod namespaces {
 
     /// Creative Commons (legacy Userland)
     pub const CREATIVE_COMMONS: &str = "http://backend.userland.com/creativeCommonsRssModule";
+
+    /// Feed History (RFC 5005)
+    pub const FEED_HISTORY: &str = "http://purl.org/syndication/history/1.0";
+
+    /// PRISM 2.0 publishing metadata
+    pub const PRISM: &str = "http://prismstandard.org/namespaces/basic/2.0/";
+
+    /// Google Data (`GData`)
+    pub const GDATA: &str = "http://schemas.google.com/g/2005";
+
+    /// Activity Streams Atom extension
+    pub const ACTIVITY_STREAMS: &str = "http://activitystrea.ms/spec/1.0/";
+
+    /// blogChannel RSS module
+    pub const BLOG_CHANNEL: &str = "http://backend.userland.com/blogChannelModule";
+
+    /// Atom Threading Extensions (RFC 4685)
+    pub const THREADING: &str = "http://purl.org/syndication/thread/1.0";
+
+    /// Slash RSS module
+    pub const SLASH: &str = "http://purl.org/rss/1.0/modules/slash/";
+
+    /// DCMI Metadata Terms
+    pub const DC_TERMS: &str = "http://purl.org/dc/terms/";
 }
 
 /// Get namespace URI for a common prefix
@@ -99,6 +145,14 @@ pub fn get_namespace_uri(prefix: &str) -> Option<&'static str> {
         "georss" => Some(namespaces::GEORSS),
         "cc" => Some(namespaces::CC),
         "creativeCommons" => Some(namespaces::CREATIVE_COMMONS),
+        "fh" => Some(namespaces::FEED_HISTORY),
+        "prism" => Some(namespaces::PRISM),
+        "gd" => Some(namespaces::GDATA),
+        "activity" => Some(namespaces::ACTIVITY_STREAMS),
+        "blogChannel" => Some(namespaces::BLOG_CHANNEL),
+        "thr" => Some(namespaces::THREADING),
+        "slash" => Some(namespaces::SLASH),
+        "dcterms" => Some(namespaces::DC_TERMS),
         _ => None,
     }
 }
@@ -125,6 +179,14 @@ pub fn get_namespace_prefix(uri: &str) -> Option<&'static str> {
         namespaces::GEORSS => Some("georss"),
         namespaces::CC => Some("cc"),
         namespaces::CREATIVE_COMMONS => Some("creativeCommons"),
+        namespaces::FEED_HISTORY => Some("fh"),
+        namespaces::PRISM => Some("prism"),
+        namespaces::GDATA => Some("gd"),
+        namespaces::ACTIVITY_STREAMS => Some("activity"),
+        namespaces::BLOG_CHANNEL => Some("blogChannel"),
+        namespaces::THREADING => Some("thr"),
+        namespaces::SLASH => Some("slash"),
+        namespaces::DC_TERMS => Some("dcterms"),
         _ => None,
     }
 }
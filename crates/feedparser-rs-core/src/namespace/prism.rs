@@ -0,0 +1,176 @@
+/// PRISM (Publishing Requirements for Industry Standard Metadata)
+///
+/// Namespace: <http://prismstandard.org/namespaces/basic/2.0/>
+/// Prefix: prism
+///
+/// This module provides parsing support for the PRISM elements that
+/// journal and other academic publishers add to their table-of-contents
+/// feeds to identify where an article appeared.
+///
+/// Elements:
+/// - `prism:publicationName` → Journal or magazine title
+/// - `prism:volume` → Volume
+/// - `prism:number` → Issue number
+/// - `prism:startingPage` → First page of the article
+/// - `prism:doi` → Digital Object Identifier
+/// - `prism:publicationDate` → Publication date
+use crate::types::Entry;
+use crate::util::date::parse_date;
+use chrono::{DateTime, Utc};
+
+/// PRISM 2.0 namespace URI
+pub const PRISM_NAMESPACE: &str = "http://prismstandard.org/namespaces/basic/2.0/";
+
+/// PRISM publishing metadata for an entry
+///
+/// Volume, issue and page are kept as written since publishers use values
+/// such as `"12-13"`, `"S1"` or `"e1001"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrismMeta {
+    /// Title of the journal or magazine (`prism:publicationName`)
+    pub publication_name: Option<String>,
+    /// Volume (`prism:volume`)
+    pub volume: Option<String>,
+    /// Issue number (`prism:number`)
+    pub number: Option<String>,
+    /// First page of the article (`prism:startingPage`)
+    pub starting_page: Option<String>,
+    /// Digital Object Identifier without a `doi:` or resolver prefix (`prism:doi`)
+    pub doi: Option<String>,
+    /// Publication date (`prism:publicationDate`)
+    pub publication_date: Option<DateTime<Utc>>,
+}
+
+impl PrismMeta {
+    /// Returns the `https://doi.org/` URL for the DOI, if present
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::PrismMeta;
+    ///
+    /// let prism = PrismMeta {
+    ///     doi: Some("10.1038/s41586-024-0001-x".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     prism.doi_url().as_deref(),
+    ///     Some("https://doi.org/10.1038/s41586-024-0001-x")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn doi_url(&self) -> Option<String> {
+        self.doi
+            .as_ref()
+            .map(|doi| format!("https://doi.org/{doi}"))
+    }
+}
+
+/// Handle PRISM namespace element at entry level
+///
+/// Empty values and unparseable dates are ignored.
+///
+/// # Arguments
+///
+/// * `element` - Local name of the element (without namespace prefix)
+/// * `text` - Text content of the element
+/// * `entry` - Entry to update
+pub fn handle_entry_element(element: &str, text: &str, entry: &mut Entry) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    match element {
+        "publicationName" => prism_mut(entry).publication_name = Some(text.to_string()),
+        "volume" => prism_mut(entry).volume = Some(text.to_string()),
+        "number" => prism_mut(entry).number = Some(text.to_string()),
+        "startingPage" => prism_mut(entry).starting_page = Some(text.to_string()),
+        "doi" => prism_mut(entry).doi = Some(normalize_doi(text).to_string()),
+        "publicationDate" => {
+            if let Some(dt) = parse_date(text) {
+                prism_mut(entry).publication_date = Some(dt);
+            }
+        }
+        _ => {
+            // Ignore unknown PRISM elements
+        }
+    }
+}
+
+fn prism_mut(entry: &mut Entry) -> &mut PrismMeta {
+    entry
+        .prism
+        .get_or_insert_with(|| Box::new(PrismMeta::default()))
+}
+
+/// Strips the `doi:` scheme or a resolver URL some publishers put in front
+fn normalize_doi(text: &str) -> &str {
+    const PREFIXES: [&str; 4] = [
+        "doi:",
+        "https://doi.org/",
+        "http://doi.org/",
+        "http://dx.doi.org/",
+    ];
+    PREFIXES
+        .iter()
+        .find_map(|prefix| {
+            text.get(..prefix.len())
+                .filter(|head| head.eq_ignore_ascii_case(prefix))
+                .map(|_| text[prefix.len()..].trim_start())
+        })
+        .unwrap_or(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    #[test]
+    fn test_handle_entry_elements() {
+        let mut entry = Entry::default();
+
+        handle_entry_element("publicationName", "Nature", &mut entry);
+        handle_entry_element("volume", "627", &mut entry);
+        handle_entry_element("number", "8003", &mut entry);
+        handle_entry_element("startingPage", "S1", &mut entry);
+        handle_entry_element("doi", "10.1038/d41586-024-00001-1", &mut entry);
+        handle_entry_element("publicationDate", "2024-03-14", &mut entry);
+
+        let prism = entry.prism.unwrap();
+        assert_eq!(prism.publication_name.as_deref(), Some("Nature"));
+        assert_eq!(prism.volume.as_deref(), Some("627"));
+        assert_eq!(prism.number.as_deref(), Some("8003"));
+        assert_eq!(prism.starting_page.as_deref(), Some("S1"));
+        assert_eq!(prism.doi.as_deref(), Some("10.1038/d41586-024-00001-1"));
+        let date = prism.publication_date.unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 3, 14));
+    }
+
+    #[test]
+    fn test_handle_doi_prefixes() {
+        for doi in [
+            "doi:10.1000/182",
+            "DOI: 10.1000/182",
+            "https://doi.org/10.1000/182",
+            "http://dx.doi.org/10.1000/182",
+        ] {
+            let mut entry = Entry::default();
+            handle_entry_element("doi", doi, &mut entry);
+            assert_eq!(entry.prism.unwrap().doi.as_deref(), Some("10.1000/182"));
+        }
+    }
+
+    #[test]
+    fn test_handle_invalid_and_unknown() {
+        let mut entry = Entry::default();
+
+        handle_entry_element("publicationDate", "not a date", &mut entry);
+        handle_entry_element("volume", "  ", &mut entry);
+        handle_entry_element("issueIdentifier", "x", &mut entry);
+
+        assert!(entry.prism.is_none());
+    }
+}
@@ -17,6 +17,8 @@ pub const SYNDICATION_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/syndica
 
 /// Valid update period values
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum UpdatePeriod {
     /// Update hourly
     Hourly,
@@ -60,7 +62,8 @@ impl UpdatePeriod {
 }
 
 /// Syndication metadata
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SyndicationMeta {
     /// Update period (hourly, daily, weekly, monthly, yearly)
     pub update_period: Option<UpdatePeriod>,
@@ -0,0 +1,72 @@
+/// Comment counts: Atom Threading Extensions (RFC 4685) and the Slash module
+///
+/// Namespaces: <http://purl.org/syndication/thread/1.0> (prefix `thr`) and
+/// <http://purl.org/rss/1.0/modules/slash/> (prefix `slash`)
+///
+/// Elements:
+/// - `thr:total` → `Entry::thr_total`
+/// - `slash:comments` → `Entry::slash_comments`
+///
+/// Attributes:
+/// - `thr:count` on a `rel="replies"` link → `Link::thr_count`
+///
+/// All three are combined into `Entry::comments_count` after parsing.
+use crate::types::Entry;
+
+/// Atom Threading Extensions namespace URI
+pub const THREADING_NAMESPACE: &str = "http://purl.org/syndication/thread/1.0";
+
+/// Slash module namespace URI
+pub const SLASH_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/slash/";
+
+/// Whether `tag` is an entry-level comment count element
+#[inline]
+#[must_use]
+pub fn is_count_tag(tag: &[u8]) -> bool {
+    matches!(tag, b"thr:total" | b"slash:comments")
+}
+
+/// Handle a comment count element at entry level
+///
+/// Counts that are not a non-negative integer are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::namespace::threading::handle_entry_element;
+/// use feedparser_rs::Entry;
+///
+/// let mut entry = Entry::default();
+/// handle_entry_element(b"slash:comments", " 42 ", &mut entry);
+/// assert_eq!(entry.slash_comments, Some(42));
+/// ```
+pub fn handle_entry_element(tag: &[u8], text: &str, entry: &mut Entry) {
+    let count = parse_count(text);
+    match tag {
+        b"thr:total" => entry.thr_total = count,
+        b"slash:comments" => entry.slash_comments = count,
+        _ => {}
+    }
+}
+
+/// Parse a comment count, tolerating surrounding whitespace
+#[inline]
+pub(crate) fn parse_count(text: &str) -> Option<u32> {
+    text.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_entry_element() {
+        let mut entry = Entry::default();
+        handle_entry_element(b"thr:total", "7", &mut entry);
+        handle_entry_element(b"slash:comments", "-1", &mut entry);
+        assert_eq!(entry.thr_total, Some(7));
+        assert_eq!(entry.slash_comments, None);
+        assert!(is_count_tag(b"slash:comments"));
+        assert!(!is_count_tag(b"slash:section"));
+    }
+}
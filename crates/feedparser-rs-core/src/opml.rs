@@ -0,0 +1,472 @@
+//! OPML subscription list parser
+//!
+//! Parses OPML 1.0 and 2.0 documents (as exported by feed readers) into a
+//! typed outline tree. Folders are outlines without an `xmlUrl` that contain
+//! child outlines; subscriptions are outlines with an `xmlUrl`.
+//!
+//! Like the feed parsers, this parser is tolerant: malformed XML sets the
+//! `bozo` flag and returns whatever was parsed up to that point.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::opml::parse_opml;
+//!
+//! let xml = br#"<?xml version="1.0"?>
+//! <opml version="2.0">
+//!   <head><title>My Subscriptions</title></head>
+//!   <body>
+//!     <outline text="Tech">
+//!       <outline type="rss" text="Example" xmlUrl="https://example.com/feed.xml"/>
+//!     </outline>
+//!   </body>
+//! </opml>"#;
+//!
+//! let opml = parse_opml(xml).unwrap();
+//! assert_eq!(opml.head.title.as_deref(), Some("My Subscriptions"));
+//! assert!(opml.outlines[0].is_folder());
+//!
+//! let feeds = opml.feeds();
+//! assert_eq!(feeds.len(), 1);
+//! assert_eq!(feeds[0].xml_url.as_deref(), Some("https://example.com/feed.xml"));
+//! ```
+
+use crate::parser::XmlReader;
+use crate::{
+    ParserLimits,
+    error::{FeedError, Result},
+    parser::common::{
+        EVENT_BUFFER_CAPACITY, check_depth, limited_attributes, read_text, skip_element,
+    },
+    util::{entities::decode_attribute, parse_date, text::truncate_to_length},
+};
+use chrono::{DateTime, Utc};
+use quick_xml::events::{BytesStart, Event};
+
+/// Parsed OPML document
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Opml {
+    /// Value of the `version` attribute on the root element (e.g., "2.0")
+    pub version: Option<String>,
+    /// Document metadata from `<head>`
+    pub head: OpmlHead,
+    /// Top-level outlines from `<body>`
+    pub outlines: Vec<Outline>,
+    /// True if parsing encountered errors
+    pub bozo: bool,
+    /// Description of parsing error (if bozo is true)
+    pub bozo_exception: Option<String>,
+}
+
+/// OPML `<head>` metadata
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpmlHead {
+    /// Document title
+    pub title: Option<String>,
+    /// Creation date
+    pub date_created: Option<DateTime<Utc>>,
+    /// Last modification date
+    pub date_modified: Option<DateTime<Utc>>,
+    /// Owner name
+    pub owner_name: Option<String>,
+    /// Owner email address
+    pub owner_email: Option<String>,
+    /// Owner profile URL (OPML 2.0)
+    pub owner_id: Option<String>,
+}
+
+/// A single `<outline>` element: either a folder or a subscription
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Outline {
+    /// Display text (`text` attribute, required by the spec)
+    pub text: Option<String>,
+    /// Title (`title` attribute, commonly set by feed readers)
+    pub title: Option<String>,
+    /// Outline type (`type` attribute, e.g., "rss", "link")
+    pub outline_type: Option<String>,
+    /// Feed URL (`xmlUrl` attribute)
+    pub xml_url: Option<String>,
+    /// Website URL (`htmlUrl` attribute)
+    pub html_url: Option<String>,
+    /// Description (`description` attribute)
+    pub description: Option<String>,
+    /// Language (`language` attribute)
+    pub language: Option<String>,
+    /// Feed format version (`version` attribute, e.g., "RSS2")
+    pub version: Option<String>,
+    /// Comma-separated categories (`category` attribute)
+    pub category: Option<String>,
+    /// Nested outlines
+    pub children: Vec<Self>,
+}
+
+impl Opml {
+    /// Returns all subscription outlines in document order, flattening folders
+    #[must_use]
+    pub fn feeds(&self) -> Vec<&Outline> {
+        let mut feeds = Vec::new();
+        collect_feeds(&self.outlines, &mut feeds);
+        feeds
+    }
+}
+
+impl Outline {
+    /// Returns true if this outline points to a feed
+    #[inline]
+    #[must_use]
+    pub const fn is_feed(&self) -> bool {
+        self.xml_url.is_some()
+    }
+
+    /// Returns true if this outline is a folder (no feed URL, has children)
+    #[inline]
+    #[must_use]
+    pub const fn is_folder(&self) -> bool {
+        self.xml_url.is_none() && !self.children.is_empty()
+    }
+
+    /// Returns the best display name: `title`, falling back to `text`
+    #[must_use]
+    pub fn display_name(&self) -> Option<&str> {
+        self.title.as_deref().or(self.text.as_deref())
+    }
+}
+
+fn collect_feeds<'a>(outlines: &'a [Outline], feeds: &mut Vec<&'a Outline>) {
+    for outline in outlines {
+        if outline.is_feed() {
+            feeds.push(outline);
+        }
+        collect_feeds(&outline.children, feeds);
+    }
+}
+
+/// Parse an OPML document with default limits
+///
+/// # Errors
+///
+/// Returns `FeedError::InvalidFormat` if the document is not OPML or
+/// exceeds the default size limit.
+pub fn parse_opml(data: &[u8]) -> Result<Opml> {
+    parse_opml_with_limits(data, ParserLimits::default())
+}
+
+/// Parse an OPML document with custom limits
+///
+/// `max_entries` bounds the total number of outlines, `max_nesting_depth`
+/// bounds folder nesting, and `max_attribute_length` truncates attributes.
+///
+/// # Errors
+///
+/// Returns `FeedError::InvalidFormat` if the document is not OPML or
+/// exceeds `max_feed_size_bytes`.
+pub fn parse_opml_with_limits(data: &[u8], limits: ParserLimits) -> Result<Opml> {
+    limits
+        .check_feed_size(data.len())
+        .map_err(|e| FeedError::InvalidFormat(e.to_string()))?;
+
+    let mut reader = XmlReader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    let mut opml = Opml::default();
+    let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
+    let mut found_root = false;
+    let mut outline_count = 0;
+    let depth: usize = 1;
+
+    loop {
+        let result = match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) if !found_root => {
+                if e.local_name().as_ref() != b"opml" {
+                    break;
+                }
+                found_root = true;
+                opml.version = find_attr(&e, b"version", &limits);
+                Ok(())
+            }
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"head" => parse_head(&mut reader, &mut buf, &mut opml.head, &limits, depth + 1),
+                b"body" => parse_outlines(
+                    &mut reader,
+                    &mut buf,
+                    &mut opml.outlines,
+                    &limits,
+                    depth + 1,
+                    &mut outline_count,
+                ),
+                _ => skip_element(&mut reader, &limits, depth + 1),
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => Err(e.into()),
+            _ => Ok(()),
+        };
+
+        if let Err(e) = result {
+            opml.bozo = true;
+            opml.bozo_exception = Some(e.to_string());
+            break;
+        }
+        buf.clear();
+    }
+
+    if !found_root {
+        return Err(FeedError::InvalidFormat(
+            "Missing <opml> root element".to_string(),
+        ));
+    }
+
+    if outline_count > limits.max_entries {
+        opml.bozo = true;
+        opml.bozo_exception = Some(format!("Outline limit exceeded: {}", limits.max_entries));
+    }
+
+    Ok(opml)
+}
+
+/// Parse `<head>` children until `</head>`
+fn parse_head(
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
+    head: &mut OpmlHead,
+    limits: &ParserLimits,
+    depth: usize,
+) -> Result<()> {
+    loop {
+        match reader.read_event_into(buf) {
+            Ok(Event::Start(e)) => {
+                check_depth(depth + 1, limits.max_nesting_depth)?;
+                match e.local_name().as_ref() {
+                    b"title" => head.title = Some(read_text(reader, limits)?),
+                    b"dateCreated" => {
+                        head.date_created = parse_date(&read_text(reader, limits)?);
+                    }
+                    b"dateModified" => {
+                        head.date_modified = parse_date(&read_text(reader, limits)?);
+                    }
+                    b"ownerName" => head.owner_name = Some(read_text(reader, limits)?),
+                    b"ownerEmail" => head.owner_email = Some(read_text(reader, limits)?),
+                    b"ownerId" => head.owner_id = Some(read_text(reader, limits)?),
+                    _ => skip_element(reader, limits, depth + 1)?,
+                }
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"head" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+/// Parse `<outline>` children until the enclosing element closes
+fn parse_outlines(
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
+    outlines: &mut Vec<Outline>,
+    limits: &ParserLimits,
+    depth: usize,
+    count: &mut usize,
+) -> Result<()> {
+    loop {
+        match reader.read_event_into(buf) {
+            Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+
+                if e.local_name().as_ref() != b"outline" {
+                    if !is_empty {
+                        skip_element(reader, limits, depth + 1)?;
+                    }
+                    buf.clear();
+                    continue;
+                }
+
+                check_depth(depth + 1, limits.max_nesting_depth)?;
+                *count += 1;
+                let mut outline = outline_from_attributes(e, limits);
+                if !is_empty {
+                    parse_outlines(reader, buf, &mut outline.children, limits, depth + 1, count)?;
+                }
+                if *count <= limits.max_entries {
+                    outlines.push(outline);
+                }
+            }
+            Ok(Event::End(_) | Event::Eof) => break,
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+fn outline_from_attributes(e: &BytesStart, limits: &ParserLimits) -> Outline {
+    let mut outline = Outline::default();
+
+    for attr in limited_attributes(e, limits) {
+        let value = Some(truncate_to_length(
+            &decode_attribute(&attr.value),
+            limits.max_attribute_length,
+        ));
+        match attr.key.as_ref() {
+            b"text" => outline.text = value,
+            b"title" => outline.title = value,
+            b"type" => outline.outline_type = value,
+            b"xmlUrl" => outline.xml_url = value,
+            b"htmlUrl" => outline.html_url = value,
+            b"description" => outline.description = value,
+            b"language" => outline.language = value,
+            b"version" => outline.version = value,
+            b"category" => outline.category = value,
+            _ => {}
+        }
+    }
+
+    outline
+}
+
+fn find_attr(e: &BytesStart, key: &[u8], limits: &ParserLimits) -> Option<String> {
+    limited_attributes(e, limits)
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| truncate_to_length(&decode_attribute(&attr.value), limits.max_attribute_length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = br#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head>
+    <title>Subscriptions</title>
+    <dateCreated>Mon, 01 Jan 2024 12:00:00 GMT</dateCreated>
+    <ownerName>Jane Doe</ownerName>
+    <ownerEmail>jane@example.com</ownerEmail>
+  </head>
+  <body>
+    <outline text="News" title="News">
+      <outline type="rss" text="Example News" xmlUrl="https://example.com/news.xml"
+               htmlUrl="https://example.com/"/>
+      <outline text="Nested">
+        <outline type="rss" text="Deep" xmlUrl="https://example.com/deep.xml?a=1&amp;b=2"/>
+      </outline>
+    </outline>
+    <outline type="rss" text="Top Level" xmlUrl="https://example.org/rss"/>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn test_parse_opml_head() {
+        let opml = parse_opml(SAMPLE).unwrap();
+        assert!(!opml.bozo);
+        assert_eq!(opml.version.as_deref(), Some("2.0"));
+        assert_eq!(opml.head.title.as_deref(), Some("Subscriptions"));
+        assert!(opml.head.date_created.is_some());
+        assert_eq!(opml.head.owner_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(opml.head.owner_email.as_deref(), Some("jane@example.com"));
+    }
+
+    #[test]
+    fn test_parse_opml_tree() {
+        let opml = parse_opml(SAMPLE).unwrap();
+        assert_eq!(opml.outlines.len(), 2);
+
+        let folder = &opml.outlines[0];
+        assert!(folder.is_folder());
+        assert_eq!(folder.display_name(), Some("News"));
+        assert_eq!(folder.children.len(), 2);
+        assert_eq!(
+            folder.children[0].html_url.as_deref(),
+            Some("https://example.com/")
+        );
+        assert_eq!(folder.children[0].outline_type.as_deref(), Some("rss"));
+        assert!(folder.children[1].is_folder());
+
+        assert!(opml.outlines[1].is_feed());
+    }
+
+    #[test]
+    fn test_parse_opml_feeds_flattened() {
+        let opml = parse_opml(SAMPLE).unwrap();
+        let urls: Vec<_> = opml
+            .feeds()
+            .iter()
+            .filter_map(|o| o.xml_url.as_deref())
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/news.xml",
+                "https://example.com/deep.xml?a=1&b=2",
+                "https://example.org/rss",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_opml_v1_without_head() {
+        let xml = br#"<opml version="1.0"><body>
+            <outline text="Feed" xmlUrl="http://example.com/rss"/>
+        </body></opml>"#;
+        let opml = parse_opml(xml).unwrap();
+        assert_eq!(opml.version.as_deref(), Some("1.0"));
+        assert!(opml.head.title.is_none());
+        assert_eq!(opml.feeds().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_opml_not_opml() {
+        let xml = br#"<rss version="2.0"><channel/></rss>"#;
+        assert!(parse_opml(xml).is_err());
+    }
+
+    #[test]
+    fn test_parse_opml_malformed_sets_bozo() {
+        let xml = br#"<opml version="2.0"><body>
+            <outline text="Good" xmlUrl="http://example.com/a"/>
+            <outline text="Broken" xmlUrl="http://example.com/b
+        </body></opml>"#;
+        let opml = parse_opml(xml).unwrap();
+        assert!(opml.bozo);
+        assert_eq!(opml.feeds().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_opml_outline_limit() {
+        let xml = br#"<opml version="2.0"><body>
+            <outline text="1" xmlUrl="http://example.com/1"/>
+            <outline text="2" xmlUrl="http://example.com/2"/>
+            <outline text="3" xmlUrl="http://example.com/3"/>
+        </body></opml>"#;
+        let limits = ParserLimits {
+            max_entries: 2,
+            ..Default::default()
+        };
+        let opml = parse_opml_with_limits(xml, limits).unwrap();
+        assert!(opml.bozo);
+        assert_eq!(opml.outlines.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_opml_nesting_limit() {
+        let xml = br#"<opml><body>
+            <outline text="a"><outline text="b"><outline text="c">
+                <outline text="d" xmlUrl="http://example.com/"/>
+            </outline></outline></outline>
+        </body></opml>"#;
+        let limits = ParserLimits {
+            max_nesting_depth: 4,
+            ..Default::default()
+        };
+        let opml = parse_opml_with_limits(xml, limits).unwrap();
+        assert!(opml.bozo);
+    }
+}
@@ -4,6 +4,11 @@
 //! Options control features like URL resolution, HTML sanitization, and resource limits.
 
 use crate::limits::ParserLimits;
+use crate::parser::XmlReader;
+use crate::types::{AuthorSource, ImageSource};
+use crate::util::base_url::BaseUrlContext;
+use crate::util::sanitize::SanitizePolicy;
+use std::ops::Range;
 
 /// Parser configuration options
 ///
@@ -25,9 +30,24 @@ use crate::limits::ParserLimits;
 ///     resolve_relative_uris: true,
 ///     sanitize_html: false, // Trust feed content
 ///     limits: feedparser_rs::ParserLimits::strict(),
+///     collect_stats: false,
+///     base_url: None,
+///     preserve_whitespace: false,
+///     clamp_future_dates: feedparser_rs::FutureDatePolicy::Off,
+///     sanitize_policy: feedparser_rs::util::sanitize::SanitizePolicy::new(),
+///     strip_tracking_params: false,
+///     extra_tracking_params: Vec::new(),
+///     keep_raw_body: false,
+///     image_precedence: None,
+///     normalize_tags: false,
+///     author_precedence: None,
+///     normalize_text: false,
+///     fix_mojibake: false,
+///     entry_range: None,
 /// };
 /// ```
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)] // Independent on/off switches
 pub struct ParseOptions {
     /// Whether to resolve relative URLs to absolute URLs
     ///
@@ -69,6 +89,35 @@ pub struct ParseOptions {
     /// ```
     pub sanitize_html: bool,
 
+    /// What the sanitizer keeps when `sanitize_html` is on
+    ///
+    /// The default policy keeps a conservative HTML allowlist. Science and
+    /// engineering feeds can opt in to MathML and inline SVG here.
+    ///
+    /// Default: `SanitizePolicy::new()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    /// use feedparser_rs::util::sanitize::SanitizePolicy;
+    ///
+    /// let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom"><entry>
+    ///     <content type="html">&lt;math&gt;&lt;mi&gt;x&lt;/mi&gt;&lt;/math&gt;</content>
+    /// </entry></feed>"#;
+    /// let options = ParseOptions {
+    ///     sanitize_policy: SanitizePolicy {
+    ///         allow_mathml: true,
+    ///         ..SanitizePolicy::new()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.entries[0].content[0].value, "<math><mi>x</mi></math>");
+    /// ```
+    pub sanitize_policy: SanitizePolicy,
+
     /// Parser limits for `DoS` protection
     ///
     /// Controls maximum allowed sizes for collections, text fields,
@@ -87,6 +136,329 @@ pub struct ParseOptions {
     /// };
     /// ```
     pub limits: ParserLimits,
+
+    /// Whether to fill in [`ParsedFeed::stats`](crate::ParsedFeed::stats)
+    ///
+    /// Counting elements takes a second pass over the document, so this is
+    /// off by default. The pass runs after the timed parse and does not
+    /// affect the reported `parse_duration`.
+    ///
+    /// Default: `false`
+    pub collect_stats: bool,
+
+    /// URL the document was retrieved from, used as its base URL
+    ///
+    /// Relative links, images, icons and enclosures are resolved against
+    /// it, as are relative `xml:base` values. In RSS it takes the place of
+    /// the channel `<link>`, which is otherwise used as the base. Ignored
+    /// when `resolve_relative_uris` is `false`.
+    ///
+    /// Default: `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0"><channel><item><link>/post/1</link></item></channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     base_url: Some("https://example.com/feed.xml".to_string()),
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.entries[0].link.as_deref(), Some("https://example.com/post/1"));
+    /// ```
+    pub base_url: Option<String>,
+
+    /// Whether to keep leading and trailing whitespace in element text
+    ///
+    /// By default text is trimmed, which also collapses indentation and
+    /// blank lines that matter in preformatted or code-heavy content. With
+    /// this set, text is kept as written; only whitespace that stands alone
+    /// between elements or next to a CDATA section is dropped.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = b"<rss version=\"2.0\"><channel><item>
+    ///     <title>  indented</title>
+    /// </item></channel></rss>";
+    /// let options = ParseOptions {
+    ///     preserve_whitespace: true,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.entries[0].title.as_deref(), Some("  indented"));
+    /// ```
+    pub preserve_whitespace: bool,
+
+    /// What to do with entry and feed dates in the future
+    ///
+    /// Some feeds date entries years ahead, which pins them to the top of
+    /// date-sorted timelines. Dates less than a day ahead are left alone to
+    /// tolerate clock skew between the publisher and this machine.
+    ///
+    /// Default: [`FutureDatePolicy::Off`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{FutureDatePolicy, ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0"><channel><item>
+    ///     <pubDate>Fri, 01 Jan 2100 00:00:00 GMT</pubDate>
+    /// </item></channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     clamp_future_dates: FutureDatePolicy::Clamp,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert!(feed.bozo);
+    /// assert!(feed.entries[0].published.unwrap() <= chrono::Utc::now());
+    /// ```
+    pub clamp_future_dates: FutureDatePolicy,
+
+    /// Whether to remove click-tracking parameters from links
+    ///
+    /// Aggregators dedupe on URLs, and `utm_*`, `fbclid`, `gclid` and
+    /// similar parameters make copies of one article look different. With
+    /// this set, they are stripped from feed and entry links, along with
+    /// [`extra_tracking_params`](Self::extra_tracking_params). See
+    /// [`ParsedFeed::strip_tracking_params`](crate::ParsedFeed::strip_tracking_params).
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0"><channel><item>
+    ///     <link>https://example.com/1?utm_source=rss&amp;ref=home</link>
+    /// </item></channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     strip_tracking_params: true,
+    ///     extra_tracking_params: vec!["ref".to_string()],
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.entries[0].link.as_deref(), Some("https://example.com/1"));
+    /// ```
+    pub strip_tracking_params: bool,
+
+    /// Additional query parameter names removed when
+    /// `strip_tracking_params` is on
+    ///
+    /// Default: empty
+    pub extra_tracking_params: Vec<String>,
+
+    /// Whether fetching keeps the response body in `ParsedFeed::raw_body`
+    ///
+    /// Archival systems can store the exact payload that produced a parse
+    /// without fetching it twice. Only `FeedHttpClient::fetch_feed_with_options`
+    /// and `parse_url_with_options` read this; parsing from bytes leaves
+    /// `raw_body` empty.
+    ///
+    /// Default: `false`
+    pub keep_raw_body: bool,
+
+    /// Sources [`Entry::image`](crate::Entry::image) is filled in from, most
+    /// preferred first
+    ///
+    /// `None` uses [`ImageSource::DEFAULT_ORDER`]; an empty list leaves
+    /// `image` unset.
+    ///
+    /// Default: `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ImageSource, ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    /// <channel><item>
+    ///     <media:thumbnail url="https://example.com/thumb.jpg"/>
+    ///     <description><![CDATA[<img src="https://example.com/photo.jpg">]]></description>
+    /// </item></channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     image_precedence: Some(vec![ImageSource::ContentImage, ImageSource::MediaThumbnail]),
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// let image = feed.entries[0].image.as_ref().unwrap();
+    /// assert_eq!(image.url.as_str(), "https://example.com/photo.jpg");
+    /// ```
+    pub image_precedence: Option<Vec<ImageSource>>,
+
+    /// Whether to split keyword lists into separate tags and drop
+    /// duplicate tags
+    ///
+    /// See [`ParsedFeed::normalize_tags`](crate::ParsedFeed::normalize_tags).
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0"><channel><item>
+    ///     <category>news; Politics</category>
+    ///     <category>politics</category>
+    /// </item></channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     normalize_tags: true,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// let terms: Vec<&str> = feed.entries[0].tags.iter().map(|t| t.term.as_str()).collect();
+    /// assert_eq!(terms, ["news", "Politics"]);
+    /// ```
+    pub normalize_tags: bool,
+
+    /// Order in which author sources fill `author` and `author_detail` on
+    /// the feed and each entry
+    ///
+    /// The first source in the list that names an author wins. Authors from
+    /// every listed source are kept in `authors`, so nothing is lost when
+    /// sources disagree.
+    ///
+    /// `None` uses [`AuthorSource::DEFAULT_ORDER`]; an empty list leaves
+    /// `author` unset.
+    ///
+    /// Default: `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{AuthorSource, ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    /// <channel>
+    ///     <managingEditor>editor@example.com (Jane Editor)</managingEditor>
+    ///     <itunes:author>The Show</itunes:author>
+    /// </channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     author_precedence: Some(vec![AuthorSource::ItunesAuthor, AuthorSource::ManagingEditor]),
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// # #[cfg(feature = "itunes")]
+    /// assert_eq!(feed.feed.author.as_deref(), Some("The Show"));
+    /// # #[cfg(feature = "itunes")]
+    /// assert_eq!(feed.feed.authors.len(), 2);
+    /// ```
+    pub author_precedence: Option<Vec<AuthorSource>>,
+
+    /// Whether to collapse whitespace and NFC-normalize titles, tags and
+    /// author names
+    ///
+    /// See [`ParsedFeed::normalize_text`](crate::ParsedFeed::normalize_text).
+    /// Runs before [`normalize_tags`](Self::normalize_tags), so tags that
+    /// differ only in spacing or Unicode composition are deduplicated too.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = b"<rss version=\"2.0\"><channel><item>
+    ///     <title>Weekly\n    roundup</title>
+    /// </item></channel></rss>";
+    /// let options = ParseOptions {
+    ///     normalize_text: true,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.entries[0].title.as_deref(), Some("Weekly roundup"));
+    /// ```
+    pub normalize_text: bool,
+
+    /// Whether to repair UTF-8 text that was decoded with the wrong
+    /// encoding, such as `â€™` for `’`
+    ///
+    /// See [`ParsedFeed::fix_mojibake`](crate::ParsedFeed::fix_mojibake).
+    /// A feed with repaired text is flagged as `bozo`, since its
+    /// publisher mangled it.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = "<rss version=\"2.0\"><channel><item>
+    ///     <title>Caf\u{c3}\u{a9} opening</title>
+    /// </item></channel></rss>";
+    /// let options = ParseOptions {
+    ///     fix_mojibake: true,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml.as_bytes(), &options).unwrap();
+    /// assert_eq!(feed.entries[0].title.as_deref(), Some("Caf\u{e9} opening"));
+    /// assert!(feed.bozo);
+    /// ```
+    pub fix_mojibake: bool,
+
+    /// Positions of the entries to keep, counting from 0 in document order
+    ///
+    /// Entries outside the range are skipped without building their data,
+    /// so a very large feed can be ingested one page at a time. Feed
+    /// metadata is parsed in full on every call. Positions count every
+    /// entry element, including ones later dropped by limits.
+    ///
+    /// Default: `None` (all entries)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ParseOptions, parse_with_options};
+    ///
+    /// let xml = br#"<rss version="2.0"><channel><title>Archive</title>
+    ///     <item><title>1</title></item>
+    ///     <item><title>2</title></item>
+    ///     <item><title>3</title></item>
+    /// </channel></rss>"#;
+    /// let options = ParseOptions {
+    ///     entry_range: Some(1..3),
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let feed = parse_with_options(xml, &options).unwrap();
+    /// assert_eq!(feed.feed.title.as_deref(), Some("Archive"));
+    /// let titles: Vec<_> = feed.entries.iter().filter_map(|e| e.title.as_deref()).collect();
+    /// assert_eq!(titles, ["2", "3"]);
+    /// ```
+    pub entry_range: Option<Range<usize>>,
+}
+
+/// Handling of dates in the future, see
+/// [`ParseOptions::clamp_future_dates`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FutureDatePolicy {
+    /// Keep future dates as published
+    #[default]
+    Off,
+    /// Keep future dates but flag the feed as `bozo`
+    Warn,
+    /// Replace future dates with the time of parsing and flag the feed as `bozo`
+    Clamp,
 }
 
 impl Default for ParseOptions {
@@ -96,6 +468,20 @@ impl Default for ParseOptions {
     /// - `resolve_relative_uris`: `true`
     /// - `sanitize_html`: `true`
     /// - `limits`: `ParserLimits::default()`
+    /// - `collect_stats`: `false`
+    /// - `base_url`: `None`
+    /// - `preserve_whitespace`: `false`
+    /// - `clamp_future_dates`: `FutureDatePolicy::Off`
+    /// - `sanitize_policy`: `SanitizePolicy::new()`
+    /// - `strip_tracking_params`: `false`
+    /// - `extra_tracking_params`: empty
+    /// - `keep_raw_body`: `false`
+    /// - `image_precedence`: `None` ([`ImageSource::DEFAULT_ORDER`])
+    /// - `normalize_tags`: `false`
+    /// - `author_precedence`: `None` ([`AuthorSource::DEFAULT_ORDER`])
+    /// - `normalize_text`: `false`
+    /// - `fix_mojibake`: `false`
+    /// - `entry_range`: `None`
     ///
     /// These defaults are suitable for most use cases and provide
     /// good security and compatibility.
@@ -104,11 +490,40 @@ impl Default for ParseOptions {
             resolve_relative_uris: true,
             sanitize_html: true,
             limits: ParserLimits::default(),
+            collect_stats: false,
+            base_url: None,
+            preserve_whitespace: false,
+            clamp_future_dates: FutureDatePolicy::Off,
+            sanitize_policy: SanitizePolicy::new(),
+            strip_tracking_params: false,
+            extra_tracking_params: Vec::new(),
+            keep_raw_body: false,
+            image_precedence: None,
+            normalize_tags: false,
+            author_precedence: None,
+            normalize_text: false,
+            fix_mojibake: false,
+            entry_range: None,
         }
     }
 }
 
 impl ParseOptions {
+    /// Creates an XML reader over `data` configured by these options
+    pub(crate) fn xml_reader<'a>(&self, data: &'a [u8]) -> XmlReader<'a> {
+        let mut reader = XmlReader::from_reader(data);
+        reader.config_mut().trim_text(!self.preserve_whitespace);
+        reader
+    }
+
+    /// Base URL context seeded with [`base_url`](Self::base_url)
+    pub(crate) fn base_context(&self) -> BaseUrlContext {
+        self.base_url
+            .as_deref()
+            .map_or_else(BaseUrlContext::new, BaseUrlContext::with_base)
+            .with_resolution(self.resolve_relative_uris)
+    }
+
     /// Creates permissive parse options
     ///
     /// Suitable for trusted feeds where you want maximum compatibility
@@ -135,6 +550,20 @@ impl ParseOptions {
             resolve_relative_uris: true,
             sanitize_html: false,
             limits: ParserLimits::permissive(),
+            collect_stats: false,
+            base_url: None,
+            preserve_whitespace: false,
+            clamp_future_dates: FutureDatePolicy::Off,
+            sanitize_policy: SanitizePolicy::new(),
+            strip_tracking_params: false,
+            extra_tracking_params: Vec::new(),
+            keep_raw_body: false,
+            image_precedence: None,
+            normalize_tags: false,
+            author_precedence: None,
+            normalize_text: false,
+            fix_mojibake: false,
+            entry_range: None,
         }
     }
 
@@ -160,6 +589,20 @@ impl ParseOptions {
             resolve_relative_uris: false,
             sanitize_html: true,
             limits: ParserLimits::strict(),
+            collect_stats: false,
+            base_url: None,
+            preserve_whitespace: false,
+            clamp_future_dates: FutureDatePolicy::Off,
+            sanitize_policy: SanitizePolicy::new(),
+            strip_tracking_params: false,
+            extra_tracking_params: Vec::new(),
+            keep_raw_body: false,
+            image_precedence: None,
+            normalize_tags: false,
+            author_precedence: None,
+            normalize_text: false,
+            fix_mojibake: false,
+            entry_range: None,
         }
     }
 }
@@ -174,6 +617,11 @@ mod tests {
         assert!(options.resolve_relative_uris);
         assert!(options.sanitize_html);
         assert_eq!(options.limits.max_entries, 10_000);
+        assert!(!options.collect_stats);
+        assert!(options.base_url.is_none());
+        assert!(!options.preserve_whitespace);
+        assert_eq!(options.clamp_future_dates, FutureDatePolicy::Off);
+        assert!(!options.strip_tracking_params);
     }
 
     #[test]
@@ -198,10 +646,25 @@ mod tests {
             resolve_relative_uris: false,
             sanitize_html: false,
             limits: ParserLimits::permissive(),
+            collect_stats: true,
+            base_url: None,
+            preserve_whitespace: false,
+            clamp_future_dates: FutureDatePolicy::Off,
+            sanitize_policy: SanitizePolicy::new(),
+            strip_tracking_params: false,
+            extra_tracking_params: Vec::new(),
+            keep_raw_body: false,
+            image_precedence: None,
+            normalize_tags: false,
+            author_precedence: None,
+            normalize_text: false,
+            fix_mojibake: false,
+            entry_range: None,
         };
         assert!(!options.resolve_relative_uris);
         assert!(!options.sanitize_html);
         assert_eq!(options.limits.max_entries, 100_000);
+        assert!(options.collect_stats);
     }
 
     #[test]
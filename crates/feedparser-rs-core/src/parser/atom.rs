@@ -1,22 +1,36 @@
 //! Atom 1.0 parser implementation
 
+use super::XmlReader;
+#[cfg(feature = "dublincore")]
+use crate::namespace::dublin_core;
 use crate::{
-    ParserLimits,
+    ParseOptions, ParserLimits,
     error::{FeedError, Result},
-    namespace::{content, dublin_core, media_rss},
+    limits::MemoryBudget,
+    namespace::{
+        activity::{self, ActivityObject},
+        content, feed_history, gdata, license, prism, threading,
+    },
     types::{
-        Content, Entry, FeedVersion, Generator, Link, MediaContent, MediaThumbnail, ParsedFeed,
-        Person, Source, Tag, TextConstruct, TextType,
+        Content, Enclosure, Entry, FeedVersion, Generator, LicenseInfo, Link, ParsedFeed, Person,
+        Source, Tag, TextConstruct, TextType,
     },
-    util::{base_url::BaseUrlContext, parse_date},
+    util::{base_url::BaseUrlContext, entities::decode_attribute, parse_date},
 };
-use quick_xml::{Reader, events::Event};
+#[cfg(feature = "mediarss")]
+use crate::{namespace::media_rss, types::MediaThumbnail};
+use quick_xml::events::{BytesStart, Event};
 
+#[cfg(feature = "dublincore")]
+use super::common::is_dc_tag;
 use super::common::{
-    EVENT_BUFFER_CAPACITY, FromAttributes, LimitedCollectionExt, bytes_to_string, check_depth,
-    extract_xml_base, init_feed, is_content_tag, is_dc_tag, is_media_tag, read_text, skip_element,
-    skip_to_end,
+    EVENT_BUFFER_CAPACITY, EntryWindow, FromAttributes, LimitedCollectionExt, check_depth,
+    extract_xml_base, init_feed, is_activity_tag, is_content_tag, is_fh_tag, is_gd_tag,
+    is_media_tag, is_prism_tag, limited_attributes, parse_license_element, read_text,
+    read_text_cow, skip_element, skip_to_end,
 };
+#[cfg(feature = "mediarss")]
+use super::common::{parse_media_content, parse_media_group};
 
 /// Parse Atom 1.0 feed from raw bytes
 ///
@@ -54,29 +68,58 @@ pub fn parse_atom10(data: &[u8]) -> Result<ParsedFeed> {
 
 /// Parse Atom with custom limits
 pub fn parse_atom10_with_limits(data: &[u8], limits: ParserLimits) -> Result<ParsedFeed> {
+    parse_atom10_with_options(
+        data,
+        &ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse Atom with custom parse options
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.parse_atom",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            entries = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn parse_atom10_with_options(data: &[u8], options: &ParseOptions) -> Result<ParsedFeed> {
+    let limits = options.limits;
     limits
         .check_feed_size(data.len())
         .map_err(|e| FeedError::InvalidFormat(e.to_string()))?;
 
-    let mut reader = Reader::from_reader(data);
-    reader.config_mut().trim_text(true);
+    let mut reader = options.xml_reader(data);
 
     let mut feed = init_feed(FeedVersion::Atom10, limits.max_entries);
     let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
     let mut depth: usize = 1;
-    let mut base_ctx = BaseUrlContext::new();
+    let mut base_ctx = options.base_context();
+    let mut window = EntryWindow::new(options);
 
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(e)) if e.local_name().as_ref() == b"feed" => {
-                if let Some(xml_base) = extract_xml_base(&e, limits.max_attribute_length) {
+                if let Some(xml_base) = extract_xml_base(&e, &limits) {
                     base_ctx.update_base(&xml_base);
                 }
 
                 depth += 1;
-                if let Err(e) =
-                    parse_feed_element(&mut reader, &mut feed, &limits, &mut depth, &base_ctx)
-                {
+                if let Err(e) = parse_feed_element(
+                    &mut reader,
+                    &mut buf,
+                    &mut feed,
+                    &limits,
+                    &mut depth,
+                    &base_ctx,
+                    &mut window,
+                ) {
                     feed.bozo = true;
                     feed.bozo_exception = Some(e.to_string());
                 }
@@ -93,22 +136,31 @@ pub fn parse_atom10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Par
         buf.clear();
     }
 
+    if options.collect_stats {
+        reader.counts().record(&mut feed);
+    }
+    record_span!(entries = feed.entries.len());
     Ok(feed)
 }
 
 /// Parse <feed> element
 #[allow(clippy::too_many_lines)]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.feed", level = "debug", skip_all)
+)]
 fn parse_feed_element(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
     depth: &mut usize,
     base_ctx: &BaseUrlContext,
+    window: &mut EntryWindow,
 ) -> Result<()> {
-    let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
-
+    let mut budget = MemoryBudget::new(limits);
     loop {
-        match reader.read_event_into(&mut buf) {
+        match reader.read_event_into(buf) {
             Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
                 let is_empty = matches!(event, Event::Empty(_));
                 let (Event::Start(e) | Event::Empty(e)) = &event else {
@@ -122,12 +174,12 @@ fn parse_feed_element(
                 // Use name() instead of local_name() to preserve namespace prefixes
                 match element.name().as_ref() {
                     b"title" if !is_empty => {
-                        let text = parse_text_construct(reader, &mut buf, &element, limits)?;
+                        let text = parse_text_construct(reader, &element, limits)?;
                         feed.feed.set_title(text);
                     }
                     b"link" => {
                         if let Some(mut link) = Link::from_attributes(
-                            element.attributes().flatten(),
+                            limited_attributes(&element, limits),
                             limits.max_attribute_length,
                         ) {
                             link.href = base_ctx.resolve_safe(&link.href).into();
@@ -136,36 +188,42 @@ fn parse_feed_element(
                             {
                                 feed.feed.link = Some(link.href.to_string());
                             }
-                            if feed.feed.license.is_none() && link.rel.as_deref() == Some("license")
-                            {
-                                feed.feed.license = Some(link.href.to_string());
+                            if link.rel.as_deref() == Some("license") {
+                                license::merge(
+                                    &mut feed.feed.license_detail,
+                                    &mut feed.feed.license,
+                                    license_from_link(&link),
+                                );
+                            }
+                            if let Some(rel) = link.rel.as_deref() {
+                                feed_history::handle_link(rel, &link.href, feed);
                             }
                             feed.feed
                                 .links
                                 .try_push_limited(link, limits.max_links_per_feed);
                         }
                         if !is_empty {
-                            skip_to_end(reader, &mut buf, b"link")?;
+                            skip_to_end(reader, b"link")?;
                         }
                     }
                     b"subtitle" if !is_empty => {
-                        let text = parse_text_construct(reader, &mut buf, &element, limits)?;
+                        let text = parse_text_construct(reader, &element, limits)?;
                         feed.feed.set_subtitle(text);
                     }
                     b"id" if !is_empty => {
-                        feed.feed.id = Some(read_text(reader, &mut buf, limits)?);
+                        feed.feed.id = Some(read_text(reader, limits)?);
                     }
                     b"updated" if !is_empty => {
-                        let text = read_text(reader, &mut buf, limits)?;
+                        let text = read_text_cow(reader, limits)?;
                         feed.feed.updated = parse_date(&text);
                     }
                     b"published" if !is_empty => {
-                        let text = read_text(reader, &mut buf, limits)?;
+                        let text = read_text_cow(reader, limits)?;
                         feed.feed.published = parse_date(&text);
                     }
                     b"author" if !is_empty => {
-                        if let Ok(person) = parse_person(reader, &mut buf, limits, depth) {
-                            if feed.feed.author.is_none() {
+                        if let Ok(person) = parse_person(reader, buf, limits, depth) {
+                            if feed.feed.author_detail.is_none() {
                                 feed.feed.set_author(person.clone());
                             }
                             feed.feed
@@ -174,7 +232,7 @@ fn parse_feed_element(
                         }
                     }
                     b"contributor" if !is_empty => {
-                        if let Ok(person) = parse_person(reader, &mut buf, limits, depth) {
+                        if let Ok(person) = parse_person(reader, buf, limits, depth) {
                             feed.feed
                                 .contributors
                                 .try_push_limited(person, limits.max_contributors);
@@ -182,45 +240,48 @@ fn parse_feed_element(
                     }
                     b"category" => {
                         if let Some(tag) = Tag::from_attributes(
-                            element.attributes().flatten(),
+                            limited_attributes(&element, limits),
                             limits.max_attribute_length,
                         ) {
                             feed.feed.tags.try_push_limited(tag, limits.max_tags);
                         }
                         if !is_empty {
-                            skip_to_end(reader, &mut buf, b"category")?;
+                            skip_to_end(reader, b"category")?;
                         }
                     }
                     b"generator" if !is_empty => {
-                        let generator = parse_generator(reader, &mut buf, &element, limits)?;
+                        let generator = parse_generator(reader, &element, limits)?;
                         feed.feed.set_generator(generator);
                     }
                     b"icon" if !is_empty => {
-                        let url = read_text(reader, &mut buf, limits)?;
+                        let url = read_text_cow(reader, limits)?;
                         feed.feed.icon = Some(base_ctx.resolve_safe(&url));
+                        feed.feed.raw_icon = Some(url.into_owned());
                     }
                     b"logo" if !is_empty => {
-                        let url = read_text(reader, &mut buf, limits)?;
+                        let url = read_text_cow(reader, limits)?;
                         feed.feed.logo = Some(base_ctx.resolve_safe(&url));
+                        feed.feed.raw_logo = Some(url.into_owned());
                     }
                     b"rights" if !is_empty => {
-                        let text = parse_text_construct(reader, &mut buf, &element, limits)?;
+                        let text = parse_text_construct(reader, &element, limits)?;
                         feed.feed.set_rights(text);
                     }
+                    b"entry" if !is_empty && !window.admit() => {
+                        skip_element(reader, limits, *depth)?;
+                    }
                     b"entry" if !is_empty => {
-                        if !feed.check_entry_limit(reader, &mut buf, limits, depth)? {
+                        if !feed.check_entry_limit(reader, limits, depth)? {
                             continue;
                         }
 
                         let mut entry_ctx = base_ctx.child();
-                        if let Some(xml_base) =
-                            extract_xml_base(&element, limits.max_attribute_length)
-                        {
+                        if let Some(xml_base) = extract_xml_base(&element, limits) {
                             entry_ctx.update_base(&xml_base);
                         }
 
-                        match parse_entry(reader, &mut buf, limits, depth, &entry_ctx) {
-                            Ok(entry) => feed.entries.push(entry),
+                        match parse_entry(reader, buf, limits, depth, &entry_ctx) {
+                            Ok(entry) => feed.push_entry(entry, &mut budget),
                             Err(e) => {
                                 feed.bozo = true;
                                 feed.bozo_exception = Some(e.to_string());
@@ -229,31 +290,11 @@ fn parse_feed_element(
                     }
                     tag => {
                         // Check for namespace elements
-                        let handled = if let Some(dc_element) = is_dc_tag(tag) {
-                            let dc_elem = dc_element.to_string();
-                            if !is_empty {
-                                let text = read_text(reader, &mut buf, limits)?;
-                                dublin_core::handle_feed_element(&dc_elem, &text, &mut feed.feed);
-                            }
-                            true
-                        } else if let Some(_content_element) = is_content_tag(tag) {
-                            // Content namespace - typically entry-level
-                            if !is_empty {
-                                skip_element(reader, &mut buf, limits, *depth)?;
-                            }
-                            true
-                        } else if let Some(_media_element) = is_media_tag(tag) {
-                            // Media RSS - typically entry-level
-                            if !is_empty {
-                                skip_element(reader, &mut buf, limits, *depth)?;
-                            }
-                            true
-                        } else {
-                            false
-                        };
-
+                        let handled = parse_feed_namespace(
+                            reader, &element, tag, feed, limits, *depth, is_empty,
+                        )?;
                         if !handled && !is_empty {
-                            skip_element(reader, &mut buf, limits, *depth)?;
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                 }
@@ -267,13 +308,18 @@ fn parse_feed_element(
         buf.clear();
     }
 
+    feed.charge_feed_meta(&mut budget);
     Ok(())
 }
 
 /// Parse <entry> element
 #[allow(clippy::too_many_lines)]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.entry", level = "trace", skip_all)
+)]
 fn parse_entry(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -296,12 +342,12 @@ fn parse_entry(
                 // Use name() instead of local_name() to preserve namespace prefixes
                 match element.name().as_ref() {
                     b"title" if !is_empty => {
-                        let text = parse_text_construct(reader, buf, &element, limits)?;
+                        let text = parse_text_construct(reader, &element, limits)?;
                         entry.set_title(text);
                     }
                     b"link" => {
                         if let Some(mut link) = Link::from_attributes(
-                            element.attributes().flatten(),
+                            limited_attributes(&element, limits),
                             limits.max_attribute_length,
                         ) {
                             link.href = base_ctx.resolve_safe(&link.href).into();
@@ -309,41 +355,55 @@ fn parse_entry(
                             if entry.link.is_none() && link.rel.as_deref() == Some("alternate") {
                                 entry.link = Some(link.href.to_string());
                             }
-                            if entry.license.is_none() && link.rel.as_deref() == Some("license") {
-                                entry.license = Some(link.href.to_string());
+                            if link.rel.as_deref() == Some("license") {
+                                license::merge(
+                                    &mut entry.license_detail,
+                                    &mut entry.license,
+                                    license_from_link(&link),
+                                );
+                            }
+                            if link.rel.as_deref() == Some("enclosure") {
+                                entry.enclosures.try_push_limited(
+                                    Enclosure {
+                                        url: link.href.clone(),
+                                        length: link.length,
+                                        enclosure_type: link.link_type.clone(),
+                                    },
+                                    limits.max_enclosures,
+                                );
                             }
                             entry
                                 .links
                                 .try_push_limited(link, limits.max_links_per_entry);
                         }
                         if !is_empty {
-                            skip_to_end(reader, buf, b"link")?;
+                            skip_to_end(reader, b"link")?;
                         }
                     }
                     b"id" if !is_empty => {
-                        entry.id = Some(read_text(reader, buf, limits)?.into());
+                        entry.id = Some(read_text(reader, limits)?.into());
                     }
                     b"updated" if !is_empty => {
-                        let text = read_text(reader, buf, limits)?;
+                        let text = read_text_cow(reader, limits)?;
                         entry.updated = parse_date(&text);
                     }
                     b"published" if !is_empty => {
-                        let text = read_text(reader, buf, limits)?;
+                        let text = read_text_cow(reader, limits)?;
                         entry.published = parse_date(&text);
                     }
                     b"summary" if !is_empty => {
-                        let text = parse_text_construct(reader, buf, &element, limits)?;
+                        let text = parse_text_construct(reader, &element, limits)?;
                         entry.set_summary(text);
                     }
                     b"content" if !is_empty => {
-                        let content = parse_content(reader, buf, &element, limits)?;
+                        let content = parse_content(reader, &element, limits)?;
                         entry
                             .content
                             .try_push_limited(content, limits.max_content_blocks);
                     }
                     b"author" if !is_empty => {
                         if let Ok(person) = parse_person(reader, buf, limits, depth) {
-                            if entry.author.is_none() {
+                            if entry.author_detail.is_none() {
                                 entry.set_author(person.clone());
                             }
                             entry.authors.try_push_limited(person, limits.max_authors);
@@ -358,76 +418,29 @@ fn parse_entry(
                     }
                     b"category" => {
                         if let Some(tag) = Tag::from_attributes(
-                            element.attributes().flatten(),
+                            limited_attributes(&element, limits),
                             limits.max_attribute_length,
                         ) {
                             entry.tags.try_push_limited(tag, limits.max_tags);
                         }
                         if !is_empty {
-                            skip_to_end(reader, buf, b"category")?;
+                            skip_to_end(reader, b"category")?;
                         }
                     }
                     b"source" if !is_empty => {
-                        if let Ok(source) = parse_atom_source(reader, buf, limits, depth) {
+                        if let Ok(source) = parse_atom_source(reader, buf, limits, depth, base_ctx)
+                        {
                             entry.source = Some(source);
                         }
                     }
                     tag => {
                         // Check for namespace elements
-                        let handled = if let Some(dc_element) = is_dc_tag(tag) {
-                            let dc_elem = dc_element.to_string();
-                            if !is_empty {
-                                let text = read_text(reader, buf, limits)?;
-                                dublin_core::handle_entry_element(&dc_elem, &text, &mut entry);
-                            }
-                            true
-                        } else if let Some(content_element) = is_content_tag(tag) {
-                            let content_elem = content_element.to_string();
-                            if !is_empty {
-                                let text = read_text(reader, buf, limits)?;
-                                content::handle_entry_element(&content_elem, &text, &mut entry);
-                            }
-                            true
-                        } else if let Some(media_element) = is_media_tag(tag) {
-                            // Media RSS namespace
-                            if media_element == "thumbnail" {
-                                if let Some(thumbnail) = MediaThumbnail::from_attributes(
-                                    element.attributes().flatten(),
-                                    limits.max_attribute_length,
-                                ) {
-                                    entry
-                                        .media_thumbnails
-                                        .try_push_limited(thumbnail, limits.max_enclosures);
-                                }
-                                if !is_empty {
-                                    skip_element(reader, buf, limits, *depth)?;
-                                }
-                            } else if media_element == "content" {
-                                if let Some(media) = MediaContent::from_attributes(
-                                    element.attributes().flatten(),
-                                    limits.max_attribute_length,
-                                ) {
-                                    entry
-                                        .media_content
-                                        .try_push_limited(media, limits.max_enclosures);
-                                }
-                                if !is_empty {
-                                    skip_element(reader, buf, limits, *depth)?;
-                                }
-                            } else {
-                                let media_elem = media_element.to_string();
-                                if !is_empty {
-                                    let text = read_text(reader, buf, limits)?;
-                                    media_rss::handle_entry_element(&media_elem, &text, &mut entry);
-                                }
-                            }
-                            true
-                        } else {
-                            false
-                        };
-
+                        let handled = parse_entry_namespace(
+                            reader, buf, &element, tag, &mut entry, limits, depth, base_ctx,
+                            is_empty,
+                        )?;
                         if !handled && !is_empty {
-                            skip_element(reader, buf, limits, *depth)?;
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                 }
@@ -444,16 +457,218 @@ fn parse_entry(
     Ok(entry)
 }
 
+/// License declared by a `rel="license"` link, named by its title
+///
+/// The SPDX guess is filled in when the license is merged.
+fn license_from_link(link: &Link) -> LicenseInfo {
+    LicenseInfo {
+        url: Some(link.href.to_string()),
+        name: link.title.clone(),
+        spdx_guess: None,
+    }
+}
+
+/// Parse Dublin Core, Feed History, Content, Media RSS and license tags at feed level
+///
+/// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
+fn parse_feed_namespace(
+    reader: &mut XmlReader<'_>,
+    element: &BytesStart,
+    tag: &[u8],
+    feed: &mut ParsedFeed,
+    limits: &ParserLimits,
+    depth: usize,
+    is_empty: bool,
+) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
+    if let Some(dc_element) = is_dc_tag(tag) {
+        let dc_elem = dc_element.to_string();
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            dublin_core::handle_feed_element(&dc_elem, &text, &mut feed.feed);
+        }
+        return Ok(true);
+    }
+
+    if let Some(fh_element) = is_fh_tag(tag) {
+        feed_history::handle_feed_element(fh_element, feed);
+        if !is_empty {
+            skip_element(reader, limits, depth)?;
+        }
+        Ok(true)
+    } else if is_content_tag(tag).is_some() || is_media_tag(tag).is_some() {
+        // Content and Media RSS - typically entry-level
+        if !is_empty {
+            skip_element(reader, limits, depth)?;
+        }
+        Ok(true)
+    } else if license::is_license_tag(tag) {
+        parse_license_element(
+            reader,
+            element,
+            &mut feed.feed.license_detail,
+            &mut feed.feed.license,
+            limits,
+            is_empty,
+        )?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Parse namespaced tags at entry level
+///
+/// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
+///
+/// Note: Uses 9 parameters instead of a context struct due to borrow checker constraints
+/// with multiple simultaneous `&mut` references during parsing.
+#[allow(clippy::too_many_arguments)]
+fn parse_entry_namespace(
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
+    element: &BytesStart,
+    tag: &[u8],
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    depth: &mut usize,
+    base_ctx: &BaseUrlContext,
+    is_empty: bool,
+) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
+    if let Some(dc_element) = is_dc_tag(tag) {
+        let dc_elem = dc_element.to_string();
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            dublin_core::handle_entry_element(&dc_elem, &text, entry);
+        }
+        return Ok(true);
+    }
+    #[cfg(feature = "mediarss")]
+    if let Some(media_element) = is_media_tag(tag) {
+        parse_entry_media(
+            reader,
+            element,
+            media_element,
+            entry,
+            limits,
+            is_empty,
+            *depth,
+        )?;
+        return Ok(true);
+    }
+
+    if let Some(content_element) = is_content_tag(tag) {
+        let content_elem = content_element.to_string();
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            content::handle_entry_element(&content_elem, &text, entry);
+        }
+        Ok(true)
+    } else if let Some(prism_element) = is_prism_tag(tag) {
+        let prism_elem = prism_element.to_string();
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            prism::handle_entry_element(&prism_elem, &text, entry);
+        }
+        Ok(true)
+    } else if let Some(activity_element) = is_activity_tag(tag) {
+        if activity_element == "object" {
+            if !is_empty {
+                let object = parse_activity_object(reader, buf, limits, depth, base_ctx)?;
+                activity::activity_mut(entry).object.get_or_insert(object);
+            }
+        } else if !is_empty {
+            let activity_elem = activity_element.to_string();
+            let text = read_text_cow(reader, limits)?;
+            activity::handle_entry_element(&activity_elem, &text, entry);
+        }
+        Ok(true)
+    } else if let Some(gd_element) = is_gd_tag(tag) {
+        gdata::handle_entry_element(
+            gd_element,
+            limited_attributes(element, limits),
+            entry,
+            limits,
+        );
+        if !is_empty {
+            skip_element(reader, limits, *depth)?;
+        }
+        Ok(true)
+    } else if threading::is_count_tag(tag) {
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            threading::handle_entry_element(tag, &text, entry);
+        }
+        Ok(true)
+    } else if license::is_license_tag(tag) {
+        parse_license_element(
+            reader,
+            element,
+            &mut entry.license_detail,
+            &mut entry.license,
+            limits,
+            is_empty,
+        )?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+#[cfg(feature = "mediarss")]
+/// Parse Media RSS namespace elements
+fn parse_entry_media(
+    reader: &mut XmlReader<'_>,
+    element: &BytesStart,
+    media_element: &str,
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    is_empty: bool,
+    depth: usize,
+) -> Result<()> {
+    match media_element {
+        "thumbnail" => {
+            if let Some(thumbnail) = MediaThumbnail::from_attributes(
+                limited_attributes(element, limits),
+                limits.max_attribute_length,
+            ) {
+                entry
+                    .media_thumbnails
+                    .try_push_limited(thumbnail, limits.max_media_thumbnails);
+            }
+            if !is_empty {
+                skip_element(reader, limits, depth)?;
+            }
+        }
+        "content" => {
+            parse_media_content(reader, element, entry, limits, is_empty, depth, None)?;
+        }
+        "group" => {
+            if !is_empty {
+                parse_media_group(reader, entry, limits, depth)?;
+            }
+        }
+        _ => {
+            if !is_empty {
+                let media_elem = media_element.to_string();
+                let text = read_text_cow(reader, limits)?;
+                media_rss::handle_entry_element(&media_elem, &text, entry);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Parse Atom text construct (title, summary, rights, etc.)
 fn parse_text_construct(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     e: &quick_xml::events::BytesStart,
     limits: &ParserLimits,
 ) -> Result<TextConstruct> {
     let mut content_type = TextType::Text;
 
-    for attr in e.attributes().flatten() {
+    for attr in limited_attributes(e, limits) {
         if attr.value.len() > limits.max_attribute_length {
             continue;
         }
@@ -467,7 +682,7 @@ fn parse_text_construct(
         }
     }
 
-    let value = read_text(reader, buf, limits)?;
+    let value = read_text(reader, limits)?;
 
     Ok(TextConstruct {
         value,
@@ -479,7 +694,7 @@ fn parse_text_construct(
 
 /// Parse <person> element (author, contributor)
 fn parse_person(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -495,10 +710,10 @@ fn parse_person(
                 check_depth(*depth, limits.max_nesting_depth)?;
 
                 match e.local_name().as_ref() {
-                    b"name" => name = Some(read_text(reader, buf, limits)?.into()),
-                    b"email" => email = Some(read_text(reader, buf, limits)?.into()),
-                    b"uri" => uri = Some(read_text(reader, buf, limits)?),
-                    _ => skip_element(reader, buf, limits, *depth)?,
+                    b"name" => name = Some(read_text(reader, limits)?.into()),
+                    b"email" => email = Some(read_text(reader, limits)?.into()),
+                    b"uri" => uri = Some(read_text(reader, limits)?),
+                    _ => skip_element(reader, limits, *depth)?,
                 }
                 *depth = depth.saturating_sub(1);
             }
@@ -520,27 +735,26 @@ fn parse_person(
 
 /// Parse <generator> element
 fn parse_generator(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     e: &quick_xml::events::BytesStart,
     limits: &ParserLimits,
 ) -> Result<Generator> {
     let mut uri = None;
     let mut version = None;
 
-    for attr in e.attributes().flatten() {
+    for attr in limited_attributes(e, limits) {
         if attr.value.len() > limits.max_attribute_length {
             continue;
         }
         match attr.key.as_ref() {
-            b"uri" => uri = Some(bytes_to_string(&attr.value)),
-            b"version" => version = Some(bytes_to_string(&attr.value).into()),
+            b"uri" => uri = Some(decode_attribute(&attr.value)),
+            b"version" => version = Some(decode_attribute(&attr.value).into()),
             _ => {}
         }
     }
 
     Ok(Generator {
-        value: read_text(reader, buf, limits)?,
+        value: read_text(reader, limits)?,
         uri,
         version,
     })
@@ -548,24 +762,23 @@ fn parse_generator(
 
 /// Parse <content> element
 fn parse_content(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     e: &quick_xml::events::BytesStart,
     limits: &ParserLimits,
 ) -> Result<Content> {
     let mut content_type = None;
 
-    for attr in e.attributes().flatten() {
+    for attr in limited_attributes(e, limits) {
         if attr.value.len() > limits.max_attribute_length {
             continue;
         }
         if attr.key.as_ref() == b"type" {
-            content_type = Some(bytes_to_string(&attr.value).into());
+            content_type = Some(decode_attribute(&attr.value).into());
         }
     }
 
     Ok(Content {
-        value: read_text(reader, buf, limits)?,
+        value: read_text(reader, limits)?,
         content_type,
         language: None,
         base: None,
@@ -573,38 +786,53 @@ fn parse_content(
 }
 
 /// Parse <source> element (renamed to avoid confusion with RSS source)
+///
+/// The source's `alternate` link is preferred, as for the feed itself, and
+/// any other link is kept only as a fallback.
 fn parse_atom_source(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
+    base_ctx: &BaseUrlContext,
 ) -> Result<Source> {
     let mut title = None;
-    let mut link = None;
+    let mut alternate = None;
+    let mut fallback = None;
     let mut id = None;
 
     loop {
         match reader.read_event_into(buf) {
-            Ok(Event::Start(e) | Event::Empty(e)) => {
+            Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+
                 *depth += 1;
                 check_depth(*depth, limits.max_nesting_depth)?;
 
-                let element = e.to_owned();
-                // Use name() instead of local_name() to preserve namespace prefixes
-                match element.name().as_ref() {
-                    b"title" => title = Some(read_text(reader, buf, limits)?),
+                match e.local_name().as_ref() {
+                    b"title" if !is_empty => title = Some(read_text(reader, limits)?),
+                    b"id" if !is_empty => id = Some(read_text(reader, limits)?),
                     b"link" => {
                         if let Some(l) = Link::from_attributes(
-                            element.attributes().flatten(),
+                            limited_attributes(e, limits),
                             limits.max_attribute_length,
-                        ) && link.is_none()
-                        {
-                            link = Some(l.href.to_string());
+                        ) {
+                            let href = base_ctx.resolve_safe(&l.href);
+                            if l.rel.as_deref() == Some("alternate") {
+                                alternate.get_or_insert(href);
+                            } else {
+                                fallback.get_or_insert(href);
+                            }
+                        }
+                        if !is_empty {
+                            skip_to_end(reader, b"link")?;
                         }
-                        skip_to_end(reader, buf, b"link")?;
                     }
-                    b"id" => id = Some(read_text(reader, buf, limits)?),
-                    _ => skip_element(reader, buf, limits, *depth)?,
+                    _ if !is_empty => skip_element(reader, limits, *depth)?,
+                    _ => {}
                 }
                 *depth = depth.saturating_sub(1);
             }
@@ -616,7 +844,77 @@ fn parse_atom_source(
         buf.clear();
     }
 
-    Ok(Source { title, link, id })
+    Ok(Source {
+        title,
+        link: alternate.or(fallback),
+        id,
+    })
+}
+
+/// Parse an `activity:object`, which nests Atom elements
+fn parse_activity_object(
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
+    limits: &ParserLimits,
+    depth: &mut usize,
+    base_ctx: &BaseUrlContext,
+) -> Result<ActivityObject> {
+    let mut object = ActivityObject::default();
+    let mut alternate = None;
+    let mut fallback = None;
+    let mut summary = None;
+
+    loop {
+        match reader.read_event_into(buf) {
+            Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+
+                *depth += 1;
+                check_depth(*depth, limits.max_nesting_depth)?;
+
+                match e.name().as_ref() {
+                    b"id" if !is_empty => object.id = Some(read_text(reader, limits)?),
+                    b"title" if !is_empty => object.title = Some(read_text(reader, limits)?),
+                    b"content" if !is_empty => object.content = Some(read_text(reader, limits)?),
+                    b"summary" if !is_empty => summary = Some(read_text(reader, limits)?),
+                    b"activity:object-type" if !is_empty => {
+                        object.object_type = Some(read_text(reader, limits)?);
+                    }
+                    b"link" => {
+                        if let Some(l) = Link::from_attributes(
+                            limited_attributes(e, limits),
+                            limits.max_attribute_length,
+                        ) {
+                            let href = base_ctx.resolve_safe(&l.href);
+                            if l.rel.as_deref().is_none_or(|rel| rel == "alternate") {
+                                alternate.get_or_insert(href);
+                            } else {
+                                fallback.get_or_insert(href);
+                            }
+                        }
+                        if !is_empty {
+                            skip_to_end(reader, b"link")?;
+                        }
+                    }
+                    _ if !is_empty => skip_element(reader, limits, *depth)?,
+                    _ => {}
+                }
+                *depth = depth.saturating_sub(1);
+            }
+            Ok(Event::End(e)) if e.name().as_ref() == b"activity:object" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    object.link = alternate.or(fallback);
+    object.content = object.content.or(summary);
+    Ok(object)
 }
 
 #[cfg(test)]
@@ -770,6 +1068,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_atom_relative_icon_and_logo() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom" xml:base="http://example.com/blog/">
+            <icon>/favicon.ico</icon>
+            <logo>logo.png</logo>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        assert_eq!(
+            feed.feed.icon.as_deref(),
+            Some("http://example.com/favicon.ico")
+        );
+        assert_eq!(
+            feed.feed.logo.as_deref(),
+            Some("http://example.com/blog/logo.png")
+        );
+        assert_eq!(feed.feed.raw_icon.as_deref(), Some("/favicon.ico"));
+        assert_eq!(feed.feed.raw_logo.as_deref(), Some("logo.png"));
+    }
+
     #[test]
     fn test_parse_atom_with_rights() {
         let xml = br#"<?xml version="1.0"?>
@@ -860,6 +1179,29 @@ mod tests {
         assert_eq!(source.id.as_deref(), Some("source-id"));
     }
 
+    #[test]
+    fn test_parse_atom_source_prefers_alternate_link() {
+        let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom" xml:base="http://example.com/">
+            <entry>
+                <source>
+                    <link rel="self" href="origin/atom.xml"/>
+                    <link href="origin/"/>
+                    <id>urn:origin</id>
+                    <title>Origin</title>
+                </source>
+                <title>Entry</title>
+            </entry>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        let entry = &feed.entries[0];
+        let source = entry.source.as_ref().unwrap();
+        assert_eq!(source.link.as_deref(), Some("http://example.com/origin/"));
+        assert_eq!(source.id.as_deref(), Some("urn:origin"));
+        assert_eq!(source.title.as_deref(), Some("Origin"));
+        assert_eq!(entry.title.as_deref(), Some("Entry"));
+    }
+
     #[test]
     fn test_parse_atom_multiple_links() {
         let xml = br#"<?xml version="1.0"?>
@@ -951,6 +1293,58 @@ mod tests {
         assert_eq!(feed.feed.link.as_deref(), Some("https://example.com/"));
     }
 
+    #[test]
+    fn test_parse_atom_link_rels() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <link rel="http://www.iana.org/assignments/relation/License"
+                  href="https://creativecommons.org/licenses/by/4.0/"/>
+            <link rel="SELF" href="https://example.com/feed"/>
+            <link rel="hub" href="https://hub.example.com/"/>
+            <link rel="payment" href="https://example.com/donate"/>
+            <link rel="https://example.com/rels/custom" href="https://example.com/x"/>
+            <entry>
+                <id>urn:uuid:1</id>
+                <link rel="via" href="https://other.example.com/post"/>
+                <link rel="enclosure" type="audio/mpeg" length="1234"
+                      href="https://example.com/episode.mp3"/>
+            </entry>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        assert_eq!(
+            feed.feed.license.as_deref(),
+            Some("https://creativecommons.org/licenses/by/4.0/")
+        );
+        let rels: Vec<_> = feed
+            .feed
+            .links
+            .iter()
+            .filter_map(|l| l.rel.as_deref())
+            .collect();
+        assert_eq!(
+            rels,
+            [
+                "license",
+                "self",
+                "hub",
+                "payment",
+                "https://example.com/rels/custom"
+            ]
+        );
+
+        let entry = &feed.entries[0];
+        assert_eq!(entry.links[0].rel.as_deref(), Some("via"));
+        assert_eq!(entry.links[1].rel.as_deref(), Some("enclosure"));
+        assert_eq!(entry.enclosures.len(), 1);
+        assert_eq!(entry.enclosures[0].url, "https://example.com/episode.mp3");
+        assert_eq!(entry.enclosures[0].length, Some(1234));
+        assert_eq!(
+            entry.enclosures[0].enclosure_type.as_deref(),
+            Some("audio/mpeg")
+        );
+    }
+
     #[test]
     fn test_parse_atom_license_entry() {
         let xml = br#"<?xml version="1.0"?>
@@ -974,4 +1368,127 @@ mod tests {
             Some("https://example.com/entry/1")
         );
     }
+
+    #[test]
+    fn test_parse_atom_feed_history() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom"
+              xmlns:fh="http://purl.org/syndication/history/1.0">
+            <title>Archive</title>
+            <fh:archive/>
+            <link rel="current" href="https://example.com/feed"/>
+            <link rel="prev-archive" href="https://example.com/archive/2"/>
+            <entry><title>Old</title><id>urn:uuid:1</id></entry>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        assert!(!feed.bozo);
+        let history = feed.history.unwrap();
+        assert!(history.archive);
+        assert!(!history.complete);
+        assert_eq!(history.current.as_deref(), Some("https://example.com/feed"));
+        assert_eq!(
+            history.prev_archive.as_deref(),
+            Some("https://example.com/archive/2")
+        );
+        assert_eq!(feed.feed.links.len(), 2);
+        assert_eq!(feed.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_atom_entry_prism() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom"
+              xmlns:prism="http://prismstandard.org/namespaces/basic/2.0/">
+            <title>Journal</title>
+            <entry>
+                <title>Article</title>
+                <id>urn:uuid:1</id>
+                <prism:publicationName>Journal of Tests</prism:publicationName>
+                <prism:doi>doi:10.1000/test.2</prism:doi>
+                <prism:volume/>
+            </entry>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        assert!(!feed.bozo);
+        let prism = feed.entries[0].prism.as_ref().unwrap();
+        assert_eq!(prism.publication_name.as_deref(), Some("Journal of Tests"));
+        assert_eq!(prism.doi.as_deref(), Some("10.1000/test.2"));
+        assert_eq!(prism.volume, None);
+    }
+
+    #[test]
+    fn test_parse_atom_entry_gdata() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom"
+              xmlns:gd="http://schemas.google.com/g/2005">
+            <title>Calendar</title>
+            <entry>
+                <title>Standup</title>
+                <id>urn:uuid:1</id>
+                <gd:when startTime="2024-06-01T09:00:00Z" endTime="2024-06-01T09:15:00Z">
+                    <gd:reminder minutes="10"/>
+                </gd:when>
+                <gd:where valueString="Room 2"/>
+                <gd:rating value="4" min="1" max="5"/>
+                <gd:extendedProperty name="team" value="core"/>
+            </entry>
+        </feed>"#;
+
+        let feed = parse_atom10(xml).unwrap();
+        assert!(!feed.bozo);
+        assert_eq!(feed.entries[0].title.as_deref(), Some("Standup"));
+        let gdata = feed.entries[0].gdata.as_ref().unwrap();
+        assert_eq!(gdata.when.len(), 1);
+        assert!(gdata.start_time().is_some());
+        assert_eq!(gdata.location(), Some("Room 2"));
+        assert_eq!(gdata.rating.as_ref().unwrap().normalized(), Some(0.75));
+        assert_eq!(gdata.extended_property("team"), Some("core"));
+    }
+
+    #[test]
+    fn test_parse_atom_entry_activity() {
+        let xml = br#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom"
+              xmlns:activity="http://activitystrea.ms/spec/1.0/">
+            <title>Timeline</title>
+            <entry>
+                <title>alice repeated a notice</title>
+                <id>tag:example.com,2024:share-1</id>
+                <activity:verb>http://activitystrea.ms/schema/1.0/share</activity:verb>
+                <activity:object-type>http://activitystrea.ms/schema/1.0/activity</activity:object-type>
+                <activity:object>
+                    <id>tag:example.com,2024:note-9</id>
+                    <title>Original</title>
+                    <activity:object-type>http://activitystrea.ms/schema/1.0/note</activity:object-type>
+                    <link rel="alternate" type="text/html" href="/notice/9"/>
+                    <content type="html">Hello</content>
+                </activity:object>
+                <link rel="alternate" href="https://example.com/notice/10"/>
+            </entry>
+            <entry>
+                <title>Plain post</title>
+                <id>tag:example.com,2024:note-11</id>
+            </entry>
+        </feed>"#;
+
+        let feed = crate::parse_with_base_url(xml, "https://example.com/feed").unwrap();
+        assert!(!feed.bozo);
+        let entry = &feed.entries[0];
+        assert_eq!(entry.link.as_deref(), Some("https://example.com/notice/10"));
+        let activity = entry.activity.as_ref().unwrap();
+        assert_eq!(activity.verb_name(), "share");
+        assert_eq!(activity.object_type_name(), Some("activity"));
+        let object = activity.object.as_ref().unwrap();
+        assert_eq!(object.id.as_deref(), Some("tag:example.com,2024:note-9"));
+        assert_eq!(object.title.as_deref(), Some("Original"));
+        assert_eq!(object.link.as_deref(), Some("https://example.com/notice/9"));
+        assert_eq!(object.content.as_deref(), Some("Hello"));
+        assert_eq!(
+            object.object_type.as_deref().map(activity::short_name),
+            Some("note")
+        );
+        assert!(feed.entries[1].activity.is_none());
+    }
 }
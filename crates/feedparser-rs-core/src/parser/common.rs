@@ -3,15 +3,25 @@
 //! This module eliminates code duplication by providing shared functionality
 //! for XML parsing operations used by both feed formats.
 
+use super::XmlReader;
 use crate::{
-    ParserLimits,
+    ParseOptions, ParserLimits,
     error::{FeedError, Result},
-    types::{FeedVersion, ParsedFeed},
+    namespace::license,
+    types::{FeedVersion, LicenseInfo, ParsedFeed},
+    util::entities::{decode_attribute, expand_reference},
+};
+use quick_xml::events::{BytesRef, BytesStart, Event, attributes::Attribute};
+use std::borrow::Cow;
+use std::ops::Range;
+
+#[cfg(feature = "mediarss")]
+use crate::{
+    namespace::media_rss,
+    types::{Entry, MediaContent, MediaThumbnail},
 };
-use quick_xml::{Reader, events::Event};
 
 pub use crate::types::{FromAttributes, LimitedCollectionExt};
-pub use crate::util::text::bytes_to_string;
 
 /// Initial capacity for XML event buffer (fits most elements)
 pub const EVENT_BUFFER_CAPACITY: usize = 1024;
@@ -76,7 +86,7 @@ pub fn new_text_buffer() -> String {
 #[allow(dead_code)]
 pub struct ParseContext<'a> {
     /// XML reader
-    pub reader: Reader<&'a [u8]>,
+    pub reader: XmlReader<'a>,
     /// Reusable buffer for XML events
     pub buf: Vec<u8>,
     /// Parser limits for validation
@@ -93,7 +103,7 @@ impl<'a> ParseContext<'a> {
             .check_feed_size(data.len())
             .map_err(|e| FeedError::InvalidFormat(e.to_string()))?;
 
-        let mut reader = Reader::from_reader(data);
+        let mut reader = XmlReader::from_reader(data);
         reader.config_mut().trim_text(true);
 
         Ok(Self {
@@ -142,6 +152,36 @@ pub fn init_feed(version: FeedVersion, max_entries: usize) -> ParsedFeed {
     feed
 }
 
+/// Entries a parse keeps, by position in the document
+///
+/// Built from [`ParseOptions::entry_range`]; parsers call
+/// [`admit`](Self::admit) once per entry element, in document order, and
+/// skip the element without building an entry when it returns `false`.
+#[derive(Debug, Clone, Default)]
+pub struct EntryWindow {
+    range: Option<Range<usize>>,
+    next: usize,
+}
+
+impl EntryWindow {
+    /// Creates a window over `options.entry_range`
+    pub fn new(options: &ParseOptions) -> Self {
+        Self {
+            range: options.entry_range.clone(),
+            next: 0,
+        }
+    }
+
+    /// Moves past the next entry, returning whether it falls in the window
+    pub fn admit(&mut self) -> bool {
+        let index = self.next;
+        self.next += 1;
+        self.range
+            .as_ref()
+            .is_none_or(|range| range.contains(&index))
+    }
+}
+
 /// Check nesting depth and return error if exceeded
 ///
 /// This is a standalone helper for parsers that don't use `ParseContext`.
@@ -182,6 +222,7 @@ pub fn extract_ns_local_name<'a>(name: &'a [u8], prefix: &[u8]) -> Option<&'a st
     }
 }
 
+#[cfg(feature = "dublincore")]
 /// Check if element is a Dublin Core namespaced tag
 ///
 /// # Examples
@@ -237,6 +278,7 @@ pub fn is_media_tag(name: &[u8]) -> Option<&str> {
     extract_ns_local_name(name, b"media:")
 }
 
+#[cfg(feature = "georss")]
 /// Check if element is a `GeoRSS` namespaced tag
 ///
 /// # Examples
@@ -251,6 +293,77 @@ pub fn is_georss_tag(name: &[u8]) -> Option<&str> {
     extract_ns_local_name(name, b"georss:")
 }
 
+/// Check if element is a Feed History (RFC 5005) namespaced tag
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(is_fh_tag(b"fh:complete"), Some("complete"));
+/// assert_eq!(is_fh_tag(b"fh:archive"), Some("archive"));
+/// assert_eq!(is_fh_tag(b"dc:creator"), None);
+/// ```
+#[inline]
+pub fn is_fh_tag(name: &[u8]) -> Option<&str> {
+    extract_ns_local_name(name, b"fh:")
+}
+
+/// Check if element is a PRISM namespaced tag
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(is_prism_tag(b"prism:doi"), Some("doi"));
+/// assert_eq!(is_prism_tag(b"prism:startingPage"), Some("startingPage"));
+/// assert_eq!(is_prism_tag(b"dc:creator"), None);
+/// ```
+#[inline]
+pub fn is_prism_tag(name: &[u8]) -> Option<&str> {
+    extract_ns_local_name(name, b"prism:")
+}
+
+/// Check if element is a Google Data (`GData`) namespaced tag
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(is_gd_tag(b"gd:when"), Some("when"));
+/// assert_eq!(is_gd_tag(b"gd:extendedProperty"), Some("extendedProperty"));
+/// assert_eq!(is_gd_tag(b"dc:creator"), None);
+/// ```
+#[inline]
+pub fn is_gd_tag(name: &[u8]) -> Option<&str> {
+    extract_ns_local_name(name, b"gd:")
+}
+
+/// Check if element is an Activity Streams namespaced tag
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(is_activity_tag(b"activity:verb"), Some("verb"));
+/// assert_eq!(is_activity_tag(b"activity:object-type"), Some("object-type"));
+/// assert_eq!(is_activity_tag(b"dc:creator"), None);
+/// ```
+#[inline]
+pub fn is_activity_tag(name: &[u8]) -> Option<&str> {
+    extract_ns_local_name(name, b"activity:")
+}
+
+/// Check if element is a blogChannel namespaced tag
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(is_blog_channel_tag(b"blogChannel:blogRoll"), Some("blogRoll"));
+/// assert_eq!(is_blog_channel_tag(b"blogChannel:blink"), Some("blink"));
+/// assert_eq!(is_blog_channel_tag(b"dc:creator"), None);
+/// ```
+#[inline]
+pub fn is_blog_channel_tag(name: &[u8]) -> Option<&str> {
+    extract_ns_local_name(name, b"blogChannel:")
+}
+
+#[cfg(feature = "itunes")]
 /// Check if element matches an iTunes namespace tag
 ///
 /// Supports both prefixed (itunes:author) and unprefixed (author) forms
@@ -272,15 +385,41 @@ pub fn is_itunes_tag(name: &[u8], tag: &[u8]) -> bool {
     name == tag
 }
 
+/// Iterate over the attributes of an element, up to `max_attributes_per_element`
+///
+/// Malformed attributes are skipped but still count towards the limit, so
+/// an element with millions of attributes costs no more than one with the
+/// maximum.
+///
+/// # Examples
+///
+/// ```ignore
+/// use feedparser_rs::parser::common::limited_attributes;
+///
+/// for attr in limited_attributes(&element, limits) {
+///     // ...
+/// }
+/// ```
+pub fn limited_attributes<'a>(
+    element: &'a BytesStart<'_>,
+    limits: &ParserLimits,
+) -> impl Iterator<Item = Attribute<'a>> + use<'a> {
+    element
+        .attributes()
+        .take(limits.max_attributes_per_element)
+        .flatten()
+}
+
 /// Extract xml:base attribute from element
 ///
 /// Returns the base URL string if xml:base attribute exists.
-/// Respects `max_attribute_length` limit for `DoS` protection.
+/// Respects `max_attribute_length` and `max_attributes_per_element` limits
+/// for `DoS` protection.
 ///
 /// # Arguments
 ///
 /// * `element` - The XML element to extract xml:base from
-/// * `max_attr_length` - Maximum allowed attribute length (`DoS` protection)
+/// * `limits` - Parser limits (`DoS` protection)
 ///
 /// # Returns
 ///
@@ -293,35 +432,33 @@ pub fn is_itunes_tag(name: &[u8], tag: &[u8]) -> bool {
 /// use feedparser_rs::parser::common::extract_xml_base;
 ///
 /// let element = /* BytesStart from quick-xml */;
-/// if let Some(base) = extract_xml_base(&element, 1024) {
+/// if let Some(base) = extract_xml_base(&element, &ParserLimits::default()) {
 ///     println!("Base URL: {}", base);
 /// }
 /// ```
 pub fn extract_xml_base(
     element: &quick_xml::events::BytesStart,
-    max_attr_length: usize,
+    limits: &ParserLimits,
 ) -> Option<String> {
-    element
-        .attributes()
-        .flatten()
+    limited_attributes(element, limits)
         .find(|attr| {
             let key = attr.key.as_ref();
             key == b"xml:base" || key == b"base"
         })
-        .filter(|attr| attr.value.len() <= max_attr_length)
-        .and_then(|attr| attr.unescape_value().ok())
-        .map(|s| s.to_string())
+        .filter(|attr| attr.value.len() <= limits.max_attribute_length)
+        .map(|attr| decode_attribute(&attr.value))
 }
 
 /// Extract xml:lang attribute from element
 ///
 /// Returns the language code if xml:lang or lang attribute exists.
-/// Respects `max_attribute_length` limit for `DoS` protection.
+/// Respects `max_attribute_length` and `max_attributes_per_element` limits
+/// for `DoS` protection.
 ///
 /// # Arguments
 ///
 /// * `element` - The XML element to extract xml:lang from
-/// * `max_attr_length` - Maximum allowed attribute length (`DoS` protection)
+/// * `limits` - Parser limits (`DoS` protection)
 ///
 /// # Returns
 ///
@@ -334,78 +471,156 @@ pub fn extract_xml_base(
 /// use feedparser_rs::parser::common::extract_xml_lang;
 ///
 /// let element = /* BytesStart from quick-xml */;
-/// if let Some(lang) = extract_xml_lang(&element, 1024) {
+/// if let Some(lang) = extract_xml_lang(&element, &ParserLimits::default()) {
 ///     println!("Language: {}", lang);
 /// }
 /// ```
 pub fn extract_xml_lang(
     element: &quick_xml::events::BytesStart,
-    max_attr_length: usize,
+    limits: &ParserLimits,
 ) -> Option<String> {
-    element
-        .attributes()
-        .flatten()
+    limited_attributes(element, limits)
         .find(|attr| {
             let key = attr.key.as_ref();
             key == b"xml:lang" || key == b"lang"
         })
-        .filter(|attr| attr.value.len() <= max_attr_length)
-        .and_then(|attr| attr.unescape_value().ok())
-        .map(|s| s.to_string())
+        .filter(|attr| attr.value.len() <= limits.max_attribute_length)
+        .map(|attr| decode_attribute(&attr.value))
 }
 
 /// Read text content from current XML element (handles text and CDATA)
-pub fn read_text(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+///
+/// Events are borrowed straight from the input slice, so the common case of
+/// a single text node costs exactly one allocation of the right size.
+pub fn read_text(reader: &mut XmlReader<'_>, limits: &ParserLimits) -> Result<String> {
+    read_text_cow(reader, limits).map(Cow::into_owned)
+}
+
+/// Like [`read_text`], but borrows from the input when no copy is needed
+///
+/// Text made of a single valid UTF-8 node is returned without allocating,
+/// which suits values that are parsed further (dates, numbers, flags) and
+/// then dropped. Only text split across several nodes, e.g. around CDATA,
+/// or containing invalid UTF-8 is copied.
+pub fn read_text_cow<'a>(
+    reader: &mut XmlReader<'a>,
     limits: &ParserLimits,
-) -> Result<String> {
-    let mut text = String::with_capacity(TEXT_BUFFER_CAPACITY);
+) -> Result<Cow<'a, str>> {
+    let (text, trimmed) = with_untrimmed_text(reader, |reader| {
+        let mut text = Cow::Borrowed("");
+        // Whitespace-only text is held back until it turns out to sit
+        // between two runs of text rather than next to CDATA or a tag
+        let mut pending = None;
 
-    loop {
-        match reader.read_event_into(buf) {
-            Ok(Event::Text(e)) => {
-                append_bytes(&mut text, e.as_ref(), limits.max_text_length)?;
-            }
-            Ok(Event::CData(e)) => {
-                append_bytes(&mut text, e.as_ref(), limits.max_text_length)?;
+        loop {
+            let bytes = match reader.read_event()? {
+                Event::Text(e) if e.iter().all(u8::is_ascii_whitespace) => {
+                    pending = Some(e.into_inner());
+                    continue;
+                }
+                Event::Text(e) => e.into_inner(),
+                Event::CData(e) => {
+                    pending = None;
+                    e.into_inner()
+                }
+                Event::GeneralRef(e) => resolve_reference(&e),
+                Event::End(_) | Event::Eof => break,
+                _ => continue,
+            };
+            if let Some(whitespace) = pending.take() {
+                append_bytes(&mut text, whitespace, limits.max_text_length)?;
             }
-            Ok(Event::End(_) | Event::Eof) => break,
-            Err(e) => return Err(e.into()),
-            _ => {}
+            append_bytes(&mut text, bytes, limits.max_text_length)?;
         }
-        buf.clear();
+        Ok(text)
+    })?;
+
+    Ok(if trimmed {
+        trim_xml_whitespace(text)
+    } else {
+        text
+    })
+}
+
+/// Runs `f` with the reader's text trimming turned off
+///
+/// quick-xml trims every text node on its own, which also eats the spaces
+/// around an entity reference and turns `a &amp; b` into `a&b`. Callers
+/// read the text untrimmed instead and, if the returned flag is `true`,
+/// trim the assembled result.
+pub fn with_untrimmed_text<'a, T>(
+    reader: &mut XmlReader<'a>,
+    f: impl FnOnce(&mut XmlReader<'a>) -> Result<T>,
+) -> Result<(T, bool)> {
+    let config = reader.config_mut();
+    let (trim_start, trim_end) = (config.trim_text_start, config.trim_text_end);
+    config.trim_text(false);
+    let result = f(reader);
+    let config = reader.config_mut();
+    config.trim_text_start = trim_start;
+    config.trim_text_end = trim_end;
+    result.map(|value| (value, trim_start))
+}
+
+/// Trims XML whitespace (space, tab, CR, LF) from both ends, keeping a borrow
+pub fn trim_xml_whitespace(text: Cow<'_, str>) -> Cow<'_, str> {
+    let is_xml_space = |c: char| matches!(c, ' ' | '\t' | '\r' | '\n');
+    match text {
+        Cow::Borrowed(s) => Cow::Borrowed(s.trim_matches(is_xml_space)),
+        Cow::Owned(s) if s.trim_matches(is_xml_space).len() == s.len() => Cow::Owned(s),
+        Cow::Owned(s) => Cow::Owned(s.trim_matches(is_xml_space).to_string()),
     }
+}
 
-    Ok(text)
+/// Expands an entity or character reference that appeared in text
+///
+/// The five predefined XML entities and numeric character references are
+/// replaced by what they stand for, with invalid code points mapped as in
+/// [`decode_char_ref`](crate::util::entities::decode_char_ref). Anything
+/// else, such as an HTML entity like `&nbsp;` that the document never
+/// declared, is kept verbatim so that HTML content still renders it.
+pub fn resolve_reference(reference: &BytesRef<'_>) -> Cow<'static, [u8]> {
+    let name = String::from_utf8_lossy(reference);
+    match expand_reference(&name) {
+        Some(Cow::Borrowed(value)) => Cow::Borrowed(value.as_bytes()),
+        Some(Cow::Owned(value)) => Cow::Owned(value.into_bytes()),
+        None => Cow::Owned(format!("&{name};").into_bytes()),
+    }
 }
 
 #[inline]
-fn append_bytes(text: &mut String, bytes: &[u8], max_len: usize) -> Result<()> {
+fn append_bytes<'a>(text: &mut Cow<'a, str>, bytes: Cow<'a, [u8]>, max_len: usize) -> Result<()> {
     if text.len() + bytes.len() > max_len {
         return Err(FeedError::InvalidFormat(format!(
             "Text field exceeds maximum length of {max_len} bytes"
         )));
     }
-    match std::str::from_utf8(bytes) {
-        Ok(s) => text.push_str(s),
-        Err(_) => text.push_str(&String::from_utf8_lossy(bytes)),
+    let segment = match bytes {
+        Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes),
+        Cow::Owned(bytes) => match String::from_utf8(bytes) {
+            Ok(s) => Cow::Owned(s),
+            Err(e) => Cow::Owned(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+        },
+    };
+    if text.is_empty() {
+        *text = segment;
+    } else {
+        text.to_mut().push_str(&segment);
     }
     Ok(())
 }
 
 /// Skip unknown element and all its children (enforces nesting depth limits)
 pub fn skip_element(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     limits: &ParserLimits,
     current_depth: usize,
 ) -> Result<()> {
     let mut local_depth: usize = 1;
 
     loop {
-        match reader.read_event_into(buf) {
-            Ok(Event::Start(_)) => {
+        match reader.read_event()? {
+            Event::Start(_) => {
                 local_depth += 1;
                 if current_depth + local_depth > limits.max_nesting_depth {
                     return Err(FeedError::InvalidFormat(format!(
@@ -414,39 +629,197 @@ pub fn skip_element(
                     )));
                 }
             }
-            Ok(Event::End(_)) => {
+            Event::End(_) => {
                 local_depth = local_depth.saturating_sub(1);
                 if local_depth == 0 {
                     break;
                 }
             }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(e.into()),
+            Event::Eof => break,
             _ => {}
         }
-        buf.clear();
     }
 
     Ok(())
 }
 
 /// Skip to end of specified element (for attribute-only elements like `<link>`)
-pub fn skip_to_end(reader: &mut Reader<&[u8]>, buf: &mut Vec<u8>, tag: &[u8]) -> Result<()> {
+pub fn skip_to_end(reader: &mut XmlReader<'_>, tag: &[u8]) -> Result<()> {
     loop {
-        match reader.read_event_into(buf) {
-            Ok(Event::End(e)) if e.local_name().as_ref() == tag => break,
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(e.into()),
+        match reader.read_event()? {
+            Event::End(e) if e.local_name().as_ref() == tag => break,
+            Event::Eof => break,
             _ => {}
         }
-        buf.clear();
     }
     Ok(())
 }
 
+/// Parse a license element and merge it into a feed's or entry's license
+///
+/// `detail` and `value` are the `license_detail` and `license` fields; see
+/// [`license::merge`] for how sources combine.
+pub fn parse_license_element(
+    reader: &mut XmlReader<'_>,
+    element: &BytesStart,
+    detail: &mut Option<LicenseInfo>,
+    value: &mut Option<String>,
+    limits: &ParserLimits,
+    is_empty: bool,
+) -> Result<()> {
+    let attrs: Vec<_> = limited_attributes(element, limits)
+        .map(|attr| (attr.key.as_ref().to_vec(), decode_attribute(&attr.value)))
+        .collect();
+    let text = if is_empty {
+        Cow::Borrowed("")
+    } else {
+        read_text_cow(reader, limits)?
+    };
+    let found = license::license_from_element(element.name().as_ref(), &attrs, &text);
+    license::merge(detail, value, found);
+    Ok(())
+}
+
+/// Parse a `media:group` and add its renditions to `entry`
+///
+/// Every `media:content` in the group gets the same group index, one past
+/// the entry's last group. A rendition without thumbnails of its own takes
+/// the group's, as elements nested deeper override those above them in
+/// Media RSS.
+#[cfg(feature = "mediarss")]
+pub fn parse_media_group(
+    reader: &mut XmlReader<'_>,
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    depth: usize,
+) -> Result<()> {
+    let group = entry
+        .media_content
+        .iter()
+        .filter_map(|media| media.group)
+        .max()
+        .map_or(0, |last| last + 1);
+    let first = entry.media_content.len();
+
+    let thumbnails = parse_media_children(reader, entry, limits, depth, Some(group))?;
+    if !thumbnails.is_empty() {
+        for media in &mut entry.media_content[first..] {
+            if media.thumbnails.is_empty() {
+                media.thumbnails.clone_from(&thumbnails);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse a `media:content` element along with the thumbnails nested in it
+///
+/// `group` is the index of the enclosing `media:group`, if any.
+#[cfg(feature = "mediarss")]
+pub fn parse_media_content(
+    reader: &mut XmlReader<'_>,
+    element: &BytesStart,
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    is_empty: bool,
+    depth: usize,
+    group: Option<usize>,
+) -> Result<()> {
+    let media = MediaContent::from_attributes(
+        limited_attributes(element, limits),
+        limits.max_attribute_length,
+    );
+    let thumbnails = if is_empty {
+        Vec::new()
+    } else {
+        parse_media_children(reader, entry, limits, depth, None)?
+    };
+
+    if let Some(mut media) = media {
+        media.thumbnails = thumbnails;
+        media.group = group;
+        entry
+            .media_content
+            .try_push_limited(media, limits.max_media_content);
+    }
+    Ok(())
+}
+
+/// Parse the children of a `media:content` or `media:group` element
+///
+/// Thumbnails are added to `entry.media_thumbnails` in document order and
+/// also returned so the caller can attach them to their rendition. Inside a
+/// group (`group` is `Some`), `media:content` children are parsed as
+/// renditions. Text children such as `media:title` apply to the entry.
+#[cfg(feature = "mediarss")]
+pub fn parse_media_children(
+    reader: &mut XmlReader<'_>,
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    depth: usize,
+    group: Option<usize>,
+) -> Result<Vec<MediaThumbnail>> {
+    let mut thumbnails = Vec::new();
+    let child_depth = depth + 1;
+
+    loop {
+        let (element, is_empty) = match reader.read_event()? {
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(_) | Event::Eof => break,
+            _ => continue,
+        };
+        check_depth(child_depth, limits.max_nesting_depth)?;
+
+        match is_media_tag(element.name().as_ref()) {
+            Some("thumbnail") => {
+                if let Some(thumbnail) = MediaThumbnail::from_attributes(
+                    limited_attributes(&element, limits),
+                    limits.max_attribute_length,
+                ) {
+                    if thumbnails.len() < limits.max_media_thumbnails {
+                        thumbnails.push(thumbnail.clone());
+                    }
+                    entry
+                        .media_thumbnails
+                        .try_push_limited(thumbnail, limits.max_media_thumbnails);
+                }
+                if !is_empty {
+                    skip_element(reader, limits, child_depth)?;
+                }
+            }
+            Some("content") if group.is_some() => {
+                parse_media_content(
+                    reader,
+                    &element,
+                    entry,
+                    limits,
+                    is_empty,
+                    child_depth,
+                    group,
+                )?;
+            }
+            Some("content" | "group") | None => {
+                if !is_empty {
+                    skip_element(reader, limits, child_depth)?;
+                }
+            }
+            Some(media_element) => {
+                if !is_empty {
+                    let media_elem = media_element.to_string();
+                    let text = read_text_cow(reader, limits)?;
+                    media_rss::handle_entry_element(&media_elem, &text, entry);
+                }
+            }
+        }
+    }
+    Ok(thumbnails)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::text::bytes_to_string;
 
     #[test]
     fn test_bytes_to_string_valid_utf8() {
@@ -464,7 +837,7 @@ mod tests {
     #[test]
     fn test_read_text_basic() {
         let xml = b"<title>Test Title</title>";
-        let mut reader = Reader::from_reader(&xml[..]);
+        let mut reader = XmlReader::from_reader(&xml[..]);
         reader.config_mut().trim_text(true);
         let mut buf = Vec::new();
         let limits = ParserLimits::default();
@@ -480,14 +853,14 @@ mod tests {
         }
         buf.clear();
 
-        let text = read_text(&mut reader, &mut buf, &limits).unwrap();
+        let text = read_text(&mut reader, &limits).unwrap();
         assert_eq!(text, "Test Title");
     }
 
     #[test]
     fn test_read_text_exceeds_limit() {
         let xml = b"<title>This is a very long title</title>";
-        let mut reader = Reader::from_reader(&xml[..]);
+        let mut reader = XmlReader::from_reader(&xml[..]);
         reader.config_mut().trim_text(true);
         let mut buf = Vec::new();
         let limits = ParserLimits {
@@ -506,14 +879,56 @@ mod tests {
         }
         buf.clear();
 
-        let result = read_text(&mut reader, &mut buf, &limits);
+        let result = read_text(&mut reader, &limits);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_read_text_cow_borrows_single_node() {
+        let xml = b"<a>plain</a><b>one<![CDATA[ & two]]></b>";
+        let mut reader = XmlReader::from_reader(&xml[..]);
+        let limits = ParserLimits::default();
+
+        reader.read_event().unwrap();
+        let text = read_text_cow(&mut reader, &limits).unwrap();
+        assert!(matches!(text, Cow::Borrowed("plain")));
+
+        reader.read_event().unwrap();
+        let text = read_text_cow(&mut reader, &limits).unwrap();
+        assert!(matches!(text, Cow::Owned(_)));
+        assert_eq!(text, "one & two");
+    }
+
+    #[test]
+    fn test_read_text_expands_references() {
+        let xml = b"<d>&lt;p&gt;Fish &amp; chips&#33; caf&#xE9;&nbsp;&#0;&#x;</d>";
+        let mut reader = XmlReader::from_reader(&xml[..]);
+        let limits = ParserLimits::default();
+
+        reader.read_event().unwrap();
+        let text = read_text(&mut reader, &limits).unwrap();
+        assert_eq!(text, "<p>Fish & chips! caf\u{e9}&nbsp;\u{FFFD}&#x;");
+    }
+
+    #[test]
+    fn test_read_text_trims_around_references() {
+        let xml = b"<d>\n  Fish &amp; chips\n</d><e> <![CDATA[ x ]]> </e>";
+        let mut reader = XmlReader::from_reader(&xml[..]);
+        reader.config_mut().trim_text(true);
+        let limits = ParserLimits::default();
+
+        reader.read_event().unwrap();
+        assert_eq!(read_text(&mut reader, &limits).unwrap(), "Fish & chips");
+        assert!(reader.config().trim_text_start);
+
+        reader.read_event().unwrap();
+        assert_eq!(read_text(&mut reader, &limits).unwrap(), "x");
+    }
+
     #[test]
     fn test_skip_element_basic() {
         let xml = b"<parent><child>content</child></parent>";
-        let mut reader = Reader::from_reader(&xml[..]);
+        let mut reader = XmlReader::from_reader(&xml[..]);
         reader.config_mut().trim_text(true);
         let mut buf = Vec::new();
         let limits = ParserLimits::default();
@@ -530,7 +945,7 @@ mod tests {
         }
         buf.clear();
 
-        let result = skip_element(&mut reader, &mut buf, &limits, depth);
+        let result = skip_element(&mut reader, &limits, depth);
         assert!(result.is_ok());
     }
 }
@@ -1,7 +1,8 @@
 //! Feed format detection from XML/JSON content
 
 use crate::types::FeedVersion;
-use quick_xml::{Reader, events::Event};
+use crate::util::{json_top_level_string, root_tag};
+use memchr::memmem;
 
 /// H1: Maximum size for JSON detection to prevent memory exhaustion
 /// We only need to read the "version" field which is at the start
@@ -34,21 +35,105 @@ const MAX_JSON_DETECTION_SIZE: usize = 1024 * 1024; // 1MB
 /// ```
 #[must_use]
 pub fn detect_format(data: &[u8]) -> FeedVersion {
-    // Check for JSON Feed (starts with '{')
-    let first_non_whitespace = data.iter().find(|&&b| !b.is_ascii_whitespace()).copied();
+    detect_format_detailed(data).version
+}
 
-    if first_non_whitespace == Some(b'{') {
-        return detect_json_feed_version(data);
+/// Confidence for formats identified from an explicit marker, such as a
+/// `version` attribute, a known namespace or a JSON Feed version URL
+const CONFIDENT: f64 = 1.0;
+
+/// Confidence for formats inferred without an explicit marker, such as an
+/// `<rss>` root with no `version` or a large JSON document matched by search
+const INFERRED: f64 = 0.6;
+
+/// Result of [`detect_format_detailed`]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FormatDetection {
+    /// Detected format version
+    pub version: FeedVersion,
+    /// How sure the detection is, from 0.0 (unrecognized) to 1.0
+    ///
+    /// 1.0 means the document declared its format explicitly; lower values
+    /// mean the version was assumed from the root element alone.
+    pub confidence: f64,
+}
+
+impl FormatDetection {
+    const fn new(version: FeedVersion, confidence: f64) -> Self {
+        Self {
+            version,
+            confidence,
+        }
     }
 
-    // Parse XML to find root element
-    detect_xml_format(data)
+    const fn unknown() -> Self {
+        Self::new(FeedVersion::Unknown, 0.0)
+    }
+}
+
+/// Auto-detect feed format and report how confident the detection is
+///
+/// Like [`detect_format`], but also returns a confidence score so callers
+/// can treat ambiguous inputs differently from explicitly versioned ones.
+/// Use [`FeedVersion::family`] and [`FeedVersion::content_type`] on the
+/// result to branch on RSS/Atom/JSON or pick a MIME type.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::{FeedFamily, FeedVersion, detect_format_detailed};
+///
+/// let explicit = detect_format_detailed(br#"<rss version="2.0"></rss>"#);
+/// assert_eq!(explicit.version, FeedVersion::Rss20);
+/// assert_eq!(explicit.version.family(), FeedFamily::Rss);
+///
+/// let assumed = detect_format_detailed(b"<rss></rss>");
+/// assert_eq!(assumed.version, FeedVersion::Rss20);
+/// assert!(assumed.confidence < explicit.confidence);
+/// ```
+#[must_use]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.detect",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            version = tracing::field::Empty,
+            confidence = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn detect_format_detailed(data: &[u8]) -> FormatDetection {
+    // Check for JSON Feed (starts with '{')
+    let first_non_whitespace = data.iter().find(|&&b| !b.is_ascii_whitespace()).copied();
+
+    let detection = if first_non_whitespace == Some(b'{') {
+        detect_json_feed_version(data)
+    } else {
+        // Parse XML to find root element
+        detect_xml_format(data)
+    };
+    record_span!(
+        version = detection.version.as_str(),
+        confidence = detection.confidence,
+    );
+    detection
 }
 
 /// Detect JSON Feed version from JSON data
 ///
-/// H1: Uses size limit to prevent memory exhaustion from large JSON files.
-fn detect_json_feed_version(data: &[u8]) -> FeedVersion {
+/// A top-level `version` member is read straight from the bytes, so the
+/// usual case never builds a `serde_json::Value` for the whole document.
+/// H1: Full parsing, the fallback for unusual layouts, is capped in size to
+/// prevent memory exhaustion from large JSON files.
+fn detect_json_feed_version(data: &[u8]) -> FormatDetection {
+    if let Some(version) = json_top_level_string(data, "version") {
+        return json_feed_version(version).map_or_else(FormatDetection::unknown, |v| {
+            FormatDetection::new(v, CONFIDENT)
+        });
+    }
+
     // H1: Check size limit before parsing to prevent memory exhaustion
     if data.len() > MAX_JSON_DETECTION_SIZE {
         // For detection, we only need to find the "version" field
@@ -62,97 +147,88 @@ fn detect_json_feed_version(data: &[u8]) -> FeedVersion {
     // Try to parse as JSON and check version field
     if let Ok(json) = serde_json::from_slice::<serde_json::Value>(data)
         && let Some(version) = json.get("version").and_then(|v| v.as_str())
+        && let Some(version) = json_feed_version(version.as_bytes())
     {
-        return match version {
-            "https://jsonfeed.org/version/1" => FeedVersion::JsonFeed10,
-            "https://jsonfeed.org/version/1.1" => FeedVersion::JsonFeed11,
-            _ => FeedVersion::Unknown,
-        };
+        return FormatDetection::new(version, CONFIDENT);
     }
-    FeedVersion::Unknown
+    FormatDetection::unknown()
 }
 
-/// Fallback detection for large JSON files using string search
-fn detect_json_version_from_partial(data: &[u8]) -> FeedVersion {
-    // Simple byte search for version field patterns
-    // This is a heuristic fallback for oversized JSON
-    let data_str = std::str::from_utf8(data).unwrap_or("");
+/// Maps a JSON Feed `version` URL, escaped or not, to its [`FeedVersion`]
+fn json_feed_version(url: &[u8]) -> Option<FeedVersion> {
+    match url {
+        b"https://jsonfeed.org/version/1" | br"https:\/\/jsonfeed.org\/version\/1" => {
+            Some(FeedVersion::JsonFeed10)
+        }
+        b"https://jsonfeed.org/version/1.1" | br"https:\/\/jsonfeed.org\/version\/1.1" => {
+            Some(FeedVersion::JsonFeed11)
+        }
+        _ => None,
+    }
+}
 
-    if data_str.contains("https://jsonfeed.org/version/1.1") {
-        FeedVersion::JsonFeed11
-    } else if data_str.contains("https://jsonfeed.org/version/1") {
-        FeedVersion::JsonFeed10
+/// Fallback detection for large JSON files using byte search
+fn detect_json_version_from_partial(data: &[u8]) -> FormatDetection {
+    // This is a heuristic fallback for oversized JSON
+    if memmem::find(data, b"https://jsonfeed.org/version/1.1").is_some() {
+        FormatDetection::new(FeedVersion::JsonFeed11, INFERRED)
+    } else if memmem::find(data, b"https://jsonfeed.org/version/1").is_some() {
+        FormatDetection::new(FeedVersion::JsonFeed10, INFERRED)
     } else {
-        FeedVersion::Unknown
+        FormatDetection::unknown()
     }
 }
 
 /// Detect XML-based feed format (RSS or Atom)
-fn detect_xml_format(data: &[u8]) -> FeedVersion {
-    let mut reader = Reader::from_reader(data);
-    reader.config_mut().trim_text(true);
-
-    let mut buf = Vec::new();
-
-    // Read events until we find the root element
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e) | Event::Empty(e)) => {
-                let name = e.local_name();
-
-                match name.as_ref() {
-                    b"rss" => {
-                        // Check version attribute
-                        for attr in e.attributes().flatten() {
-                            if attr.key.as_ref() == b"version" {
-                                return match attr.value.as_ref() {
-                                    b"0.90" => FeedVersion::Rss090,
-                                    b"0.91" => FeedVersion::Rss091,
-                                    b"0.92" => FeedVersion::Rss092,
-                                    b"2.0" => FeedVersion::Rss20,
-                                    _ => FeedVersion::Unknown,
-                                };
-                            }
-                        }
-                        // No version attribute, assume 2.0
-                        return FeedVersion::Rss20;
-                    }
-                    b"rdf:RDF" | b"RDF" => {
-                        // RSS 1.0 uses RDF
-                        return FeedVersion::Rss10;
-                    }
-                    b"feed" => {
-                        // Atom - check xmlns attribute
-                        for attr in e.attributes().flatten() {
-                            if attr.key.as_ref() == b"xmlns" {
-                                let ns = attr.value.as_ref();
-                                if ns == b"http://www.w3.org/2005/Atom" {
-                                    return FeedVersion::Atom10;
-                                } else if ns == b"http://purl.org/atom/ns#" {
-                                    return FeedVersion::Atom03;
-                                }
-                            }
-                        }
-                        // No xmlns or unknown, assume Atom 1.0
-                        return FeedVersion::Atom10;
-                    }
-                    _ => {
-                        // Unknown root element
-                        return FeedVersion::Unknown;
-                    }
-                }
+///
+/// Only the root start tag matters, so it is located with `memchr` scans
+/// rather than by tokenizing the document.
+fn detect_xml_format(data: &[u8]) -> FormatDetection {
+    let Some(root) = root_tag(data) else {
+        return FormatDetection::unknown();
+    };
+
+    match root.local_name() {
+        b"rss" => match root.attribute(b"version") {
+            Some(version) => {
+                let version = match version {
+                    b"0.90" => FeedVersion::Rss090,
+                    b"0.91" => FeedVersion::Rss091,
+                    b"0.92" => FeedVersion::Rss092,
+                    b"2.0" => FeedVersion::Rss20,
+                    _ => return FormatDetection::unknown(),
+                };
+                FormatDetection::new(version, CONFIDENT)
             }
-            Ok(Event::Eof) => break,
-            Err(_) => {
-                // XML parsing error, can't detect
-                break;
+            // No version attribute, assume 2.0
+            None => FormatDetection::new(FeedVersion::Rss20, INFERRED),
+        },
+        // RSS 1.0 uses RDF
+        b"RDF" => FormatDetection::new(FeedVersion::Rss10, CONFIDENT),
+        b"feed" => match root.attribute(b"xmlns") {
+            Some(b"http://www.w3.org/2005/Atom") => {
+                FormatDetection::new(FeedVersion::Atom10, CONFIDENT)
             }
-            _ => {}
-        }
-        buf.clear();
+            Some(b"http://purl.org/atom/ns#") => {
+                FormatDetection::new(FeedVersion::Atom03, CONFIDENT)
+            }
+            // No xmlns or unknown, assume Atom 1.0
+            _ => FormatDetection::new(FeedVersion::Atom10, INFERRED),
+        },
+        _ => FormatDetection::unknown(),
     }
+}
 
-    FeedVersion::Unknown
+/// Returns the `version` attribute of the root element, if any
+///
+/// Reads only up to the root start tag. The value is returned verbatim, so
+/// undeclared or unrecognized versions can be reported alongside the
+/// normalized [`FeedVersion`].
+pub fn declared_xml_version(data: &[u8]) -> Option<String> {
+    let version = root_tag(data)?.attribute(b"version")?;
+    let version = std::str::from_utf8(version).ok()?;
+    let version = quick_xml::escape::unescape(version).ok()?;
+    Some(version.trim().to_string())
 }
 
 #[cfg(test)]
@@ -165,6 +241,15 @@ mod tests {
         assert_eq!(detect_format(xml), FeedVersion::Rss20);
     }
 
+    #[test]
+    fn test_declared_xml_version() {
+        let xml = br#"<?xml version="1.0"?><rss version=" 2.0.1 "><channel/></rss>"#;
+        assert_eq!(declared_xml_version(xml).as_deref(), Some("2.0.1"));
+        let xml = br#"<feed version="0.3" xmlns="http://purl.org/atom/ns#"/>"#;
+        assert_eq!(declared_xml_version(xml).as_deref(), Some("0.3"));
+        assert_eq!(declared_xml_version(b"<rss><channel/></rss>"), None);
+    }
+
     #[test]
     fn test_detect_rss20_no_version() {
         let xml = br#"<?xml version="1.0"?><rss></rss>"#;
@@ -231,6 +316,19 @@ mod tests {
         assert_eq!(detect_format(json), FeedVersion::JsonFeed11);
     }
 
+    #[test]
+    fn test_detect_json_feed_escaped_version() {
+        let json = br#"{"version": "https:\/\/jsonfeed.org\/version\/1.1"}"#;
+        assert_eq!(detect_format(json), FeedVersion::JsonFeed11);
+    }
+
+    #[test]
+    fn test_detect_json_feed_version_after_items() {
+        // The first "version" key is nested, so detection falls back to serde
+        let json = br#"{"items": [{"version": "x"}], "version": "https://jsonfeed.org/version/1"}"#;
+        assert_eq!(detect_format(json), FeedVersion::JsonFeed10);
+    }
+
     #[test]
     fn test_detect_unknown_xml() {
         let xml = br"<unknown></unknown>";
@@ -268,20 +366,38 @@ mod tests {
 
         let json_11 = br#"{"version": "https://jsonfeed.org/version/1.1", "title": "Test"}"#;
         assert_eq!(
-            detect_json_version_from_partial(json_11),
+            detect_json_version_from_partial(json_11).version,
             FeedVersion::JsonFeed11
         );
 
         let json_10 = br#"{"version": "https://jsonfeed.org/version/1", "title": "Test"}"#;
         assert_eq!(
-            detect_json_version_from_partial(json_10),
+            detect_json_version_from_partial(json_10).version,
             FeedVersion::JsonFeed10
         );
 
         let unknown = br#"{"title": "No version field"}"#;
         assert_eq!(
-            detect_json_version_from_partial(unknown),
+            detect_json_version_from_partial(unknown).version,
             FeedVersion::Unknown
         );
     }
+
+    #[test]
+    fn test_detect_format_detailed_confidence() {
+        let explicit = detect_format_detailed(br#"<feed xmlns="http://www.w3.org/2005/Atom"/>"#);
+        assert_eq!(explicit.version, FeedVersion::Atom10);
+        assert!((explicit.confidence - CONFIDENT).abs() < f64::EPSILON);
+
+        let assumed = detect_format_detailed(b"<feed/>");
+        assert_eq!(assumed.version, FeedVersion::Atom10);
+        assert!((assumed.confidence - INFERRED).abs() < f64::EPSILON);
+
+        let json = detect_format_detailed(br#"{"version": "https://jsonfeed.org/version/1.1"}"#);
+        assert_eq!(json.version, FeedVersion::JsonFeed11);
+        assert!((json.confidence - CONFIDENT).abs() < f64::EPSILON);
+
+        let unknown = detect_format_detailed(b"<html></html>");
+        assert_eq!(unknown, FormatDetection::unknown());
+    }
 }
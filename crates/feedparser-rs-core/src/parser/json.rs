@@ -2,12 +2,14 @@
 //!
 //! Specification: <https://www.jsonfeed.org/version/1.1/>
 
+use super::common::EntryWindow;
 use crate::{
-    ParserLimits,
+    ParseOptions, ParserLimits,
     error::{FeedError, Result},
+    limits::MemoryBudget,
     types::{
         Content, Enclosure, Entry, FeedMeta, FeedVersion, Image, LimitedCollectionExt, Link,
-        ParseFrom, ParsedFeed, Person, Tag, TextConstruct,
+        MediaThumbnail, ParseFrom, ParsedFeed, Person, Tag, TextConstruct,
     },
     util::{date::parse_date, text::truncate_to_length},
 };
@@ -21,6 +23,29 @@ pub fn parse_json_feed(data: &[u8]) -> Result<ParsedFeed> {
 
 /// Parse JSON Feed with custom limits
 pub fn parse_json_feed_with_limits(data: &[u8], limits: ParserLimits) -> Result<ParsedFeed> {
+    parse_json_feed_with_options(
+        data,
+        &ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse JSON Feed with custom parse options
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.parse_json",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            entries = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn parse_json_feed_with_options(data: &[u8], options: &ParseOptions) -> Result<ParsedFeed> {
+    let limits = options.limits;
     if data.len() > limits.max_feed_size_bytes {
         return Err(FeedError::InvalidFormat(format!(
             "Feed size {} exceeds limit {}",
@@ -45,6 +70,7 @@ pub fn parse_json_feed_with_limits(data: &[u8], limits: ParserLimits) -> Result<
         .and_then(|v| v.as_str())
         .ok_or_else(|| FeedError::InvalidFormat("Missing version field".to_string()))?;
 
+    feed.raw_version = Some(version.to_string());
     feed.version = match version {
         "https://jsonfeed.org/version/1" => FeedVersion::JsonFeed10,
         "https://jsonfeed.org/version/1.1" => FeedVersion::JsonFeed11,
@@ -56,9 +82,12 @@ pub fn parse_json_feed_with_limits(data: &[u8], limits: ParserLimits) -> Result<
     };
 
     parse_feed_metadata(&json, &mut feed.feed, &limits);
+    let mut budget = MemoryBudget::new(&limits);
+    feed.charge_feed_meta(&mut budget);
 
     if let Some(items) = json.get("items").and_then(|v| v.as_array()) {
-        for (idx, item) in items.iter().enumerate() {
+        let mut window = EntryWindow::new(options);
+        for (idx, item) in items.iter().filter(|_| window.admit()).enumerate() {
             if idx >= limits.max_entries {
                 feed.bozo = true;
                 feed.bozo_exception = Some(format!(
@@ -67,10 +96,14 @@ pub fn parse_json_feed_with_limits(data: &[u8], limits: ParserLimits) -> Result<
                 ));
                 break;
             }
-            feed.entries.push(parse_item(item, &limits));
+            feed.push_entry(parse_item(item, &limits), &mut budget);
         }
     }
+    if options.collect_stats {
+        super::stats::count_json(&json).record(&mut feed);
+    }
 
+    record_span!(entries = feed.entries.len());
     Ok(feed)
 }
 
@@ -99,23 +132,19 @@ fn parse_feed_metadata(json: &Value, feed: &mut FeedMeta, limits: &ParserLimits)
         feed.subtitle = Some(truncated);
     }
 
+    // JSON Feed's `icon` is the large square artwork and `favicon` the
+    // small one, which is how Atom uses `logo` and `icon`
     if let Some(icon) = json.get("icon").and_then(|v| v.as_str())
         && icon.len() <= limits.max_text_length
     {
-        feed.icon = Some(icon.to_string());
+        feed.logo = Some(icon.to_string());
+        feed.image = Some(feed_image(icon, feed));
     }
 
     if let Some(favicon) = json.get("favicon").and_then(|v| v.as_str())
         && favicon.len() <= limits.max_text_length
     {
-        feed.image = Some(Image {
-            url: favicon.to_string().into(),
-            title: None,
-            link: None,
-            width: None,
-            height: None,
-            description: None,
-        });
+        feed.icon = Some(favicon.to_string());
     }
 
     parse_authors(
@@ -126,19 +155,48 @@ fn parse_feed_metadata(json: &Value, feed: &mut FeedMeta, limits: &ParserLimits)
         limits,
     );
 
+    if feed.image.is_none()
+        && let Some(avatar) = author_avatar(json)
+        && avatar.len() <= limits.max_text_length
+    {
+        feed.image = Some(feed_image(avatar, feed));
+    }
+
     if let Some(language) = json.get("language").and_then(|v| v.as_str())
         && language.len() <= limits.max_text_length
     {
         feed.language = Some(language.into());
     }
 
-    if let Some(expired) = json.get("expired").and_then(Value::as_bool)
-        && expired
-    {
-        feed.ttl = Some(0);
+    feed.expired = json
+        .get("expired")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+}
+
+/// Builds the feed image for `url`, linked to the feed's home page
+fn feed_image(url: &str, feed: &FeedMeta) -> Image {
+    Image {
+        url: url.to_string().into(),
+        title: feed.title.clone(),
+        link: feed.link.clone(),
+        width: None,
+        height: None,
+        description: None,
     }
 }
 
+/// Returns the avatar of the first feed author that has one
+fn author_avatar(json: &Value) -> Option<&str> {
+    fn avatar(author: &Value) -> Option<&str> {
+        author.get("avatar").and_then(Value::as_str)
+    }
+    json.get("authors").and_then(Value::as_array).map_or_else(
+        || json.get("author").and_then(avatar),
+        |authors| authors.iter().find_map(avatar),
+    )
+}
+
 fn parse_item(json: &Value, limits: &ParserLimits) -> Entry {
     let mut entry = Entry::default();
 
@@ -154,6 +212,7 @@ fn parse_item(json: &Value, limits: &ParserLimits) -> Entry {
     }
 
     if let Some(external_url) = json.get("external_url").and_then(|v| v.as_str()) {
+        entry.external_url = Some(external_url.to_string());
         let _ = entry
             .links
             .try_push_limited(Link::related(external_url), limits.max_entries);
@@ -164,6 +223,7 @@ fn parse_item(json: &Value, limits: &ParserLimits) -> Entry {
         entry.set_title(TextConstruct::text(&truncated));
     }
 
+    // Both bodies are kept, HTML first, so the first block is the richest one
     if let Some(content_html) = json.get("content_html").and_then(|v| v.as_str()) {
         let text = truncate_to_length(content_html, limits.max_text_length);
         let _ = entry
@@ -184,12 +244,21 @@ fn parse_item(json: &Value, limits: &ParserLimits) -> Entry {
     }
 
     if let Some(image) = json.get("image").and_then(|v| v.as_str()) {
-        let _ = entry.links.try_push_limited(
-            Link::enclosure(image, Some("image/*".into())),
+        let _ = entry.media_thumbnails.try_push_limited(
+            MediaThumbnail {
+                url: image.into(),
+                width: None,
+                height: None,
+                time: None,
+            },
             limits.max_entries,
         );
     }
 
+    if let Some(banner_image) = json.get("banner_image").and_then(|v| v.as_str()) {
+        entry.banner_image = Some(banner_image.into());
+    }
+
     if let Some(date_str) = json.get("date_published").and_then(|v| v.as_str()) {
         entry.published = parse_date(date_str);
     }
@@ -338,10 +407,66 @@ mod tests {
         assert_eq!(feed.feed.link.as_deref(), Some("https://example.com"));
         assert_eq!(feed.feed.subtitle.as_deref(), Some("Feed description"));
         assert_eq!(
-            feed.feed.icon.as_deref(),
+            feed.feed.logo.as_deref(),
             Some("https://example.com/icon.png")
         );
+        let image = feed.feed.image.as_ref().unwrap();
+        assert_eq!(image.url.as_str(), "https://example.com/icon.png");
+        assert_eq!(image.link.as_deref(), Some("https://example.com"));
+        assert_eq!(image.title.as_deref(), Some("Example Feed"));
+        assert_eq!(feed.feed.icon, None);
         assert_eq!(feed.feed.language.as_deref(), Some("en-US"));
+        assert!(!feed.feed.expired);
+    }
+
+    #[test]
+    fn test_parse_json_feed_favicon_and_expired() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Old Feed",
+            "favicon": "https://example.com/favicon.png",
+            "expired": true,
+            "items": []
+        }"#;
+
+        let feed = parse_json_feed(json).unwrap();
+        assert_eq!(
+            feed.feed.icon.as_deref(),
+            Some("https://example.com/favicon.png")
+        );
+        assert!(feed.feed.image.is_none());
+        assert!(feed.feed.expired);
+        assert_eq!(feed.feed.ttl, None);
+    }
+
+    #[test]
+    fn test_parse_json_feed_author_avatar_image() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Personal",
+            "authors": [
+                {"name": "No Avatar"},
+                {"name": "Jane", "avatar": "https://example.com/jane.jpg"}
+            ],
+            "items": []
+        }"#;
+
+        let feed = parse_json_feed(json).unwrap();
+        let image = feed.feed.image.unwrap();
+        assert_eq!(image.url.as_str(), "https://example.com/jane.jpg");
+        assert_eq!(feed.feed.logo, None);
+
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1",
+            "icon": "https://example.com/icon.png",
+            "author": {"name": "Jane", "avatar": "https://example.com/jane.jpg"},
+            "items": []
+        }"#;
+        let feed = parse_json_feed(json).unwrap();
+        assert_eq!(
+            feed.feed.image.unwrap().url.as_str(),
+            "https://example.com/icon.png"
+        );
     }
 
     #[test]
@@ -364,6 +489,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_item_with_dual_content() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Test",
+            "items": [
+                {
+                    "id": "1",
+                    "content_text": "Hello",
+                    "content_html": "<p>Hello</p>"
+                },
+                {"id": "2", "content_text": "Text only"}
+            ]
+        }"#;
+
+        let feed = parse_json_feed(json).unwrap();
+        let content = &feed.entries[0].content;
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[0].value, "<p>Hello</p>");
+        assert_eq!(content[0].content_type.as_deref(), Some("text/html"));
+        assert_eq!(content[1].value, "Hello");
+        assert_eq!(content[1].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(feed.entries[0].best_content(), Some(&content[0]));
+
+        let content = &feed.entries[1].content;
+        assert_eq!(content.len(), 1);
+        assert_eq!(feed.entries[1].best_content().unwrap().value, "Text only");
+    }
+
     #[test]
     fn test_parse_item_with_dates() {
         let json = br#"{
@@ -433,6 +587,42 @@ mod tests {
         assert_eq!(feed.entries[0].enclosures[0].length, Some(12345));
     }
 
+    #[test]
+    fn test_parse_item_images_and_external_url() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Test",
+            "items": [
+                {
+                    "id": "1",
+                    "url": "https://example.com/posts/1",
+                    "external_url": "https://other.example.org/article",
+                    "image": "https://example.com/square.png",
+                    "banner_image": "https://example.com/banner.png"
+                }
+            ]
+        }"#;
+
+        let feed = parse_json_feed(json).unwrap();
+        let entry = &feed.entries[0];
+        assert_eq!(
+            entry.external_url.as_deref(),
+            Some("https://other.example.org/article")
+        );
+        assert!(entry.links.iter().any(|l| {
+            l.rel.as_deref() == Some("related") && l.href == "https://other.example.org/article"
+        }));
+        assert_eq!(
+            entry.best_image().unwrap().url.as_str(),
+            "https://example.com/square.png"
+        );
+        assert_eq!(
+            entry.banner_image.as_deref(),
+            Some("https://example.com/banner.png")
+        );
+        assert!(entry.enclosures.is_empty());
+    }
+
     #[test]
     fn test_parse_invalid_json() {
         let json = b"not valid json";
@@ -1,20 +1,24 @@
 pub mod atom;
-mod common;
+pub mod common;
 mod detect;
 pub mod json;
 pub mod namespace_detection;
 pub mod rss;
 pub mod rss10;
+mod stats;
 
-use crate::{error::Result, types::ParsedFeed};
+use crate::{ParseOptions, error::Result, types::ParsedFeed, util::base_url::resolve_feed_urls};
 
 pub use common::skip_element;
-pub use detect::detect_format;
+pub use detect::{FormatDetection, detect_format, detect_format_detailed};
+pub use stats::XmlReader;
 
 /// Parse feed from raw bytes
 ///
 /// This is the main entry point for parsing feeds. It automatically detects
-/// the feed format (RSS, Atom, JSON) and parses accordingly.
+/// the feed format (RSS, Atom, JSON) and parses accordingly. Like Python
+/// feedparser, it sanitizes HTML titles, summaries and content; use
+/// [`parse_with_options`] with `sanitize_html: false` to keep them as written.
 ///
 /// # Errors
 ///
@@ -22,6 +26,13 @@ pub use detect::detect_format;
 /// the parser will set the `bozo` flag and return partial results rather than
 /// returning an error.
 ///
+/// # Panics
+///
+/// Never, whatever the input: malformed, truncated or hostile bytes produce a
+/// bozo result or an error, and memory use stays within
+/// [`ParserLimits`](crate::ParserLimits). The `fuzz/` targets exercise this
+/// guarantee.
+///
 /// # Examples
 ///
 /// ```
@@ -64,49 +75,536 @@ pub fn parse(data: &[u8]) -> Result<ParsedFeed> {
 /// - Format is unknown or unsupported
 /// - Fatal parsing error occurs
 pub fn parse_with_limits(data: &[u8], limits: crate::ParserLimits) -> Result<ParsedFeed> {
+    parse_with_options(
+        data,
+        &ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse feed, resolving relative URLs against the URL it came from
+///
+/// Many feeds use relative links without declaring an `xml:base`, which
+/// leaves them unusable on their own. Passing the feed's own URL fixes up
+/// links, images, icons and enclosures. [`parse_url`](crate::parse_url)
+/// does this automatically with the final URL after redirects.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::parse_with_base_url;
+///
+/// let xml = br#"<rss version="2.0"><channel>
+///     <link>/blog/</link>
+///     <item><link>posts/1</link><enclosure url="/a.mp3" type="audio/mpeg"/></item>
+/// </channel></rss>"#;
+///
+/// let feed = parse_with_base_url(xml, "https://example.com/blog/feed.xml").unwrap();
+/// assert_eq!(feed.feed.link.as_deref(), Some("https://example.com/blog/"));
+/// assert_eq!(feed.entries[0].link.as_deref(), Some("https://example.com/blog/posts/1"));
+/// assert_eq!(feed.entries[0].enclosures[0].url, "https://example.com/a.mp3");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`parse`].
+pub fn parse_with_base_url(data: &[u8], base_url: &str) -> Result<ParsedFeed> {
+    parse_with_options(
+        data,
+        &ParseOptions {
+            base_url: Some(base_url.to_string()),
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse feed with custom parse options
+///
+/// Like [`parse_with_limits`], but also controls relative URL resolution and
+/// HTML sanitization. With `sanitize_html` enabled, HTML-typed titles,
+/// subtitles, summaries and content blocks are cleaned with
+/// [`sanitize_html`](crate::util::sanitize::sanitize_html).
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::{ParseOptions, parse_with_options};
+///
+/// let xml = br#"<rss version="2.0"><channel><item>
+///     <description><![CDATA[<p>Hi</p><script>alert(1)</script>]]></description>
+/// </item></channel></rss>"#;
+///
+/// let feed = parse_with_options(xml, &ParseOptions::default()).unwrap();
+/// assert_eq!(feed.entries[0].summary.as_deref(), Some("<p>Hi</p>"));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`parse_with_limits`].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.parse",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            version = tracing::field::Empty,
+            entries = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn parse_with_options(data: &[u8], options: &ParseOptions) -> Result<ParsedFeed> {
     use crate::types::FeedVersion;
 
+    let started = options.collect_stats.then(std::time::Instant::now);
+
+    // Compressed input, e.g. from crawl archives, is inflated up to the feed size limit
+    #[cfg(feature = "compression")]
+    let decompressed =
+        crate::util::compression::decompress(data, options.limits.max_feed_size_bytes)?;
+    #[cfg(feature = "compression")]
+    let data = decompressed.as_ref();
+
     // Detect format
     let version = detect_format(data);
 
     // Parse based on detected format
-    match version {
+    let result = match version {
         // RSS variants (all use RSS 2.0 parser for now)
         FeedVersion::Rss20 | FeedVersion::Rss092 | FeedVersion::Rss091 | FeedVersion::Rss090 => {
-            rss::parse_rss20_with_limits(data, limits)
+            rss::parse_rss20_with_options(data, options)
         }
 
         // Atom variants
-        FeedVersion::Atom10 | FeedVersion::Atom03 => atom::parse_atom10_with_limits(data, limits),
+        FeedVersion::Atom10 | FeedVersion::Atom03 => atom::parse_atom10_with_options(data, options),
 
-        // RSS 1.0 (RDF)
-        FeedVersion::Rss10 => rss10::parse_rss10_with_limits(data, limits),
-
-        // JSON Feed
+        // RSS 1.0 (RDF) and JSON Feed resolve against the document base afterwards
+        FeedVersion::Rss10 => rss10::parse_rss10_with_options(data, options).map(|mut feed| {
+            resolve_feed_urls(&mut feed, &options.base_context());
+            feed
+        }),
         FeedVersion::JsonFeed10 | FeedVersion::JsonFeed11 => {
-            json::parse_json_feed_with_limits(data, limits)
+            json::parse_json_feed_with_options(data, options).map(|mut feed| {
+                resolve_feed_urls(&mut feed, &options.base_context());
+                feed
+            })
         }
 
-        // Unknown format - try RSS first (most common)
-        FeedVersion::Unknown => {
-            // Try RSS first
-            if let Ok(feed) = rss::parse_rss20_with_limits(data, limits) {
-                return Ok(feed);
-            }
+        // Unknown format - try RSS first (most common), then Atom
+        FeedVersion::Unknown => rss::parse_rss20_with_options(data, options)
+            .or_else(|_| atom::parse_atom10_with_options(data, options)),
+    };
 
-            // Try Atom
-            atom::parse_atom10_with_limits(data, limits)
+    result.map(|mut feed| {
+        if version.family() != crate::FeedFamily::Json {
+            if feed.raw_version.is_none() {
+                feed.raw_version = detect::declared_xml_version(data);
+            }
+            // Such references were decoded leniently; the document is still malformed
+            if !feed.bozo
+                && let Some(reference) = crate::util::entities::find_invalid_char_ref(data)
+            {
+                feed.bozo = true;
+                feed.bozo_exception = Some(format!("Invalid character reference {reference}"));
+            }
         }
-    }
+        crate::util::date::apply_future_date_policy(
+            &mut feed,
+            options.clamp_future_dates,
+            chrono::Utc::now(),
+        );
+        if options.strip_tracking_params {
+            feed.strip_tracking_params(&options.extra_tracking_params);
+        }
+        crate::util::language::normalize_feed_languages(&mut feed);
+        let author_sources = options
+            .author_precedence
+            .as_deref()
+            .unwrap_or(&crate::AuthorSource::DEFAULT_ORDER);
+        feed.feed.resolve_author(author_sources);
+        for entry in &mut feed.entries {
+            entry.reconcile_enclosures();
+            entry.aggregate_comments_count();
+            entry.resolve_author(author_sources);
+        }
+        if options.fix_mojibake {
+            let repaired = feed.fix_mojibake();
+            if !feed.bozo
+                && let Some(first) = repaired.first()
+            {
+                feed.bozo = true;
+                feed.bozo_exception = Some(format!("Repaired mis-decoded UTF-8 text in {first}"));
+            }
+        }
+        if options.normalize_text {
+            feed.normalize_text();
+        }
+        if options.normalize_tags {
+            feed.normalize_tags();
+        }
+        #[cfg(feature = "language-detection")]
+        crate::util::language::detect_entry_languages(&mut feed);
+        if options.sanitize_html {
+            feed.sanitized =
+                crate::util::sanitize::sanitize_feed(&mut feed, &options.sanitize_policy);
+        }
+        // Derived last so inline images reflect sanitized content
+        let image_sources = options
+            .image_precedence
+            .as_deref()
+            .unwrap_or(&crate::ImageSource::DEFAULT_ORDER);
+        for entry in &mut feed.entries {
+            entry.image = entry.image_from(image_sources);
+        }
+        if let Some(started) = started {
+            let parse_duration = started.elapsed();
+            // Element and skipped-entry counts were recorded by the parser
+            let stats = feed.stats.get_or_insert_default();
+            stats.bytes_read = data.len();
+            stats.entries_parsed = feed.entries.len();
+            stats.parse_duration = parse_duration;
+            stats.sanitized_fields = feed.sanitized.len();
+        }
+        record_span!(
+            version = feed.version.as_str(),
+            entries = feed.entries.len()
+        );
+        feed
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::FeedVersion;
 
     #[test]
     fn test_parse_returns_ok() {
         let result = parse(b"test");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_parse_with_options_keeps_relative_uris() {
+        let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom" xml:base="http://example.com/">
+            <entry><link href="post/1"/></entry>
+        </feed>"#;
+        let resolved = parse_with_options(xml, &ParseOptions::default()).unwrap();
+        assert_eq!(
+            resolved.entries[0].links[0].href,
+            "http://example.com/post/1"
+        );
+
+        let options = ParseOptions {
+            resolve_relative_uris: false,
+            ..ParseOptions::default()
+        };
+        let verbatim = parse_with_options(xml, &options).unwrap();
+        assert_eq!(verbatim.entries[0].links[0].href, "post/1");
+    }
+
+    #[test]
+    fn test_parse_with_base_url() {
+        let base = "https://example.com/feeds/main.xml";
+
+        let atom = br#"<feed xmlns="http://www.w3.org/2005/Atom" xml:base="archive/">
+            <icon>/favicon.ico</icon>
+            <entry><link href="post/1"/></entry>
+        </feed>"#;
+        let feed = parse_with_base_url(atom, base).unwrap();
+        assert_eq!(
+            feed.feed.icon.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        assert_eq!(
+            feed.entries[0].links[0].href,
+            "https://example.com/feeds/archive/post/1"
+        );
+
+        let json = br#"{"version": "https://jsonfeed.org/version/1.1", "home_page_url": "/",
+            "items": [{"id": "1", "url": "p/1", "attachments": [{"url": "https://cdn.example.org/a.mp3", "mime_type": "audio/mpeg"}]}]}"#;
+        let feed = parse_with_base_url(json, base).unwrap();
+        assert_eq!(feed.feed.link.as_deref(), Some("https://example.com/"));
+        assert_eq!(
+            feed.entries[0].link.as_deref(),
+            Some("https://example.com/feeds/p/1")
+        );
+        assert_eq!(
+            feed.entries[0].enclosures[0].url,
+            "https://cdn.example.org/a.mp3"
+        );
+
+        let options = ParseOptions {
+            resolve_relative_uris: false,
+            base_url: Some(base.to_string()),
+            ..ParseOptions::default()
+        };
+        let feed = parse_with_options(json, &options).unwrap();
+        assert_eq!(feed.entries[0].link.as_deref(), Some("p/1"));
+    }
+
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_parse_gzip_input() {
+        use std::io::Write;
+
+        let xml = br#"<rss version="2.0"><channel><title>Archived</title></channel></rss>"#;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(xml).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let feed = parse(&compressed).unwrap();
+        assert!(!feed.bozo);
+        assert_eq!(feed.version, FeedVersion::Rss20);
+        assert_eq!(feed.feed.title.as_deref(), Some("Archived"));
+
+        let limits = crate::ParserLimits {
+            max_feed_size_bytes: xml.len() - 1,
+            ..crate::ParserLimits::default()
+        };
+        assert!(parse_with_limits(&compressed, limits).is_err());
+    }
+
+    #[test]
+    fn test_parse_preserve_whitespace() {
+        let xml = b"<rss version=\"2.0\"><channel>
+            <item>
+                <title>  spaced  </title>
+                <description>    x = 1\n    y = 2\n</description>
+                <comments>
+                    <![CDATA[https://example.com/c]]>
+                </comments>
+            </item>
+        </channel></rss>";
+        let options = ParseOptions {
+            preserve_whitespace: true,
+            ..ParseOptions::default()
+        };
+
+        let feed = parse_with_options(xml, &options).unwrap();
+        let entry = &feed.entries[0];
+        assert_eq!(entry.title.as_deref(), Some("  spaced  "));
+        assert_eq!(entry.summary.as_deref(), Some("    x = 1\n    y = 2\n"));
+        assert_eq!(entry.comments.as_deref(), Some("https://example.com/c"));
+
+        let feed = parse(xml).unwrap();
+        assert_eq!(feed.entries[0].title.as_deref(), Some("spaced"));
+        assert_eq!(feed.entries[0].summary.as_deref(), Some("x = 1\n    y = 2"));
+    }
+
+    #[test]
+    fn test_parse_keeps_raw_version() {
+        let feed = parse(br#"<rss version="2.0"><channel/></rss>"#).unwrap();
+        assert_eq!(feed.version, FeedVersion::Rss20);
+        assert_eq!(feed.raw_version.as_deref(), Some("2.0"));
+
+        let feed = parse(br#"<rss version="2.0.1"><channel/></rss>"#).unwrap();
+        assert_eq!(feed.raw_version.as_deref(), Some("2.0.1"));
+
+        let feed = parse(b"<rss><channel/></rss>").unwrap();
+        assert_eq!(feed.raw_version, None);
+
+        let feed =
+            parse(br#"{"version": "https://jsonfeed.org/version/1.1", "items": []}"#).unwrap();
+        assert_eq!(feed.version, FeedVersion::JsonFeed11);
+        assert_eq!(
+            feed.raw_version.as_deref(),
+            Some("https://jsonfeed.org/version/1.1")
+        );
+    }
+
+    #[test]
+    fn test_parse_collects_stats() {
+        let xml = br#"<rss version="2.0"><channel>
+            <item><description><![CDATA[<p>1</p><script>x</script>]]></description></item>
+            <item><title>2</title></item>
+            <item><title>3</title></item>
+        </channel></rss>"#;
+
+        assert_eq!(parse(xml).unwrap().stats, None);
+
+        let options = ParseOptions {
+            collect_stats: true,
+            limits: crate::ParserLimits::default().with_max_entries(2),
+            ..ParseOptions::default()
+        };
+        let stats = parse_with_options(xml, &options).unwrap().stats.unwrap();
+        assert_eq!(stats.bytes_read, xml.len());
+        assert_eq!(stats.elements_seen, 8);
+        assert_eq!(stats.entries_parsed, 2);
+        assert_eq!(stats.entries_skipped, 1);
+        assert_eq!(stats.sanitized_fields, 1);
+    }
+
+    #[test]
+    fn test_parse_records_sanitized_fields() {
+        let xml = br#"<rss version="2.0"><channel>
+            <item><title>Plain</title><description>Fine</description></item>
+            <item><description><![CDATA[<p onclick="x()">1</p>]]></description></item>
+        </channel></rss>"#;
+
+        assert_eq!(parse(xml).unwrap().sanitized, ["entries[1].summary"]);
+
+        let options = ParseOptions {
+            sanitize_html: false,
+            ..ParseOptions::default()
+        };
+        let feed = parse_with_options(xml, &options).unwrap();
+        assert!(feed.sanitized.is_empty());
+        assert_eq!(
+            feed.entries[1].summary.as_deref(),
+            Some(r#"<p onclick="x()">1</p>"#)
+        );
+    }
+
+    #[test]
+    fn test_parse_enforces_memory_limit() {
+        let text = "x".repeat(4096);
+        let items = format!("<item><description>{text}</description></item>").repeat(10);
+        let rss = format!(r#"<rss version="2.0"><channel>{items}</channel></rss>"#);
+        let entries = format!("<entry><summary>{text}</summary></entry>").repeat(10);
+        let atom = format!(r#"<feed xmlns="http://www.w3.org/2005/Atom">{entries}</feed>"#);
+        let items = vec![format!(r#"{{"id": "1", "content_text": "{text}"}}"#); 10].join(",");
+        let json =
+            format!(r#"{{"version": "https://jsonfeed.org/version/1.1", "items": [{items}]}}"#);
+
+        let limits = crate::ParserLimits::default().with_max_total_memory_bytes(20 * 1024);
+        for data in [rss, atom, json] {
+            assert_eq!(parse(data.as_bytes()).unwrap().entries.len(), 10);
+
+            let feed = parse_with_limits(data.as_bytes(), limits).unwrap();
+            assert!((1..10).contains(&feed.entries.len()));
+            assert!(feed.bozo);
+            assert!(feed.bozo_exception.unwrap().contains("memory limit"));
+        }
+    }
+
+    #[test]
+    fn test_parse_charges_feed_metadata() {
+        let text = "x".repeat(4096);
+        let rss = format!(
+            r#"<rss version="2.0"><channel><description>{text}</description></channel></rss>"#
+        );
+        let atom = format!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><subtitle>{text}</subtitle></feed>"#
+        );
+        let json = format!(
+            r#"{{"version": "https://jsonfeed.org/version/1.1", "description": "{text}", "items": []}}"#
+        );
+
+        let limits = crate::ParserLimits::default().with_max_total_memory_bytes(1024);
+        for data in [rss, atom, json] {
+            let feed = parse_with_limits(data.as_bytes(), limits).unwrap();
+            assert!(feed.bozo);
+            assert!(feed.bozo_exception.unwrap().contains("memory limit"));
+        }
+    }
+
+    #[test]
+    fn test_parse_limits_attributes_per_element() {
+        let padding = (0..200)
+            .map(|i| format!(" a{i}='x'"))
+            .collect::<Vec<_>>()
+            .concat();
+        let atom = format!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">
+                <entry><link{padding} href="https://example.com/a"/></entry>
+            </feed>"#
+        );
+        let rss = format!(
+            r#"<rss version="2.0"><channel>
+                <item><enclosure{padding} url="https://example.com/a.mp3"/></item>
+            </channel></rss>"#
+        );
+
+        assert!(parse(atom.as_bytes()).unwrap().entries[0].links.is_empty());
+        assert!(
+            parse(rss.as_bytes()).unwrap().entries[0]
+                .enclosures
+                .is_empty()
+        );
+
+        let limits = crate::ParserLimits::default().with_max_attributes_per_element(256);
+        let feed = parse_with_limits(atom.as_bytes(), limits).unwrap();
+        assert_eq!(feed.entries[0].links[0].href, "https://example.com/a");
+        let feed = parse_with_limits(rss.as_bytes(), limits).unwrap();
+        assert_eq!(
+            feed.entries[0].enclosures[0].url,
+            "https://example.com/a.mp3"
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_range() {
+        let rss = br#"<rss version="2.0"><channel><title>T</title>
+            <item><guid>1</guid></item><item><guid>2</guid></item>
+            <item><guid>3</guid></item><item><guid>4</guid></item>
+        </channel></rss>"#;
+        let rss10 = br#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+            xmlns="http://purl.org/rss/1.0/"><channel rdf:about="c"><title>T</title></channel>
+            <item rdf:about="1"><title>1</title></item><item rdf:about="2"><title>2</title></item>
+            <item rdf:about="3"><title>3</title></item><item rdf:about="4"><title>4</title></item>
+        </rdf:RDF>"#;
+        let atom = br#"<feed xmlns="http://www.w3.org/2005/Atom"><title>T</title>
+            <entry><id>1</id></entry><entry><id>2</id></entry>
+            <entry><id>3</id></entry><entry><id>4</id></entry>
+        </feed>"#;
+        let json = br#"{"version": "https://jsonfeed.org/version/1.1", "title": "T",
+            "items": [{"id": "1"}, {"id": "2"}, {"id": "3"}, {"id": "4"}]}"#;
+
+        for data in [&rss[..], &rss10[..], &atom[..], &json[..]] {
+            let options = ParseOptions {
+                entry_range: Some(1..3),
+                ..ParseOptions::default()
+            };
+            let feed = parse_with_options(data, &options).unwrap();
+            assert_eq!(feed.feed.title.as_deref(), Some("T"));
+            let ids: Vec<_> = feed
+                .entries
+                .iter()
+                .filter_map(|e| e.id.as_deref())
+                .collect();
+            assert_eq!(ids, ["2", "3"]);
+
+            let options = ParseOptions {
+                entry_range: Some(10..20),
+                ..ParseOptions::default()
+            };
+            assert!(
+                parse_with_options(data, &options)
+                    .unwrap()
+                    .entries
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_strips_tracking_params() {
+        let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom">
+            <link href="https://example.com/?utm_campaign=feed"/>
+            <entry><link href="https://example.com/1?fbclid=abc"/></entry>
+        </feed>"#;
+
+        let kept = parse(xml).unwrap();
+        assert_eq!(
+            kept.entries[0].link.as_deref(),
+            Some("https://example.com/1?fbclid=abc")
+        );
+
+        let options = ParseOptions {
+            strip_tracking_params: true,
+            ..ParseOptions::default()
+        };
+        let feed = parse_with_options(xml, &options).unwrap();
+        assert_eq!(feed.feed.link.as_deref(), Some("https://example.com/"));
+        assert_eq!(feed.feed.links[0].href, "https://example.com/");
+        assert_eq!(
+            feed.entries[0].link.as_deref(),
+            Some("https://example.com/1")
+        );
+        assert_eq!(feed.entries[0].links[0].href, "https://example.com/1");
+    }
 }
@@ -1,23 +1,55 @@
 //! RSS 2.0 parser implementation
 
+use super::XmlReader;
+#[cfg(feature = "dublincore")]
+use crate::namespace::dublin_core;
+#[cfg(feature = "georss")]
+use crate::namespace::georss;
+#[cfg(feature = "mediarss")]
+use crate::namespace::media_rss;
+#[cfg(feature = "itunes")]
+use crate::types::{
+    ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner, parse_duration, parse_explicit,
+};
+#[cfg(feature = "mediarss")]
+use crate::types::{MediaContent, MediaThumbnail};
+#[cfg(feature = "podcast20")]
+use crate::types::{
+    PodcastAlternateEnclosure, PodcastChapters, PodcastEntryMeta, PodcastFunding, PodcastMeta,
+    PodcastPerson, PodcastSoundbite, PodcastTranscript,
+};
 use crate::{
-    ParserLimits,
+    ParseOptions, ParserLimits,
     error::{FeedError, Result},
-    namespace::{content, dublin_core, georss, media_rss},
+    limits::MemoryBudget,
+    namespace::{blog_channel, content, feed_history, license, prism, threading},
     types::{
-        Enclosure, Entry, FeedVersion, Image, ItunesCategory, ItunesEntryMeta, ItunesFeedMeta,
-        ItunesOwner, Link, MediaContent, MediaThumbnail, ParsedFeed, PodcastChapters,
-        PodcastEntryMeta, PodcastFunding, PodcastMeta, PodcastPerson, PodcastSoundbite,
-        PodcastTranscript, Source, Tag, TextConstruct, TextType, parse_duration, parse_explicit,
+        Enclosure, Entry, FeedVersion, Image, Link, ParsedFeed, Person, Source, Tag, TextConstruct,
+        TextType,
+    },
+    util::{
+        base_url::BaseUrlContext,
+        entities::decode_attribute,
+        parse_date,
+        text::{detect_text_type, truncate_to_length},
     },
-    util::{base_url::BaseUrlContext, parse_date, text::truncate_to_length},
 };
-use quick_xml::{Reader, events::Event};
+use quick_xml::events::Event;
+use std::borrow::Cow;
 
+#[cfg(feature = "dublincore")]
+use super::common::is_dc_tag;
+#[cfg(feature = "georss")]
+use super::common::is_georss_tag;
 use super::common::{
-    EVENT_BUFFER_CAPACITY, LimitedCollectionExt, check_depth, extract_xml_lang, init_feed,
-    is_content_tag, is_dc_tag, is_georss_tag, is_itunes_tag, is_media_tag, read_text, skip_element,
+    EVENT_BUFFER_CAPACITY, EntryWindow, LimitedCollectionExt, check_depth, extract_xml_lang,
+    init_feed, is_blog_channel_tag, is_content_tag, is_fh_tag, is_media_tag, is_prism_tag,
+    read_text, read_text_cow, resolve_reference, skip_element, with_untrimmed_text,
 };
+#[cfg(feature = "itunes")]
+use super::common::{is_itunes_tag, limited_attributes};
+#[cfg(feature = "mediarss")]
+use super::common::{parse_media_children, parse_media_group};
 
 /// Error message for malformed XML attributes (shared constant)
 const MALFORMED_ATTRIBUTES_ERROR: &str = "Malformed XML attributes";
@@ -31,19 +63,19 @@ const MALFORMED_ATTRIBUTES_ERROR: &str = "Malformed XML attributes";
 /// multiple parsing calls in `parse_enclosure` and other functions.
 ///
 /// Pre-allocates space for 4 attributes (typical for enclosures: url, type, length, maybe one more)
+/// and reads at most `max_attributes_per_element` of them.
 #[inline]
-fn collect_attributes(e: &quick_xml::events::BytesStart) -> (Vec<(Vec<u8>, String)>, bool) {
+fn collect_attributes(
+    e: &quick_xml::events::BytesStart,
+    limits: &ParserLimits,
+) -> (Vec<(Vec<u8>, String)>, bool) {
     let mut has_errors = false;
     let mut attrs = Vec::with_capacity(4);
 
-    for result in e.attributes() {
+    for result in e.attributes().take(limits.max_attributes_per_element) {
         match result {
             Ok(attr) => {
-                if let Ok(v) = attr.unescape_value() {
-                    attrs.push((attr.key.as_ref().to_vec(), v.to_string()));
-                } else {
-                    has_errors = true;
-                }
+                attrs.push((attr.key.as_ref().to_vec(), decode_attribute(&attr.value)));
             }
             Err(_) => {
                 has_errors = true;
@@ -98,30 +130,55 @@ pub fn parse_rss20(data: &[u8]) -> Result<ParsedFeed> {
 
 /// Parse RSS 2.0 with custom parser limits
 pub fn parse_rss20_with_limits(data: &[u8], limits: ParserLimits) -> Result<ParsedFeed> {
+    parse_rss20_with_options(
+        data,
+        &ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse RSS 2.0 with custom parse options
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.parse_rss",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            entries = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn parse_rss20_with_options(data: &[u8], options: &ParseOptions) -> Result<ParsedFeed> {
+    let limits = options.limits;
     limits
         .check_feed_size(data.len())
         .map_err(|e| FeedError::InvalidFormat(e.to_string()))?;
 
-    let mut reader = Reader::from_reader(data);
-    reader.config_mut().trim_text(true);
+    let mut reader = options.xml_reader(data);
 
     let mut feed = init_feed(FeedVersion::Rss20, limits.max_entries);
     let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
     let mut depth: usize = 1;
-    let mut base_ctx = BaseUrlContext::new();
+    let mut base_ctx = options.base_context();
+    let mut window = EntryWindow::new(options);
 
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(e)) if e.local_name().as_ref() == b"channel" => {
-                let channel_lang = extract_xml_lang(&e, limits.max_attribute_length);
+                let channel_lang = extract_xml_lang(&e, &limits);
                 depth += 1;
                 if let Err(e) = parse_channel(
                     &mut reader,
+                    &mut buf,
                     &mut feed,
                     &limits,
                     &mut depth,
                     &mut base_ctx,
                     channel_lang.as_deref(),
+                    &mut window,
                 ) {
                     feed.bozo = true;
                     feed.bozo_exception = Some(e.to_string());
@@ -139,22 +196,32 @@ pub fn parse_rss20_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
         buf.clear();
     }
 
+    if options.collect_stats {
+        reader.counts().record(&mut feed);
+    }
+    record_span!(entries = feed.entries.len());
     Ok(feed)
 }
 
 /// Parse <channel> element (feed metadata and items)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.channel", level = "debug", skip_all)
+)]
+#[allow(clippy::too_many_arguments)]
 fn parse_channel(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
     depth: &mut usize,
     base_ctx: &mut BaseUrlContext,
     channel_lang: Option<&str>,
+    window: &mut EntryWindow,
 ) -> Result<()> {
-    let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
-
+    let mut budget = MemoryBudget::new(limits);
     loop {
-        match reader.read_event_into(&mut buf) {
+        match reader.read_event_into(buf) {
             Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
                 let is_empty = matches!(event, Event::Empty(_));
                 let (Event::Start(e) | Event::Empty(e)) = &event else {
@@ -165,54 +232,55 @@ fn parse_channel(
                 check_depth(*depth, limits.max_nesting_depth)?;
 
                 // NOTE: Allocation here is necessary due to borrow checker constraints.
-                // We need owned tag data to pass &mut buf to helper functions simultaneously.
+                // We need owned tag data to pass buf to helper functions simultaneously.
                 // Potential future optimization: restructure helpers to avoid this allocation.
                 let tag = e.name().as_ref().to_vec();
-                let (attrs, has_attr_errors) = collect_attributes(e);
+                let (attrs, has_attr_errors) = collect_attributes(e, limits);
                 if has_attr_errors {
                     feed.bozo = true;
                     feed.bozo_exception = Some(MALFORMED_ATTRIBUTES_ERROR.to_string());
                 }
 
                 // Extract xml:lang before matching to avoid borrow issues
-                let item_lang = extract_xml_lang(e, limits.max_attribute_length);
+                let item_lang = extract_xml_lang(e, limits);
 
                 // Use full qualified name to distinguish standard RSS tags from namespaced tags
                 match tag.as_slice() {
                     b"title" | b"link" | b"description" | b"language" | b"pubDate"
-                    | b"managingEditor" | b"webMaster" | b"generator" | b"ttl" | b"category"
+                    | b"lastBuildDate" | b"managingEditor" | b"webMaster" | b"generator"
+                    | b"copyright" | b"ttl" | b"category"
                         if !is_empty =>
                     {
-                        parse_channel_standard(
-                            reader,
-                            &mut buf,
-                            &tag,
-                            feed,
-                            limits,
-                            base_ctx,
-                            channel_lang,
-                        )?;
+                        parse_channel_standard(reader, &tag, feed, limits, base_ctx, channel_lang)?;
                     }
                     b"image" if !is_empty => {
-                        if let Ok(image) = parse_image(reader, &mut buf, limits, depth) {
+                        if let Ok(mut image) = parse_image(reader, buf, limits, depth) {
+                            let raw_url = image.url.into_inner();
+                            image.url = base_ctx.resolve_safe(&raw_url).into();
+                            feed.feed.raw_image_url = Some(raw_url);
+                            image.link = image.link.map(|link| base_ctx.resolve_safe(&link));
                             feed.feed.image = Some(image);
                         }
                     }
+                    b"item" if !is_empty && !window.admit() => {
+                        skip_element(reader, limits, *depth)?;
+                    }
                     b"item" if !is_empty => {
                         parse_channel_item(
                             item_lang.as_deref(),
                             reader,
-                            &mut buf,
+                            buf,
                             feed,
                             limits,
                             depth,
                             base_ctx,
                             channel_lang,
+                            &mut budget,
                         )?;
                     }
                     _ => {
                         parse_channel_extension(
-                            reader, &mut buf, &tag, &attrs, feed, limits, depth, is_empty,
+                            reader, buf, &tag, &attrs, feed, limits, depth, is_empty,
                         )?;
                     }
                 }
@@ -228,26 +296,28 @@ fn parse_channel(
         buf.clear();
     }
 
+    feed.charge_feed_meta(&mut budget);
     Ok(())
 }
 
 /// Parse <item> element within channel
 ///
-/// Note: Uses 8 parameters instead of a context struct due to borrow checker constraints
+/// Note: Uses 9 parameters instead of a context struct due to borrow checker constraints
 /// with multiple simultaneous `&mut` references during parsing.
 #[inline]
 #[allow(clippy::too_many_arguments)]
 fn parse_channel_item(
     item_lang: Option<&str>,
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
     depth: &mut usize,
     base_ctx: &BaseUrlContext,
     channel_lang: Option<&str>,
+    budget: &mut MemoryBudget,
 ) -> Result<()> {
-    if !feed.check_entry_limit(reader, buf, limits, depth)? {
+    if !feed.check_entry_limit(reader, limits, depth)? {
         return Ok(());
     }
 
@@ -259,7 +329,7 @@ fn parse_channel_item(
                 feed.bozo = true;
                 feed.bozo_exception = Some(MALFORMED_ATTRIBUTES_ERROR.to_string());
             }
-            feed.entries.push(entry);
+            feed.push_entry(entry, budget);
         }
         Err(e) => {
             feed.bozo = true;
@@ -273,8 +343,13 @@ fn parse_channel_item(
 /// Parse channel extension elements (iTunes, Podcast, namespaces)
 #[inline]
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "itunes"), allow(clippy::needless_pass_by_ref_mut))]
+#[cfg_attr(
+    not(any(feature = "itunes", feature = "podcast20")),
+    allow(unused_variables, clippy::ptr_arg)
+)]
 fn parse_channel_extension(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
@@ -283,17 +358,25 @@ fn parse_channel_extension(
     depth: &mut usize,
     is_empty: bool,
 ) -> Result<()> {
-    let mut handled = parse_channel_itunes(reader, buf, tag, attrs, feed, limits, depth, is_empty)?;
+    let mut handled = false;
+    #[cfg(feature = "itunes")]
+    if !handled {
+        handled = parse_channel_itunes(reader, buf, tag, attrs, feed, limits, depth, is_empty)?;
+    }
+    #[cfg(feature = "podcast20")]
     if !handled {
         handled = parse_channel_podcast(reader, buf, tag, attrs, feed, limits, is_empty)?;
     }
     if !handled {
-        handled = parse_channel_namespace(reader, buf, tag, feed, limits, *depth, is_empty)?;
+        handled = parse_channel_feed_history(reader, tag, attrs, feed, limits, *depth, is_empty)?;
+    }
+    if !handled {
+        handled = parse_channel_namespace(reader, tag, attrs, feed, limits, *depth, is_empty)?;
     }
 
     // Only skip element content if this is NOT an empty element
     if !handled && !is_empty {
-        skip_element(reader, buf, limits, *depth)?;
+        skip_element(reader, limits, *depth)?;
     }
 
     Ok(())
@@ -329,8 +412,7 @@ fn parse_enclosure(attrs: &[(Vec<u8>, String)], limits: &ParserLimits) -> Option
 /// Parse standard RSS 2.0 channel elements
 #[inline]
 fn parse_channel_standard(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
@@ -339,7 +421,7 @@ fn parse_channel_standard(
 ) -> Result<()> {
     match tag {
         b"title" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             feed.feed.set_title(TextConstruct {
                 value: text,
                 content_type: TextType::Text,
@@ -348,28 +430,30 @@ fn parse_channel_standard(
             });
         }
         b"link" => {
-            let link_text = read_text(reader, buf, limits)?;
-            feed.feed
-                .set_alternate_link(link_text.clone(), limits.max_links_per_feed);
+            let mut link_text = read_text(reader, limits)?;
 
             if base_ctx.base().is_none() {
                 base_ctx.update_base(&link_text);
+            } else {
+                link_text = base_ctx.resolve_safe(&link_text);
             }
+            feed.feed
+                .set_alternate_link(link_text, limits.max_links_per_feed);
         }
         b"description" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             feed.feed.set_subtitle(TextConstruct {
+                content_type: detect_text_type(&text),
                 value: text,
-                content_type: TextType::Html,
                 language: channel_lang.map(std::convert::Into::into),
                 base: base_ctx.base().map(String::from),
             });
         }
         b"language" => {
-            feed.feed.language = Some(read_text(reader, buf, limits)?.into());
+            feed.feed.language = Some(read_text(reader, limits)?.into());
         }
         b"pubDate" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             match parse_date(&text) {
                 Some(dt) => feed.feed.published = Some(dt),
                 None if !text.is_empty() => {
@@ -379,21 +463,38 @@ fn parse_channel_standard(
                 None => {}
             }
         }
+        b"lastBuildDate" => {
+            let text = read_text_cow(reader, limits)?;
+            match parse_date(&text) {
+                Some(dt) => feed.feed.updated = Some(dt),
+                None if !text.is_empty() => {
+                    feed.bozo = true;
+                    feed.bozo_exception = Some("Invalid lastBuildDate format".to_string());
+                }
+                None => {}
+            }
+        }
         b"managingEditor" => {
-            feed.feed.author = Some(read_text(reader, buf, limits)?.into());
+            feed.feed.managing_editor = Some(read_text(reader, limits)?);
         }
         b"webMaster" => {
-            feed.feed.publisher = Some(read_text(reader, buf, limits)?.into());
+            feed.feed.publisher = Some(read_text(reader, limits)?.into());
         }
         b"generator" => {
-            feed.feed.generator = Some(read_text(reader, buf, limits)?);
+            feed.feed.generator = Some(read_text(reader, limits)?);
+        }
+        b"copyright" => {
+            feed.feed.set_rights(TextConstruct {
+                language: channel_lang.map(std::convert::Into::into),
+                ..TextConstruct::text(read_text(reader, limits)?)
+            });
         }
         b"ttl" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             feed.feed.ttl = text.parse().ok();
         }
         b"category" => {
-            let term = read_text(reader, buf, limits)?;
+            let term = read_text_cow(reader, limits)?;
             feed.feed.tags.try_push_limited(
                 Tag {
                     term: term.into(),
@@ -408,12 +509,13 @@ fn parse_channel_standard(
     Ok(())
 }
 
+#[cfg(feature = "itunes")]
 /// Parse iTunes namespace tags at channel level
 ///
 /// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
 #[allow(clippy::too_many_arguments)]
 fn parse_channel_itunes(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
@@ -424,7 +526,7 @@ fn parse_channel_itunes(
 ) -> Result<bool> {
     if is_itunes_tag(tag, b"author") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             let itunes = feed
                 .feed
                 .itunes
@@ -448,7 +550,7 @@ fn parse_channel_itunes(
         Ok(true)
     } else if is_itunes_tag(tag, b"explicit") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             let itunes = feed
                 .feed
                 .itunes
@@ -479,21 +581,23 @@ fn parse_channel_itunes(
         Ok(true)
     } else if is_itunes_tag(tag, b"keywords") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             let itunes = feed
                 .feed
                 .itunes
                 .get_or_insert_with(|| Box::new(ItunesFeedMeta::default()));
             itunes.keywords = text
                 .split(',')
-                .map(|s| s.trim().to_string())
+                .map(str::trim)
                 .filter(|s| !s.is_empty())
+                .take(limits.max_itunes_keywords)
+                .map(str::to_string)
                 .collect();
         }
         Ok(true)
     } else if is_itunes_tag(tag, b"type") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             let itunes = feed
                 .feed
                 .itunes
@@ -503,7 +607,7 @@ fn parse_channel_itunes(
         Ok(true)
     } else if is_itunes_tag(tag, b"complete") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             let itunes = feed
                 .feed
                 .itunes
@@ -513,7 +617,7 @@ fn parse_channel_itunes(
         Ok(true)
     } else if is_itunes_tag(tag, b"new-feed-url") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             if !text.is_empty() {
                 let itunes = feed
                     .feed
@@ -528,9 +632,10 @@ fn parse_channel_itunes(
     }
 }
 
+#[cfg(feature = "itunes")]
 /// Parse iTunes category with potential subcategory
 fn parse_itunes_category(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     attrs: &[(Vec<u8>, String)],
     feed: &mut ParsedFeed,
@@ -551,10 +656,9 @@ fn parse_itunes_category(
                     if is_itunes_tag(sub_e.name().as_ref(), b"category") {
                         nesting += 1;
                         if nesting == 1 {
-                            for attr in sub_e.attributes().flatten() {
-                                if attr.key.as_ref() == b"text"
-                                    && let Ok(value) = attr.unescape_value()
-                                {
+                            for attr in limited_attributes(&sub_e, limits) {
+                                if attr.key.as_ref() == b"text" {
+                                    let value = decode_attribute(&attr.value);
                                     subcategory_text = Some(
                                         value.chars().take(limits.max_attribute_length).collect(),
                                     );
@@ -568,10 +672,9 @@ fn parse_itunes_category(
                     if is_itunes_tag(sub_e.name().as_ref(), b"category")
                         && subcategory_text.is_none()
                     {
-                        for attr in sub_e.attributes().flatten() {
-                            if attr.key.as_ref() == b"text"
-                                && let Ok(value) = attr.unescape_value()
-                            {
+                        for attr in limited_attributes(&sub_e, limits) {
+                            if attr.key.as_ref() == b"text" {
+                                let value = decode_attribute(&attr.value);
                                 subcategory_text =
                                     Some(value.chars().take(limits.max_attribute_length).collect());
                                 break;
@@ -598,18 +701,22 @@ fn parse_itunes_category(
         .feed
         .itunes
         .get_or_insert_with(|| Box::new(ItunesFeedMeta::default()));
-    itunes.categories.push(ItunesCategory {
-        text: category_text,
-        subcategory: subcategory_text,
-    });
+    itunes.categories.try_push_limited(
+        ItunesCategory {
+            text: category_text,
+            subcategory: subcategory_text,
+        },
+        limits.max_itunes_categories,
+    );
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 namespace tags at channel level
 ///
 /// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
 #[inline]
 fn parse_channel_podcast(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
@@ -619,7 +726,7 @@ fn parse_channel_podcast(
 ) -> Result<bool> {
     if tag.starts_with(b"podcast:guid") {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             let podcast = feed
                 .feed
                 .podcast
@@ -634,7 +741,7 @@ fn parse_channel_podcast(
         let message = if is_empty {
             None
         } else {
-            let message_text = read_text(reader, buf, limits)?;
+            let message_text = read_text(reader, limits)?;
             if message_text.is_empty() {
                 None
             } else {
@@ -663,44 +770,90 @@ fn parse_channel_podcast(
     }
 }
 
-/// Parse Dublin Core, Content, `GeoRSS`, and Media RSS namespace tags at channel level
+/// Parse Feed History (RFC 5005) markers and `atom:link` archive relations at channel level
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn parse_channel_feed_history(
+    reader: &mut XmlReader<'_>,
+    tag: &[u8],
+    attrs: &[(Vec<u8>, String)],
+    feed: &mut ParsedFeed,
+    limits: &ParserLimits,
+    depth: usize,
+    is_empty: bool,
+) -> Result<bool> {
+    if let Some(fh_element) = is_fh_tag(tag) {
+        feed_history::handle_feed_element(fh_element, feed);
+    } else if tag == b"atom:link" {
+        let (Some(rel), Some(href)) = (
+            find_attribute(attrs, b"rel"),
+            find_attribute(attrs, b"href"),
+        ) else {
+            return Ok(false);
+        };
+        let href = truncate_to_length(href, limits.max_attribute_length);
+        if !feed_history::handle_link(rel, &href, feed) {
+            return Ok(false);
+        }
+    } else {
+        return Ok(false);
+    }
+
+    if !is_empty {
+        skip_element(reader, limits, depth)?;
+    }
+    Ok(true)
+}
+
+/// Parse Dublin Core, Content, `GeoRSS`, Media RSS and license tags at channel level
 #[inline]
 fn parse_channel_namespace(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
+    attrs: &[(Vec<u8>, String)],
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
     depth: usize,
     is_empty: bool,
 ) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
     if let Some(dc_element) = is_dc_tag(tag) {
         if !is_empty {
             let dc_elem = dc_element.to_string();
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             dublin_core::handle_feed_element(&dc_elem, &text, &mut feed.feed);
         }
-        Ok(true)
-    } else if let Some(_content_element) = is_content_tag(tag) {
+        return Ok(true);
+    }
+    #[cfg(feature = "georss")]
+    if let Some(georss_element) = is_georss_tag(tag) {
         if !is_empty {
-            skip_element(reader, buf, limits, depth)?;
+            let text = read_text_cow(reader, limits)?;
+            georss::handle_feed_element(georss_element.as_bytes(), &text, &mut feed.feed, limits);
         }
-        Ok(true)
-    } else if let Some(_media_element) = is_media_tag(tag) {
+        return Ok(true);
+    }
+
+    if is_content_tag(tag).is_some() || is_media_tag(tag).is_some() {
         if !is_empty {
-            skip_element(reader, buf, limits, depth)?;
+            skip_element(reader, limits, depth)?;
         }
         Ok(true)
-    } else if let Some(georss_element) = is_georss_tag(tag) {
+    } else if let Some(blog_channel_element) = is_blog_channel_tag(tag) {
         if !is_empty {
-            let text = read_text(reader, buf, limits)?;
-            georss::handle_feed_element(georss_element.as_bytes(), &text, &mut feed.feed, limits);
+            let blog_channel_elem = blog_channel_element.to_string();
+            let text = read_text_cow(reader, limits)?;
+            blog_channel::handle_feed_element(&blog_channel_elem, &text, &mut feed.feed);
         }
         Ok(true)
-    } else if tag.starts_with(b"creativeCommons:license") || tag == b"license" {
-        if !is_empty {
-            feed.feed.license = Some(read_text(reader, buf, limits)?);
-        }
+    } else if license::is_license_tag(tag) {
+        let text = if is_empty {
+            Cow::Borrowed("")
+        } else {
+            read_text_cow(reader, limits)?
+        };
+        let found = license::license_from_element(tag, attrs, &text);
+        license::merge(&mut feed.feed.license_detail, &mut feed.feed.license, found);
         Ok(true)
     } else {
         Ok(false)
@@ -712,8 +865,12 @@ fn parse_channel_namespace(
 /// Returns a tuple where:
 /// - First element: the parsed `Entry`
 /// - Second element: `bool` indicating whether attribute parsing errors occurred (for bozo flag)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.item", level = "trace", skip_all)
+)]
 fn parse_item(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -738,7 +895,7 @@ fn parse_item(
                 // We need owned tag data to pass &mut buf to helper functions simultaneously.
                 // Potential future optimization: restructure helpers to avoid this allocation.
                 let tag = e.name().as_ref().to_vec();
-                let (attrs, attr_error) = collect_attributes(e);
+                let (attrs, attr_error) = collect_attributes(e, limits);
                 if attr_error {
                     has_attr_errors = true;
                 }
@@ -747,9 +904,7 @@ fn parse_item(
                 match tag.as_slice() {
                     b"title" | b"link" | b"description" | b"guid" | b"pubDate" | b"author"
                     | b"category" | b"comments" => {
-                        parse_item_standard(
-                            reader, buf, &tag, &mut entry, limits, base_ctx, item_lang,
-                        )?;
+                        parse_item_standard(reader, &tag, &mut entry, limits, base_ctx, item_lang)?;
                     }
                     b"enclosure" => {
                         if let Some(mut enclosure) = parse_enclosure(&attrs, limits) {
@@ -759,31 +914,38 @@ fn parse_item(
                                 .try_push_limited(enclosure, limits.max_enclosures);
                         }
                         if !is_empty {
-                            skip_element(reader, buf, limits, *depth)?;
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                     b"source" => {
-                        if let Ok(source) = parse_source(reader, buf, limits, depth) {
+                        if let Ok(source) =
+                            parse_source(reader, buf, &attrs, is_empty, limits, depth, base_ctx)
+                        {
                             entry.source = Some(source);
                         }
                     }
                     _ => {
-                        let mut handled = parse_item_itunes(
-                            reader, buf, &tag, &attrs, &mut entry, limits, is_empty, *depth,
-                        )?;
+                        let mut handled = false;
+                        #[cfg(feature = "itunes")]
+                        if !handled {
+                            handled = parse_item_itunes(
+                                reader, &tag, &attrs, &mut entry, limits, is_empty, *depth,
+                            )?;
+                        }
+                        #[cfg(feature = "podcast20")]
                         if !handled {
                             handled = parse_item_podcast(
-                                reader, buf, &tag, &attrs, &mut entry, limits, is_empty, *depth,
+                                reader, &tag, &attrs, &mut entry, limits, is_empty, *depth,
                             )?;
                         }
                         if !handled {
                             handled = parse_item_namespace(
-                                reader, buf, &tag, &attrs, &mut entry, limits, is_empty, *depth,
+                                reader, &tag, &attrs, &mut entry, limits, is_empty, *depth,
                             )?;
                         }
 
                         if !handled && !is_empty {
-                            skip_element(reader, buf, limits, *depth)?;
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                 }
@@ -805,8 +967,7 @@ fn parse_item(
 /// Parse standard RSS 2.0 item elements
 #[inline]
 fn parse_item_standard(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
     entry: &mut Entry,
     limits: &ParserLimits,
@@ -815,7 +976,7 @@ fn parse_item_standard(
 ) -> Result<()> {
     match tag {
         b"title" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             entry.set_title(TextConstruct {
                 value: text,
                 content_type: TextType::Text,
@@ -824,7 +985,7 @@ fn parse_item_standard(
             });
         }
         b"link" => {
-            let link_text = read_text(reader, buf, limits)?;
+            let link_text = read_text_cow(reader, limits)?;
             let resolved_link = base_ctx.resolve_safe(&link_text);
             entry.link = Some(resolved_link.clone());
             entry.links.try_push_limited(
@@ -837,26 +998,32 @@ fn parse_item_standard(
             );
         }
         b"description" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             entry.set_summary(TextConstruct {
+                content_type: detect_text_type(&text),
                 value: text,
-                content_type: TextType::Html,
                 language: item_lang.map(std::convert::Into::into),
                 base: base_ctx.base().map(String::from),
             });
         }
         b"guid" => {
-            entry.id = Some(read_text(reader, buf, limits)?.into());
+            entry.id = Some(read_text(reader, limits)?.into());
         }
         b"pubDate" => {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             entry.published = parse_date(&text);
         }
         b"author" => {
-            entry.author = Some(read_text(reader, buf, limits)?.into());
+            let text = read_text(reader, limits)?;
+            let person = Person::from_rss(&text);
+            if entry.author_detail.is_none() {
+                entry.author = Some(text.into());
+                entry.author_detail = Some(person.clone());
+            }
+            entry.authors.try_push_limited(person, limits.max_authors);
         }
         b"category" => {
-            let term = read_text(reader, buf, limits)?;
+            let term = read_text_cow(reader, limits)?;
             entry.tags.try_push_limited(
                 Tag {
                     term: term.into(),
@@ -867,13 +1034,14 @@ fn parse_item_standard(
             );
         }
         b"comments" => {
-            entry.comments = Some(read_text(reader, buf, limits)?);
+            entry.comments = Some(read_text(reader, limits)?);
         }
         _ => {}
     }
     Ok(())
 }
 
+#[cfg(feature = "itunes")]
 /// Parse iTunes namespace tags at item level
 ///
 /// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
@@ -883,8 +1051,7 @@ fn parse_item_standard(
 #[inline]
 #[allow(clippy::too_many_arguments)]
 fn parse_item_itunes(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
@@ -893,28 +1060,28 @@ fn parse_item_itunes(
     depth: usize,
 ) -> Result<bool> {
     if is_itunes_tag(tag, b"title") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
         itunes.title = Some(text);
         Ok(true)
     } else if is_itunes_tag(tag, b"author") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
         itunes.author = Some(text);
         Ok(true)
     } else if is_itunes_tag(tag, b"duration") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
         itunes.duration = parse_duration(&text);
         Ok(true)
     } else if is_itunes_tag(tag, b"explicit") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
@@ -928,25 +1095,25 @@ fn parse_item_itunes(
             itunes.image = Some(truncate_to_length(value, limits.max_attribute_length).into());
         }
         if !is_empty {
-            skip_element(reader, buf, limits, depth)?;
+            skip_element(reader, limits, depth)?;
         }
         Ok(true)
     } else if is_itunes_tag(tag, b"episode") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
         itunes.episode = text.parse().ok();
         Ok(true)
     } else if is_itunes_tag(tag, b"season") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
         itunes.season = text.parse().ok();
         Ok(true)
     } else if is_itunes_tag(tag, b"episodeType") {
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text(reader, limits)?;
         let itunes = entry
             .itunes
             .get_or_insert_with(|| Box::new(ItunesEntryMeta::default()));
@@ -957,6 +1124,7 @@ fn parse_item_itunes(
     }
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 namespace tags at item level
 ///
 /// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
@@ -966,8 +1134,7 @@ fn parse_item_itunes(
 #[inline]
 #[allow(clippy::too_many_arguments)]
 fn parse_item_podcast(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
@@ -976,29 +1143,32 @@ fn parse_item_podcast(
     depth: usize,
 ) -> Result<bool> {
     if tag.starts_with(b"podcast:transcript") {
-        parse_podcast_transcript(reader, buf, attrs, entry, limits, is_empty, depth)?;
+        parse_podcast_transcript(reader, attrs, entry, limits, is_empty, depth)?;
         Ok(true)
     } else if tag.starts_with(b"podcast:person") {
-        parse_podcast_person(reader, buf, attrs, entry, limits)?;
+        parse_podcast_person(reader, attrs, entry, limits)?;
         Ok(true)
     } else if tag.starts_with(b"podcast:chapters") {
-        parse_podcast_chapters(reader, buf, attrs, entry, limits, is_empty, depth)?;
+        parse_podcast_chapters(reader, attrs, entry, limits, is_empty, depth)?;
         Ok(true)
     } else if tag.starts_with(b"podcast:soundbite") {
-        parse_podcast_soundbite(reader, buf, attrs, entry, limits, is_empty, depth)?;
+        parse_podcast_soundbite(reader, attrs, entry, limits, is_empty, depth)?;
+        Ok(true)
+    } else if tag.starts_with(b"podcast:alternateEnclosure") {
+        parse_podcast_alternate_enclosure(reader, attrs, entry, limits, is_empty, depth)?;
         Ok(true)
     } else {
         Ok(false)
     }
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 transcript element
 ///
 /// Note: Currently always returns `Ok(())` but uses `Result` return type
 /// for consistency with other parsers and potential future error handling.
 fn parse_podcast_transcript(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
     limits: &ParserLimits,
@@ -1028,16 +1198,16 @@ fn parse_podcast_transcript(
     }
 
     if !is_empty {
-        skip_element(reader, buf, limits, depth)?;
+        skip_element(reader, limits, depth)?;
     }
 
     Ok(())
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 person element
 fn parse_podcast_person(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
     limits: &ParserLimits,
@@ -1051,7 +1221,7 @@ fn parse_podcast_person(
     let href =
         find_attribute(attrs, b"href").map(|v| truncate_to_length(v, limits.max_attribute_length));
 
-    let name = read_text(reader, buf, limits)?;
+    let name = read_text(reader, limits)?;
     if !name.is_empty() {
         entry.podcast_persons.try_push_limited(
             PodcastPerson {
@@ -1068,10 +1238,10 @@ fn parse_podcast_person(
     Ok(())
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 chapters element
 fn parse_podcast_chapters(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
     limits: &ParserLimits,
@@ -1096,16 +1266,16 @@ fn parse_podcast_chapters(
     }
 
     if !is_empty {
-        skip_element(reader, buf, limits, depth)?;
+        skip_element(reader, limits, depth)?;
     }
 
     Ok(())
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 soundbite element
 fn parse_podcast_soundbite(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
     limits: &ParserLimits,
@@ -1119,7 +1289,7 @@ fn parse_podcast_soundbite(
         let title = if is_empty {
             None
         } else {
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text(reader, limits)?;
             if text.is_empty() { None } else { Some(text) }
         };
 
@@ -1135,13 +1305,86 @@ fn parse_podcast_soundbite(
             limits.max_podcast_soundbites,
         );
     } else if !is_empty {
-        skip_element(reader, buf, limits, depth)?;
+        skip_element(reader, limits, depth)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "podcast20")]
+/// Parse Podcast 2.0 alternateEnclosure element and its podcast:source children
+fn parse_podcast_alternate_enclosure(
+    reader: &mut XmlReader<'_>,
+    attrs: &[(Vec<u8>, String)],
+    entry: &mut Entry,
+    limits: &ParserLimits,
+    is_empty: bool,
+    depth: usize,
+) -> Result<()> {
+    let attr = |key: &[u8]| {
+        find_attribute(attrs, key).map(|v| truncate_to_length(v, limits.max_attribute_length))
+    };
+    let mut alternate = PodcastAlternateEnclosure {
+        enclosure_type: attr(b"type").unwrap_or_default().into(),
+        length: find_attribute(attrs, b"length").and_then(|v| v.trim().parse().ok()),
+        // Some feeds write fractional bitrates such as "128000.5"
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        bitrate: find_attribute(attrs, b"bitrate")
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|b| b.is_finite() && *b >= 0.0)
+            .map(|b| b.round() as u64),
+        height: find_attribute(attrs, b"height").and_then(|v| v.trim().parse().ok()),
+        lang: attr(b"lang"),
+        title: attr(b"title"),
+        default: find_attribute(attrs, b"default").is_some_and(|v| v.trim() == "true"),
+        sources: Vec::new(),
+    };
+
+    // Depth below the alternateEnclosure element
+    let mut nested = 0usize;
+    let mut open = !is_empty;
+    while open {
+        match reader.read_event()? {
+            event @ (Event::Start(_) | Event::Empty(_)) => {
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+                if e.local_name().as_ref() == b"source" {
+                    let (source_attrs, _) = collect_attributes(e, limits);
+                    if let Some(uri) =
+                        find_attribute(&source_attrs, b"uri").filter(|u| !u.is_empty())
+                    {
+                        alternate.sources.try_push_limited(
+                            truncate_to_length(uri, limits.max_attribute_length).into(),
+                            limits.max_enclosures,
+                        );
+                    }
+                }
+                if matches!(event, Event::Start(_)) {
+                    nested += 1;
+                    check_depth(depth + nested, limits.max_nesting_depth)?;
+                }
+            }
+            Event::End(_) if nested == 0 => open = false,
+            Event::End(_) => nested -= 1,
+            Event::Eof => open = false,
+            _ => {}
+        }
+    }
+
+    if !alternate.sources.is_empty() {
+        let podcast = entry
+            .podcast
+            .get_or_insert_with(|| Box::new(PodcastEntryMeta::default()));
+        podcast
+            .alternate_enclosure
+            .try_push_limited(alternate, limits.max_enclosures);
     }
 
     Ok(())
 }
 
-/// Parse Dublin Core, Content, and Media RSS namespace tags at item level
+/// Parse Dublin Core, Content, `GeoRSS`, PRISM and Media RSS namespace tags at item level
 ///
 /// Returns `Ok(true)` if the tag was recognized and handled, `Ok(false)` if not recognized.
 ///
@@ -1149,9 +1392,9 @@ fn parse_podcast_soundbite(
 /// with multiple simultaneous `&mut` references during parsing.
 #[inline]
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "mediarss"), allow(unused_variables))]
 fn parse_item_namespace(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     tag: &[u8],
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
@@ -1159,45 +1402,60 @@ fn parse_item_namespace(
     is_empty: bool,
     depth: usize,
 ) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
     if let Some(dc_element) = is_dc_tag(tag) {
         let dc_elem = dc_element.to_string();
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         dublin_core::handle_entry_element(&dc_elem, &text, entry);
-        Ok(true)
-    } else if let Some(content_element) = is_content_tag(tag) {
+        return Ok(true);
+    }
+    #[cfg(feature = "georss")]
+    if let Some(georss_element) = is_georss_tag(tag) {
+        let text = read_text_cow(reader, limits)?;
+        georss::handle_entry_element(georss_element.as_bytes(), &text, entry, limits);
+        return Ok(true);
+    }
+    #[cfg(feature = "mediarss")]
+    if let Some(media_element) = is_media_tag(tag) {
+        parse_item_media(reader, media_element, attrs, entry, limits, is_empty, depth)?;
+        return Ok(true);
+    }
+
+    if let Some(content_element) = is_content_tag(tag) {
         let content_elem = content_element.to_string();
-        let text = read_text(reader, buf, limits)?;
+        let text = read_text_cow(reader, limits)?;
         content::handle_entry_element(&content_elem, &text, entry);
         Ok(true)
-    } else if let Some(georss_element) = is_georss_tag(tag) {
-        let text = read_text(reader, buf, limits)?;
-        georss::handle_entry_element(georss_element.as_bytes(), &text, entry, limits);
+    } else if let Some(prism_element) = is_prism_tag(tag) {
+        let prism_elem = prism_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        prism::handle_entry_element(&prism_elem, &text, entry);
         Ok(true)
-    } else if let Some(media_element) = is_media_tag(tag) {
-        parse_item_media(
-            reader,
-            buf,
-            media_element,
-            attrs,
-            entry,
-            limits,
-            is_empty,
-            depth,
-        )?;
+    } else if license::is_license_tag(tag) {
+        let text = if is_empty {
+            Cow::Borrowed("")
+        } else {
+            read_text_cow(reader, limits)?
+        };
+        let found = license::license_from_element(tag, attrs, &text);
+        license::merge(&mut entry.license_detail, &mut entry.license, found);
         Ok(true)
-    } else if tag.starts_with(b"creativeCommons:license") || tag == b"license" {
-        entry.license = Some(read_text(reader, buf, limits)?);
+    } else if threading::is_count_tag(tag) {
+        if !is_empty {
+            let text = read_text_cow(reader, limits)?;
+            threading::handle_entry_element(tag, &text, entry);
+        }
         Ok(true)
     } else {
         Ok(false)
     }
 }
 
+#[cfg(feature = "mediarss")]
 /// Parse Media RSS namespace elements
 #[allow(clippy::too_many_arguments)]
 fn parse_item_media(
-    reader: &mut Reader<&[u8]>,
-    buf: &mut Vec<u8>,
+    reader: &mut XmlReader<'_>,
     media_element: &str,
     attrs: &[(Vec<u8>, String)],
     entry: &mut Entry,
@@ -1212,6 +1470,8 @@ fn parse_item_media(
                 .unwrap_or_default();
             let width = find_attribute(attrs, b"width").and_then(|v| v.parse().ok());
             let height = find_attribute(attrs, b"height").and_then(|v| v.parse().ok());
+            let time = find_attribute(attrs, b"time")
+                .map(|v| truncate_to_length(v, limits.max_attribute_length));
 
             if !url.is_empty() {
                 entry.media_thumbnails.try_push_limited(
@@ -1219,12 +1479,13 @@ fn parse_item_media(
                         url: url.into(),
                         width,
                         height,
+                        time,
                     },
-                    limits.max_enclosures,
+                    limits.max_media_thumbnails,
                 );
             }
             if !is_empty {
-                skip_element(reader, buf, limits, depth)?;
+                skip_element(reader, limits, depth)?;
             }
         }
         "content" => {
@@ -1237,6 +1498,11 @@ fn parse_item_media(
             let duration = find_attribute(attrs, b"duration").and_then(|v| v.parse().ok());
             let width = find_attribute(attrs, b"width").and_then(|v| v.parse().ok());
             let height = find_attribute(attrs, b"height").and_then(|v| v.parse().ok());
+            let thumbnails = if is_empty {
+                Vec::new()
+            } else {
+                parse_media_children(reader, entry, limits, depth, None)?
+            };
 
             if !url.is_empty() {
                 entry.media_content.try_push_limited(
@@ -1247,17 +1513,21 @@ fn parse_item_media(
                         width,
                         height,
                         duration,
+                        thumbnails,
+                        group: None,
                     },
-                    limits.max_enclosures,
+                    limits.max_media_content,
                 );
             }
+        }
+        "group" => {
             if !is_empty {
-                skip_element(reader, buf, limits, depth)?;
+                parse_media_group(reader, entry, limits, depth)?;
             }
         }
         _ => {
             let media_elem = media_element.to_string();
-            let text = read_text(reader, buf, limits)?;
+            let text = read_text_cow(reader, limits)?;
             media_rss::handle_entry_element(&media_elem, &text, entry);
         }
     }
@@ -1266,7 +1536,7 @@ fn parse_item_media(
 
 /// Parse <image> element
 fn parse_image(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -1285,21 +1555,21 @@ fn parse_image(
                 check_depth(*depth, limits.max_nesting_depth)?;
 
                 match e.local_name().as_ref() {
-                    b"url" => url = read_text(reader, buf, limits)?,
-                    b"title" => title = Some(read_text(reader, buf, limits)?),
-                    b"link" => link = Some(read_text(reader, buf, limits)?),
+                    b"url" => url = read_text(reader, limits)?,
+                    b"title" => title = Some(read_text(reader, limits)?),
+                    b"link" => link = Some(read_text(reader, limits)?),
                     b"width" => {
-                        if let Ok(w) = read_text(reader, buf, limits)?.parse() {
+                        if let Ok(w) = read_text(reader, limits)?.parse() {
                             width = Some(w);
                         }
                     }
                     b"height" => {
-                        if let Ok(h) = read_text(reader, buf, limits)?.parse() {
+                        if let Ok(h) = read_text(reader, limits)?.parse() {
                             height = Some(h);
                         }
                     }
-                    b"description" => description = Some(read_text(reader, buf, limits)?),
-                    _ => skip_element(reader, buf, limits, *depth)?,
+                    b"description" => description = Some(read_text(reader, limits)?),
+                    _ => skip_element(reader, limits, *depth)?,
                 }
                 *depth = depth.saturating_sub(1);
             }
@@ -1326,43 +1596,78 @@ fn parse_image(
 }
 
 /// Parse <source> element
+///
+/// The standard form is `<source url="feed.xml">Title</source>`. Some feeds
+/// nest `<title>` and `<url>` children instead, which are also accepted.
 fn parse_source(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
+    attrs: &[(Vec<u8>, String)],
+    is_empty: bool,
     limits: &ParserLimits,
     depth: &mut usize,
+    base_ctx: &BaseUrlContext,
 ) -> Result<Source> {
     let mut title = None;
-    let mut link = None;
-    let id = None;
-
-    loop {
-        match reader.read_event_into(buf) {
-            Ok(Event::Start(e)) => {
-                *depth += 1;
-                check_depth(*depth, limits.max_nesting_depth)?;
-
-                match e.local_name().as_ref() {
-                    b"title" => title = Some(read_text(reader, buf, limits)?),
-                    b"url" => link = Some(read_text(reader, buf, limits)?),
-                    _ => skip_element(reader, buf, limits, *depth)?,
+    let mut link = attrs
+        .iter()
+        .find(|(key, value)| key.as_slice() == b"url" && !value.is_empty())
+        .map(|(_, url)| truncate_to_length(url, limits.max_attribute_length));
+    let mut text = String::new();
+    if !is_empty {
+        // Children are read untrimmed too, so their values are trimmed here
+        with_untrimmed_text(reader, |reader| {
+            loop {
+                match reader.read_event_into(buf) {
+                    Ok(Event::Start(e)) => {
+                        *depth += 1;
+                        check_depth(*depth, limits.max_nesting_depth)?;
+
+                        match e.local_name().as_ref() {
+                            b"title" => title = Some(read_text(reader, limits)?.trim().to_string()),
+                            b"url" => link = Some(read_text(reader, limits)?.trim().to_string()),
+                            _ => skip_element(reader, limits, *depth)?,
+                        }
+                        *depth = depth.saturating_sub(1);
+                    }
+                    Ok(Event::Text(e)) => push_limited(&mut text, &e, limits),
+                    Ok(Event::CData(e)) => push_limited(&mut text, &e, limits),
+                    Ok(Event::GeneralRef(e)) => {
+                        push_limited(&mut text, &resolve_reference(&e), limits);
+                    }
+                    Ok(Event::End(e)) if e.local_name().as_ref() == b"source" => break,
+                    Ok(Event::Eof) => break,
+                    Err(e) => return Err(e.into()),
+                    _ => {}
                 }
-                *depth = depth.saturating_sub(1);
+                buf.clear();
             }
-            Ok(Event::End(e)) if e.local_name().as_ref() == b"source" => break,
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(e.into()),
-            _ => {}
-        }
-        buf.clear();
+            Ok(())
+        })?;
     }
 
-    Ok(Source { title, link, id })
+    let text = text.trim();
+    if title.is_none() && !text.is_empty() {
+        title = Some(text.to_string());
+    }
+    Ok(Source {
+        title,
+        link: link.map(|url| base_ctx.resolve_safe(&url)),
+        id: None,
+    })
 }
 
+/// Appends text bytes, ignoring anything past `max_text_length`
+fn push_limited(text: &mut String, bytes: &[u8], limits: &ParserLimits) {
+    if text.len() + bytes.len() <= limits.max_text_length {
+        text.push_str(&String::from_utf8_lossy(bytes));
+    }
+}
+
+#[cfg(feature = "itunes")]
 /// Parse iTunes owner from <itunes:owner> element
 fn parse_itunes_owner(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -1377,11 +1682,11 @@ fn parse_itunes_owner(
 
                 let tag_name = e.local_name();
                 if is_itunes_tag(tag_name.as_ref(), b"name") {
-                    owner.name = Some(read_text(reader, buf, limits)?);
+                    owner.name = Some(read_text(reader, limits)?);
                 } else if is_itunes_tag(tag_name.as_ref(), b"email") {
-                    owner.email = Some(read_text(reader, buf, limits)?);
+                    owner.email = Some(read_text(reader, limits)?);
                 } else {
-                    skip_element(reader, buf, limits, *depth)?;
+                    skip_element(reader, limits, *depth)?;
                 }
                 *depth = depth.saturating_sub(1);
             }
@@ -1395,12 +1700,13 @@ fn parse_itunes_owner(
     Ok(owner)
 }
 
+#[cfg(feature = "podcast20")]
 /// Parse Podcast 2.0 value element from <podcast:value> element
 ///
 /// Parses value-for-value payment information including payment type, method,
 /// suggested amount, and nested valueRecipient elements.
 fn parse_podcast_value(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     attrs: &[(Vec<u8>, String)],
     feed: &mut ParsedFeed,
@@ -1424,7 +1730,7 @@ fn parse_podcast_value(
             Ok(Event::Start(e) | Event::Empty(e)) => {
                 let tag_name = e.name();
                 if tag_name.as_ref().starts_with(b"podcast:valueRecipient") {
-                    let (recipient_attrs, _) = collect_attributes(&e);
+                    let (recipient_attrs, _) = collect_attributes(&e, limits);
 
                     let name = find_attribute(&recipient_attrs, b"name")
                         .map(|v| truncate_to_length(v, limits.max_attribute_length));
@@ -1484,6 +1790,8 @@ fn parse_podcast_value(
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "podcast20")]
+    use crate::types::Url;
     use chrono::Datelike;
 
     #[test]
@@ -1553,6 +1861,22 @@ mod tests {
         assert_eq!(dt.day(), 14);
     }
 
+    #[test]
+    fn test_parse_rss_pub_date_and_last_build_date() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0">
+            <channel>
+                <pubDate>Sat, 14 Dec 2024 10:30:00 +0000</pubDate>
+                <lastBuildDate>Mon, 16 Dec 2024 08:00:00 +0000</lastBuildDate>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        assert!(!feed.bozo);
+        assert_eq!(feed.feed.published.unwrap().day(), 14);
+        assert_eq!(feed.feed.updated.unwrap().day(), 16);
+    }
+
     #[test]
     fn test_parse_rss_with_invalid_date() {
         let xml = br#"<?xml version="1.0"?>
@@ -1671,6 +1995,26 @@ mod tests {
         assert_eq!(img.height, Some(36));
     }
 
+    #[test]
+    fn test_parse_rss_relative_image() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0">
+            <channel>
+                <link>http://example.com/blog/</link>
+                <image>
+                    <url>images/logo.png</url>
+                    <link>/</link>
+                </image>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        let img = feed.feed.image.as_ref().unwrap();
+        assert_eq!(img.url, "http://example.com/blog/images/logo.png");
+        assert_eq!(img.link.as_deref(), Some("http://example.com/"));
+        assert_eq!(feed.feed.raw_image_url.as_deref(), Some("images/logo.png"));
+    }
+
     #[test]
     fn test_parse_rss_with_author() {
         let xml = br#"<?xml version="1.0"?>
@@ -1761,6 +2105,21 @@ mod tests {
         assert_eq!(feed.feed.generator.as_deref(), Some("WordPress 6.0"));
     }
 
+    #[test]
+    fn test_parse_rss_with_copyright() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0">
+            <channel>
+                <copyright>Copyright 2024 Example</copyright>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        assert_eq!(feed.feed.rights.as_deref(), Some("Copyright 2024 Example"));
+        let detail = feed.feed.rights_detail.as_ref().unwrap();
+        assert_eq!(detail.content_type, TextType::Text);
+    }
+
     #[test]
     fn test_parse_rss_with_limits() {
         let xml = br#"<?xml version="1.0"?>
@@ -1818,6 +2177,41 @@ mod tests {
         assert_eq!(source.link.as_deref(), Some("http://source.example.com"));
     }
 
+    #[test]
+    fn test_parse_rss_source_url_attribute() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0">
+            <channel>
+                <link>http://example.com/</link>
+                <item>
+                    <source url="http://origin.example.com/rss.xml">Origin &amp; Co</source>
+                    <title>First</title>
+                </item>
+                <item>
+                    <source url="/partner.xml"/>
+                    <title>Second</title>
+                </item>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        let source = feed.entries[0].source.as_ref().unwrap();
+        assert_eq!(source.title.as_deref(), Some("Origin & Co"));
+        assert_eq!(
+            source.link.as_deref(),
+            Some("http://origin.example.com/rss.xml")
+        );
+        assert_eq!(feed.entries[0].title.as_deref(), Some("First"));
+
+        let source = feed.entries[1].source.as_ref().unwrap();
+        assert_eq!(source.title, None);
+        assert_eq!(
+            source.link.as_deref(),
+            Some("http://example.com/partner.xml")
+        );
+        assert_eq!(feed.entries[1].title.as_deref(), Some("Second"));
+    }
+
     #[test]
     fn test_parse_rss_empty_elements() {
         let xml = br#"<?xml version="1.0"?>
@@ -1832,6 +2226,35 @@ mod tests {
         assert!(feed.feed.title.is_none() || feed.feed.title.as_deref() == Some(""));
     }
 
+    #[test]
+    fn test_parse_rss_description_text_type() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0">
+            <channel>
+                <description>News &amp; views</description>
+                <item><description>Plain text, 1 &lt; 2</description></item>
+                <item><description>&lt;p&gt;Escaped &lt;b&gt;HTML&lt;/b&gt;&lt;/p&gt;</description></item>
+                <item><description><![CDATA[Line one<br/>line two]]></description></item>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        let summary_type = |i: usize| {
+            feed.entries[i]
+                .summary_detail
+                .as_ref()
+                .unwrap()
+                .content_type
+        };
+        assert_eq!(
+            feed.feed.subtitle_detail.as_ref().unwrap().content_type,
+            TextType::Text
+        );
+        assert_eq!(summary_type(0), TextType::Text);
+        assert_eq!(summary_type(1), TextType::Html);
+        assert_eq!(summary_type(2), TextType::Html);
+    }
+
     #[test]
     fn test_parse_rss_nesting_depth_limit() {
         let mut xml = String::from(r#"<?xml version="1.0"?><rss version="2.0"><channel>"#);
@@ -1864,6 +2287,7 @@ mod tests {
 
     // PRIORITY 1: iTunes Item-Level Tests (CRITICAL)
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_parse_rss_itunes_episode_metadata() {
         let xml = br#"<?xml version="1.0"?>
@@ -1905,6 +2329,7 @@ mod tests {
         assert_eq!(itunes.episode_type.as_deref(), Some("full"));
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_parse_rss_itunes_duration_formats() {
         // Test HH:MM:SS format
@@ -1960,6 +2385,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_parse_rss_itunes_nested_categories() {
         let xml = br#"<?xml version="1.0"?>
@@ -1997,6 +2423,7 @@ mod tests {
         assert!(itunes.categories[2].subcategory.is_none());
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_parse_rss_itunes_owner_parsing() {
         let xml = br#"<?xml version="1.0"?>
@@ -2020,6 +2447,7 @@ mod tests {
 
     // PRIORITY 2: Podcast 2.0 Tests
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_locked_and_guid() {
         let xml = br#"<?xml version="1.0"?>
@@ -2040,6 +2468,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_funding() {
         let xml = br#"<?xml version="1.0"?>
@@ -2063,6 +2492,48 @@ mod tests {
         assert_eq!(podcast.funding[1].url, "https://buymeacoffee.com/example");
     }
 
+    #[cfg(feature = "podcast20")]
+    #[test]
+    fn test_parse_rss_podcast_alternate_enclosure() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+            <channel>
+                <item>
+                    <podcast:alternateEnclosure type="audio/opus" length="3200000"
+                        bitrate="64000.4" lang="en" title="Opus" default="true">
+                        <podcast:source uri="ipfs://QmExample"/>
+                        <podcast:source uri="https://example.com/ep1.opus"></podcast:source>
+                        <podcast:integrity type="sri" value="sha384-abc"/>
+                    </podcast:alternateEnclosure>
+                    <podcast:alternateEnclosure type="video/mp4"/>
+                    <title>Episode 1</title>
+                </item>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        let entry = &feed.entries[0];
+        assert_eq!(entry.title.as_deref(), Some("Episode 1"));
+
+        let alternates = &entry.podcast.as_ref().unwrap().alternate_enclosure;
+        assert_eq!(alternates.len(), 1);
+        let alternate = &alternates[0];
+        assert_eq!(alternate.enclosure_type, "audio/opus");
+        assert_eq!(alternate.length, Some(3_200_000));
+        assert_eq!(alternate.bitrate, Some(64_000));
+        assert_eq!(alternate.lang.as_deref(), Some("en"));
+        assert_eq!(alternate.title.as_deref(), Some("Opus"));
+        assert!(alternate.default);
+        assert_eq!(
+            alternate.sources,
+            vec![
+                Url::new("ipfs://QmExample"),
+                Url::new("https://example.com/ep1.opus")
+            ]
+        );
+    }
+
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_transcript() {
         let xml = br#"<?xml version="1.0"?>
@@ -2106,6 +2577,7 @@ mod tests {
         assert_eq!(transcripts[1].transcript_type.as_deref(), Some("text/vtt"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_person() {
         let xml = br#"<?xml version="1.0"?>
@@ -2146,6 +2618,7 @@ mod tests {
 
     // PRIORITY 3: Namespace Tests
 
+    #[cfg(feature = "dublincore")]
     #[test]
     fn test_parse_rss_dublin_core_channel() {
         let xml = br#"<?xml version="1.0"?>
@@ -2315,6 +2788,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_rss_blog_channel() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0" xmlns:blogChannel="http://backend.userland.com/blogChannelModule">
+            <channel>
+                <title>Weblog</title>
+                <blogChannel:blogRoll>http://example.com/blogroll.opml</blogChannel:blogRoll>
+                <blogChannel:mySubscriptions>http://example.com/subs.opml</blogChannel:mySubscriptions>
+                <blogChannel:blink>http://friend.example.org/</blogChannel:blink>
+                <blogChannel:changes>http://weblogs.com/changes.xml</blogChannel:changes>
+                <item><title>Post</title></item>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        assert!(!feed.bozo);
+        let meta = feed.feed.blog_channel.unwrap();
+        assert_eq!(
+            meta.blog_roll.as_deref(),
+            Some("http://example.com/blogroll.opml")
+        );
+        assert_eq!(
+            meta.my_subscriptions.as_deref(),
+            Some("http://example.com/subs.opml")
+        );
+        assert_eq!(meta.blink.as_deref(), Some("http://friend.example.org/"));
+        assert_eq!(feed.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rss_feed_history() {
+        let xml = br#"<?xml version="1.0"?>
+        <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom"
+             xmlns:fh="http://purl.org/syndication/history/1.0">
+            <channel>
+                <title>Complete Feed</title>
+                <fh:complete/>
+                <atom:link rel="self" href="https://example.com/feed"/>
+                <atom:link rel="prev-archive" href="https://example.com/archive/1"/>
+                <item><title>Item</title></item>
+            </channel>
+        </rss>"#;
+
+        let feed = parse_rss20(xml).unwrap();
+        assert!(!feed.bozo);
+        let history = feed.history.unwrap();
+        assert!(history.complete);
+        assert!(!history.archive);
+        assert_eq!(
+            history.prev_archive.as_deref(),
+            Some("https://example.com/archive/1")
+        );
+        assert!(history.next_archive.is_none());
+        assert_eq!(feed.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rss_without_feed_history() {
+        let xml = br#"<rss version="2.0"><channel><title>T</title></channel></rss>"#;
+        let feed = parse_rss20(xml).unwrap();
+        assert!(feed.history.is_none());
+    }
+
     #[test]
     fn test_parse_rss_license_channel() {
         let xml = br#"<?xml version="1.0"?>
@@ -2352,6 +2888,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_value_lightning() {
         let xml = br#"<?xml version="1.0"?>
@@ -2405,6 +2942,7 @@ mod tests {
         assert_eq!(value.recipients[1].fee, Some(false));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_value_without_suggested() {
         let xml = br#"<?xml version="1.0"?>
@@ -2431,6 +2969,7 @@ mod tests {
         assert_eq!(value.recipients[0].split, 100);
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_value_with_fee_recipient() {
         let xml = br#"<?xml version="1.0"?>
@@ -2462,6 +3001,7 @@ mod tests {
         assert_eq!(value.recipients[1].fee, Some(false));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_value_respects_limits() {
         let mut xml = String::from(
@@ -2500,6 +3040,46 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "mediarss")]
+    #[cfg(feature = "itunes")]
+    #[test]
+    fn test_parse_rss_extension_limits() {
+        let mut xml = String::from(
+            r#"<rss version="2.0"
+                xmlns:media="http://search.yahoo.com/mrss/"
+                xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel>
+                <itunes:keywords>a, b, c, d, e</itunes:keywords>
+                <itunes:category text="Arts"/>
+                <itunes:category text="News"/>
+                <itunes:category text="Technology"/>
+                <item>"#,
+        );
+        for i in 0..5 {
+            use std::fmt::Write;
+            write!(
+                xml,
+                r#"<media:content url="https://e.com/{i}.mp4"/><media:thumbnail url="https://e.com/{i}.jpg"/>"#
+            )
+            .unwrap();
+        }
+        xml.push_str("</item></channel></rss>");
+
+        let limits = ParserLimits::default()
+            .with_max_media_content(2)
+            .with_max_media_thumbnails(3)
+            .with_max_itunes_keywords(4)
+            .with_max_itunes_categories(1);
+        let feed = parse_rss20_with_limits(xml.as_bytes(), limits).unwrap();
+
+        let itunes = feed.feed.itunes.as_ref().unwrap();
+        assert_eq!(itunes.keywords, ["a", "b", "c", "d"]);
+        assert_eq!(itunes.categories.len(), 1);
+        assert_eq!(feed.entries[0].media_content.len(), 2);
+        assert_eq!(feed.entries[0].media_thumbnails.len(), 3);
+    }
+
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_parse_rss_podcast_value_empty_recipients() {
         let xml = br#"<?xml version="1.0"?>
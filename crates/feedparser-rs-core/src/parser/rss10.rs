@@ -7,17 +7,29 @@
 //! - Items have `rdf:about` attributes for identification
 //! - Supports Dublin Core and other RDF vocabularies
 
+use super::XmlReader;
+#[cfg(feature = "dublincore")]
+use crate::namespace::dublin_core;
+#[cfg(feature = "georss")]
+use crate::namespace::georss;
 use crate::{
-    ParserLimits,
+    ParseOptions, ParserLimits,
     error::{FeedError, Result},
-    namespace::{content, dublin_core, georss, syndication},
-    types::{Entry, FeedVersion, Image, ParsedFeed, TextConstruct, TextType},
+    limits::MemoryBudget,
+    namespace::{content, license, prism, syndication, threading},
+    types::{Entry, FeedMeta, FeedVersion, Image, ParsedFeed, TextConstruct},
+    util::{entities::decode_attribute, text::detect_text_type},
 };
-use quick_xml::{Reader, events::Event};
+use quick_xml::events::Event;
 
+#[cfg(feature = "dublincore")]
+use super::common::is_dc_tag;
+#[cfg(feature = "georss")]
+use super::common::is_georss_tag;
 use super::common::{
-    EVENT_BUFFER_CAPACITY, LimitedCollectionExt, check_depth, init_feed, is_content_tag, is_dc_tag,
-    is_georss_tag, is_syn_tag, read_text, skip_element,
+    EVENT_BUFFER_CAPACITY, EntryWindow, LimitedCollectionExt, check_depth, init_feed,
+    is_content_tag, is_prism_tag, is_syn_tag, limited_attributes, parse_license_element, read_text,
+    read_text_cow, skip_element,
 };
 
 /// Parse RSS 1.0 (RDF) feed from raw bytes
@@ -62,16 +74,40 @@ pub fn parse_rss10(data: &[u8]) -> Result<ParsedFeed> {
 
 /// Parse RSS 1.0 with custom parser limits
 pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<ParsedFeed> {
+    parse_rss10_with_options(
+        data,
+        &ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        },
+    )
+}
+
+/// Parse RSS 1.0 with custom parse options
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.parse_rss10",
+        skip_all,
+        fields(
+            bytes = data.len(),
+            entries = tracing::field::Empty,
+        ),
+    )
+)]
+pub fn parse_rss10_with_options(data: &[u8], options: &ParseOptions) -> Result<ParsedFeed> {
+    let limits = options.limits;
     limits
         .check_feed_size(data.len())
         .map_err(|e| FeedError::InvalidFormat(e.to_string()))?;
 
-    let mut reader = Reader::from_reader(data);
-    reader.config_mut().trim_text(true);
+    let mut reader = options.xml_reader(data);
 
     let mut feed = init_feed(FeedVersion::Rss10, limits.max_entries);
     let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
     let mut depth: usize = 1;
+    let mut budget = MemoryBudget::new(&limits);
+    let mut window = EntryWindow::new(options);
 
     loop {
         match reader.read_event_into(&mut buf) {
@@ -86,15 +122,16 @@ pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
                     // RDF root - nothing more to do, continue parsing children
                 } else if name.as_ref() == b"channel" {
                     // Extract rdf:about as feed ID
-                    for attr in e.attributes().flatten() {
-                        if (attr.key.as_ref() == b"rdf:about"
-                            || attr.key.local_name().as_ref() == b"about")
-                            && let Ok(value) = attr.unescape_value()
+                    for attr in limited_attributes(&e, &limits) {
+                        if attr.key.as_ref() == b"rdf:about"
+                            || attr.key.local_name().as_ref() == b"about"
                         {
-                            feed.feed.id = Some(value.as_ref().into());
+                            feed.feed.id = Some(decode_attribute(&attr.value));
                         }
                     }
-                    if let Err(e) = parse_channel(&mut reader, &mut feed, &limits, &mut depth) {
+                    if let Err(e) =
+                        parse_channel(&mut reader, &mut buf, &mut feed, &limits, &mut depth)
+                    {
                         feed.bozo = true;
                         feed.bozo_exception = Some(e.to_string());
                     }
@@ -106,18 +143,25 @@ pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
                             "XML nesting depth {} exceeds maximum {}",
                             depth, limits.max_nesting_depth
                         ));
-                        skip_element(&mut reader, &mut buf, &limits, depth)?;
+                        skip_element(&mut reader, &limits, depth)?;
+                        depth = depth.saturating_sub(1);
+                        buf.clear();
+                        continue;
+                    }
+
+                    if !window.admit() {
+                        skip_element(&mut reader, &limits, depth)?;
                         depth = depth.saturating_sub(1);
                         buf.clear();
                         continue;
                     }
 
                     // Extract rdf:about as item ID first (before releasing borrow on buf)
-                    let item_id = e.attributes().flatten().find_map(|attr| {
+                    let item_id = limited_attributes(&e, &limits).find_map(|attr| {
                         if attr.key.as_ref() == b"rdf:about"
                             || attr.key.local_name().as_ref() == b"about"
                         {
-                            attr.unescape_value().ok().map(|v| v.to_string())
+                            Some(decode_attribute(&attr.value))
                         } else {
                             None
                         }
@@ -128,14 +172,14 @@ pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
                         feed.bozo = true;
                         feed.bozo_exception =
                             Some(format!("Entry limit exceeded: {}", limits.max_entries));
-                        skip_element(&mut reader, &mut buf, &limits, depth)?;
+                        skip_element(&mut reader, &limits, depth)?;
                         depth = depth.saturating_sub(1);
                         buf.clear();
                         continue;
                     }
 
                     match parse_item(&mut reader, &mut buf, &limits, &mut depth, item_id) {
-                        Ok(entry) => feed.entries.push(entry),
+                        Ok(entry) => feed.push_entry(entry, &mut budget),
                         Err(err) => {
                             feed.bozo = true;
                             feed.bozo_exception = Some(err.to_string());
@@ -149,11 +193,11 @@ pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
                     depth = depth.saturating_sub(1);
                 } else if name.as_ref() == b"textinput" || name.as_ref() == b"textInput" {
                     // Skip textinput element (rarely used)
-                    skip_element(&mut reader, &mut buf, &limits, depth)?;
+                    skip_element(&mut reader, &limits, depth)?;
                     depth = depth.saturating_sub(1);
                 } else {
                     // Skip unknown elements at RDF level
-                    skip_element(&mut reader, &mut buf, &limits, depth)?;
+                    skip_element(&mut reader, &limits, depth)?;
                     depth = depth.saturating_sub(1);
                 }
             }
@@ -171,21 +215,36 @@ pub fn parse_rss10_with_limits(data: &[u8], limits: ParserLimits) -> Result<Pars
         buf.clear();
     }
 
+    resolve_image(&mut feed.feed, options);
+    feed.charge_feed_meta(&mut budget);
+
+    if options.collect_stats {
+        reader.counts().record(&mut feed);
+    }
+    record_span!(entries = feed.entries.len());
     Ok(feed)
 }
 
 /// Parse <channel> element (feed metadata)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.channel", level = "debug", skip_all)
+)]
 fn parse_channel(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
+    buf: &mut Vec<u8>,
     feed: &mut ParsedFeed,
     limits: &ParserLimits,
     depth: &mut usize,
 ) -> Result<()> {
-    let mut buf = Vec::with_capacity(EVENT_BUFFER_CAPACITY);
-
     loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e) | Event::Empty(e)) => {
+        match reader.read_event_into(buf) {
+            Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+
                 *depth += 1;
                 check_depth(*depth, limits.max_nesting_depth)?;
 
@@ -194,50 +253,48 @@ fn parse_channel(
 
                 match name.as_ref() {
                     b"title" => {
-                        feed.feed.title = Some(read_text(reader, &mut buf, limits)?);
+                        feed.feed.title = Some(read_text(reader, limits)?);
                     }
                     b"link" => {
-                        let link_text = read_text(reader, &mut buf, limits)?;
+                        let link_text = read_text(reader, limits)?;
                         feed.feed
                             .set_alternate_link(link_text, limits.max_links_per_feed);
                     }
                     b"description" => {
-                        feed.feed.subtitle = Some(read_text(reader, &mut buf, limits)?);
+                        feed.feed.subtitle = Some(read_text(reader, limits)?);
                     }
                     b"items" => {
                         // RSS 1.0 has an <items> element containing rdf:Seq with rdf:li references
                         // We skip this as items are parsed at the RDF root level
-                        skip_element(reader, &mut buf, limits, *depth)?;
+                        skip_element(reader, limits, *depth)?;
                     }
                     b"image" => {
                         // This is a reference, not the actual image - skip it
-                        skip_element(reader, &mut buf, limits, *depth)?;
+                        skip_element(reader, limits, *depth)?;
                     }
                     b"textinput" | b"textInput" => {
                         // This is a reference, not the actual textinput - skip it
-                        skip_element(reader, &mut buf, limits, *depth)?;
+                        skip_element(reader, limits, *depth)?;
+                    }
+                    _ if license::is_license_tag(full_name.as_ref()) => {
+                        parse_license_element(
+                            reader,
+                            e,
+                            &mut feed.feed.license_detail,
+                            &mut feed.feed.license,
+                            limits,
+                            is_empty,
+                        )?;
                     }
                     _ => {
                         // Check for Dublin Core and other namespace tags
-                        if let Some(dc_element) = is_dc_tag(full_name.as_ref()) {
-                            let dc_elem = dc_element.to_string();
-                            let text = read_text(reader, &mut buf, limits)?;
-                            dublin_core::handle_feed_element(&dc_elem, &text, &mut feed.feed);
-                        } else if let Some(syn_element) = is_syn_tag(full_name.as_ref()) {
-                            let syn_elem = syn_element.to_string();
-                            let text = read_text(reader, &mut buf, limits)?;
-                            syndication::handle_feed_element(&syn_elem, &text, &mut feed.feed);
-                        } else if let Some(georss_element) = is_georss_tag(full_name.as_ref()) {
-                            let georss_elem = georss_element.to_string();
-                            let text = read_text(reader, &mut buf, limits)?;
-                            georss::handle_feed_element(
-                                georss_elem.as_bytes(),
-                                &text,
-                                &mut feed.feed,
-                                limits,
-                            );
-                        } else {
-                            skip_element(reader, &mut buf, limits, *depth)?;
+                        if !parse_channel_namespace(
+                            reader,
+                            full_name.as_ref(),
+                            &mut feed.feed,
+                            limits,
+                        )? {
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                 }
@@ -257,8 +314,12 @@ fn parse_channel(
 }
 
 /// Parse <item> element (entry)
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "feedparser.item", level = "trace", skip_all)
+)]
 fn parse_item(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -269,7 +330,12 @@ fn parse_item(
 
     loop {
         match reader.read_event_into(buf) {
-            Ok(Event::Start(e) | Event::Empty(e)) => {
+            Ok(event @ (Event::Start(_) | Event::Empty(_))) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let (Event::Start(e) | Event::Empty(e)) = &event else {
+                    unreachable!()
+                };
+
                 *depth += 1;
                 check_depth(*depth, limits.max_nesting_depth)?;
 
@@ -278,44 +344,36 @@ fn parse_item(
 
                 match name.as_ref() {
                     b"title" => {
-                        entry.title = Some(read_text(reader, buf, limits)?);
+                        entry.title = Some(read_text(reader, limits)?);
                     }
                     b"link" => {
-                        let link_text = read_text(reader, buf, limits)?;
+                        let link_text = read_text(reader, limits)?;
                         entry.set_alternate_link(link_text, limits.max_links_per_entry);
                     }
                     b"description" => {
-                        let desc = read_text(reader, buf, limits)?;
+                        let desc = read_text(reader, limits)?;
                         entry.summary = Some(desc.clone());
                         entry.summary_detail = Some(TextConstruct {
+                            content_type: detect_text_type(&desc),
                             value: desc,
-                            content_type: TextType::Html,
                             language: None,
                             base: None,
                         });
                     }
+                    _ if license::is_license_tag(full_name.as_ref()) => {
+                        parse_license_element(
+                            reader,
+                            e,
+                            &mut entry.license_detail,
+                            &mut entry.license,
+                            limits,
+                            is_empty,
+                        )?;
+                    }
                     _ => {
                         // Check for Dublin Core and other namespace tags
-                        if let Some(dc_element) = is_dc_tag(full_name.as_ref()) {
-                            let dc_elem = dc_element.to_string();
-                            let text = read_text(reader, buf, limits)?;
-                            // dublin_core::handle_entry_element already handles dc:date -> published
-                            dublin_core::handle_entry_element(&dc_elem, &text, &mut entry);
-                        } else if let Some(content_element) = is_content_tag(full_name.as_ref()) {
-                            let content_elem = content_element.to_string();
-                            let text = read_text(reader, buf, limits)?;
-                            content::handle_entry_element(&content_elem, &text, &mut entry);
-                        } else if let Some(georss_element) = is_georss_tag(full_name.as_ref()) {
-                            let georss_elem = georss_element.to_string();
-                            let text = read_text(reader, buf, limits)?;
-                            georss::handle_entry_element(
-                                georss_elem.as_bytes(),
-                                &text,
-                                &mut entry,
-                                limits,
-                            );
-                        } else {
-                            skip_element(reader, buf, limits, *depth)?;
+                        if !parse_item_namespace(reader, full_name.as_ref(), &mut entry, limits)? {
+                            skip_element(reader, limits, *depth)?;
                         }
                     }
                 }
@@ -334,9 +392,110 @@ fn parse_item(
     Ok(entry)
 }
 
+/// Parse Dublin Core, Syndication and `GeoRSS` tags at channel level
+///
+/// Returns `Ok(true)` if the tag was recognized and its content consumed.
+fn parse_channel_namespace(
+    reader: &mut XmlReader<'_>,
+    tag: &[u8],
+    feed: &mut FeedMeta,
+    limits: &ParserLimits,
+) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
+    if let Some(dc_element) = is_dc_tag(tag) {
+        let dc_elem = dc_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        dublin_core::handle_feed_element(&dc_elem, &text, feed);
+        return Ok(true);
+    }
+    #[cfg(feature = "georss")]
+    if let Some(georss_element) = is_georss_tag(tag) {
+        let georss_elem = georss_element.to_string();
+        let text = read_text(reader, limits)?;
+        georss::handle_feed_element(georss_elem.as_bytes(), &text, feed, limits);
+        return Ok(true);
+    }
+
+    if let Some(syn_element) = is_syn_tag(tag) {
+        let syn_elem = syn_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        syndication::handle_feed_element(&syn_elem, &text, feed);
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Parse Dublin Core, Content, `GeoRSS`, PRISM and comment count tags at item level
+///
+/// Returns `Ok(true)` if the tag was recognized and its content consumed.
+fn parse_item_namespace(
+    reader: &mut XmlReader<'_>,
+    tag: &[u8],
+    entry: &mut Entry,
+    limits: &ParserLimits,
+) -> Result<bool> {
+    #[cfg(feature = "dublincore")]
+    if let Some(dc_element) = is_dc_tag(tag) {
+        let dc_elem = dc_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        // dublin_core::handle_entry_element already handles dc:date -> published
+        dublin_core::handle_entry_element(&dc_elem, &text, entry);
+        return Ok(true);
+    }
+    #[cfg(feature = "georss")]
+    if let Some(georss_element) = is_georss_tag(tag) {
+        let georss_elem = georss_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        georss::handle_entry_element(georss_elem.as_bytes(), &text, entry, limits);
+        return Ok(true);
+    }
+
+    if let Some(content_element) = is_content_tag(tag) {
+        let content_elem = content_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        content::handle_entry_element(&content_elem, &text, entry);
+        Ok(true)
+    } else if let Some(prism_element) = is_prism_tag(tag) {
+        let prism_elem = prism_element.to_string();
+        let text = read_text_cow(reader, limits)?;
+        prism::handle_entry_element(&prism_elem, &text, entry);
+        Ok(true)
+    } else if threading::is_count_tag(tag) {
+        let text = read_text_cow(reader, limits)?;
+        threading::handle_entry_element(tag, &text, entry);
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Resolves the image URLs against the parse base URL, or the channel link
+///
+/// `<image>` usually follows `<channel>`, so this runs once the whole
+/// document has been read.
+fn resolve_image(feed: &mut FeedMeta, options: &ParseOptions) {
+    let mut base_ctx = options.base_context();
+    if base_ctx.base().is_none()
+        && let Some(link) = &feed.link
+    {
+        base_ctx.update_base(link);
+    }
+    let Some(image) = feed.image.as_mut() else {
+        return;
+    };
+    let raw_url = std::mem::take(&mut image.url).into_inner();
+    image.url = base_ctx.resolve_safe(&raw_url).into();
+    image.link = image
+        .link
+        .as_deref()
+        .map(|link| base_ctx.resolve_safe(link));
+    feed.raw_image_url = Some(raw_url);
+}
+
 /// Parse <image> element
 fn parse_image(
-    reader: &mut Reader<&[u8]>,
+    reader: &mut XmlReader<'_>,
     buf: &mut Vec<u8>,
     limits: &ParserLimits,
     depth: &mut usize,
@@ -352,10 +511,10 @@ fn parse_image(
                 check_depth(*depth, limits.max_nesting_depth)?;
 
                 match e.local_name().as_ref() {
-                    b"url" => url = read_text(reader, buf, limits)?,
-                    b"title" => title = Some(read_text(reader, buf, limits)?),
-                    b"link" => link = Some(read_text(reader, buf, limits)?),
-                    _ => skip_element(reader, buf, limits, *depth)?,
+                    b"url" => url = read_text(reader, limits)?,
+                    b"title" => title = Some(read_text(reader, limits)?),
+                    b"link" => link = Some(read_text(reader, limits)?),
+                    _ => skip_element(reader, limits, *depth)?,
                 }
                 *depth = depth.saturating_sub(1);
             }
@@ -384,7 +543,6 @@ fn parse_image(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Datelike;
 
     #[test]
     fn test_parse_basic_rss10() {
@@ -441,8 +599,11 @@ mod tests {
         assert_eq!(feed.entries[1].title.as_deref(), Some("Item 2"));
     }
 
+    #[cfg(feature = "dublincore")]
     #[test]
     fn test_parse_rss10_with_dublin_core() {
+        use chrono::Datelike;
+
         let xml = br#"<?xml version="1.0"?>
         <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
                  xmlns="http://purl.org/rss/1.0/"
@@ -499,6 +660,31 @@ mod tests {
         assert_eq!(img.title.as_deref(), Some("Logo"));
     }
 
+    #[test]
+    fn test_parse_rss10_relative_image() {
+        let xml = br#"<?xml version="1.0"?>
+        <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                 xmlns="http://purl.org/rss/1.0/">
+            <channel rdf:about="http://example.com/news/">
+                <title>Test</title>
+                <link>http://example.com/news/</link>
+            </channel>
+            <image>
+                <url>/images/logo.png</url>
+                <link>index.html</link>
+            </image>
+        </rdf:RDF>"#;
+
+        let feed = parse_rss10(xml).unwrap();
+        let img = feed.feed.image.as_ref().unwrap();
+        assert_eq!(img.url, "http://example.com/images/logo.png");
+        assert_eq!(
+            img.link.as_deref(),
+            Some("http://example.com/news/index.html")
+        );
+        assert_eq!(feed.feed.raw_image_url.as_deref(), Some("/images/logo.png"));
+    }
+
     #[test]
     fn test_parse_rss10_without_rdf_prefix() {
         // Some RSS 1.0 feeds don't use the rdf: prefix
@@ -562,6 +748,7 @@ mod tests {
         assert_eq!(feed.feed.title.as_deref(), Some("Test"));
     }
 
+    #[cfg(feature = "dublincore")]
     #[test]
     fn test_is_dc_tag_valid() {
         assert_eq!(is_dc_tag(b"dc:creator"), Some("creator"));
@@ -571,6 +758,7 @@ mod tests {
         assert_eq!(is_dc_tag(b"dc:content-type"), Some("content-type"));
     }
 
+    #[cfg(feature = "dublincore")]
     #[test]
     fn test_is_dc_tag_rejects_malicious() {
         // Path traversal attempts
@@ -588,6 +776,7 @@ mod tests {
         assert!(is_dc_tag(b"dc:").is_none());
     }
 
+    #[cfg(feature = "dublincore")]
     #[test]
     fn test_is_dc_tag_non_dc() {
         assert!(is_dc_tag(b"title").is_none());
@@ -654,4 +843,38 @@ mod tests {
         assert_eq!(syn.update_frequency, Some(2));
         assert_eq!(syn.update_base.as_deref(), Some("2024-01-01T00:00:00Z"));
     }
+
+    #[test]
+    fn test_parse_rss10_with_prism() {
+        let xml = br#"<?xml version="1.0"?>
+        <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                 xmlns="http://purl.org/rss/1.0/"
+                 xmlns:prism="http://prismstandard.org/namespaces/basic/2.0/">
+            <channel rdf:about="http://example.com/">
+                <title>Journal TOC</title>
+                <link>http://example.com</link>
+                <description>Test</description>
+            </channel>
+            <item rdf:about="http://example.com/article/1">
+                <title>Article</title>
+                <link>http://example.com/article/1</link>
+                <prism:publicationName>Journal of Tests</prism:publicationName>
+                <prism:volume>12</prism:volume>
+                <prism:number>3</prism:number>
+                <prism:startingPage>101</prism:startingPage>
+                <prism:doi>10.1000/test.1</prism:doi>
+                <prism:publicationDate>2024-05-01</prism:publicationDate>
+            </item>
+        </rdf:RDF>"#;
+
+        let feed = parse_rss10(xml).unwrap();
+        assert!(!feed.bozo);
+        let prism = feed.entries[0].prism.as_ref().unwrap();
+        assert_eq!(prism.publication_name.as_deref(), Some("Journal of Tests"));
+        assert_eq!(prism.volume.as_deref(), Some("12"));
+        assert_eq!(prism.number.as_deref(), Some("3"));
+        assert_eq!(prism.starting_page.as_deref(), Some("101"));
+        assert_eq!(prism.doi.as_deref(), Some("10.1000/test.1"));
+        assert!(prism.publication_date.is_some());
+    }
 }
@@ -0,0 +1,171 @@
+//! Counting backing [`ParseStats`](crate::ParseStats)
+//!
+//! The XML parsers read through [`XmlReader`], which tallies elements as
+//! they go by, so statistics describe what the parser actually saw without
+//! a second pass over the document.
+
+use std::ops::{Deref, DerefMut};
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde_json::Value;
+
+use crate::ParseStats;
+use crate::types::ParsedFeed;
+
+/// Element and entry counts of a document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocumentCounts {
+    /// XML elements, or JSON values
+    pub elements: usize,
+    /// `<item>`/`<entry>` elements, or JSON Feed `items`
+    pub entries: usize,
+}
+
+impl DocumentCounts {
+    /// Records the counts on `feed` as a partial [`ParseStats`]
+    ///
+    /// Entries read but not kept count as skipped; the remaining fields are
+    /// filled in once parsing and post-processing are done.
+    pub fn record(self, feed: &mut ParsedFeed) {
+        feed.stats = Some(ParseStats {
+            elements_seen: self.elements,
+            entries_skipped: self.entries.saturating_sub(feed.entries.len()),
+            ..ParseStats::default()
+        });
+    }
+}
+
+/// Reader over an in-memory XML document that counts elements as it reads
+///
+/// Dereferences to the underlying [`Reader`]; only reading events goes
+/// through the counting methods.
+pub struct XmlReader<'a> {
+    inner: Reader<&'a [u8]>,
+    counts: DocumentCounts,
+}
+
+impl<'a> XmlReader<'a> {
+    /// Creates a reader over `data` with quick-xml's default configuration
+    pub fn from_reader(data: &'a [u8]) -> Self {
+        Self {
+            inner: Reader::from_reader(data),
+            counts: DocumentCounts::default(),
+        }
+    }
+
+    /// Reads the next event into `buf`, counting it
+    #[inline]
+    pub fn read_event_into<'b>(&mut self, buf: &'b mut Vec<u8>) -> quick_xml::Result<Event<'b>> {
+        let event = self.inner.read_event_into(buf);
+        if let Ok(event) = &event {
+            self.count(event);
+        }
+        event
+    }
+
+    /// Reads the next event borrowed from the input, counting it
+    #[inline]
+    pub fn read_event(&mut self) -> quick_xml::Result<Event<'a>> {
+        let event = self.inner.read_event();
+        if let Ok(event) = &event {
+            self.count(event);
+        }
+        event
+    }
+
+    /// Elements and entries read so far
+    pub const fn counts(&self) -> DocumentCounts {
+        self.counts
+    }
+
+    fn count(&mut self, event: &Event<'_>) {
+        if let Event::Start(e) | Event::Empty(e) = event {
+            self.counts.elements += 1;
+            if matches!(e.local_name().as_ref(), b"item" | b"entry") {
+                self.counts.entries += 1;
+            }
+        }
+    }
+}
+
+impl<'a> Deref for XmlReader<'a> {
+    type Target = Reader<&'a [u8]>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for XmlReader<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Counts the values and items of a parsed JSON Feed document
+pub fn count_json(json: &Value) -> DocumentCounts {
+    DocumentCounts {
+        elements: count_values(json),
+        entries: json
+            .get("items")
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len),
+    }
+}
+
+fn count_values(value: &Value) -> usize {
+    1 + match value {
+        Value::Array(items) => items.iter().map(count_values).sum(),
+        Value::Object(map) => map.values().map(count_values).sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_xml(data: &[u8]) -> DocumentCounts {
+        let mut reader = XmlReader::from_reader(data);
+        let mut buf = Vec::new();
+        while !matches!(reader.read_event_into(&mut buf), Ok(Event::Eof) | Err(_)) {
+            buf.clear();
+        }
+        reader.counts()
+    }
+
+    #[test]
+    fn test_count_xml() {
+        let xml = br#"<?xml version="1.0"?>
+            <rss version="2.0"><channel>
+                <title>T</title>
+                <item><title>A</title><enclosure url="a.mp3"/></item>
+                <item><description><![CDATA[<p>not an element</p>]]></description></item>
+            </channel></rss>"#;
+
+        let counts = count_xml(xml);
+        assert_eq!(counts.elements, 8);
+        assert_eq!(counts.entries, 2);
+    }
+
+    #[test]
+    fn test_count_json() {
+        let json: Value = serde_json::from_slice(
+            br#"{"version": "https://jsonfeed.org/version/1.1",
+            "items": [{"id": "1"}, {"id": "2", "tags": ["a"]}]}"#,
+        )
+        .unwrap();
+
+        let counts = count_json(&json);
+        // root, version, items, 2 items, 2 ids, tags, "a"
+        assert_eq!(counts.elements, 9);
+        assert_eq!(counts.entries, 2);
+    }
+
+    #[test]
+    fn test_count_stops_at_syntax_error() {
+        let counts = count_xml(b"<rss><channel><item></channel>");
+        assert_eq!(counts.entries, 1);
+    }
+}
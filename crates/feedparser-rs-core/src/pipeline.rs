@@ -0,0 +1,573 @@
+//! Batch aggregation: fetch, parse, clean up and merge many feeds
+//!
+//! A [`Pipeline`] takes a batch of [`FeedInput`]s (URLs, raw bytes or
+//! already parsed feeds), parses them in parallel, runs every feed through
+//! its [`Stage`]s in order, and merges the survivors into one timeline with
+//! [`merge_feeds`]. Alongside the timeline it returns a [`FeedDiagnostics`]
+//! per input, so one broken feed shows up as a diagnostic instead of failing
+//! the batch.
+//!
+//! [`Pipeline::standard`] runs the usual fetch → parse → [`Sanitize`] →
+//! [`Normalize`] → [`Dedup`] → merge chain; [`Pipeline::new`] starts empty
+//! for custom chains. Closures with the signature of [`Stage::apply`] are
+//! stages too.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::pipeline::{FeedInput, Pipeline};
+//!
+//! let pipeline = Pipeline::standard()
+//!     .stage(|feed: &mut feedparser_rs::ParsedFeed, _: &mut Vec<String>| {
+//!         feed.retain_entries(|entry| entry.title.is_some());
+//!         Ok(())
+//!     });
+//!
+//! let output = pipeline.run(vec![
+//!     FeedInput::bytes("a", r#"<rss version="2.0"><channel><title>A</title>
+//!         <item><guid>1</guid><title>One</title></item>
+//!     </channel></rss>"#),
+//!     FeedInput::bytes("b", "<rss version='2.0'><channel><item><title>x</item></channel></rss>"),
+//! ]);
+//! assert_eq!(output.timeline.entries.len(), 1);
+//! assert!(output.diagnostics[0].error.is_none());
+//! assert!(output.diagnostics[1].bozo_exception.is_some());
+//! ```
+
+use crate::error::{FeedError, Result};
+#[cfg(feature = "http")]
+use crate::http::FeedHttpClient;
+use crate::merge::{MergeOptions, merge_feeds};
+use crate::options::ParseOptions;
+use crate::types::ParsedFeed;
+use crate::util::sanitize::{SanitizePolicy, sanitize_feed};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One feed to feed into a [`Pipeline`]
+#[derive(Debug, Clone)]
+pub enum FeedInput {
+    /// Fetch the feed from a URL (requires the `http` feature)
+    #[cfg(feature = "http")]
+    Url(String),
+    /// Parse raw feed bytes; `label` names the feed in diagnostics
+    Bytes {
+        /// Name of the feed in diagnostics
+        label: String,
+        /// Raw feed document
+        data: Vec<u8>,
+    },
+    /// Skip fetching and parsing; only the stages and merge run
+    Parsed {
+        /// Name of the feed in diagnostics
+        label: String,
+        /// The parsed feed
+        feed: Box<ParsedFeed>,
+    },
+}
+
+impl FeedInput {
+    /// Feed fetched from `url`
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url(url.into())
+    }
+
+    /// Feed parsed from `data`
+    #[must_use]
+    pub fn bytes(label: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes {
+            label: label.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Feed that was already parsed
+    #[must_use]
+    pub fn parsed(label: impl Into<String>, feed: ParsedFeed) -> Self {
+        Self::Parsed {
+            label: label.into(),
+            feed: Box::new(feed),
+        }
+    }
+
+    /// Name of the input in diagnostics: the URL or the label
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            #[cfg(feature = "http")]
+            Self::Url(url) => url,
+            Self::Bytes { label, .. } | Self::Parsed { label, .. } => label,
+        }
+    }
+}
+
+/// A processing step applied to each parsed feed
+///
+/// Stages run in the order they were added, on the worker thread that
+/// parsed the feed. A stage that fails drops its feed from the timeline and
+/// records the error in the feed's [`FeedDiagnostics`].
+pub trait Stage: Send + Sync {
+    /// Name used in diagnostics
+    // Not `&'static str`, so stages can return a configured name
+    #[allow(clippy::unnecessary_literal_bound)]
+    fn name(&self) -> &str {
+        "custom"
+    }
+
+    /// Processes one feed in place
+    ///
+    /// `notes` collects human-readable remarks for the feed's diagnostics,
+    /// such as how many entries a stage removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error to drop the feed from the timeline.
+    fn apply(&self, feed: &mut ParsedFeed, notes: &mut Vec<String>) -> Result<()>;
+}
+
+impl<F> Stage for F
+where
+    F: Fn(&mut ParsedFeed, &mut Vec<String>) -> Result<()> + Send + Sync,
+{
+    fn apply(&self, feed: &mut ParsedFeed, notes: &mut Vec<String>) -> Result<()> {
+        self(feed, notes)
+    }
+}
+
+/// Stage that sanitizes HTML in titles, summaries and content
+///
+/// Equivalent to parsing with `sanitize_html`, for inputs that were parsed
+/// without it or need a different [`SanitizePolicy`].
+#[derive(Debug, Clone, Default)]
+pub struct Sanitize {
+    /// What the sanitizer keeps
+    pub policy: SanitizePolicy,
+}
+
+impl Stage for Sanitize {
+    fn name(&self) -> &'static str {
+        "sanitize"
+    }
+
+    fn apply(&self, feed: &mut ParsedFeed, notes: &mut Vec<String>) -> Result<()> {
+        let changed = sanitize_feed(feed, &self.policy).len();
+        if changed > 0 {
+            notes.push(format!("sanitized {changed} fields"));
+        }
+        Ok(())
+    }
+}
+
+/// Stage that strips tracking parameters and fills in missing entry ids
+#[derive(Debug, Clone)]
+pub struct Normalize {
+    /// Remove `utm_*` and other click-tracking parameters from links, see
+    /// [`ParsedFeed::strip_tracking_params`]
+    ///
+    /// Default: `true`
+    pub strip_tracking_params: bool,
+    /// Further query parameters to remove along with the built-in ones
+    ///
+    /// Default: empty
+    pub extra_tracking_params: Vec<String>,
+    /// Give entries without an id a synthetic one, see
+    /// [`ParsedFeed::synthesize_entry_ids`]
+    ///
+    /// Default: `true`
+    pub synthesize_ids: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self {
+            strip_tracking_params: true,
+            extra_tracking_params: Vec::new(),
+            synthesize_ids: true,
+        }
+    }
+}
+
+impl Stage for Normalize {
+    fn name(&self) -> &'static str {
+        "normalize"
+    }
+
+    fn apply(&self, feed: &mut ParsedFeed, notes: &mut Vec<String>) -> Result<()> {
+        if self.strip_tracking_params {
+            let stripped = feed.strip_tracking_params(&self.extra_tracking_params);
+            if stripped > 0 {
+                notes.push(format!(
+                    "stripped tracking parameters from {stripped} links"
+                ));
+            }
+        }
+        if self.synthesize_ids {
+            let synthesized = feed.synthesize_entry_ids();
+            if synthesized > 0 {
+                notes.push(format!("synthesized {synthesized} entry ids"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Stage that removes duplicate entries within a feed
+///
+/// Duplicates across feeds are removed by the merge when
+/// [`MergeOptions::dedup`] is on.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dedup;
+
+impl Stage for Dedup {
+    fn name(&self) -> &'static str {
+        "dedup"
+    }
+
+    fn apply(&self, feed: &mut ParsedFeed, notes: &mut Vec<String>) -> Result<()> {
+        let removed = feed.dedup_entries();
+        if removed > 0 {
+            notes.push(format!("removed {removed} duplicate entries"));
+        }
+        Ok(())
+    }
+}
+
+/// What happened to one input of a [`Pipeline::run`]
+#[derive(Debug, Clone)]
+pub struct FeedDiagnostics {
+    /// URL or label of the input
+    pub source: String,
+    /// HTTP status of the fetch, for URL inputs
+    pub status: Option<u16>,
+    /// Entries the feed contributed to the merge, before cross-feed dedup
+    pub entries: usize,
+    /// Why the feed was malformed, if it was
+    pub bozo_exception: Option<String>,
+    /// Remarks from the stages, prefixed with the stage name
+    pub notes: Vec<String>,
+    /// Fetch, parse or stage error that kept the feed out of the timeline
+    pub error: Option<FeedError>,
+    /// Time spent fetching, parsing and running stages
+    pub elapsed: Duration,
+}
+
+/// Result of [`Pipeline::run`]
+#[derive(Debug, Clone)]
+pub struct PipelineOutput {
+    /// All entries of the successful feeds, merged newest first
+    pub timeline: ParsedFeed,
+    /// One entry per input, in input order
+    pub diagnostics: Vec<FeedDiagnostics>,
+}
+
+/// Composable fetch → parse → stages → merge chain
+///
+/// See the [module documentation](self) for an overview.
+pub struct Pipeline {
+    options: ParseOptions,
+    stages: Vec<Box<dyn Stage>>,
+    merge: MergeOptions,
+    workers: usize,
+    #[cfg(feature = "http")]
+    client: Option<FeedHttpClient>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pipeline {
+    /// Pipeline without stages, parsing with [`ParseOptions::default`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: ParseOptions::default(),
+            stages: Vec::new(),
+            merge: MergeOptions::default(),
+            workers: 4,
+            #[cfg(feature = "http")]
+            client: None,
+        }
+    }
+
+    /// Pipeline running [`Sanitize`], [`Normalize`] and [`Dedup`]
+    ///
+    /// Parsing skips sanitization since the [`Sanitize`] stage does it.
+    #[must_use]
+    pub fn standard() -> Self {
+        Self::new()
+            .with_options(ParseOptions {
+                sanitize_html: false,
+                ..ParseOptions::default()
+            })
+            .stage(Sanitize::default())
+            .stage(Normalize::default())
+            .stage(Dedup)
+    }
+
+    /// Sets the options inputs are parsed with
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets how the feeds are merged into the timeline
+    #[must_use]
+    pub fn with_merge_options(mut self, merge: MergeOptions) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Sets how many inputs are processed in parallel
+    ///
+    /// Default: `4`
+    #[must_use]
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Sets the client URL inputs are fetched with
+    ///
+    /// Default: [`FeedHttpClient::shared`]
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn with_client(mut self, client: FeedHttpClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Appends a stage
+    #[must_use]
+    pub fn stage(mut self, stage: impl Stage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// Processes a batch of inputs and merges the results
+    ///
+    /// Never fails as a whole: inputs that cannot be fetched, parsed or
+    /// processed are left out of the timeline and explained in their
+    /// diagnostics.
+    #[must_use]
+    pub fn run(&self, inputs: Vec<FeedInput>) -> PipelineOutput {
+        let count = inputs.len();
+        let queue = Mutex::new(inputs.into_iter().enumerate());
+        let results = Mutex::new(Vec::with_capacity(count));
+
+        thread::scope(|scope| {
+            for _ in 0..self.workers.min(count) {
+                scope.spawn(|| {
+                    loop {
+                        let next = queue
+                            .lock()
+                            .unwrap_or_else(std::sync::PoisonError::into_inner)
+                            .next();
+                        let Some((index, input)) = next else {
+                            return;
+                        };
+                        let result = self.process(input);
+                        results
+                            .lock()
+                            .unwrap_or_else(std::sync::PoisonError::into_inner)
+                            .push((index, result));
+                    }
+                });
+            }
+        });
+
+        let mut results = results
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        results.sort_by_key(|&(index, _)| index);
+        let (feeds, diagnostics): (Vec<_>, Vec<_>) =
+            results.into_iter().map(|(_, result)| result).unzip();
+        let feeds: Vec<ParsedFeed> = feeds.into_iter().flatten().collect();
+
+        PipelineOutput {
+            timeline: merge_feeds(&feeds, &self.merge),
+            diagnostics,
+        }
+    }
+
+    /// Fetches, parses and runs the stages on one input
+    fn process(&self, input: FeedInput) -> (Option<ParsedFeed>, FeedDiagnostics) {
+        let started = Instant::now();
+        let mut diagnostics = FeedDiagnostics {
+            source: input.label().to_string(),
+            status: None,
+            entries: 0,
+            bozo_exception: None,
+            notes: Vec::new(),
+            error: None,
+            elapsed: Duration::ZERO,
+        };
+        let result = self.load(input).and_then(|mut feed| {
+            diagnostics.status = feed.status;
+            diagnostics.bozo_exception.clone_from(&feed.bozo_exception);
+            for stage in &self.stages {
+                let mut notes = Vec::new();
+                let applied = stage.apply(&mut feed, &mut notes);
+                diagnostics.notes.extend(
+                    notes
+                        .into_iter()
+                        .map(|note| format!("{}: {note}", stage.name())),
+                );
+                applied.map_err(|err| {
+                    FeedError::Unknown(format!("stage {} failed: {err}", stage.name()))
+                })?;
+            }
+            Ok(feed)
+        });
+        diagnostics.elapsed = started.elapsed();
+
+        match result {
+            Ok(feed) => {
+                diagnostics.entries = feed.entries.len();
+                (Some(feed), diagnostics)
+            }
+            Err(err) => {
+                diagnostics.error = Some(err);
+                (None, diagnostics)
+            }
+        }
+    }
+
+    /// Fetches and parses an input
+    fn load(&self, input: FeedInput) -> Result<ParsedFeed> {
+        match input {
+            #[cfg(feature = "http")]
+            FeedInput::Url(url) => {
+                let client = match &self.client {
+                    Some(client) => client,
+                    None => FeedHttpClient::shared()?,
+                };
+                client.fetch_feed_with_options(&url, None, None, &self.options)
+            }
+            FeedInput::Bytes { data, .. } => crate::parse_with_options(&data, &self.options),
+            FeedInput::Parsed { feed, .. } => Ok(*feed),
+        }
+    }
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("options", &self.options)
+            .field(
+                "stages",
+                &self
+                    .stages
+                    .iter()
+                    .map(|stage| stage.name())
+                    .collect::<Vec<_>>(),
+            )
+            .field("merge", &self.merge)
+            .field("workers", &self.workers)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rss(title: &str, items: &str) -> FeedInput {
+        FeedInput::bytes(
+            title,
+            format!(
+                r#"<rss version="2.0"><channel><title>{title}</title>
+                <link>https://{title}.example/</link>{items}</channel></rss>"#
+            ),
+        )
+    }
+
+    #[test]
+    fn test_standard_pipeline_merges_and_cleans() {
+        let output = Pipeline::standard().run(vec![
+            rss(
+                "a",
+                r#"<item><guid>1</guid><title>&lt;b onclick="x()"&gt;One&lt;/b&gt;</title>
+                   <description>&lt;script&gt;bad()&lt;/script&gt;Hi</description>
+                   <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+                   <item><guid>1</guid><title>One again</title></item>"#,
+            ),
+            rss(
+                "b",
+                r"<item><link>https://b.example/post?utm_source=rss</link>
+                   <pubDate>Tue, 02 Jan 2024 00:00:00 GMT</pubDate></item>",
+            ),
+        ]);
+
+        let timeline = &output.timeline;
+        assert_eq!(timeline.entries.len(), 2);
+        assert_eq!(
+            timeline.entries[0].link.as_deref(),
+            Some("https://b.example/post")
+        );
+        assert!(timeline.entries[0].id_synthetic);
+        assert!(
+            !timeline.entries[1]
+                .summary
+                .as_deref()
+                .unwrap()
+                .contains("script")
+        );
+
+        let a = &output.diagnostics[0];
+        assert_eq!(a.source, "a");
+        assert_eq!(a.entries, 1);
+        assert!(a.notes.iter().any(|note| note.starts_with("sanitize: ")));
+        assert!(
+            a.notes
+                .contains(&"dedup: removed 1 duplicate entries".to_string())
+        );
+        let b = &output.diagnostics[1];
+        assert!(
+            b.notes
+                .iter()
+                .any(|note| note.starts_with("normalize: stripped"))
+        );
+    }
+
+    #[test]
+    fn test_failing_stage_drops_feed() {
+        let output = Pipeline::new()
+            .stage(|feed: &mut ParsedFeed, _: &mut Vec<String>| {
+                if feed.feed.title.as_deref() == Some("bad") {
+                    return Err(FeedError::InvalidFormat("rejected".into()));
+                }
+                Ok(())
+            })
+            .with_workers(1)
+            .run(vec![
+                rss("good", "<item><guid>1</guid></item>"),
+                rss("bad", "<item><guid>2</guid></item>"),
+            ]);
+        assert_eq!(output.timeline.entries.len(), 1);
+        assert!(output.diagnostics[0].error.is_none());
+        let error = output.diagnostics[1].error.as_ref().unwrap().to_string();
+        assert!(error.contains("stage custom failed"), "{error}");
+    }
+
+    #[test]
+    fn test_parsed_input_and_empty_batch() {
+        let feed = crate::parse(
+            b"<rss version='2.0'><channel><item><guid>1</guid></item></channel></rss>",
+        )
+        .unwrap();
+        let output = Pipeline::new().run(vec![FeedInput::parsed("cached", feed)]);
+        assert_eq!(output.timeline.entries.len(), 1);
+        assert_eq!(output.diagnostics[0].source, "cached");
+
+        let empty = Pipeline::standard().run(Vec::new());
+        assert!(empty.timeline.entries.is_empty());
+        assert!(empty.diagnostics.is_empty());
+    }
+}
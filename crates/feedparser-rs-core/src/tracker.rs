@@ -0,0 +1,175 @@
+//! Delta detection across consecutive fetches
+//!
+//! [`FeedTracker`] remembers the [`Entry::fingerprint`] of every entry it has
+//! seen together with a hash of the entry's content. Given a fresh parse it
+//! returns only the entries that are new or whose content changed since the
+//! last time they were seen.
+//!
+//! With the `serde` feature the tracker is serializable, so its state can be
+//! persisted between runs of a poller.
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{FeedTracker, parse};
+//!
+//! let mut tracker = FeedTracker::new();
+//! let first = parse(br#"<rss version="2.0"><channel>
+//!     <item><guid>1</guid><title>One</title></item>
+//! </channel></rss>"#).unwrap();
+//! assert_eq!(tracker.update(&first).len(), 1);
+//!
+//! let second = parse(br#"<rss version="2.0"><channel>
+//!     <item><guid>2</guid><title>Two</title></item>
+//!     <item><guid>1</guid><title>One</title></item>
+//! </channel></rss>"#).unwrap();
+//! let fresh = tracker.update(&second);
+//! assert_eq!(fresh.len(), 1);
+//! assert_eq!(fresh[0].id.as_deref(), Some("2"));
+//! ```
+
+use crate::{
+    types::{Entry, ParsedFeed},
+    util::Fnv1a,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Tracks previously seen entries to report only new or updated ones
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeedTracker {
+    /// Entry fingerprint -> content hash at the time it was last seen
+    seen: HashMap<u64, u64>,
+}
+
+impl FeedTracker {
+    /// Creates an empty tracker
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns entries of `feed` that are new or changed, and records them
+    ///
+    /// Entries are returned in feed order. An entry counts as changed when
+    /// its title, summary, content, links, enclosures or `updated` date
+    /// differ from the last time it was seen.
+    pub fn update<'a>(&mut self, feed: &'a ParsedFeed) -> Vec<&'a Entry> {
+        feed.entries
+            .iter()
+            .filter(|entry| {
+                let hash = content_hash(entry);
+                self.seen.insert(entry.fingerprint(), hash) != Some(hash)
+            })
+            .collect()
+    }
+
+    /// Returns true if the entry has been seen with its current content
+    #[must_use]
+    pub fn is_seen(&self, entry: &Entry) -> bool {
+        self.seen.get(&entry.fingerprint()) == Some(&content_hash(entry))
+    }
+
+    /// Forget entries that are no longer present in `feed`
+    ///
+    /// Call this after [`update`](Self::update) to keep the tracker from
+    /// growing without bound on long-running feeds. An entry that later
+    /// reappears will be reported as new again.
+    pub fn retain_current(&mut self, feed: &ParsedFeed) {
+        let current: HashSet<u64> = feed.entries.iter().map(Entry::fingerprint).collect();
+        self.seen
+            .retain(|fingerprint, _| current.contains(fingerprint));
+    }
+
+    /// Number of tracked entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns true if no entries are tracked
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Forget all tracked entries
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
+
+/// Hash of the fields whose change makes an entry "updated"
+fn content_hash(entry: &Entry) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write_normalized(b't', entry.title.as_deref().unwrap_or_default());
+    hasher.write_normalized(b's', entry.summary.as_deref().unwrap_or_default());
+    for content in &entry.content {
+        hasher.write_normalized(b'c', &content.value);
+    }
+    for link in &entry.links {
+        hasher.write_normalized(b'l', &link.href);
+    }
+    for enclosure in &entry.enclosures {
+        hasher.write_normalized(b'e', &enclosure.url);
+    }
+    let updated = entry.updated.map_or(i64::MIN, |d| d.timestamp());
+    hasher.write(&updated.to_le_bytes());
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn rss(items: &str) -> ParsedFeed {
+        parse(
+            format!(r#"<rss version="2.0"><channel><title>T</title>{items}</channel></rss>"#)
+                .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reports_new_entries_once() {
+        let mut tracker = FeedTracker::new();
+        let feed = rss("<item><guid>1</guid><title>A</title></item>");
+        assert_eq!(tracker.update(&feed).len(), 1);
+        assert!(tracker.update(&feed).is_empty());
+        assert!(tracker.is_seen(&feed.entries[0]));
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn test_reports_updated_entries() {
+        let mut tracker = FeedTracker::new();
+        tracker.update(&rss("<item><guid>1</guid><title>A</title></item>"));
+        let edited = rss("<item><guid>1</guid><title>A (edited)</title></item>");
+        assert_eq!(tracker.update(&edited).len(), 1);
+        assert!(tracker.update(&edited).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_whitespace_changes() {
+        let mut tracker = FeedTracker::new();
+        tracker.update(&rss("<item><guid>1</guid><title>A  B</title></item>"));
+        let reformatted = rss("<item><guid> 1 </guid><title>A B</title></item>");
+        assert!(tracker.update(&reformatted).is_empty());
+    }
+
+    #[test]
+    fn test_retain_current() {
+        let mut tracker = FeedTracker::new();
+        tracker.update(&rss(
+            "<item><guid>1</guid></item><item><guid>2</guid></item>",
+        ));
+        let feed = rss("<item><guid>2</guid></item>");
+        tracker.retain_current(&feed);
+        assert_eq!(tracker.len(), 1);
+        assert!(tracker.is_seen(&feed.entries[0]));
+
+        tracker.clear();
+        assert!(tracker.is_empty());
+    }
+}
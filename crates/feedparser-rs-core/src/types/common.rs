@@ -1,9 +1,14 @@
 use super::generics::{FromAttributes, ParseFrom};
-use crate::util::text::bytes_to_string;
+use crate::namespace::threading::parse_count;
+use crate::util::entities::decode_attribute;
+use crate::util::mime::{self, MediaKind};
+use crate::util::text::normalize_text;
 use compact_str::CompactString;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Optimized string type for small strings (≤24 bytes stored inline)
 ///
@@ -425,7 +430,8 @@ impl PartialEq<String> for Email {
 }
 
 /// Link in feed or entry
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Link {
     /// Link URL
     pub href: Url,
@@ -440,6 +446,9 @@ pub struct Link {
     pub length: Option<u64>,
     /// Language of the linked resource (stored inline for lang codes ≤24 bytes)
     pub hreflang: Option<SmallString>,
+    /// Number of replies behind a `rel="replies"` link, from the RFC 4685
+    /// `thr:count` attribute
+    pub thr_count: Option<u32>,
 }
 
 impl Link {
@@ -453,6 +462,7 @@ impl Link {
             title: None,
             length: None,
             hreflang: None,
+            thr_count: None,
         }
     }
 
@@ -472,6 +482,7 @@ impl Link {
             title: None,
             length: None,
             hreflang: None,
+            thr_count: None,
         }
     }
 
@@ -485,6 +496,7 @@ impl Link {
             title: None,
             length: None,
             hreflang: None,
+            thr_count: None,
         }
     }
 
@@ -504,7 +516,8 @@ impl Link {
 }
 
 /// Person (author, contributor, etc.)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Person {
     /// Person's name (stored inline for names ≤24 bytes)
     pub name: Option<SmallString>,
@@ -535,10 +548,160 @@ impl Person {
             uri: None,
         }
     }
+
+    /// Create person from an RSS `author` or `managingEditor` value
+    ///
+    /// RSS puts an email address in these elements, usually followed by
+    /// the name in parentheses. `Name <email>` is accepted too, and text
+    /// without an address is taken as a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::types::Person;
+    ///
+    /// let person = Person::from_rss("jane@example.com (Jane Doe)");
+    /// assert_eq!(person.name.as_deref(), Some("Jane Doe"));
+    /// assert_eq!(person.email.as_deref(), Some("jane@example.com"));
+    ///
+    /// let person = Person::from_rss("Jane Doe <jane@example.com>");
+    /// assert_eq!(person.name.as_deref(), Some("Jane Doe"));
+    /// assert_eq!(person.email.as_deref(), Some("jane@example.com"));
+    ///
+    /// assert_eq!(Person::from_rss("Jane Doe"), Person::from_name("Jane Doe"));
+    /// ```
+    #[must_use]
+    pub fn from_rss(value: &str) -> Self {
+        let value = value.trim();
+        let (email, name) = if let Some((email, rest)) = value.split_once('(')
+            && let Some(name) = rest.strip_suffix(')')
+        {
+            (email.trim(), name.trim())
+        } else if let Some((name, rest)) = value.split_once('<')
+            && let Some(email) = rest.strip_suffix('>')
+        {
+            (email.trim(), name.trim())
+        } else if value.contains('@') && !value.contains(char::is_whitespace) {
+            (value, "")
+        } else {
+            ("", value)
+        };
+        Self {
+            name: (!name.is_empty()).then(|| name.into()),
+            email: (email.contains('@')).then(|| email.into()),
+            uri: None,
+        }
+    }
+
+    /// Whether `other` names the same person, comparing names without
+    /// regard to case, then email addresses
+    fn is_same(&self, other: &Self) -> bool {
+        match (&self.name, &other.name) {
+            (Some(a), Some(b)) => a.to_lowercase() == b.to_lowercase(),
+            _ => self.email.is_some() && self.email == other.email,
+        }
+    }
+}
+
+/// Where the `author` of a feed or entry can come from
+///
+/// Used by [`ParseOptions::author_precedence`](crate::ParseOptions::author_precedence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthorSource {
+    /// The format's own author element: Atom `<author>`, RSS `<author>` on
+    /// items, or JSON Feed `authors`
+    Author,
+    /// The channel's RSS `managingEditor`
+    ManagingEditor,
+    /// `dc:creator`
+    DcCreator,
+    /// `itunes:author`
+    ItunesAuthor,
+}
+
+impl AuthorSource {
+    /// Order used unless configured otherwise
+    pub const DEFAULT_ORDER: [Self; 4] = [
+        Self::Author,
+        Self::ManagingEditor,
+        Self::DcCreator,
+        Self::ItunesAuthor,
+    ];
+}
+
+/// Fills `author` and `author_detail` from the first of `candidates`, and
+/// adds every candidate missing from `authors`
+///
+/// `candidates` holds the `author` text and person of each source, in order
+/// of precedence. `author` ends up unset if there are none.
+pub fn resolve_author(
+    author: &mut Option<SmallString>,
+    author_detail: &mut Option<Person>,
+    authors: &mut Vec<Person>,
+    candidates: Vec<(Option<SmallString>, Person)>,
+) {
+    let mut winner = None;
+    for (text, person) in candidates {
+        if !authors.iter().any(|known| known.is_same(&person)) {
+            authors.push(person.clone());
+        }
+        winner.get_or_insert((text, person));
+    }
+    let (text, person) = winner.unzip();
+    *author = text.flatten();
+    *author_detail = person;
+}
+
+/// Normalizes the title, tag and author name fields shared by feeds and
+/// entries, see [`normalize_text`]
+pub fn normalize_text_fields(
+    title: &mut Option<String>,
+    title_detail: &mut Option<TextConstruct>,
+    author: &mut Option<SmallString>,
+    author_detail: &mut Option<Person>,
+    authors: &mut [Person],
+    contributors: &mut [Person],
+    tags: &mut [Tag],
+) {
+    title.iter_mut().for_each(normalize_in_place);
+    if let Some(detail) = title_detail {
+        normalize_in_place(&mut detail.value);
+    }
+    author.iter_mut().for_each(normalize_in_place);
+    let people = author_detail.iter_mut().chain(authors).chain(contributors);
+    for person in people {
+        person.name.iter_mut().for_each(normalize_in_place);
+    }
+    for tag in tags {
+        normalize_in_place(&mut tag.term);
+        tag.label.iter_mut().for_each(normalize_in_place);
+    }
+}
+
+/// Replaces `value` with its [`normalize_text`] form if that differs
+fn normalize_in_place<T: AsRef<str> + From<String>>(value: &mut T) {
+    if let Cow::Owned(normalized) = normalize_text(value.as_ref()) {
+        *value = normalized.into();
+    }
+}
+
+/// License of a feed or entry, merged from every element that declares one
+///
+/// See [`namespace::license`](crate::namespace::license) for the sources.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LicenseInfo {
+    /// License URL, e.g. `https://creativecommons.org/licenses/by/4.0/`
+    pub url: Option<String>,
+    /// License name or identifier as written in the feed
+    pub name: Option<String>,
+    /// SPDX identifier guessed from the URL or name, e.g. `CC-BY-4.0`
+    pub spdx_guess: Option<String>,
 }
 
 /// Tag/category
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag {
     /// Tag term/label (stored inline for terms ≤24 bytes)
     pub term: SmallString,
@@ -561,7 +724,8 @@ impl Tag {
 }
 
 /// Image metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     /// Image URL
     pub url: Url,
@@ -578,7 +742,8 @@ pub struct Image {
 }
 
 /// Enclosure (attached media file)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enclosure {
     /// Enclosure URL
     pub url: Url,
@@ -588,8 +753,41 @@ pub struct Enclosure {
     pub enclosure_type: Option<MimeType>,
 }
 
+impl Enclosure {
+    /// Normalized MIME type of the enclosure
+    ///
+    /// Maps non-standard types such as `audio/mp3` to their registered form
+    /// and infers the type from the URL extension when it is missing or
+    /// `application/octet-stream`. See [`crate::util::mime`].
+    #[must_use]
+    pub fn normalized_type(&self) -> Option<String> {
+        mime::resolve_mime_type(self.enclosure_type.as_deref(), &self.url)
+    }
+
+    /// Coarse kind of the enclosure (audio, video, image, document)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{Enclosure, MediaKind};
+    ///
+    /// let enclosure = Enclosure {
+    ///     url: "https://example.com/episode.mp3".into(),
+    ///     length: None,
+    ///     enclosure_type: None,
+    /// };
+    /// assert_eq!(enclosure.media_kind(), MediaKind::Audio);
+    /// ```
+    #[must_use]
+    pub fn media_kind(&self) -> MediaKind {
+        self.normalized_type()
+            .map_or(MediaKind::Other, |mime| MediaKind::from_mime(&mime))
+    }
+}
+
 /// Content block
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Content {
     /// Content body
     pub value: String,
@@ -626,7 +824,9 @@ impl Content {
 }
 
 /// Text construct type (Atom-style)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum TextType {
     /// Plain text
     Text,
@@ -637,7 +837,8 @@ pub enum TextType {
 }
 
 /// Text construct with metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextConstruct {
     /// Text content
     pub value: String,
@@ -682,7 +883,8 @@ impl TextConstruct {
 }
 
 /// Generator metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Generator {
     /// Generator name
     pub value: String,
@@ -693,7 +895,8 @@ pub struct Generator {
 }
 
 /// Source reference (for entries)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Source {
     /// Source title
     pub title: Option<String>,
@@ -704,7 +907,8 @@ pub struct Source {
 }
 
 /// Media RSS thumbnail
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaThumbnail {
     /// Thumbnail URL
     ///
@@ -717,10 +921,83 @@ pub struct MediaThumbnail {
     pub width: Option<u32>,
     /// Thumbnail height in pixels
     pub height: Option<u32>,
+    /// Point in the media the still was taken from, as written in the `time`
+    /// attribute (Normal Play Time, e.g. `12:05:01.123` or `90.5`)
+    ///
+    /// See [`MediaThumbnail::time_offset`] for the parsed value.
+    pub time: Option<String>,
+}
+
+impl MediaThumbnail {
+    /// Offset of the still from the start of the media
+    ///
+    /// Parses the Normal Play Time forms Media RSS allows: seconds
+    /// (`90.5`) or `h:mm:ss` with optional fractional seconds
+    /// (`0:01:30.5`). `mm:ss` is accepted as well. Returns `None` when there
+    /// is no `time` attribute, for `now`, or when the value is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{MediaThumbnail, Url};
+    /// use std::time::Duration;
+    ///
+    /// let thumbnail = MediaThumbnail {
+    ///     url: Url::new("https://example.com/still.jpg"),
+    ///     width: None,
+    ///     height: None,
+    ///     time: Some("12:05:01.123".to_string()),
+    /// };
+    /// assert_eq!(
+    ///     thumbnail.time_offset(),
+    ///     Some(Duration::from_millis(43_501_123))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn time_offset(&self) -> Option<Duration> {
+        parse_npt(self.time.as_deref()?)
+    }
+}
+
+/// Parses a Normal Play Time value (RFC 2326 section 3.6) into a duration
+fn parse_npt(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let value = value.strip_prefix("npt=").unwrap_or(value);
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut fields = [0u64; 3];
+    let mut count = 0;
+    for field in whole.split(':') {
+        if count == fields.len() || field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        fields[count] = field.parse().ok()?;
+        count += 1;
+    }
+    let seconds = match &fields[..count] {
+        [seconds] => *seconds,
+        [minutes, seconds] if *seconds < 60 => minutes.checked_mul(60)?.checked_add(*seconds)?,
+        [hours, minutes, seconds] if *minutes < 60 && *seconds < 60 => hours
+            .checked_mul(3600)?
+            .checked_add(minutes * 60 + seconds)?,
+        _ => return None,
+    };
+
+    // Digits past nanosecond precision are dropped
+    let nanos = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(9)
+        .fold(0u32, |nanos, digit| nanos * 10 + u32::from(digit - b'0'));
+    Some(Duration::new(seconds, nanos))
 }
 
 /// Media RSS content
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaContent {
     /// Media URL
     ///
@@ -739,6 +1016,33 @@ pub struct MediaContent {
     pub height: Option<u32>,
     /// Duration in seconds (for audio/video)
     pub duration: Option<u64>,
+    /// Preview stills for this rendition, in document order
+    ///
+    /// These are the `media:thumbnail` elements nested in this
+    /// `media:content`, or, when it has none, those of its `media:group`.
+    /// Every thumbnail also appears in `Entry::media_thumbnails`.
+    pub thumbnails: Vec<MediaThumbnail>,
+    /// Index of the `media:group` this rendition belongs to, counting the
+    /// entry's groups from 0
+    ///
+    /// Renditions sharing a group are alternative encodings of the same
+    /// media; `None` for `media:content` outside any group.
+    pub group: Option<usize>,
+}
+
+impl MediaContent {
+    /// Normalized MIME type, inferred from the URL when missing
+    #[must_use]
+    pub fn normalized_type(&self) -> Option<String> {
+        mime::resolve_mime_type(self.content_type.as_deref(), &self.url)
+    }
+
+    /// Coarse kind of the media object (audio, video, image, document)
+    #[must_use]
+    pub fn media_kind(&self) -> MediaKind {
+        self.normalized_type()
+            .map_or(MediaKind::Other, |mime| MediaKind::from_mime(&mime))
+    }
 }
 
 impl FromAttributes for Link {
@@ -752,35 +1056,60 @@ impl FromAttributes for Link {
         let mut title = None;
         let mut hreflang = None;
         let mut length = None;
+        let mut thr_count = None;
 
         for attr in attrs {
             if attr.value.len() > max_attr_length {
                 continue;
             }
             match attr.key.as_ref() {
-                b"href" => href = Some(bytes_to_string(&attr.value)),
-                b"rel" => rel = Some(bytes_to_string(&attr.value)),
-                b"type" => link_type = Some(bytes_to_string(&attr.value)),
-                b"title" => title = Some(bytes_to_string(&attr.value)),
-                b"hreflang" => hreflang = Some(bytes_to_string(&attr.value)),
-                b"length" => length = bytes_to_string(&attr.value).parse().ok(),
+                b"href" => href = Some(decode_attribute(&attr.value)),
+                b"rel" => rel = Some(decode_attribute(&attr.value)),
+                b"type" => link_type = Some(decode_attribute(&attr.value)),
+                b"title" => title = Some(decode_attribute(&attr.value)),
+                b"hreflang" => hreflang = Some(decode_attribute(&attr.value)),
+                b"length" => length = decode_attribute(&attr.value).parse().ok(),
+                b"thr:count" => thr_count = parse_count(&decode_attribute(&attr.value)),
                 _ => {}
             }
         }
 
         href.map(|href| Self {
             href: Url::new(href),
-            rel: rel
-                .map(std::convert::Into::into)
-                .or_else(|| Some("alternate".into())),
+            rel: Some(normalize_rel(rel.as_deref().unwrap_or_default())),
             link_type: link_type.map(MimeType::new),
             title,
             length,
             hreflang: hreflang.map(std::convert::Into::into),
+            thr_count,
         })
     }
 }
 
+/// Prefix of link relations spelled as IANA registry URIs
+const IANA_REL_PREFIX: &str = "http://www.iana.org/assignments/relation/";
+
+/// Canonical form of a link `rel` value
+///
+/// Registered relations are case-insensitive and may also be written as
+/// their IANA registry URI (RFC 4287 §4.2.7.2), so
+/// `http://www.iana.org/assignments/relation/Enclosure` becomes `enclosure`.
+/// Extension relations are URIs and are kept as written.
+fn normalize_rel(rel: &str) -> SmallString {
+    let rel = rel.trim();
+    let rel = rel
+        .get(..IANA_REL_PREFIX.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(IANA_REL_PREFIX))
+        .map_or(rel, |_| &rel[IANA_REL_PREFIX.len()..]);
+    if rel.is_empty() {
+        "alternate".into()
+    } else if rel.contains(':') {
+        rel.into()
+    } else {
+        rel.to_ascii_lowercase().into()
+    }
+}
+
 impl FromAttributes for Tag {
     fn from_attributes<'a, I>(attrs: I, max_attr_length: usize) -> Option<Self>
     where
@@ -796,9 +1125,9 @@ impl FromAttributes for Tag {
             }
 
             match attr.key.as_ref() {
-                b"term" => term = Some(bytes_to_string(&attr.value)),
-                b"scheme" | b"domain" => scheme = Some(bytes_to_string(&attr.value)),
-                b"label" => label = Some(bytes_to_string(&attr.value)),
+                b"term" => term = Some(decode_attribute(&attr.value)),
+                b"scheme" | b"domain" => scheme = Some(decode_attribute(&attr.value)),
+                b"label" => label = Some(decode_attribute(&attr.value)),
                 _ => {}
             }
         }
@@ -826,9 +1155,9 @@ impl FromAttributes for Enclosure {
             }
 
             match attr.key.as_ref() {
-                b"url" => url = Some(bytes_to_string(&attr.value)),
-                b"length" => length = bytes_to_string(&attr.value).parse().ok(),
-                b"type" => enclosure_type = Some(bytes_to_string(&attr.value)),
+                b"url" => url = Some(decode_attribute(&attr.value)),
+                b"length" => length = decode_attribute(&attr.value).parse().ok(),
+                b"type" => enclosure_type = Some(decode_attribute(&attr.value)),
                 _ => {}
             }
         }
@@ -849,6 +1178,7 @@ impl FromAttributes for MediaThumbnail {
         let mut url = None;
         let mut width = None;
         let mut height = None;
+        let mut time = None;
 
         for attr in attrs {
             if attr.value.len() > max_attr_length {
@@ -856,9 +1186,10 @@ impl FromAttributes for MediaThumbnail {
             }
 
             match attr.key.as_ref() {
-                b"url" => url = Some(bytes_to_string(&attr.value)),
-                b"width" => width = bytes_to_string(&attr.value).parse().ok(),
-                b"height" => height = bytes_to_string(&attr.value).parse().ok(),
+                b"url" => url = Some(decode_attribute(&attr.value)),
+                b"width" => width = decode_attribute(&attr.value).parse().ok(),
+                b"height" => height = decode_attribute(&attr.value).parse().ok(),
+                b"time" => time = Some(decode_attribute(&attr.value)),
                 _ => {}
             }
         }
@@ -867,6 +1198,7 @@ impl FromAttributes for MediaThumbnail {
             url: Url::new(url),
             width,
             height,
+            time,
         })
     }
 }
@@ -889,12 +1221,12 @@ impl FromAttributes for MediaContent {
             }
 
             match attr.key.as_ref() {
-                b"url" => url = Some(bytes_to_string(&attr.value)),
-                b"type" => content_type = Some(bytes_to_string(&attr.value)),
-                b"fileSize" => filesize = bytes_to_string(&attr.value).parse().ok(),
-                b"width" => width = bytes_to_string(&attr.value).parse().ok(),
-                b"height" => height = bytes_to_string(&attr.value).parse().ok(),
-                b"duration" => duration = bytes_to_string(&attr.value).parse().ok(),
+                b"url" => url = Some(decode_attribute(&attr.value)),
+                b"type" => content_type = Some(decode_attribute(&attr.value)),
+                b"fileSize" => filesize = decode_attribute(&attr.value).parse().ok(),
+                b"width" => width = decode_attribute(&attr.value).parse().ok(),
+                b"height" => height = decode_attribute(&attr.value).parse().ok(),
+                b"duration" => duration = decode_attribute(&attr.value).parse().ok(),
                 _ => {}
             }
         }
@@ -906,6 +1238,8 @@ impl FromAttributes for MediaContent {
             width,
             height,
             duration,
+            thumbnails: Vec::new(),
+            group: None,
         })
     }
 }
@@ -1022,6 +1356,25 @@ mod tests {
         assert!(person.email.is_none());
     }
 
+    #[test]
+    fn test_person_from_rss() {
+        let person = Person::from_rss("editor@example.com (Jane Doe)");
+        assert_eq!(person.name.as_deref(), Some("Jane Doe"));
+        assert_eq!(person.email.as_deref(), Some("editor@example.com"));
+
+        let person = Person::from_rss("Jane Doe <editor@example.com>");
+        assert_eq!(person.name.as_deref(), Some("Jane Doe"));
+        assert_eq!(person.email.as_deref(), Some("editor@example.com"));
+
+        let person = Person::from_rss("editor@example.com");
+        assert!(person.name.is_none());
+        assert_eq!(person.email.as_deref(), Some("editor@example.com"));
+
+        let person = Person::from_rss("Jane Doe");
+        assert_eq!(person.name.as_deref(), Some("Jane Doe"));
+        assert!(person.email.is_none());
+    }
+
     #[test]
     fn test_person_parse_from_empty_json() {
         let json = json!({});
@@ -1193,4 +1546,29 @@ mod tests {
         let email2 = email1.clone();
         assert_eq!(email1, email2);
     }
+
+    #[test]
+    fn test_parse_npt() {
+        assert_eq!(parse_npt("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_npt("90.5"), Some(Duration::from_millis(90_500)));
+        assert_eq!(parse_npt("1:30"), Some(Duration::from_secs(90)));
+        assert_eq!(
+            parse_npt("12:05:01.123"),
+            Some(Duration::from_millis(43_501_123))
+        );
+        assert_eq!(
+            parse_npt(" npt=0:00:01.000000001 "),
+            Some(Duration::new(1, 1))
+        );
+        assert_eq!(
+            parse_npt("1.1234567891"),
+            Some(Duration::new(1, 123_456_789))
+        );
+        assert_eq!(parse_npt("now"), None);
+        assert_eq!(parse_npt(""), None);
+        assert_eq!(parse_npt("1:60"), None);
+        assert_eq!(parse_npt("1:2:3:4"), None);
+        assert_eq!(parse_npt("-5"), None);
+        assert_eq!(parse_npt("5.x"), None);
+    }
 }
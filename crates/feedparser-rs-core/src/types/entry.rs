@@ -1,14 +1,33 @@
+#[cfg(feature = "itunes")]
+use super::podcast::ItunesEntryMeta;
+#[cfg(feature = "podcast20")]
+use super::podcast::{PodcastEntryMeta, PodcastPerson, PodcastTranscript};
 use super::{
     common::{
-        Content, Enclosure, Link, MediaContent, MediaThumbnail, Person, Source, Tag, TextConstruct,
+        AuthorSource, Content, Enclosure, Image, LicenseInfo, Link, MediaContent, MediaThumbnail,
+        MimeType, Person, SmallString, Source, Tag, TextConstruct, Url, normalize_text_fields,
+        resolve_author,
     },
     generics::LimitedCollectionExt,
-    podcast::{ItunesEntryMeta, PodcastEntryMeta, PodcastPerson, PodcastTranscript},
+};
+#[cfg(feature = "time")]
+use crate::util::date::ToOffsetDateTime;
+use crate::util::mime::MediaKind;
+#[cfg(feature = "podcast20")]
+use crate::util::mime::resolve_mime_type;
+use crate::util::{
+    Fnv1a,
+    text::{HtmlToTextOptions, attribute, first_img_tag, html_to_text},
+    url,
 };
 use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use std::time::Duration;
+use uuid::Uuid;
 
 /// Feed entry/item
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entry {
     /// Unique entry identifier (stored inline for IDs ≤24 bytes)
     pub id: Option<super::common::SmallString>,
@@ -24,7 +43,10 @@ pub struct Entry {
     pub summary: Option<String>,
     /// Detailed summary with metadata
     pub summary_detail: Option<TextConstruct>,
-    /// Full content blocks
+    /// Full content blocks, in document order
+    ///
+    /// JSON Feed items with both `content_html` and `content_text` get the
+    /// HTML block first and the plain text block second.
     pub content: Vec<Content>,
     /// Publication date
     pub published: Option<DateTime<Utc>>,
@@ -49,38 +71,129 @@ pub struct Entry {
     /// Tags/categories
     pub tags: Vec<Tag>,
     /// Media enclosures (audio, video, etc.)
+    ///
+    /// Every `<enclosure>` of an item is kept, although RSS allows one. An
+    /// enclosure listed twice with the same URL, or repeated as a
+    /// `media:content` element, appears once, with its details reconciled:
+    ///
+    /// - The first enclosure's length and type win over later duplicates,
+    ///   and enclosures win over `media:content`.
+    /// - A missing or zero length is filled from a duplicate that has one.
+    /// - A missing or `application/octet-stream` type is filled from a
+    ///   duplicate that has a specific one.
+    ///
+    /// The matching `media:content` is filled in the same way from the
+    /// enclosure.
     pub enclosures: Vec<Enclosure>,
     /// Comments URL or text
     pub comments: Option<String>,
+    /// Number of comments on the entry, if the feed says
+    ///
+    /// Combined after parsing from, in order of precedence:
+    ///
+    /// 1. `thr:total` ([`thr_total`](Self::thr_total))
+    /// 2. `slash:comments` ([`slash_comments`](Self::slash_comments))
+    /// 3. the sum of `thr:count` over `rel="replies"` links
+    ///    ([`Link::thr_count`])
+    pub comments_count: Option<u32>,
+    /// Total replies from the RFC 4685 `thr:total` element
+    pub thr_total: Option<u32>,
+    /// Comment count from the Slash module's `slash:comments` element
+    pub slash_comments: Option<u32>,
+    /// URL of the page the entry is about, for link blogs (JSON Feed `external_url`)
+    pub external_url: Option<String>,
+    /// Wide banner image shown above the entry (JSON Feed `banner_image`)
+    pub banner_image: Option<Url>,
     /// Source feed reference
     pub source: Option<Source>,
+    #[cfg(feature = "itunes")]
     /// iTunes episode metadata (if present)
     pub itunes: Option<Box<ItunesEntryMeta>>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core creator (author fallback) - stored inline for names ≤24 bytes
     pub dc_creator: Option<super::common::SmallString>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core date (publication date fallback)
     pub dc_date: Option<DateTime<Utc>>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core subjects (tags)
     pub dc_subject: Vec<String>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core rights (copyright)
     pub dc_rights: Option<String>,
     /// Media RSS thumbnails
     pub media_thumbnails: Vec<MediaThumbnail>,
     /// Media RSS content items
     pub media_content: Vec<MediaContent>,
+    /// Representative image for the entry, if any
+    ///
+    /// Derived after parsing from the sources listed in
+    /// [`ParseOptions::image_precedence`](crate::ParseOptions::image_precedence),
+    /// so UI code has one field to read instead of probing thumbnails,
+    /// iTunes artwork, enclosures and content. See [`Entry::image_from`].
+    pub image: Option<Image>,
+    #[cfg(feature = "podcast20")]
     /// Podcast 2.0 transcripts for this episode
     pub podcast_transcripts: Vec<PodcastTranscript>,
+    #[cfg(feature = "podcast20")]
     /// Podcast 2.0 persons for this episode (hosts, guests, etc.)
     pub podcast_persons: Vec<PodcastPerson>,
+    #[cfg(feature = "podcast20")]
     /// Podcast 2.0 episode metadata
     pub podcast: Option<Box<PodcastEntryMeta>>,
+    #[cfg(feature = "georss")]
     /// `GeoRSS` location data
     pub geo: Option<Box<crate::namespace::georss::GeoLocation>>,
-    /// License URL (Creative Commons, etc.)
+    /// License URL (Creative Commons, etc.), or its name when no URL is given
     pub license: Option<String>,
+    /// License merged from Atom `rel="license"` links and the `cc`,
+    /// `creativeCommons`, `podcast` and `dcterms` license elements
+    pub license_detail: Option<LicenseInfo>,
+    /// PRISM publishing metadata (journal, volume, issue, DOI)
+    pub prism: Option<Box<crate::namespace::prism::PrismMeta>>,
+    /// Google Data elements (event times, locations, rating, extended properties)
+    pub gdata: Option<Box<crate::namespace::gdata::GDataMeta>>,
+    /// Activity Streams verb and object (posts, likes, shares)
+    pub activity: Option<Box<crate::namespace::activity::ActivityMeta>>,
+    /// True if `id` was generated by [`ParsedFeed::synthesize_entry_ids`]
+    /// rather than read from the feed
+    ///
+    /// [`ParsedFeed::synthesize_entry_ids`]: super::ParsedFeed::synthesize_entry_ids
+    pub id_synthetic: bool,
+}
+
+/// Where an entry's representative image can come from
+///
+/// Used by [`Entry::image_from`] and
+/// [`ParseOptions::image_precedence`](crate::ParseOptions::image_precedence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageSource {
+    /// The largest `media:thumbnail`
+    MediaThumbnail,
+    /// The episode's `itunes:image`
+    ItunesImage,
+    /// The first image `media:content`, then the first image enclosure
+    Enclosure,
+    /// The first `<img>` in the content or summary, with its `alt` text as
+    /// the image title
+    ContentImage,
+}
+
+impl ImageSource {
+    /// Order used by [`Entry::best_image`] and, unless configured otherwise,
+    /// to fill in [`Entry::image`]
+    pub const DEFAULT_ORDER: [Self; 4] = [
+        Self::MediaThumbnail,
+        Self::ItunesImage,
+        Self::Enclosure,
+        Self::ContentImage,
+    ];
 }
 
 impl Entry {
+    /// Average adult silent reading speed used by [`Self::estimated_reading_time`]
+    pub const READING_WPM: u32 = 200;
+
     /// Creates `Entry` with pre-allocated capacity for collections
     ///
     /// Pre-allocates space for typical entry fields:
@@ -108,10 +221,13 @@ impl Entry {
             contributors: Vec::with_capacity(0),
             tags: Vec::with_capacity(3),
             enclosures: Vec::with_capacity(1),
+            #[cfg(feature = "dublincore")]
             dc_subject: Vec::with_capacity(2),
             media_thumbnails: Vec::with_capacity(1),
             media_content: Vec::with_capacity(1),
+            #[cfg(feature = "podcast20")]
             podcast_transcripts: Vec::with_capacity(2),
+            #[cfg(feature = "podcast20")]
             podcast_persons: Vec::with_capacity(4),
             ..Default::default()
         }
@@ -168,6 +284,61 @@ impl Entry {
         self.author_detail = Some(person);
     }
 
+    /// Fills `author` and `author_detail` from the first source in `order`
+    /// that names an author, and keeps every source's author in `authors`
+    pub(crate) fn resolve_author(&mut self, order: &[AuthorSource]) {
+        let candidates = order
+            .iter()
+            .filter_map(|&source| self.author_candidate(source))
+            .collect();
+        resolve_author(
+            &mut self.author,
+            &mut self.author_detail,
+            &mut self.authors,
+            candidates,
+        );
+    }
+
+    /// Normalizes whitespace and Unicode in the title, tags and author
+    /// names, see [`ParsedFeed::normalize_text`](crate::ParsedFeed::normalize_text)
+    pub(crate) fn normalize_text(&mut self) {
+        normalize_text_fields(
+            &mut self.title,
+            &mut self.title_detail,
+            &mut self.author,
+            &mut self.author_detail,
+            &mut self.authors,
+            &mut self.contributors,
+            &mut self.tags,
+        );
+    }
+
+    /// `author` text and person given by `source`
+    ///
+    /// An author element is the only thing that sets `author_detail` while
+    /// parsing, so its presence identifies [`AuthorSource::Author`].
+    fn author_candidate(&self, source: AuthorSource) -> Option<(Option<SmallString>, Person)> {
+        match source {
+            AuthorSource::Author => self
+                .author_detail
+                .as_ref()
+                .map(|person| (self.author.clone(), person.clone())),
+            #[cfg(feature = "dublincore")]
+            AuthorSource::DcCreator => self
+                .dc_creator
+                .as_ref()
+                .map(|name| (Some(name.clone()), Person::from_name(name))),
+            #[cfg(feature = "itunes")]
+            AuthorSource::ItunesAuthor => self
+                .itunes
+                .as_ref()
+                .and_then(|itunes| itunes.author.as_deref())
+                .map(|name| (Some(name.into()), Person::from_name(name))),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
     /// Sets publisher field with `Person`, storing both simple and detailed versions
     ///
     /// # Examples
@@ -216,11 +387,527 @@ impl Entry {
             max_links,
         );
     }
+
+    /// Stable identity hash for deduplication
+    ///
+    /// Uses the first available of `id`, `link`, or `title` plus `published`
+    /// (falling back to `updated`). Whitespace is collapsed before hashing, so
+    /// an item republished with reformatted text keeps its fingerprint, and
+    /// links go through [`util::url::normalize`](crate::util::url::normalize),
+    /// so `HTTP://Example.com:80/post` and `http://example.com/post` match. The
+    /// value is FNV-1a and stays the same across runs, platforms and releases,
+    /// so it is safe to persist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::Entry;
+    ///
+    /// let mut a = Entry::default();
+    /// a.id = Some("urn:post:1".into());
+    /// let mut b = Entry::default();
+    /// b.id = Some("  urn:post:1\n".into());
+    /// b.title = Some("Different title".to_string());
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let non_empty = |s: &&str| !s.trim().is_empty();
+        let mut hasher = Fnv1a::new();
+        if let Some(id) = self.id.as_deref().filter(non_empty) {
+            hasher.write_normalized(b'i', id);
+        } else if let Some(link) = self.link.as_deref().filter(non_empty) {
+            hasher.write_normalized(b'l', &url::normalize(link));
+        } else {
+            hasher.write_normalized(b't', self.title.as_deref().unwrap_or_default());
+            let date = self.published.or(self.updated);
+            hasher.write(&date.map_or(i64::MIN, |d| d.timestamp()).to_le_bytes());
+        }
+        hasher.finish()
+    }
+
+    /// Representative image for the entry, if any
+    ///
+    /// Checks, in order: the largest `media:thumbnail`, `itunes:image`, the
+    /// first image `media:content`, the first image enclosure, and finally the
+    /// first `<img>` in the content or summary. Width and height are filled in
+    /// when the source declares them; for `<img>` the `alt` text becomes the
+    /// image title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(br#"<rss version="2.0"><channel><item>
+    ///     <description><![CDATA[<p><img src="https://example.com/a.png" width="64"></p>]]></description>
+    /// </item></channel></rss>"#).unwrap();
+    /// let image = feed.entries[0].best_image().unwrap();
+    /// assert_eq!(image.url.as_str(), "https://example.com/a.png");
+    /// assert_eq!(image.width, Some(64));
+    /// ```
+    #[must_use]
+    pub fn best_image(&self) -> Option<Image> {
+        self.image_from(&ImageSource::DEFAULT_ORDER)
+    }
+
+    /// Image from the first of `sources` that has one
+    ///
+    /// [`best_image`](Self::best_image) uses [`ImageSource::DEFAULT_ORDER`];
+    /// this lets callers put, say, inline content images ahead of podcast
+    /// artwork. Sources may be listed in any order and left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{ImageSource, parse};
+    ///
+    /// let feed = parse(br#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    /// <channel><item>
+    ///     <media:thumbnail url="https://example.com/thumb.jpg"/>
+    ///     <description><![CDATA[<img src="https://example.com/inline.png">]]></description>
+    /// </item></channel></rss>"#).unwrap();
+    /// let entry = &feed.entries[0];
+    ///
+    /// let image = entry.image_from(&[ImageSource::ContentImage, ImageSource::MediaThumbnail]);
+    /// assert_eq!(image.unwrap().url.as_str(), "https://example.com/inline.png");
+    /// assert_eq!(entry.image_from(&[ImageSource::ItunesImage]), None);
+    /// ```
+    #[must_use]
+    pub fn image_from(&self, sources: &[ImageSource]) -> Option<Image> {
+        sources.iter().find_map(|&source| self.image_in(source))
+    }
+
+    /// Image from a single source
+    fn image_in(&self, source: ImageSource) -> Option<Image> {
+        let sized = |url: &Url, width, height| Image {
+            url: url.clone(),
+            title: None,
+            link: None,
+            width,
+            height,
+            description: None,
+        };
+        let is_image = |mime: Option<&MimeType>| mime.is_some_and(|m| m.starts_with("image/"));
+
+        match source {
+            ImageSource::MediaThumbnail => self
+                .media_thumbnails
+                .iter()
+                .rev()
+                .max_by_key(|t| u64::from(t.width.unwrap_or(0)) * u64::from(t.height.unwrap_or(0)))
+                .map(|thumb| sized(&thumb.url, thumb.width, thumb.height)),
+            #[cfg(feature = "itunes")]
+            ImageSource::ItunesImage => self
+                .itunes
+                .as_ref()
+                .and_then(|i| i.image.as_ref())
+                .map(|url| sized(url, None, None)),
+            #[cfg(not(feature = "itunes"))]
+            ImageSource::ItunesImage => None,
+            ImageSource::Enclosure => self
+                .media_content
+                .iter()
+                .find(|m| is_image(m.content_type.as_ref()))
+                .map(|media| sized(&media.url, media.width, media.height))
+                .or_else(|| {
+                    self.enclosures
+                        .iter()
+                        .find(|e| is_image(e.enclosure_type.as_ref()))
+                        .map(|enclosure| sized(&enclosure.url, None, None))
+                }),
+            ImageSource::ContentImage => {
+                let html = self
+                    .content
+                    .iter()
+                    .map(|c| c.value.as_str())
+                    .chain(self.summary.as_deref());
+                html.filter_map(first_img_tag).find_map(|tag| {
+                    let src = attribute(tag, "src").filter(|src| !src.trim().is_empty())?;
+                    let dimension = |name| attribute(tag, name).and_then(|v| v.trim().parse().ok());
+                    Some(Image {
+                        url: Url::new(src.trim()),
+                        title: attribute(tag, "alt").filter(|alt| !alt.is_empty()),
+                        link: None,
+                        width: dimension("width"),
+                        height: dimension("height"),
+                        description: None,
+                    })
+                })
+            }
+        }
+    }
+
+    /// Fills in [`comments_count`](Self::comments_count) from the sources
+    /// listed there
+    pub(crate) fn aggregate_comments_count(&mut self) {
+        self.comments_count = self.thr_total.or(self.slash_comments).or_else(|| {
+            self.links
+                .iter()
+                .filter(|link| link.rel.as_deref() == Some("replies"))
+                .filter_map(|link| link.thr_count)
+                .reduce(u32::saturating_add)
+        });
+    }
+
+    /// The one media file a player should use for this entry, if any
+    ///
+    /// Considers regular enclosures, `media:content` and Podcast 2.0
+    /// `alternateEnclosure` elements together and ranks them by:
+    ///
+    /// 1. position of the MIME type in `preferences` (most preferred first;
+    ///    `audio/*` matches a whole top-level type), with unlisted types last
+    /// 2. audio and video ahead of other kinds of files
+    /// 3. highest declared bitrate, then largest declared size
+    /// 4. feed order
+    ///
+    /// MIME types are normalized and inferred from the URL when missing, and
+    /// the returned enclosure carries the normalized type. For alternate
+    /// enclosures the first `http(s)` source is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(br#"<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    /// <channel><item>
+    ///     <enclosure url="https://example.com/ep1.mp3" length="1000" type="audio/mpeg"/>
+    ///     <podcast:alternateEnclosure type="audio/opus" bitrate="64000">
+    ///         <podcast:source uri="https://example.com/ep1.opus"/>
+    ///     </podcast:alternateEnclosure>
+    /// </item></channel></rss>"#).unwrap();
+    /// let entry = &feed.entries[0];
+    ///
+    /// # #[cfg(feature = "podcast20")] {
+    /// let best = entry.best_enclosure(&["audio/opus", "audio/*"]).unwrap();
+    /// assert_eq!(best.url.as_str(), "https://example.com/ep1.opus");
+    /// # }
+    /// let best = entry.best_enclosure(&["audio/mpeg"]).unwrap();
+    /// assert_eq!(best.url.as_str(), "https://example.com/ep1.mp3");
+    /// ```
+    #[must_use]
+    pub fn best_enclosure(&self, preferences: &[&str]) -> Option<Enclosure> {
+        let enclosures = self.enclosures.iter().map(|e| {
+            let mime = e.normalized_type();
+            (&e.url, mime, e.length, None)
+        });
+        let media = self.media_content.iter().map(|m| {
+            let mime = m.normalized_type();
+            (&m.url, mime, m.filesize, None)
+        });
+        #[cfg(feature = "podcast20")]
+        let alternates = self
+            .podcast
+            .iter()
+            .flat_map(|p| &p.alternate_enclosure)
+            .filter_map(|a| {
+                let url = a
+                    .sources
+                    .iter()
+                    .find(|u| u.starts_with("https://") || u.starts_with("http://"))
+                    .or_else(|| a.sources.first())?;
+                let mime = resolve_mime_type(Some(&a.enclosure_type), url);
+                Some((url, mime, a.length, a.bitrate))
+            });
+        #[cfg(not(feature = "podcast20"))]
+        let alternates = std::iter::empty::<(&Url, Option<String>, Option<u64>, Option<u64>)>();
+
+        let preference = |mime: Option<&str>| {
+            let mime = mime.unwrap_or_default();
+            preferences
+                .iter()
+                .position(|p| {
+                    p.strip_suffix("/*").map_or_else(
+                        || p.eq_ignore_ascii_case(mime),
+                        |top| {
+                            mime.split('/')
+                                .next()
+                                .is_some_and(|t| t.eq_ignore_ascii_case(top))
+                        },
+                    )
+                })
+                .unwrap_or(preferences.len())
+        };
+        let playable = |mime: Option<&str>| {
+            mime.is_some_and(|m| {
+                matches!(MediaKind::from_mime(m), MediaKind::Audio | MediaKind::Video)
+            })
+        };
+
+        enclosures
+            .chain(media)
+            .chain(alternates)
+            .min_by_key(|(_, mime, length, bitrate)| {
+                let mime = mime.as_deref();
+                (
+                    preference(mime),
+                    !playable(mime),
+                    Reverse(*bitrate),
+                    Reverse(*length),
+                )
+            })
+            .map(|(url, mime, length, _)| Enclosure {
+                url: url.clone(),
+                length,
+                enclosure_type: mime.map(Into::into),
+            })
+    }
+
+    /// Content block best suited for display
+    ///
+    /// Returns the first HTML or XHTML block (`text/html`,
+    /// `application/xhtml+xml`, or Atom's `html` and `xhtml`), falling back
+    /// to the first block of any type. Blocks without a declared type count
+    /// as HTML, as in RSS `content:encoded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(br#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "Example",
+    ///     "items": [{
+    ///         "id": "1",
+    ///         "content_text": "Hello, world",
+    ///         "content_html": "<p>Hello, <b>world</b></p>"
+    ///     }]
+    /// }"#).unwrap();
+    /// let entry = &feed.entries[0];
+    ///
+    /// assert_eq!(entry.content.len(), 2);
+    /// assert_eq!(entry.best_content().unwrap().value, "<p>Hello, <b>world</b></p>");
+    /// ```
+    #[must_use]
+    pub fn best_content(&self) -> Option<&Content> {
+        self.content
+            .iter()
+            .find(|c| {
+                c.content_type.as_deref().is_none_or(|t| {
+                    matches!(
+                        t,
+                        "html" | "xhtml" | MimeType::TEXT_HTML | "application/xhtml+xml"
+                    )
+                })
+            })
+            .or_else(|| self.content.first())
+    }
+
+    /// Number of words in the entry body, ignoring markup
+    ///
+    /// Counts the longest `content` block, falling back to `summary`.
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        let body = self
+            .content
+            .iter()
+            .map(|c| c.value.as_str())
+            .max_by_key(|v| v.len())
+            .or(self.summary.as_deref())
+            .unwrap_or_default();
+        let options = HtmlToTextOptions {
+            include_link_urls: false,
+            list_bullets: false,
+        };
+        html_to_text(body, &options).split_whitespace().count()
+    }
+
+    /// Estimated time to read the entry at [`Self::READING_WPM`] words per minute
+    ///
+    /// Returns `None` when the entry has no text. The estimate is rounded up
+    /// to whole seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::Entry;
+    /// use std::time::Duration;
+    ///
+    /// let entry = Entry {
+    ///     summary: Some(format!("<p>{}</p>", "word ".repeat(400))),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(entry.estimated_reading_time(), Some(Duration::from_secs(120)));
+    /// ```
+    #[must_use]
+    pub fn estimated_reading_time(&self) -> Option<Duration> {
+        self.estimated_reading_time_at(Self::READING_WPM)
+    }
+
+    /// Estimated time to read the entry at `wpm` words per minute
+    ///
+    /// Returns `None` when the entry has no text or `wpm` is zero.
+    #[must_use]
+    pub fn estimated_reading_time_at(&self, wpm: u32) -> Option<Duration> {
+        let words = self.word_count() as u64;
+        if words == 0 || wpm == 0 {
+            return None;
+        }
+        Some(Duration::from_secs((words * 60).div_ceil(u64::from(wpm))))
+    }
+
+    /// Stable episode identifier scoped to a podcast's `podcast:guid`
+    ///
+    /// Hashes the item's `guid` (falling back to the first enclosure URL,
+    /// then to [`Self::synthetic_id`]) into a version 5 UUID under the podcast GUID,
+    /// so the same episode maps to the same identifier on every crawl and in
+    /// every tool that follows this scheme, even when two feeds reuse guids.
+    /// Returns `None` if `podcast_guid` is not a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{Entry, podcast_guid_for};
+    ///
+    /// let podcast = podcast_guid_for("https://example.com/feed");
+    /// let entry = Entry {
+    ///     id: Some("episode-1".into()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     entry.episode_guid(&podcast).as_deref(),
+    ///     Some("a6f0ab7f-dd59-555f-bc85-dcc4807202cf")
+    /// );
+    /// assert_eq!(entry.episode_guid("not-a-uuid"), None);
+    /// ```
+    #[must_use]
+    pub fn episode_guid(&self, podcast_guid: &str) -> Option<String> {
+        let namespace = Uuid::parse_str(podcast_guid.trim()).ok()?;
+        let name = self
+            .id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .or_else(|| self.enclosures.first().map(|e| e.url.to_string()))
+            .unwrap_or_else(|| self.synthetic_id());
+        Some(Uuid::new_v5(&namespace, name.as_bytes()).to_string())
+    }
+
+    /// Deterministic identifier derived from link, title and published date
+    ///
+    /// Falls back to the summary when none of those are present. The result
+    /// has the form `urn:feedparser-rs:entry:{16 hex digits}`.
+    #[must_use]
+    pub fn synthetic_id(&self) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write_normalized(b'l', self.link.as_deref().unwrap_or_default());
+        hasher.write_normalized(b't', self.title.as_deref().unwrap_or_default());
+        let published = self.published.or(self.updated);
+        hasher.write(&published.map_or(i64::MIN, |d| d.timestamp()).to_le_bytes());
+        if self.link.is_none() && self.title.is_none() && published.is_none() {
+            hasher.write_normalized(b's', self.summary.as_deref().unwrap_or_default());
+        }
+        format!("urn:feedparser-rs:entry:{:016x}", hasher.finish())
+    }
+
+    /// Merges enclosures sharing a URL and reconciles them with `media:content`
+    ///
+    /// See [`Entry::enclosures`] for the precedence rules.
+    pub(crate) fn reconcile_enclosures(&mut self) {
+        if self.enclosures.len() > 1 {
+            let mut merged: Vec<Enclosure> = Vec::with_capacity(self.enclosures.len());
+            for enclosure in std::mem::take(&mut self.enclosures) {
+                match merged.iter_mut().find(|e| e.url == enclosure.url) {
+                    Some(first) => {
+                        fill_length(&mut first.length, enclosure.length);
+                        fill_type(&mut first.enclosure_type, enclosure.enclosure_type);
+                    }
+                    None => merged.push(enclosure),
+                }
+            }
+            self.enclosures = merged;
+        }
+        for media in &mut self.media_content {
+            if let Some(enclosure) = self.enclosures.iter_mut().find(|e| e.url == media.url) {
+                fill_length(&mut enclosure.length, media.filesize);
+                fill_type(&mut enclosure.enclosure_type, media.content_type.clone());
+                fill_length(&mut media.filesize, enclosure.length);
+                fill_type(&mut media.content_type, enclosure.enclosure_type.clone());
+            }
+        }
+    }
+}
+
+/// Date fields as `time::OffsetDateTime`, for code standardized on `time`
+///
+/// Each accessor converts the matching chrono field through
+/// [`ToOffsetDateTime`](crate::ToOffsetDateTime), in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::parse;
+///
+/// let feed = parse(br#"<rss version="2.0"><channel><item>
+///     <pubDate>Sat, 14 Dec 2024 10:30:00 +0100</pubDate>
+/// </item></channel></rss>"#).unwrap();
+///
+/// let published = feed.entries[0].published_time().unwrap();
+/// assert_eq!(published.year(), 2024);
+/// assert_eq!(published.hour(), 9);
+/// ```
+#[cfg(feature = "time")]
+impl Entry {
+    /// [`published`](Self::published) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn published_time(&self) -> Option<time::OffsetDateTime> {
+        self.published.to_offset_date_time()
+    }
+
+    /// [`updated`](Self::updated) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn updated_time(&self) -> Option<time::OffsetDateTime> {
+        self.updated.to_offset_date_time()
+    }
+
+    /// [`created`](Self::created) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn created_time(&self) -> Option<time::OffsetDateTime> {
+        self.created.to_offset_date_time()
+    }
+
+    /// [`expired`](Self::expired) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn expired_time(&self) -> Option<time::OffsetDateTime> {
+        self.expired.to_offset_date_time()
+    }
+
+    /// [`dc_date`](Self::dc_date) as `time::OffsetDateTime`
+    #[cfg(feature = "dublincore")]
+    #[must_use]
+    pub fn dc_date_time(&self) -> Option<time::OffsetDateTime> {
+        self.dc_date.to_offset_date_time()
+    }
+}
+
+/// Replaces a missing or zero length, which feeds use as a placeholder
+fn fill_length(length: &mut Option<u64>, other: Option<u64>) {
+    if length.unwrap_or(0) == 0 && other.is_some_and(|n| n > 0) {
+        *length = other;
+    }
+}
+
+/// Replaces a missing or generic MIME type with a specific one
+fn fill_type(mime: &mut Option<MimeType>, other: Option<MimeType>) {
+    let generic = |m: &Option<MimeType>| {
+        m.as_deref().is_none_or(|m| {
+            let essence = m.split(';').next().unwrap_or_default().trim();
+            essence.is_empty() || essence.eq_ignore_ascii_case("application/octet-stream")
+        })
+    };
+    if generic(mime) && !generic(&other) {
+        *mime = other;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "podcast20")]
+    use crate::PodcastAlternateEnclosure;
 
     #[test]
     fn test_entry_default() {
@@ -247,4 +934,287 @@ mod tests {
         assert_eq!(cloned.title.as_deref(), Some("Test"));
         assert_eq!(cloned.links.len(), 1);
     }
+
+    #[test]
+    fn test_fingerprint_fallbacks() {
+        let by_link = Entry {
+            link: Some("https://example.com/1".to_string()),
+            ..Default::default()
+        };
+        let mut by_link_ws = by_link.clone();
+        by_link_ws.link = Some(" https://example.com/1 ".to_string());
+        assert_eq!(by_link.fingerprint(), by_link_ws.fingerprint());
+
+        let mut by_link_variant = by_link.clone();
+        by_link_variant.link = Some("HTTPS://Example.com:443/./%31".to_string());
+        assert_eq!(by_link.fingerprint(), by_link_variant.fingerprint());
+
+        let mut by_id = by_link.clone();
+        by_id.id = Some("https://example.com/1".into());
+        assert_ne!(by_id.fingerprint(), by_link.fingerprint());
+
+        let by_title = Entry {
+            title: Some("Hello   world".to_string()),
+            ..Default::default()
+        };
+        let mut by_title_ws = Entry {
+            title: Some("Hello world\n".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(by_title.fingerprint(), by_title_ws.fingerprint());
+
+        by_title_ws.published = Some(Utc::now());
+        assert_ne!(by_title.fingerprint(), by_title_ws.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let entry = Entry {
+            id: Some("a".into()),
+            ..Default::default()
+        };
+        // FNV-1a of b"ia"
+        assert_eq!(entry.fingerprint(), 0x08b7_3307_b55c_433f);
+    }
+
+    #[test]
+    fn test_episode_guid_fallbacks() {
+        let podcast = "917393e3-1b1e-5cef-ace4-edaa54e1f810";
+        let mut entry = Entry {
+            title: Some("Episode".to_string()),
+            ..Default::default()
+        };
+        let synthetic = entry.episode_guid(podcast).unwrap();
+
+        entry.enclosures.push(Enclosure {
+            url: Url::new("https://e.com/ep.mp3"),
+            length: None,
+            enclosure_type: None,
+        });
+        let by_enclosure = entry.episode_guid(podcast).unwrap();
+        assert_ne!(by_enclosure, synthetic);
+
+        entry.id = Some(" guid-1 ".into());
+        let by_guid = entry.episode_guid(podcast).unwrap();
+        assert_ne!(by_guid, by_enclosure);
+        assert_eq!(entry.episode_guid(&podcast.to_uppercase()), Some(by_guid));
+    }
+
+    #[test]
+    fn test_synthetic_id() {
+        let entry = Entry {
+            link: Some("https://example.com/1".to_string()),
+            title: Some("Post".to_string()),
+            ..Default::default()
+        };
+        let id = entry.synthetic_id();
+        assert!(id.starts_with("urn:feedparser-rs:entry:"));
+        assert_eq!(id.len(), "urn:feedparser-rs:entry:".len() + 16);
+        assert_eq!(id, entry.synthetic_id());
+
+        let other = Entry {
+            title: Some("Other".to_string()),
+            ..entry
+        };
+        assert_ne!(id, other.synthetic_id());
+    }
+
+    #[cfg(feature = "itunes")]
+    #[test]
+    fn test_best_image_precedence() {
+        let mut entry = Entry {
+            summary: Some(r#"<img alt="inline" src="https://e.com/inline.png">"#.to_string()),
+            ..Default::default()
+        };
+        let image = entry.best_image().unwrap();
+        assert_eq!(image.url.as_str(), "https://e.com/inline.png");
+        assert_eq!(image.title.as_deref(), Some("inline"));
+
+        entry.enclosures.push(Enclosure {
+            url: Url::new("https://e.com/audio.mp3"),
+            length: None,
+            enclosure_type: Some(MimeType::new("audio/mpeg")),
+        });
+        entry.enclosures.push(Enclosure {
+            url: Url::new("https://e.com/cover.jpg"),
+            length: None,
+            enclosure_type: Some(MimeType::new("image/jpeg")),
+        });
+        assert_eq!(
+            entry.best_image().unwrap().url.as_str(),
+            "https://e.com/cover.jpg"
+        );
+
+        entry.itunes = Some(Box::new(ItunesEntryMeta {
+            image: Some(Url::new("https://e.com/itunes.jpg")),
+            ..Default::default()
+        }));
+        assert_eq!(
+            entry.best_image().unwrap().url.as_str(),
+            "https://e.com/itunes.jpg"
+        );
+
+        for (url, size) in [("small", 10), ("large", 100)] {
+            entry.media_thumbnails.push(MediaThumbnail {
+                url: Url::new(url),
+                width: Some(size),
+                height: Some(size),
+                time: None,
+            });
+        }
+        let image = entry.best_image().unwrap();
+        assert_eq!(image.url.as_str(), "large");
+        assert_eq!(image.width, Some(100));
+    }
+
+    #[test]
+    fn test_best_image_none() {
+        let entry = Entry {
+            summary: Some("<p>No images, just <imgur> text</p>".to_string()),
+            ..Default::default()
+        };
+        assert!(entry.best_image().is_none());
+    }
+
+    #[cfg(feature = "podcast20")]
+    #[test]
+    fn test_best_enclosure_ranking() {
+        let enclosure = |url: &str, length, mime: Option<&str>| Enclosure {
+            url: Url::new(url),
+            length,
+            enclosure_type: mime.map(MimeType::new),
+        };
+        let mut entry = Entry::default();
+        assert!(entry.best_enclosure(&[]).is_none());
+
+        entry
+            .enclosures
+            .push(enclosure("https://e.com/cover.jpg", Some(9000), None));
+        entry
+            .enclosures
+            .push(enclosure("https://e.com/low.mp3", Some(1000), None));
+        entry.enclosures.push(enclosure(
+            "https://e.com/high.mp3",
+            Some(4000),
+            Some("audio/mp3"),
+        ));
+        // Playable files beat larger images; larger beats smaller
+        let best = entry.best_enclosure(&[]).unwrap();
+        assert_eq!(best.url.as_str(), "https://e.com/high.mp3");
+        assert_eq!(best.enclosure_type.as_deref(), Some("audio/mpeg"));
+        assert_eq!(
+            entry.best_enclosure(&["image/*"]).unwrap().url.as_str(),
+            "https://e.com/cover.jpg"
+        );
+
+        entry.media_content.push(MediaContent {
+            url: Url::new("https://e.com/ep.mp4"),
+            content_type: None,
+            filesize: None,
+            width: None,
+            height: None,
+            duration: None,
+            thumbnails: Vec::new(),
+            group: None,
+        });
+        entry.podcast = Some(Box::new(PodcastEntryMeta {
+            alternate_enclosure: vec![
+                PodcastAlternateEnclosure {
+                    enclosure_type: MimeType::new("audio/opus"),
+                    bitrate: Some(32_000),
+                    sources: vec![Url::new("ipfs://low"), Url::new("https://e.com/low.opus")],
+                    ..Default::default()
+                },
+                PodcastAlternateEnclosure {
+                    enclosure_type: MimeType::new("audio/opus"),
+                    bitrate: Some(96_000),
+                    sources: vec![Url::new("ipfs://high")],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }));
+        assert_eq!(
+            entry
+                .best_enclosure(&["VIDEO/*", "audio/*"])
+                .unwrap()
+                .url
+                .as_str(),
+            "https://e.com/ep.mp4"
+        );
+        assert_eq!(
+            entry.best_enclosure(&["audio/opus"]).unwrap().url.as_str(),
+            "ipfs://high"
+        );
+    }
+
+    #[test]
+    fn test_best_content() {
+        let mut entry = Entry::default();
+        assert!(entry.best_content().is_none());
+
+        entry.content.push(Content::plain("plain"));
+        assert_eq!(entry.best_content().unwrap().value, "plain");
+
+        entry.content.push(Content {
+            value: "<div>xhtml</div>".to_string(),
+            content_type: Some("xhtml".into()),
+            language: None,
+            base: None,
+        });
+        entry.content.push(Content::html("<p>html</p>"));
+        assert_eq!(entry.best_content().unwrap().value, "<div>xhtml</div>");
+    }
+
+    #[test]
+    fn test_reading_time() {
+        let mut entry = Entry::default();
+        assert_eq!(entry.word_count(), 0);
+        assert_eq!(entry.estimated_reading_time(), None);
+
+        entry.summary = Some("short teaser".to_string());
+        entry.content.push(Content::html(
+            "<p>One <b>two</b> three</p><script>not counted</script><p>four five</p>",
+        ));
+        assert_eq!(entry.word_count(), 5);
+        assert_eq!(entry.estimated_reading_time(), Some(Duration::from_secs(2)));
+        assert_eq!(
+            entry.estimated_reading_time_at(60),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(entry.estimated_reading_time_at(0), None);
+    }
+
+    #[test]
+    fn test_reconcile_enclosures_with_media_content() {
+        let enclosure = |length, mime: Option<&str>| Enclosure {
+            url: "https://example.com/a.mp3".into(),
+            length,
+            enclosure_type: mime.map(Into::into),
+        };
+        let mut entry = Entry {
+            enclosures: vec![
+                enclosure(None, None),
+                enclosure(Some(0), Some("audio/mpeg")),
+            ],
+            media_content: vec![MediaContent {
+                url: "https://example.com/a.mp3".into(),
+                content_type: None,
+                filesize: Some(42),
+                width: None,
+                height: None,
+                duration: None,
+                thumbnails: Vec::new(),
+                group: None,
+            }],
+            ..Default::default()
+        };
+        entry.reconcile_enclosures();
+
+        assert_eq!(entry.enclosures, [enclosure(Some(42), Some("audio/mpeg"))]);
+        assert_eq!(
+            entry.media_content[0].content_type.as_deref(),
+            Some("audio/mpeg")
+        );
+    }
 }
@@ -1,18 +1,39 @@
+#[cfg(feature = "itunes")]
+use super::podcast::ItunesFeedMeta;
+#[cfg(feature = "podcast20")]
+use super::podcast::PodcastMeta;
 use super::{
-    common::{Generator, Image, Link, Person, Tag, TextConstruct},
+    common::{
+        AuthorSource, Generator, Image, LicenseInfo, Link, Person, SmallString, Tag, TextConstruct,
+        normalize_text_fields, resolve_author,
+    },
     entry::Entry,
     generics::LimitedCollectionExt,
-    podcast::{ItunesFeedMeta, PodcastMeta},
+    heap::HeapSize,
+    stats::ParseStats,
     version::FeedVersion,
 };
+use crate::namespace::blog_channel::BlogChannelMeta;
+use crate::namespace::feed_history::FeedHistory;
 use crate::namespace::syndication::SyndicationMeta;
-use crate::{ParserLimits, error::Result};
+use crate::parser::XmlReader;
+#[cfg(feature = "time")]
+use crate::util::date::ToOffsetDateTime;
+use crate::{
+    ParserLimits,
+    error::Result,
+    limits::MemoryBudget,
+    util::{language::LanguageTag, text::fix_mojibake, url::strip_tracking_params},
+};
 use chrono::{DateTime, Utc};
-use quick_xml::Reader;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{HashMap, HashSet};
 
 /// Feed metadata
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(not(feature = "georss"), allow(clippy::derive_partial_eq_without_eq))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeedMeta {
     /// Feed title
     pub title: Option<String>,
@@ -36,6 +57,8 @@ pub struct FeedMeta {
     pub author_detail: Option<Person>,
     /// All authors
     pub authors: Vec<Person>,
+    /// RSS `managingEditor` as written, e.g. `editor@example.com (Jane Doe)`
+    pub managing_editor: Option<String>,
     /// Contributors
     pub contributors: Vec<Person>,
     /// Publisher name (stored inline for names ≤24 bytes)
@@ -58,35 +81,56 @@ pub struct FeedMeta {
     pub icon: Option<String>,
     /// Logo URL (larger image)
     pub logo: Option<String>,
+    /// `icon` exactly as the document wrote it, before resolving it against
+    /// `xml:base` or the feed URL
+    pub raw_icon: Option<String>,
+    /// `logo` exactly as the document wrote it, before resolving it
+    pub raw_logo: Option<String>,
+    /// Image URL (`image.url`) exactly as the document wrote it, before resolving it
+    pub raw_image_url: Option<String>,
     /// Feed-level tags/categories
     pub tags: Vec<Tag>,
     /// Unique feed identifier
     pub id: Option<String>,
     /// Time-to-live (update frequency hint) in minutes
     pub ttl: Option<u32>,
+    /// True if the publisher has stopped updating the feed (JSON Feed `expired`)
+    pub expired: bool,
+    #[cfg(feature = "itunes")]
     /// iTunes podcast metadata (if present)
     pub itunes: Option<Box<ItunesFeedMeta>>,
+    #[cfg(feature = "podcast20")]
     /// Podcast 2.0 namespace metadata (if present)
     pub podcast: Option<Box<PodcastMeta>>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core creator (author fallback) - stored inline for names ≤24 bytes
     pub dc_creator: Option<super::common::SmallString>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core publisher (stored inline for names ≤24 bytes)
     pub dc_publisher: Option<super::common::SmallString>,
+    #[cfg(feature = "dublincore")]
     /// Dublin Core rights (copyright)
     pub dc_rights: Option<String>,
-    /// License URL (Creative Commons, etc.)
+    /// License URL (Creative Commons, etc.), or its name when no URL is given
     pub license: Option<String>,
+    /// License merged from Atom `rel="license"` links and the `cc`,
+    /// `creativeCommons`, `podcast` and `dcterms` license elements
+    pub license_detail: Option<LicenseInfo>,
     /// Syndication module metadata (RSS 1.0)
     pub syndication: Option<Box<SyndicationMeta>>,
+    #[cfg(feature = "georss")]
     /// Geographic location from `GeoRSS` namespace (feed level)
     pub geo: Option<Box<crate::namespace::georss::GeoLocation>>,
+    /// blogChannel module links (blogroll, subscriptions, recommended blog)
+    pub blog_channel: Option<Box<BlogChannelMeta>>,
 }
 
 /// Parsed feed result
 ///
 /// This is the main result type returned by the parser, analogous to
 /// Python feedparser's `FeedParserDict`.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParsedFeed {
     /// Feed metadata
     pub feed: FeedMeta,
@@ -100,6 +144,13 @@ pub struct ParsedFeed {
     pub encoding: String,
     /// Detected feed format version
     pub version: FeedVersion,
+    /// Version exactly as the document declared it
+    ///
+    /// The `version` attribute of the root element (`"2.0"`, `"0.91"`, or
+    /// something unusual like `"2.0.1"`) or the JSON Feed `version` URL.
+    /// Kept even when it does not match a known [`FeedVersion`], so callers
+    /// can audit nonstandard declarations. `None` when nothing was declared.
+    pub raw_version: Option<String>,
     /// XML namespaces (prefix -> URI)
     pub namespaces: HashMap<String, String>,
     /// HTTP status code (if fetched from URL)
@@ -110,9 +161,25 @@ pub struct ParsedFeed {
     pub etag: Option<String>,
     /// Last-Modified header from HTTP response
     pub modified: Option<String>,
+    /// Archive markers and archive links (RFC 5005), if present
+    pub history: Option<FeedHistory>,
+    /// Parse statistics, when [`ParseOptions::collect_stats`](crate::ParseOptions::collect_stats) is set
+    pub stats: Option<ParseStats>,
+    /// Fields the HTML sanitizer changed, as paths such as `feed.title` or
+    /// `entries[0].content[1]`
+    ///
+    /// Empty when [`ParseOptions::sanitize_html`](crate::ParseOptions::sanitize_html)
+    /// is off.
+    pub sanitized: Vec<String>,
     /// HTTP response headers (if fetched from URL)
     #[cfg(feature = "http")]
     pub headers: Option<HashMap<String, String>>,
+    /// Response body exactly as fetched, after HTTP content decoding
+    ///
+    /// Only set when fetched with
+    /// [`ParseOptions::keep_raw_body`](crate::ParseOptions::keep_raw_body).
+    #[cfg(feature = "http")]
+    pub raw_body: Option<Vec<u8>>,
 }
 
 impl ParsedFeed {
@@ -183,7 +250,7 @@ impl ParsedFeed {
     ///
     /// ```ignore
     /// // In parser:
-    /// if !feed.check_entry_limit(reader, &mut buf, limits, depth)? {
+    /// if !feed.check_entry_limit(reader, limits, depth)? {
     ///     continue;
     /// }
     /// // Process entry...
@@ -191,8 +258,7 @@ impl ParsedFeed {
     #[inline]
     pub fn check_entry_limit(
         &mut self,
-        reader: &mut Reader<&[u8]>,
-        buf: &mut Vec<u8>,
+        reader: &mut XmlReader<'_>,
         limits: &ParserLimits,
         depth: &mut usize,
     ) -> Result<bool> {
@@ -201,16 +267,387 @@ impl ParsedFeed {
         if self.entries.is_at_limit(limits.max_entries) {
             self.bozo = true;
             self.bozo_exception = Some(format!("Entry limit exceeded: {}", limits.max_entries));
-            skip_element(reader, buf, limits, *depth)?;
+            skip_element(reader, limits, *depth)?;
             *depth = depth.saturating_sub(1);
             Ok(false)
         } else {
             Ok(true)
         }
     }
+
+    /// Appends a parsed entry if it fits in the memory budget
+    ///
+    /// Once the entries stored so far exceed
+    /// [`ParserLimits::max_total_memory_bytes`], the entry is dropped and the
+    /// feed is marked bozo.
+    pub(crate) fn push_entry(&mut self, entry: Entry, budget: &mut MemoryBudget) {
+        match budget.charge(std::mem::size_of::<Entry>() + entry.heap_size()) {
+            Ok(()) => self.entries.push(entry),
+            Err(e) => {
+                self.bozo = true;
+                self.bozo_exception = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Charges the feed metadata to the memory budget
+    ///
+    /// Called once the channel or feed element is parsed. The metadata is
+    /// kept even if it does not fit, but the feed is marked bozo.
+    pub(crate) fn charge_feed_meta(&mut self, budget: &mut MemoryBudget) {
+        if let Err(e) = budget.charge(self.feed.heap_size()) {
+            self.bozo = true;
+            self.bozo_exception = Some(e.to_string());
+        }
+    }
+
+    /// Remove entries whose [`Entry::fingerprint`] was already seen
+    ///
+    /// Keeps the first occurrence of each entry and preserves order.
+    /// Returns the number of entries removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse(br#"<rss version="2.0"><channel>
+    ///     <item><guid>1</guid><title>First</title></item>
+    ///     <item><guid> 1 </guid><title>First, again</title></item>
+    /// </channel></rss>"#).unwrap();
+    /// assert_eq!(feed.dedup_entries(), 1);
+    /// assert_eq!(feed.entries.len(), 1);
+    /// ```
+    pub fn dedup_entries(&mut self) -> usize {
+        let before = self.entries.len();
+        let mut seen = HashSet::with_capacity(before);
+        self.entries
+            .retain(|entry| seen.insert(entry.fingerprint()));
+        before - self.entries.len()
+    }
+
+    /// Give every entry without an `id` a deterministic synthetic one
+    ///
+    /// Opt-in normalization for sloppy feeds that omit `guid`/`atom:id`, so
+    /// downstream deduplication keyed on ids keeps working. Generated ids come
+    /// from [`Entry::synthetic_id`] and are flagged with `id_synthetic`.
+    /// Returns the number of ids synthesized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse(br#"<rss version="2.0"><channel>
+    ///     <item><title>No guid</title><link>https://example.com/1</link></item>
+    /// </channel></rss>"#).unwrap();
+    /// assert_eq!(feed.synthesize_entry_ids(), 1);
+    /// assert!(feed.entries[0].id_synthetic);
+    /// assert!(feed.entries[0].id.as_deref().unwrap().starts_with("urn:feedparser-rs:entry:"));
+    /// ```
+    pub fn synthesize_entry_ids(&mut self) -> usize {
+        let mut count = 0;
+        for entry in &mut self.entries {
+            if entry.id.as_deref().is_none_or(|id| id.trim().is_empty()) {
+                entry.id = Some(entry.synthetic_id().into());
+                entry.id_synthetic = true;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Remove click-tracking parameters from feed and entry links
+    ///
+    /// Applies [`util::url::strip_tracking_params`](crate::util::url::strip_tracking_params)
+    /// to `feed.link`, `feed.links` and each entry's `link` and `links`, with
+    /// `extra` naming parameters to drop on top of `utm_*` and the built-in
+    /// [`TRACKING_PARAMS`](crate::util::url::TRACKING_PARAMS). Returns the
+    /// number of URLs changed. `ParseOptions::strip_tracking_params` runs
+    /// this during parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse(br#"<rss version="2.0"><channel><item>
+    ///     <link>https://example.com/1?utm_source=rss&amp;src=feed</link>
+    /// </item></channel></rss>"#).unwrap();
+    /// // The RSS <link> fills both `link` and `links`
+    /// assert_eq!(feed.strip_tracking_params(&["src"]), 2);
+    /// assert_eq!(feed.entries[0].link.as_deref(), Some("https://example.com/1"));
+    /// ```
+    pub fn strip_tracking_params<S: AsRef<str>>(&mut self, extra: &[S]) -> usize {
+        let mut count = 0;
+        let mut strip = |url: &str| match strip_tracking_params(url, extra) {
+            Cow::Owned(stripped) => {
+                count += 1;
+                Some(stripped)
+            }
+            Cow::Borrowed(_) => None,
+        };
+        let feed_links = self.feed.links.iter_mut();
+        let entry_links = self.entries.iter_mut().flat_map(|e| e.links.iter_mut());
+        for link in feed_links.chain(entry_links) {
+            if let Some(href) = strip(&link.href) {
+                link.href = href.into();
+            }
+        }
+        let feed_link = self.feed.link.as_mut();
+        let entry_link = self.entries.iter_mut().filter_map(|e| e.link.as_mut());
+        for link in feed_link.into_iter().chain(entry_link) {
+            if let Some(stripped) = strip(link) {
+                *link = stripped;
+            }
+        }
+        count
+    }
+
+    /// Split keyword lists into separate tags and drop duplicate tags
+    ///
+    /// Some feeds put a whole keyword list such as `rust, web; async` in a
+    /// single `dc:subject` or `category`. Terms are split on commas and
+    /// semicolons and trimmed, and empty ones are dropped. Duplicates are
+    /// then removed case-insensitively across all tag sources (categories,
+    /// `dc:subject`, `media:keywords`, ...), keeping the first spelling; a
+    /// dropped duplicate fills in a scheme or label the kept tag lacks.
+    /// Applies to feed and entry tags. `dc_subject` keeps the raw values.
+    ///
+    /// This also splits terms that legitimately contain a comma, such as
+    /// `Smith, John`, which is why it is opt-in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse(br#"<rss version="2.0"><channel><item>
+    ///     <category>Rust</category>
+    ///     <category>rust, Web ; async</category>
+    /// </item></channel></rss>"#).unwrap();
+    /// feed.normalize_tags();
+    ///
+    /// let terms: Vec<&str> = feed.entries[0].tags.iter().map(|t| t.term.as_str()).collect();
+    /// assert_eq!(terms, ["Rust", "Web", "async"]);
+    /// ```
+    pub fn normalize_tags(&mut self) {
+        normalize_tag_list(&mut self.feed.tags);
+        for entry in &mut self.entries {
+            normalize_tag_list(&mut entry.tags);
+        }
+    }
+
+    /// Normalizes whitespace and Unicode in titles, tags and author names
+    ///
+    /// Applies [`normalize_text`](crate::util::text::normalize_text) to the
+    /// title and `title_detail` value, tag terms and labels, `author`, and
+    /// the names in `author_detail`, `authors` and `contributors`, on the
+    /// feed and every entry. Runs of whitespace collapse to one space, ends
+    /// are trimmed and text is converted to NFC, so the same title or name
+    /// from different feeds compares equal byte for byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse("<rss version=\"2.0\"><channel><item>
+    ///     <title>  Cafe\u{301}\n   opening </title>
+    ///     <category> local  news </category>
+    /// </item></channel></rss>".as_bytes()).unwrap();
+    /// feed.normalize_text();
+    ///
+    /// assert_eq!(feed.entries[0].title.as_deref(), Some("Caf\u{e9} opening"));
+    /// assert_eq!(feed.entries[0].tags[0].term, "local news");
+    /// ```
+    pub fn normalize_text(&mut self) {
+        self.feed.normalize_text();
+        for entry in &mut self.entries {
+            entry.normalize_text();
+        }
+    }
+
+    /// Repairs double-encoded UTF-8 in text fields, returning the paths of
+    /// the fields that changed
+    ///
+    /// Applies [`fix_mojibake`](crate::util::text::fix_mojibake) to the
+    /// title, subtitle, author names and tags of the feed, and to the title,
+    /// summary, content, author names and tags of every entry. Paths name
+    /// the field as in [`ParsedFeed::sanitized`], such as `feed.title` or
+    /// `entries[0].summary`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse("<rss version=\"2.0\"><channel><item>
+    ///     <title>Don\u{e2}\u{20ac}\u{2122}t panic</title>
+    /// </item></channel></rss>".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(feed.fix_mojibake(), ["entries[0].title"]);
+    /// assert_eq!(feed.entries[0].title.as_deref(), Some("Don\u{2019}t panic"));
+    /// ```
+    pub fn fix_mojibake(&mut self) -> Vec<String> {
+        let mut changed = Vec::new();
+        let meta = &mut self.feed;
+        let fields = [
+            ("title", fix_text(&mut meta.title, &mut meta.title_detail)),
+            (
+                "subtitle",
+                fix_text(&mut meta.subtitle, &mut meta.subtitle_detail),
+            ),
+            (
+                "author",
+                fix_people(&mut meta.author, &mut meta.author_detail, &mut meta.authors),
+            ),
+            ("tags", fix_tags(&mut meta.tags)),
+        ];
+        changed.extend(
+            fields
+                .into_iter()
+                .filter(|&(_, fixed)| fixed)
+                .map(|(field, _)| format!("feed.{field}")),
+        );
+        for (index, entry) in self.entries.iter_mut().enumerate() {
+            let content = fix_all(entry.content.iter_mut().map(|content| &mut content.value));
+            let fields = [
+                ("title", fix_text(&mut entry.title, &mut entry.title_detail)),
+                (
+                    "summary",
+                    fix_text(&mut entry.summary, &mut entry.summary_detail),
+                ),
+                ("content", content),
+                (
+                    "author",
+                    fix_people(
+                        &mut entry.author,
+                        &mut entry.author_detail,
+                        &mut entry.authors,
+                    ),
+                ),
+                ("tags", fix_tags(&mut entry.tags)),
+            ];
+            changed.extend(
+                fields
+                    .into_iter()
+                    .filter(|&(_, fixed)| fixed)
+                    .map(|(field, _)| format!("entries[{index}].{field}")),
+            );
+        }
+        changed
+    }
+
+    /// Entries published or updated at or after `since`
+    ///
+    /// Uses `published`, falling back to `updated`; undated entries are
+    /// excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(br#"<rss version="2.0"><channel>
+    ///     <item><guid>old</guid><pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+    ///     <item><guid>new</guid><pubDate>Mon, 01 Jul 2024 00:00:00 GMT</pubDate></item>
+    /// </channel></rss>"#).unwrap();
+    /// let since = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    /// let recent: Vec<_> = feed.entries_since(since).collect();
+    /// assert_eq!(recent.len(), 1);
+    /// assert_eq!(recent[0].id.as_deref(), Some("new"));
+    /// ```
+    pub fn entries_since(&self, since: DateTime<Utc>) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.published.or(e.updated).is_some_and(|d| d >= since))
+    }
+
+    /// Entries with a tag whose term or label matches `tag`, ignoring case
+    pub fn filter_by_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Entry> {
+        self.entries.iter().filter(move |e| {
+            e.tags.iter().any(|t| {
+                t.term.eq_ignore_ascii_case(tag)
+                    || t.label
+                        .as_deref()
+                        .is_some_and(|l| l.eq_ignore_ascii_case(tag))
+            })
+        })
+    }
+
+    /// Sort entries newest first by `published`, falling back to `updated`
+    ///
+    /// Undated entries are moved to the end; the sort is stable. Returns
+    /// `self` so calls can be chained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let mut feed = parse(br#"<rss version="2.0"><channel>
+    ///     <item><guid>1</guid><pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+    ///     <item><guid>2</guid><pubDate>Tue, 02 Jan 2024 00:00:00 GMT</pubDate></item>
+    ///     <item><guid>3</guid><pubDate>Wed, 03 Jan 2024 00:00:00 GMT</pubDate></item>
+    /// </channel></rss>"#).unwrap();
+    /// feed.sort_entries_desc().truncate_entries(2);
+    /// let ids: Vec<_> = feed.entries.iter().filter_map(|e| e.id.as_deref()).collect();
+    /// assert_eq!(ids, ["3", "2"]);
+    /// ```
+    pub fn sort_entries_desc(&mut self) -> &mut Self {
+        self.entries.sort_by(|a, b| {
+            let (a, b) = (a.published.or(a.updated), b.published.or(b.updated));
+            b.is_some().cmp(&a.is_some()).then(b.cmp(&a))
+        });
+        self
+    }
+
+    /// Sort entries oldest first by `published`, falling back to `updated`
+    ///
+    /// Undated entries are moved to the end; the sort is stable. Returns
+    /// `self` so calls can be chained.
+    pub fn sort_entries_asc(&mut self) -> &mut Self {
+        self.entries.sort_by(|a, b| {
+            let (a, b) = (a.published.or(a.updated), b.published.or(b.updated));
+            b.is_some().cmp(&a.is_some()).then(a.cmp(&b))
+        });
+        self
+    }
+
+    /// Keep only the first `n` entries, returning `self` for chaining
+    pub fn truncate_entries(&mut self, n: usize) -> &mut Self {
+        self.entries.truncate(n);
+        self
+    }
+
+    /// Keep only entries matching `predicate`, returning `self` for chaining
+    pub fn retain_entries(&mut self, predicate: impl FnMut(&Entry) -> bool) -> &mut Self {
+        self.entries.retain(predicate);
+        self
+    }
 }
 
 impl FeedMeta {
+    /// Feed language parsed as a BCP 47 tag
+    ///
+    /// Returns `None` if no language is declared or it is not a well-formed tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(b"<rss version='2.0'><channel><language>pt_BR</language></channel></rss>").unwrap();
+    /// assert_eq!(feed.feed.language.as_deref(), Some("pt-BR"));
+    /// assert_eq!(feed.feed.language_tag().unwrap().language, "pt");
+    /// ```
+    #[must_use]
+    pub fn language_tag(&self) -> Option<LanguageTag> {
+        self.language.as_deref().and_then(LanguageTag::parse)
+    }
+
     /// Creates `FeedMeta` with capacity hints for typical RSS 2.0 feeds
     ///
     /// Pre-allocates collections based on common RSS 2.0 field usage:
@@ -354,6 +791,65 @@ impl FeedMeta {
         self.author_detail = Some(person);
     }
 
+    /// Fills `author` and `author_detail` from the first source in `order`
+    /// that names an author, and keeps every source's author in `authors`
+    pub(crate) fn resolve_author(&mut self, order: &[AuthorSource]) {
+        let candidates = order
+            .iter()
+            .filter_map(|&source| self.author_candidate(source))
+            .collect();
+        resolve_author(
+            &mut self.author,
+            &mut self.author_detail,
+            &mut self.authors,
+            candidates,
+        );
+    }
+
+    /// Normalizes whitespace and Unicode in the title, tags and author
+    /// names, see [`ParsedFeed::normalize_text`](crate::ParsedFeed::normalize_text)
+    pub(crate) fn normalize_text(&mut self) {
+        normalize_text_fields(
+            &mut self.title,
+            &mut self.title_detail,
+            &mut self.author,
+            &mut self.author_detail,
+            &mut self.authors,
+            &mut self.contributors,
+            &mut self.tags,
+        );
+    }
+
+    /// `author` text and person given by `source`
+    ///
+    /// An author element is the only thing that sets `author_detail` while
+    /// parsing, so its presence identifies [`AuthorSource::Author`].
+    fn author_candidate(&self, source: AuthorSource) -> Option<(Option<SmallString>, Person)> {
+        match source {
+            AuthorSource::Author => self
+                .author_detail
+                .as_ref()
+                .map(|person| (self.author.clone(), person.clone())),
+            AuthorSource::ManagingEditor => self
+                .managing_editor
+                .as_deref()
+                .map(|value| (Some(value.into()), Person::from_rss(value))),
+            #[cfg(feature = "dublincore")]
+            AuthorSource::DcCreator => self
+                .dc_creator
+                .as_ref()
+                .map(|name| (Some(name.clone()), Person::from_name(name))),
+            #[cfg(feature = "itunes")]
+            AuthorSource::ItunesAuthor => self
+                .itunes
+                .as_ref()
+                .and_then(|itunes| itunes.author.as_deref())
+                .map(|name| (Some(name.into()), Person::from_name(name))),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
     /// Sets publisher field with `Person`, storing both simple and detailed versions
     ///
     /// # Examples
@@ -404,6 +900,133 @@ impl FeedMeta {
     }
 }
 
+/// Date fields as `time::OffsetDateTime`, for code standardized on `time`
+///
+/// Each accessor converts the matching chrono field through
+/// [`ToOffsetDateTime`](crate::ToOffsetDateTime), in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::parse;
+///
+/// let feed = parse(br#"<feed xmlns="http://www.w3.org/2005/Atom">
+///     <updated>2024-12-14T10:30:00Z</updated>
+/// </feed>"#).unwrap();
+///
+/// assert_eq!(feed.feed.updated_time().unwrap().unix_timestamp(), 1_734_172_200);
+/// assert!(feed.feed.published_time().is_none());
+/// ```
+#[cfg(feature = "time")]
+impl FeedMeta {
+    /// [`updated`](Self::updated) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn updated_time(&self) -> Option<time::OffsetDateTime> {
+        self.updated.to_offset_date_time()
+    }
+
+    /// [`published`](Self::published) as `time::OffsetDateTime`
+    #[must_use]
+    pub fn published_time(&self) -> Option<time::OffsetDateTime> {
+        self.published.to_offset_date_time()
+    }
+}
+
+/// Applies [`fix_mojibake`] to each value, returning whether any changed
+fn fix_all<'a, T>(values: impl IntoIterator<Item = &'a mut T>) -> bool
+where
+    T: AsRef<str> + From<String> + 'a,
+{
+    let mut changed = false;
+    for value in values {
+        if let Cow::Owned(fixed) = fix_mojibake(value.as_ref()) {
+            *value = fixed.into();
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Repairs a text field and its detail, see [`ParsedFeed::fix_mojibake`]
+fn fix_text(value: &mut Option<String>, detail: &mut Option<TextConstruct>) -> bool {
+    let detail = detail.iter_mut().map(|detail| &mut detail.value);
+    fix_all(value.iter_mut().chain(detail))
+}
+
+/// Repairs `author` and every author's name, see [`ParsedFeed::fix_mojibake`]
+fn fix_people(
+    author: &mut Option<SmallString>,
+    author_detail: &mut Option<Person>,
+    authors: &mut [Person],
+) -> bool {
+    let names = author_detail
+        .iter_mut()
+        .chain(authors)
+        .filter_map(|person| person.name.as_mut());
+    fix_all(author.iter_mut().chain(names))
+}
+
+/// Repairs tag terms and labels, see [`ParsedFeed::fix_mojibake`]
+fn fix_tags(tags: &mut [Tag]) -> bool {
+    fix_all(
+        tags.iter_mut()
+            .flat_map(|tag| std::iter::once(&mut tag.term).chain(tag.label.as_mut())),
+    )
+}
+
+/// Splits keyword-list terms and removes case-insensitive duplicates,
+/// see [`ParsedFeed::normalize_tags`]
+fn normalize_tag_list(tags: &mut Vec<Tag>) {
+    if tags.is_empty() {
+        return;
+    }
+    let mut kept: Vec<Tag> = Vec::with_capacity(tags.len());
+    let mut seen: HashMap<SmallString, usize> = HashMap::with_capacity(tags.len());
+    let mut add = |tag: Tag| match seen.entry(tag.term.to_lowercase()) {
+        Occupied(slot) => {
+            let first = &mut kept[*slot.get()];
+            if first.scheme.is_none() {
+                first.scheme = tag.scheme;
+            }
+            if first.label.is_none() {
+                first.label = tag.label;
+            }
+        }
+        Vacant(slot) => {
+            slot.insert(kept.len());
+            kept.push(tag);
+        }
+    };
+
+    for tag in tags.drain(..) {
+        if tag.term.contains([',', ';']) {
+            for term in tag.term.split([',', ';']).map(str::trim) {
+                if !term.is_empty() {
+                    add(Tag {
+                        term: term.into(),
+                        scheme: tag.scheme.clone(),
+                        label: None,
+                    });
+                }
+            }
+        } else {
+            let term = tag.term.trim();
+            if term.is_empty() {
+                continue;
+            }
+            if term.len() == tag.term.len() {
+                add(tag);
+            } else {
+                add(Tag {
+                    term: term.into(),
+                    ..tag
+                });
+            }
+        }
+    }
+    *tags = kept;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -443,4 +1066,65 @@ mod tests {
         assert_eq!(feed.version, FeedVersion::Rss20);
         assert!(feed.bozo);
     }
+
+    #[test]
+    fn test_entry_helpers() {
+        let feed = crate::parse(
+            br#"<rss version="2.0"><channel>
+            <item><guid>a</guid><category>Rust</category></item>
+            <item><guid>b</guid><pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate></item>
+            <item><guid>c</guid><pubDate>Wed, 03 Jan 2024 00:00:00 GMT</pubDate>
+                <category>rust</category></item>
+        </channel></rss>"#,
+        )
+        .unwrap();
+        let ids = |feed: &ParsedFeed| -> Vec<String> {
+            feed.entries
+                .iter()
+                .filter_map(|e| e.id.as_deref().map(str::to_string))
+                .collect()
+        };
+
+        assert_eq!(feed.filter_by_tag("RUST").count(), 2);
+
+        let mut sorted = feed.clone();
+        sorted.sort_entries_desc();
+        assert_eq!(ids(&sorted), ["c", "b", "a"]);
+        sorted.sort_entries_asc();
+        assert_eq!(ids(&sorted), ["b", "c", "a"]);
+
+        sorted
+            .retain_entries(|e| e.published.is_some())
+            .truncate_entries(1);
+        assert_eq!(ids(&sorted), ["b"]);
+
+        let since = sorted.entries[0].published.unwrap();
+        assert_eq!(feed.entries_since(since).count(), 2);
+    }
+
+    #[test]
+    fn test_normalize_tag_list() {
+        let mut tags = vec![
+            Tag::new("  Rust "),
+            Tag {
+                term: "rust;Web".into(),
+                scheme: Some("https://example.com/tags".into()),
+                label: Some("Rust and web".into()),
+            },
+            Tag {
+                term: "WEB".into(),
+                scheme: None,
+                label: Some("Web".into()),
+            },
+            Tag::new(" , ;"),
+            Tag::new("Émigré, ÉMIGRÉ"),
+        ];
+        normalize_tag_list(&mut tags);
+
+        let terms: Vec<&str> = tags.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(terms, ["Rust", "Web", "Émigré"]);
+        assert_eq!(tags[0].scheme.as_deref(), Some("https://example.com/tags"));
+        assert_eq!(tags[0].label, None);
+        assert_eq!(tags[1].label.as_deref(), Some("Web"));
+    }
 }
@@ -181,6 +181,10 @@ impl<T> LimitedCollectionExt<T> for Vec<T> {
 ///
 /// Implement this trait for structs that are parsed from XML element attributes,
 /// providing a consistent interface for attribute extraction with limit validation.
+/// The parsers pass at most [`ParserLimits::max_attributes_per_element`]
+/// attributes, so implementations can consume the iterator to the end.
+///
+/// [`ParserLimits::max_attributes_per_element`]: crate::ParserLimits::max_attributes_per_element
 pub trait FromAttributes: Sized {
     /// Parse from XML attributes with limit validation
     ///
@@ -0,0 +1,500 @@
+//! Approximate memory held by parsed values
+//!
+//! Backs [`ParserLimits::max_total_memory_bytes`](crate::ParserLimits::max_total_memory_bytes).
+//! Sizes are estimates: text counts at its length, lists at their capacity
+//! and boxed namespace extensions at their size plus their contents.
+
+use std::mem::size_of;
+
+use super::common::{
+    Content, Email, Enclosure, Generator, Image, LicenseInfo, Link, MediaContent, MediaThumbnail,
+    MimeType, Person, SmallString, Source, Tag, TextConstruct, Url,
+};
+use super::entry::Entry;
+use super::feed::FeedMeta;
+#[cfg(feature = "itunes")]
+use super::podcast::{ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner};
+#[cfg(feature = "podcast20")]
+use super::podcast::{
+    PodcastAlternateEnclosure, PodcastChapters, PodcastEntryMeta, PodcastFunding, PodcastMeta,
+    PodcastPerson, PodcastSoundbite, PodcastTranscript, PodcastValue, PodcastValueRecipient,
+};
+use crate::namespace::activity::{ActivityMeta, ActivityObject};
+use crate::namespace::blog_channel::BlogChannelMeta;
+use crate::namespace::gdata::{GDataMeta, GdExtendedProperty, GdRating, GdWhen, GdWhere};
+use crate::namespace::prism::PrismMeta;
+use crate::namespace::syndication::SyndicationMeta;
+
+/// Bytes a value owns on the heap, estimated
+pub trait HeapSize {
+    /// Heap bytes owned by `self`, not counting `size_of::<Self>()`
+    fn heap_size(&self) -> usize;
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for SmallString {
+    fn heap_size(&self) -> usize {
+        if self.is_heap_allocated() {
+            self.capacity()
+        } else {
+            0
+        }
+    }
+}
+
+impl HeapSize for Url {
+    fn heap_size(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl HeapSize for Email {
+    fn heap_size(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl HeapSize for MimeType {
+    fn heap_size(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        size_of::<T>() + (**self).heap_size()
+    }
+}
+
+impl HeapSize for TextConstruct {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size() + self.language.heap_size() + self.base.heap_size()
+    }
+}
+
+impl HeapSize for Link {
+    fn heap_size(&self) -> usize {
+        self.href.heap_size()
+            + self.rel.heap_size()
+            + self.title.heap_size()
+            + self.hreflang.heap_size()
+    }
+}
+
+impl HeapSize for Person {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.email.heap_size() + self.uri.heap_size()
+    }
+}
+
+impl HeapSize for Tag {
+    fn heap_size(&self) -> usize {
+        self.term.heap_size() + self.scheme.heap_size() + self.label.heap_size()
+    }
+}
+
+impl HeapSize for Image {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size()
+            + self.title.heap_size()
+            + self.link.heap_size()
+            + self.description.heap_size()
+    }
+}
+
+impl HeapSize for Enclosure {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size()
+    }
+}
+
+impl HeapSize for Content {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size() + self.language.heap_size() + self.base.heap_size()
+    }
+}
+
+impl HeapSize for Source {
+    fn heap_size(&self) -> usize {
+        self.title.heap_size() + self.link.heap_size() + self.id.heap_size()
+    }
+}
+
+impl HeapSize for LicenseInfo {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size() + self.name.heap_size() + self.spdx_guess.heap_size()
+    }
+}
+
+impl HeapSize for MediaThumbnail {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size() + self.time.heap_size()
+    }
+}
+
+impl HeapSize for MediaContent {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size() + self.thumbnails.heap_size()
+    }
+}
+
+impl HeapSize for Generator {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size() + self.uri.heap_size() + self.version.heap_size()
+    }
+}
+
+#[cfg(feature = "itunes")]
+impl HeapSize for ItunesOwner {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.email.heap_size()
+    }
+}
+
+#[cfg(feature = "itunes")]
+impl HeapSize for ItunesCategory {
+    fn heap_size(&self) -> usize {
+        self.text.heap_size() + self.subcategory.heap_size()
+    }
+}
+
+#[cfg(feature = "itunes")]
+impl HeapSize for ItunesFeedMeta {
+    fn heap_size(&self) -> usize {
+        self.author.heap_size()
+            + self.owner.heap_size()
+            + self.categories.heap_size()
+            + self.image.heap_size()
+            + self.keywords.heap_size()
+            + self.podcast_type.heap_size()
+            + self.new_feed_url.heap_size()
+    }
+}
+
+#[cfg(feature = "itunes")]
+impl HeapSize for ItunesEntryMeta {
+    fn heap_size(&self) -> usize {
+        self.title.heap_size()
+            + self.author.heap_size()
+            + self.image.heap_size()
+            + self.episode_type.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastTranscript {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size()
+            + self.transcript_type.heap_size()
+            + self.language.heap_size()
+            + self.rel.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastFunding {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size() + self.message.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastPerson {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+            + self.role.heap_size()
+            + self.group.heap_size()
+            + self.img.heap_size()
+            + self.href.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastValueRecipient {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.type_.heap_size() + self.address.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastValue {
+    fn heap_size(&self) -> usize {
+        self.type_.heap_size()
+            + self.method.heap_size()
+            + self.suggested.heap_size()
+            + self.recipients.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastMeta {
+    fn heap_size(&self) -> usize {
+        self.transcripts.heap_size()
+            + self.funding.heap_size()
+            + self.persons.heap_size()
+            + self.guid.heap_size()
+            + self.value.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastChapters {
+    fn heap_size(&self) -> usize {
+        self.url.heap_size() + self.type_.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastSoundbite {
+    fn heap_size(&self) -> usize {
+        self.title.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastAlternateEnclosure {
+    fn heap_size(&self) -> usize {
+        self.enclosure_type.heap_size()
+            + self.lang.heap_size()
+            + self.title.heap_size()
+            + self.sources.heap_size()
+    }
+}
+
+#[cfg(feature = "podcast20")]
+impl HeapSize for PodcastEntryMeta {
+    fn heap_size(&self) -> usize {
+        self.transcript.heap_size()
+            + self.chapters.heap_size()
+            + self.soundbite.heap_size()
+            + self.person.heap_size()
+            + self.alternate_enclosure.heap_size()
+    }
+}
+
+impl HeapSize for PrismMeta {
+    fn heap_size(&self) -> usize {
+        self.publication_name.heap_size()
+            + self.volume.heap_size()
+            + self.number.heap_size()
+            + self.starting_page.heap_size()
+            + self.doi.heap_size()
+    }
+}
+
+impl HeapSize for GdWhen {
+    fn heap_size(&self) -> usize {
+        self.value_string.heap_size()
+    }
+}
+
+impl HeapSize for GdWhere {
+    fn heap_size(&self) -> usize {
+        self.value_string.heap_size() + self.label.heap_size() + self.rel.heap_size()
+    }
+}
+
+impl HeapSize for GdRating {
+    fn heap_size(&self) -> usize {
+        self.rel.heap_size()
+    }
+}
+
+impl HeapSize for GdExtendedProperty {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.value.heap_size()
+    }
+}
+
+impl HeapSize for GDataMeta {
+    fn heap_size(&self) -> usize {
+        self.when.heap_size()
+            + self.locations.heap_size()
+            + self.rating.heap_size()
+            + self.extended_properties.heap_size()
+    }
+}
+
+impl HeapSize for ActivityObject {
+    fn heap_size(&self) -> usize {
+        self.id.heap_size()
+            + self.title.heap_size()
+            + self.link.heap_size()
+            + self.object_type.heap_size()
+            + self.content.heap_size()
+    }
+}
+
+impl HeapSize for ActivityMeta {
+    fn heap_size(&self) -> usize {
+        self.verb.heap_size() + self.object_type.heap_size() + self.object.heap_size()
+    }
+}
+
+#[cfg(feature = "georss")]
+impl HeapSize for crate::namespace::georss::GeoLocation {
+    fn heap_size(&self) -> usize {
+        self.coordinates.capacity() * size_of::<(f64, f64)>() + self.srs_name.heap_size()
+    }
+}
+
+impl HeapSize for SyndicationMeta {
+    fn heap_size(&self) -> usize {
+        self.update_base.heap_size()
+    }
+}
+
+impl HeapSize for BlogChannelMeta {
+    fn heap_size(&self) -> usize {
+        self.blog_roll.heap_size() + self.my_subscriptions.heap_size() + self.blink.heap_size()
+    }
+}
+
+impl HeapSize for FeedMeta {
+    fn heap_size(&self) -> usize {
+        let size = self.title.heap_size()
+            + self.title_detail.heap_size()
+            + self.link.heap_size()
+            + self.links.heap_size()
+            + self.subtitle.heap_size()
+            + self.subtitle_detail.heap_size()
+            + self.author.heap_size()
+            + self.author_detail.heap_size()
+            + self.authors.heap_size()
+            + self.managing_editor.heap_size()
+            + self.contributors.heap_size()
+            + self.publisher.heap_size()
+            + self.publisher_detail.heap_size()
+            + self.language.heap_size()
+            + self.rights.heap_size()
+            + self.rights_detail.heap_size()
+            + self.generator.heap_size()
+            + self.generator_detail.heap_size()
+            + self.image.heap_size()
+            + self.icon.heap_size()
+            + self.logo.heap_size()
+            + self.raw_icon.heap_size()
+            + self.raw_logo.heap_size()
+            + self.raw_image_url.heap_size()
+            + self.tags.heap_size()
+            + self.id.heap_size()
+            + self.license.heap_size()
+            + self.license_detail.heap_size()
+            + self.syndication.heap_size()
+            + self.blog_channel.heap_size();
+        #[cfg(feature = "itunes")]
+        let size = size + self.itunes.heap_size();
+        #[cfg(feature = "podcast20")]
+        let size = size + self.podcast.heap_size();
+        #[cfg(feature = "dublincore")]
+        let size = size
+            + self.dc_creator.heap_size()
+            + self.dc_publisher.heap_size()
+            + self.dc_rights.heap_size();
+        #[cfg(feature = "georss")]
+        let size = size + self.geo.heap_size();
+        size
+    }
+}
+
+impl HeapSize for Entry {
+    fn heap_size(&self) -> usize {
+        let size = self.id.heap_size()
+            + self.title.heap_size()
+            + self.title_detail.heap_size()
+            + self.link.heap_size()
+            + self.links.heap_size()
+            + self.summary.heap_size()
+            + self.summary_detail.heap_size()
+            + self.content.heap_size()
+            + self.author.heap_size()
+            + self.author_detail.heap_size()
+            + self.authors.heap_size()
+            + self.contributors.heap_size()
+            + self.publisher.heap_size()
+            + self.publisher_detail.heap_size()
+            + self.tags.heap_size()
+            + self.enclosures.heap_size()
+            + self.comments.heap_size()
+            + self.external_url.heap_size()
+            + self.banner_image.heap_size()
+            + self.source.heap_size()
+            + self.media_thumbnails.heap_size()
+            + self.media_content.heap_size()
+            + self.image.heap_size()
+            + self.license.heap_size()
+            + self.license_detail.heap_size()
+            + self.prism.heap_size()
+            + self.gdata.heap_size()
+            + self.activity.heap_size();
+        #[cfg(feature = "itunes")]
+        let size = size + self.itunes.heap_size();
+        #[cfg(feature = "dublincore")]
+        let size = size
+            + self.dc_creator.heap_size()
+            + self.dc_subject.heap_size()
+            + self.dc_rights.heap_size();
+        #[cfg(feature = "podcast20")]
+        let size = size
+            + self.podcast_transcripts.heap_size()
+            + self.podcast_persons.heap_size()
+            + self.podcast.heap_size();
+        #[cfg(feature = "georss")]
+        let size = size + self.geo.heap_size();
+        size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_heap_size() {
+        let mut entry = Entry::default();
+        let empty = entry.heap_size();
+
+        entry.summary = Some("x".repeat(1000));
+        entry.tags = vec![Tag::new("rust"); 4];
+        let size = entry.heap_size();
+        assert!(size >= empty + 1000 + 4 * size_of::<Tag>());
+        assert!(size < empty + 2000 + 4 * size_of::<Tag>());
+    }
+
+    #[test]
+    fn test_extension_heap_size() {
+        let mut entry = Entry::default();
+        let empty = entry.heap_size();
+
+        entry.gdata = Some(Box::new(GDataMeta {
+            extended_properties: vec![GdExtendedProperty {
+                name: "x".repeat(1000),
+                value: None,
+            }],
+            ..GDataMeta::default()
+        }));
+        assert!(entry.heap_size() >= empty + size_of::<GDataMeta>() + 1000);
+
+        let mut feed = FeedMeta::default();
+        let empty = feed.heap_size();
+        feed.subtitle = Some("x".repeat(1000));
+        assert!(feed.heap_size() >= empty + 1000);
+    }
+}
@@ -0,0 +1,513 @@
+//! Apple Podcasts category table
+//!
+//! Apple accepts only the categories listed at
+//! <https://podcasters.apple.com/support/1691-apple-podcasts-categories>.
+//! Feeds in the wild still use the pre-2019 list, entity-encode the
+//! ampersands twice, or vary the capitalization; [`ItunesCategory::normalized`]
+//! repairs all three and [`ItunesCategory::validate`] rejects what is left.
+
+use super::podcast::ItunesCategory;
+
+/// Current Apple Podcasts categories and their subcategories
+const CATEGORIES: &[(&str, &[&str])] = &[
+    (
+        "Arts",
+        &[
+            "Books",
+            "Design",
+            "Fashion & Beauty",
+            "Food",
+            "Performing Arts",
+            "Visual Arts",
+        ],
+    ),
+    (
+        "Business",
+        &[
+            "Careers",
+            "Entrepreneurship",
+            "Investing",
+            "Management",
+            "Marketing",
+            "Non-Profit",
+        ],
+    ),
+    ("Comedy", &["Comedy Interviews", "Improv", "Stand-Up"]),
+    (
+        "Education",
+        &["Courses", "How To", "Language Learning", "Self-Improvement"],
+    ),
+    ("Fiction", &["Comedy Fiction", "Drama", "Science Fiction"]),
+    ("Government", &[]),
+    ("History", &[]),
+    (
+        "Health & Fitness",
+        &[
+            "Alternative Health",
+            "Fitness",
+            "Medicine",
+            "Mental Health",
+            "Nutrition",
+            "Sexuality",
+        ],
+    ),
+    (
+        "Kids & Family",
+        &[
+            "Education for Kids",
+            "Parenting",
+            "Pets & Animals",
+            "Stories for Kids",
+        ],
+    ),
+    (
+        "Leisure",
+        &[
+            "Animation & Manga",
+            "Automotive",
+            "Aviation",
+            "Crafts",
+            "Games",
+            "Hobbies",
+            "Home & Garden",
+            "Video Games",
+        ],
+    ),
+    (
+        "Music",
+        &["Music Commentary", "Music History", "Music Interviews"],
+    ),
+    (
+        "News",
+        &[
+            "Business News",
+            "Daily News",
+            "Entertainment News",
+            "News Commentary",
+            "Politics",
+            "Sports News",
+            "Tech News",
+        ],
+    ),
+    (
+        "Religion & Spirituality",
+        &[
+            "Buddhism",
+            "Christianity",
+            "Hinduism",
+            "Islam",
+            "Judaism",
+            "Religion",
+            "Spirituality",
+        ],
+    ),
+    (
+        "Science",
+        &[
+            "Astronomy",
+            "Chemistry",
+            "Earth Sciences",
+            "Life Sciences",
+            "Mathematics",
+            "Natural Sciences",
+            "Nature",
+            "Physics",
+            "Social Sciences",
+        ],
+    ),
+    (
+        "Society & Culture",
+        &[
+            "Documentary",
+            "Personal Journals",
+            "Philosophy",
+            "Places & Travel",
+            "Relationships",
+        ],
+    ),
+    (
+        "Sports",
+        &[
+            "Baseball",
+            "Basketball",
+            "Cricket",
+            "Fantasy Sports",
+            "Football",
+            "Golf",
+            "Hockey",
+            "Rugby",
+            "Running",
+            "Soccer",
+            "Swimming",
+            "Tennis",
+            "Volleyball",
+            "Wilderness",
+            "Wrestling",
+        ],
+    ),
+    ("Technology", &[]),
+    ("True Crime", &[]),
+    (
+        "TV & Film",
+        &[
+            "After Shows",
+            "Film History",
+            "Film Interviews",
+            "Film Reviews",
+            "TV Reviews",
+        ],
+    ),
+];
+
+/// Pre-2019 categories and where Apple moved them
+///
+/// Each row is `(category, subcategory, new category, new subcategory)`.
+/// A `None` subcategory in the first half matches any subcategory without
+/// a row of its own.
+type Remap = (
+    &'static str,
+    Option<&'static str>,
+    &'static str,
+    Option<&'static str>,
+);
+
+const DEPRECATED: &[Remap] = &[
+    ("Arts", Some("Literature"), "Arts", Some("Books")),
+    (
+        "Business",
+        Some("Business News"),
+        "News",
+        Some("Business News"),
+    ),
+    (
+        "Business",
+        Some("Management & Marketing"),
+        "Business",
+        Some("Marketing"),
+    ),
+    ("Business", Some("Shopping"), "Business", None),
+    (
+        "Education",
+        Some("Educational Technology"),
+        "Education",
+        None,
+    ),
+    (
+        "Education",
+        Some("Higher Education"),
+        "Education",
+        Some("Courses"),
+    ),
+    ("Education", Some("K-12"), "Education", None),
+    (
+        "Education",
+        Some("Language Courses"),
+        "Education",
+        Some("Language Learning"),
+    ),
+    ("Education", Some("Training"), "Education", Some("How To")),
+    (
+        "Games & Hobbies",
+        Some("Automotive"),
+        "Leisure",
+        Some("Automotive"),
+    ),
+    (
+        "Games & Hobbies",
+        Some("Aviation"),
+        "Leisure",
+        Some("Aviation"),
+    ),
+    (
+        "Games & Hobbies",
+        Some("Hobbies"),
+        "Leisure",
+        Some("Hobbies"),
+    ),
+    (
+        "Games & Hobbies",
+        Some("Other Games"),
+        "Leisure",
+        Some("Games"),
+    ),
+    (
+        "Games & Hobbies",
+        Some("Video Games"),
+        "Leisure",
+        Some("Video Games"),
+    ),
+    ("Games & Hobbies", None, "Leisure", None),
+    (
+        "Government & Organizations",
+        Some("Non-Profit"),
+        "Business",
+        Some("Non-Profit"),
+    ),
+    ("Government & Organizations", None, "Government", None),
+    (
+        "Health",
+        Some("Fitness & Nutrition"),
+        "Health & Fitness",
+        Some("Fitness"),
+    ),
+    (
+        "Health",
+        Some("Self-Help"),
+        "Education",
+        Some("Self-Improvement"),
+    ),
+    (
+        "Health",
+        Some("Alternative Health"),
+        "Health & Fitness",
+        Some("Alternative Health"),
+    ),
+    (
+        "Health",
+        Some("Sexuality"),
+        "Health & Fitness",
+        Some("Sexuality"),
+    ),
+    ("Health", None, "Health & Fitness", None),
+    ("News & Politics", None, "News", Some("Politics")),
+    (
+        "Religion & Spirituality",
+        Some("Other"),
+        "Religion & Spirituality",
+        Some("Religion"),
+    ),
+    (
+        "Science & Medicine",
+        Some("Medicine"),
+        "Health & Fitness",
+        Some("Medicine"),
+    ),
+    (
+        "Science & Medicine",
+        Some("Natural Sciences"),
+        "Science",
+        Some("Natural Sciences"),
+    ),
+    (
+        "Science & Medicine",
+        Some("Social Sciences"),
+        "Science",
+        Some("Social Sciences"),
+    ),
+    ("Science & Medicine", None, "Science", None),
+    ("Society & Culture", Some("History"), "History", None),
+    (
+        "Sports & Recreation",
+        Some("Outdoor"),
+        "Sports",
+        Some("Wilderness"),
+    ),
+    ("Sports & Recreation", None, "Sports", None),
+    ("Technology", Some("Tech News"), "News", Some("Tech News")),
+    ("Technology", Some("Gadgets"), "Technology", None),
+    ("Technology", Some("Podcasting"), "Technology", None),
+    ("Technology", Some("Software How-To"), "Technology", None),
+];
+
+/// Reason an [`ItunesCategory`] is not on Apple's list
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidCategory {
+    /// The top-level category is unknown
+    #[error("Unknown Apple Podcasts category '{0}'")]
+    UnknownCategory(String),
+    /// The category is known but has no such subcategory
+    #[error("Unknown subcategory '{subcategory}' of Apple Podcasts category '{category}'")]
+    UnknownSubcategory {
+        /// Canonical category name
+        category: String,
+        /// Subcategory as found in the feed, after normalization
+        subcategory: String,
+    },
+}
+
+impl ItunesCategory {
+    /// Canonical form of this category on Apple's current list
+    ///
+    /// Decodes leftover HTML entities (`Kids &amp; Family`), collapses
+    /// whitespace, fixes capitalization and maps categories Apple retired in
+    /// 2019 to their replacements. Names that match nothing are returned
+    /// cleaned up but otherwise unchanged; use [`validate`](Self::validate)
+    /// to reject them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::ItunesCategory;
+    ///
+    /// let category = ItunesCategory {
+    ///     text: "Games &amp; Hobbies".to_string(),
+    ///     subcategory: Some("other games".to_string()),
+    /// };
+    /// let normalized = category.normalized();
+    /// assert_eq!(normalized.text, "Leisure");
+    /// assert_eq!(normalized.subcategory.as_deref(), Some("Games"));
+    /// ```
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let text = clean(&self.text);
+        let subcategory = self
+            .subcategory
+            .as_deref()
+            .map(clean)
+            .filter(|s| !s.is_empty());
+
+        let remap = DEPRECATED.iter().find(|(old, old_sub, _, _)| {
+            old.eq_ignore_ascii_case(&text)
+                && old_sub.is_none_or(|old_sub| {
+                    subcategory
+                        .as_deref()
+                        .is_some_and(|s| old_sub.eq_ignore_ascii_case(s))
+                })
+        });
+        if let Some((_, old_sub, new, new_sub)) = remap {
+            let subcategory = match (old_sub, new_sub) {
+                (_, Some(new_sub)) => Some((*new_sub).to_string()),
+                // A catch-all row keeps subcategories that still exist
+                (None, None) => subcategory.map(|s| canonical_subcategory(new, &s).unwrap_or(s)),
+                (Some(_), None) => None,
+            };
+            return Self {
+                text: (*new).to_string(),
+                subcategory,
+            };
+        }
+
+        match CATEGORIES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&text))
+        {
+            Some((name, _)) => Self {
+                text: (*name).to_string(),
+                subcategory: subcategory.map(|s| canonical_subcategory(name, &s).unwrap_or(s)),
+            },
+            None => Self { text, subcategory },
+        }
+    }
+
+    /// Normalizes this category and checks it against Apple's list
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidCategory`] if the category, or its subcategory, is
+    /// still unknown after [`normalized`](Self::normalized).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{InvalidCategory, ItunesCategory};
+    ///
+    /// let category = ItunesCategory {
+    ///     text: "technology".to_string(),
+    ///     subcategory: None,
+    /// };
+    /// assert_eq!(category.validate().unwrap().text, "Technology");
+    ///
+    /// let category = ItunesCategory {
+    ///     text: "Podcasts About Podcasts".to_string(),
+    ///     subcategory: None,
+    /// };
+    /// assert!(matches!(category.validate(), Err(InvalidCategory::UnknownCategory(_))));
+    /// ```
+    pub fn validate(&self) -> Result<Self, InvalidCategory> {
+        let normalized = self.normalized();
+        let Some((_, subcategories)) = CATEGORIES.iter().find(|(name, _)| *name == normalized.text)
+        else {
+            return Err(InvalidCategory::UnknownCategory(normalized.text));
+        };
+        if let Some(subcategory) = &normalized.subcategory
+            && !subcategories.contains(&subcategory.as_str())
+        {
+            return Err(InvalidCategory::UnknownSubcategory {
+                category: normalized.text,
+                subcategory: subcategory.clone(),
+            });
+        }
+        Ok(normalized)
+    }
+}
+
+/// Decodes entities and collapses runs of whitespace
+fn clean(name: &str) -> String {
+    html_escape::decode_html_entities(name)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn canonical_subcategory(category: &str, subcategory: &str) -> Option<String> {
+    CATEGORIES
+        .iter()
+        .find(|(name, _)| *name == category)?
+        .1
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case(subcategory))
+        .map(|name| (*name).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(text: &str, subcategory: Option<&str>) -> ItunesCategory {
+        ItunesCategory {
+            text: text.to_string(),
+            subcategory: subcategory.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_normalized_cleans_names() {
+        let normalized = category(" kids &amp; family ", Some("PARENTING")).normalized();
+        assert_eq!(normalized, category("Kids & Family", Some("Parenting")));
+
+        let normalized = category("TV  &  Film", Some("")).normalized();
+        assert_eq!(normalized, category("TV & Film", None));
+    }
+
+    #[test]
+    fn test_normalized_maps_deprecated() {
+        assert_eq!(
+            category("Health", Some("Self-Help")).normalized(),
+            category("Education", Some("Self-Improvement"))
+        );
+        assert_eq!(
+            category("Society & Culture", Some("History")).normalized(),
+            category("History", None)
+        );
+        assert_eq!(
+            category("Science & Medicine", Some("nature")).normalized(),
+            category("Science", Some("Nature"))
+        );
+        assert_eq!(
+            category("News & Politics", None).normalized(),
+            category("News", Some("Politics"))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(category("Comedy", Some("Stand-Up")).validate().is_ok());
+        assert_eq!(
+            category("Comedy", Some("Sketch")).validate(),
+            Err(InvalidCategory::UnknownSubcategory {
+                category: "Comedy".to_string(),
+                subcategory: "Sketch".to_string(),
+            })
+        );
+        assert_eq!(
+            category("Gardening", None).validate(),
+            Err(InvalidCategory::UnknownCategory("Gardening".to_string()))
+        );
+
+        // Every row of the deprecation table lands on the current list
+        for (old, old_sub, _, _) in DEPRECATED {
+            let result = category(old, *old_sub).validate();
+            assert!(result.is_ok(), "{old} / {old_sub:?}: {result:?}");
+        }
+    }
+}
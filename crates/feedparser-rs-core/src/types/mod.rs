@@ -2,19 +2,29 @@ mod common;
 mod entry;
 mod feed;
 pub mod generics;
+mod heap;
+#[cfg(feature = "itunes")]
+mod itunes_category;
 mod podcast;
+mod stats;
 mod version;
 
 pub use common::{
-    Content, Email, Enclosure, Generator, Image, Link, MediaContent, MediaThumbnail, MimeType,
-    Person, SmallString, Source, Tag, TextConstruct, TextType, Url,
+    AuthorSource, Content, Email, Enclosure, Generator, Image, LicenseInfo, Link, MediaContent,
+    MediaThumbnail, MimeType, Person, SmallString, Source, Tag, TextConstruct, TextType, Url,
 };
-pub use entry::Entry;
+pub use entry::{Entry, ImageSource};
 pub use feed::{FeedMeta, ParsedFeed};
 pub use generics::{FromAttributes, LimitedCollectionExt, ParseFrom};
+#[cfg(feature = "itunes")]
+pub use itunes_category::InvalidCategory;
+#[cfg(feature = "itunes")]
+pub use podcast::{ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner};
+#[cfg(feature = "podcast20")]
 pub use podcast::{
-    ItunesCategory, ItunesEntryMeta, ItunesFeedMeta, ItunesOwner, PodcastChapters,
-    PodcastEntryMeta, PodcastFunding, PodcastMeta, PodcastPerson, PodcastSoundbite,
-    PodcastTranscript, PodcastValue, PodcastValueRecipient, parse_duration, parse_explicit,
+    PodcastAlternateEnclosure, PodcastChapters, PodcastEntryMeta, PodcastFunding, PodcastMeta,
+    PodcastPerson, PodcastSoundbite, PodcastTranscript, PodcastValue, PodcastValueRecipient,
 };
-pub use version::FeedVersion;
+pub use podcast::{parse_duration, parse_explicit, podcast_guid_for};
+pub use stats::ParseStats;
+pub use version::{FeedFamily, FeedVersion, ParseFeedVersionError};
@@ -1,4 +1,8 @@
-use super::common::{MimeType, Url};
+#[cfg(feature = "podcast20")]
+use super::common::MimeType;
+#[cfg(any(feature = "itunes", feature = "podcast20"))]
+use super::common::Url;
+use uuid::Uuid;
 
 /// iTunes podcast metadata for feeds
 ///
@@ -17,7 +21,9 @@ use super::common::{MimeType, Url};
 ///
 /// assert_eq!(itunes.author.as_deref(), Some("John Doe"));
 /// ```
-#[derive(Debug, Clone, Default)]
+#[cfg(feature = "itunes")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItunesFeedMeta {
     /// Podcast author (itunes:author)
     pub author: Option<String>,
@@ -66,7 +72,9 @@ pub struct ItunesFeedMeta {
 ///
 /// assert_eq!(episode.duration, Some(3600));
 /// ```
-#[derive(Debug, Clone, Default)]
+#[cfg(feature = "itunes")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItunesEntryMeta {
     /// Episode title override (itunes:title)
     pub title: Option<String>,
@@ -104,7 +112,9 @@ pub struct ItunesEntryMeta {
 ///
 /// assert_eq!(owner.name.as_deref(), Some("Jane Doe"));
 /// ```
-#[derive(Debug, Clone, Default)]
+#[cfg(feature = "itunes")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItunesOwner {
     /// Owner's name (itunes:name)
     pub name: Option<String>,
@@ -128,7 +138,9 @@ pub struct ItunesOwner {
 ///
 /// assert_eq!(category.text, "Technology");
 /// ```
-#[derive(Debug, Clone)]
+#[cfg(feature = "itunes")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItunesCategory {
     /// Category name (text attribute)
     pub text: String,
@@ -150,7 +162,9 @@ pub struct ItunesCategory {
 ///
 /// assert!(podcast.guid.is_some());
 /// ```
-#[derive(Debug, Clone, Default)]
+#[cfg(feature = "podcast20")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastMeta {
     /// Transcript URLs (podcast:transcript)
     pub transcripts: Vec<PodcastTranscript>,
@@ -201,7 +215,9 @@ pub struct PodcastMeta {
 /// assert_eq!(value.type_, "lightning");
 /// assert_eq!(value.recipients.len(), 2);
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastValue {
     /// Payment type (type attribute): "lightning", "hive", etc.
     pub type_: String,
@@ -236,7 +252,9 @@ pub struct PodcastValue {
 /// assert_eq!(recipient.split, 95);
 /// assert_eq!(recipient.fee, Some(false));
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastValueRecipient {
     /// Recipient's name (name attribute)
     pub name: Option<String>,
@@ -281,7 +299,9 @@ pub struct PodcastValueRecipient {
 ///
 /// assert_eq!(transcript.url, "https://example.com/transcript.txt");
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastTranscript {
     /// Transcript URL (url attribute)
     ///
@@ -314,7 +334,9 @@ pub struct PodcastTranscript {
 ///
 /// assert_eq!(funding.url, "https://example.com/donate");
 /// ```
-#[derive(Debug, Clone)]
+#[cfg(feature = "podcast20")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastFunding {
     /// Funding URL (url attribute)
     ///
@@ -347,7 +369,9 @@ pub struct PodcastFunding {
 /// assert_eq!(host.name, "John Doe");
 /// assert_eq!(host.role.as_deref(), Some("host"));
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastPerson {
     /// Person's name (text content)
     pub name: String,
@@ -388,7 +412,9 @@ pub struct PodcastPerson {
 ///
 /// assert_eq!(chapters.url, "https://example.com/chapters.json");
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastChapters {
     /// Chapters file URL (url attribute)
     ///
@@ -420,7 +446,9 @@ pub struct PodcastChapters {
 /// assert_eq!(soundbite.start_time, 120.5);
 /// assert_eq!(soundbite.duration, 30.0);
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub struct PodcastSoundbite {
     /// Start time in seconds (startTime attribute)
@@ -431,6 +459,52 @@ pub struct PodcastSoundbite {
     pub title: Option<String>,
 }
 
+/// Podcast 2.0 alternate enclosure
+///
+/// Another encoding or quality of the episode media, such as a lower
+/// bitrate or video version, reachable through one or more sources.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::PodcastAlternateEnclosure;
+///
+/// let alternate = PodcastAlternateEnclosure {
+///     enclosure_type: "audio/opus".into(),
+///     bitrate: Some(64_000),
+///     sources: vec!["https://example.com/ep1.opus".into()],
+///     ..PodcastAlternateEnclosure::default()
+/// };
+///
+/// assert_eq!(alternate.sources[0], "https://example.com/ep1.opus");
+/// ```
+#[cfg(feature = "podcast20")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PodcastAlternateEnclosure {
+    /// MIME type (type attribute)
+    pub enclosure_type: MimeType,
+    /// File size in bytes (length attribute)
+    pub length: Option<u64>,
+    /// Encoding bitrate in bits per second (bitrate attribute)
+    pub bitrate: Option<u64>,
+    /// Video height in pixels (height attribute)
+    pub height: Option<u32>,
+    /// Language code (lang attribute)
+    pub lang: Option<String>,
+    /// Short human-readable label (title attribute)
+    pub title: Option<String>,
+    /// Whether this is the same media as the main enclosure (default attribute)
+    pub default: bool,
+    /// Media URIs, in feed order (podcast:source uri attributes)
+    ///
+    /// # Security Warning
+    ///
+    /// These URLs come from untrusted feed input and have NOT been validated for SSRF.
+    /// Applications MUST validate URLs before fetching to prevent SSRF attacks.
+    pub sources: Vec<Url>,
+}
+
 /// Podcast 2.0 metadata for episodes
 ///
 /// Container for entry-level podcast metadata.
@@ -445,7 +519,9 @@ pub struct PodcastSoundbite {
 /// assert!(podcast.chapters.is_none());
 /// assert!(podcast.soundbite.is_empty());
 /// ```
+#[cfg(feature = "podcast20")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PodcastEntryMeta {
     /// Transcript URLs (podcast:transcript)
     pub transcript: Vec<PodcastTranscript>,
@@ -455,6 +531,8 @@ pub struct PodcastEntryMeta {
     pub soundbite: Vec<PodcastSoundbite>,
     /// People associated with this episode (podcast:person)
     pub person: Vec<PodcastPerson>,
+    /// Other encodings of the episode media (podcast:alternateEnclosure)
+    pub alternate_enclosure: Vec<PodcastAlternateEnclosure>,
 }
 
 /// Parse duration from various iTunes duration formats
@@ -463,6 +541,16 @@ pub struct PodcastEntryMeta {
 /// - Seconds only: "3600" → 3600 seconds
 /// - MM:SS format: "60:30" → 3630 seconds
 /// - HH:MM:SS format: "1:00:30" → 3630 seconds
+/// - ISO 8601 durations: "PT1H30M" → 5400 seconds, "P1DT2H" → 93600 seconds
+///
+/// Any component may carry a fraction with either `.` or `,` as the decimal
+/// separator ("90.5", "1:30,25", "PT1.5H"). Minute and second values above 59
+/// are accepted as-is ("1:75" → 135), since many feeds emit them.
+///
+/// Fractions are rounded to the nearest whole second, with halves rounded up;
+/// digits beyond milliseconds are ignored. ISO 8601 year and month
+/// designators are rejected because their length in seconds is ambiguous.
+/// Returns `None` for negative, malformed or overflowing values.
 ///
 /// # Arguments
 ///
@@ -477,6 +565,8 @@ pub struct PodcastEntryMeta {
 /// assert_eq!(parse_duration("60:30"), Some(3630));
 /// assert_eq!(parse_duration("1:00:30"), Some(3630));
 /// assert_eq!(parse_duration("1:30"), Some(90));
+/// assert_eq!(parse_duration("PT1H30M"), Some(5400));
+/// assert_eq!(parse_duration("12:30.5"), Some(751));
 /// assert_eq!(parse_duration("invalid"), None);
 /// ```
 pub fn parse_duration(s: &str) -> Option<u32> {
@@ -487,25 +577,115 @@ pub fn parse_duration(s: &str) -> Option<u32> {
         return Some(secs);
     }
 
+    let millis = if s.starts_with(['P', 'p']) {
+        parse_iso8601_duration(&s[1..])?
+    } else {
+        parse_clock_duration(s)?
+    };
+    u32::try_from(millis.checked_add(500)? / 1000).ok()
+}
+
+/// Parses `[[HH:]MM:]SS` into milliseconds
+fn parse_clock_duration(s: &str) -> Option<u64> {
     // Parse HH:MM:SS or MM:SS format using iterator pattern matching
     let mut parts = s.split(':');
-    match (parts.next(), parts.next(), parts.next(), parts.next()) {
-        (Some(first), None, None, None) => first.parse().ok(),
-        (Some(min), Some(sec), None, None) => {
-            // MM:SS
-            let min = min.parse::<u32>().ok()?;
-            let sec = sec.parse::<u32>().ok()?;
-            Some(min * 60 + sec)
-        }
-        (Some(hr), Some(min), Some(sec), None) => {
-            // HH:MM:SS
-            let hr = hr.parse::<u32>().ok()?;
-            let min = min.parse::<u32>().ok()?;
-            let sec = sec.parse::<u32>().ok()?;
-            Some(hr * 3600 + min * 60 + sec)
-        }
-        _ => None,
+    let (hr, min, sec) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(sec), None, None, None) => ("0", "0", sec),
+        (Some(min), Some(sec), None, None) => ("0", min, sec),
+        (Some(hr), Some(min), Some(sec), None) => (hr, min, sec),
+        _ => return None,
+    };
+    let hr = parse_millis(hr)?.checked_mul(3600)?;
+    let min = parse_millis(min)?.checked_mul(60)?;
+    hr.checked_add(min)?.checked_add(parse_millis(sec)?)
+}
+
+/// Parses the part of an ISO 8601 duration after the leading `P`
+/// into milliseconds
+fn parse_iso8601_duration(s: &str) -> Option<u64> {
+    let mut total: u64 = 0;
+    let mut in_time = false;
+    let mut any = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        let unit = match c.to_ascii_uppercase() {
+            'T' if !in_time && start == i => {
+                in_time = true;
+                start = i + 1;
+                continue;
+            }
+            'W' if !in_time => 7 * 86_400,
+            'D' if !in_time => 86_400,
+            'H' if in_time => 3600,
+            'M' if in_time => 60,
+            'S' if in_time => 1,
+            '0'..='9' | '.' | ',' => continue,
+            _ => return None,
+        };
+        let value = parse_millis(&s[start..i])?.checked_mul(unit)?;
+        total = total.checked_add(value)?;
+        any = true;
+        start = i + 1;
+    }
+    (any && start == s.len()).then_some(total)
+}
+
+/// Parses a non-negative decimal number into thousandths
+fn parse_millis(s: &str) -> Option<u64> {
+    let (whole, fraction) = s.split_once(['.', ',']).unwrap_or((s, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return None;
     }
+    if !whole
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<u64>().ok()?
+    };
+    let millis = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(3)
+        .fold(0, |acc, b| acc * 10 + u64::from(b - b'0'));
+    whole.checked_mul(1000)?.checked_add(millis)
+}
+
+/// UUID namespace of the `podcast:guid` algorithm
+const PODCAST_GUID_NAMESPACE: Uuid = Uuid::from_u128(0xead4_c236_bf58_58c6_a2c6_a6b2_8d12_8cb6);
+
+/// Compute the Podcast Index GUID (`podcast:guid`) for a feed URL
+///
+/// Implements the algorithm from the Podcast 2.0 namespace: the URL's
+/// scheme and any trailing slashes are removed, and the rest is hashed
+/// into a version 5 UUID under the namespace `ead4c236-bf58-58c6-a2c6-a6b28d128cb6`.
+/// Use it for feeds that do not declare a `podcast:guid` of their own.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::podcast_guid_for;
+///
+/// assert_eq!(
+///     podcast_guid_for("https://mp3s.nashownotes.com/pc20rss.xml"),
+///     "917393e3-1b1e-5cef-ace4-edaa54e1f810"
+/// );
+/// assert_eq!(
+///     podcast_guid_for("http://mp3s.nashownotes.com/pc20rss.xml/"),
+///     podcast_guid_for("https://mp3s.nashownotes.com/pc20rss.xml")
+/// );
+/// ```
+#[must_use]
+pub fn podcast_guid_for(feed_url: &str) -> String {
+    let url = feed_url.trim();
+    let url = url.find("://").map_or(url, |i| &url[i + 3..]);
+    let url = url.trim_end_matches('/');
+    Uuid::new_v5(&PODCAST_GUID_NAMESPACE, url.as_bytes()).to_string()
 }
 
 /// Parse iTunes explicit flag from various string representations
@@ -587,6 +767,43 @@ mod tests {
         assert_eq!(parse_duration("  1:30:00  "), Some(5400));
     }
 
+    #[test]
+    fn test_parse_duration_iso8601() {
+        assert_eq!(parse_duration("PT1H30M"), Some(5400));
+        assert_eq!(parse_duration("PT45S"), Some(45));
+        assert_eq!(parse_duration("P1DT2H"), Some(93_600));
+        assert_eq!(parse_duration("P1W"), Some(604_800));
+        assert_eq!(parse_duration("pt2m5s"), Some(125));
+        assert_eq!(parse_duration("PT1.5H"), Some(5400));
+        assert_eq!(parse_duration("PT0,5S"), Some(1));
+        assert_eq!(parse_duration("P"), None);
+        assert_eq!(parse_duration("PT"), None);
+        assert_eq!(parse_duration("P1Y"), None);
+        assert_eq!(parse_duration("P1M"), None);
+        assert_eq!(parse_duration("PT1H30"), None);
+        assert_eq!(parse_duration("PT1D"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_fractional() {
+        assert_eq!(parse_duration("90.4"), Some(90));
+        assert_eq!(parse_duration("90.5"), Some(91));
+        assert_eq!(parse_duration("90,5"), Some(91));
+        assert_eq!(parse_duration("1:30.75"), Some(91));
+        assert_eq!(parse_duration("0:00:00.4999"), Some(0));
+        assert_eq!(parse_duration(".5"), Some(1));
+        assert_eq!(parse_duration("1.2.3"), None);
+        assert_eq!(parse_duration("-5"), None);
+        assert_eq!(parse_duration("1e3"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_out_of_range_fields() {
+        assert_eq!(parse_duration("1:75"), Some(135));
+        assert_eq!(parse_duration("00:90:00"), Some(5400));
+        assert_eq!(parse_duration("99999999:00:00"), None);
+    }
+
     #[test]
     fn test_parse_duration_invalid() {
         assert_eq!(parse_duration("invalid"), None);
@@ -631,6 +848,7 @@ mod tests {
         assert_eq!(parse_explicit("1"), None);
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_itunes_feed_meta_default() {
         let meta = ItunesFeedMeta::default();
@@ -645,6 +863,7 @@ mod tests {
         assert!(meta.new_feed_url.is_none());
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_itunes_entry_meta_default() {
         let meta = ItunesEntryMeta::default();
@@ -658,6 +877,7 @@ mod tests {
         assert!(meta.episode_type.is_none());
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_itunes_owner_default() {
         let owner = ItunesOwner::default();
@@ -665,6 +885,7 @@ mod tests {
         assert!(owner.email.is_none());
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_itunes_category_clone() {
@@ -677,6 +898,7 @@ mod tests {
         assert_eq!(cloned.subcategory.as_deref(), Some("Software"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_meta_default() {
         let meta = PodcastMeta::default();
@@ -686,6 +908,7 @@ mod tests {
         assert!(meta.guid.is_none());
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_transcript_clone() {
@@ -700,6 +923,7 @@ mod tests {
         assert_eq!(cloned.transcript_type.as_deref(), Some("text/plain"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_funding_clone() {
@@ -712,6 +936,7 @@ mod tests {
         assert_eq!(cloned.message.as_deref(), Some("Support us!"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_person_clone() {
@@ -727,6 +952,7 @@ mod tests {
         assert_eq!(cloned.role.as_deref(), Some("host"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_chapters_default() {
         let chapters = PodcastChapters::default();
@@ -734,6 +960,7 @@ mod tests {
         assert!(chapters.type_.is_empty());
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_chapters_clone() {
@@ -746,6 +973,7 @@ mod tests {
         assert_eq!(cloned.type_, "application/json+chapters");
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_soundbite_default() {
         let soundbite = PodcastSoundbite::default();
@@ -754,6 +982,7 @@ mod tests {
         assert!(soundbite.title.is_none());
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_soundbite_clone() {
@@ -768,6 +997,7 @@ mod tests {
         assert_eq!(cloned.title.as_deref(), Some("Great quote"));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_entry_meta_default() {
         let meta = PodcastEntryMeta::default();
@@ -777,6 +1007,7 @@ mod tests {
         assert!(meta.person.is_empty());
     }
 
+    #[cfg(feature = "itunes")]
     #[test]
     fn test_itunes_feed_meta_new_fields() {
         let meta = ItunesFeedMeta {
@@ -792,6 +1023,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_default() {
         let value = PodcastValue::default();
@@ -801,6 +1033,7 @@ mod tests {
         assert!(value.recipients.is_empty());
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_lightning() {
         let value = PodcastValue {
@@ -835,6 +1068,7 @@ mod tests {
         assert_eq!(value.recipients[1].split, 10);
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_recipient_default() {
         let recipient = PodcastValueRecipient::default();
@@ -845,6 +1079,7 @@ mod tests {
         assert!(recipient.fee.is_none());
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_recipient_with_fee() {
         let recipient = PodcastValueRecipient {
@@ -861,6 +1096,7 @@ mod tests {
         assert_eq!(recipient.fee, Some(true));
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_recipient_without_name() {
         let recipient = PodcastValueRecipient {
@@ -876,6 +1112,7 @@ mod tests {
         assert_eq!(recipient.split, 100);
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_multiple_recipients() {
         let mut value = PodcastValue {
@@ -900,6 +1137,7 @@ mod tests {
         assert_eq!(value.recipients.iter().map(|r| r.split).sum::<u32>(), 100);
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_value_hive() {
         let value = PodcastValue {
@@ -919,6 +1157,7 @@ mod tests {
         assert_eq!(value.method, "direct");
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     fn test_podcast_meta_with_value() {
         let mut meta = PodcastMeta::default();
@@ -935,6 +1174,7 @@ mod tests {
         assert_eq!(meta.value.as_ref().unwrap().type_, "lightning");
     }
 
+    #[cfg(feature = "podcast20")]
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_podcast_value_clone() {
@@ -956,4 +1196,18 @@ mod tests {
         assert_eq!(cloned.recipients.len(), 1);
         assert_eq!(cloned.recipients[0].name.as_deref(), Some("Host"));
     }
+
+    #[test]
+    fn test_podcast_guid_for_normalizes_url() {
+        let guid = podcast_guid_for("mp3s.nashownotes.com/pc20rss.xml");
+        assert_eq!(guid, "917393e3-1b1e-5cef-ace4-edaa54e1f810");
+        assert_eq!(
+            podcast_guid_for(" https://mp3s.nashownotes.com/pc20rss.xml// "),
+            guid
+        );
+        assert_ne!(
+            podcast_guid_for("https://mp3s.nashownotes.com/other.xml"),
+            guid
+        );
+    }
 }
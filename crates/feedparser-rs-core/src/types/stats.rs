@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// Statistics gathered while parsing a feed
+///
+/// Populated on [`ParsedFeed::stats`](super::ParsedFeed::stats) when
+/// [`ParseOptions::collect_stats`](crate::ParseOptions::collect_stats) is
+/// enabled. Useful for monitoring dashboards and for tuning
+/// [`ParserLimits`](crate::ParserLimits) against real traffic.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::{ParseOptions, parse_with_options};
+///
+/// let xml = br#"<rss version="2.0"><channel><item><title>A</title></item></channel></rss>"#;
+/// let options = ParseOptions {
+///     collect_stats: true,
+///     ..ParseOptions::default()
+/// };
+///
+/// let feed = parse_with_options(xml, &options).unwrap();
+/// let stats = feed.stats.unwrap();
+/// assert_eq!(stats.bytes_read, xml.len());
+/// assert_eq!(stats.elements_seen, 4);
+/// assert_eq!(stats.entries_parsed, 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseStats {
+    /// Size of the input document in bytes
+    pub bytes_read: usize,
+    /// XML elements read by the parser, or JSON values for JSON Feed
+    pub elements_seen: usize,
+    /// Entries kept in [`ParsedFeed::entries`](super::ParsedFeed::entries)
+    pub entries_parsed: usize,
+    /// Entries read by the parser but dropped, e.g. over `max_entries`
+    pub entries_skipped: usize,
+    /// Wall-clock time spent detecting, parsing and sanitizing
+    pub parse_duration: Duration,
+    /// HTML fields rewritten by the sanitizer
+    pub sanitized_fields: usize,
+}
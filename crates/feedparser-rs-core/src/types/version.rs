@@ -1,28 +1,40 @@
 use std::fmt;
+use std::str::FromStr;
 
 /// Feed format version
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeedVersion {
     /// RSS 0.90
+    #[cfg_attr(feature = "serde", serde(rename = "rss090"))]
     Rss090,
     /// RSS 0.91
+    #[cfg_attr(feature = "serde", serde(rename = "rss091"))]
     Rss091,
     /// RSS 0.92
+    #[cfg_attr(feature = "serde", serde(rename = "rss092"))]
     Rss092,
     /// RSS 1.0 (RDF)
+    #[cfg_attr(feature = "serde", serde(rename = "rss10"))]
     Rss10,
     /// RSS 2.0
+    #[cfg_attr(feature = "serde", serde(rename = "rss20"))]
     Rss20,
     /// Atom 0.3
+    #[cfg_attr(feature = "serde", serde(rename = "atom03"))]
     Atom03,
     /// Atom 1.0
+    #[cfg_attr(feature = "serde", serde(rename = "atom10"))]
     Atom10,
     /// JSON Feed 1.0
+    #[cfg_attr(feature = "serde", serde(rename = "json10"))]
     JsonFeed10,
     /// JSON Feed 1.1
+    #[cfg_attr(feature = "serde", serde(rename = "json11"))]
     JsonFeed11,
     /// Unknown format
     #[default]
+    #[cfg_attr(feature = "serde", serde(rename = ""))]
     Unknown,
 }
 
@@ -52,6 +64,125 @@ impl FeedVersion {
             Self::Unknown => "",
         }
     }
+
+    /// Returns the format family of this version
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::{FeedFamily, FeedVersion};
+    ///
+    /// assert_eq!(FeedVersion::Rss10.family(), FeedFamily::Rss);
+    /// assert_eq!(FeedVersion::JsonFeed11.family(), FeedFamily::Json);
+    /// ```
+    #[must_use]
+    pub const fn family(&self) -> FeedFamily {
+        match self {
+            Self::Rss090 | Self::Rss091 | Self::Rss092 | Self::Rss10 | Self::Rss20 => {
+                FeedFamily::Rss
+            }
+            Self::Atom03 | Self::Atom10 => FeedFamily::Atom,
+            Self::JsonFeed10 | Self::JsonFeed11 => FeedFamily::Json,
+            Self::Unknown => FeedFamily::Unknown,
+        }
+    }
+
+    /// Returns the MIME type a server should use for this version
+    ///
+    /// RSS 1.0 is RDF and maps to `application/rdf+xml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::FeedVersion;
+    ///
+    /// assert_eq!(FeedVersion::Atom10.content_type(), Some("application/atom+xml"));
+    /// assert_eq!(FeedVersion::Unknown.content_type(), None);
+    /// ```
+    #[must_use]
+    pub const fn content_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Rss10 => Some("application/rdf+xml"),
+            Self::Rss090 | Self::Rss091 | Self::Rss092 | Self::Rss20 => Some("application/rss+xml"),
+            Self::Atom03 | Self::Atom10 => Some("application/atom+xml"),
+            Self::JsonFeed10 | Self::JsonFeed11 => Some("application/feed+json"),
+            Self::Unknown => None,
+        }
+    }
+}
+
+impl FromStr for FeedVersion {
+    type Err = ParseFeedVersionError;
+
+    /// Parses the feedparser-compatible string produced by [`FeedVersion::as_str`]
+    ///
+    /// The empty string parses as [`FeedVersion::Unknown`], so every version
+    /// round-trips through its string form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::FeedVersion;
+    ///
+    /// assert_eq!("atom10".parse(), Ok(FeedVersion::Atom10));
+    /// assert_eq!("".parse(), Ok(FeedVersion::Unknown));
+    /// assert!("rss30".parse::<FeedVersion>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "rss090" => Self::Rss090,
+            "rss091" => Self::Rss091,
+            "rss092" => Self::Rss092,
+            "rss10" => Self::Rss10,
+            "rss20" => Self::Rss20,
+            "atom03" => Self::Atom03,
+            "atom10" => Self::Atom10,
+            "json10" => Self::JsonFeed10,
+            "json11" => Self::JsonFeed11,
+            "" => Self::Unknown,
+            _ => return Err(ParseFeedVersionError(s.to_string())),
+        })
+    }
+}
+
+/// Error returned when a string is not a feedparser version identifier
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown feed version: {0:?}")]
+pub struct ParseFeedVersionError(String);
+
+/// Format family of a [`FeedVersion`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum FeedFamily {
+    /// RSS 0.9x, 1.0 (RDF) and 2.0
+    Rss,
+    /// Atom 0.3 and 1.0
+    Atom,
+    /// JSON Feed
+    Json,
+    /// Unrecognized format
+    #[default]
+    Unknown,
+}
+
+impl FeedFamily {
+    /// Returns the lowercase name of the family
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rss => "rss",
+            Self::Atom => "atom",
+            Self::Json => "json",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for FeedFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl fmt::Display for FeedVersion {
@@ -77,9 +208,50 @@ mod tests {
         assert_eq!(format!("{}", FeedVersion::Atom10), "atom10");
     }
 
+    #[test]
+    fn test_version_from_str_round_trips() {
+        for version in [
+            FeedVersion::Rss090,
+            FeedVersion::Rss091,
+            FeedVersion::Rss092,
+            FeedVersion::Rss10,
+            FeedVersion::Rss20,
+            FeedVersion::Atom03,
+            FeedVersion::Atom10,
+            FeedVersion::JsonFeed10,
+            FeedVersion::JsonFeed11,
+            FeedVersion::Unknown,
+        ] {
+            assert_eq!(version.as_str().parse(), Ok(version));
+        }
+    }
+
+    #[test]
+    fn test_version_from_str_rejects_unknown() {
+        let err = "2.0".parse::<FeedVersion>().unwrap_err();
+        assert_eq!(err.to_string(), r#"Unknown feed version: "2.0""#);
+        assert!("RSS20".parse::<FeedVersion>().is_err());
+    }
+
     #[test]
     fn test_version_default() {
         let v: FeedVersion = FeedVersion::default();
         assert_eq!(v, FeedVersion::Unknown);
     }
+
+    #[test]
+    fn test_family_and_content_type() {
+        assert_eq!(FeedVersion::Rss091.family(), FeedFamily::Rss);
+        assert_eq!(FeedVersion::Atom03.family(), FeedFamily::Atom);
+        assert_eq!(FeedVersion::Unknown.family(), FeedFamily::Unknown);
+        assert_eq!(FeedFamily::Json.to_string(), "json");
+        assert_eq!(
+            FeedVersion::Rss20.content_type(),
+            Some("application/rss+xml")
+        );
+        assert_eq!(
+            FeedVersion::JsonFeed10.content_type(),
+            Some("application/feed+json")
+        );
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides URL resolution following RFC 3986, supporting
 //! the `xml:base` attribute used in Atom and some RSS feeds.
 
+use crate::types::ParsedFeed;
 use std::net::IpAddr;
 use url::Url;
 
@@ -14,6 +15,11 @@ use url::Url;
 /// - Private IP ranges (192.168.x.x, 10.x.x.x, 172.16-31.x.x)
 /// - Cloud metadata endpoints (169.254.169.254)
 ///
+/// Hosts are checked in their ASCII form: internationalized names are
+/// punycoded and full-width or percent-encoded spellings are mapped first,
+/// and a trailing dot is ignored, so `http://ｌｏｃａｌｈｏｓｔ/` and
+/// `http://localhost./` are rejected like `http://localhost/`.
+///
 /// # Arguments
 ///
 /// * `url` - The URL to validate
@@ -53,8 +59,11 @@ pub fn is_safe_url(url: &str) -> bool {
     if let Some(host) = parsed.host() {
         match host {
             url::Host::Domain(domain) => {
-                // Reject localhost domain
-                if domain == "localhost" {
+                // A trailing dot names the same host in absolute form
+                let domain = domain.trim_end_matches('.');
+
+                // Reject localhost and its subdomains (RFC 6761)
+                if domain == "localhost" || domain.ends_with(".localhost") {
                     return false;
                 }
 
@@ -139,6 +148,12 @@ fn is_private_ip(ip: &IpAddr) -> bool {
 ///
 /// // Without a base, relative URLs are returned unchanged
 /// assert_eq!(resolve_url("page.html", None), "page.html");
+///
+/// // Internationalized hosts keep the form the base was written in
+/// assert_eq!(
+///     resolve_url("page.html", Some("http://bücher.example/")),
+///     "http://bücher.example/page.html"
+/// );
 /// ```
 #[must_use]
 pub fn resolve_url(href: &str, base: Option<&str>) -> String {
@@ -162,9 +177,19 @@ pub fn resolve_url(href: &str, base: Option<&str>) -> String {
     };
 
     // Resolve href against base
-    base_url
-        .join(href)
-        .map_or_else(|_| href.to_string(), |resolved| resolved.to_string())
+    let Ok(resolved) = base_url.join(href) else {
+        return href.to_string();
+    };
+
+    // Parsing punycodes the host; show it as the feed wrote it
+    let written_in_unicode = base_url
+        .host_str()
+        .is_some_and(|host| host.contains("xn--") && !base_str.contains(host));
+    if written_in_unicode {
+        crate::util::url::to_unicode(resolved.as_str())
+    } else {
+        resolved.into()
+    }
 }
 
 /// Combines two base URLs, with child overriding parent
@@ -227,13 +252,18 @@ pub fn combine_bases(parent_base: Option<&str>, child_base: Option<&str>) -> Opt
 pub struct BaseUrlContext {
     /// The current effective base URL
     base: Option<String>,
+    /// When true, relative URLs are returned unchanged
+    keep_relative: bool,
 }
 
 impl BaseUrlContext {
     /// Creates a new context with no base URL
     #[must_use]
     pub const fn new() -> Self {
-        Self { base: None }
+        Self {
+            base: None,
+            keep_relative: false,
+        }
     }
 
     /// Creates a new context with an initial base URL
@@ -241,9 +271,30 @@ impl BaseUrlContext {
     pub fn with_base(base: impl Into<String>) -> Self {
         Self {
             base: Some(base.into()),
+            keep_relative: false,
         }
     }
 
+    /// Enables or disables resolution of relative URLs (builder pattern)
+    ///
+    /// With resolution disabled, [`resolve`](Self::resolve) and
+    /// [`resolve_safe`](Self::resolve_safe) leave relative URLs as written;
+    /// `xml:base` values are still tracked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::util::base_url::BaseUrlContext;
+    ///
+    /// let ctx = BaseUrlContext::with_base("http://example.com/").with_resolution(false);
+    /// assert_eq!(ctx.resolve("page.html"), "page.html");
+    /// ```
+    #[must_use]
+    pub const fn with_resolution(mut self, resolve: bool) -> Self {
+        self.keep_relative = !resolve;
+        self
+    }
+
     /// Gets the current base URL
     #[must_use]
     pub fn base(&self) -> Option<&str> {
@@ -261,6 +312,9 @@ impl BaseUrlContext {
     /// Resolves a URL against the current base
     #[must_use]
     pub fn resolve(&self, href: &str) -> String {
+        if self.keep_relative {
+            return href.to_string();
+        }
         resolve_url(href, self.base.as_deref())
     }
 
@@ -345,6 +399,7 @@ impl BaseUrlContext {
     pub fn child(&self) -> Self {
         Self {
             base: self.base.clone(),
+            keep_relative: self.keep_relative,
         }
     }
 
@@ -352,7 +407,66 @@ impl BaseUrlContext {
     #[must_use]
     pub fn child_with_base(&self, xml_base: &str) -> Self {
         let new_base = combine_bases(self.base.as_deref(), Some(xml_base));
-        Self { base: new_base }
+        Self {
+            base: new_base,
+            keep_relative: self.keep_relative,
+        }
+    }
+}
+
+/// Resolves the relative URLs of a parsed feed against `ctx`
+///
+/// For formats whose parsers do not track a base URL themselves (RSS 1.0
+/// and JSON Feed), this applies a document base after the fact. Covers
+/// feed and entry links, the feed image, icon and logo, and enclosures.
+/// Absolute URLs are left as they are.
+pub fn resolve_feed_urls(feed: &mut ParsedFeed, ctx: &BaseUrlContext) {
+    if ctx.base().is_none() {
+        return;
+    }
+    let resolve = |url: &str| (Url::parse(url).is_err()).then(|| ctx.resolve_safe(url));
+
+    let meta = &mut feed.feed;
+    for url in [&mut meta.link, &mut meta.icon, &mut meta.logo]
+        .into_iter()
+        .flatten()
+    {
+        if let Some(resolved) = resolve(url) {
+            *url = resolved;
+        }
+    }
+    for link in &mut meta.links {
+        if let Some(resolved) = resolve(&link.href) {
+            link.href = resolved.into();
+        }
+    }
+    if let Some(image) = &mut meta.image {
+        if let Some(resolved) = resolve(&image.url) {
+            image.url = resolved.into();
+        }
+        if let Some(link) = &mut image.link
+            && let Some(resolved) = resolve(link)
+        {
+            *link = resolved;
+        }
+    }
+
+    for entry in &mut feed.entries {
+        if let Some(link) = &mut entry.link
+            && let Some(resolved) = resolve(link)
+        {
+            *link = resolved;
+        }
+        for link in &mut entry.links {
+            if let Some(resolved) = resolve(&link.href) {
+                link.href = resolved.into();
+            }
+        }
+        for enclosure in &mut entry.enclosures {
+            if let Some(resolved) = resolve(&enclosure.url) {
+                enclosure.url = resolved.into();
+            }
+        }
     }
 }
 
@@ -522,6 +636,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_idn_base() {
+        assert_eq!(
+            resolve_url("/a", Some("https://münchen.example/feed")),
+            "https://münchen.example/a"
+        );
+        assert_eq!(
+            resolve_url("/a", Some("https://xn--mnchen-3ya.example/feed")),
+            "https://xn--mnchen-3ya.example/a"
+        );
+    }
+
     // SSRF Protection Tests
     #[test]
     fn test_is_safe_url_file_scheme() {
@@ -537,6 +663,16 @@ mod tests {
         assert!(!is_safe_url("https://localhost:8080/api"));
     }
 
+    #[test]
+    fn test_is_safe_url_localhost_spellings() {
+        assert!(!is_safe_url("http://localhost./"));
+        assert!(!is_safe_url("http://app.localhost/"));
+        assert!(!is_safe_url("http://ｌｏｃａｌｈｏｓｔ/"));
+        assert!(!is_safe_url("http://%6cocalhost/"));
+        assert!(!is_safe_url("http://metadata.google.internal./"));
+        assert!(is_safe_url("http://bücher.example/"));
+    }
+
     #[test]
     fn test_is_safe_url_private_ip() {
         // 192.168.x.x range
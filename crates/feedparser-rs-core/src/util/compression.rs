@@ -0,0 +1,154 @@
+//! Transparent decompression of gzip and zstd input
+//!
+//! Feeds read from crawl archives or caches are often stored compressed.
+//! [`decompress`] recognizes the gzip and zstd magic bytes and inflates the
+//! data so it can be parsed like any other document.
+
+use std::borrow::Cow;
+use std::io::Read;
+
+use crate::error::{FeedError, Result};
+
+/// Compression formats recognized by [`detect_compression`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// gzip (RFC 1952)
+    Gzip,
+    /// Zstandard (RFC 8878)
+    Zstd,
+}
+
+impl Compression {
+    /// Lowercase name, as used in error messages
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// Detects compressed input from its magic bytes
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::compression::{Compression, detect_compression};
+///
+/// assert_eq!(detect_compression(&[0x1f, 0x8b, 0x08]), Some(Compression::Gzip));
+/// assert_eq!(detect_compression(b"<rss/>"), None);
+/// ```
+#[must_use]
+pub fn detect_compression(data: &[u8]) -> Option<Compression> {
+    if data.starts_with(&[0x1f, 0x8b]) {
+        Some(Compression::Gzip)
+    } else if data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Some(Compression::Zstd)
+    } else {
+        None
+    }
+}
+
+/// Decompresses gzip or zstd input, passing anything else through
+///
+/// Uncompressed data is returned borrowed. Decompression stops as soon as
+/// the output would exceed `max_size` bytes, so a small "zip bomb" cannot
+/// exhaust memory.
+///
+/// # Errors
+///
+/// Returns `FeedError::InvalidFormat` if the compressed stream is corrupt
+/// or inflates to more than `max_size` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::compression::decompress;
+///
+/// let plain = b"<rss version=\"2.0\"/>";
+/// assert_eq!(decompress(plain, 1024).unwrap().as_ref(), plain);
+/// ```
+pub fn decompress(data: &[u8], max_size: usize) -> Result<Cow<'_, [u8]>> {
+    let Some(compression) = detect_compression(data) else {
+        return Ok(Cow::Borrowed(data));
+    };
+
+    let invalid = |e: &dyn std::fmt::Display| {
+        FeedError::InvalidFormat(format!(
+            "Failed to decompress {} input: {e}",
+            compression.as_str()
+        ))
+    };
+
+    let mut output = Vec::new();
+    // One byte over the limit tells an exact fit from an overflow
+    let limit = u64::try_from(max_size)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    match compression {
+        Compression::Gzip => flate2::read::MultiGzDecoder::new(data)
+            .take(limit)
+            .read_to_end(&mut output)
+            .map_err(|e| invalid(&e))?,
+        Compression::Zstd => ruzstd::decoding::StreamingDecoder::new(data)
+            .map_err(|e| invalid(&e))?
+            .take(limit)
+            .read_to_end(&mut output)
+            .map_err(|e| invalid(&e))?,
+    };
+
+    if output.len() > max_size {
+        return Err(FeedError::InvalidFormat(format!(
+            "Decompressed {} input exceeds maximum size of {max_size} bytes",
+            compression.as_str()
+        )));
+    }
+    Ok(Cow::Owned(output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_decompress_gzip() {
+        let xml = b"<rss version=\"2.0\"><channel/></rss>";
+        let compressed = gzip(xml);
+        let output = decompress(&compressed, 1024).unwrap();
+        assert!(matches!(output, Cow::Owned(_)));
+        assert_eq!(output.as_ref(), xml);
+    }
+
+    #[test]
+    fn test_decompress_zstd() {
+        let xml = b"<rss version=\"2.0\"><channel/></rss>";
+        let compressed = ruzstd::encoding::compress_to_vec(
+            &xml[..],
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+        assert_eq!(detect_compression(&compressed), Some(Compression::Zstd));
+        assert_eq!(decompress(&compressed, 1024).unwrap().as_ref(), xml);
+    }
+
+    #[test]
+    fn test_decompress_enforces_limit() {
+        let compressed = gzip(&[b' '; 4096]);
+        assert!(decompress(&compressed, 4096).is_ok());
+        let err = decompress(&compressed, 4095).unwrap_err();
+        assert!(err.to_string().contains("exceeds maximum size"));
+    }
+
+    #[test]
+    fn test_decompress_corrupt_input() {
+        let err = decompress(&[0x1f, 0x8b, 0x08, 0x00, 0xff], 1024).unwrap_err();
+        assert!(err.to_string().contains("Failed to decompress gzip input"));
+    }
+}
@@ -1,6 +1,8 @@
 //! Multi-format date parsing for RSS and Atom feeds
 
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use crate::options::FutureDatePolicy;
+use crate::types::ParsedFeed;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
 
 /// Date format strings to try, in priority order
 ///
@@ -129,6 +131,113 @@ pub fn parse_date(input: &str) -> Option<DateTime<Utc>> {
     None
 }
 
+/// Dates less than this far ahead are taken as clock skew, not future dates
+const FUTURE_DATE_TOLERANCE: TimeDelta = TimeDelta::days(1);
+
+/// Applies [`ParseOptions::clamp_future_dates`](crate::ParseOptions::clamp_future_dates)
+///
+/// Checks the `published` and `updated` dates of the feed and its entries
+/// against `now` and returns how many were in the future. Any hit flags the
+/// feed as `bozo`, keeping an earlier `bozo_exception` if there is one.
+pub(crate) fn apply_future_date_policy(
+    feed: &mut ParsedFeed,
+    policy: FutureDatePolicy,
+    now: DateTime<Utc>,
+) -> usize {
+    if policy == FutureDatePolicy::Off {
+        return 0;
+    }
+
+    let limit = now + FUTURE_DATE_TOLERANCE;
+    let mut found = 0;
+    let mut check = |date: &mut Option<DateTime<Utc>>| {
+        if date.is_some_and(|d| d > limit) {
+            found += 1;
+            if policy == FutureDatePolicy::Clamp {
+                *date = Some(now);
+            }
+        }
+    };
+
+    check(&mut feed.feed.published);
+    check(&mut feed.feed.updated);
+    for entry in &mut feed.entries {
+        check(&mut entry.published);
+        check(&mut entry.updated);
+    }
+
+    if found > 0 {
+        feed.bozo = true;
+        feed.bozo_exception
+            .get_or_insert_with(|| format!("Future dates found: {found}"));
+    }
+    found
+}
+
+/// Conversion of parsed dates to the `time` crate
+///
+/// Dates in parse results are `chrono::DateTime<Utc>`. With the `time`
+/// feature this trait converts a date, or an optional one, to
+/// `time::OffsetDateTime` in UTC. Dates outside the range `time` supports
+/// (years -9999 to 9999) convert to `None`.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::{ToOffsetDateTime, parse};
+///
+/// let feed = parse(br#"<rss version="2.0"><channel><item>
+///     <pubDate>Sat, 14 Dec 2024 10:30:00 +0100</pubDate>
+/// </item></channel></rss>"#).unwrap();
+///
+/// let published = feed.entries[0].published.to_offset_date_time().unwrap();
+/// assert_eq!(published.year(), 2024);
+/// assert_eq!(published.hour(), 9);
+/// ```
+#[cfg(feature = "time")]
+pub trait ToOffsetDateTime {
+    /// Converts to `time::OffsetDateTime`
+    fn to_offset_date_time(&self) -> Option<time::OffsetDateTime>;
+}
+
+#[cfg(feature = "time")]
+impl ToOffsetDateTime for DateTime<Utc> {
+    fn to_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        let nanos = i128::from(self.timestamp()) * 1_000_000_000
+            + i128::from(self.timestamp_subsec_nanos());
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToOffsetDateTime for Option<DateTime<Utc>> {
+    fn to_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        self.as_ref()?.to_offset_date_time()
+    }
+}
+
+/// Converts a `time::OffsetDateTime` to the chrono type used in parse results
+///
+/// Useful when building a [`ParsedFeed`] to serialize from data kept in
+/// `time` types. The offset is normalized to UTC.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::date::from_offset_date_time;
+///
+/// let date = time::OffsetDateTime::from_unix_timestamp(1_734_172_200).unwrap();
+/// assert_eq!(
+///     from_offset_date_time(date).unwrap().to_rfc3339(),
+///     "2024-12-14T10:30:00+00:00"
+/// );
+/// ```
+#[cfg(feature = "time")]
+#[must_use]
+pub const fn from_offset_date_time(date: time::OffsetDateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(date.unix_timestamp(), date.nanosecond())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,4 +444,65 @@ mod tests {
             assert_eq!(dt.day(), day, "Day mismatch for: {date_str}");
         }
     }
+
+    #[test]
+    fn test_future_date_policy() {
+        let now = parse_date("2024-06-01T00:00:00Z").unwrap();
+        let far = parse_date("2030-01-01T00:00:00Z");
+        let skewed = parse_date("2024-06-01T06:00:00Z");
+        let mut feed = ParsedFeed::new();
+        feed.feed.updated = far;
+        feed.entries.push(crate::Entry {
+            published: skewed,
+            updated: far,
+            ..crate::Entry::default()
+        });
+
+        let mut off = feed.clone();
+        assert_eq!(
+            apply_future_date_policy(&mut off, FutureDatePolicy::Off, now),
+            0
+        );
+        assert!(!off.bozo);
+
+        let mut warn = feed.clone();
+        assert_eq!(
+            apply_future_date_policy(&mut warn, FutureDatePolicy::Warn, now),
+            2
+        );
+        assert!(warn.bozo);
+        assert_eq!(
+            warn.bozo_exception.as_deref(),
+            Some("Future dates found: 2")
+        );
+        assert_eq!(warn.feed.updated, far);
+
+        apply_future_date_policy(&mut feed, FutureDatePolicy::Clamp, now);
+        assert_eq!(feed.feed.updated, Some(now));
+        assert_eq!(feed.entries[0].published, skewed);
+        assert_eq!(feed.entries[0].updated, Some(now));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_offset_date_time_round_trip() {
+        let date = parse_date("2024-12-14T10:30:45.123456789+02:00").unwrap();
+        let converted = date.to_offset_date_time().unwrap();
+        assert_eq!(converted.unix_timestamp(), date.timestamp());
+        assert_eq!(converted.nanosecond(), 123_456_789);
+        assert_eq!(converted.offset(), time::UtcOffset::UTC);
+        assert_eq!(from_offset_date_time(converted), Some(date));
+
+        let before_epoch = parse_date("1969-12-31T23:59:59.5Z").unwrap();
+        let converted = before_epoch.to_offset_date_time().unwrap();
+        assert_eq!(from_offset_date_time(converted), Some(before_epoch));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_offset_date_time_out_of_range() {
+        let far = DateTime::<Utc>::MAX_UTC;
+        assert_eq!(far.to_offset_date_time(), None);
+        assert_eq!(None::<DateTime<Utc>>.to_offset_date_time(), None);
+    }
 }
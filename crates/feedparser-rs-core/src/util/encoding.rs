@@ -10,6 +10,7 @@
 //! 4. Default to UTF-8
 
 use encoding_rs::{Encoding, UTF_8};
+use memchr::{memchr, memmem};
 
 /// Detect character encoding from byte data
 ///
@@ -61,16 +62,14 @@ fn extract_xml_encoding(data: &[u8]) -> Option<&'static str> {
     let search_len = data.len().min(512);
     let search_data = &data[..search_len];
 
-    if let Ok(header) = std::str::from_utf8(search_data)
-        && let Some(enc_start) = header.find("encoding=")
-    {
-        let after_eq = &header[enc_start + 9..];
-        let quote = after_eq.chars().next()?;
-        if quote == '"' || quote == '\'' {
-            let quote_end = after_eq[1..].find(quote)?;
-            let encoding_name = &after_eq[1..=quote_end];
-            return normalize_encoding_name(encoding_name);
-        }
+    // Searched as bytes so non-UTF-8 text after the declaration doesn't matter
+    let enc_start = memmem::find(search_data, b"encoding=")?;
+    let after_eq = &search_data[enc_start + 9..];
+    let quote = *after_eq.first()?;
+    if quote == b'"' || quote == b'\'' {
+        let quote_end = memchr(quote, &after_eq[1..])?;
+        let encoding_name = std::str::from_utf8(&after_eq[1..=quote_end]).ok()?;
+        return normalize_encoding_name(encoding_name);
     }
 
     None
@@ -325,6 +324,12 @@ mod tests {
         assert_eq!(detect_encoding(data), "UTF-8");
     }
 
+    #[test]
+    fn test_detect_from_xml_declaration_before_non_utf8() {
+        let data = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><title>caf\xE9</title>";
+        assert_eq!(detect_encoding(data), "windows-1252");
+    }
+
     #[test]
     fn test_detect_default_utf8() {
         let data = b"<?xml version=\"1.0\"?>";
@@ -0,0 +1,264 @@
+//! Entity and character reference decoding for element text and attributes
+//!
+//! Feeds are full of numeric character references that XML does not allow:
+//! `&#0;`, lone UTF-16 surrogates such as `&#xD83D;` written by JavaScript
+//! escapers, code points past U+10FFFF, or `&#150;` meaning the Windows-1252
+//! dash. XML parsers reject these outright. Like HTML parsers and Python
+//! feedparser, this module decodes them to a best-effort character instead,
+//! and the parser flags the feed as bozo.
+//!
+//! Element text and attribute values share these rules, so a reference
+//! decodes the same way wherever it appears.
+
+use memchr::{memchr, memchr2, memmem};
+use std::borrow::Cow;
+
+/// Longest reference body considered, e.g. `#x0010FFFF` or `thetasym`
+const MAX_REFERENCE_LEN: usize = 32;
+
+/// Characters for 0x80–0x9F, the C1 controls that Windows-1252 text
+/// mislabeled as Latin-1 puts in numeric references
+///
+/// Unassigned slots (0x81, 0x8D, 0x8F, 0x90, 0x9D) keep their code point.
+pub(crate) const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+/// Character a numeric character reference stands for
+///
+/// `reference` is the text between `&` and `;`, such as `#233` or `#x263A`.
+/// Returns `None` if it is not a well-formed numeric reference. Code points
+/// XML does not allow are mapped the way HTML parsers map them:
+///
+/// - NUL, surrogates and values above U+10FFFF become U+FFFD. This includes
+///   both halves of a surrogate pair written as two references.
+/// - 0x80–0x9F become the Windows-1252 characters they were meant to be.
+/// - Other control characters and noncharacters are kept.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::entities::decode_char_ref;
+///
+/// assert_eq!(decode_char_ref("#233"), Some('é'));
+/// assert_eq!(decode_char_ref("#x263a"), Some('☺'));
+/// assert_eq!(decode_char_ref("#150"), Some('–'));
+/// assert_eq!(decode_char_ref("#xD83D"), Some('\u{FFFD}'));
+/// assert_eq!(decode_char_ref("#x110000"), Some('\u{FFFD}'));
+/// assert_eq!(decode_char_ref("#xZZ"), None);
+/// assert_eq!(decode_char_ref("amp"), None);
+/// ```
+#[must_use]
+pub fn decode_char_ref(reference: &str) -> Option<char> {
+    code_point(reference).map(|code| map_code_point(code).0)
+}
+
+/// Code point of a numeric reference, saturating at `u32::MAX`
+fn code_point(reference: &str) -> Option<u32> {
+    let digits = reference.strip_prefix('#')?;
+    let (digits, radix) = digits
+        .strip_prefix(['x', 'X'])
+        .map_or((digits, 10), |hex| (hex, 16));
+    if digits.is_empty() {
+        return None;
+    }
+    digits.chars().try_fold(0u32, |code, c| {
+        let digit = c.to_digit(radix)?;
+        Some(code.saturating_mul(radix).saturating_add(digit))
+    })
+}
+
+/// Character for a code point and whether XML allows it as written
+fn map_code_point(code: u32) -> (char, bool) {
+    match code {
+        0x9 | 0xA | 0xD | 0x20..=0x7F | 0xA0..=0xD7FF | 0xE000..=0xFFFD | 0x1_0000..=0x10_FFFF => (
+            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+            true,
+        ),
+        0x80..=0x9F => (WINDOWS_1252[(code - 0x80) as usize], false),
+        0 | 0xD800..=0xDFFF | 0x11_0000.. => (char::REPLACEMENT_CHARACTER, false),
+        _ => (
+            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+            false,
+        ),
+    }
+}
+
+/// Expansion of an entity or character reference body, if it is decoded
+///
+/// The five predefined XML entities and numeric references are expanded;
+/// anything else, such as an undeclared HTML entity like `nbsp`, is not.
+pub(crate) fn expand_reference(reference: &str) -> Option<Cow<'static, str>> {
+    if let Some(value) = quick_xml::escape::resolve_predefined_entity(reference) {
+        return Some(Cow::Borrowed(value));
+    }
+    decode_char_ref(reference).map(|c| Cow::Owned(c.to_string()))
+}
+
+/// Attribute value with its entity and character references expanded
+///
+/// References decode as in element text: predefined entities and numeric
+/// references (see [`decode_char_ref`]) are expanded, and undeclared
+/// entities like `&nbsp;` or a bare `&` are kept verbatim rather than
+/// making the attribute unreadable. Invalid UTF-8 is replaced.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::entities::decode_attribute;
+///
+/// assert_eq!(decode_attribute(b"a?b=1&amp;c=2"), "a?b=1&c=2");
+/// assert_eq!(decode_attribute(b"caf&#xE9; &nbsp;&#0;"), "caf\u{e9} &nbsp;\u{FFFD}");
+/// assert_eq!(decode_attribute(b"AT&T"), "AT&T");
+/// ```
+#[must_use]
+pub fn decode_attribute(value: &[u8]) -> String {
+    let value = String::from_utf8_lossy(value);
+    if memchr(b'&', value.as_bytes()).is_none() {
+        return value.into_owned();
+    }
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value.as_ref();
+    while let Some(amp) = memchr(b'&', rest.as_bytes()) {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp + 1..];
+        let expanded = reference_body(rest)
+            .and_then(|body| expand_reference(body).map(|value| (value, body.len() + 1)));
+        match expanded {
+            Some((value, len)) => {
+                out.push_str(&value);
+                rest = &rest[len..];
+            }
+            None => out.push('&'),
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Text up to the `;` closing a reference, if there is one nearby
+fn reference_body(s: &str) -> Option<&str> {
+    let end = memchr(b';', &s.as_bytes()[..s.len().min(MAX_REFERENCE_LEN + 1)])?;
+    let body = &s[..end];
+    body.bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'#' | b'_' | b'-' | b'.' | b':'))
+        .then_some(body)
+}
+
+/// First numeric character reference in an XML document that XML forbids
+///
+/// Reports references that are malformed (`&#;`, `&#xZZ;`) or name a
+/// character outside the XML character range, which [`decode_char_ref`]
+/// replaced or remapped. References inside CDATA sections and comments are
+/// literal text and are skipped. Returns the reference as written.
+pub(crate) fn find_invalid_char_ref(data: &[u8]) -> Option<&str> {
+    let mut pos = 0;
+    while let Some(offset) = memchr2(b'&', b'<', &data[pos..]) {
+        let at = pos + offset;
+        let rest = &data[at..];
+        if rest[0] == b'<' {
+            pos = at + 1;
+            let skipped = if rest.starts_with(b"<![CDATA[") {
+                memmem::find(rest, b"]]>")
+            } else if rest.starts_with(b"<!--") {
+                memmem::find(rest, b"-->")
+            } else {
+                continue;
+            };
+            match skipped {
+                Some(end) => pos = at + end + 3,
+                None => return None,
+            }
+            continue;
+        }
+
+        pos = at + 1;
+        if rest.get(1) != Some(&b'#') {
+            continue;
+        }
+        let window = &rest[1..rest.len().min(MAX_REFERENCE_LEN + 2)];
+        let Some(end) = memchr(b';', window) else {
+            continue;
+        };
+        let Ok(body) = std::str::from_utf8(&window[..end]) else {
+            continue;
+        };
+        if code_point(body).is_none_or(|code| !map_code_point(code).1) {
+            return std::str::from_utf8(&rest[..end + 2]).ok();
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_char_ref_valid() {
+        assert_eq!(decode_char_ref("#65"), Some('A'));
+        assert_eq!(decode_char_ref("#x41"), Some('A'));
+        assert_eq!(decode_char_ref("#X41"), Some('A'));
+        assert_eq!(decode_char_ref("#0000233"), Some('é'));
+        assert_eq!(decode_char_ref("#x1F600"), Some('😀'));
+        assert_eq!(decode_char_ref("#x10FFFF"), Some('\u{10FFFF}'));
+    }
+
+    #[test]
+    fn test_decode_char_ref_html_mapping() {
+        assert_eq!(decode_char_ref("#0"), Some('\u{FFFD}'));
+        assert_eq!(decode_char_ref("#xD800"), Some('\u{FFFD}'));
+        assert_eq!(decode_char_ref("#xDFFF"), Some('\u{FFFD}'));
+        assert_eq!(decode_char_ref("#x110000"), Some('\u{FFFD}'));
+        assert_eq!(decode_char_ref("#99999999999999999999"), Some('\u{FFFD}'));
+        assert_eq!(decode_char_ref("#128"), Some('€'));
+        assert_eq!(decode_char_ref("#146"), Some('\u{2019}'));
+        assert_eq!(decode_char_ref("#x81"), Some('\u{81}'));
+        assert_eq!(decode_char_ref("#1"), Some('\u{1}'));
+    }
+
+    #[test]
+    fn test_decode_char_ref_malformed() {
+        assert_eq!(decode_char_ref("#"), None);
+        assert_eq!(decode_char_ref("#x"), None);
+        assert_eq!(decode_char_ref("#12a"), None);
+        assert_eq!(decode_char_ref("#-1"), None);
+        assert_eq!(decode_char_ref("x41"), None);
+    }
+
+    #[test]
+    fn test_decode_attribute() {
+        assert_eq!(decode_attribute(b"plain"), "plain");
+        assert_eq!(
+            decode_attribute(b"&lt;b&gt; &quot;q&quot; &apos;"),
+            "<b> \"q\" '"
+        );
+        assert_eq!(decode_attribute(b"&amp;amp;"), "&amp;");
+        assert_eq!(decode_attribute(b"&#xD83D;&#xDE00;"), "\u{FFFD}\u{FFFD}");
+        assert_eq!(decode_attribute(b"&#150; &#x;"), "\u{2013} &#x;");
+        assert_eq!(decode_attribute(b"a & b &c"), "a & b &c");
+        assert_eq!(decode_attribute(b"trailing&"), "trailing&");
+    }
+
+    #[test]
+    fn test_find_invalid_char_ref() {
+        assert_eq!(find_invalid_char_ref(b"<t>&#233; &amp; &nbsp;</t>"), None);
+        assert_eq!(find_invalid_char_ref(b"<t>x&#0;</t>"), Some("&#0;"));
+        assert_eq!(
+            find_invalid_char_ref(b"<t a=\"&#xD800;\">ok</t>"),
+            Some("&#xD800;")
+        );
+        assert_eq!(find_invalid_char_ref(b"<t>&#xZZ;</t>"), Some("&#xZZ;"));
+        assert_eq!(find_invalid_char_ref(b"<t>&#150;</t>"), Some("&#150;"));
+        assert_eq!(
+            find_invalid_char_ref(b"<t><![CDATA[&#0;]]><!-- &#0; --></t>"),
+            None
+        );
+        assert_eq!(find_invalid_char_ref(b"<t><![CDATA[&#0;</t>"), None);
+        assert_eq!(find_invalid_char_ref(b"&#"), None);
+    }
+}
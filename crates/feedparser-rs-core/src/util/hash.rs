@@ -0,0 +1,35 @@
+//! Stable, non-cryptographic hashing
+
+/// 64-bit FNV-1a, used where a hash must be stable across builds
+pub struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    pub const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    /// Hash a kind tag followed by `text` with whitespace runs collapsed
+    pub fn write_normalized(&mut self, kind: u8, text: &str) {
+        self.write(&[kind]);
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 {
+                self.write(b" ");
+            }
+            self.write(word.as_bytes());
+        }
+    }
+
+    pub const fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,450 @@
+//! Language utilities
+//!
+//! Language values read from feeds are normalized to canonical BCP 47 form
+//! (see [`normalize_language_tag`]) and can be inspected as a parsed
+//! [`LanguageTag`]. With the `language-detection` feature, entries of feeds
+//! that do not declare a language get one detected from their text.
+
+use crate::types::{ParsedFeed, SmallString, TextConstruct};
+#[cfg(feature = "language-detection")]
+use crate::{
+    types::Entry,
+    util::text::{HtmlToTextOptions, html_to_text},
+};
+use std::fmt;
+
+/// Deprecated ISO 639 codes still common in feeds, with their replacements
+const LEGACY_LANGUAGES: &[(&str, &str)] = &[
+    ("in", "id"),
+    ("iw", "he"),
+    ("ji", "yi"),
+    ("jw", "jv"),
+    ("mo", "ro"),
+];
+
+/// Parsed BCP 47 language tag
+///
+/// Subtags are stored in canonical case: lowercase language, title-case
+/// script, uppercase region, lowercase variants and extensions.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::LanguageTag;
+///
+/// let tag = LanguageTag::parse("zh_hant_tw").unwrap();
+/// assert_eq!(tag.language, "zh");
+/// assert_eq!(tag.script.as_deref(), Some("Hant"));
+/// assert_eq!(tag.region.as_deref(), Some("TW"));
+/// assert_eq!(tag.to_string(), "zh-Hant-TW");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LanguageTag {
+    /// Primary language subtag (`en`, `pt`, `yue`)
+    pub language: String,
+    /// Script subtag (`Latn`, `Hant`)
+    pub script: Option<String>,
+    /// Region subtag (`US`, `BR`, `419`)
+    pub region: Option<String>,
+    /// Variant subtags (`1996`, `valencia`)
+    pub variants: Vec<String>,
+    /// Extension and private-use subtags, starting at the first singleton
+    pub extensions: Vec<String>,
+}
+
+impl LanguageTag {
+    /// Parses a language tag, accepting `_` as separator and any case
+    ///
+    /// Legacy codes are replaced (`iw` becomes `he`, `in` becomes `id`).
+    /// Returns `None` if the value is not a well-formed language tag.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut subtags = value.trim().split(['-', '_']);
+        let first = subtags.next()?.to_ascii_lowercase();
+        if !(2..=8).contains(&first.len()) || !first.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return None;
+        }
+        let language = LEGACY_LANGUAGES
+            .iter()
+            .find(|(old, _)| *old == first)
+            .map_or(first, |(_, new)| (*new).to_string());
+
+        let mut tag = Self {
+            language,
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            extensions: Vec::new(),
+        };
+        for subtag in subtags {
+            if subtag.is_empty()
+                || subtag.len() > 8
+                || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+            {
+                return None;
+            }
+            let alpha = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+            let digits = subtag.bytes().all(|b| b.is_ascii_digit());
+            let in_extensions = !tag.extensions.is_empty();
+            let before_variants = tag.variants.is_empty() && !in_extensions;
+
+            if in_extensions || subtag.len() == 1 {
+                tag.extensions.push(subtag.to_ascii_lowercase());
+            } else if alpha
+                && subtag.len() == 4
+                && tag.script.is_none()
+                && tag.region.is_none()
+                && before_variants
+            {
+                let mut script = subtag.to_ascii_lowercase();
+                script[..1].make_ascii_uppercase();
+                tag.script = Some(script);
+            } else if ((alpha && subtag.len() == 2) || (digits && subtag.len() == 3))
+                && tag.region.is_none()
+                && before_variants
+            {
+                tag.region = Some(subtag.to_ascii_uppercase());
+            } else if subtag.len() >= 5
+                || (subtag.len() == 4 && subtag.as_bytes()[0].is_ascii_digit())
+            {
+                tag.variants.push(subtag.to_ascii_lowercase());
+            } else {
+                return None;
+            }
+        }
+        Some(tag)
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.language)?;
+        let rest = self
+            .script
+            .iter()
+            .chain(&self.region)
+            .chain(&self.variants)
+            .chain(&self.extensions);
+        for subtag in rest {
+            write!(f, "-{subtag}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Normalizes a language value to canonical BCP 47 form
+///
+/// Fixes case, converts `_` to `-` and replaces legacy codes. Values that are
+/// not well-formed tags are only trimmed. Returns `None` for blank input.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::language::normalize_language_tag;
+///
+/// assert_eq!(normalize_language_tag("EN_us").as_deref(), Some("en-US"));
+/// assert_eq!(normalize_language_tag("iw").as_deref(), Some("he"));
+/// assert_eq!(normalize_language_tag(" sr-latn-rs ").as_deref(), Some("sr-Latn-RS"));
+/// assert_eq!(normalize_language_tag("  "), None);
+/// ```
+#[must_use]
+pub fn normalize_language_tag(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    Some(LanguageTag::parse(value).map_or_else(|| value.to_string(), |tag| tag.to_string()))
+}
+
+fn normalize_in_place(language: &mut Option<SmallString>) {
+    if let Some(value) = language.as_deref() {
+        let normalized = normalize_language_tag(value);
+        if normalized.as_deref() != Some(value) {
+            *language = normalized.map(Into::into);
+        }
+    }
+}
+
+fn normalize_construct(detail: Option<&mut TextConstruct>) {
+    if let Some(detail) = detail {
+        normalize_in_place(&mut detail.language);
+    }
+}
+
+/// Normalize every language value in a parsed feed
+pub(crate) fn normalize_feed_languages(feed: &mut ParsedFeed) {
+    let meta = &mut feed.feed;
+    normalize_in_place(&mut meta.language);
+    normalize_construct(meta.title_detail.as_mut());
+    normalize_construct(meta.subtitle_detail.as_mut());
+    normalize_construct(meta.rights_detail.as_mut());
+    for entry in &mut feed.entries {
+        normalize_construct(entry.title_detail.as_mut());
+        normalize_construct(entry.summary_detail.as_mut());
+        for content in &mut entry.content {
+            normalize_in_place(&mut content.language);
+        }
+    }
+}
+
+/// Maximum number of characters of entry text fed to the detector
+#[cfg(feature = "language-detection")]
+const DETECTION_SAMPLE_CHARS: usize = 2000;
+
+/// Detects the language of `text`, returning a BCP 47 language tag
+///
+/// Returns the two-letter ISO 639-1 code when one exists (`en`, `fr`, `zh`),
+/// otherwise the ISO 639-3 code. Returns `None` when the text is too short or
+/// ambiguous for a reliable guess.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::language::detect_language;
+///
+/// let text = "Le renard brun rapide saute par-dessus le chien paresseux, puis il \
+///             repart tranquillement vers la forêt pour y passer la nuit.";
+/// assert_eq!(detect_language(text), Some("fr"));
+/// assert_eq!(detect_language("ok"), None);
+/// ```
+#[cfg(feature = "language-detection")]
+#[must_use]
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let info = whatlang::detect(text).filter(whatlang::Info::is_reliable)?;
+    let code = info.lang().code();
+    Some(iso_639_1(code).unwrap_or(code))
+}
+
+/// Fill in missing `language` on entry text constructs and content blocks
+///
+/// Does nothing when the feed declares a language; values declared on
+/// individual constructs are never overwritten.
+#[cfg(feature = "language-detection")]
+pub(crate) fn detect_entry_languages(feed: &mut ParsedFeed) {
+    if feed.feed.language.is_some() {
+        return;
+    }
+    for entry in &mut feed.entries {
+        let needs_language = entry.content.iter().any(|c| c.language.is_none())
+            || entry
+                .title_detail
+                .as_ref()
+                .is_some_and(|t| t.language.is_none())
+            || entry
+                .summary_detail
+                .as_ref()
+                .is_some_and(|s| s.language.is_none());
+        if !needs_language {
+            continue;
+        }
+        let Some(lang) = detect_language(&entry_sample(entry)) else {
+            continue;
+        };
+        for content in &mut entry.content {
+            content.language.get_or_insert_with(|| lang.into());
+        }
+        for detail in [&mut entry.title_detail, &mut entry.summary_detail]
+            .into_iter()
+            .flatten()
+        {
+            detail.language.get_or_insert_with(|| lang.into());
+        }
+    }
+}
+
+/// Plain text of an entry's title, summary and content, capped for speed
+#[cfg(feature = "language-detection")]
+fn entry_sample(entry: &Entry) -> String {
+    let options = HtmlToTextOptions {
+        include_link_urls: false,
+        list_bullets: false,
+    };
+    let parts = entry
+        .title
+        .iter()
+        .chain(&entry.summary)
+        .chain(entry.content.iter().map(|c| &c.value));
+    let mut sample = String::new();
+    for part in parts {
+        sample.push_str(&html_to_text(part, &options));
+        sample.push('\n');
+        if sample.len() >= DETECTION_SAMPLE_CHARS {
+            break;
+        }
+    }
+    sample.chars().take(DETECTION_SAMPLE_CHARS).collect()
+}
+
+/// Two-letter code for the ISO 639-3 languages whatlang can detect
+#[cfg(feature = "language-detection")]
+fn iso_639_1(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "afr" => "af",
+        "aka" => "ak",
+        "amh" => "am",
+        "ara" => "ar",
+        "aze" => "az",
+        "bel" => "be",
+        "ben" => "bn",
+        "bul" => "bg",
+        "cat" => "ca",
+        "ces" => "cs",
+        "cmn" => "zh",
+        "dan" => "da",
+        "deu" => "de",
+        "ell" => "el",
+        "eng" => "en",
+        "epo" => "eo",
+        "est" => "et",
+        "fin" => "fi",
+        "fra" => "fr",
+        "guj" => "gu",
+        "heb" => "he",
+        "hin" => "hi",
+        "hrv" => "hr",
+        "hun" => "hu",
+        "hye" => "hy",
+        "ind" => "id",
+        "ita" => "it",
+        "jav" => "jv",
+        "jpn" => "ja",
+        "kan" => "kn",
+        "kat" => "ka",
+        "khm" => "km",
+        "kor" => "ko",
+        "lat" => "la",
+        "lav" => "lv",
+        "lit" => "lt",
+        "mal" => "ml",
+        "mar" => "mr",
+        "mkd" => "mk",
+        "mya" => "my",
+        "nep" => "ne",
+        "nld" => "nl",
+        "nob" => "nb",
+        "ori" => "or",
+        "pan" => "pa",
+        "pes" => "fa",
+        "pol" => "pl",
+        "por" => "pt",
+        "ron" => "ro",
+        "rus" => "ru",
+        "sin" => "si",
+        "slk" => "sk",
+        "slv" => "sl",
+        "sna" => "sn",
+        "spa" => "es",
+        "srp" => "sr",
+        "swe" => "sv",
+        "tam" => "ta",
+        "tel" => "te",
+        "tgl" => "tl",
+        "tha" => "th",
+        "tuk" => "tk",
+        "tur" => "tr",
+        "ukr" => "uk",
+        "urd" => "ur",
+        "uzb" => "uz",
+        "vie" => "vi",
+        "yid" => "yi",
+        "zul" => "zu",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_language_tag_parse() {
+        let tag = LanguageTag::parse("ca-ES-valencia").unwrap();
+        assert_eq!(tag.region.as_deref(), Some("ES"));
+        assert_eq!(tag.variants, ["valencia"]);
+
+        let tag = LanguageTag::parse("es-419").unwrap();
+        assert_eq!(tag.region.as_deref(), Some("419"));
+
+        let tag = LanguageTag::parse("en-US-x-Twain").unwrap();
+        assert_eq!(tag.extensions, ["x", "twain"]);
+        assert_eq!(tag.to_string(), "en-US-x-twain");
+
+        assert_eq!(LanguageTag::parse("IN-id").unwrap().to_string(), "id-ID");
+        assert!(LanguageTag::parse("").is_none());
+        assert!(LanguageTag::parse("en--us").is_none());
+        assert!(LanguageTag::parse("english language").is_none());
+        assert!(LanguageTag::parse("e").is_none());
+    }
+
+    #[test]
+    fn test_normalize_language_tag() {
+        assert_eq!(normalize_language_tag("en-us").as_deref(), Some("en-US"));
+        assert_eq!(normalize_language_tag("PT_br").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize_language_tag("ji").as_deref(), Some("yi"));
+        assert_eq!(
+            normalize_language_tag("not a tag").as_deref(),
+            Some("not a tag")
+        );
+    }
+
+    #[test]
+    fn test_parse_normalizes_languages() {
+        let mut feed =
+            parse(b"<rss version='2.0'><channel><language>EN_gb</language><item><title>E</title></item></channel></rss>")
+                .unwrap();
+        assert_eq!(feed.feed.language.as_deref(), Some("en-GB"));
+        assert_eq!(
+            feed.feed.language_tag().unwrap().region.as_deref(),
+            Some("GB")
+        );
+
+        let entry = &mut feed.entries[0];
+        entry.title_detail.as_mut().unwrap().language = Some("iw".into());
+        entry.content.push(crate::Content {
+            language: Some("fr_ca".into()),
+            ..crate::Content::plain("c")
+        });
+        normalize_feed_languages(&mut feed);
+        let entry = &feed.entries[0];
+        assert_eq!(
+            entry.title_detail.as_ref().unwrap().language.as_deref(),
+            Some("he")
+        );
+        assert_eq!(entry.content[0].language.as_deref(), Some("fr-CA"));
+    }
+
+    #[cfg(feature = "language-detection")]
+    const GERMAN: &str = "Der schnelle braune Fuchs springt über den faulen Hund und \
+                          läuft danach ruhig in den Wald zurück, wo er die Nacht verbringt.";
+
+    #[test]
+    #[cfg(feature = "language-detection")]
+    fn test_detects_undeclared_language() {
+        let xml = format!(
+            r#"<rss version="2.0"><channel><title>T</title>
+            <item><title>Nachrichten</title><description>{GERMAN}</description></item>
+            </channel></rss>"#
+        );
+        let feed = parse(xml.as_bytes()).unwrap();
+        let entry = &feed.entries[0];
+        assert_eq!(
+            entry.summary_detail.as_ref().unwrap().language.as_deref(),
+            Some("de")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "language-detection")]
+    fn test_respects_declared_language() {
+        let xml = format!(
+            r#"<rss version="2.0"><channel><title>T</title><language>en</language>
+            <item><description>{GERMAN}</description></item>
+            </channel></rss>"#
+        );
+        let feed = parse(xml.as_bytes()).unwrap();
+        let detail = feed.entries[0].summary_detail.as_ref().unwrap();
+        assert_eq!(detail.language, None);
+    }
+}
@@ -0,0 +1,273 @@
+//! MIME type normalization for enclosures and media
+//!
+//! Feeds in the wild declare enclosure types inconsistently (`audio/mp3`,
+//! `Audio/MPEG; charset=binary`, or nothing at all). These helpers map such
+//! values to their registered form, infer a type from the URL extension when
+//! none is declared, and classify the result into a coarse [`MediaKind`].
+
+use std::fmt;
+
+/// Coarse classification of an enclosure or media object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum MediaKind {
+    /// Audio (`audio/*`)
+    Audio,
+    /// Video (`video/*`)
+    Video,
+    /// Image (`image/*`)
+    Image,
+    /// Documents such as PDF, EPUB or office files
+    Document,
+    /// Anything else, or unknown
+    Other,
+}
+
+impl MediaKind {
+    /// Classifies a MIME type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::MediaKind;
+    ///
+    /// assert_eq!(MediaKind::from_mime("audio/mpeg"), MediaKind::Audio);
+    /// assert_eq!(MediaKind::from_mime("application/pdf"), MediaKind::Document);
+    /// assert_eq!(MediaKind::from_mime("application/zip"), MediaKind::Other);
+    /// ```
+    #[must_use]
+    pub fn from_mime(mime: &str) -> Self {
+        let mime = normalize_mime_type(mime);
+        match mime.split('/').next().unwrap_or_default() {
+            "audio" => Self::Audio,
+            "video" => Self::Video,
+            "image" => Self::Image,
+            "text" => Self::Document,
+            _ if DOCUMENT_TYPES.contains(&mime.as_str()) => Self::Document,
+            _ if matches!(mime.as_str(), "application/ogg" | "application/x-mpegurl") => {
+                Self::Audio
+            }
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns the lowercase name of the kind
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Audio => "audio",
+            Self::Video => "video",
+            Self::Image => "image",
+            Self::Document => "document",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for MediaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `application/*` types treated as documents
+const DOCUMENT_TYPES: &[&str] = &[
+    "application/epub+zip",
+    "application/msword",
+    "application/pdf",
+    "application/rtf",
+    "application/vnd.ms-excel",
+    "application/vnd.ms-powerpoint",
+    "application/vnd.oasis.opendocument.presentation",
+    "application/vnd.oasis.opendocument.spreadsheet",
+    "application/vnd.oasis.opendocument.text",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+];
+
+/// Common non-standard MIME types and their registered equivalents
+const MIME_ALIASES: &[(&str, &str)] = &[
+    ("audio/mp3", "audio/mpeg"),
+    ("audio/mpeg3", "audio/mpeg"),
+    ("audio/x-mp3", "audio/mpeg"),
+    ("audio/x-mpeg", "audio/mpeg"),
+    ("audio/x-mpeg-3", "audio/mpeg"),
+    ("audio/mpg", "audio/mpeg"),
+    ("audio/x-m4a", "audio/mp4"),
+    ("audio/m4a", "audio/mp4"),
+    ("audio/x-aac", "audio/aac"),
+    ("audio/x-wav", "audio/wav"),
+    ("audio/wave", "audio/wav"),
+    ("audio/x-ogg", "audio/ogg"),
+    ("audio/x-flac", "audio/flac"),
+    ("video/x-m4v", "video/mp4"),
+    ("video/m4v", "video/mp4"),
+    ("video/x-mp4", "video/mp4"),
+    ("video/mov", "video/quicktime"),
+    ("image/jpg", "image/jpeg"),
+    ("image/pjpeg", "image/jpeg"),
+    ("image/x-png", "image/png"),
+    ("application/x-pdf", "application/pdf"),
+];
+
+/// File extensions and the MIME type they imply
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("mp3", "audio/mpeg"),
+    ("m4a", "audio/mp4"),
+    ("aac", "audio/aac"),
+    ("ogg", "audio/ogg"),
+    ("oga", "audio/ogg"),
+    ("opus", "audio/opus"),
+    ("flac", "audio/flac"),
+    ("wav", "audio/wav"),
+    ("mp4", "video/mp4"),
+    ("m4v", "video/mp4"),
+    ("mov", "video/quicktime"),
+    ("webm", "video/webm"),
+    ("ogv", "video/ogg"),
+    ("mkv", "video/x-matroska"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("png", "image/png"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("avif", "image/avif"),
+    ("svg", "image/svg+xml"),
+    ("pdf", "application/pdf"),
+    ("epub", "application/epub+zip"),
+    ("txt", "text/plain"),
+    ("srt", "application/x-subrip"),
+    ("vtt", "text/vtt"),
+];
+
+/// Normalizes a declared MIME type
+///
+/// Lowercases, strips parameters (`; charset=...`) and maps common
+/// non-standard aliases such as `audio/mp3` to their registered form.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::mime::normalize_mime_type;
+///
+/// assert_eq!(normalize_mime_type("audio/mp3"), "audio/mpeg");
+/// assert_eq!(normalize_mime_type(" Image/JPG; q=1 "), "image/jpeg");
+/// assert_eq!(normalize_mime_type("video/mp4"), "video/mp4");
+/// ```
+#[must_use]
+pub fn normalize_mime_type(mime: &str) -> String {
+    let essence = mime
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    MIME_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == essence)
+        .map_or(essence, |(_, canonical)| (*canonical).to_string())
+}
+
+/// Infers a MIME type from the file extension of a URL
+///
+/// Query strings and fragments are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::mime::mime_from_url;
+///
+/// assert_eq!(mime_from_url("https://cdn.example.com/ep1.MP3?token=x"), Some("audio/mpeg"));
+/// assert_eq!(mime_from_url("https://example.com/episode"), None);
+/// ```
+#[must_use]
+pub fn mime_from_url(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let file = path.rsplit('/').next().unwrap_or_default();
+    let (_, extension) = file.rsplit_once('.')?;
+    EXTENSIONS
+        .iter()
+        .find(|(ext, _)| ext.eq_ignore_ascii_case(extension))
+        .map(|(_, mime)| *mime)
+}
+
+/// Normalized MIME type for a declared type and URL
+///
+/// Uses the declared type when it is present and specific; falls back to the
+/// URL extension when the declared type is missing or a generic placeholder
+/// such as `application/octet-stream`.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::mime::resolve_mime_type;
+///
+/// assert_eq!(resolve_mime_type(Some("audio/x-m4a"), "a.m4a").as_deref(), Some("audio/mp4"));
+/// assert_eq!(resolve_mime_type(None, "a.mp3").as_deref(), Some("audio/mpeg"));
+/// assert_eq!(
+///     resolve_mime_type(Some("application/octet-stream"), "a.pdf").as_deref(),
+///     Some("application/pdf")
+/// );
+/// assert_eq!(resolve_mime_type(None, "a"), None);
+/// ```
+#[must_use]
+pub fn resolve_mime_type(declared: Option<&str>, url: &str) -> Option<String> {
+    let declared = declared
+        .map(normalize_mime_type)
+        .filter(|m| !m.is_empty() && !is_generic(m));
+    declared.or_else(|| mime_from_url(url).map(str::to_string))
+}
+
+fn is_generic(mime: &str) -> bool {
+    matches!(
+        mime,
+        "application/octet-stream" | "binary/octet-stream" | "application/unknown" | "unknown"
+    ) || !mime.contains('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_kind() {
+        assert_eq!(MediaKind::from_mime("audio/mp3"), MediaKind::Audio);
+        assert_eq!(MediaKind::from_mime("VIDEO/MP4"), MediaKind::Video);
+        assert_eq!(MediaKind::from_mime("image/jpg"), MediaKind::Image);
+        assert_eq!(
+            MediaKind::from_mime("application/epub+zip"),
+            MediaKind::Document
+        );
+        assert_eq!(MediaKind::from_mime("text/html"), MediaKind::Document);
+        assert_eq!(MediaKind::from_mime(""), MediaKind::Other);
+        assert_eq!(MediaKind::Audio.to_string(), "audio");
+    }
+
+    #[test]
+    fn test_mime_from_url() {
+        assert_eq!(
+            mime_from_url("https://e.com/a.b/c.webm#t=10"),
+            Some("video/webm")
+        );
+        assert_eq!(mime_from_url("https://e.com/a.b/c"), None);
+        assert_eq!(mime_from_url("cover.JPEG"), Some("image/jpeg"));
+    }
+
+    #[test]
+    fn test_resolve_mime_type() {
+        assert_eq!(
+            resolve_mime_type(Some("audio/mpeg3"), "x.ogg").as_deref(),
+            Some("audio/mpeg")
+        );
+        assert_eq!(
+            resolve_mime_type(Some("  "), "x.ogg").as_deref(),
+            Some("audio/ogg")
+        );
+        assert_eq!(
+            resolve_mime_type(Some("mp3"), "x.mp3").as_deref(),
+            Some("audio/mpeg")
+        );
+    }
+}
@@ -3,12 +3,22 @@
 //! This module provides helper functions for common feed parsing tasks.
 
 pub mod base_url;
+#[cfg(feature = "compression")]
+pub mod compression;
 pub mod date;
 pub mod encoding;
+pub mod entities;
+mod hash;
+pub mod language;
+pub mod mime;
 pub mod sanitize;
+mod scan;
 /// Text processing utilities
 pub mod text;
+pub mod url;
 
 // Re-export commonly used functions
 pub use base_url::{BaseUrlContext, combine_bases, is_safe_url, resolve_url};
 pub use date::parse_date;
+pub(crate) use hash::Fnv1a;
+pub(crate) use scan::{json_top_level_string, root_tag};
@@ -3,13 +3,578 @@
 //! This module provides functions for sanitizing HTML content to prevent XSS attacks
 //! while preserving safe formatting.
 
+use crate::types::{MimeType, ParsedFeed, TextConstruct, TextType};
+use crate::util::text::{HtmlToTextOptions, attribute, html_to_text, nesting_exceeds};
 use ammonia::Builder;
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// Deepest element nesting sanitized as HTML
+///
+/// The HTML tree builder takes quadratic time in the nesting depth, so a
+/// few megabytes of unclosed `<div>`s would stall the parser for minutes.
+/// Deeper markup is reduced to its escaped text instead.
+const MAX_HTML_DEPTH: usize = 512;
+
+/// Safe subset of SVG elements: shapes, text and gradients
+///
+/// Leaves out everything that can script, animate, embed or fetch:
+/// `script`, `foreignObject`, `animate*`, `set`, `a`, `image`, `use`,
+/// `style` and filters.
+const SVG_TAGS: &[&str] = &[
+    "circle",
+    "clipPath",
+    "defs",
+    "desc",
+    "ellipse",
+    "g",
+    "line",
+    "linearGradient",
+    "marker",
+    "path",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "stop",
+    "svg",
+    "symbol",
+    "text",
+    "title",
+    "tspan",
+];
+
+/// Presentation and geometry attributes allowed on [`SVG_TAGS`]
+const SVG_ATTRS: &[&str] = &[
+    "clip-path",
+    "clip-rule",
+    "cx",
+    "cy",
+    "d",
+    "dx",
+    "dy",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "gradientTransform",
+    "gradientUnits",
+    "height",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "markerHeight",
+    "markerWidth",
+    "offset",
+    "opacity",
+    "orient",
+    "points",
+    "preserveAspectRatio",
+    "r",
+    "refX",
+    "refY",
+    "rx",
+    "ry",
+    "stop-color",
+    "stop-opacity",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-opacity",
+    "stroke-width",
+    "text-anchor",
+    "transform",
+    "viewBox",
+    "width",
+    "x",
+    "x1",
+    "x2",
+    "y",
+    "y1",
+    "y2",
+];
+
+/// Presentation MathML elements
+///
+/// `annotation-xml` is left out since it can carry arbitrary HTML.
+const MATHML_TAGS: &[&str] = &[
+    "annotation",
+    "math",
+    "menclose",
+    "merror",
+    "mfrac",
+    "mi",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mover",
+    "mpadded",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mspace",
+    "msqrt",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "munder",
+    "munderover",
+    "semantics",
+];
+
+/// Layout attributes allowed on [`MATHML_TAGS`]
+const MATHML_ATTRS: &[&str] = &[
+    "accent",
+    "accentunder",
+    "columnalign",
+    "columnspan",
+    "depth",
+    "display",
+    "displaystyle",
+    "encoding",
+    "fence",
+    "form",
+    "height",
+    "largeop",
+    "linethickness",
+    "lspace",
+    "mathvariant",
+    "movablelimits",
+    "notation",
+    "rowalign",
+    "rowspan",
+    "rspace",
+    "scriptlevel",
+    "separator",
+    "stretchy",
+    "symmetric",
+    "width",
+];
+
+/// Sanitizer configuration beyond the default HTML allowlist
+///
+/// The default policy is the one [`sanitize_html`] applies. Set it on
+/// [`ParseOptions::sanitize_policy`](crate::ParseOptions::sanitize_policy)
+/// to change what the parser keeps.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::sanitize::SanitizePolicy;
+///
+/// let formula = "<math><mi>x</mi><mo>=</mo><mn>1</mn></math>";
+/// // The default policy drops the formula entirely
+/// assert_eq!(SanitizePolicy::new().clean(formula), "");
+///
+/// let policy = SanitizePolicy {
+///     allow_mathml: true,
+///     ..SanitizePolicy::new()
+/// };
+/// assert_eq!(policy.clean(formula), formula);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SanitizePolicy {
+    /// Keep presentation MathML, for formulas
+    pub allow_mathml: bool,
+    /// Keep inline SVG shapes and text, with scripting, `foreignObject`,
+    /// animation and external references removed
+    pub allow_svg: bool,
+    /// Hosts whose `<iframe>` embeds are kept
+    ///
+    /// An entry matches the host itself and its subdomains. Iframes pointing
+    /// anywhere else are removed, as are all iframes when this is empty.
+    /// Known players are rewritten to their privacy-enhanced variants:
+    /// YouTube to `youtube-nocookie.com`, Vimeo with `dnt=1`.
+    /// [`COMMON_EMBED_HOSTS`](Self::COMMON_EMBED_HOSTS) covers the usual
+    /// video and audio players.
+    pub iframe_hosts: Vec<String>,
+    /// Remove tracking pixels: images 1×1 or smaller, images served from
+    /// [`TRACKING_PIXEL_HOSTS`](Self::TRACKING_PIXEL_HOSTS), and links left
+    /// with nothing to click once those are gone. Other images keep their
+    /// `width` and `height`, which the check reads.
+    pub strip_tracking_pixels: bool,
+    /// Whether sanitized fields stay HTML or become plain text
+    pub output: SanitizeOutput,
+}
+
+/// Output format of [`SanitizePolicy::clean`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SanitizeOutput {
+    /// Safe HTML
+    #[default]
+    Html,
+    /// Entity-decoded plain text, as produced by [`html_to_text`]
+    ///
+    /// For consumers that never render HTML, such as notifications or
+    /// search indexing. During parsing, converted fields are retyped as
+    /// plain text: `TextType::Text` for titles and summaries, `text/plain`
+    /// for content.
+    PlainText(HtmlToTextOptions),
+}
+
+impl SanitizePolicy {
+    /// YouTube, Vimeo, Spotify and SoundCloud players
+    pub const COMMON_EMBED_HOSTS: &'static [&'static str] = &[
+        "youtube.com",
+        "youtube-nocookie.com",
+        "player.vimeo.com",
+        "open.spotify.com",
+        "w.soundcloud.com",
+    ];
+
+    /// Hosts serving open-tracking pixels and feed flare images:
+    /// `FeedBurner`, Mailchimp, WordPress.com stats and common analytics
+    pub const TRACKING_PIXEL_HOSTS: &'static [&'static str] = &[
+        "feeds.feedburner.com",
+        "feedproxy.google.com",
+        "list-manage.com",
+        "pixel.wp.com",
+        "stats.wordpress.com",
+        "feeds.wordpress.com",
+        "google-analytics.com",
+        "doubleclick.net",
+        "pixel.quantserve.com",
+        "scorecardresearch.com",
+    ];
+
+    /// The default policy: HTML allowlist only
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_mathml: false,
+            allow_svg: false,
+            iframe_hosts: Vec::new(),
+            strip_tracking_pixels: false,
+            output: SanitizeOutput::Html,
+        }
+    }
+
+    const fn is_plain_text(&self) -> bool {
+        matches!(self.output, SanitizeOutput::PlainText(_))
+    }
+
+    /// Sanitizes `input` under this policy
+    ///
+    /// Markup nested more than 512 elements deep is reduced to its text,
+    /// escaped as HTML.
+    #[must_use]
+    pub fn clean(&self, input: &str) -> String {
+        if let SanitizeOutput::PlainText(options) = &self.output {
+            return html_to_text(input, options);
+        }
+        if nesting_exceeds(input, MAX_HTML_DEPTH) {
+            return escaped_text(input);
+        }
+
+        // NOTE: Inline HashSet construction is faster than LazyLock with .clone()
+        // because ammonia requires owned values. See benchmark results in .local/
+        let mut safe_tags: HashSet<_> = HTML_TAGS.iter().copied().collect();
+        let mut tag_attrs = HashMap::new();
+        tag_attrs.insert("img", IMG_ATTRS.iter().copied().collect::<HashSet<_>>());
+        // Dropped along with their content rather than unwrapped
+        let mut clean_content_tags: HashSet<_> = ["script", "style"].into_iter().collect();
+
+        if self.allow_svg {
+            let attrs: HashSet<_> = SVG_ATTRS.iter().copied().collect();
+            for &tag in SVG_TAGS {
+                safe_tags.insert(tag);
+                tag_attrs.insert(tag, attrs.clone());
+            }
+            clean_content_tags.insert("foreignObject");
+        }
+        if self.allow_mathml {
+            let attrs: HashSet<_> = MATHML_ATTRS.iter().copied().collect();
+            for &tag in MATHML_TAGS {
+                safe_tags.insert(tag);
+                tag_attrs.entry(tag).or_default().extend(&attrs);
+            }
+            clean_content_tags.insert("annotation-xml");
+        }
+
+        let mut builder = Builder::default();
+        if self.iframe_hosts.is_empty() {
+            clean_content_tags.insert("iframe");
+        } else {
+            safe_tags.insert("iframe");
+            tag_attrs.insert(
+                "iframe",
+                ["allow", "allowfullscreen", "height", "loading", "width"]
+                    .into_iter()
+                    .collect(),
+            );
+            let hosts = self.iframe_hosts.clone();
+            builder.attribute_filter(move |element, attribute, value| {
+                if element == "iframe" && attribute == "src" {
+                    embed_src(value, &hosts).map(Cow::Owned)
+                } else {
+                    Some(Cow::Borrowed(value))
+                }
+            });
+        }
+
+        let safe_attrs: HashSet<_> = ["alt", "cite", "class", "href", "id", "src", "title"]
+            .into_iter()
+            .collect();
+
+        let safe_url_schemes: HashSet<_> = ["http", "https", "mailto"].into_iter().collect();
+
+        let clean = builder
+            .tags(safe_tags)
+            .clean_content_tags(clean_content_tags)
+            .tag_attributes(tag_attrs)
+            .generic_attributes(safe_attrs)
+            .link_rel(Some("nofollow noopener noreferrer"))
+            .url_schemes(safe_url_schemes)
+            .clean(input)
+            .to_string();
+        let clean = if self.iframe_hosts.is_empty() {
+            clean
+        } else {
+            drop_iframes_without_src(&clean)
+        };
+        if self.strip_tracking_pixels {
+            drop_tracking_pixels(&clean)
+        } else {
+            clean
+        }
+    }
+}
+
+/// Text of `html`, escaped for use as HTML
+fn escaped_text(html: &str) -> String {
+    html_escape::encode_text(&html_to_text(html, &HtmlToTextOptions::default())).into_owned()
+}
+
+/// Allowed and privacy-enhanced `src` of an embed, or `None` to drop it
+fn embed_src(src: &str, hosts: &[String]) -> Option<String> {
+    let mut url = url::Url::parse(src).ok()?;
+    let host = url.host_str()?.to_ascii_lowercase();
+    let allowed = hosts.iter().any(|allowed| host_matches(&host, allowed));
+    if !allowed || !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+
+    let _ = url.set_scheme("https");
+    if matches!(
+        host.as_str(),
+        "youtube.com" | "www.youtube.com" | "m.youtube.com"
+    ) && url.path().starts_with("/embed/")
+    {
+        url.set_host(Some("www.youtube-nocookie.com")).ok()?;
+    } else if host == "player.vimeo.com" && !url.query_pairs().any(|(key, _)| key == "dnt") {
+        url.query_pairs_mut().append_pair("dnt", "1");
+    }
+    Some(url.into())
+}
+
+/// Whether `host` is `allowed` or one of its subdomains
+fn host_matches(host: &str, allowed: &str) -> bool {
+    host == allowed
+        || host
+            .strip_suffix(allowed)
+            .is_some_and(|sub| sub.ends_with('.'))
+}
+
+/// Removes tracking images, and links that held only tracking images or
+/// point at a tracking host with nothing to click
+///
+/// Works on ammonia's serialized output, where every link carries a `rel`
+/// attribute and links never nest.
+fn drop_tracking_pixels(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<a ") {
+        out.push_str(&drop_tracking_images(&rest[..start]));
+        let tag = &rest[start..];
+        let tag_len = tag_end(tag);
+        let Some(close) = tag[tag_len..].find("</a>") else {
+            rest = tag;
+            break;
+        };
+        let inner = &tag[tag_len..tag_len + close];
+        let kept = drop_tracking_images(inner);
+        let tracking = kept.trim().is_empty()
+            && (!inner.trim().is_empty()
+                || attribute(&tag[..tag_len], "href").is_some_and(|href| is_tracking_url(&href)));
+        if !tracking {
+            out.push_str(&tag[..tag_len]);
+            out.push_str(&kept);
+            out.push_str("</a>");
+        }
+        rest = &tag[tag_len + close + "</a>".len()..];
+    }
+    out.push_str(&drop_tracking_images(rest));
+    out
+}
+
+/// Removes `<img>` tags that are tracking pixels
+fn drop_tracking_images(html: &str) -> Cow<'_, str> {
+    if !html.contains("<img") {
+        return Cow::Borrowed(html);
+    }
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<img") {
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        let tag_len = tag_end(tag);
+        if !is_tracking_image(&tag[..tag_len]) {
+            out.push_str(&tag[..tag_len]);
+        }
+        rest = &tag[tag_len..];
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// Whether an `<img>` tag is 1×1 or smaller, or loads from a tracking host
+fn is_tracking_image(tag: &str) -> bool {
+    let tiny = |name| {
+        attribute(tag, name).is_some_and(|value| {
+            let value = value.trim();
+            value
+                .strip_suffix("px")
+                .unwrap_or(value)
+                .parse::<u32>()
+                .is_ok_and(|size| size <= 1)
+        })
+    };
+    (tiny("width") && tiny("height"))
+        || attribute(tag, "src").is_some_and(|src| is_tracking_url(&src))
+}
+
+/// Whether `src` is served from one of
+/// [`SanitizePolicy::TRACKING_PIXEL_HOSTS`]
+fn is_tracking_url(src: &str) -> bool {
+    url::Url::parse(src).ok().is_some_and(|url| {
+        url.host_str().is_some_and(|host| {
+            let host = host.to_ascii_lowercase();
+            SanitizePolicy::TRACKING_PIXEL_HOSTS
+                .iter()
+                .any(|tracker| host_matches(&host, tracker))
+        })
+    })
+}
+
+/// Removes the iframes whose `src` the attribute filter dropped
+///
+/// Works on ammonia's serialized output, where attribute values are always
+/// double-quoted with `"` escaped, and iframe content is plain text.
+fn drop_iframes_without_src(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<iframe") {
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        let tag_len = tag_end(tag);
+        let element_len = tag[tag_len..]
+            .find("</iframe>")
+            .map_or(tag.len(), |close| tag_len + close + "</iframe>".len());
+        if tag[..tag_len].contains(" src=\"") {
+            out.push_str(&tag[..element_len]);
+        }
+        rest = &tag[element_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Length of the start tag at the beginning of `tag`, `>` included
+fn tag_end(tag: &str) -> usize {
+    let mut quoted = false;
+    for (index, byte) in tag.bytes().enumerate() {
+        match byte {
+            b'"' => quoted = !quoted,
+            b'>' if !quoted => return index + 1,
+            _ => {}
+        }
+    }
+    tag.len()
+}
+
+/// Attributes kept on `<img>` besides the generic ones
+///
+/// The declared size lets [`Entry::best_image`](crate::Entry::best_image)
+/// report dimensions for content images and lets
+/// [`SanitizePolicy::strip_tracking_pixels`] recognize 1×1 images.
+const IMG_ATTRS: &[&str] = &["height", "width"];
+
+/// HTML elements kept by every policy
+const HTML_TAGS: &[&str] = &[
+    // Text formatting
+    "a",
+    "abbr",
+    "acronym",
+    "b",
+    "cite",
+    "code",
+    "em",
+    "i",
+    "kbd",
+    "mark",
+    "s",
+    "samp",
+    "small",
+    "strike",
+    "strong",
+    "sub",
+    "sup",
+    "u",
+    "var",
+    // Structural
+    "br",
+    "div",
+    "hr",
+    "p",
+    "span",
+    // Headings
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    // Lists
+    "dd",
+    "dl",
+    "dt",
+    "li",
+    "ol",
+    "ul",
+    // Tables
+    "caption",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    // Quotes
+    "blockquote",
+    "q",
+    // Pre-formatted
+    "pre",
+    // Media
+    "img",
+];
 
 /// Sanitize HTML content, removing dangerous tags and attributes
 ///
 /// This function uses ammonia to clean HTML content, allowing only safe tags
 /// and attributes. It's designed to match feedparser's sanitization behavior.
+/// Equivalent to `SanitizePolicy::new().clean(input)`.
 ///
 /// # Arguments
 ///
@@ -29,82 +594,46 @@ use std::collections::HashSet;
 /// assert_eq!(safe_html, "<p>Hello</p>");
 /// ```
 pub fn sanitize_html(input: &str) -> String {
-    // NOTE: Inline HashSet construction is faster than LazyLock with .clone()
-    // because ammonia requires owned values. See benchmark results in .local/
-    let safe_tags: HashSet<_> = [
-        // Text formatting
-        "a",
-        "abbr",
-        "acronym",
-        "b",
-        "cite",
-        "code",
-        "em",
-        "i",
-        "kbd",
-        "mark",
-        "s",
-        "samp",
-        "small",
-        "strike",
-        "strong",
-        "sub",
-        "sup",
-        "u",
-        "var",
-        // Structural
-        "br",
-        "div",
-        "hr",
-        "p",
-        "span",
-        // Headings
-        "h1",
-        "h2",
-        "h3",
-        "h4",
-        "h5",
-        "h6",
-        // Lists
-        "dd",
-        "dl",
-        "dt",
-        "li",
-        "ol",
-        "ul",
-        // Tables
-        "caption",
-        "table",
-        "tbody",
-        "td",
-        "tfoot",
-        "th",
-        "thead",
-        "tr",
-        // Quotes
-        "blockquote",
-        "q",
-        // Pre-formatted
-        "pre",
-        // Media
-        "img",
-    ]
-    .into_iter()
-    .collect();
-
-    let safe_attrs: HashSet<_> = ["alt", "cite", "class", "href", "id", "src", "title"]
-        .into_iter()
-        .collect();
-
-    let safe_url_schemes: HashSet<_> = ["http", "https", "mailto"].into_iter().collect();
+    SanitizePolicy::new().clean(input)
+}
 
-    Builder::default()
-        .tags(safe_tags)
-        .generic_attributes(safe_attrs)
-        .link_rel(Some("nofollow noopener noreferrer"))
-        .url_schemes(safe_url_schemes)
-        .clean(input)
-        .to_string()
+/// Sanitizes HTML exactly as [`parse`](crate::parse) does by default
+///
+/// For markup an application assembled itself, such as a digest built from
+/// several entries. Use [`SanitizePolicy::clean`] to apply a custom
+/// [`ParseOptions::sanitize_policy`](crate::ParseOptions::sanitize_policy).
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::sanitize::clean_html;
+///
+/// let html = r#"<p onclick="x()">Hi <a href="javascript:x()">there</a></p>"#;
+/// assert_eq!(clean_html(html), "<p>Hi <a rel=\"nofollow noopener noreferrer\">there</a></p>");
+/// ```
+#[must_use]
+pub fn clean_html(input: &str) -> String {
+    SanitizePolicy::new().clean(input)
+}
+
+/// Converts HTML to plain text exactly as the parser's plain-text output does
+///
+/// Same as sanitizing with [`SanitizeOutput::PlainText`] and default
+/// [`HtmlToTextOptions`]: tags and scripts are removed, entities decoded and
+/// block structure kept as line breaks. Unlike [`strip_tags`], the result
+/// is text, not escaped HTML.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::sanitize::strip_html;
+///
+/// let html = "<p>Fish &amp; chips</p><script>x()</script><p>Today</p>";
+/// assert_eq!(strip_html(html), "Fish & chips\n\nToday");
+/// ```
+#[must_use]
+pub fn strip_html(input: &str) -> String {
+    html_to_text(input, &HtmlToTextOptions::default())
 }
 
 /// Decode HTML entities to Unicode characters
@@ -151,12 +680,103 @@ pub fn sanitize_and_decode(input: &str) -> String {
 /// assert_eq!(strip_tags("<p>Hello <b>world</b></p>"), "Hello world");
 /// ```
 pub fn strip_tags(input: &str) -> String {
+    if nesting_exceeds(input, MAX_HTML_DEPTH) {
+        return escaped_text(input);
+    }
     Builder::default()
         .tags(HashSet::new())
         .clean(input)
         .to_string()
 }
 
+/// Sanitizes every HTML-typed text field of a parsed feed in place
+///
+/// Covers feed title and subtitle, and entry title, summary and content.
+/// Plain-text fields are left untouched. Returns the paths of the fields the
+/// sanitizer changed, such as `feed.title` or `entries[0].content[1]`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "feedparser.sanitize",
+        skip_all,
+        fields(entries = feed.entries.len()),
+    )
+)]
+pub(crate) fn sanitize_feed(feed: &mut ParsedFeed, policy: &SanitizePolicy) -> Vec<String> {
+    let mut changed = Vec::new();
+    if sanitize_text(
+        &mut feed.feed.title,
+        feed.feed.title_detail.as_mut(),
+        policy,
+    ) {
+        changed.push("feed.title".to_string());
+    }
+    if sanitize_text(
+        &mut feed.feed.subtitle,
+        feed.feed.subtitle_detail.as_mut(),
+        policy,
+    ) {
+        changed.push("feed.subtitle".to_string());
+    }
+    for (index, entry) in feed.entries.iter_mut().enumerate() {
+        if sanitize_text(&mut entry.title, entry.title_detail.as_mut(), policy) {
+            changed.push(format!("entries[{index}].title"));
+        }
+        if sanitize_text(&mut entry.summary, entry.summary_detail.as_mut(), policy) {
+            changed.push(format!("entries[{index}].summary"));
+        }
+        for (content_index, content) in entry.content.iter_mut().enumerate() {
+            if content
+                .content_type
+                .as_deref()
+                .is_some_and(|t| t.to_ascii_lowercase().contains("html"))
+            {
+                if sanitize_in_place(&mut content.value, policy) {
+                    changed.push(format!("entries[{index}].content[{content_index}]"));
+                }
+                if policy.is_plain_text() {
+                    content.content_type = Some(MimeType::new("text/plain"));
+                }
+            }
+        }
+    }
+    changed
+}
+
+/// Sanitizes a value and its detail when the detail is typed as HTML,
+/// returning whether either changed
+fn sanitize_text(
+    value: &mut Option<String>,
+    detail: Option<&mut TextConstruct>,
+    policy: &SanitizePolicy,
+) -> bool {
+    let Some(detail) = detail else {
+        return false;
+    };
+    if detail.content_type == TextType::Text {
+        return false;
+    }
+    let mut changed = false;
+    if let Some(value) = value {
+        changed |= sanitize_in_place(value, policy);
+    }
+    if !detail.value.is_empty() {
+        changed |= sanitize_in_place(&mut detail.value, policy);
+    }
+    if policy.is_plain_text() {
+        detail.content_type = TextType::Text;
+    }
+    changed
+}
+
+/// Replaces `value` with its sanitized form, returning whether it changed
+fn sanitize_in_place(value: &mut String, policy: &SanitizePolicy) -> bool {
+    let clean = policy.clean(value);
+    let changed = clean != *value;
+    *value = clean;
+    changed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,12 +879,54 @@ mod tests {
         assert_eq!(decode_entities(text), text);
     }
 
+    #[test]
+    fn test_clean_html_keeps_image_size() {
+        assert_eq!(
+            clean_html(r#"<img src="a.png" width="64" height="48" border="1">"#),
+            r#"<img src="a.png" width="64" height="48">"#
+        );
+        assert_eq!(clean_html(r#"<p width="64">x</p>"#), "<p>x</p>");
+    }
+
+    #[test]
+    fn test_clean_html_matches_parser() {
+        let html = r#"<p>Hi</p><img src="x.png" width="10" onerror="x()"><iframe src="https://www.youtube.com/embed/a"></iframe>"#;
+        let xml = format!(
+            "<rss version=\"2.0\"><channel><item><description>{}</description></item></channel></rss>",
+            html_escape::encode_text(html)
+        );
+        let feed = crate::parse(xml.as_bytes()).unwrap();
+        assert_eq!(
+            feed.entries[0].summary.as_deref(),
+            Some(clean_html(html).as_str())
+        );
+        assert_eq!(clean_html(html), r#"<p>Hi</p><img src="x.png" width="10">"#);
+    }
+
+    #[test]
+    fn test_strip_html_decodes_text() {
+        assert_eq!(strip_html("a &lt;b&gt; <i>c</i>"), "a <b> c");
+        assert_eq!(strip_tags("a &lt;b&gt; <i>c</i>"), "a &lt;b&gt; c");
+        assert_eq!(strip_html(""), "");
+    }
+
     #[test]
     fn test_strip_tags_nested() {
         let html = "<div><p>Hello <span><b>world</b></span></p></div>";
         assert_eq!(strip_tags(html), "Hello world");
     }
 
+    #[test]
+    fn test_deep_nesting_reduced_to_text() {
+        let deep = format!("{}<b>x &amp; y</b>", "<div>".repeat(MAX_HTML_DEPTH + 1));
+        assert_eq!(sanitize_html(&deep), "x &amp; y");
+        assert_eq!(strip_tags(&deep), "x &amp; y");
+
+        // Unclosed paragraphs do not nest
+        let paragraphs = "<p>a".repeat(MAX_HTML_DEPTH * 2);
+        assert!(sanitize_html(&paragraphs).starts_with("<p>a</p><p>a</p>"));
+    }
+
     #[test]
     fn test_sanitize_link_rel_attribute() {
         let html = r#"<a href="http://example.com">Link</a>"#;
@@ -273,4 +935,147 @@ mod tests {
         assert!(clean.contains("noopener"));
         assert!(clean.contains("noreferrer"));
     }
+
+    #[test]
+    fn test_sanitize_feed_only_touches_html_fields() {
+        let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom">
+            <title type="html"><![CDATA[<b>T</b><script>x</script>]]></title>
+            <entry>
+                <title type="text"><![CDATA[a <script> b]]></title>
+                <content type="html"><![CDATA[<p onclick="x()">C</p>]]></content>
+            </entry>
+        </feed>"#;
+        let options = crate::ParseOptions {
+            sanitize_html: false,
+            ..crate::ParseOptions::default()
+        };
+        let mut feed = crate::parse_with_options(xml, &options).unwrap();
+        assert_eq!(
+            sanitize_feed(&mut feed, &SanitizePolicy::new()),
+            ["feed.title", "entries[0].content[0]"]
+        );
+        assert_eq!(feed.feed.title.as_deref(), Some("<b>T</b>"));
+        assert_eq!(feed.entries[0].title.as_deref(), Some("a <script> b"));
+        assert_eq!(feed.entries[0].content[0].value, "<p>C</p>");
+    }
+
+    #[test]
+    fn test_policy_svg() {
+        let html = r#"<svg viewBox="0 0 10 10" onload="x()"><script>alert(1)</script><foreignObject><p>html</p></foreignObject><circle cx="5" cy="5" r="4" fill="red"/><a href="javascript:x()"><text x="1">t</text></a></svg>"#;
+        assert!(!sanitize_html(html).contains("<svg"));
+
+        let policy = SanitizePolicy {
+            allow_svg: true,
+            ..SanitizePolicy::new()
+        };
+        assert_eq!(
+            policy.clean(html),
+            concat!(
+                r#"<svg viewBox="0 0 10 10"><circle cx="5" cy="5" r="4" fill="red"></circle>"#,
+                r#"<a rel="nofollow noopener noreferrer"><text x="1">t</text></a></svg>"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_policy_mathml() {
+        let html = r#"<math display="block"><semantics><mfrac><mi>a</mi><mn>2</mn></mfrac><annotation-xml encoding="text/html"><img src=x onerror=alert(1)></annotation-xml></semantics></math>"#;
+        let policy = SanitizePolicy {
+            allow_mathml: true,
+            ..SanitizePolicy::new()
+        };
+        assert_eq!(
+            policy.clean(html),
+            r#"<math display="block"><semantics><mfrac><mi>a</mi><mn>2</mn></mfrac></semantics></math>"#
+        );
+    }
+
+    #[test]
+    fn test_policy_iframe_hosts() {
+        let html = concat!(
+            r#"<iframe src="https://www.youtube.com/embed/abc" width="560" onload="x()"></iframe>"#,
+            r#"<iframe src="http://player.vimeo.com/video/1"></iframe>"#,
+            r#"<iframe src="https://evil.example.com/" title="a>b">fallback</iframe>"#,
+            r#"<iframe src="https://notyoutube.com/embed/x"></iframe>"#,
+            "<p>after</p>",
+        );
+        assert_eq!(sanitize_html(html), "<p>after</p>");
+
+        let policy = SanitizePolicy {
+            iframe_hosts: SanitizePolicy::COMMON_EMBED_HOSTS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            ..SanitizePolicy::new()
+        };
+        assert_eq!(
+            policy.clean(html),
+            concat!(
+                r#"<iframe src="https://www.youtube-nocookie.com/embed/abc" width="560"></iframe>"#,
+                r#"<iframe src="https://player.vimeo.com/video/1?dnt=1"></iframe>"#,
+                "<p>after</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn test_policy_strip_tracking_pixels() {
+        let html = concat!(
+            r#"<p>Story<img src="https://example.com/p.gif" width="1" height="1"></p>"#,
+            r#"<p><img src="https://example.com/photo.jpg" width="1" height="300"></p>"#,
+            r#"<a href="https://feeds.feedburner.com/~ff/blog?a=1"><img src="https://feeds.feedburner.com/~ff/blog?i=1"></a>"#,
+            r#"<img src="https://blog.us1.list-manage.com/track/open.php?u=1&amp;id=2">"#,
+            r#"<a href="https://pixel.wp.com/b.gif"> </a>"#,
+            r#"<a href="https://example.com/">Read <img src="https://stats.wordpress.com/g.gif">more</a>"#,
+            r#"<img src="https://notfeedburner.com/x.png">"#,
+        );
+        let default = sanitize_html(html);
+        assert!(default.contains("list-manage.com"));
+
+        let policy = SanitizePolicy {
+            strip_tracking_pixels: true,
+            ..SanitizePolicy::new()
+        };
+        assert_eq!(
+            policy.clean(html),
+            concat!(
+                "<p>Story</p>",
+                r#"<p><img src="https://example.com/photo.jpg" width="1" height="300"></p>"#,
+                r#"<a href="https://example.com/" rel="nofollow noopener noreferrer">Read more</a>"#,
+                r#"<img src="https://notfeedburner.com/x.png">"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_plain_text_output() {
+        let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom"><entry>
+            <title type="html">Fish &amp;amp; chips</title>
+            <summary type="html">&lt;p&gt;One&lt;/p&gt;&lt;script&gt;x()&lt;/script&gt;&lt;p&gt;Two&lt;/p&gt;</summary>
+            <content type="html">&lt;ul&gt;&lt;li&gt;a&lt;/li&gt;&lt;li&gt;b&lt;/li&gt;&lt;/ul&gt;</content>
+        </entry></feed>"#;
+        let mut feed = crate::parse_with_options(
+            xml,
+            &crate::ParseOptions {
+                sanitize_html: false,
+                ..crate::ParseOptions::default()
+            },
+        )
+        .unwrap();
+        let policy = SanitizePolicy {
+            output: SanitizeOutput::PlainText(HtmlToTextOptions::default()),
+            ..SanitizePolicy::new()
+        };
+        sanitize_feed(&mut feed, &policy);
+
+        let entry = &feed.entries[0];
+        assert_eq!(entry.title.as_deref(), Some("Fish & chips"));
+        assert_eq!(entry.summary.as_deref(), Some("One\n\nTwo"));
+        assert_eq!(
+            entry.summary_detail.as_ref().unwrap().content_type,
+            TextType::Text
+        );
+        assert_eq!(entry.content[0].value, "- a\n- b");
+        assert_eq!(entry.content[0].content_type.as_deref(), Some("text/plain"));
+    }
 }
@@ -0,0 +1,222 @@
+//! Byte-level scanning built on `memchr`
+//!
+//! Format detection and similar dispatch decisions only need a handful of
+//! bytes near the start of a document. These helpers jump straight to them
+//! with vectorized searches instead of tokenizing everything in between.
+
+use memchr::{memchr, memchr3, memmem, memrchr};
+
+/// Start tag of a document's root element, as found by [`root_tag`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootTag<'a> {
+    /// Qualified name, e.g. `rss` or `rdf:RDF`
+    pub name: &'a [u8],
+    /// Raw text between the name and the closing `>`
+    attributes: &'a [u8],
+}
+
+impl<'a> RootTag<'a> {
+    /// Name without its namespace prefix
+    pub fn local_name(&self) -> &'a [u8] {
+        memrchr(b':', self.name).map_or(self.name, |colon| &self.name[colon + 1..])
+    }
+
+    /// Raw, still-escaped value of the attribute with qualified name `key`
+    pub fn attribute(&self, key: &[u8]) -> Option<&'a [u8]> {
+        let mut rest = self.attributes;
+        loop {
+            rest = rest.trim_ascii_start();
+            let eq = memchr(b'=', rest)?;
+            let name = rest[..eq].trim_ascii_end();
+            let value = rest[eq + 1..].trim_ascii_start();
+            let quote = *value.first()?;
+            if quote != b'"' && quote != b'\'' {
+                return None;
+            }
+            let len = memchr(quote, &value[1..])?;
+            if name == key {
+                return Some(&value[1..=len]);
+            }
+            rest = &value[len + 2..];
+        }
+    }
+}
+
+/// Finds the root element's start tag
+///
+/// Skips the XML declaration, processing instructions, comments and the
+/// DOCTYPE (including an internal subset). Returns `None` if the document
+/// ends before a complete start tag, or if the first tag is not one.
+pub fn root_tag(data: &[u8]) -> Option<RootTag<'_>> {
+    let mut pos = 0;
+    loop {
+        pos += memchr(b'<', &data[pos..])? + 1;
+        let rest = &data[pos..];
+        pos += match rest.first()? {
+            b'?' => memmem::find(rest, b"?>")? + 2,
+            b'!' if rest.starts_with(b"!--") => memmem::find(rest, b"-->")? + 3,
+            b'!' => declaration_len(rest)?,
+            _ => return start_tag(rest),
+        };
+    }
+}
+
+/// Length of a `<!...>` declaration such as a DOCTYPE, after the `<`
+fn declaration_len(rest: &[u8]) -> Option<usize> {
+    let end = memchr(b'>', rest)?;
+    match memchr(b'[', &rest[..end]) {
+        // The internal subset may itself contain `>`
+        Some(open) => {
+            let close = open + memchr(b']', &rest[open..])?;
+            Some(close + memchr(b'>', &rest[close..])? + 1)
+        }
+        None => Some(end + 1),
+    }
+}
+
+/// Parses a start tag whose `<` has already been consumed
+fn start_tag(rest: &[u8]) -> Option<RootTag<'_>> {
+    let name_len = rest
+        .iter()
+        .position(|&b| b.is_ascii_whitespace() || b == b'/' || b == b'>')?;
+    if name_len == 0 {
+        return None;
+    }
+
+    // Find the closing `>`, stepping over quoted attribute values
+    let mut pos = name_len;
+    loop {
+        pos += memchr3(b'>', b'"', b'\'', &rest[pos..])?;
+        let quote = rest[pos];
+        if quote == b'>' {
+            break;
+        }
+        pos += 1 + memchr(quote, &rest[pos + 1..])? + 1;
+    }
+
+    let attributes = &rest[name_len..pos];
+    Some(RootTag {
+        name: &rest[..name_len],
+        attributes: attributes.strip_suffix(b"/").unwrap_or(attributes),
+    })
+}
+
+/// Raw value of a string member of a JSON document's top-level object
+///
+/// Looks only at the first occurrence of `"key"` and gives up, returning
+/// `None`, if that occurrence is not a key of the outermost object or its
+/// value is not a string. Escape sequences are left as they are.
+pub fn json_top_level_string<'a>(data: &'a [u8], key: &str) -> Option<&'a [u8]> {
+    let needle = [b"\"", key.as_bytes(), b"\""].concat();
+    let pos = memmem::find(data, &needle)?;
+    if json_depth(&data[..pos])? != 1 {
+        return None;
+    }
+
+    let after = data[pos + needle.len()..].trim_ascii_start();
+    let value = after.strip_prefix(b":")?.trim_ascii_start();
+    let value = value.strip_prefix(b"\"")?;
+
+    let mut end = 0;
+    loop {
+        end += memchr(b'"', &value[end..])?;
+        let backslashes = value[..end]
+            .iter()
+            .rev()
+            .take_while(|&&b| b == b'\\')
+            .count();
+        if backslashes % 2 == 0 {
+            return Some(&value[..end]);
+        }
+        end += 1;
+    }
+}
+
+/// Nesting depth at the end of `prefix`, or `None` if it ends inside a string
+fn json_depth(prefix: &[u8]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for &b in prefix {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    (!in_string).then_some(depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_tag_skips_prolog() {
+        let xml = br#"<?xml version="1.0"?>
+            <!-- <feed> in a comment -->
+            <?xml-stylesheet href="a.xsl"?>
+            <!DOCTYPE rss [<!ENTITY e "<x>">]>
+            <rss version="2.0" data-x='a>b'><channel/></rss>"#;
+
+        let tag = root_tag(xml).unwrap();
+        assert_eq!(tag.name, b"rss");
+        assert_eq!(tag.attribute(b"version"), Some(&b"2.0"[..]));
+        assert_eq!(tag.attribute(b"data-x"), Some(&b"a>b"[..]));
+        assert_eq!(tag.attribute(b"missing"), None);
+    }
+
+    #[test]
+    fn test_root_tag_prefixed_and_empty() {
+        let tag = root_tag(b"\xEF\xBB\xBF<rdf:RDF xmlns:rdf = \"x\"/>").unwrap();
+        assert_eq!(tag.name, b"rdf:RDF");
+        assert_eq!(tag.local_name(), b"RDF");
+        assert_eq!(tag.attribute(b"xmlns:rdf"), Some(&b"x"[..]));
+
+        assert_eq!(root_tag(b"<feed>").unwrap().local_name(), b"feed");
+    }
+
+    #[test]
+    fn test_root_tag_incomplete() {
+        assert_eq!(root_tag(b""), None);
+        assert_eq!(root_tag(b"not xml"), None);
+        assert_eq!(root_tag(b"<!-- unterminated"), None);
+        assert_eq!(root_tag(br#"<rss version="2.0"#), None);
+        assert_eq!(root_tag(b"</rss>"), None);
+    }
+
+    #[test]
+    fn test_json_top_level_string() {
+        let json =
+            br#"{"items": [{"version": "nested"}], "version": "https://jsonfeed.org/version/1.1"}"#;
+        // The first "version" is nested, so the scan gives up
+        assert_eq!(json_top_level_string(json, "version"), None);
+
+        let json = br#"{ "version" : "https:\/\/jsonfeed.org\/version\/1", "title": "a \"q\"" }"#;
+        assert_eq!(
+            json_top_level_string(json, "version"),
+            Some(&br"https:\/\/jsonfeed.org\/version\/1"[..])
+        );
+        assert_eq!(
+            json_top_level_string(json, "title"),
+            Some(&br#"a \"q\""#[..])
+        );
+    }
+
+    #[test]
+    fn test_json_top_level_string_requires_string_value() {
+        let json = br#"{"title": "\"version\": \"x\"", "version": 1}"#;
+        assert_eq!(json_top_level_string(json, "version"), None);
+        assert_eq!(json_top_level_string(br#"{"title": "x"}"#, "version"), None);
+    }
+}
@@ -1,7 +1,15 @@
 //! Text processing utilities
 //!
 //! This module provides functions for text manipulation,
-//! such as trimming, normalizing whitespace, and encoding conversion.
+//! such as trimming, normalizing whitespace and Unicode, encoding
+//! conversion, and rendering HTML as plain text.
+
+use icu_normalizer::ComposingNormalizerBorrowed;
+use memchr::{memchr, memchr2, memrchr};
+use std::borrow::Cow;
+
+use crate::types::TextType;
+use crate::util::entities::WINDOWS_1252;
 
 /// Efficient bytes to string conversion - zero-copy for valid UTF-8
 ///
@@ -51,3 +59,864 @@ pub fn truncate_to_length(s: &str, max_len: usize) -> String {
         s.chars().take(max_len).collect()
     }
 }
+
+/// Options for [`html_to_text`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlToTextOptions {
+    /// Append link targets as `text (url)`
+    ///
+    /// Fragment-only and `javascript:` links, and links whose text already is
+    /// the URL, are never expanded.
+    ///
+    /// Default: `true`
+    pub include_link_urls: bool,
+
+    /// Prefix list items with `- `
+    ///
+    /// Default: `true`
+    pub list_bullets: bool,
+}
+
+impl Default for HtmlToTextOptions {
+    fn default() -> Self {
+        Self {
+            include_link_urls: true,
+            list_bullets: true,
+        }
+    }
+}
+
+/// Converts HTML to readable plain text
+///
+/// Strips tags, decodes entities and collapses whitespace while keeping the
+/// document's shape: block elements (`p`, `div`, headings, lists, tables...)
+/// become paragraph breaks, `br`, `li` and `tr` become line breaks, and
+/// `pre` content is kept verbatim. `script`, `style` and comments are dropped,
+/// images are replaced by their `alt` text.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::text::{HtmlToTextOptions, html_to_text};
+///
+/// let html = r#"<p>Hello &amp; <b>welcome</b>!</p><p>See <a href="https://example.com/">our site</a>.</p>"#;
+/// assert_eq!(
+///     html_to_text(html, &HtmlToTextOptions::default()),
+///     "Hello & welcome!\n\nSee our site (https://example.com/)."
+/// );
+/// ```
+#[must_use]
+pub fn html_to_text(html: &str, options: &HtmlToTextOptions) -> String {
+    let mut w = TextWriter::default();
+    let mut links: Vec<(Option<String>, usize)> = Vec::new();
+    let mut skip: Option<&'static str> = None;
+    let mut pre_depth = 0usize;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            if skip.is_none() {
+                w.text(&decode(rest), pre_depth > 0);
+            }
+            break;
+        };
+        if skip.is_none() {
+            w.text(&decode(&rest[..lt]), pre_depth > 0);
+        }
+        let markup = &rest[lt..];
+
+        if let Some(comment) = markup.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(gt) = markup.find('>') else {
+            // Unterminated tag: treat the remainder as text
+            if skip.is_none() {
+                w.text(&decode(markup), pre_depth > 0);
+            }
+            break;
+        };
+        let tag = &markup[1..gt];
+        rest = &markup[gt + 1..];
+
+        let (closing, tag) = tag
+            .strip_prefix('/')
+            .map_or((false, tag), |tag| (true, tag));
+        let name = tag
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if let Some(skipped) = skip {
+            if closing && name == skipped {
+                skip = None;
+            }
+            continue;
+        }
+
+        match name.as_str() {
+            "script" if !closing => skip = Some("script"),
+            "style" if !closing => skip = Some("style"),
+            "br" => w.line_break(),
+            "pre" => {
+                w.block_break();
+                pre_depth = if closing {
+                    pre_depth.saturating_sub(1)
+                } else {
+                    pre_depth + 1
+                };
+            }
+            "li" => {
+                w.soft_break();
+                if !closing && options.list_bullets {
+                    w.word("-");
+                    w.space = true;
+                }
+            }
+            "tr" | "dt" | "dd" => w.soft_break(),
+            "td" | "th" => w.space = true,
+            "img" => {
+                if let Some(alt) = attribute(tag, "alt").filter(|alt| !alt.trim().is_empty()) {
+                    w.text(&alt, false);
+                }
+            }
+            "a" if !closing => links.push((attribute(tag, "href"), w.out.len())),
+            "a" => {
+                if let Some((Some(href), start)) = links.pop() {
+                    let href = href.trim();
+                    let label = w.out.get(start..).unwrap_or_default().trim();
+                    let shown = label == href
+                        || href.strip_prefix("mailto:") == Some(label)
+                        || href.is_empty()
+                        || href.starts_with('#')
+                        || href.to_ascii_lowercase().starts_with("javascript:");
+                    if options.include_link_urls && !shown {
+                        w.space = true;
+                        w.word(&format!("({href})"));
+                    }
+                }
+            }
+            "p" | "div" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "blockquote" | "ul" | "ol"
+            | "dl" | "table" | "hr" | "section" | "article" | "header" | "footer" | "aside"
+            | "nav" | "figure" | "figcaption" | "address" => w.block_break(),
+            _ => {}
+        }
+    }
+
+    let mut out = w.out;
+    out.truncate(out.trim_end().len());
+    out
+}
+
+fn decode(text: &str) -> std::borrow::Cow<'_, str> {
+    html_escape::decode_html_entities(text)
+}
+
+/// Extracts an attribute value from the inside of a start tag
+pub(crate) fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find(name) {
+        let start = from + pos;
+        from = start + name.len();
+        let preceded = lower[..start].ends_with(|c: char| c.is_ascii_whitespace());
+        let Some(value) = tag[from..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if !preceded {
+            continue;
+        }
+        let value = value.trim_start();
+        let raw = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split_ascii_whitespace().next().unwrap_or_default(),
+        };
+        return Some(decode(raw).into_owned());
+    }
+    None
+}
+
+/// Accumulates text, collapsing whitespace and tracking pending line breaks
+#[derive(Default)]
+struct TextWriter {
+    out: String,
+    /// Newlines to emit before the next word (at most 2)
+    breaks: usize,
+    /// Whether a space is owed before the next word
+    space: bool,
+}
+
+impl TextWriter {
+    fn text(&mut self, text: &str, preformatted: bool) {
+        if preformatted {
+            if !text.is_empty() {
+                self.flush_breaks();
+                self.out.push_str(text);
+            }
+            return;
+        }
+        if text.starts_with(char::is_whitespace) {
+            self.space = true;
+        }
+        for word in text.split_whitespace() {
+            self.word(word);
+            self.space = true;
+        }
+        if !text.ends_with(char::is_whitespace) && !text.is_empty() {
+            self.space = false;
+        }
+    }
+
+    fn word(&mut self, word: &str) {
+        if self.breaks > 0 {
+            self.flush_breaks();
+        } else if self.space && !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push(' ');
+        }
+        self.space = false;
+        self.out.push_str(word);
+    }
+
+    fn flush_breaks(&mut self) {
+        if !self.out.is_empty() {
+            let trimmed = self.out.trim_end_matches(' ').len();
+            self.out.truncate(trimmed);
+            for _ in 0..self.breaks {
+                self.out.push('\n');
+            }
+        }
+        self.breaks = 0;
+        self.space = false;
+    }
+
+    /// Paragraph boundary: a blank line before the next word
+    const fn block_break(&mut self) {
+        self.breaks = 2;
+    }
+
+    /// Line boundary that does not add to an existing break
+    fn soft_break(&mut self) {
+        self.breaks = self.breaks.max(1);
+    }
+
+    /// Explicit `<br>`: consecutive breaks accumulate up to a blank line
+    fn line_break(&mut self) {
+        self.breaks = (self.breaks + 1).min(2);
+    }
+}
+
+/// Elements that never have a closing tag
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Finds the first `<img>` tag in `html` and returns its inner tag text
+pub(crate) fn first_img_tag(html: &str) -> Option<&str> {
+    let lower = html.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find("<img") {
+        let start = from + pos;
+        from = start + 4;
+        if lower[from..].starts_with(|c: char| c.is_ascii_whitespace() || c == '/') {
+            let end = html[from..].find('>').map_or(html.len(), |end| from + end);
+            return Some(&html[start + 1..end]);
+        }
+    }
+    None
+}
+
+/// Truncates text or HTML to at most `max_chars` visible characters
+///
+/// Cuts at the last word boundary before the limit and appends `…`. Entity
+/// references count as one character and are never split. When the input is
+/// HTML, tags do not count towards the limit and every element still open at
+/// the cut is closed, so the result is well-formed and display-ready. Input
+/// that already fits is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::text::truncate_summary;
+///
+/// assert_eq!(truncate_summary("The quick brown fox", 12), "The quick…");
+/// assert_eq!(
+///     truncate_summary("<p>The <b>quick brown</b> fox</p>", 12),
+///     "<p>The <b>quick…</b></p>"
+/// );
+/// assert_eq!(truncate_summary("Fish &amp; chips", 100), "Fish &amp; chips");
+/// ```
+#[must_use]
+pub fn truncate_summary(input: &str, max_chars: usize) -> String {
+    let tags_end = tags_end(input);
+    let mut open: Vec<&str> = Vec::new();
+    let mut count = 0usize;
+    // Output length at the last word boundary
+    let mut boundary: Option<usize> = None;
+    let mut pos = 0usize;
+
+    while pos < input.len() {
+        let rest = &input[pos..];
+        if rest.starts_with('<')
+            && let Some(len) = markup_len(rest, pos < tags_end)
+        {
+            track_tag(&rest[..len], &mut open);
+            pos += len;
+            continue;
+        }
+
+        let unit = if rest.starts_with('&') {
+            entity_len(rest).unwrap_or(1)
+        } else {
+            rest.chars().next().map_or(1, char::len_utf8)
+        };
+        let is_space = rest.starts_with(char::is_whitespace);
+        if is_space {
+            boundary = Some(pos);
+        }
+        if count == max_chars {
+            let cut = match boundary {
+                Some(cut) if !is_space && cut < pos => {
+                    // Elements open at the boundary, not at the current position
+                    open.clear();
+                    let mut at = 0;
+                    while let Some(found) = memchr(b'<', &input.as_bytes()[at..cut]) {
+                        at += found;
+                        match markup_len(&input[at..], at < tags_end) {
+                            Some(len) => {
+                                track_tag(&input[at..at + len], &mut open);
+                                at += len;
+                            }
+                            None => at += 1,
+                        }
+                    }
+                    cut
+                }
+                _ => pos,
+            };
+            let mut out = input[..cut].trim_end().to_string();
+            out.push('\u{2026}');
+            for name in open.iter().rev() {
+                out.push_str("</");
+                out.push_str(name);
+                out.push('>');
+            }
+            return out;
+        }
+        count += 1;
+        pos += unit;
+    }
+    input.to_string()
+}
+
+/// Classifies a value of unspecified type as HTML or plain text
+///
+/// RSS `<description>` carries no type attribute: most feeds put escaped
+/// HTML there, but many hold plain text. A value counts as HTML when it
+/// contains a tag, a comment or an entity reference; a bare `<` or `&`, as
+/// in `a < b` or `AT&T`, does not.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::TextType;
+/// use feedparser_rs::util::text::detect_text_type;
+///
+/// assert_eq!(detect_text_type("<p>Hello</p>"), TextType::Html);
+/// assert_eq!(detect_text_type("Fish &amp; chips"), TextType::Html);
+/// assert_eq!(detect_text_type("AT&T says 1 < 2"), TextType::Text);
+/// ```
+#[must_use]
+pub fn detect_text_type(value: &str) -> TextType {
+    let bytes = value.as_bytes();
+    let tags_end = tags_end(value);
+    let mut pos = 0;
+    while let Some(found) = memchr2(b'<', b'&', &bytes[pos..]) {
+        pos += found;
+        let rest = &value[pos..];
+        let markup = if bytes[pos] == b'<' {
+            markup_len(rest, pos < tags_end)
+        } else {
+            entity_len(rest)
+        };
+        if markup.is_some() {
+            return TextType::Html;
+        }
+        pos += 1;
+    }
+    TextType::Text
+}
+
+/// Normalizes text for comparison and display
+///
+/// Trims the value, collapses each run of whitespace (including newlines
+/// and no-break spaces) to a single space, and converts the result to
+/// Unicode Normalization Form C, so that `e` followed by a combining acute
+/// accent and the precomposed `é` compare equal. Text that is already
+/// normalized is returned borrowed.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::text::normalize_text;
+///
+/// assert_eq!(normalize_text("  Breaking\n\t news "), "Breaking news");
+/// assert_eq!(normalize_text("Cafe\u{301}"), "Caf\u{e9}");
+/// assert!(matches!(normalize_text("As is"), std::borrow::Cow::Borrowed(_)));
+/// ```
+#[must_use]
+pub fn normalize_text(value: &str) -> Cow<'_, str> {
+    let trimmed = value.trim();
+    let mut after_space = false;
+    let needs_collapse = trimmed.chars().any(|c| {
+        let space = c.is_whitespace();
+        let collapse = space && (after_space || c != ' ');
+        after_space = space;
+        collapse
+    });
+    let collapsed = if needs_collapse {
+        let mut out = String::with_capacity(trimmed.len());
+        for word in trimmed.split_whitespace() {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(word);
+        }
+        Cow::Owned(out)
+    } else {
+        Cow::Borrowed(trimmed)
+    };
+    match ComposingNormalizerBorrowed::new_nfc().normalize(&collapsed) {
+        Cow::Borrowed(_) => collapsed,
+        Cow::Owned(composed) => Cow::Owned(composed),
+    }
+}
+
+/// Repairs UTF-8 text that was decoded as Windows-1252 or Latin-1
+///
+/// A publisher that reads UTF-8 bytes with the wrong encoding, then
+/// re-encodes the result, turns `’` into `â€™` and `é` into `Ã©`. This
+/// finds runs of characters whose Windows-1252 bytes form a valid UTF-8
+/// sequence and decodes them back, repeating for text that was
+/// double-encoded more than once. Each run must be a complete multi-byte
+/// sequence, so ordinary accented text such as `Ça va` or `Zoë` is left
+/// alone. Text with nothing to repair is returned borrowed.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::text::fix_mojibake;
+///
+/// assert_eq!(fix_mojibake("It\u{e2}\u{20ac}\u{2122}s here"), "It\u{2019}s here");
+/// assert_eq!(fix_mojibake("Caf\u{c3}\u{a9} cr\u{c3}\u{a8}me"), "Caf\u{e9} cr\u{e8}me");
+/// assert_eq!(fix_mojibake("\u{c7}a va, Zo\u{eb}?"), "\u{c7}a va, Zo\u{eb}?");
+/// ```
+#[must_use]
+pub fn fix_mojibake(text: &str) -> Cow<'_, str> {
+    let mut fixed = Cow::Borrowed(text);
+    // Each pass shortens the text, so this ends; the bound caps the work
+    for _ in 0..3 {
+        match fix_mojibake_once(&fixed) {
+            Some(repaired) => fixed = Cow::Owned(repaired),
+            None => break,
+        }
+    }
+    fixed
+}
+
+/// One repair pass of [`fix_mojibake`], or `None` if nothing was repaired
+fn fix_mojibake_once(text: &str) -> Option<String> {
+    // Lead bytes of multi-byte sequences read as Latin-1
+    if !text.contains(|c| matches!(c, '\u{C2}'..='\u{F4}')) {
+        return None;
+    }
+    let mut out = String::new();
+    let mut copied = 0;
+    let mut chars = text.char_indices();
+    while let Some((start, c)) = chars.next() {
+        let Some(lead) = cp1252_byte(c) else {
+            continue;
+        };
+        let len = match lead {
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => continue,
+        };
+        let mut bytes = [lead, 0, 0, 0];
+        let mut rest = chars.clone();
+        let mut end = start + c.len_utf8();
+        let complete = (1..len).all(|i| match rest.next() {
+            Some((at, next)) => match cp1252_byte(next) {
+                Some(byte @ 0x80..=0xBF) => {
+                    bytes[i] = byte;
+                    end = at + next.len_utf8();
+                    true
+                }
+                _ => false,
+            },
+            None => false,
+        });
+        if let Some(decoded) = complete
+            .then(|| std::str::from_utf8(&bytes[..len]).ok())
+            .flatten()
+        {
+            out.push_str(&text[copied..start]);
+            out.push_str(decoded);
+            copied = end;
+            chars = rest;
+        }
+    }
+    if copied == 0 {
+        return None;
+    }
+    out.push_str(&text[copied..]);
+    Some(out)
+}
+
+/// Byte that `c` was decoded from, reading it as Windows-1252 or Latin-1
+fn cp1252_byte(c: char) -> Option<u8> {
+    match u32::from(c) {
+        code @ 0x80..=0xFF => u8::try_from(code).ok(),
+        _ => WINDOWS_1252
+            .iter()
+            .position(|&mapped| mapped == c)
+            .and_then(|index| u8::try_from(0x80 + index).ok()),
+    }
+}
+
+/// Offset just past the last `>` in `s`, or 0 without one
+///
+/// No tag can start at or after this offset. Callers pass
+/// `pos < tags_end` to [`markup_len`] so that a run of `<` with no `>`
+/// after it is not rescanned to the end once per `<`.
+fn tags_end(s: &str) -> usize {
+    memrchr(b'>', s.as_bytes()).map_or(0, |end| end + 1)
+}
+
+/// Length of a tag or comment at the start of `s`, if it is markup
+///
+/// `may_close` tells whether a `>` follows; without one only an
+/// unterminated comment counts as markup.
+fn markup_len(s: &str, may_close: bool) -> Option<usize> {
+    if let Some(comment) = s.strip_prefix("<!--") {
+        return Some(comment.find("-->").map_or(s.len(), |end| end + 7));
+    }
+    if !may_close {
+        return None;
+    }
+    let next = s[1..].chars().next()?;
+    if !(next.is_ascii_alphabetic() || next == '/' || next == '!') {
+        return None;
+    }
+    s.find('>').map(|end| end + 1)
+}
+
+/// Elements whose end tag may be left out, so that an unclosed one does
+/// not nest the content that follows
+const OPTIONAL_END_ELEMENTS: &[&str] = &[
+    "body", "caption", "colgroup", "dd", "dt", "head", "html", "li", "p", "rp", "rt", "tbody",
+    "td", "tfoot", "th", "thead", "tr",
+];
+
+/// Returns true if elements in `html` nest more than `max_depth` deep
+///
+/// Elements with optional end tags, like `<p>` and `<li>`, are not
+/// counted, since HTML parsers close them implicitly. An end tag closes
+/// only its own element, which overestimates the depth of misnested
+/// markup the way HTML tree builders handle it. Runs in time linear in the
+/// length of `html`.
+pub(crate) fn nesting_exceeds(html: &str, max_depth: usize) -> bool {
+    let tags_end = tags_end(html);
+    let mut open: Vec<&str> = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = memchr(b'<', &html.as_bytes()[pos..]) {
+        pos += found;
+        let Some(len) = markup_len(&html[pos..], pos < tags_end) else {
+            pos += 1;
+            continue;
+        };
+        match Tag::parse(&html[pos..pos + len]) {
+            Some(Tag::Start(name))
+                if !OPTIONAL_END_ELEMENTS
+                    .iter()
+                    .any(|optional| optional.eq_ignore_ascii_case(name)) =>
+            {
+                open.push(name);
+                if open.len() > max_depth {
+                    return true;
+                }
+            }
+            // Only the element itself is closed: misnested formatting
+            // elements leave the elements opened inside them open
+            Some(Tag::End(name)) => {
+                if let Some(index) = open.iter().rposition(|n| n.eq_ignore_ascii_case(name)) {
+                    open.remove(index);
+                }
+            }
+            _ => {}
+        }
+        pos += len;
+    }
+    false
+}
+
+/// Length of an entity reference like `&amp;` or `&#8217;` at the start of `s`
+fn entity_len(s: &str) -> Option<usize> {
+    let body = s[1..]
+        .char_indices()
+        .take(32)
+        .find(|&(_, c)| !(c.is_ascii_alphanumeric() || c == '#'));
+    match body {
+        Some((end, ';')) if end > 0 => Some(end + 2),
+        _ => None,
+    }
+}
+
+/// A start or end tag, as produced by [`markup_len`]
+enum Tag<'a> {
+    /// Start tag of an element with content
+    Start(&'a str),
+    /// End tag
+    End(&'a str),
+}
+
+impl<'a> Tag<'a> {
+    /// Element name of a tag; `None` for comments, declarations and
+    /// self-closing or void elements
+    fn parse(tag: &'a str) -> Option<Self> {
+        let inner = tag.trim_start_matches('<').trim_end_matches('>');
+        if inner.starts_with('!') {
+            return None;
+        }
+        let (closing, inner) = inner
+            .strip_prefix('/')
+            .map_or((false, inner), |inner| (true, inner));
+        let name_len = inner
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(inner.len());
+        let name = &inner[..name_len];
+        if name.is_empty() {
+            None
+        } else if closing {
+            Some(Self::End(name))
+        } else if inner.ends_with('/') || VOID_ELEMENTS.iter().any(|v| v.eq_ignore_ascii_case(name))
+        {
+            None
+        } else {
+            Some(Self::Start(name))
+        }
+    }
+}
+
+/// Update the stack of open elements for a start or end tag
+fn track_tag<'a>(tag: &'a str, open: &mut Vec<&'a str>) {
+    match Tag::parse(tag) {
+        Some(Tag::Start(name)) => open.push(name),
+        Some(Tag::End(name)) => {
+            if let Some(index) = open.iter().rposition(|n| n.eq_ignore_ascii_case(name)) {
+                open.truncate(index);
+            }
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(html: &str) -> String {
+        html_to_text(html, &HtmlToTextOptions::default())
+    }
+
+    #[test]
+    fn test_nesting_exceeds() {
+        assert!(!nesting_exceeds("<div><div>x</div></div>", 2));
+        assert!(nesting_exceeds("<div><div><div>x", 2));
+        assert!(!nesting_exceeds(
+            "<ul><li>a<li>b<li>c</ul><br><img src=x>",
+            1
+        ));
+        assert!(!nesting_exceeds(&"<a".repeat(1000), 2));
+    }
+
+    #[test]
+    fn test_unclosed_tags_stay_linear() {
+        // Each `<` used to rescan the rest of the input for a `>`
+        let text = "<a".repeat(200_000);
+        assert_eq!(detect_text_type(&text), TextType::Text);
+        assert_eq!(truncate_summary(&text, 3), "<a<…");
+    }
+
+    #[test]
+    fn test_html_to_text_blocks() {
+        assert_eq!(
+            text("<h1>Title</h1><p>One</p><p>Two</p>"),
+            "Title\n\nOne\n\nTwo"
+        );
+        assert_eq!(text("a<br>b<br/><br />c"), "a\nb\n\nc");
+        assert_eq!(text("  lots   of\n\n  space  "), "lots of space");
+    }
+
+    #[test]
+    fn test_html_to_text_entities_and_inline() {
+        assert_eq!(
+            text("<p>Fish &amp; <em>chips</em> &lt;3</p>"),
+            "Fish & chips <3"
+        );
+        assert_eq!(text("caf&eacute; &#233; &#x263A;"), "café é ☺");
+    }
+
+    #[test]
+    fn test_html_to_text_lists() {
+        assert_eq!(
+            text("<p>Items:</p><ul><li>one</li><li>two</li></ul>"),
+            "Items:\n\n- one\n- two"
+        );
+        let options = HtmlToTextOptions {
+            list_bullets: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            html_to_text("<ol><li>a</li><li>b</li></ol>", &options),
+            "a\nb"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_links() {
+        assert_eq!(
+            text(r#"<a href="https://e.com/?a=1&amp;b=2">here</a>"#),
+            "here (https://e.com/?a=1&b=2)"
+        );
+        assert_eq!(
+            text("<a href='https://e.com/'>https://e.com/</a>"),
+            "https://e.com/"
+        );
+        assert_eq!(text(r##"<a href="#top">top</a>"##), "top");
+        assert_eq!(text("<a HREF=https://e.com/>x</a>"), "x (https://e.com/)");
+        assert_eq!(text(r#"<a data-href="x" href="y">z</a>"#), "z (y)");
+        let options = HtmlToTextOptions {
+            include_link_urls: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            html_to_text(r#"<a href="https://e.com/">x</a>"#, &options),
+            "x"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_drops_scripts_and_comments() {
+        assert_eq!(
+            text("a<script>var x = '<p>';</script><!-- <b>c</b> -->b<style>p{}</style>"),
+            "ab"
+        );
+        assert_eq!(
+            text(r#"<img src="x.png" alt="A cat"> sleeping"#),
+            "A cat sleeping"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_pre() {
+        assert_eq!(
+            text("<p>Code:</p><pre>fn main() {\n    x\n}</pre>"),
+            "Code:\n\nfn main() {\n    x\n}"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_malformed() {
+        assert_eq!(text("a < b"), "a < b");
+        assert_eq!(text("<p>unclosed"), "unclosed");
+        assert_eq!(text(""), "");
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(normalize_text(""), "");
+        assert_eq!(normalize_text(" \t\n "), "");
+        assert_eq!(normalize_text("a\u{a0}\u{a0}b\r\nc"), "a b c");
+        assert_eq!(normalize_text(" Ame\u{301}lie "), "Am\u{e9}lie");
+        assert!(matches!(
+            normalize_text("one two"),
+            Cow::Borrowed("one two")
+        ));
+        assert!(matches!(
+            normalize_text(" padded "),
+            Cow::Borrowed("padded")
+        ));
+        assert!(matches!(normalize_text("a  b"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_fix_mojibake() {
+        // Windows-1252 and Latin-1 readings of U+2019
+        assert_eq!(fix_mojibake("don\u{e2}\u{20ac}\u{2122}t"), "don\u{2019}t");
+        assert_eq!(fix_mojibake("don\u{e2}\u{80}\u{99}t"), "don\u{2019}t");
+        assert_eq!(fix_mojibake("\u{f0}\u{178}\u{2dc}\u{20ac}"), "\u{1F600}");
+        // Encoded twice
+        assert_eq!(
+            fix_mojibake("\u{c3}\u{83}\u{c2}\u{a9}t\u{c3}\u{83}\u{c2}\u{a9}"),
+            "\u{e9}t\u{e9}"
+        );
+        assert!(matches!(fix_mojibake("plain ASCII"), Cow::Borrowed(_)));
+        assert!(matches!(
+            fix_mojibake("na\u{ef}ve r\u{e9}sum\u{e9} \u{c0} la"),
+            Cow::Borrowed(_)
+        ));
+        // A lead byte without enough continuation bytes is kept
+        assert_eq!(fix_mojibake("\u{e2}\u{20ac}"), "\u{e2}\u{20ac}");
+        assert_eq!(fix_mojibake("\u{c3}"), "\u{c3}");
+    }
+
+    #[test]
+    fn test_detect_text_type() {
+        assert_eq!(detect_text_type("Plain text, no markup"), TextType::Text);
+        assert_eq!(detect_text_type(""), TextType::Text);
+        assert_eq!(detect_text_type("x <3 y && z"), TextType::Text);
+        assert_eq!(detect_text_type("Line<br/>break"), TextType::Html);
+        assert_eq!(detect_text_type("bold</b> only"), TextType::Html);
+        assert_eq!(detect_text_type("<!-- note -->"), TextType::Html);
+        assert_eq!(detect_text_type("caf&#233;"), TextType::Html);
+    }
+
+    #[test]
+    fn test_truncate_summary_plain() {
+        assert_eq!(truncate_summary("short", 10), "short");
+        assert_eq!(truncate_summary("one two three", 7), "one two…");
+        assert_eq!(truncate_summary("one two three", 9), "one two…");
+        assert_eq!(truncate_summary("abcdefghij", 4), "abcd…");
+        assert_eq!(truncate_summary("héllo wörld", 8), "héllo…");
+    }
+
+    #[test]
+    fn test_truncate_summary_entities() {
+        assert_eq!(truncate_summary("a &amp; b &amp; c", 5), "a &amp; b…");
+        assert_eq!(truncate_summary("x&#8217;yz", 2), "x&#8217;…");
+        assert_eq!(truncate_summary("AT&T rocks", 4), "AT&T…");
+    }
+
+    #[test]
+    fn test_truncate_summary_html() {
+        assert_eq!(
+            truncate_summary(
+                "<div><p>First para</p><p>Second <i>para here</i></p></div>",
+                21
+            ),
+            "<div><p>First para</p><p>Second <i>para…</i></p></div>"
+        );
+        assert_eq!(
+            truncate_summary("<p>Image <img src=\"a.png\"> and <br/>text more</p>", 15),
+            "<p>Image <img src=\"a.png\"> and <br/>text…</p>"
+        );
+        assert_eq!(
+            truncate_summary("<!-- long comment -->ab cd", 3),
+            "<!-- long comment -->ab…"
+        );
+        assert_eq!(truncate_summary("1 < 2 and more", 5), "1 < 2…");
+    }
+}
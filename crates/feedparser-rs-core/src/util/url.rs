@@ -0,0 +1,368 @@
+//! URL normalization for comparison and deduplication
+//!
+//! The same article is often linked as `HTTP://Example.com:80/a/../post`,
+//! `http://example.com/post` and `http://example.com/%70ost`. [`normalize`]
+//! rewrites such variants to one canonical spelling so they compare equal,
+//! and [`strip_tracking_params`] drops the campaign parameters that make
+//! otherwise identical links differ. [`to_ascii`] and [`to_unicode`] switch
+//! internationalized host names between their punycode and display forms.
+
+use std::borrow::Cow;
+use url::{Position, Url};
+
+/// Click-tracking query parameters removed by [`strip_tracking_params`]
+///
+/// Any parameter starting with `utm_` is removed as well.
+pub const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "yclid", "igshid", "mc_cid",
+    "mc_eid",
+];
+
+/// What [`normalize_with`] does with a trailing slash on the path
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TrailingSlash {
+    /// Leave the path as written
+    #[default]
+    Keep,
+    /// Remove trailing slashes, except for the root path `/`
+    Strip,
+}
+
+/// Normalizes a URL, keeping any trailing slash
+///
+/// Equivalent to [`normalize_with`] with [`TrailingSlash::Keep`].
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::url::normalize;
+///
+/// assert_eq!(
+///     normalize("HTTP://Example.COM:80/a/./b/../post?q=%7e"),
+///     "http://example.com/a/post?q=~"
+/// );
+/// assert_eq!(normalize("https://example.com"), "https://example.com/");
+/// assert_eq!(normalize(" /relative/path "), "/relative/path");
+/// ```
+#[must_use]
+pub fn normalize(url: &str) -> String {
+    normalize_with(url, TrailingSlash::Keep)
+}
+
+/// Normalizes a URL following RFC 3986 section 6
+///
+/// - the scheme and host are lowercased
+/// - default ports (`:80` for http, `:443` for https, ...) are removed
+/// - `.` and `..` path segments are resolved
+/// - percent-encoded unreserved characters (`A-Z a-z 0-9 - . _ ~`) are
+///   decoded, and the remaining escapes use uppercase hex digits
+/// - an empty path becomes `/`
+///
+/// Input that is not an absolute URL is returned trimmed but otherwise
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::url::{TrailingSlash, normalize_with};
+///
+/// assert_eq!(
+///     normalize_with("https://example.com/post/", TrailingSlash::Strip),
+///     "https://example.com/post"
+/// );
+/// assert_eq!(
+///     normalize_with("https://example.com/", TrailingSlash::Strip),
+///     "https://example.com/"
+/// );
+/// ```
+#[must_use]
+pub fn normalize_with(url: &str, trailing_slash: TrailingSlash) -> String {
+    let url = url.trim();
+    let Ok(mut parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+
+    if !parsed.cannot_be_a_base() {
+        let mut path = normalize_percent_encoding(parsed.path());
+        if trailing_slash == TrailingSlash::Strip {
+            let trimmed = path.trim_end_matches('/');
+            if !trimmed.is_empty() {
+                path.truncate(trimmed.len());
+            }
+        }
+        parsed.set_path(&path);
+    }
+    if let Some(query) = parsed.query().map(normalize_percent_encoding) {
+        parsed.set_query(Some(&query));
+    }
+    if let Some(fragment) = parsed.fragment().map(normalize_percent_encoding) {
+        parsed.set_fragment(Some(&fragment));
+    }
+
+    parsed.into()
+}
+
+/// Converts the host of `url` to its ASCII (punycode) form
+///
+/// This is the form DNS lookups and the SSRF checks in
+/// [`is_safe_url`](crate::util::base_url::is_safe_url) use. Input that is
+/// not an absolute URL is returned trimmed but otherwise unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::url::to_ascii;
+///
+/// assert_eq!(to_ascii("https://Bücher.example/a"), "https://xn--bcher-kva.example/a");
+/// ```
+#[must_use]
+pub fn to_ascii(url: &str) -> String {
+    let url = url.trim();
+    Url::parse(url).map_or_else(|_| url.to_string(), String::from)
+}
+
+/// Converts a punycode host in `url` to Unicode for display
+///
+/// Only the host changes; the path, query and fragment keep their
+/// percent-encoding. Hosts that are not valid IDNA, IP addresses and
+/// non-URLs are returned as written.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::url::to_unicode;
+///
+/// assert_eq!(to_unicode("https://xn--bcher-kva.example/a"), "https://bücher.example/a");
+/// assert_eq!(to_unicode("https://example.com/a"), "https://example.com/a");
+/// ```
+#[must_use]
+pub fn to_unicode(url: &str) -> String {
+    let url = url.trim();
+    let Ok(parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+    match parsed.host() {
+        Some(url::Host::Domain(domain)) if domain.contains("xn--") => {
+            let (unicode, result) = idna::domain_to_unicode(domain);
+            if result.is_err() {
+                return parsed.into();
+            }
+            let mut out = String::with_capacity(parsed.as_str().len());
+            out.push_str(&parsed[..Position::BeforeHost]);
+            out.push_str(&unicode);
+            out.push_str(&parsed[Position::AfterHost..]);
+            out
+        }
+        _ => parsed.into(),
+    }
+}
+
+/// Removes click-tracking parameters from the query of `url`
+///
+/// Drops `utm_*`, the parameters in [`TRACKING_PARAMS`] and any name in
+/// `extra`, comparing names case-insensitively. The rest of the URL is left
+/// as written, so relative URLs work too; the `?` goes away when no
+/// parameter is left. Returns the input unchanged when nothing matches.
+///
+/// # Examples
+///
+/// ```
+/// use feedparser_rs::util::url::strip_tracking_params;
+///
+/// assert_eq!(
+///     strip_tracking_params("https://example.com/post?id=7&utm_source=rss&fbclid=x#top", &[] as &[&str]),
+///     "https://example.com/post?id=7#top"
+/// );
+/// assert_eq!(
+///     strip_tracking_params("/post?ref=feed", &["ref"]),
+///     "/post"
+/// );
+/// ```
+#[must_use]
+pub fn strip_tracking_params<'a, S: AsRef<str>>(url: &'a str, extra: &[S]) -> Cow<'a, str> {
+    let (before_fragment, fragment) = url.find('#').map_or((url, ""), |i| (&url[..i], &url[i..]));
+    let Some((base, query)) = before_fragment.split_once('?') else {
+        return Cow::Borrowed(url);
+    };
+
+    let is_tracking = |param: &str| {
+        let name = param.split_once('=').map_or(param, |(name, _)| name);
+        name.get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("utm_"))
+            || TRACKING_PARAMS
+                .iter()
+                .any(|known| name.eq_ignore_ascii_case(known))
+            || extra
+                .iter()
+                .any(|known| name.eq_ignore_ascii_case(known.as_ref()))
+    };
+    if !query.split('&').any(is_tracking) {
+        return Cow::Borrowed(url);
+    }
+
+    let mut out = String::with_capacity(url.len());
+    out.push_str(base);
+    for (i, param) in query
+        .split('&')
+        .filter(|param| !param.is_empty() && !is_tracking(param))
+        .enumerate()
+    {
+        out.push(if i == 0 { '?' } else { '&' });
+        out.push_str(param);
+    }
+    out.push_str(fragment);
+    Cow::Owned(out)
+}
+
+/// Decodes escaped unreserved characters and uppercases the other escapes
+fn normalize_percent_encoding(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(decoded) = bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            if decoded.is_ascii_alphanumeric() || matches!(decoded, b'-' | b'.' | b'_' | b'~') {
+                out.push(char::from(decoded));
+            } else {
+                out.push('%');
+                out.push_str(&s[i + 1..i + 3].to_ascii_uppercase());
+            }
+            i += 3;
+            continue;
+        }
+        // Escapes are ASCII, so copying up to the next '%' keeps char boundaries
+        let next = bytes[i + 1..]
+            .iter()
+            .position(|&b| b == b'%')
+            .map_or(bytes.len(), |p| i + 1 + p);
+        out.push_str(&s[i..next]);
+        i = next;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_scheme_host_and_port() {
+        assert_eq!(
+            normalize("HTTPS://WWW.Example.com:443/Path"),
+            "https://www.example.com/Path"
+        );
+        assert_eq!(
+            normalize("http://example.com:8080/"),
+            "http://example.com:8080/"
+        );
+    }
+
+    #[test]
+    fn test_normalize_dot_segments() {
+        assert_eq!(
+            normalize("http://example.com/a/b/../../c/./d"),
+            "http://example.com/c/d"
+        );
+    }
+
+    #[test]
+    fn test_normalize_percent_encoding() {
+        assert_eq!(
+            normalize("http://example.com/%7euser/%2f?a=%3d%41#%62"),
+            "http://example.com/~user/%2F?a=%3DA#b"
+        );
+        // Malformed escapes are left alone
+        assert_eq!(
+            normalize("http://example.com/100%zz"),
+            "http://example.com/100%zz"
+        );
+    }
+
+    #[test]
+    fn test_normalize_trailing_slash() {
+        assert_eq!(
+            normalize("http://example.com/post/"),
+            "http://example.com/post/"
+        );
+        assert_eq!(
+            normalize_with("http://example.com/post//?x=1", TrailingSlash::Strip),
+            "http://example.com/post?x=1"
+        );
+        assert_eq!(
+            normalize_with("http://example.com", TrailingSlash::Strip),
+            "http://example.com/"
+        );
+    }
+
+    #[test]
+    fn test_normalize_non_urls() {
+        assert_eq!(normalize("  urn:uuid:1234 "), "urn:uuid:1234");
+        assert_eq!(
+            normalize("mailto:Someone@Example.com"),
+            "mailto:Someone@Example.com"
+        );
+        assert_eq!(normalize("not a url"), "not a url");
+    }
+
+    #[test]
+    fn test_strip_tracking_params() {
+        let none: &[&str] = &[];
+        assert_eq!(
+            strip_tracking_params(
+                "https://example.com/?UTM_Medium=a&gclid=b&q=1&msclkid",
+                none
+            ),
+            "https://example.com/?q=1"
+        );
+        assert_eq!(
+            strip_tracking_params("https://example.com/?utm_source=a#frag", none),
+            "https://example.com/#frag"
+        );
+        assert_eq!(
+            strip_tracking_params("https://example.com/?a=1&&b=2&ref=x", &["ref".to_string()]),
+            "https://example.com/?a=1&b=2"
+        );
+    }
+
+    #[test]
+    fn test_strip_tracking_params_unchanged() {
+        let none: &[&str] = &[];
+        for url in [
+            "https://example.com/post",
+            "https://example.com/?utmost=1",
+            "https://example.com/#?utm_source=a",
+        ] {
+            assert!(matches!(strip_tracking_params(url, none), Cow::Borrowed(u) if u == url));
+        }
+    }
+
+    #[test]
+    fn test_idn_round_trip() {
+        let unicode = "https://user@münchen.example:8443/straße?q=ü#frag";
+        let ascii = to_ascii(unicode);
+        assert_eq!(
+            ascii,
+            "https://user@xn--mnchen-3ya.example:8443/stra%C3%9Fe?q=%C3%BC#frag"
+        );
+        assert_eq!(
+            to_unicode(&ascii),
+            "https://user@münchen.example:8443/stra%C3%9Fe?q=%C3%BC#frag"
+        );
+        assert_eq!(normalize(unicode), ascii);
+    }
+
+    #[test]
+    fn test_to_unicode_leaves_other_hosts() {
+        assert_eq!(to_unicode("http://127.0.0.1/"), "http://127.0.0.1/");
+        assert_eq!(
+            to_unicode("http://xn--invalid-.example/"),
+            "http://xn--invalid-.example/"
+        );
+        assert_eq!(to_unicode("relative/xn--path"), "relative/xn--path");
+    }
+}
@@ -0,0 +1,254 @@
+//! Atom 1.0 serializer
+
+use super::{entry_id, feed_id, rfc3339, write_element_text, write_opt_text, write_text};
+use crate::{
+    error::Result,
+    types::{Content, Entry, Link, ParsedFeed, Person, Tag, TextConstruct, TextType},
+};
+use chrono::{DateTime, Utc};
+use quick_xml::{
+    Writer,
+    events::{BytesDecl, Event},
+};
+use std::io;
+
+/// Atom 1.0 namespace
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+
+/// Serialize a parsed feed as Atom 1.0
+///
+/// # Errors
+///
+/// Returns `FeedError::IoError` if writing the XML fails.
+pub fn write_atom10(feed: &ParsedFeed) -> Result<Vec<u8>> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    let element = writer
+        .create_element("feed")
+        .with_attribute(("xmlns", ATOM_NS));
+    let element = match feed.feed.language.as_deref() {
+        Some(lang) => element.with_attribute(("xml:lang", lang)),
+        None => element,
+    };
+    element.write_inner_content(|w| write_feed(w, feed))?;
+    Ok(writer.into_inner())
+}
+
+fn write_feed(w: &mut Writer<Vec<u8>>, feed: &ParsedFeed) -> io::Result<()> {
+    let meta = &feed.feed;
+    let id = feed_id(feed);
+
+    write_text(w, "id", id)?;
+    write_text_construct(
+        w,
+        "title",
+        meta.title_detail.as_ref(),
+        meta.title.as_deref().unwrap_or_default(),
+    )?;
+    if let Some(subtitle) = meta.subtitle.as_deref() {
+        write_text_construct(w, "subtitle", meta.subtitle_detail.as_ref(), subtitle)?;
+    }
+    let updated = meta.updated.or(meta.published).or_else(|| {
+        feed.entries
+            .iter()
+            .filter_map(|e| e.updated.or(e.published))
+            .max()
+    });
+    write_date(w, "updated", updated.as_ref())?;
+    write_links(w, meta.link.as_deref(), &meta.links)?;
+    for author in authors(meta.author_detail.as_ref(), &meta.authors) {
+        write_person(w, "author", author)?;
+    }
+    for contributor in &meta.contributors {
+        write_person(w, "contributor", contributor)?;
+    }
+    for tag in &meta.tags {
+        write_category(w, tag)?;
+    }
+    if let Some(generator) = &meta.generator_detail {
+        let element = w.create_element("generator");
+        let element = match generator.uri.as_deref() {
+            Some(uri) => element.with_attribute(("uri", uri)),
+            None => element,
+        };
+        let element = match generator.version.as_deref() {
+            Some(version) => element.with_attribute(("version", version)),
+            None => element,
+        };
+        write_element_text(element, &generator.value)?;
+    } else {
+        write_opt_text(w, "generator", meta.generator.as_deref())?;
+    }
+    write_opt_text(w, "icon", meta.icon.as_deref())?;
+    let logo = meta
+        .logo
+        .as_deref()
+        .or_else(|| meta.image.as_ref().map(|i| i.url.as_str()));
+    write_opt_text(w, "logo", logo)?;
+    if let Some(rights) = meta.rights.as_deref() {
+        write_text_construct(w, "rights", meta.rights_detail.as_ref(), rights)?;
+    }
+
+    for (index, entry) in feed.entries.iter().enumerate() {
+        w.create_element("entry")
+            .write_inner_content(|w| write_entry(w, entry, &entry_id(entry, id, index)))?;
+    }
+    Ok(())
+}
+
+fn write_entry(w: &mut Writer<Vec<u8>>, entry: &Entry, id: &str) -> io::Result<()> {
+    write_text(w, "id", id)?;
+    write_text_construct(
+        w,
+        "title",
+        entry.title_detail.as_ref(),
+        entry.title.as_deref().unwrap_or_default(),
+    )?;
+    write_date(w, "updated", entry.updated.or(entry.published).as_ref())?;
+    write_date(w, "published", entry.published.as_ref())?;
+    write_links(w, entry.link.as_deref(), &entry.links)?;
+    // Atom input keeps enclosures among the links as well
+    let enclosures = entry.enclosures.iter().filter(|enclosure| {
+        !entry
+            .links
+            .iter()
+            .any(|l| l.rel.as_deref() == Some("enclosure") && l.href == enclosure.url)
+    });
+    for enclosure in enclosures {
+        let link = Link::enclosure(
+            enclosure.url.as_str(),
+            enclosure.enclosure_type.as_deref().map(Into::into),
+        );
+        write_link(w, &link, enclosure.length)?;
+    }
+    for author in authors(entry.author_detail.as_ref(), &entry.authors) {
+        write_person(w, "author", author)?;
+    }
+    for contributor in &entry.contributors {
+        write_person(w, "contributor", contributor)?;
+    }
+    for tag in &entry.tags {
+        write_category(w, tag)?;
+    }
+    if let Some(summary) = entry.summary.as_deref() {
+        write_text_construct(w, "summary", entry.summary_detail.as_ref(), summary)?;
+    }
+    if let Some(content) = entry.content.first() {
+        write_content(w, content)?;
+    }
+    Ok(())
+}
+
+/// All authors, with the detailed primary author first if not already listed
+fn authors<'a>(detail: Option<&'a Person>, authors: &'a [Person]) -> Vec<&'a Person> {
+    let mut out: Vec<&Person> = authors.iter().collect();
+    if let Some(detail) = detail
+        && !authors
+            .iter()
+            .any(|a| a.name == detail.name && a.email == detail.email)
+    {
+        out.insert(0, detail);
+    }
+    out
+}
+
+fn write_text_construct(
+    w: &mut Writer<Vec<u8>>,
+    name: &str,
+    detail: Option<&TextConstruct>,
+    value: &str,
+) -> io::Result<()> {
+    // XHTML is written as escaped HTML to avoid emitting unchecked markup
+    let content_type = match detail.map(|d| d.content_type) {
+        Some(TextType::Html | TextType::Xhtml) => "html",
+        Some(TextType::Text) | None => "text",
+    };
+    write_element_text(
+        w.create_element(name)
+            .with_attribute(("type", content_type)),
+        value,
+    )?;
+    Ok(())
+}
+
+fn write_content(w: &mut Writer<Vec<u8>>, content: &Content) -> io::Result<()> {
+    let content_type = match content.content_type.as_deref() {
+        Some("text/html" | "html" | "application/xhtml+xml" | "xhtml") => "html",
+        Some("text/plain" | "text") | None => "text",
+        Some(other) => other,
+    };
+    write_element_text(
+        w.create_element("content")
+            .with_attribute(("type", content_type)),
+        &content.value,
+    )?;
+    Ok(())
+}
+
+fn write_date(w: &mut Writer<Vec<u8>>, name: &str, date: Option<&DateTime<Utc>>) -> io::Result<()> {
+    write_opt_text(w, name, date.map(rfc3339).as_deref())
+}
+
+/// Write the primary alternate link plus all other links, without duplicates
+fn write_links(w: &mut Writer<Vec<u8>>, primary: Option<&str>, links: &[Link]) -> io::Result<()> {
+    let has_primary = primary.is_some_and(|href| {
+        links
+            .iter()
+            .any(|l| l.href == href && l.rel.as_deref().unwrap_or("alternate") == "alternate")
+    });
+    if let Some(href) = primary
+        && !has_primary
+    {
+        write_link(w, &Link::alternate(href), None)?;
+    }
+    for link in links {
+        write_link(w, link, link.length)?;
+    }
+    Ok(())
+}
+
+fn write_link(w: &mut Writer<Vec<u8>>, link: &Link, length: Option<u64>) -> io::Result<()> {
+    let length = length.map(|l| l.to_string());
+    let mut element = w
+        .create_element("link")
+        .with_attribute(("href", link.href.as_str()))
+        .with_attribute(("rel", link.rel.as_deref().unwrap_or("alternate")));
+    if let Some(link_type) = link.link_type.as_deref() {
+        element = element.with_attribute(("type", link_type));
+    }
+    if let Some(title) = link.title.as_deref() {
+        element = element.with_attribute(("title", title));
+    }
+    if let Some(hreflang) = link.hreflang.as_deref() {
+        element = element.with_attribute(("hreflang", hreflang));
+    }
+    if let Some(length) = length.as_deref() {
+        element = element.with_attribute(("length", length));
+    }
+    element.write_empty()?;
+    Ok(())
+}
+
+fn write_person(w: &mut Writer<Vec<u8>>, name: &str, person: &Person) -> io::Result<()> {
+    w.create_element(name).write_inner_content(|w| {
+        write_text(w, "name", person.name.as_deref().unwrap_or_default())?;
+        write_opt_text(w, "email", person.email.as_deref())?;
+        write_opt_text(w, "uri", person.uri.as_deref())?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_category(w: &mut Writer<Vec<u8>>, tag: &Tag) -> io::Result<()> {
+    let mut element = w
+        .create_element("category")
+        .with_attribute(("term", tag.term.as_str()));
+    if let Some(scheme) = tag.scheme.as_deref() {
+        element = element.with_attribute(("scheme", scheme));
+    }
+    if let Some(label) = tag.label.as_deref() {
+        element = element.with_attribute(("label", label));
+    }
+    element.write_empty()?;
+    Ok(())
+}
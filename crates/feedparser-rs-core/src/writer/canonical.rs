@@ -0,0 +1,373 @@
+//! Canonical normalized JSON export
+//!
+//! [`ParsedFeed::to_canonical_json`] writes a stable, documented schema that
+//! does not depend on Rust field names or the `serde` feature, so output can
+//! be compared across language bindings and ingested by other tools.
+//!
+//! Schema rules:
+//!
+//! - Object keys are sorted; output is compact (no whitespace)
+//! - Absent values, empty strings, empty arrays and empty objects are omitted
+//! - Dates are RFC 3339 in UTC with second precision (`2024-01-01T12:00:00Z`)
+//! - `version` is the [`FeedVersion::as_str`](crate::FeedVersion::as_str) string
+//! - Text constructs are flattened to `{field}` plus `{field}_type`
+//!   (`text`, `html` or `xhtml`)
+//!
+//! Top-level keys: `schema` (always `feedparser-rs/canonical/1`), `version`,
+//! `bozo`, `bozo_exception`, `encoding`, `href`, `feed`, `entries`.
+//!
+//! `feed` keys: `id`, `title`, `title_type`, `subtitle`, `subtitle_type`,
+//! `link`, `links`, `updated`, `published`, `authors`, `contributors`,
+//! `language`, `rights`, `generator`, `image`, `icon`, `logo`, `tags`, `ttl`.
+//!
+//! Entry keys: `id`, `title`, `title_type`, `link`, `links`, `summary`,
+//! `summary_type`, `content`, `published`, `updated`, `authors`,
+//! `contributors`, `tags`, `enclosures`, `comments`.
+//!
+//! Nested objects: links are `{href, rel, type, title, length, hreflang}`,
+//! people `{name, email, uri}`, tags `{term, scheme, label}`, images
+//! `{url, title, link, width, height}`, content `{value, type, language}` and
+//! enclosures `{url, type, length}`. When a primary author is known but not
+//! listed in `authors`, it is prepended so `authors` is always complete.
+
+use super::rfc3339;
+use crate::types::{
+    Content, Enclosure, Entry, FeedMeta, Image, Link, ParsedFeed, Person, Tag, TextConstruct,
+    TextType,
+};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+/// Identifier of the canonical schema revision
+const SCHEMA: &str = "feedparser-rs/canonical/1";
+
+impl ParsedFeed {
+    /// Export this feed as canonical normalized JSON
+    ///
+    /// See the [`writer::canonical`](crate::writer::canonical) module for
+    /// the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(b"<rss version='2.0'><channel><title>T</title></channel></rss>").unwrap();
+    /// let json = feed.to_canonical_json();
+    /// assert!(json.contains(r#""title":"T""#));
+    /// assert!(json.contains(r#""version":"rss20""#));
+    /// ```
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        let mut root = Object::new();
+        root.str("schema", Some(SCHEMA));
+        root.str("version", Some(self.version.as_str()));
+        root.insert("bozo", Value::Bool(self.bozo));
+        root.str("bozo_exception", self.bozo_exception.as_deref());
+        root.str("encoding", Some(&self.encoding));
+        root.str("href", self.href.as_deref());
+        root.object("feed", feed_meta(&self.feed));
+        root.insert(
+            "entries",
+            Value::Array(self.entries.iter().map(entry).collect()),
+        );
+        Value::Object(root.0).to_string()
+    }
+}
+
+impl FeedMeta {
+    /// Export this metadata as the canonical JSON `feed` object
+    ///
+    /// The same object [`ParsedFeed::to_canonical_json`] writes under
+    /// `feed`, so two values are canonically equal when these strings are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(b"<rss version='2.0'><channel><title>T</title></channel></rss>").unwrap();
+    /// assert_eq!(feed.feed.to_canonical_json(), r#"{"title":"T","title_type":"text"}"#);
+    /// ```
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        Value::Object(feed_meta(self).0).to_string()
+    }
+}
+
+impl Entry {
+    /// Export this entry as a canonical JSON `entries` element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feedparser_rs::parse;
+    ///
+    /// let feed = parse(b"<rss version='2.0'><channel><item><guid>a</guid></item></channel></rss>")
+    ///     .unwrap();
+    /// assert_eq!(feed.entries[0].to_canonical_json(), r#"{"id":"a"}"#);
+    /// ```
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        entry(self).to_string()
+    }
+}
+
+fn feed_meta(meta: &FeedMeta) -> Object {
+    let mut out = Object::new();
+    out.str("id", meta.id.as_deref());
+    out.text("title", meta.title.as_deref(), meta.title_detail.as_ref());
+    out.text(
+        "subtitle",
+        meta.subtitle.as_deref(),
+        meta.subtitle_detail.as_ref(),
+    );
+    out.str("link", meta.link.as_deref());
+    out.array("links", meta.links.iter().map(link));
+    out.date("updated", meta.updated.as_ref());
+    out.date("published", meta.published.as_ref());
+    out.array(
+        "authors",
+        people(meta.author_detail.as_ref(), &meta.authors).map(person),
+    );
+    out.array("contributors", meta.contributors.iter().map(person));
+    out.str("language", meta.language.as_deref());
+    out.str("rights", meta.rights.as_deref());
+    out.str(
+        "generator",
+        meta.generator
+            .as_deref()
+            .or_else(|| meta.generator_detail.as_ref().map(|g| g.value.as_str())),
+    );
+    if let Some(img) = &meta.image {
+        out.object("image", image(img));
+    }
+    out.str("icon", meta.icon.as_deref());
+    out.str("logo", meta.logo.as_deref());
+    out.array("tags", meta.tags.iter().map(tag));
+    if let Some(ttl) = meta.ttl {
+        out.insert("ttl", Value::from(ttl));
+    }
+    out
+}
+
+fn entry(entry: &Entry) -> Value {
+    let mut out = Object::new();
+    out.str("id", entry.id.as_deref());
+    out.text("title", entry.title.as_deref(), entry.title_detail.as_ref());
+    out.str("link", entry.link.as_deref());
+    out.array("links", entry.links.iter().map(link));
+    out.text(
+        "summary",
+        entry.summary.as_deref(),
+        entry.summary_detail.as_ref(),
+    );
+    out.array("content", entry.content.iter().map(content));
+    out.date("published", entry.published.as_ref());
+    out.date("updated", entry.updated.as_ref());
+    out.array(
+        "authors",
+        people(entry.author_detail.as_ref(), &entry.authors).map(person),
+    );
+    out.array("contributors", entry.contributors.iter().map(person));
+    out.array("tags", entry.tags.iter().map(tag));
+    out.array("enclosures", entry.enclosures.iter().map(enclosure));
+    out.str("comments", entry.comments.as_deref());
+    Value::Object(out.0)
+}
+
+/// Primary author (if not already listed) followed by all listed authors
+fn people<'a>(
+    detail: Option<&'a Person>,
+    authors: &'a [Person],
+) -> impl Iterator<Item = &'a Person> {
+    let extra = detail.filter(|d| {
+        !authors
+            .iter()
+            .any(|a| a.name == d.name && a.email == d.email)
+    });
+    extra.into_iter().chain(authors)
+}
+
+fn link(link: &Link) -> Value {
+    let mut out = Object::new();
+    out.str("href", Some(&link.href));
+    out.str("rel", link.rel.as_deref());
+    out.str("type", link.link_type.as_deref());
+    out.str("title", link.title.as_deref());
+    if let Some(length) = link.length {
+        out.insert("length", Value::from(length));
+    }
+    out.str("hreflang", link.hreflang.as_deref());
+    Value::Object(out.0)
+}
+
+fn person(person: &Person) -> Value {
+    let mut out = Object::new();
+    out.str("name", person.name.as_deref());
+    out.str("email", person.email.as_deref());
+    out.str("uri", person.uri.as_deref());
+    Value::Object(out.0)
+}
+
+fn tag(tag: &Tag) -> Value {
+    let mut out = Object::new();
+    out.str("term", Some(&tag.term));
+    out.str("scheme", tag.scheme.as_deref());
+    out.str("label", tag.label.as_deref());
+    Value::Object(out.0)
+}
+
+fn image(image: &Image) -> Object {
+    let mut out = Object::new();
+    out.str("url", Some(&image.url));
+    out.str("title", image.title.as_deref());
+    out.str("link", image.link.as_deref());
+    if let Some(width) = image.width {
+        out.insert("width", Value::from(width));
+    }
+    if let Some(height) = image.height {
+        out.insert("height", Value::from(height));
+    }
+    out
+}
+
+fn content(content: &Content) -> Value {
+    let mut out = Object::new();
+    out.insert("value", Value::from(content.value.as_str()));
+    out.str("type", content.content_type.as_deref());
+    out.str("language", content.language.as_deref());
+    Value::Object(out.0)
+}
+
+fn enclosure(enclosure: &Enclosure) -> Value {
+    let mut out = Object::new();
+    out.str("url", Some(&enclosure.url));
+    out.str("type", enclosure.enclosure_type.as_deref());
+    if let Some(length) = enclosure.length {
+        out.insert("length", Value::from(length));
+    }
+    Value::Object(out.0)
+}
+
+const fn text_type(text_type: TextType) -> &'static str {
+    match text_type {
+        TextType::Text => "text",
+        TextType::Html => "html",
+        TextType::Xhtml => "xhtml",
+    }
+}
+
+/// JSON object builder that drops empty values
+struct Object(Map<String, Value>);
+
+impl Object {
+    fn new() -> Self {
+        Self(Map::new())
+    }
+
+    fn insert(&mut self, key: &str, value: Value) {
+        self.0.insert(key.to_string(), value);
+    }
+
+    fn str(&mut self, key: &str, value: Option<&str>) {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            self.insert(key, Value::from(value));
+        }
+    }
+
+    fn text(&mut self, key: &str, value: Option<&str>, detail: Option<&TextConstruct>) {
+        if value.is_some_and(|v| !v.is_empty()) {
+            self.str(key, value);
+            let kind = detail.map_or(TextType::Text, |d| d.content_type);
+            self.insert(&format!("{key}_type"), Value::from(text_type(kind)));
+        }
+    }
+
+    fn date(&mut self, key: &str, value: Option<&DateTime<Utc>>) {
+        if let Some(date) = value {
+            self.insert(key, Value::from(rfc3339(date)));
+        }
+    }
+
+    fn array(&mut self, key: &str, values: impl Iterator<Item = Value>) {
+        let values: Vec<Value> = values.collect();
+        if !values.is_empty() {
+            self.insert(key, Value::Array(values));
+        }
+    }
+
+    fn object(&mut self, key: &str, value: Self) {
+        if !value.0.is_empty() {
+            self.insert(key, Value::Object(value.0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use serde_json::Value;
+
+    #[test]
+    fn test_canonical_schema() {
+        let xml = br#"<rss version="2.0"><channel>
+            <title>Example</title>
+            <link>https://example.com/</link>
+            <item>
+                <title>Post</title>
+                <guid>urn:1</guid>
+                <pubDate>Mon, 01 Jan 2024 12:00:00 +0200</pubDate>
+                <category>rust</category>
+            </item>
+        </channel></rss>"#;
+        let json = parse(xml).unwrap().to_canonical_json();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["schema"], "feedparser-rs/canonical/1");
+        assert_eq!(value["version"], "rss20");
+        assert_eq!(value["bozo"], false);
+        assert_eq!(value["feed"]["title"], "Example");
+        assert_eq!(value["feed"]["title_type"], "text");
+        assert_eq!(value["entries"][0]["id"], "urn:1");
+        assert_eq!(value["entries"][0]["published"], "2024-01-01T10:00:00Z");
+        assert_eq!(value["entries"][0]["tags"][0]["term"], "rust");
+    }
+
+    #[test]
+    fn test_omits_empty_values() {
+        let json = parse(b"<rss version='2.0'><channel><title>T</title></channel></rss>")
+            .unwrap()
+            .to_canonical_json();
+        assert!(!json.contains("null"));
+        assert!(!json.contains("\"links\""));
+        assert!(!json.contains("\"subtitle\""));
+        assert!(json.contains(r#""entries":[]"#));
+    }
+
+    #[test]
+    fn test_stable_across_formats() {
+        let rss = br#"<rss version="2.0"><channel><title>T</title>
+            <item><title>A</title><guid>urn:a</guid></item></channel></rss>"#;
+        let atom = br#"<feed xmlns="http://www.w3.org/2005/Atom"><title>T</title>
+            <entry><title>A</title><id>urn:a</id></entry></feed>"#;
+
+        let mut from_rss: Value =
+            serde_json::from_str(&parse(rss).unwrap().to_canonical_json()).unwrap();
+        let mut from_atom: Value =
+            serde_json::from_str(&parse(atom).unwrap().to_canonical_json()).unwrap();
+        from_rss["version"] = Value::Null;
+        from_atom["version"] = Value::Null;
+        assert_eq!(from_rss, from_atom);
+    }
+
+    #[test]
+    fn test_keys_sorted() {
+        let json = parse(b"<rss version='2.0'><channel><title>T</title></channel></rss>")
+            .unwrap()
+            .to_canonical_json();
+        let bozo = json.find("\"bozo\"").unwrap();
+        let version = json.find("\"version\"").unwrap();
+        assert!(bozo < version);
+    }
+}
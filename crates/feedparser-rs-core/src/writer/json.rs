@@ -0,0 +1,194 @@
+//! JSON Feed 1.0/1.1 serializer
+
+use super::{entry_id, feed_id, primary_author, rfc3339};
+use crate::{
+    error::Result,
+    types::{Entry, FeedVersion, ParsedFeed, Person},
+};
+use serde_json::{Map, Value, json};
+
+/// Serialize a parsed feed as JSON Feed
+///
+/// `version` selects between JSON Feed 1.0 (single `author` object) and
+/// JSON Feed 1.1 (`authors` array and `language`); any other value is
+/// written as 1.1.
+///
+/// # Errors
+///
+/// Returns `FeedError::JsonError` if serialization fails.
+pub fn write_json_feed(feed: &ParsedFeed, version: FeedVersion) -> Result<Vec<u8>> {
+    let v11 = version != FeedVersion::JsonFeed10;
+    let meta = &feed.feed;
+    let mut root = Map::new();
+
+    let version_url = if v11 {
+        "https://jsonfeed.org/version/1.1"
+    } else {
+        "https://jsonfeed.org/version/1"
+    };
+    root.insert("version".into(), json!(version_url));
+    root.insert(
+        "title".into(),
+        json!(meta.title.as_deref().unwrap_or_default()),
+    );
+    insert_opt(&mut root, "home_page_url", meta.link.as_deref());
+    let feed_url = meta
+        .links
+        .iter()
+        .find(|l| l.rel.as_deref() == Some("self"))
+        .map(|l| l.href.as_str())
+        .or(feed.href.as_deref());
+    insert_opt(&mut root, "feed_url", feed_url);
+    insert_opt(&mut root, "description", meta.subtitle.as_deref());
+    let icon = meta
+        .image
+        .as_ref()
+        .map(|i| i.url.as_str())
+        .or(meta.logo.as_deref());
+    insert_opt(&mut root, "icon", icon);
+    insert_opt(&mut root, "favicon", meta.icon.as_deref());
+    insert_authors(
+        &mut root,
+        primary_author(meta.author_detail.as_ref(), &meta.authors),
+        &meta.authors,
+        v11,
+    );
+    if v11 {
+        insert_opt(&mut root, "language", meta.language.as_deref());
+    }
+    if meta.expired {
+        root.insert("expired".into(), json!(true));
+    }
+
+    let id = feed_id(feed);
+    let items: Vec<Value> = feed
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| item_to_json(entry, &entry_id(entry, id, index), v11))
+        .collect();
+    root.insert("items".into(), Value::Array(items));
+
+    Ok(serde_json::to_vec_pretty(&Value::Object(root))?)
+}
+
+fn item_to_json(entry: &Entry, id: &str, v11: bool) -> Value {
+    let mut item = Map::new();
+    item.insert("id".into(), json!(id));
+    insert_opt(&mut item, "url", entry.link.as_deref());
+    let external_url = entry.external_url.as_deref().or_else(|| {
+        entry
+            .links
+            .iter()
+            .find(|l| l.rel.as_deref() == Some("related"))
+            .map(|l| l.href.as_str())
+    });
+    insert_opt(&mut item, "external_url", external_url);
+    insert_opt(&mut item, "title", entry.title.as_deref());
+
+    let content = entry.content.first();
+    let is_html =
+        content.is_none_or(|c| !matches!(c.content_type.as_deref(), Some("text/plain" | "text")));
+    let body = content
+        .map(|c| c.value.as_str())
+        .or(entry.summary.as_deref())
+        .unwrap_or_default();
+    let body_key = if is_html {
+        "content_html"
+    } else {
+        "content_text"
+    };
+    item.insert(body_key.into(), json!(body));
+    if content.is_some() {
+        insert_opt(&mut item, "summary", entry.summary.as_deref());
+    }
+    insert_opt(
+        &mut item,
+        "image",
+        entry.media_thumbnails.first().map(|t| t.url.as_str()),
+    );
+    insert_opt(&mut item, "banner_image", entry.banner_image.as_deref());
+
+    insert_opt(
+        &mut item,
+        "date_published",
+        entry.published.as_ref().map(rfc3339).as_deref(),
+    );
+    insert_opt(
+        &mut item,
+        "date_modified",
+        entry.updated.as_ref().map(rfc3339).as_deref(),
+    );
+    insert_authors(
+        &mut item,
+        primary_author(entry.author_detail.as_ref(), &entry.authors),
+        &entry.authors,
+        v11,
+    );
+    if !entry.tags.is_empty() {
+        let tags: Vec<&str> = entry.tags.iter().map(|t| t.term.as_str()).collect();
+        item.insert("tags".into(), json!(tags));
+    }
+    if !entry.enclosures.is_empty() {
+        let attachments: Vec<Value> = entry
+            .enclosures
+            .iter()
+            .map(|e| {
+                let mut attachment = Map::new();
+                attachment.insert("url".into(), json!(e.url.as_str()));
+                attachment.insert(
+                    "mime_type".into(),
+                    json!(
+                        e.enclosure_type
+                            .as_deref()
+                            .unwrap_or("application/octet-stream")
+                    ),
+                );
+                if let Some(length) = e.length {
+                    attachment.insert("size_in_bytes".into(), json!(length));
+                }
+                Value::Object(attachment)
+            })
+            .collect();
+        item.insert("attachments".into(), Value::Array(attachments));
+    }
+
+    Value::Object(item)
+}
+
+fn insert_authors(
+    map: &mut Map<String, Value>,
+    primary: Option<&Person>,
+    authors: &[Person],
+    v11: bool,
+) {
+    if v11 {
+        let list: Vec<Value> = if authors.is_empty() {
+            primary.into_iter().map(person_to_json).collect()
+        } else {
+            authors.iter().map(person_to_json).collect()
+        };
+        if !list.is_empty() {
+            map.insert("authors".into(), Value::Array(list));
+        }
+    } else if let Some(author) = primary {
+        map.insert("author".into(), person_to_json(author));
+    }
+}
+
+fn person_to_json(person: &Person) -> Value {
+    let mut author = Map::new();
+    if let Some(name) = person.name.as_deref() {
+        author.insert("name".into(), json!(name));
+    }
+    if let Some(uri) = person.uri.as_deref() {
+        author.insert("url".into(), json!(uri));
+    }
+    Value::Object(author)
+}
+
+fn insert_opt(map: &mut Map<String, Value>, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        map.insert(key.into(), json!(value));
+    }
+}
@@ -0,0 +1,287 @@
+//! Feed serializers and cross-format conversion
+//!
+//! Serializes a [`ParsedFeed`] back to RSS 2.0, Atom 1.0, or JSON Feed, and
+//! provides [`convert`] to normalize any supported input format into one of
+//! those targets. The [`canonical`] module adds a format-independent JSON
+//! export with a stable schema.
+//!
+//! Conversion is lossy but predictable. Only the fields common to all formats
+//! are written; namespace extensions (iTunes, Podcast 2.0, Media RSS, `GeoRSS`,
+//! Dublin Core) are dropped. The mapping is:
+//!
+//! | Field              | RSS 2.0                     | Atom 1.0                  | JSON Feed                    |
+//! |--------------------|-----------------------------|---------------------------|------------------------------|
+//! | `feed.title`       | `title`                     | `title`                   | `title`                      |
+//! | `feed.link`        | `link`                      | `link rel="alternate"`    | `home_page_url`              |
+//! | `feed.subtitle`    | `description`               | `subtitle`                | `description`                |
+//! | `feed.id`          | —                           | `id` (synthesized)        | —                            |
+//! | `feed.updated`     | `lastBuildDate`             | `updated`                 | —                            |
+//! | `feed.published`   | `pubDate`                   | —                         | —                            |
+//! | `feed.image`       | `image`                     | `logo`                    | `icon`                       |
+//! | `feed.icon`        | —                           | `icon`                    | `favicon`                    |
+//! | `entry.id`         | `guid` (synthesized)        | `id` (synthesized)        | `id` (synthesized)           |
+//! | `entry.summary`    | `description`               | `summary`                 | `summary`                    |
+//! | `entry.content[0]` | `description` if no summary | `content`                 | `content_html`/`content_text`|
+//! | `entry.enclosures` | first `enclosure`           | `link rel="enclosure"`    | `attachments`                |
+//! | `tags`             | `category`                  | `category`                | `tags`                       |
+//!
+//! Identifiers required by the target format are synthesized when missing:
+//! the feed id falls back to the feed link, then the fetch URL, then
+//! `urn:feedparser-rs:feed`; an entry id falls back to the entry link, then
+//! `{feed id}#entry-{index}` (zero-based position in the feed).
+//!
+//! # Examples
+//!
+//! ```
+//! use feedparser_rs::{FeedVersion, convert, parse};
+//!
+//! let rss = br#"<rss version="2.0"><channel>
+//!     <title>Example</title>
+//!     <link>https://example.com/</link>
+//!     <item><title>Post</title><link>https://example.com/1</link></item>
+//! </channel></rss>"#;
+//!
+//! let atom = convert(rss, FeedVersion::Atom10).unwrap();
+//! let feed = parse(&atom).unwrap();
+//! assert_eq!(feed.version, FeedVersion::Atom10);
+//! assert_eq!(feed.entries[0].id.as_deref(), Some("https://example.com/1"));
+//! ```
+
+mod atom;
+pub mod canonical;
+mod json;
+mod rss;
+
+use crate::{
+    error::{FeedError, Result},
+    parser::parse,
+    types::{Entry, FeedVersion, ParsedFeed, Person},
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::{
+    Writer,
+    events::{BytesCData, BytesText},
+    writer::ElementWriter,
+};
+use std::io;
+
+pub use atom::write_atom10;
+pub use json::write_json_feed;
+pub use rss::write_rss20;
+
+/// Fallback feed identifier when the source provides none
+const DEFAULT_FEED_ID: &str = "urn:feedparser-rs:feed";
+
+/// Parse `data` in any supported format and serialize it as `target`
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be parsed at all, or if `target`
+/// is not a supported output format (see [`write_feed`]).
+pub fn convert(data: &[u8], target: FeedVersion) -> Result<Vec<u8>> {
+    let feed = parse(data)?;
+    write_feed(&feed, target)
+}
+
+/// Serialize a parsed feed as `target`
+///
+/// Supported targets are RSS 0.91, 0.92 and 2.0 (all written with the
+/// RSS 2.0 element set and the requested `version` attribute), Atom 1.0,
+/// and JSON Feed 1.0 and 1.1.
+///
+/// # Errors
+///
+/// Returns `FeedError::InvalidFormat` for RSS 0.90, RSS 1.0, Atom 0.3 and
+/// `Unknown` targets.
+pub fn write_feed(feed: &ParsedFeed, target: FeedVersion) -> Result<Vec<u8>> {
+    match target {
+        FeedVersion::Rss20 => write_rss20(feed),
+        FeedVersion::Rss091 => rss::write_rss(feed, "0.91"),
+        FeedVersion::Rss092 => rss::write_rss(feed, "0.92"),
+        FeedVersion::Atom10 => write_atom10(feed),
+        FeedVersion::JsonFeed10 | FeedVersion::JsonFeed11 => write_json_feed(feed, target),
+        FeedVersion::Rss090 | FeedVersion::Rss10 | FeedVersion::Atom03 | FeedVersion::Unknown => {
+            Err(FeedError::InvalidFormat(format!(
+                "Unsupported conversion target: {target:?}"
+            )))
+        }
+    }
+}
+
+/// Feed identifier with the documented fallbacks applied
+fn feed_id(feed: &ParsedFeed) -> &str {
+    feed.feed
+        .id
+        .as_deref()
+        .or(feed.feed.link.as_deref())
+        .or(feed.href.as_deref())
+        .unwrap_or(DEFAULT_FEED_ID)
+}
+
+/// Entry identifier with the documented fallbacks applied
+fn entry_id(entry: &Entry, feed_id: &str, index: usize) -> String {
+    entry
+        .id
+        .as_deref()
+        .or(entry.link.as_deref())
+        .map_or_else(|| format!("{feed_id}#entry-{index}"), str::to_string)
+}
+
+/// First author of an entry or feed, preferring the detailed form
+fn primary_author<'a>(detail: Option<&'a Person>, authors: &'a [Person]) -> Option<&'a Person> {
+    detail.or_else(|| authors.first())
+}
+
+fn rfc3339(dt: &DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn rfc2822(dt: &DateTime<Utc>) -> String {
+    dt.to_rfc2822()
+}
+
+/// Write `<name>text</name>`
+fn write_text(writer: &mut Writer<Vec<u8>>, name: &str, text: &str) -> io::Result<()> {
+    write_element_text(writer.create_element(name), text)
+}
+
+/// Finish an element with text content
+///
+/// Text containing markup characters is wrapped in CDATA, which is how
+/// HTML is conventionally embedded in feeds and keeps the output readable.
+fn write_element_text(element: ElementWriter<'_, Vec<u8>>, text: &str) -> io::Result<()> {
+    if text.contains(['<', '&']) && !text.contains("]]>") {
+        element.write_cdata_content(BytesCData::new(text))?;
+    } else {
+        element.write_text_content(BytesText::new(text))?;
+    }
+    Ok(())
+}
+
+/// Write `<name>text</name>` if `text` is present
+fn write_opt_text(writer: &mut Writer<Vec<u8>>, name: &str, text: Option<&str>) -> io::Result<()> {
+    text.map_or(Ok(()), |text| write_text(writer, name, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSS: &[u8] = br#"<?xml version="1.0"?>
+    <rss version="2.0">
+        <channel>
+            <title>Example Co</title>
+            <link>https://example.com/</link>
+            <description>An example feed</description>
+            <language>en-us</language>
+            <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate>
+            <category>news</category>
+            <item>
+                <title>First</title>
+                <link>https://example.com/1</link>
+                <description><![CDATA[<p>Hello & welcome</p>]]></description>
+                <guid isPermaLink="false">id-1</guid>
+                <pubDate>Mon, 01 Jan 2024 10:00:00 GMT</pubDate>
+                <category>rust</category>
+                <enclosure url="https://example.com/a.mp3" length="123" type="audio/mpeg"/>
+            </item>
+            <item>
+                <title>No identifiers</title>
+            </item>
+        </channel>
+    </rss>"#;
+
+    #[test]
+    fn test_rss_to_atom_round_trip() {
+        let out = convert(RSS, FeedVersion::Atom10).unwrap();
+        let feed = parse(&out).unwrap();
+        assert!(!feed.bozo, "{:?}", feed.bozo_exception);
+        assert_eq!(feed.version, FeedVersion::Atom10);
+        assert_eq!(feed.feed.title.as_deref(), Some("Example Co"));
+        assert_eq!(feed.feed.id.as_deref(), Some("https://example.com/"));
+        assert_eq!(feed.feed.link.as_deref(), Some("https://example.com/"));
+        assert_eq!(feed.entries.len(), 2);
+
+        let first = &feed.entries[0];
+        assert_eq!(first.id.as_deref(), Some("id-1"));
+        assert_eq!(first.summary.as_deref(), Some("<p>Hello &amp; welcome</p>"));
+        assert!(first.published.is_some());
+        assert_eq!(first.tags[0].term, "rust");
+        assert!(
+            first
+                .links
+                .iter()
+                .any(|l| l.rel.as_deref() == Some("enclosure"))
+        );
+
+        assert_eq!(
+            feed.entries[1].id.as_deref(),
+            Some("https://example.com/#entry-1")
+        );
+    }
+
+    #[test]
+    fn test_atom_to_rss_round_trip() {
+        let atom = convert(RSS, FeedVersion::Atom10).unwrap();
+        let out = convert(&atom, FeedVersion::Rss20).unwrap();
+        let feed = parse(&out).unwrap();
+        assert!(!feed.bozo, "{:?}", feed.bozo_exception);
+        assert_eq!(feed.version, FeedVersion::Rss20);
+        assert_eq!(feed.feed.title.as_deref(), Some("Example Co"));
+        assert_eq!(feed.entries[0].enclosures.len(), 1);
+        assert_eq!(feed.entries[0].enclosures[0].length, Some(123));
+    }
+
+    #[test]
+    fn test_rss_to_json_feed() {
+        let out = convert(RSS, FeedVersion::JsonFeed11).unwrap();
+        let feed = parse(&out).unwrap();
+        assert_eq!(feed.version, FeedVersion::JsonFeed11);
+        assert_eq!(feed.feed.title.as_deref(), Some("Example Co"));
+        assert_eq!(feed.feed.language.as_deref(), Some("en-US"));
+        assert_eq!(feed.entries.len(), 2);
+        assert_eq!(feed.entries[0].id.as_deref(), Some("id-1"));
+        assert_eq!(feed.entries[0].enclosures.len(), 1);
+    }
+
+    #[test]
+    fn test_json_feed_item_images_round_trip() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Links",
+            "items": [{
+                "id": "1",
+                "content_text": "Worth reading",
+                "external_url": "https://other.example.org/article",
+                "image": "https://example.com/square.png",
+                "banner_image": "https://example.com/banner.png"
+            }]
+        }"#;
+        let out = convert(json, FeedVersion::JsonFeed11).unwrap();
+        let entry = &parse(&out).unwrap().entries[0];
+        assert_eq!(
+            entry.external_url.as_deref(),
+            Some("https://other.example.org/article")
+        );
+        assert_eq!(
+            entry.media_thumbnails[0].url.as_str(),
+            "https://example.com/square.png"
+        );
+        assert_eq!(
+            entry.banner_image.as_deref(),
+            Some("https://example.com/banner.png")
+        );
+    }
+
+    #[test]
+    fn test_rss_version_attribute() {
+        let out = convert(RSS, FeedVersion::Rss092).unwrap();
+        assert_eq!(crate::detect_format(&out), FeedVersion::Rss092);
+    }
+
+    #[test]
+    fn test_unsupported_target() {
+        assert!(convert(RSS, FeedVersion::Rss10).is_err());
+        assert!(convert(RSS, FeedVersion::Unknown).is_err());
+    }
+}
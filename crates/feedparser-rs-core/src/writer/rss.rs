@@ -0,0 +1,176 @@
+//! RSS 2.0 serializer
+
+use super::{
+    entry_id, feed_id, primary_author, rfc2822, write_element_text, write_opt_text, write_text,
+};
+use crate::{
+    error::Result,
+    types::{Entry, ParsedFeed, Person, Tag},
+};
+use quick_xml::{
+    Writer,
+    events::{BytesDecl, Event},
+};
+use std::io;
+
+/// Serialize a parsed feed as RSS 2.0
+///
+/// # Errors
+///
+/// Returns `FeedError::IoError` if writing the XML fails.
+pub fn write_rss20(feed: &ParsedFeed) -> Result<Vec<u8>> {
+    write_rss(feed, "2.0")
+}
+
+/// Serialize with the RSS 2.0 element set and the given `version` attribute
+pub(super) fn write_rss(feed: &ParsedFeed, version: &str) -> Result<Vec<u8>> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    writer
+        .create_element("rss")
+        .with_attribute(("version", version))
+        .write_inner_content(|w| {
+            w.create_element("channel")
+                .write_inner_content(|w| write_channel(w, feed))?;
+            Ok(())
+        })?;
+    Ok(writer.into_inner())
+}
+
+fn write_channel(w: &mut Writer<Vec<u8>>, feed: &ParsedFeed) -> io::Result<()> {
+    let meta = &feed.feed;
+    write_text(w, "title", meta.title.as_deref().unwrap_or_default())?;
+    write_text(w, "link", meta.link.as_deref().unwrap_or_default())?;
+    write_text(
+        w,
+        "description",
+        meta.subtitle.as_deref().unwrap_or_default(),
+    )?;
+    write_opt_text(w, "language", meta.language.as_deref())?;
+    write_opt_text(w, "copyright", meta.rights.as_deref())?;
+    if let Some(author) = primary_author(meta.author_detail.as_ref(), &meta.authors) {
+        write_opt_text(w, "managingEditor", person_to_rss(author).as_deref())?;
+    }
+    write_opt_text(
+        w,
+        "pubDate",
+        meta.published.as_ref().map(rfc2822).as_deref(),
+    )?;
+    write_opt_text(
+        w,
+        "lastBuildDate",
+        meta.updated.as_ref().map(rfc2822).as_deref(),
+    )?;
+    write_opt_text(w, "generator", meta.generator.as_deref())?;
+    if let Some(ttl) = meta.ttl {
+        write_text(w, "ttl", &ttl.to_string())?;
+    }
+    for tag in &meta.tags {
+        write_category(w, tag)?;
+    }
+    if let Some(image) = &meta.image {
+        w.create_element("image").write_inner_content(|w| {
+            write_text(w, "url", &image.url)?;
+            write_text(
+                w,
+                "title",
+                image
+                    .title
+                    .as_deref()
+                    .or(meta.title.as_deref())
+                    .unwrap_or_default(),
+            )?;
+            write_text(
+                w,
+                "link",
+                image
+                    .link
+                    .as_deref()
+                    .or(meta.link.as_deref())
+                    .unwrap_or_default(),
+            )?;
+            Ok(())
+        })?;
+    }
+
+    let id = feed_id(feed);
+    for (index, entry) in feed.entries.iter().enumerate() {
+        w.create_element("item")
+            .write_inner_content(|w| write_item(w, entry, &entry_id(entry, id, index)))?;
+    }
+    Ok(())
+}
+
+fn write_item(w: &mut Writer<Vec<u8>>, entry: &Entry, guid: &str) -> io::Result<()> {
+    write_opt_text(w, "title", entry.title.as_deref())?;
+    write_opt_text(w, "link", entry.link.as_deref())?;
+    let description = entry
+        .summary
+        .as_deref()
+        .or_else(|| entry.content.first().map(|c| c.value.as_str()));
+    write_opt_text(w, "description", description)?;
+    if let Some(author) = primary_author(entry.author_detail.as_ref(), &entry.authors) {
+        write_opt_text(w, "author", person_to_rss(author).as_deref())?;
+    }
+    for tag in &entry.tags {
+        write_category(w, tag)?;
+    }
+    write_opt_text(w, "comments", entry.comments.as_deref())?;
+    if let Some((url, length, mime)) = first_enclosure(entry) {
+        let length = length.unwrap_or_default().to_string();
+        w.create_element("enclosure")
+            .with_attribute(("url", url))
+            .with_attribute(("length", length.as_str()))
+            .with_attribute(("type", mime.unwrap_or("application/octet-stream")))
+            .write_empty()?;
+    }
+    let is_permalink = if entry.link.as_deref() == Some(guid) {
+        "true"
+    } else {
+        "false"
+    };
+    write_element_text(
+        w.create_element("guid")
+            .with_attribute(("isPermaLink", is_permalink)),
+        guid,
+    )?;
+    let date = entry.published.as_ref().or(entry.updated.as_ref());
+    write_opt_text(w, "pubDate", date.map(rfc2822).as_deref())?;
+    Ok(())
+}
+
+/// First enclosure, falling back to the first `rel="enclosure"` link (Atom input)
+fn first_enclosure(entry: &Entry) -> Option<(&str, Option<u64>, Option<&str>)> {
+    if let Some(enclosure) = entry.enclosures.first() {
+        return Some((
+            enclosure.url.as_str(),
+            enclosure.length,
+            enclosure.enclosure_type.as_deref(),
+        ));
+    }
+    entry
+        .links
+        .iter()
+        .find(|link| link.rel.as_deref() == Some("enclosure"))
+        .map(|link| (link.href.as_str(), link.length, link.link_type.as_deref()))
+}
+
+fn write_category(w: &mut Writer<Vec<u8>>, tag: &Tag) -> io::Result<()> {
+    let element = w.create_element("category");
+    let element = match tag.scheme.as_deref() {
+        Some(scheme) => element.with_attribute(("domain", scheme)),
+        None => element,
+    };
+    write_element_text(element, &tag.term)?;
+    Ok(())
+}
+
+/// RSS author elements hold an email address, optionally followed by the name
+fn person_to_rss(person: &Person) -> Option<String> {
+    match (person.email.as_deref(), person.name.as_deref()) {
+        (Some(email), Some(name)) => Some(format!("{email} ({name})")),
+        (Some(email), None) => Some(email.to_string()),
+        (None, Some(name)) => Some(name.to_string()),
+        (None, None) => None,
+    }
+}
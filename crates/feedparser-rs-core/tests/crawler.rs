@@ -0,0 +1,293 @@
+#![allow(missing_docs, clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+#[cfg(feature = "crawler")]
+#[allow(clippy::significant_drop_tightening)]
+mod crawler_tests {
+    use chrono::{TimeDelta, TimeZone, Utc};
+    use feedparser_rs::FeedHttpClient;
+    use feedparser_rs::crawler::{CrawlEvent, CrawlReport, Crawler, CrawlerConfig, FileStore};
+    use std::cell::RefCell;
+    use std::net::Ipv4Addr;
+    use std::rc::Rc;
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+        <rss version="2.0"><channel>
+            <title>Crawled</title>
+            <ttl>120</ttl>
+            <item><guid>1</guid><title>One</title></item>
+        </channel></rss>"#;
+
+    /// Crawler whose requests for `feeds.example.com` reach the mock server
+    ///
+    /// Pinning a public name keeps the SSRF checks, which refuse loopback
+    /// URLs, out of the way.
+    fn crawler() -> Crawler {
+        let client = FeedHttpClient::new()
+            .unwrap()
+            .with_resolve("feeds.example.com", Ipv4Addr::LOCALHOST.into())
+            .unwrap();
+        Crawler::new(client, CrawlerConfig::default())
+    }
+
+    fn feed_url(server: &mockito::Server, path: &str) -> String {
+        format!(
+            "http://feeds.example.com:{}{path}",
+            server.socket_address().port()
+        )
+    }
+
+    #[test]
+    fn test_crawl_then_not_modified() {
+        let mut server = mockito::Server::new();
+        let fresh = server
+            .mock("GET", "/feed.xml")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_header("etag", "\"v1\"")
+            .with_body(RSS)
+            .create();
+        let unchanged = server
+            .mock("GET", "/feed.xml")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .create();
+
+        let mut crawler = crawler();
+        let id = crawler.subscribe(feed_url(&server, "/feed.xml")).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let mut titles = Vec::new();
+        let report = crawler
+            .crawl_due_at(now, |feed_id, feed| {
+                titles.push((feed_id, feed.feed.title));
+            })
+            .unwrap();
+        assert_eq!(
+            report,
+            CrawlReport {
+                updated: 1,
+                ..CrawlReport::default()
+            }
+        );
+        assert_eq!(titles, [(id, Some("Crawled".to_string()))]);
+        fresh.assert();
+
+        // `<ttl>120</ttl>` schedules the next poll two hours later
+        let sub = crawler.subscription(id).unwrap();
+        assert_eq!(sub.cache.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(sub.next_fetch, now + TimeDelta::hours(2));
+
+        // Nothing is due before then
+        let report = crawler
+            .crawl_due_at(now + TimeDelta::hours(1), |_, _| panic!("not due"))
+            .unwrap();
+        assert_eq!(report, CrawlReport::default());
+
+        let later = now + TimeDelta::hours(2);
+        let report = crawler
+            .crawl_due_at(later, |_, _| panic!("feed is unchanged"))
+            .unwrap();
+        assert_eq!(report.not_modified, 1);
+        unchanged.assert();
+        assert_eq!(
+            crawler.subscription(id).unwrap().next_fetch,
+            later + TimeDelta::hours(2)
+        );
+    }
+
+    #[test]
+    fn test_failure_backs_off() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/busy.xml")
+            .with_status(503)
+            .with_header("retry-after", "3600")
+            .create();
+        server.mock("GET", "/gone.xml").with_status(404).create();
+
+        let mut crawler = crawler();
+        let busy = crawler.subscribe(feed_url(&server, "/busy.xml")).unwrap();
+        let gone = crawler.subscribe(feed_url(&server, "/gone.xml")).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let report = crawler
+            .crawl_due_at(now, |_, _| panic!("no feed expected"))
+            .unwrap();
+        assert_eq!(report.failed, 2);
+
+        let busy = crawler.subscription(busy).unwrap();
+        assert_eq!(busy.failures, 1);
+        assert!(busy.last_error.as_deref().unwrap().contains("503"));
+        assert_eq!(busy.next_fetch, now + TimeDelta::hours(1));
+
+        let gone = crawler.subscription(gone).unwrap();
+        assert_eq!(gone.next_fetch, now + TimeDelta::minutes(5));
+        assert_eq!(crawler.next_due(), Some(now + TimeDelta::minutes(5)));
+    }
+
+    #[test]
+    fn test_many_feeds_one_host() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", mockito::Matcher::Regex("^/feed/".to_string()))
+            .with_body(RSS)
+            .expect(10)
+            .create();
+
+        let mut crawler = crawler();
+        let ids: Vec<_> = (0..10)
+            .map(|i| {
+                crawler
+                    .subscribe(feed_url(&server, &format!("/feed/{i}")))
+                    .unwrap()
+            })
+            .collect();
+
+        let mut seen = Vec::new();
+        let report = crawler.crawl_due(|id, _| seen.push(id)).unwrap();
+        assert_eq!(report.updated, 10);
+        seen.sort();
+        assert_eq!(seen, ids);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_file_store_survives_restart() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/feed.xml")
+            .with_header("etag", "\"v1\"")
+            .with_body(RSS)
+            .create();
+        let dir =
+            std::env::temp_dir().join(format!("feedparser-rs-crawler-{}", std::process::id()));
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let mut first = crawler()
+            .with_store(FileStore::open(&dir).unwrap())
+            .unwrap();
+        let id = first.subscribe(feed_url(&server, "/feed.xml")).unwrap();
+        first.crawl_due_at(now, |_, _| {}).unwrap();
+        drop(first);
+
+        let restarted = crawler()
+            .with_store(FileStore::open(&dir).unwrap())
+            .unwrap();
+        let sub = restarted.subscription(id).unwrap();
+        assert_eq!(sub.cache.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(sub.next_fetch, now + TimeDelta::hours(2));
+        let snapshot = restarted.store().snapshot(id).unwrap().unwrap();
+        assert_eq!(snapshot.feed.title.as_deref(), Some("Crawled"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Registers a callback collecting events with their feed ids
+    fn record(crawler: &mut Crawler) -> Rc<RefCell<Vec<CrawlEvent>>> {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&events);
+        crawler.on_event(move |_, event| sink.borrow_mut().push(event.clone()));
+        events
+    }
+
+    #[test]
+    fn test_events_for_new_entries_and_metadata() {
+        let mut server = mockito::Server::new();
+        let first = server.mock("GET", "/feed.xml").with_body(RSS).create();
+
+        let mut crawler = crawler();
+        let events = record(&mut crawler);
+        crawler.subscribe(feed_url(&server, "/feed.xml")).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        crawler.crawl_due_at(now, |_, _| {}).unwrap();
+        first.assert();
+        let seen = events.take();
+        assert_eq!(seen.len(), 1);
+        let CrawlEvent::NewEntries(entries) = &seen[0] else {
+            panic!("expected new entries, got {seen:?}");
+        };
+        assert_eq!(entries[0].title.as_deref(), Some("One"));
+
+        first.remove();
+        server
+            .mock("GET", "/feed.xml")
+            .with_body(
+                RSS.replace("<title>Crawled</title>", "<title>Renamed</title>")
+                    .replace("</channel>", "<item><guid>2</guid></item></channel>"),
+            )
+            .create();
+        crawler
+            .crawl_due_at(now + TimeDelta::hours(2), |_, _| {})
+            .unwrap();
+        let seen = events.take();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], CrawlEvent::MetadataChanged(vec!["title"]));
+        let CrawlEvent::NewEntries(entries) = &seen[1] else {
+            panic!("expected new entries, got {seen:?}");
+        };
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_permanent_redirect_moves_subscription() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/old.xml")
+            .with_status(301)
+            .with_header("location", "/new.xml")
+            .create();
+        let new = server
+            .mock("GET", "/new.xml")
+            .with_body(RSS)
+            .expect(2)
+            .create();
+
+        let mut crawler = crawler();
+        let events = record(&mut crawler);
+        let id = crawler.subscribe(feed_url(&server, "/old.xml")).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        crawler.crawl_due_at(now, |_, _| {}).unwrap();
+        assert_eq!(
+            events.borrow()[0],
+            CrawlEvent::PermanentRedirect {
+                from: feed_url(&server, "/old.xml"),
+                to: feed_url(&server, "/new.xml"),
+            }
+        );
+        assert_eq!(
+            crawler.subscription(id).unwrap().url,
+            feed_url(&server, "/new.xml")
+        );
+
+        // The next poll goes straight to the new URL
+        crawler
+            .crawl_due_at(now + TimeDelta::hours(2), |_, _| {})
+            .unwrap();
+        new.assert();
+    }
+
+    #[test]
+    fn test_repeated_failures_reported() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/down.xml").with_status(500).create();
+
+        let mut crawler = crawler();
+        let events = record(&mut crawler);
+        crawler.subscribe(feed_url(&server, "/down.xml")).unwrap();
+        let mut now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        for _ in 0..3 {
+            crawler.crawl_due_at(now, |_, _| {}).unwrap();
+            now += TimeDelta::days(1);
+        }
+        let seen = events.take();
+        assert_eq!(seen.len(), 1);
+        let CrawlEvent::RepeatedFailures { failures, error } = &seen[0] else {
+            panic!("expected repeated failures, got {seen:?}");
+        };
+        assert_eq!(*failures, 3);
+        assert!(error.contains("500"));
+    }
+}
@@ -118,8 +118,11 @@ mod http_tests {
             body,
             etag,
             last_modified,
+            last_modified_parsed: None,
+            date_parsed: None,
             content_type,
             encoding,
+            permanent_redirect: None,
         })
     }
 
@@ -607,6 +610,60 @@ mod http_tests {
         assert_eq!(response.etag.as_deref(), Some("\"final-etag\""));
     }
 
+    #[test]
+    fn test_permanent_redirect_reported() {
+        let mut server = mockito::Server::new();
+        let base = format!(
+            "http://feeds.example.com:{}",
+            server.socket_address().port()
+        );
+        server
+            .mock("GET", "/moved.xml")
+            .with_status(301)
+            .with_header("location", "/new.xml")
+            .create();
+        server
+            .mock("GET", "/new.xml")
+            .with_status(308)
+            .with_header("location", &format!("{base}/final.xml"))
+            .create();
+        server
+            .mock("GET", "/temp.xml")
+            .with_status(302)
+            .with_header("location", "/final.xml")
+            .create();
+        server
+            .mock("GET", "/final.xml")
+            .with_body("<rss></rss>")
+            .create();
+
+        // Pinning a public name keeps the SSRF checks out of the way
+        let client = FeedHttpClient::new()
+            .unwrap()
+            .with_resolve("feeds.example.com", std::net::Ipv4Addr::LOCALHOST.into())
+            .unwrap();
+
+        let response = client
+            .get(&format!("{base}/moved.xml"), None, None, None)
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.permanent_redirect,
+            Some(format!("{base}/final.xml"))
+        );
+
+        let response = client
+            .get(&format!("{base}/temp.xml"), None, None, None)
+            .unwrap();
+        assert_eq!(response.url, format!("{base}/final.xml"));
+        assert_eq!(response.permanent_redirect, None);
+
+        let response = client
+            .get(&format!("{base}/final.xml"), None, None, None)
+            .unwrap();
+        assert_eq!(response.permanent_redirect, None);
+    }
+
     // === Compression Tests ===
 
     #[test]
@@ -7,7 +7,7 @@
     clippy::panic
 )]
 
-use feedparser_rs::{FeedVersion, detect_format, parse};
+use feedparser_rs::{FeedVersion, ParseOptions, detect_format, parse, parse_with_options};
 
 /// Helper function to load test fixtures
 fn load_fixture(path: &str) -> Vec<u8> {
@@ -161,6 +161,7 @@ fn test_parse_json_feed_minimal() {
     assert_eq!(feed.entries.len(), 0);
 }
 
+#[cfg(feature = "itunes")]
 #[test]
 fn test_parse_itunes_podcast_feed() {
     let xml = load_fixture("podcast/itunes-basic.xml");
@@ -204,3 +205,518 @@ fn test_parse_itunes_podcast_feed() {
 
     assert!(!feed.entries.is_empty(), "Feed should have episodes");
 }
+
+#[test]
+fn test_char_refs_decode_alike_in_text_and_attributes() {
+    let xml = br#"<?xml version="1.0"?>
+    <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+        <title>Caf&#xE9; &#8220;Q&#8221;</title>
+        <itunes:category text="Caf&#xE9;s &amp; Bars"/>
+        <item>
+            <title>A&#150;B <![CDATA[&#233;]]>&#233;</title>
+            <category>&#x263A;</category>
+            <enclosure url="https://example.com/a.mp3?x=1&amp;t=caf&#233;" type="audio/mpeg"/>
+        </item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+
+    assert_eq!(feed.feed.title.as_deref(), Some("Café “Q”"));
+    #[cfg(feature = "itunes")]
+    assert_eq!(
+        feed.feed.itunes.as_ref().unwrap().categories[0].text,
+        "Cafés & Bars"
+    );
+    let entry = &feed.entries[0];
+    // &#150; is the Windows-1252 en dash; CDATA content stays literal
+    assert_eq!(entry.title.as_deref(), Some("A–B &#233;é"));
+    assert_eq!(entry.tags[0].term, "☺");
+    assert_eq!(
+        entry.enclosures[0].url,
+        "https://example.com/a.mp3?x=1&t=café"
+    );
+    assert!(feed.bozo, "&#150; is not a valid XML character");
+    assert_eq!(
+        feed.bozo_exception.as_deref(),
+        Some("Invalid character reference &#150;")
+    );
+}
+
+#[test]
+fn test_invalid_char_refs_replaced_and_flagged() {
+    let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom">
+        <title>&#xD83D;&#xDE00; &#0; &#x110000;</title>
+        <entry>
+            <title>ok</title>
+            <link href="/p?a=&#xD800;"/>
+        </entry>
+    </feed>"#;
+    let feed = parse(xml).unwrap();
+
+    assert_eq!(
+        feed.feed.title.as_deref(),
+        Some("\u{FFFD}\u{FFFD} \u{FFFD} \u{FFFD}")
+    );
+    assert_eq!(feed.entries[0].links[0].href, "/p?a=\u{FFFD}");
+    assert!(feed.bozo);
+    assert_eq!(
+        feed.bozo_exception.as_deref(),
+        Some("Invalid character reference &#xD83D;")
+    );
+}
+
+#[test]
+fn test_valid_char_refs_not_bozo() {
+    let xml = br#"<rss version="2.0"><channel>
+        <title>Fish &amp; chips &#233; &#x1F600;</title>
+        <description><![CDATA[&#0; is literal here]]></description>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+
+    assert_eq!(feed.feed.title.as_deref(), Some("Fish & chips é 😀"));
+    assert!(!feed.bozo, "{:?}", feed.bozo_exception);
+}
+
+#[cfg(feature = "mediarss")]
+#[test]
+fn test_multiple_enclosures_deduplicated_and_reconciled() {
+    let xml = br#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/"><channel>
+        <item>
+            <enclosure url="https://example.com/ep.mp3" length="0" type="application/octet-stream"/>
+            <enclosure url="https://example.com/ep.m4a" length="2000" type="audio/mp4"/>
+            <enclosure url="https://example.com/ep.mp3" length="1000" type="audio/mpeg"/>
+            <enclosure url="https://example.com/ep.mp3" length="3000" type="audio/mp3"/>
+            <media:content url="https://example.com/ep.m4a" fileSize="2500" type="video/mp4" duration="60"/>
+            <media:content url="https://example.com/ep.ogg" type="audio/ogg"/>
+        </item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+    let entry = &feed.entries[0];
+
+    assert_eq!(entry.enclosures.len(), 2);
+    let mp3 = &entry.enclosures[0];
+    assert_eq!(mp3.url, "https://example.com/ep.mp3");
+    assert_eq!(mp3.length, Some(1000));
+    assert_eq!(mp3.enclosure_type.as_deref(), Some("audio/mpeg"));
+
+    // The enclosure's own details win over media:content
+    let m4a = &entry.enclosures[1];
+    assert_eq!(m4a.length, Some(2000));
+    assert_eq!(m4a.enclosure_type.as_deref(), Some("audio/mp4"));
+
+    assert_eq!(entry.media_content.len(), 2);
+    assert_eq!(entry.media_content[1].filesize, None);
+}
+
+#[cfg(feature = "mediarss")]
+#[test]
+fn test_media_thumbnails_grouped_per_rendition() {
+    use std::time::Duration;
+
+    let xml = br#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/"><channel>
+        <item>
+            <media:thumbnail url="https://example.com/poster.jpg"/>
+            <media:group>
+                <media:title>Trailer</media:title>
+                <media:thumbnail url="https://example.com/still-1.jpg" time="0:00:05"/>
+                <media:thumbnail url="https://example.com/still-2.jpg" time="12.5"/>
+                <media:content url="https://example.com/trailer-hd.mp4" type="video/mp4" width="1920">
+                    <media:thumbnail url="https://example.com/still-hd.jpg" width="1920" time="00:00:05.040"/>
+                </media:content>
+                <media:content url="https://example.com/trailer-sd.mp4" type="video/mp4" width="640"/>
+            </media:group>
+            <media:group>
+                <media:content url="https://example.com/clip.mp4" type="video/mp4"/>
+            </media:group>
+            <media:content url="https://example.com/extra.mp4" type="video/mp4">
+                <media:thumbnail url="https://example.com/extra.jpg" time="now"/>
+            </media:content>
+        </item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+    let entry = &feed.entries[0];
+
+    assert_eq!(entry.title.as_deref(), Some("Trailer"));
+    let urls: Vec<&str> = entry
+        .media_thumbnails
+        .iter()
+        .map(|t| t.url.as_str())
+        .collect();
+    assert_eq!(
+        urls,
+        [
+            "https://example.com/poster.jpg",
+            "https://example.com/still-1.jpg",
+            "https://example.com/still-2.jpg",
+            "https://example.com/still-hd.jpg",
+            "https://example.com/extra.jpg",
+        ]
+    );
+
+    assert_eq!(entry.media_content.len(), 4);
+    let hd = &entry.media_content[0];
+    assert_eq!(hd.group, Some(0));
+    assert_eq!(hd.thumbnails.len(), 1);
+    assert_eq!(hd.thumbnails[0].time.as_deref(), Some("00:00:05.040"));
+    assert_eq!(
+        hd.thumbnails[0].time_offset(),
+        Some(Duration::from_millis(5040))
+    );
+
+    // Without thumbnails of its own, a rendition takes its group's
+    let sd = &entry.media_content[1];
+    assert_eq!(sd.group, Some(0));
+    assert_eq!(sd.thumbnails.len(), 2);
+    assert_eq!(sd.thumbnails[0].time_offset(), Some(Duration::from_secs(5)));
+    assert_eq!(
+        sd.thumbnails[1].time_offset(),
+        Some(Duration::from_millis(12_500))
+    );
+
+    assert_eq!(entry.media_content[2].group, Some(1));
+    assert!(entry.media_content[2].thumbnails.is_empty());
+
+    let extra = &entry.media_content[3];
+    assert_eq!(extra.group, None);
+    assert_eq!(extra.thumbnails[0].time.as_deref(), Some("now"));
+    assert_eq!(extra.thumbnails[0].time_offset(), None);
+}
+
+#[cfg(feature = "mediarss")]
+#[test]
+fn test_atom_media_group() {
+    use std::time::Duration;
+
+    let xml =
+        br#"<feed xmlns="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/">
+        <entry>
+            <id>urn:1</id>
+            <media:group>
+                <media:thumbnail url="https://example.com/still.jpg" time="1:00"/>
+                <media:content url="https://example.com/v.webm" type="video/webm"/>
+                <media:content url="https://example.com/v.mp4" type="video/mp4">
+                    <media:thumbnail url="https://example.com/v.jpg"/>
+                </media:content>
+            </media:group>
+        </entry>
+    </feed>"#;
+    let feed = parse(xml).unwrap();
+    let entry = &feed.entries[0];
+
+    assert_eq!(entry.media_thumbnails.len(), 2);
+    assert_eq!(entry.media_content.len(), 2);
+    assert!(entry.media_content.iter().all(|m| m.group == Some(0)));
+    assert_eq!(
+        entry.media_content[0].thumbnails[0].url,
+        "https://example.com/still.jpg"
+    );
+    assert_eq!(
+        entry.media_content[0].thumbnails[0].time_offset(),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(
+        entry.media_content[1].thumbnails[0].url,
+        "https://example.com/v.jpg"
+    );
+}
+
+#[cfg(feature = "itunes")]
+#[test]
+fn test_entry_image_precedence() {
+    use feedparser_rs::ImageSource;
+
+    let xml = br#"<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+        <item>
+            <itunes:image href="https://example.com/artwork.jpg"/>
+            <enclosure url="https://example.com/cover.png" type="image/png" length="100"/>
+            <description><![CDATA[<p><img src="https://example.com/inline.gif" alt="Inline"></p>]]></description>
+        </item>
+        <item><title>No image</title></item>
+    </channel></rss>"#;
+
+    let feed = parse(xml).unwrap();
+    let image = feed.entries[0].image.as_ref().unwrap();
+    assert_eq!(image.url, "https://example.com/artwork.jpg");
+    assert_eq!(feed.entries[1].image, None);
+
+    let options = ParseOptions {
+        image_precedence: Some(vec![ImageSource::ContentImage, ImageSource::Enclosure]),
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml, &options).unwrap();
+    let image = feed.entries[0].image.as_ref().unwrap();
+    assert_eq!(image.url, "https://example.com/inline.gif");
+    assert_eq!(image.title.as_deref(), Some("Inline"));
+
+    let options = ParseOptions {
+        image_precedence: Some(vec![ImageSource::Enclosure]),
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml, &options).unwrap();
+    assert_eq!(
+        feed.entries[0].image.as_ref().unwrap().url,
+        "https://example.com/cover.png"
+    );
+
+    let options = ParseOptions {
+        image_precedence: Some(Vec::new()),
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml, &options).unwrap();
+    assert_eq!(feed.entries[0].image, None);
+}
+
+#[test]
+fn test_comments_count_precedence() {
+    let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom" xmlns:thr="http://purl.org/syndication/thread/1.0">
+        <entry>
+            <id>urn:1</id>
+            <link rel="replies" href="https://example.com/1/comments.atom" thr:count="3"/>
+            <link rel="replies" href="https://example.com/1/comments.html" thr:count="2"/>
+        </entry>
+        <entry>
+            <id>urn:2</id>
+            <link rel="replies" href="https://example.com/2/comments.atom" thr:count="3"/>
+            <thr:total>10</thr:total>
+        </entry>
+        <entry><id>urn:3</id></entry>
+    </feed>"#;
+    let feed = parse(xml).unwrap();
+
+    assert_eq!(feed.entries[0].links[0].thr_count, Some(3));
+    assert_eq!(feed.entries[0].comments_count, Some(5));
+    assert_eq!(feed.entries[1].thr_total, Some(10));
+    assert_eq!(feed.entries[1].comments_count, Some(10));
+    assert_eq!(feed.entries[2].comments_count, None);
+
+    let xml = br#"<rss version="2.0" xmlns:slash="http://purl.org/rss/1.0/modules/slash/"><channel>
+        <item>
+            <comments>https://example.com/1#comments</comments>
+            <slash:comments>42</slash:comments>
+        </item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+    let entry = &feed.entries[0];
+    assert_eq!(
+        entry.comments.as_deref(),
+        Some("https://example.com/1#comments")
+    );
+    assert_eq!(entry.slash_comments, Some(42));
+    assert_eq!(entry.comments_count, Some(42));
+
+    let xml = br#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+        xmlns="http://purl.org/rss/1.0/" xmlns:slash="http://purl.org/rss/1.0/modules/slash/">
+        <channel rdf:about="https://example.com/"><title>Slash</title></channel>
+        <item rdf:about="https://example.com/1"><title>Story</title><slash:comments>7</slash:comments></item>
+    </rdf:RDF>"#;
+    let feed = parse(xml).unwrap();
+    assert_eq!(feed.entries[0].comments_count, Some(7));
+}
+
+#[test]
+fn test_license_detail_merges_sources() {
+    let xml = br#"<rss version="2.0"
+        xmlns:podcast="https://podcastindex.org/namespace/1.0"
+        xmlns:cc="http://creativecommons.org/ns#"
+        xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+        xmlns:dcterms="http://purl.org/dc/terms/"><channel>
+        <title>Show</title>
+        <podcast:license>cc-by-sa-4.0</podcast:license>
+        <cc:license rdf:resource="https://creativecommons.org/licenses/by-sa/4.0/"/>
+        <item>
+            <title>Episode</title>
+            <dcterms:license>https://opensource.org/licenses/MIT</dcterms:license>
+        </item>
+        <item>
+            <title>Bonus</title>
+            <podcast:license url="https://example.com/terms">my-license-v1</podcast:license>
+        </item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+
+    let license = feed.feed.license_detail.as_ref().unwrap();
+    assert_eq!(
+        license.url.as_deref(),
+        Some("https://creativecommons.org/licenses/by-sa/4.0/")
+    );
+    assert_eq!(license.name.as_deref(), Some("cc-by-sa-4.0"));
+    assert_eq!(license.spdx_guess.as_deref(), Some("CC-BY-SA-4.0"));
+    assert_eq!(
+        feed.feed.license.as_deref(),
+        Some("https://creativecommons.org/licenses/by-sa/4.0/")
+    );
+
+    let license = feed.entries[0].license_detail.as_ref().unwrap();
+    assert_eq!(license.spdx_guess.as_deref(), Some("MIT"));
+    assert_eq!(
+        feed.entries[0].license.as_deref(),
+        Some("https://opensource.org/licenses/MIT")
+    );
+
+    let license = feed.entries[1].license_detail.as_ref().unwrap();
+    assert_eq!(license.url.as_deref(), Some("https://example.com/terms"));
+    assert_eq!(license.name.as_deref(), Some("my-license-v1"));
+    assert_eq!(license.spdx_guess, None);
+
+    let xml = br#"<feed xmlns="http://www.w3.org/2005/Atom">
+        <entry>
+            <title>Post</title>
+            <link rel="license" title="CC BY 4.0" href="https://creativecommons.org/licenses/by/4.0/"/>
+        </entry>
+    </feed>"#;
+    let feed = parse(xml).unwrap();
+    let license = feed.entries[0].license_detail.as_ref().unwrap();
+    assert_eq!(license.name.as_deref(), Some("CC BY 4.0"));
+    assert_eq!(license.spdx_guess.as_deref(), Some("CC-BY-4.0"));
+
+    let xml = br#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+        xmlns="http://purl.org/rss/1.0/" xmlns:cc="http://web.resource.org/cc/">
+        <channel rdf:about="https://example.com/">
+            <title>RDF</title>
+            <cc:license rdf:resource="http://creativecommons.org/licenses/by-nc/2.5/"/>
+        </channel>
+        <item rdf:about="https://example.com/1"><title>Story</title></item>
+    </rdf:RDF>"#;
+    let feed = parse(xml).unwrap();
+    assert_eq!(
+        feed.feed.license_detail.unwrap().spdx_guess.as_deref(),
+        Some("CC-BY-NC-2.5")
+    );
+    assert_eq!(feed.entries[0].title.as_deref(), Some("Story"));
+}
+
+#[cfg(all(feature = "dublincore", feature = "itunes"))]
+#[test]
+fn test_author_precedence() {
+    use feedparser_rs::AuthorSource;
+
+    let xml = br#"<rss version="2.0"
+        xmlns:dc="http://purl.org/dc/elements/1.1/"
+        xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+        <title>Show</title>
+        <itunes:author>The Show</itunes:author>
+        <dc:creator>Network</dc:creator>
+        <managingEditor>editor@example.com (Jane Editor)</managingEditor>
+        <item>
+            <title>Episode</title>
+            <dc:creator>Guest Writer</dc:creator>
+            <author>host@example.com (Sam Host)</author>
+        </item>
+    </channel></rss>"#;
+
+    let feed = parse(xml).unwrap();
+    assert_eq!(
+        feed.feed.author.as_deref(),
+        Some("editor@example.com (Jane Editor)")
+    );
+    let editor = feed.feed.author_detail.as_ref().unwrap();
+    assert_eq!(editor.name.as_deref(), Some("Jane Editor"));
+    assert_eq!(editor.email.as_deref(), Some("editor@example.com"));
+    assert_eq!(
+        feed.feed.managing_editor.as_deref(),
+        Some("editor@example.com (Jane Editor)")
+    );
+    let entry = &feed.entries[0];
+    assert_eq!(entry.author.as_deref(), Some("host@example.com (Sam Host)"));
+    assert_eq!(
+        entry.author_detail.as_ref().unwrap().name.as_deref(),
+        Some("Sam Host")
+    );
+
+    let options = ParseOptions {
+        author_precedence: Some(vec![
+            AuthorSource::DcCreator,
+            AuthorSource::ItunesAuthor,
+            AuthorSource::Author,
+        ]),
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml, &options).unwrap();
+    assert_eq!(feed.feed.author.as_deref(), Some("Network"));
+    let names: Vec<_> = feed
+        .feed
+        .authors
+        .iter()
+        .filter_map(|person| person.name.as_deref())
+        .collect();
+    assert_eq!(names, ["Network", "The Show"]);
+    let entry = &feed.entries[0];
+    assert_eq!(entry.author.as_deref(), Some("Guest Writer"));
+    assert_eq!(entry.authors.len(), 2);
+
+    let options = ParseOptions {
+        author_precedence: Some(Vec::new()),
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml, &options).unwrap();
+    assert_eq!(feed.feed.author, None);
+    assert_eq!(feed.entries[0].author_detail, None);
+}
+
+#[test]
+fn test_normalize_text_option() {
+    let xml = "<?xml version=\"1.0\"?>
+<feed xmlns=\"http://www.w3.org/2005/Atom\">
+  <title>  The\u{a0}Daily\n  Feed </title>
+  <entry>
+    <title>Cafe\u{301}   reviews</title>
+    <author><name> Jose\u{301}  Garci\u{301}a </name></author>
+    <category term=\"caf\u{e9}\"/>
+    <category term=\"Cafe\u{301}\"/>
+  </entry>
+</feed>";
+
+    let feed = parse(xml.as_bytes()).unwrap();
+    assert_eq!(
+        feed.entries[0].title.as_deref(),
+        Some("Cafe\u{301}   reviews")
+    );
+
+    let options = ParseOptions {
+        normalize_text: true,
+        normalize_tags: true,
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml.as_bytes(), &options).unwrap();
+    assert_eq!(feed.feed.title.as_deref(), Some("The Daily Feed"));
+    let entry = &feed.entries[0];
+    assert_eq!(entry.title.as_deref(), Some("Caf\u{e9} reviews"));
+    assert_eq!(entry.author.as_deref(), Some("Jos\u{e9} Garc\u{ed}a"));
+    assert_eq!(
+        entry.authors[0].name.as_deref(),
+        Some("Jos\u{e9} Garc\u{ed}a")
+    );
+    assert_eq!(entry.tags.len(), 1);
+    assert_eq!(entry.tags[0].term, "caf\u{e9}");
+}
+
+#[test]
+fn test_fix_mojibake_option() {
+    let xml = "<rss version=\"2.0\"><channel>
+<title>Caf\u{c3}\u{a9} Society</title>
+<item>
+  <title>It\u{e2}\u{20ac}\u{2122}s open</title>
+  <description>Na\u{ef}ve text stays</description>
+  <category>Cr\u{c3}\u{a8}me</category>
+</item>
+</channel></rss>";
+
+    let feed = parse(xml.as_bytes()).unwrap();
+    assert!(!feed.bozo);
+    assert_eq!(feed.feed.title.as_deref(), Some("Caf\u{c3}\u{a9} Society"));
+
+    let options = ParseOptions {
+        fix_mojibake: true,
+        ..ParseOptions::default()
+    };
+    let feed = parse_with_options(xml.as_bytes(), &options).unwrap();
+    assert!(feed.bozo);
+    assert_eq!(
+        feed.bozo_exception.as_deref(),
+        Some("Repaired mis-decoded UTF-8 text in feed.title")
+    );
+    assert_eq!(feed.feed.title.as_deref(), Some("Caf\u{e9} Society"));
+    let entry = &feed.entries[0];
+    assert_eq!(entry.title.as_deref(), Some("It\u{2019}s open"));
+    assert_eq!(entry.summary.as_deref(), Some("Na\u{ef}ve text stays"));
+    assert_eq!(entry.tags[0].term, "Cr\u{e8}me");
+}
@@ -126,6 +126,7 @@ fn test_empty_atom_link_in_item() {
 
 // Fixed: Multiple empty namespace elements inside <item> now work correctly.
 // Same fix as test_empty_atom_link_in_item.
+#[cfg(feature = "mediarss")]
 #[test]
 fn test_multiple_empty_elements_in_item() {
     let xml = r#"<?xml version="1.0"?>
@@ -154,6 +155,7 @@ fn test_multiple_empty_elements_in_item() {
 // Mixed empty and non-empty namespace elements
 // =============================================================================
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_mixed_empty_and_nonempty_namespace_tags() {
     let xml = r#"<?xml version="1.0"?>
@@ -213,6 +215,7 @@ fn test_atom_link_before_and_after_content() {
 
 // Fixed: Empty itunes:image at channel level now extracts href attribute correctly.
 // The itunes:image handler also sets feed.feed.image for Python feedparser compatibility.
+#[cfg(feature = "itunes")]
 #[test]
 fn test_empty_itunes_image_in_channel() {
     let xml = r#"<?xml version="1.0"?>
@@ -360,6 +363,7 @@ fn test_realistic_podcast_feed_with_atom_self_link() {
     );
 }
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_wordpress_style_feed_with_atom_link() {
     let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
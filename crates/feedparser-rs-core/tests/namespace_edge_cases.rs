@@ -7,6 +7,7 @@
 
 use feedparser_rs::parse;
 
+#[cfg(feature = "dublincore")]
 /// Tests namespace URI handling
 ///
 /// Note: Current implementation is lenient and matches by prefix only,
@@ -29,6 +30,7 @@ fn test_namespace_uri_matching() {
     assert!(!feed.bozo);
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests that empty Dublin Core elements are handled gracefully
 #[test]
 fn test_empty_dc_elements() {
@@ -60,6 +62,7 @@ fn test_empty_dc_elements() {
     assert!(entry.dc_subject[0].is_empty());
 }
 
+#[cfg(feature = "mediarss")]
 /// Tests that invalid numeric attributes in Media RSS are handled gracefully
 #[test]
 fn test_media_invalid_numeric_attributes() {
@@ -114,6 +117,7 @@ fn test_media_missing_url() {
     assert!(entry.media_content.is_empty());
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests fallback behavior when both Dublin Core and native elements are present
 #[test]
 fn test_dc_fallback_behavior() {
@@ -145,6 +149,7 @@ fn test_dc_fallback_behavior() {
     assert_eq!(entry.dc_creator.as_deref(), Some("DC Entry Author"));
 }
 
+#[cfg(feature = "mediarss")]
 /// Tests parsing multiple Media RSS thumbnails in a single entry
 #[test]
 fn test_multiple_media_thumbnails() {
@@ -170,6 +175,7 @@ fn test_multiple_media_thumbnails() {
     assert_eq!(entry.media_thumbnails[2].width, Some(300));
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests parsing of Unicode/non-ASCII characters in Dublin Core elements
 #[test]
 fn test_dc_unicode_content() {
@@ -198,6 +204,7 @@ fn test_dc_unicode_content() {
     assert_eq!(entry.dc_rights.as_deref(), Some("© 2024 版权所有"));
 }
 
+#[cfg(feature = "mediarss")]
 /// Tests that both self-closing and normal closing Media RSS elements work
 #[test]
 fn test_self_closing_media_elements() {
@@ -241,6 +248,7 @@ fn test_rss_without_namespaces() {
     assert_eq!(feed.entries[0].title.as_deref(), Some("Basic Entry"));
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests handling of whitespace in Dublin Core elements
 #[test]
 fn test_dc_elements_with_whitespace() {
@@ -293,6 +301,7 @@ fn test_empty_content_encoded() {
     assert!(entry.content[0].value.is_empty());
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests that invalid date formats in dc:date are handled gracefully
 #[test]
 fn test_invalid_dc_date() {
@@ -315,6 +324,7 @@ fn test_invalid_dc_date() {
     assert!(entry.published.is_none());
 }
 
+#[cfg(feature = "mediarss")]
 /// Tests parsing multiple media:content elements
 #[test]
 fn test_multiple_media_content() {
@@ -338,6 +348,7 @@ fn test_multiple_media_content() {
     assert_eq!(entry.media_content[1].width, Some(1920));
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests that dc:contributor elements are handled separately from dc:creator
 #[test]
 fn test_dc_contributor_vs_creator() {
@@ -390,6 +401,7 @@ fn test_large_content_encoded() {
     assert!(entry.content[0].value.len() > 100_000);
 }
 
+#[cfg(feature = "dublincore")]
 /// Tests that `dc:publisher` is stored in the `dc_publisher` field
 #[test]
 fn test_dc_publisher_field() {
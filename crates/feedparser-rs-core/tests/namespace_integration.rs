@@ -4,6 +4,7 @@
 
 use feedparser_rs::parse;
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_rss_with_dublin_core() {
     let xml = br#"<?xml version="1.0"?>
@@ -71,6 +72,7 @@ fn test_rss_with_content_encoded() {
     assert_eq!(entry.content[0].content_type.as_deref(), Some("text/html"));
 }
 
+#[cfg(feature = "mediarss")]
 #[test]
 fn test_rss_with_media_rss() {
     let xml = br#"<?xml version="1.0"?>
@@ -119,6 +121,7 @@ fn test_rss_with_media_rss() {
     assert_eq!(entry.media_content[0].duration, Some(600));
 }
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_atom_with_dublin_core() {
     let xml = br#"<?xml version="1.0"?>
@@ -183,6 +186,7 @@ fn test_atom_with_content_encoded() {
     );
 }
 
+#[cfg(feature = "mediarss")]
 #[test]
 fn test_atom_with_media_rss() {
     let xml = br#"<?xml version="1.0"?>
@@ -221,6 +225,8 @@ fn test_atom_with_media_rss() {
     );
 }
 
+#[cfg(feature = "mediarss")]
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_mixed_namespaces() {
     let xml = br#"<?xml version="1.0"?>
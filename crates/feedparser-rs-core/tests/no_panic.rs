@@ -0,0 +1,164 @@
+#![allow(missing_docs, clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+//! `parse` never panics on arbitrary bytes
+//!
+//! A deterministic stand-in for the cargo-fuzz targets in `fuzz/`: every
+//! fixture is truncated at each offset and mutated with bytes and tokens
+//! that have tripped parsers before, and every result must be a return
+//! value, not a panic.
+
+use feedparser_rs::util::parse_date;
+use feedparser_rs::util::sanitize::sanitize_html;
+use feedparser_rs::{ParseOptions, ParserLimits, detect_format, parse, parse_with_options};
+use std::path::Path;
+
+const FIXTURES: &str = "../../tests/fixtures";
+
+/// Fragments spliced into inputs
+const TOKENS: &[&[u8]] = &[
+    b"<",
+    b">",
+    b"&",
+    b"&#",
+    b"&#x",
+    b"&#xD800;",
+    b"&#x110000;",
+    b"&#99999999999;",
+    b"&amp",
+    b"<![CDATA[",
+    b"]]>",
+    b"<!--",
+    b"<?xml version=\"1.0\" encoding=\"x-bogus\"?>",
+    b"<!DOCTYPE x [<!ENTITY a \"&a;\">]>",
+    b"xml:base=\"::\"",
+    b"xmlns:a=\"\"",
+    b"<a:b>",
+    b"</>",
+    b"\xEF\xBB\xBF",
+    b"\xFF\xFE",
+    b"\xC3",
+    b"\0",
+    b"\"",
+    b"{",
+    b"}",
+    b"[",
+    b"]",
+    b"\"items\":",
+    b"99999999999999999999",
+    b"-1",
+];
+
+/// Small xorshift generator; reproducible without extra dependencies
+struct Rng(u64);
+
+impl Rng {
+    const fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        usize::try_from(self.next() % n.max(1) as u64).unwrap()
+    }
+}
+
+fn fixtures() -> Vec<Vec<u8>> {
+    let mut out = Vec::new();
+    for dir in std::fs::read_dir(FIXTURES).unwrap() {
+        for file in std::fs::read_dir(dir.unwrap().path()).unwrap() {
+            out.push(std::fs::read(file.unwrap().path()).unwrap());
+        }
+    }
+    assert!(
+        !out.is_empty(),
+        "no fixtures in {}",
+        Path::new(FIXTURES).display()
+    );
+    out
+}
+
+fn mutate(rng: &mut Rng, input: &[u8]) -> Vec<u8> {
+    let mut data = input.to_vec();
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(data.len() + 1);
+        match rng.below(4) {
+            0 => {
+                let token = TOKENS[rng.below(TOKENS.len())];
+                data.splice(at..at, token.iter().copied());
+            }
+            1 if at < data.len() => data[at] = u8::try_from(rng.below(256)).unwrap(),
+            2 => {
+                let end = (at + rng.below(32)).min(data.len());
+                data.drain(at..end);
+            }
+            _ => {
+                // Duplicate a slice, which nests or repeats elements
+                let end = (at + rng.below(64)).min(data.len());
+                let slice = data[at..end].to_vec();
+                data.splice(at..at, slice);
+            }
+        }
+    }
+    data
+}
+
+fn check(data: &[u8]) {
+    let _ = detect_format(data);
+    let _ = parse(data);
+    let _ = parse_with_options(
+        data,
+        &ParseOptions {
+            limits: ParserLimits::strict(),
+            base_url: Some("https://example.com/feed".to_string()),
+            ..ParseOptions::default()
+        },
+    );
+    let text = String::from_utf8_lossy(data);
+    let _ = sanitize_html(&text);
+}
+
+#[test]
+fn test_truncated_fixtures() {
+    for fixture in fixtures() {
+        for end in 0..fixture.len() {
+            check(&fixture[..end]);
+        }
+    }
+}
+
+#[test]
+fn test_mutated_fixtures() {
+    let iterations = std::env::var("NO_PANIC_ITERATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(300);
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for fixture in fixtures() {
+        for _ in 0..iterations {
+            check(&mutate(&mut rng, &fixture));
+        }
+    }
+}
+
+#[test]
+fn test_dates() {
+    let seeds = [
+        "Mon, 01 Jan 2024 12:00:00 +0200",
+        "2024-01-01T12:00:00.123456789Z",
+        "2024-W01-1",
+        "Sun, 31 Dec 9999 23:59:59 GMT",
+        "1 Jan 0 00:00:00 +9999",
+        "20240101",
+    ];
+    let mut rng = Rng(42);
+    for seed in seeds {
+        for end in 0..=seed.len() {
+            let _ = parse_date(seed.get(..end).unwrap_or(seed));
+        }
+        for _ in 0..500 {
+            let data = mutate(&mut rng, seed.as_bytes());
+            let _ = parse_date(&String::from_utf8_lossy(&data));
+        }
+    }
+}
@@ -1,3 +1,6 @@
+//! Integration tests for the iTunes and Podcast 2.0 namespaces
+
+#![cfg(all(feature = "itunes", feature = "podcast20"))]
 #![allow(missing_docs, clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
 use feedparser_rs::{PodcastChapters, PodcastEntryMeta, PodcastSoundbite, parse};
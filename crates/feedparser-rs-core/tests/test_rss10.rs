@@ -9,6 +9,7 @@
 //! - RDF structure handling
 //! - Malformed feed tolerance (bozo pattern)
 
+#[cfg(feature = "dublincore")]
 use chrono::{Datelike, Timelike};
 use feedparser_rs::{FeedVersion, ParserLimits, namespace::syndication::UpdatePeriod, parse};
 use std::fmt::Write as _;
@@ -63,6 +64,7 @@ fn test_basic_rss10_feed() {
     assert_eq!(second.link.as_deref(), Some("http://example.com/article2"));
 }
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_rss10_with_dublin_core() {
     let xml = br#"<?xml version="1.0"?>
@@ -372,6 +374,7 @@ fn test_rss10_nesting_depth_limit() {
     );
 }
 
+#[cfg(feature = "dublincore")]
 #[test]
 fn test_rss10_real_world_slashdot_like() {
     let xml = br#"<?xml version="1.0"?>
@@ -423,8 +426,8 @@ fn test_rss10_real_world_slashdot_like() {
         feed.feed.subtitle.as_deref(),
         Some("News for nerds, stuff that matters")
     );
-    // dc:language is mapped to feed.language
-    assert_eq!(feed.feed.language.as_deref(), Some("en-us"));
+    // dc:language is mapped to feed.language, normalized to BCP 47 case
+    assert_eq!(feed.feed.language.as_deref(), Some("en-US"));
     assert_eq!(
         feed.feed.dc_rights.as_deref(),
         Some("Copyright 1997-2024, OSDN")
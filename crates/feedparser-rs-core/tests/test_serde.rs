@@ -0,0 +1,71 @@
+//! Integration tests for the `serde` feature
+
+#![cfg(feature = "serde")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use feedparser_rs::{FeedTracker, FeedVersion, ParsedFeed, parse};
+
+const PODCAST: &[u8] = include_bytes!("../../../tests/fixtures/podcast/itunes-basic.xml");
+
+#[test]
+fn test_parsed_feed_json_round_trip() {
+    let feed = parse(PODCAST).unwrap();
+    let json = serde_json::to_string(&feed).unwrap();
+    let restored: ParsedFeed = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.version, feed.version);
+    assert_eq!(restored.feed.title, feed.feed.title);
+    assert_eq!(restored.entries.len(), feed.entries.len());
+    assert_eq!(restored.entries[0].id, feed.entries[0].id);
+    assert_eq!(restored.entries[0].published, feed.entries[0].published);
+    assert_eq!(
+        restored.entries[0].enclosures.len(),
+        feed.entries[0].enclosures.len()
+    );
+    assert_eq!(
+        restored.feed.itunes.as_ref().map(|i| i.author.clone()),
+        feed.feed.itunes.as_ref().map(|i| i.author.clone())
+    );
+
+    // Serializing the restored feed yields the same document
+    assert_eq!(
+        serde_json::to_value(&restored).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()
+    );
+}
+
+#[test]
+fn test_feed_version_serializes_as_string() {
+    assert_eq!(
+        serde_json::to_string(&FeedVersion::Rss20).unwrap(),
+        r#""rss20""#
+    );
+    assert_eq!(
+        serde_json::to_string(&FeedVersion::JsonFeed11).unwrap(),
+        r#""json11""#
+    );
+    let version: FeedVersion = serde_json::from_str(r#""atom10""#).unwrap();
+    assert_eq!(version, FeedVersion::Atom10);
+}
+
+#[test]
+fn test_dates_serialize_as_rfc3339() {
+    let xml = br#"<rss version="2.0"><channel><title>T</title>
+        <item><title>I</title><pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate></item>
+    </channel></rss>"#;
+    let feed = parse(xml).unwrap();
+    let value = serde_json::to_value(&feed.entries[0]).unwrap();
+    assert_eq!(value["published"], "2024-01-01T12:00:00Z");
+}
+
+#[test]
+fn test_feed_tracker_round_trip() {
+    let feed = parse(PODCAST).unwrap();
+    let mut tracker = FeedTracker::new();
+    assert!(!tracker.update(&feed).is_empty());
+
+    let json = serde_json::to_string(&tracker).unwrap();
+    let mut restored: FeedTracker = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, tracker);
+    assert!(restored.update(&feed).is_empty());
+}
@@ -0,0 +1,140 @@
+//! Integration tests for the `tracing` feature
+
+#![cfg(feature = "tracing")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use feedparser_rs::{ParseOptions, parse, parse_with_options};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+/// A span's metadata and the fields recorded on it
+type SpanData = (&'static Metadata<'static>, HashMap<&'static str, String>);
+
+/// Minimal subscriber that keeps every span, in creation order, and tracks
+/// the entered span so `Span::current()` works
+#[derive(Default)]
+struct Collector {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+    stack: Mutex<Vec<Id>>,
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<&'static str, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name(), value.to_string());
+    }
+}
+
+fn index(id: &Id) -> usize {
+    usize::try_from(id.into_u64()).unwrap() - 1
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = HashMap::new();
+        span.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock().unwrap();
+        spans.push((span.metadata(), fields));
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut FieldVisitor(&mut spans[index(span)].1));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, span: &Id) {
+        self.stack.lock().unwrap().push(span.clone());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> Current {
+        let stack = self.stack.lock().unwrap();
+        stack.last().map_or_else(Current::none, |id| {
+            Current::new(id.clone(), self.spans.lock().unwrap()[index(id)].0)
+        })
+    }
+}
+
+/// Runs `f` and returns the name and fields of every span it opened
+fn collect(f: impl FnOnce()) -> Vec<(&'static str, HashMap<&'static str, String>)> {
+    let collector = Collector::default();
+    let spans = Arc::clone(&collector.spans);
+    tracing::subscriber::with_default(collector, f);
+    let spans = spans.lock().unwrap();
+    spans
+        .iter()
+        .map(|(metadata, fields)| (metadata.name(), fields.clone()))
+        .collect()
+}
+
+#[test]
+fn test_parse_emits_spans_with_counts() {
+    let xml = br#"<rss version="2.0"><channel><title>T</title>
+        <item><description>&lt;b&gt;one&lt;/b&gt;</description></item>
+        <item><title>two</title></item>
+    </channel></rss>"#;
+
+    let spans = collect(|| {
+        parse_with_options(xml, &ParseOptions::default()).unwrap();
+    });
+    let find = |name: &str| {
+        spans.iter().find(|(span, _)| *span == name).map_or_else(
+            || panic!("no {name} span in {spans:?}"),
+            |(_, fields)| fields,
+        )
+    };
+
+    let root = find("feedparser.parse");
+    assert_eq!(root["bytes"], xml.len().to_string());
+    assert_eq!(root["version"], "rss20");
+    assert_eq!(root["entries"], "2");
+
+    assert_eq!(find("feedparser.detect")["confidence"], "1.0");
+    assert_eq!(find("feedparser.parse_rss")["entries"], "2");
+    find("feedparser.channel");
+    find("feedparser.sanitize");
+
+    let items = spans
+        .iter()
+        .filter(|(name, _)| *name == "feedparser.item")
+        .count();
+    assert_eq!(items, 2);
+}
+
+#[test]
+fn test_parse_emits_atom_spans() {
+    let xml =
+        br#"<feed xmlns="http://www.w3.org/2005/Atom"><entry><title>a</title></entry></feed>"#;
+
+    let spans = collect(|| {
+        parse(xml).unwrap();
+    });
+    let names: Vec<_> = spans.iter().map(|(name, _)| *name).collect();
+
+    assert!(names.contains(&"feedparser.parse_atom"));
+    assert!(names.contains(&"feedparser.feed"));
+    assert!(names.contains(&"feedparser.entry"));
+}
@@ -1,6 +1,23 @@
 import assert from 'node:assert';
+import { mkdtempSync, rmSync, writeFileSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
 import { describe, it } from 'node:test';
-import { detectFormat, parse, parseWithOptions } from '../index.js';
+import {
+  cleanHtml,
+  detectFormat,
+  detectFormatDetailed,
+  parse,
+  parseFile,
+  parseToJson,
+  parseUrl,
+  parseUrls,
+  parseUrlWithOptions,
+  parseWithBaseUrl,
+  parseWithOptions,
+  resolveAlias,
+  stripHtml,
+} from '../index.js';
 
 describe('feedparser-rs', () => {
   describe('parse()', () => {
@@ -50,6 +67,13 @@ describe('feedparser-rs', () => {
       assert.strictEqual(feed.entries.length, 1);
     });
 
+    it('should keep the declared version verbatim', () => {
+      const feed = parse('<rss version="0.91"><channel><title>Old</title></channel></rss>');
+
+      assert.strictEqual(feed.rawVersion, '0.91');
+      assert.strictEqual(parse('<rss><channel></channel></rss>').rawVersion, undefined);
+    });
+
     it('should parse Atom 1.0 feed from string', () => {
       const xml = `
         <?xml version="1.0" encoding="utf-8"?>
@@ -151,6 +175,40 @@ describe('feedparser-rs', () => {
       assert.strictEqual(feed.feed.published, 1734516000000);
     });
 
+    it('should map lastBuildDate to feed-level updated', () => {
+      const xml = `<rss version="2.0"><channel>
+        <pubDate>Wed, 18 Dec 2024 10:00:00 +0000</pubDate>
+        <lastBuildDate>Thu, 19 Dec 2024 10:00:00 +0000</lastBuildDate>
+      </channel></rss>`;
+
+      const feed = parse(xml);
+
+      assert.strictEqual(feed.feed.published, 1734516000000);
+      assert.strictEqual(feed.feed.updated, 1734602400000);
+    });
+
+    it('should expose dates as Date objects', () => {
+      const xml = `<?xml version="1.0"?>
+        <rss version="2.0">
+          <channel>
+            <pubDate>Wed, 18 Dec 2024 10:00:00 +0000</pubDate>
+            <item>
+              <title>Dated</title>
+              <pubDate>Mon, 06 Jan 2025 12:00:00 GMT</pubDate>
+            </item>
+            <item><title>Undated</title></item>
+          </channel>
+        </rss>`;
+
+      const feed = parse(xml);
+
+      assert(feed.feed.publishedDate instanceof Date);
+      assert.strictEqual(feed.feed.publishedDate.getTime(), feed.feed.published);
+      assert.strictEqual(feed.entries[0].publishedDate.toISOString(), '2025-01-06T12:00:00.000Z');
+      assert.strictEqual(feed.entries[1].publishedDate, undefined);
+      assert.strictEqual(feed.entries[1].updatedDate, undefined);
+    });
+
     it('should handle multiple entries', () => {
       const xml = `
         <?xml version="1.0"?>
@@ -274,6 +332,119 @@ describe('feedparser-rs', () => {
     });
   });
 
+  describe('detectFormatDetailed()', () => {
+    it('should report family and content type', () => {
+      const info = detectFormatDetailed('<feed xmlns="http://www.w3.org/2005/Atom"></feed>');
+      assert.deepStrictEqual(info, {
+        version: 'atom10',
+        family: 'atom',
+        confidence: 1,
+        contentTypeHint: 'application/atom+xml',
+      });
+    });
+
+    it('should lower confidence for inferred versions', () => {
+      const info = detectFormatDetailed(Buffer.from('<rss><channel></channel></rss>'));
+      assert.strictEqual(info.version, 'rss20');
+      assert.strictEqual(info.family, 'rss');
+      assert(info.confidence > 0 && info.confidence < 1);
+    });
+
+    it('should report unknown input', () => {
+      const info = detectFormatDetailed('not a feed');
+      assert.strictEqual(info.family, 'unknown');
+      assert.strictEqual(info.confidence, 0);
+      assert.strictEqual(info.contentTypeHint, undefined);
+    });
+  });
+
+  describe('parseFile()', () => {
+    const withFile = (contents, test) => {
+      const dir = mkdtempSync(join(tmpdir(), 'feedparser-rs-'));
+      try {
+        const path = join(dir, 'feed.xml');
+        writeFileSync(path, contents);
+        test(path);
+      } finally {
+        rmSync(dir, { recursive: true, force: true });
+      }
+    };
+
+    it('should parse a UTF-8 file', () => {
+      withFile('<rss version="2.0"><channel><title>Caf\u00e9</title></channel></rss>', (path) => {
+        const feed = parseFile(path);
+
+        assert.strictEqual(feed.version, 'rss20');
+        assert.strictEqual(feed.feed.title, 'Caf\u00e9');
+        assert.strictEqual(feed.encoding, 'utf-8');
+      });
+    });
+
+    it('should convert the declared encoding to UTF-8', () => {
+      const xml = Buffer.concat([
+        Buffer.from('<?xml version="1.0" encoding="ISO-8859-1"?>'),
+        Buffer.from('<rss version="2.0"><channel><title>Caf'),
+        Buffer.from([0xe9]),
+        Buffer.from('</title></channel></rss>'),
+      ]);
+      withFile(xml, (path) => {
+        const feed = parseFile(path);
+
+        assert.strictEqual(feed.feed.title, 'Caf\u00e9');
+        assert.strictEqual(feed.encoding, 'windows-1252');
+        assert.strictEqual(feed.bozo, false);
+      });
+    });
+
+    it('should decode UTF-16 with a byte order mark', () => {
+      const xml = '<rss version="2.0"><channel><title>\u00dcber</title></channel></rss>';
+      withFile(Buffer.concat([Buffer.from([0xff, 0xfe]), Buffer.from(xml, 'utf16le')]), (path) => {
+        const feed = parseFile(path);
+
+        assert.strictEqual(feed.feed.title, '\u00dcber');
+        assert.strictEqual(feed.encoding, 'utf-16le');
+      });
+    });
+
+    it('should reject files over the size limit', () => {
+      withFile('<rss version="2.0"><channel></channel></rss>', (path) => {
+        assert.throws(() => parseFile(path, null, 10), /exceeds maximum allowed/);
+      });
+    });
+
+    it('should report missing files', () => {
+      assert.throws(() => parseFile(join(tmpdir(), 'feedparser-rs-missing.xml')), /Failed to read/);
+    });
+  });
+
+  describe('parseWithBaseUrl()', () => {
+    it('should resolve relative links against the base URL', () => {
+      const xml = `<rss version="2.0"><channel>
+        <item><link>/posts/1</link><enclosure url="a.mp3" type="audio/mpeg"/></item>
+      </channel></rss>`;
+
+      const feed = parseWithBaseUrl(xml, 'https://example.com/feeds/main.xml');
+
+      assert.strictEqual(feed.entries[0].link, 'https://example.com/posts/1');
+      assert.strictEqual(feed.entries[0].enclosures[0].url, 'https://example.com/feeds/a.mp3');
+      assert.strictEqual(parse(xml).entries[0].link, '/posts/1');
+    });
+
+    it('should resolve feed imagery and keep the raw values', () => {
+      const xml = `<feed xmlns="http://www.w3.org/2005/Atom">
+        <icon>/favicon.ico</icon>
+        <logo>logo.png</logo>
+      </feed>`;
+
+      const feed = parseWithBaseUrl(xml, 'https://example.com/feeds/main.xml');
+
+      assert.strictEqual(feed.feed.icon, 'https://example.com/favicon.ico');
+      assert.strictEqual(feed.feed.logo, 'https://example.com/feeds/logo.png');
+      assert.strictEqual(feed.feed.rawIcon, '/favicon.ico');
+      assert.strictEqual(feed.feed.rawLogo, 'logo.png');
+    });
+  });
+
   describe('parseWithOptions()', () => {
     it('should parse with default options', () => {
       const xml = `
@@ -323,6 +494,194 @@ describe('feedparser-rs', () => {
         parseWithOptions(xml, 10);
       }, /exceeds maximum/);
     });
+
+    it('should accept a ParserLimits object', () => {
+      const xml = `<?xml version="1.0"?>
+        <rss version="2.0">
+          <channel>
+            <title>Test Feed</title>
+            <item><title>One</title></item>
+            <item><title>Two</title></item>
+            <item><title>Three</title></item>
+          </channel>
+        </rss>`;
+
+      const feed = parseWithOptions(xml, { maxEntries: 2 });
+
+      assert.strictEqual(feed.entries.length, 2);
+      assert.strictEqual(feed.bozo, true);
+    });
+
+    it('should apply maxFeedSizeBytes from a ParserLimits object', () => {
+      assert.throws(() => {
+        parseWithOptions('<rss version="2.0"><channel></channel></rss>', {
+          maxFeedSizeBytes: 10,
+        });
+      }, /exceeds maximum/);
+    });
+
+    it('should keep defaults for fields missing from a ParserLimits object', () => {
+      const xml = '<rss version="2.0"><channel><title>Test Feed</title></channel></rss>';
+
+      const feed = parseWithOptions(xml, {});
+
+      assert.strictEqual(feed.feed.title, 'Test Feed');
+      assert.strictEqual(feed.bozo, false);
+    });
+
+    it('should keep whitespace with preserveWhitespace', () => {
+      const xml = '<rss version="2.0"><channel><title>  spaced  </title></channel></rss>';
+
+      assert.strictEqual(parseWithOptions(xml, null).feed.title, 'spaced');
+      const feed = parseWithOptions(xml, null, { preserveWhitespace: true });
+      assert.strictEqual(feed.feed.title, '  spaced  ');
+    });
+  });
+
+  describe('parseToJson()', () => {
+    it('should return the feed as a JSON string', () => {
+      const xml = `<?xml version="1.0"?>
+        <rss version="2.0">
+          <channel>
+            <title>Test Feed</title>
+            <item>
+              <title>Entry</title>
+              <pubDate>Mon, 06 Jan 2025 12:00:00 GMT</pubDate>
+            </item>
+          </channel>
+        </rss>`;
+
+      const json = parseToJson(xml);
+
+      assert.strictEqual(typeof json, 'string');
+      const feed = JSON.parse(json);
+      assert.strictEqual(feed.version, 'rss20');
+      assert.strictEqual(feed.feed.title, 'Test Feed');
+      assert.strictEqual(feed.entries[0].title, 'Entry');
+      assert.strictEqual(feed.entries[0].published, '2025-01-06T12:00:00Z');
+    });
+
+    it('should apply limits', () => {
+      assert.throws(() => {
+        parseToJson('<rss version="2.0"><channel></channel></rss>', 10);
+      }, /exceeds maximum/);
+    });
+  });
+
+  describe('parseUrl()', () => {
+    it('should return a Promise instead of blocking', async () => {
+      const pending = parseUrl('http://localhost/feed.xml');
+      assert(pending instanceof Promise);
+      await assert.rejects(pending, /HTTP error/);
+    });
+
+    it('should reject invalid URLs', async () => {
+      await assert.rejects(parseUrl('not a url'), /HTTP error/);
+    });
+
+    it('should return a Promise from parseUrlWithOptions', async () => {
+      const pending = parseUrlWithOptions('http://localhost/feed.xml', null, null, null, 1024);
+      assert(pending instanceof Promise);
+      await assert.rejects(pending, /HTTP error/);
+    });
+
+    it('should reject invalid request headers', async () => {
+      const options = { headers: { 'Bad Name': 'x' } };
+      await assert.rejects(
+        parseUrl('https://example.com/feed.xml', null, null, null, options),
+        /Invalid header name/,
+      );
+    });
+
+    it('should reject an invalid proxy URL', async () => {
+      const options = { proxy: 'not a url' };
+      await assert.rejects(
+        parseUrlWithOptions('https://example.com/feed.xml', null, null, null, null, options),
+        /Invalid proxy URL/,
+      );
+    });
+
+    it('should accept auth credentials', async () => {
+      const options = { auth: { username: 'alice', password: 'secret' } };
+      await assert.rejects(
+        parseUrl('http://localhost/feed.xml', null, null, null, options),
+        /Localhost domain not allowed/,
+      );
+    });
+  });
+
+  describe('parseUrls()', () => {
+    it('should resolve to an empty array for no URLs', async () => {
+      assert.deepStrictEqual(await parseUrls([]), []);
+    });
+
+    it('should report per-URL errors in input order', async () => {
+      const urls = ['http://localhost/a.xml', 'not a url', 'http://localhost/b.xml'];
+
+      const results = await parseUrls(urls, { concurrency: 2, perHostDelay: 0 });
+
+      assert.deepStrictEqual(results.map((r) => r.url), urls);
+      for (const result of results) {
+        assert.strictEqual(result.feed, undefined);
+        assert.strictEqual(typeof result.error, 'string');
+      }
+      assert.match(results[0].error, /Localhost domain not allowed/);
+    });
+
+    it('should reject when the shared client cannot be built', async () => {
+      await assert.rejects(
+        parseUrls(['https://example.com/feed.xml'], { request: { proxy: 'not a url' } }),
+        /Invalid proxy URL/,
+      );
+    });
+  });
+
+  describe('resolveAlias', () => {
+    it('should resolve feedparser keys per scope', () => {
+      assert.deepStrictEqual(resolveAlias('feed', 'description'), ['subtitle', 'summary']);
+      assert.deepStrictEqual(resolveAlias('entry', 'description'), ['summary']);
+      assert.deepStrictEqual(resolveAlias('feed', 'copyright_detail'), ['rightsDetail']);
+      assert.deepStrictEqual(resolveAlias('container', 'items'), ['entries']);
+      assert.deepStrictEqual(resolveAlias('link', 'url'), ['href']);
+    });
+
+    it('should return null for keys that are not aliases', () => {
+      assert.strictEqual(resolveAlias('entry', 'title'), null);
+      assert.strictEqual(resolveAlias('entry', 'copyright'), null);
+    });
+
+    it('should resolve to properties present on parsed feeds', () => {
+      const feed = parse(`<rss version="2.0"><channel>
+        <copyright>CC-BY</copyright>
+        <item><guid>urn:1</guid></item>
+      </channel></rss>`);
+
+      const [channel] = resolveAlias('container', 'channel');
+      const [rights] = resolveAlias('feed', 'copyright');
+      const [id] = resolveAlias('entry', 'guid');
+      assert.strictEqual(feed[channel].rights, 'CC-BY');
+      assert.strictEqual(feed.entries[0][id], 'urn:1');
+      assert.strictEqual(feed.feed[rights], 'CC-BY');
+    });
+
+    it('should reject unknown scopes', () => {
+      assert.throws(() => resolveAlias('item', 'guid'), /Unknown alias scope/);
+    });
+  });
+
+  describe('cleanHtml() / stripHtml()', () => {
+    it('should sanitize like parse()', () => {
+      const html = '<p onclick="x()">Hi</p><script>x()</script>';
+      const feed = parse(`<rss version="2.0"><channel><item>
+        <description><![CDATA[${html}]]></description>
+      </item></channel></rss>`);
+      assert.strictEqual(cleanHtml(html), '<p>Hi</p>');
+      assert.strictEqual(feed.entries[0].summary, cleanHtml(html));
+    });
+
+    it('should convert HTML to plain text', () => {
+      assert.strictEqual(stripHtml('<p>Fish &amp; chips</p><p>Today</p>'), 'Fish & chips\n\nToday');
+    });
   });
 
   describe('error handling', () => {
@@ -783,7 +1142,10 @@ describe('feedparser-rs', () => {
       assert.strictEqual(feed.version, 'json11');
       assert.strictEqual(feed.feed.title, 'Full JSON Feed');
       assert.strictEqual(feed.feed.language, 'en-US');
-      assert(feed.feed.icon);
+      assert.strictEqual(feed.feed.icon, 'https://example.com/favicon.ico');
+      assert.strictEqual(feed.feed.logo, 'https://example.com/icon.png');
+      assert.strictEqual(feed.feed.image.url, 'https://example.com/icon.png');
+      assert.strictEqual(feed.feed.expired, false);
 
       const entry = feed.entries[0];
       assert.strictEqual(entry.title, 'Item 1');
@@ -805,6 +1167,28 @@ describe('feedparser-rs', () => {
       assert.strictEqual(feed.version, 'json10');
       assert.strictEqual(feed.feed.title, 'JSON Feed 1.0');
     });
+
+    it('should map item images and external_url', () => {
+      const json = JSON.stringify({
+        version: 'https://jsonfeed.org/version/1.1',
+        title: 'Link Blog',
+        items: [
+          {
+            id: '1',
+            external_url: 'https://other.example.org/article',
+            image: 'https://example.com/square.png',
+            banner_image: 'https://example.com/banner.png',
+          },
+        ],
+      });
+
+      const entry = parse(json).entries[0];
+
+      assert.strictEqual(entry.externalUrl, 'https://other.example.org/article');
+      assert(entry.links.some((l) => l.rel === 'related'));
+      assert.strictEqual(entry.mediaThumbnails[0].url, 'https://example.com/square.png');
+      assert.strictEqual(entry.bannerImage, 'https://example.com/banner.png');
+    });
   });
 
   describe('RSS 1.0 (RDF) handling', () => {
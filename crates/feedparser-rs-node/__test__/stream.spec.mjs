@@ -0,0 +1,84 @@
+import assert from 'node:assert';
+import { Readable } from 'node:stream';
+import { describe, it } from 'node:test';
+import { FeedStreamParser } from '../index.js';
+import { parseStream } from '../stream.js';
+
+const RSS = `<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title>Streamed Feed</title>
+    <item><title>First</title></item>
+    <item><title>Second</title></item>
+  </channel>
+</rss>`;
+
+function chunks(text, size) {
+  const out = [];
+  for (let i = 0; i < text.length; i += size) {
+    out.push(Buffer.from(text.slice(i, i + size)));
+  }
+  return out;
+}
+
+describe('parseStream()', () => {
+  it('should parse a Node Readable', async () => {
+    const feed = await parseStream(Readable.from(chunks(RSS, 16)));
+
+    assert.strictEqual(feed.feed.title, 'Streamed Feed');
+    assert.strictEqual(feed.entries.length, 2);
+  });
+
+  it('should parse a web ReadableStream of Uint8Array chunks', async () => {
+    const encoder = new TextEncoder();
+    const stream = new ReadableStream({
+      start(controller) {
+        for (const chunk of chunks(RSS, 32)) {
+          controller.enqueue(encoder.encode(chunk.toString()));
+        }
+        controller.close();
+      },
+    });
+
+    const feed = await parseStream(stream);
+
+    assert.strictEqual(feed.entries[1].title, 'Second');
+  });
+
+  it('should accept string chunks', async () => {
+    const feed = await parseStream(Readable.from([RSS.slice(0, 50), RSS.slice(50)]));
+
+    assert.strictEqual(feed.feed.title, 'Streamed Feed');
+  });
+
+  it('should stop reading once the size limit is exceeded', async () => {
+    // An endless source: the test only finishes if reading stops at the limit
+    const readable = new Readable({
+      read() {
+        this.push(Buffer.alloc(64, 0x20));
+      },
+    });
+
+    await assert.rejects(parseStream(readable, { maxSize: 256 }), /exceeds maximum/);
+    assert.strictEqual(readable.destroyed, true);
+  });
+});
+
+describe('FeedStreamParser', () => {
+  it('should count received bytes and reset on finish', async () => {
+    const parser = new FeedStreamParser();
+    parser.push(RSS);
+
+    assert.strictEqual(parser.bytesReceived, Buffer.byteLength(RSS));
+    const finished = parser.finish();
+    assert.strictEqual(parser.bytesReceived, 0);
+    assert.ok(finished instanceof Promise);
+    assert.strictEqual((await finished).feed.title, 'Streamed Feed');
+  });
+
+  it('should throw from push when the limit is exceeded', () => {
+    const parser = new FeedStreamParser(10);
+
+    assert.throws(() => parser.push('<rss version="2.0">'), /exceeds maximum/);
+  });
+});
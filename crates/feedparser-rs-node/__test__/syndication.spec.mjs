@@ -144,4 +144,19 @@ describe('syndication', () => {
     assert.strictEqual(feed.feed.syndication.updateFrequency, undefined);
     assert.strictEqual(feed.feed.syndication.updateBase, undefined);
   });
+
+  it('should parse blogChannel links', () => {
+    const xml = `<rss version="2.0" xmlns:blogChannel="http://backend.userland.com/blogChannelModule">
+        <channel>
+          <title>Test</title>
+          <blogChannel:blogRoll>http://example.com/blogroll.opml</blogChannel:blogRoll>
+          <blogChannel:blink>http://friend.example.org/</blogChannel:blink>
+        </channel>
+      </rss>`;
+
+    const feed = parse(xml);
+    assert.strictEqual(feed.feed.blogChannel.blogRoll, 'http://example.com/blogroll.opml');
+    assert.strictEqual(feed.feed.blogChannel.blink, 'http://friend.example.org/');
+    assert.strictEqual(feed.feed.blogChannel.mySubscriptions, undefined);
+  });
 });
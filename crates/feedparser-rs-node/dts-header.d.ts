@@ -0,0 +1,60 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+
+/*
+ * Hand-written types prepended to the generated definitions by
+ * `napi build --dts-header-file dts-header.d.ts`. Fields in `src/lib.rs`
+ * refer to them with `#[napi(ts_type = "...")]`.
+ */
+
+/** Feed format version; empty when the format was not recognized */
+export type FeedVersion =
+  | 'rss090'
+  | 'rss091'
+  | 'rss092'
+  | 'rss10'
+  | 'rss20'
+  | 'atom03'
+  | 'atom10'
+  | 'json10'
+  | 'json11'
+  | ''
+
+/** Format family of a `FeedVersion` */
+export type FeedFamily = 'rss' | 'atom' | 'json' | 'unknown'
+
+/** Object a feedparser-style key is looked up on; see `resolveAlias` */
+export type AliasScope = 'container' | 'feed' | 'entry' | 'link'
+
+/** Content type of a `TextConstruct` */
+export type TextType = 'text' | 'html' | 'xhtml'
+
+/** A `[latitude, longitude]` pair in the location's coordinate reference system */
+export type GeoCoordinate = [latitude: number, longitude: number]
+
+/** A single position */
+export interface GeoPoint extends GeoLocationBase {
+  geoType: 'point'
+  coordinates: [GeoCoordinate]
+}
+
+/** A path through two or more positions */
+export interface GeoLine extends GeoLocationBase {
+  geoType: 'line'
+  coordinates: [GeoCoordinate, GeoCoordinate, ...GeoCoordinate[]]
+}
+
+/** A closed ring of three or more positions; the first and last are equal */
+export interface GeoPolygon extends GeoLocationBase {
+  geoType: 'polygon'
+  coordinates: [GeoCoordinate, GeoCoordinate, GeoCoordinate, ...GeoCoordinate[]]
+}
+
+/** A bounding box given by its lower-left and upper-right corners */
+export interface GeoBox extends GeoLocationBase {
+  geoType: 'box'
+  coordinates: [lowerCorner: GeoCoordinate, upperCorner: GeoCoordinate]
+}
+
+/** Geographic location from GeoRSS, discriminated by `geoType` */
+export type GeoLocation = GeoPoint | GeoLine | GeoPolygon | GeoBox
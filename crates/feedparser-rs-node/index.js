@@ -572,8 +572,17 @@ if (!nativeBinding) {
 }
 
 module.exports = nativeBinding
+module.exports.FeedStreamParser = nativeBinding.FeedStreamParser
+module.exports.cleanHtml = nativeBinding.cleanHtml
 module.exports.detectFormat = nativeBinding.detectFormat
+module.exports.detectFormatDetailed = nativeBinding.detectFormatDetailed
 module.exports.parse = nativeBinding.parse
+module.exports.parseFile = nativeBinding.parseFile
+module.exports.parseToJson = nativeBinding.parseToJson
 module.exports.parseUrl = nativeBinding.parseUrl
+module.exports.parseUrls = nativeBinding.parseUrls
 module.exports.parseUrlWithOptions = nativeBinding.parseUrlWithOptions
+module.exports.parseWithBaseUrl = nativeBinding.parseWithBaseUrl
 module.exports.parseWithOptions = nativeBinding.parseWithOptions
+module.exports.resolveAlias = nativeBinding.resolveAlias
+module.exports.stripHtml = nativeBinding.stripHtml
@@ -0,0 +1,21 @@
+import type { ParsedFeed, ParserLimits } from './index'
+
+export interface ParseStreamOptions {
+  /** Maximum feed size in bytes (default: 100MB) */
+  maxSize?: number
+  /** Full parser limits; takes precedence over `maxSize` */
+  limits?: ParserLimits
+}
+
+/**
+ * Parse a feed from a Node.js Readable or a web ReadableStream
+ *
+ * Chunks are handed to a native `FeedStreamParser` as they arrive, so the
+ * size limit is enforced while reading and the stream is cancelled as soon as
+ * it is exceeded. The feed is buffered natively and parsed off the event loop
+ * once the stream ends.
+ */
+export declare function parseStream(
+  readable: AsyncIterable<Buffer | Uint8Array | string>,
+  options?: ParseStreamOptions,
+): Promise<ParsedFeed>
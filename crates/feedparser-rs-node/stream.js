@@ -0,0 +1,37 @@
+'use strict'
+
+const { FeedStreamParser } = require('./index.js')
+
+/**
+ * Parse a feed from a Node.js Readable or a web ReadableStream
+ *
+ * Chunks are handed to a native `FeedStreamParser` as they arrive, so the
+ * size limit is enforced while reading and the stream is cancelled as soon as
+ * it is exceeded. The feed is buffered natively and parsed off the event loop
+ * once the stream ends.
+ *
+ * @param {AsyncIterable<Buffer | Uint8Array | string>} readable
+ * @param {{ maxSize?: number, limits?: import('./index').ParserLimits }} [options]
+ * @returns {Promise<import('./index').ParsedFeed>}
+ */
+async function parseStream(readable, options = {}) {
+  const parser = new FeedStreamParser(options.limits ?? options.maxSize)
+  // Leaving the loop early (a thrown limit error) destroys a Node Readable
+  // and cancels a web ReadableStream through the async iterator protocol
+  for await (const chunk of readable) {
+    parser.push(typeof chunk === 'string' || Buffer.isBuffer(chunk) ? chunk : toBuffer(chunk))
+  }
+  return parser.finish()
+}
+
+function toBuffer(chunk) {
+  if (ArrayBuffer.isView(chunk)) {
+    return Buffer.from(chunk.buffer, chunk.byteOffset, chunk.byteLength)
+  }
+  if (chunk instanceof ArrayBuffer) {
+    return Buffer.from(chunk)
+  }
+  throw new TypeError('parseStream: chunks must be Buffer, Uint8Array or string')
+}
+
+module.exports.parseStream = parseStream
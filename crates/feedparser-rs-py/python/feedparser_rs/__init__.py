@@ -13,26 +13,91 @@ Usage:
 For full documentation, see: https://github.com/bug-ops/feedparser-rs
 """
 
+from . import aio, exceptions
 from ._feedparser_rs import (
+    Content,
+    Enclosure,
+    Entries,
+    EntriesIterator,
+    Entry,
+    FeedMeta,
     FeedParserDict,
+    Generator,
+    GeoLocation,
+    Image,
+    ItunesCategory,
+    ItunesEntryMeta,
+    ItunesFeedMeta,
+    ItunesOwner,
+    LicenseInfo,
+    Link,
+    MediaContent,
+    MediaThumbnail,
     ParserLimits,
+    Person,
+    PodcastChapters,
+    PodcastEntryMeta,
+    PodcastFunding,
+    PodcastMeta,
+    PodcastPerson,
+    PodcastSoundbite,
+    PodcastTranscript,
+    Source,
+    SyndicationMeta,
+    Tag,
+    TextConstruct,
     __version__,
+    clean_html,
     detect_format,
     parse,
     parse_url,
     parse_url_with_limits,
     parse_with_limits,
+    strip_html,
 )
 
 __all__ = [
+    "Content",
+    "Enclosure",
+    "Entries",
+    "EntriesIterator",
+    "Entry",
+    "FeedMeta",
     "FeedParserDict",
+    "Generator",
+    "GeoLocation",
+    "Image",
+    "ItunesCategory",
+    "ItunesEntryMeta",
+    "ItunesFeedMeta",
+    "ItunesOwner",
+    "LicenseInfo",
+    "Link",
+    "MediaContent",
+    "MediaThumbnail",
     "ParserLimits",
+    "Person",
+    "PodcastChapters",
+    "PodcastEntryMeta",
+    "PodcastFunding",
+    "PodcastMeta",
+    "PodcastPerson",
+    "PodcastSoundbite",
+    "PodcastTranscript",
+    "Source",
+    "SyndicationMeta",
+    "Tag",
+    "TextConstruct",
     "__version__",
+    "aio",
+    "clean_html",
     "detect_format",
+    "exceptions",
     "parse",
     "parse_url",
     "parse_url_with_limits",
     "parse_with_limits",
+    "strip_html",
 ]
 
 # Type alias for better IDE support
@@ -0,0 +1,663 @@
+# Generated by scripts/generate_stubs.py from the PyO3 sources. Do not edit.
+
+import asyncio
+import datetime
+import time
+from typing import Any, final
+
+from .exceptions import BozoException
+
+__version__: str
+
+_Source = str | bytes | bytearray | memoryview
+_Modified = str | datetime.datetime | time.struct_time
+
+def _parse_url_async(
+    url: str,
+    etag: str | None = None,
+    modified: _Modified | None = None,
+    user_agent: str | None = None,
+    limits: ParserLimits | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+) -> asyncio.Future[FeedParserDict]: ...
+def _restore(type_name: str, state: str) -> Any: ...
+def clean_html(html: str, /) -> str: ...
+def detect_format(source: _Source, /) -> str: ...
+def parse(
+    source: _Source,
+    /,
+    etag: str | None = None,
+    modified: _Modified | None = None,
+    agent: str | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+    user_agent: str | None = None,
+    sanitize_html: bool = True,
+    resolve_relative_uris: bool = True,
+    base_url: str | None = None,
+    preserve_whitespace: bool = False,
+) -> FeedParserDict: ...
+def parse_url(
+    url: str,
+    etag: str | None = None,
+    modified: _Modified | None = None,
+    user_agent: str | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+) -> FeedParserDict: ...
+def parse_url_with_limits(
+    url: str,
+    etag: str | None = None,
+    modified: _Modified | None = None,
+    user_agent: str | None = None,
+    limits: ParserLimits | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+) -> FeedParserDict: ...
+def parse_with_limits(
+    source: _Source,
+    /,
+    etag: str | None = None,
+    modified: _Modified | None = None,
+    agent: str | None = None,
+    limits: ParserLimits | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+    user_agent: str | None = None,
+    sanitize_html: bool = True,
+    resolve_relative_uris: bool = True,
+    base_url: str | None = None,
+    preserve_whitespace: bool = False,
+) -> FeedParserDict: ...
+def strip_html(html: str, /) -> str: ...
+
+@final
+class Content:
+    @property
+    def value(self) -> str: ...
+    @property
+    def type(self) -> str | None: ...
+    @property
+    def language(self) -> str | None: ...
+    @property
+    def base(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Enclosure:
+    @property
+    def url(self) -> str: ...
+    @property
+    def length(self) -> int | None: ...
+    @property
+    def type(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Entries:
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int | slice) -> Entry | list[Entry]: ...
+    def __iter__(self) -> EntriesIterator: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+@final
+class EntriesIterator:
+    def __iter__(self) -> EntriesIterator: ...
+    def __next__(self) -> Entry: ...
+
+@final
+class Entry:
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self, indent: int | None = None) -> str: ...
+    @property
+    def id(self) -> str | None: ...
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def title_detail(self) -> TextConstruct | None: ...
+    @property
+    def link(self) -> str | None: ...
+    @property
+    def links(self) -> list[Link]: ...
+    @property
+    def summary(self) -> str | None: ...
+    @property
+    def summary_detail(self) -> TextConstruct | None: ...
+    @property
+    def content(self) -> list[Content]: ...
+    @property
+    def published(self) -> str | None: ...
+    @property
+    def published_parsed(self) -> time.struct_time | None: ...
+    @property
+    def updated(self) -> str | None: ...
+    @property
+    def updated_parsed(self) -> time.struct_time | None: ...
+    @property
+    def created(self) -> str | None: ...
+    @property
+    def created_parsed(self) -> time.struct_time | None: ...
+    @property
+    def expired(self) -> str | None: ...
+    @property
+    def expired_parsed(self) -> time.struct_time | None: ...
+    @property
+    def author(self) -> str | None: ...
+    @property
+    def author_detail(self) -> Person | None: ...
+    @property
+    def authors(self) -> list[Person]: ...
+    @property
+    def contributors(self) -> list[Person]: ...
+    @property
+    def publisher(self) -> str | None: ...
+    @property
+    def publisher_detail(self) -> Person | None: ...
+    @property
+    def tags(self) -> list[Tag]: ...
+    @property
+    def enclosures(self) -> list[Enclosure]: ...
+    @property
+    def comments(self) -> str | None: ...
+    @property
+    def comments_count(self) -> int | None: ...
+    @property
+    def thr_total(self) -> int | None: ...
+    @property
+    def slash_comments(self) -> int | None: ...
+    @property
+    def external_url(self) -> str | None: ...
+    @property
+    def banner_image(self) -> str | None: ...
+    @property
+    def source(self) -> Source | None: ...
+    @property
+    def itunes(self) -> ItunesEntryMeta | None: ...
+    @property
+    def podcast_transcripts(self) -> list[PodcastTranscript]: ...
+    @property
+    def podcast_persons(self) -> list[PodcastPerson]: ...
+    @property
+    def license(self) -> str | None: ...
+    @property
+    def license_detail(self) -> LicenseInfo | None: ...
+    @property
+    def geo(self) -> GeoLocation | None: ...
+    @property
+    def dc_creator(self) -> str | None: ...
+    @property
+    def dc_date(self) -> str | None: ...
+    @property
+    def dc_date_parsed(self) -> time.struct_time | None: ...
+    @property
+    def dc_rights(self) -> str | None: ...
+    @property
+    def dc_subject(self) -> list[str]: ...
+    @property
+    def media_thumbnails(self) -> list[MediaThumbnail]: ...
+    @property
+    def media_content(self) -> list[MediaContent]: ...
+    @property
+    def image(self) -> Image | None: ...
+    @property
+    def podcast(self) -> PodcastEntryMeta | None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __getattr__(self, name: str) -> Any: ...
+    def __getitem__(self, key: str) -> Any: ...
+
+@final
+class FeedMeta:
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self, indent: int | None = None) -> str: ...
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def title_detail(self) -> TextConstruct | None: ...
+    @property
+    def link(self) -> str | None: ...
+    @property
+    def links(self) -> list[Link]: ...
+    @property
+    def subtitle(self) -> str | None: ...
+    @property
+    def subtitle_detail(self) -> TextConstruct | None: ...
+    @property
+    def updated(self) -> str | None: ...
+    @property
+    def updated_parsed(self) -> time.struct_time | None: ...
+    @property
+    def published(self) -> str | None: ...
+    @property
+    def published_parsed(self) -> time.struct_time | None: ...
+    @property
+    def author(self) -> str | None: ...
+    @property
+    def author_detail(self) -> Person | None: ...
+    @property
+    def authors(self) -> list[Person]: ...
+    @property
+    def contributors(self) -> list[Person]: ...
+    @property
+    def managing_editor(self) -> str | None: ...
+    @property
+    def publisher(self) -> str | None: ...
+    @property
+    def publisher_detail(self) -> Person | None: ...
+    @property
+    def language(self) -> str | None: ...
+    @property
+    def rights(self) -> str | None: ...
+    @property
+    def rights_detail(self) -> TextConstruct | None: ...
+    @property
+    def generator(self) -> str | None: ...
+    @property
+    def generator_detail(self) -> Generator | None: ...
+    @property
+    def image(self) -> Image | None: ...
+    @property
+    def icon(self) -> str | None: ...
+    @property
+    def logo(self) -> str | None: ...
+    @property
+    def raw_icon(self) -> str | None: ...
+    @property
+    def raw_logo(self) -> str | None: ...
+    @property
+    def raw_image_url(self) -> str | None: ...
+    @property
+    def tags(self) -> list[Tag]: ...
+    @property
+    def id(self) -> str | None: ...
+    @property
+    def ttl(self) -> int | None: ...
+    @property
+    def expired(self) -> bool: ...
+    @property
+    def itunes(self) -> ItunesFeedMeta | None: ...
+    @property
+    def podcast(self) -> PodcastMeta | None: ...
+    @property
+    def license(self) -> str | None: ...
+    @property
+    def license_detail(self) -> LicenseInfo | None: ...
+    @property
+    def syndication(self) -> SyndicationMeta | None: ...
+    @property
+    def dc_creator(self) -> str | None: ...
+    @property
+    def dc_publisher(self) -> str | None: ...
+    @property
+    def dc_rights(self) -> str | None: ...
+    @property
+    def geo(self) -> GeoLocation | None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __getattr__(self, name: str) -> Any: ...
+    def __getitem__(self, key: str) -> Any: ...
+
+@final
+class FeedParserDict:
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self, indent: int | None = None) -> str: ...
+    @property
+    def feed(self) -> FeedMeta: ...
+    @property
+    def entries(self) -> Entries: ...
+    @property
+    def bozo(self) -> bool: ...
+    @property
+    def bozo_exception(self) -> BozoException | None: ...
+    @property
+    def encoding(self) -> str: ...
+    @property
+    def version(self) -> str: ...
+    @property
+    def raw_version(self) -> str | None: ...
+    @property
+    def namespaces(self) -> dict[str, Any]: ...
+    @property
+    def status(self) -> int | None: ...
+    @property
+    def href(self) -> str | None: ...
+    @property
+    def etag(self) -> str | None: ...
+    @property
+    def modified(self) -> str | None: ...
+    @property
+    def headers(self) -> dict[str, Any] | None: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __getattr__(self, name: str) -> Any: ...
+    def __getitem__(self, key: str) -> Any: ...
+
+@final
+class Generator:
+    @property
+    def value(self) -> str: ...
+    @property
+    def uri(self) -> str | None: ...
+    @property
+    def version(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class GeoLocation:
+    @property
+    def geo_type(self) -> str: ...
+    @property
+    def coordinates(self) -> list[tuple[float, float]]: ...
+    @property
+    def srs_name(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+@final
+class Image:
+    @property
+    def url(self) -> str: ...
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def link(self) -> str | None: ...
+    @property
+    def width(self) -> int | None: ...
+    @property
+    def height(self) -> int | None: ...
+    @property
+    def description(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class ItunesCategory:
+    @property
+    def text(self) -> str: ...
+    @property
+    def subcategory(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class ItunesEntryMeta:
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def author(self) -> str | None: ...
+    @property
+    def duration(self) -> int | None: ...
+    @property
+    def explicit(self) -> bool | None: ...
+    @property
+    def image(self) -> str | None: ...
+    @property
+    def episode(self) -> int | None: ...
+    @property
+    def season(self) -> int | None: ...
+    @property
+    def episode_type(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class ItunesFeedMeta:
+    @property
+    def author(self) -> str | None: ...
+    @property
+    def owner(self) -> ItunesOwner | None: ...
+    @property
+    def categories(self) -> list[ItunesCategory]: ...
+    @property
+    def explicit(self) -> bool | None: ...
+    @property
+    def image(self) -> str | None: ...
+    @property
+    def keywords(self) -> list[str]: ...
+    @property
+    def podcast_type(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class ItunesOwner:
+    @property
+    def name(self) -> str | None: ...
+    @property
+    def email(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class LicenseInfo:
+    @property
+    def url(self) -> str | None: ...
+    @property
+    def href(self) -> str | None: ...
+    @property
+    def name(self) -> str | None: ...
+    @property
+    def spdx_guess(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Link:
+    @property
+    def href(self) -> str: ...
+    @property
+    def rel(self) -> str | None: ...
+    @property
+    def type(self) -> str | None: ...
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def length(self) -> int | None: ...
+    @property
+    def hreflang(self) -> str | None: ...
+    def __getattr__(self, name: str) -> Any: ...
+    def __repr__(self) -> str: ...
+
+@final
+class MediaContent:
+    @property
+    def url(self) -> str: ...
+    @property
+    def type(self) -> str | None: ...
+    @property
+    def filesize(self) -> int | None: ...
+    @property
+    def width(self) -> int | None: ...
+    @property
+    def height(self) -> int | None: ...
+    @property
+    def duration(self) -> int | None: ...
+    @property
+    def thumbnails(self) -> list[MediaThumbnail]: ...
+    @property
+    def group(self) -> int | None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+@final
+class MediaThumbnail:
+    @property
+    def url(self) -> str: ...
+    @property
+    def width(self) -> int | None: ...
+    @property
+    def height(self) -> int | None: ...
+    @property
+    def time(self) -> str | None: ...
+    @property
+    def time_offset(self) -> float | None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+@final
+class ParserLimits:
+    def __init__(
+        self,
+        max_feed_size_bytes: int = 100000000,
+        max_entries: int = 10000,
+        max_links_per_feed: int = 100,
+        max_links_per_entry: int = 50,
+        max_authors: int = 20,
+        max_contributors: int = 20,
+        max_tags: int = 100,
+        max_content_blocks: int = 10,
+        max_enclosures: int = 20,
+    ) -> None: ...
+    @property
+    def max_feed_size_bytes(self) -> int: ...
+    @property
+    def max_entries(self) -> int: ...
+    @property
+    def max_links_per_feed(self) -> int: ...
+    @property
+    def max_links_per_entry(self) -> int: ...
+    @property
+    def max_authors(self) -> int: ...
+    @property
+    def max_contributors(self) -> int: ...
+    @property
+    def max_tags(self) -> int: ...
+    @property
+    def max_content_blocks(self) -> int: ...
+    @property
+    def max_enclosures(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Person:
+    @property
+    def name(self) -> str | None: ...
+    @property
+    def email(self) -> str | None: ...
+    @property
+    def uri(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastChapters:
+    @property
+    def url(self) -> str: ...
+    @property
+    def type(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastEntryMeta:
+    @property
+    def transcript(self) -> list[PodcastTranscript]: ...
+    @property
+    def chapters(self) -> PodcastChapters | None: ...
+    @property
+    def soundbite(self) -> list[PodcastSoundbite]: ...
+    @property
+    def person(self) -> list[PodcastPerson]: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastFunding:
+    @property
+    def url(self) -> str: ...
+    @property
+    def message(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastMeta:
+    @property
+    def transcripts(self) -> list[PodcastTranscript]: ...
+    @property
+    def funding(self) -> list[PodcastFunding]: ...
+    @property
+    def persons(self) -> list[PodcastPerson]: ...
+    @property
+    def guid(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastPerson:
+    @property
+    def name(self) -> str: ...
+    @property
+    def role(self) -> str | None: ...
+    @property
+    def group(self) -> str | None: ...
+    @property
+    def img(self) -> str | None: ...
+    @property
+    def href(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastSoundbite:
+    @property
+    def start_time(self) -> float: ...
+    @property
+    def duration(self) -> float: ...
+    @property
+    def title(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class PodcastTranscript:
+    @property
+    def url(self) -> str: ...
+    @property
+    def type(self) -> str | None: ...
+    @property
+    def language(self) -> str | None: ...
+    @property
+    def rel(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Source:
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def link(self) -> str | None: ...
+    @property
+    def href(self) -> str | None: ...
+    @property
+    def url(self) -> str | None: ...
+    @property
+    def id(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class SyndicationMeta:
+    @property
+    def update_period(self) -> str | None: ...
+    @property
+    def update_frequency(self) -> int | None: ...
+    @property
+    def update_base(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class Tag:
+    @property
+    def term(self) -> str: ...
+    @property
+    def scheme(self) -> str | None: ...
+    @property
+    def label(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+@final
+class TextConstruct:
+    @property
+    def value(self) -> str: ...
+    @property
+    def type(self) -> str: ...
+    @property
+    def language(self) -> str | None: ...
+    @property
+    def base(self) -> str | None: ...
+    def __repr__(self) -> str: ...
@@ -0,0 +1,42 @@
+"""
+asyncio support for feedparser_rs
+
+Fetching runs on a native thread with the GIL released; the event loop is
+never blocked and no executor is needed.
+
+Usage:
+    >>> from feedparser_rs import aio
+    >>> d = await aio.parse_url("https://example.com/feed.xml")
+    >>> print(d.feed.title)
+"""
+
+from ._feedparser_rs import FeedParserDict, ParserLimits, _parse_url_async
+
+__all__ = ["parse_url"]
+
+
+async def parse_url(
+    url: str,
+    etag: str | None = None,
+    modified=None,
+    user_agent: str | None = None,
+    limits: ParserLimits | None = None,
+    *,
+    referrer: str | None = None,
+    request_headers: dict[str, str] | None = None,
+) -> FeedParserDict:
+    """Fetch and parse a feed without blocking the running event loop
+
+    Accepts the same arguments as ``feedparser_rs.parse_url_with_limits()``.
+    Cancelling the awaiting task discards the result; the request itself runs
+    to completion in the background.
+    """
+    return await _parse_url_async(
+        url,
+        etag,
+        modified,
+        user_agent,
+        limits,
+        referrer=referrer,
+        request_headers=request_headers,
+    )
@@ -0,0 +1,78 @@
+"""
+Exception classes used for ``bozo_exception``
+
+As in Python feedparser, a result with ``bozo`` set carries an exception
+instance describing the problem rather than a bare string, so callers can
+``isinstance``-check it:
+
+    >>> import xml.sax
+    >>> d = feedparser_rs.parse(b"<rss><channel><title>Broken")
+    >>> isinstance(d.bozo_exception, xml.sax.SAXParseException)
+    True
+
+The exceptions are never raised by ``parse()``; they are only reported.
+"""
+
+import xml.sax
+import xml.sax.xmlreader
+
+__all__ = [
+    "BozoException",
+    "CharacterEncodingOverride",
+    "CharacterEncodingUnknown",
+    "JSONParseException",
+    "LimitExceeded",
+    "NonXMLContentType",
+    "SAXParseException",
+    "ThingsNobodyCaresAboutButMe",
+    "UndeclaredNamespace",
+]
+
+
+class BozoException(Exception):
+    """Base class of every ``bozo_exception`` value"""
+
+    def __eq__(self, other):
+        return type(self) is type(other) and self.args == other.args
+
+    def __hash__(self):
+        return hash((type(self), self.args))
+
+
+class ThingsNobodyCaresAboutButMe(BozoException):
+    """Benign problems; named after feedparser's base class of the same name"""
+
+
+class CharacterEncodingOverride(ThingsNobodyCaresAboutButMe):
+    """The declared character encoding was wrong and another was used"""
+
+
+class CharacterEncodingUnknown(ThingsNobodyCaresAboutButMe):
+    """The character encoding could not be determined or decoded"""
+
+
+class NonXMLContentType(ThingsNobodyCaresAboutButMe):
+    """The document was served with a non-XML content type"""
+
+
+class UndeclaredNamespace(ThingsNobodyCaresAboutButMe):
+    """An element used a namespace prefix that was never declared"""
+
+
+class SAXParseException(BozoException, xml.sax.SAXParseException):
+    """The XML was malformed
+
+    Also an ``xml.sax.SAXParseException``, which is what feedparser reports.
+    Line and column numbers are not tracked and read as ``-1``.
+    """
+
+    def __init__(self, message):
+        xml.sax.SAXParseException.__init__(self, message, None, xml.sax.xmlreader.Locator())
+
+
+class JSONParseException(BozoException, ValueError):
+    """The JSON Feed document was malformed"""
+
+
+class LimitExceeded(BozoException):
+    """A ``ParserLimits`` cap was hit and the result was truncated"""
@@ -0,0 +1,314 @@
+#!/usr/bin/env python3
+"""
+Generate python/feedparser_rs/_feedparser_rs.pyi from the PyO3 sources
+
+Reads every ``#[pyclass]``, ``#[pymethods]`` block and registered
+``#[pyfunction]`` under src/ and writes matching type stubs, mapping Rust
+types to their Python equivalents. Run after changing the bindings:
+
+    python scripts/generate_stubs.py          # rewrite the stub
+    python scripts/generate_stubs.py --check  # exit 1 if it is stale
+
+Only the standard library is used, so the script runs without building the
+extension. Output follows the layout ``ruff format`` uses for stubs.
+"""
+
+import argparse
+import re
+import sys
+from pathlib import Path
+
+CRATE = Path(__file__).resolve().parent.parent
+SRC = CRATE / "src"
+STUB = CRATE / "python" / "feedparser_rs" / "_feedparser_rs.pyi"
+LINE_LENGTH = 100
+
+HEADER = """\
+# Generated by scripts/generate_stubs.py from the PyO3 sources. Do not edit.
+
+import asyncio
+import datetime
+import time
+from typing import Any, final
+
+from .exceptions import BozoException
+
+__version__: str
+
+_Source = str | bytes | bytearray | memoryview
+_Modified = str | datetime.datetime | time.struct_time
+"""
+
+# Python types that cannot be read off the Rust signature
+PARAM_OVERRIDES = {
+    "source": "_Source",
+    "modified": "_Modified | None",
+}
+RETURN_OVERRIDES = {
+    "bozo_exception": "BozoException | None",
+    "to_dict": "dict[str, Any]",
+    "_parse_url_async": "asyncio.Future[FeedParserDict]",
+}
+DUNDER_PARAMS = {
+    "__eq__": (["self", "other: object"], "bool"),
+    "__getattr__": (["self", "name: str"], "Any"),
+    "__getitem__": (["self", "key: str"], "Any"),
+    "__len__": (["self"], "int"),
+    "Entries.__getitem__": (["self", "index: int | slice"], "Entry | list[Entry]"),
+    "Entries.__iter__": (["self"], "EntriesIterator"),
+    "EntriesIterator.__iter__": (["self"], "EntriesIterator"),
+    "EntriesIterator.__next__": (["self"], "Entry"),
+}
+SKIPPED_METHODS = {"__reduce__"}
+SCALARS = {
+    "str": "str",
+    "String": "str",
+    "bool": "bool",
+    "f32": "float",
+    "f64": "float",
+    "()": "None",
+    "PyAny": "Any",
+    "PyDict": "dict[str, Any]",
+}
+INTEGERS = {"u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize"}
+
+
+def split_top_level(text):
+    """Splits on commas outside of brackets"""
+    parts, depth, current = [], 0, []
+    for ch in text:
+        if ch in "<([{":
+            depth += 1
+        elif ch in ">)]}":
+            depth -= 1
+        if ch == "," and depth == 0:
+            parts.append("".join(current).strip())
+            current = []
+        else:
+            current.append(ch)
+    tail = "".join(current).strip()
+    if tail:
+        parts.append(tail)
+    return parts
+
+
+def balanced(text, start):
+    """Returns the contents of the bracket opening at ``text[start]`` and the end offset"""
+    open_ch = text[start]
+    close_ch = {"(": ")", "{": "}"}[open_ch]
+    depth = 0
+    for i in range(start, len(text)):
+        if text[i] == open_ch:
+            depth += 1
+        elif text[i] == close_ch:
+            depth -= 1
+            if depth == 0:
+                return text[start + 1 : i], i + 1
+    raise ValueError(f"unbalanced {open_ch!r} at offset {start}")
+
+
+def python_type(rust, classes):
+    rust = re.sub(r"&('\w+ )?(mut )?", "", rust.strip())
+    rust = re.sub(r"'\w+,?\s*", "", rust).strip()
+    generic = re.fullmatch(r"(\w+)<(.*)>", rust)
+    if generic:
+        outer, inner = generic.groups()
+        args = [python_type(arg, classes) for arg in split_top_level(inner)]
+        if outer == "Option":
+            return f"{args[0]} | None"
+        if outer == "Vec":
+            return f"list[{args[0]}]"
+        if outer == "HashMap":
+            return f"dict[{args[0]}, {args[1]}]"
+        if outer in ("PyResult", "Py", "Bound"):
+            return args[-1]
+    elif rust.startswith("(") and rust != "()":
+        args = [python_type(arg, classes) for arg in split_top_level(rust[1:-1])]
+        return f"tuple[{', '.join(args)}]"
+    elif rust in classes:
+        return classes[rust]
+    elif rust in INTEGERS:
+        return "int"
+    elif rust in SCALARS:
+        return SCALARS[rust]
+    raise ValueError(f"no Python type for Rust type {rust!r}")
+
+
+def python_default(value):
+    value = value.strip()
+    if value in ("true", "false"):
+        return value.capitalize()
+    if re.fullmatch(r"-?[\d_]+", value):
+        return value.replace("_", "")
+    if value == "None":
+        return value
+    return "..."
+
+
+def parse_fn(text, start):
+    """Parses the ``fn`` item at ``start``; returns (name, params, return type, end)"""
+    match = re.compile(r"fn (\w+)").match(text, start)
+    params, end = balanced(text, match.end())
+    ret = re.compile(r"\s*(->\s*([^{;]+?))?\s*[{;]").match(text, end)
+    return match.group(1), split_top_level(params), (ret.group(2) or "()").strip(), ret.end()
+
+
+def attributes_before(text, start):
+    """Collects the attribute lines directly above the line at ``start``"""
+    lines = text[:start].split("\n")[:-1]
+    attrs = []
+    for line in reversed(lines):
+        stripped = line.strip()
+        if not stripped or stripped.startswith(("///", "//", "}", "{", "fn ", "impl ")):
+            break
+        attrs.insert(0, stripped)
+    return "\n".join(attrs)
+
+
+def render_params(params, attrs, classes):
+    rust_params = {}
+    for param in params:
+        name, _, rust = param.partition(":")
+        if rust and not rust.strip().startswith("Python<"):
+            rust_params[name.strip().removeprefix("mut ")] = rust
+
+    signature = re.search(r"signature\s*=\s*\(", attrs)
+    if signature:
+        body, _ = balanced(attrs, signature.end() - 1)
+        entries = split_top_level(body)
+    else:
+        entries = list(rust_params)
+
+    rendered = []
+    for entry in entries:
+        name, _, default = (part.strip() for part in entry.partition("="))
+        if name in ("/", "*"):
+            rendered.append(name)
+            continue
+        annotation = PARAM_OVERRIDES.get(name) or python_type(rust_params[name], classes)
+        if default:
+            annotation = f"{annotation} = {python_default(default)}"
+        rendered.append(f"{name}: {annotation}")
+    return rendered
+
+
+def render_def(name, params, ret, indent):
+    """Formats a stub ``def`` the way ``ruff format`` wraps long signatures"""
+    pad = " " * indent
+    line = f"{pad}def {name}({', '.join(params)}) -> {ret}: ..."
+    if len(line) <= LINE_LENGTH:
+        return [line]
+    hugged = f"{pad}    {', '.join(params)}"
+    if len(hugged) <= LINE_LENGTH:
+        return [f"{pad}def {name}(", hugged, f"{pad}) -> {ret}: ..."]
+    lines = [f"{pad}def {name}("]
+    lines.extend(f"{pad}    {param}," for param in params)
+    lines.append(f"{pad}) -> {ret}: ...")
+    return lines
+
+
+def collect_classes(sources):
+    """Maps Rust struct names to Python class names"""
+    classes = {}
+    for text in sources:
+        for match in re.finditer(r"#\[pyclass\(name = \"(\w+)\"", text):
+            struct = re.compile(r"[\s\S]*?pub struct (\w+)").match(text, match.end())
+            classes[struct.group(1)] = match.group(1)
+    return classes
+
+
+def collect_methods(text, classes, members):
+    """Appends the rendered members of each ``#[pymethods]`` block in ``text``"""
+    for block in re.finditer(r"#\[pymethods\]\s*impl (\w+) \{", text):
+        body, _ = balanced(text, block.end() - 1)
+        cls = classes[block.group(1)]
+        rendered = members.setdefault(cls, [])
+        pos = 0
+        while match := re.compile(r"\n    (pub )?fn ").search(body, pos):
+            attrs = attributes_before(body, match.start() + 1)
+            name, params, rust_ret, pos = parse_fn(body, match.end() - 3)
+            if renamed := re.search(r'#\[pyo3\([^\]]*?\bname = "(\w+)"', attrs):
+                name = renamed.group(1)
+            if name in SKIPPED_METHODS:
+                continue
+            if "#[new]" in attrs:
+                params = ["self", *render_params(params, attrs, classes)]
+                rendered[0:0] = render_def("__init__", params, "None", 4)
+                continue
+            dunder = DUNDER_PARAMS.get(f"{cls}.{name}") or DUNDER_PARAMS.get(name)
+            if dunder:
+                params, ret = dunder
+                rendered.extend(render_def(name, params, ret, 4))
+                continue
+            ret = RETURN_OVERRIDES.get(name)
+            if ret is None and name.endswith("_parsed"):
+                ret = "time.struct_time | None"
+            ret = ret or python_type(rust_ret, classes)
+            if "#[getter]" in attrs:
+                rendered.append("    @property")
+                params = ["self"]
+            else:
+                params = ["self", *render_params(params, attrs, classes)]
+            rendered.extend(render_def(name, params, ret, 4))
+
+
+def collect_functions(sources, classes, registered):
+    """Maps Python function names to their rendered stubs"""
+    functions = {}
+    for text in sources:
+        for match in re.finditer(r"#\[pyfunction\]", text):
+            fn = re.compile(r"[\s\S]*?\n(pub )?fn ").match(text, match.end())
+            attrs = text[match.end() : fn.end()]
+            rust_name, params, rust_ret, _ = parse_fn(text, fn.end() - 3)
+            if rust_name not in registered:
+                continue
+            py_name = re.search(r'name\s*=\s*"(\w+)"', attrs)
+            py_name = py_name.group(1) if py_name else rust_name
+            ret = RETURN_OVERRIDES.get(py_name) or python_type(rust_ret, classes)
+            params = render_params(params, attrs, classes)
+            functions[py_name] = render_def(py_name, params, ret, 0)
+    return functions
+
+
+def registered_functions(lib):
+    """Rust names of the functions added to the extension module"""
+    module, _ = balanced(lib, lib.index("{", lib.index("#[pymodule]")))
+    names = re.findall(r"wrap_pyfunction!\(([\w:]+)", module)
+    return {name.rsplit("::", 1)[-1] for name in names}
+
+
+def generate():
+    sources = [path.read_text() for path in sorted(SRC.rglob("*.rs"))]
+    classes = collect_classes(sources)
+    members = {}
+    for text in sources:
+        collect_methods(text, classes, members)
+    registered = registered_functions((SRC / "lib.rs").read_text())
+    functions = collect_functions(sources, classes, registered)
+
+    out = [HEADER]
+    for name in sorted(functions):
+        out.extend(functions[name])
+    for name in sorted(members):
+        out.extend(["", "@final", f"class {name}:", *(members[name] or ["    ..."])])
+    return "\n".join(out) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    parser.add_argument("--check", action="store_true", help="fail if the stub is stale")
+    args = parser.parse_args()
+
+    stub = generate()
+    if args.check:
+        if not STUB.exists() or STUB.read_text() != stub:
+            print(f"{STUB.relative_to(CRATE)} is out of date; run scripts/generate_stubs.py")
+            return 1
+        return 0
+    STUB.write_text(stub)
+    print(f"wrote {STUB.relative_to(CRATE)}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
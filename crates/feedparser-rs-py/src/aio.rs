@@ -0,0 +1,93 @@
+//! asyncio support for URL fetching
+//!
+//! The fetch runs on a dedicated native thread with the GIL released, and the
+//! result is handed back to the event loop with `call_soon_threadsafe`, so
+//! awaiting it never blocks the loop and needs no executor.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::error::convert_feed_error;
+use crate::limits::PyParserLimits;
+use crate::types::PyParsedFeed;
+use crate::{RequestOptions, fetch_feed, parse_options};
+
+/// Start fetching a URL and return an `asyncio.Future` for the parsed feed
+///
+/// Must be called from a coroutine running on an event loop. Use
+/// `feedparser_rs.aio.parse_url()` rather than calling this directly.
+#[pyfunction]
+#[pyo3(
+    name = "_parse_url_async",
+    signature = (
+        url,
+        etag=None,
+        modified=None,
+        user_agent=None,
+        limits=None,
+        *,
+        referrer=None,
+        request_headers=None
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn parse_url_async(
+    py: Python<'_>,
+    url: String,
+    etag: Option<String>,
+    modified: Option<&Bound<'_, PyAny>>,
+    user_agent: Option<String>,
+    limits: Option<&PyParserLimits>,
+    referrer: Option<String>,
+    request_headers: Option<HashMap<String, String>>,
+) -> PyResult<Py<PyAny>> {
+    let options = RequestOptions::new(etag, modified, user_agent, referrer, request_headers)?;
+    let parse_options = parse_options(limits, true, true, None, false);
+
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let future = event_loop.call_method0("create_future")?;
+    let resolve = wrap_pyfunction!(resolve, py)?;
+
+    let (event_loop, future_ref, resolve) = (
+        event_loop.unbind(),
+        future.clone().unbind(),
+        resolve.into_any().unbind(),
+    );
+    std::thread::Builder::new()
+        .name("feedparser-rs-fetch".to_string())
+        .spawn(move || {
+            let result = fetch_feed(&url, options, &parse_options);
+            Python::attach(|py| {
+                let outcome = result
+                    .map_err(convert_feed_error)
+                    .and_then(|parsed| PyParsedFeed::from_core(py, parsed))
+                    .and_then(|feed| Ok(Py::new(py, feed)?.into_any()));
+                let (ok, value) = match outcome {
+                    Ok(feed) => (true, feed),
+                    Err(err) => (false, err.into_value(py).into_any()),
+                };
+                if let Err(err) = event_loop.call_method1(
+                    py,
+                    "call_soon_threadsafe",
+                    (resolve, future_ref, ok, value),
+                ) {
+                    // The loop was closed before the fetch finished
+                    err.write_unraisable(py, None);
+                }
+            });
+        })
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(future.unbind())
+}
+
+/// Completes `future` on the event loop thread unless it was cancelled
+#[pyfunction]
+fn resolve(future: &Bound<'_, PyAny>, ok: bool, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    if future.call_method0("done")?.is_truthy()? {
+        return Ok(());
+    }
+    let method = if ok { "set_result" } else { "set_exception" };
+    future.call_method1(method, (value,))?;
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use feedparser_rs::FeedError;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
 
 pub fn convert_feed_error(err: FeedError) -> PyErr {
     match err {
@@ -17,5 +19,55 @@ pub fn convert_feed_error(err: FeedError) -> PyErr {
     }
 }
 
+/// Runs a core parser call, raising `RuntimeError` if it panics
+///
+/// The parser is fuzzed and documented never to panic on any input. This
+/// is the safety net should a bug slip through: callers get an ordinary
+/// exception instead of a `PanicException`, which `except Exception`
+/// does not catch.
+pub fn catch_panic<T>(f: impl FnOnce() -> Result<T, FeedError>) -> PyResult<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(convert_feed_error),
+        Err(panic) => Err(PyRuntimeError::new_err(format!(
+            "Internal parser error: {}",
+            panic_message(panic.as_ref())
+        ))),
+    }
+}
+
+/// Message of a panic payload
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Builds the `feedparser_rs.exceptions` instance reported as `bozo_exception`
+///
+/// Core records bozo problems as messages, so the class is picked from the
+/// message prefix; anything unrecognised becomes a plain `BozoException`.
+pub fn bozo_exception(py: Python<'_>, message: &str) -> PyResult<Py<PyAny>> {
+    let class = bozo_exception_class(message);
+    let module = py.import("feedparser_rs.exceptions")?;
+    Ok(module.getattr(class)?.call1((message,))?.unbind())
+}
+
+fn bozo_exception_class(message: &str) -> &'static str {
+    let lower = message.to_ascii_lowercase();
+    if lower.starts_with("xml parsing error") || lower.starts_with("malformed xml") {
+        "SAXParseException"
+    } else if lower.starts_with("json pars") || lower.starts_with("unknown json feed version") {
+        "JSONParseException"
+    } else if lower.starts_with("encoding error") {
+        "CharacterEncodingUnknown"
+    } else if lower.contains("limit exceeded") || lower.contains("exceeds maximum") {
+        "LimitExceeded"
+    } else {
+        "BozoException"
+    }
+}
+
 // Note: Error conversion is tested via Python integration tests (pytest)
 // since PyErr.to_string() requires Python GIL to be initialized.
@@ -1,13 +1,17 @@
+use pyo3::buffer::PyBuffer;
 use pyo3::prelude::*;
-use pyo3::types::PyModule;
+use pyo3::types::{PyBytes, PyModule, PyString};
+use std::collections::HashMap;
 
 use feedparser_rs as core;
 
+#[cfg(feature = "http")]
+mod aio;
 mod error;
 mod limits;
 mod types;
 
-use error::convert_feed_error;
+use error::{catch_panic, convert_feed_error};
 use limits::PyParserLimits;
 use types::PyParsedFeed;
 
@@ -19,9 +23,29 @@ fn _feedparser_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse_url, m)?)?;
     #[cfg(feature = "http")]
     m.add_function(wrap_pyfunction!(parse_url_with_limits, m)?)?;
+    #[cfg(feature = "http")]
+    m.add_function(wrap_pyfunction!(aio::parse_url_async, m)?)?;
     m.add_function(wrap_pyfunction!(detect_format, m)?)?;
+    m.add_function(wrap_pyfunction!(clean_html, m)?)?;
+    m.add_function(wrap_pyfunction!(strip_html, m)?)?;
+    m.add_function(wrap_pyfunction!(types::pickle::restore, m)?)?;
     m.add_class::<PyParsedFeed>()?;
     m.add_class::<PyParserLimits>()?;
+    m.add_class::<types::feed_meta::PyFeedMeta>()?;
+    m.add_class::<types::entry::PyEntry>()?;
+    m.add_class::<types::entries::PyEntries>()?;
+    m.add_class::<types::entries::PyEntriesIterator>()?;
+    m.add_class::<types::common::PyTextConstruct>()?;
+    m.add_class::<types::common::PyLink>()?;
+    m.add_class::<types::common::PyPerson>()?;
+    m.add_class::<types::common::PyTag>()?;
+    m.add_class::<types::common::PyImage>()?;
+    m.add_class::<types::common::PyEnclosure>()?;
+    m.add_class::<types::common::PyContent>()?;
+    m.add_class::<types::common::PyGenerator>()?;
+    m.add_class::<types::common::PyLicenseInfo>()?;
+    m.add_class::<types::common::PySource>()?;
+    m.add_class::<types::syndication::PySyndicationMeta>()?;
     m.add_class::<types::geo::PyGeoLocation>()?;
     m.add_class::<types::media::PyMediaThumbnail>()?;
     m.add_class::<types::media::PyMediaContent>()?;
@@ -40,6 +64,89 @@ fn _feedparser_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+/// HTTP request options accepted by the `parse*` functions
+///
+/// Mirrors the keyword arguments of Python feedparser's `parse()`.
+#[derive(Debug, Default)]
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+struct RequestOptions {
+    etag: Option<String>,
+    modified: Option<String>,
+    agent: Option<String>,
+    referrer: Option<String>,
+    request_headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    fn new(
+        etag: Option<String>,
+        modified: Option<&Bound<'_, PyAny>>,
+        agent: Option<String>,
+        referrer: Option<String>,
+        request_headers: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            etag,
+            modified: modified.map(http_date).transpose()?.flatten(),
+            agent,
+            referrer,
+            request_headers: request_headers
+                .map(|headers| headers.into_iter().collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Builds core parse options from the `parse*` keyword arguments
+fn parse_options(
+    limits: Option<&PyParserLimits>,
+    sanitize_html: bool,
+    resolve_relative_uris: bool,
+    base_url: Option<String>,
+    preserve_whitespace: bool,
+) -> core::ParseOptions {
+    core::ParseOptions {
+        resolve_relative_uris,
+        sanitize_html,
+        limits: limits.map(|l| l.to_core_limits()).unwrap_or_default(),
+        base_url,
+        preserve_whitespace,
+        ..core::ParseOptions::default()
+    }
+}
+
+/// Converts a `modified` argument into an HTTP date string
+///
+/// Like Python feedparser, accepts a string (sent as-is), a
+/// `time.struct_time` / 9-tuple in UTC, or a `datetime` (naive values are
+/// taken as UTC).
+fn http_date(value: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    if value.is_none() {
+        return Ok(None);
+    }
+    if let Ok(s) = value.extract::<String>() {
+        return Ok(Some(s));
+    }
+    let py = value.py();
+    let tuple = if value.hasattr("utctimetuple")? {
+        value.call_method0("utctimetuple")?
+    } else {
+        value.clone()
+    };
+    let timestamp: i64 = py
+        .import("calendar")?
+        .call_method1("timegm", (tuple,))
+        .and_then(|t| t.extract())
+        .map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "modified must be str, datetime, or time.struct_time",
+            )
+        })?;
+    let date = chrono::DateTime::from_timestamp(timestamp, 0)
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("modified is out of range"))?;
+    Ok(Some(date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()))
+}
+
 /// Parse an RSS/Atom/JSON Feed from bytes, string, or URL
 ///
 /// Automatically detects whether `source` is a URL (http://, https://) or content.
@@ -49,8 +156,19 @@ fn _feedparser_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
 ///
 /// * `source` - URL string, feed content string, or bytes
 /// * `etag` - Optional ETag from previous fetch (for URLs with conditional GET)
-/// * `modified` - Optional Last-Modified timestamp (for URLs with conditional GET)
-/// * `user_agent` - Optional custom User-Agent header (for URLs)
+/// * `modified` - Optional Last-Modified value from previous fetch, as a string,
+///   `datetime`, or `time.struct_time` (for URLs with conditional GET)
+/// * `agent` - Optional custom User-Agent header (for URLs)
+/// * `referrer` - Optional Referer header (for URLs)
+/// * `request_headers` - Optional dict of extra HTTP request headers (for URLs)
+/// * `user_agent` - Alias for `agent`
+/// * `sanitize_html` - Clean HTML titles, summaries and content (default `True`)
+/// * `resolve_relative_uris` - Resolve relative URLs against `xml:base` and the
+///   channel link (default `True`)
+/// * `base_url` - URL the content came from, used to resolve relative URLs
+///   (defaults to the final URL when `source` is fetched)
+/// * `preserve_whitespace` - Keep leading and trailing whitespace in element
+///   text instead of trimming it (default `False`)
 ///
 /// # Examples
 ///
@@ -63,23 +181,61 @@ fn _feedparser_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
 /// # Parse from content
 /// feed = feedparser_rs.parse("<rss>...</rss>")
 ///
-/// # Parse from URL with caching
+/// # Parse from URL with caching, exactly like feedparser
 /// feed = feedparser_rs.parse(
 ///     "https://example.com/feed.xml",
 ///     etag=cached_etag,
-///     modified=cached_modified
+///     modified=cached_modified,
+///     agent="MyReader/1.0",
+///     request_headers={"Cache-Control": "no-cache"},
 /// )
 /// ```
 #[pyfunction]
-#[pyo3(signature = (source, /, etag=None, modified=None, user_agent=None))]
+#[pyo3(signature = (
+    source,
+    /,
+    etag=None,
+    modified=None,
+    agent=None,
+    *,
+    referrer=None,
+    request_headers=None,
+    user_agent=None,
+    sanitize_html=true,
+    resolve_relative_uris=true,
+    base_url=None,
+    preserve_whitespace=false
+))]
+#[allow(clippy::too_many_arguments)]
 fn parse(
     py: Python<'_>,
     source: &Bound<'_, PyAny>,
-    etag: Option<&str>,
-    modified: Option<&str>,
-    user_agent: Option<&str>,
+    etag: Option<String>,
+    modified: Option<&Bound<'_, PyAny>>,
+    agent: Option<String>,
+    referrer: Option<String>,
+    request_headers: Option<HashMap<String, String>>,
+    user_agent: Option<String>,
+    sanitize_html: bool,
+    resolve_relative_uris: bool,
+    base_url: Option<String>,
+    preserve_whitespace: bool,
 ) -> PyResult<PyParsedFeed> {
-    parse_internal(py, source, etag, modified, user_agent, None)
+    let options = RequestOptions::new(
+        etag,
+        modified,
+        agent.or(user_agent),
+        referrer,
+        request_headers,
+    )?;
+    let parse_options = parse_options(
+        None,
+        sanitize_html,
+        resolve_relative_uris,
+        base_url,
+        preserve_whitespace,
+    );
+    parse_internal(py, source, options, &parse_options)
 }
 
 /// Parse with custom resource limits for DoS protection
@@ -90,9 +246,11 @@ fn parse(
 ///
 /// * `source` - URL string, feed content string, or bytes
 /// * `etag` - Optional ETag from previous fetch (for URLs)
-/// * `modified` - Optional Last-Modified timestamp (for URLs)
-/// * `user_agent` - Optional custom User-Agent header (for URLs)
+/// * `modified` - Optional Last-Modified value (for URLs)
+/// * `agent` - Optional custom User-Agent header (for URLs)
 /// * `limits` - Optional parser limits for DoS protection
+/// * `referrer`, `request_headers`, `user_agent`, `sanitize_html`,
+///   `resolve_relative_uris`, `base_url`, `preserve_whitespace` - As for `parse()`
 ///
 /// # Examples
 ///
@@ -111,42 +269,75 @@ fn parse(
 /// feed = feedparser_rs.parse_with_limits("<rss>...</rss>", limits=limits)
 /// ```
 #[pyfunction]
-#[pyo3(signature = (source, /, etag=None, modified=None, user_agent=None, limits=None))]
+#[pyo3(signature = (
+    source,
+    /,
+    etag=None,
+    modified=None,
+    agent=None,
+    limits=None,
+    *,
+    referrer=None,
+    request_headers=None,
+    user_agent=None,
+    sanitize_html=true,
+    resolve_relative_uris=true,
+    base_url=None,
+    preserve_whitespace=false
+))]
+#[allow(clippy::too_many_arguments)]
 fn parse_with_limits(
     py: Python<'_>,
     source: &Bound<'_, PyAny>,
-    etag: Option<&str>,
-    modified: Option<&str>,
-    user_agent: Option<&str>,
+    etag: Option<String>,
+    modified: Option<&Bound<'_, PyAny>>,
+    agent: Option<String>,
     limits: Option<&PyParserLimits>,
+    referrer: Option<String>,
+    request_headers: Option<HashMap<String, String>>,
+    user_agent: Option<String>,
+    sanitize_html: bool,
+    resolve_relative_uris: bool,
+    base_url: Option<String>,
+    preserve_whitespace: bool,
 ) -> PyResult<PyParsedFeed> {
-    parse_internal(py, source, etag, modified, user_agent, limits)
+    let options = RequestOptions::new(
+        etag,
+        modified,
+        agent.or(user_agent),
+        referrer,
+        request_headers,
+    )?;
+    let parse_options = parse_options(
+        limits,
+        sanitize_html,
+        resolve_relative_uris,
+        base_url,
+        preserve_whitespace,
+    );
+    parse_internal(py, source, options, &parse_options)
 }
 
 /// Internal parse function that handles both URL and content sources
 fn parse_internal(
     py: Python<'_>,
     source: &Bound<'_, PyAny>,
-    etag: Option<&str>,
-    modified: Option<&str>,
-    user_agent: Option<&str>,
-    limits: Option<&PyParserLimits>,
+    options: RequestOptions,
+    parse_options: &core::ParseOptions,
 ) -> PyResult<PyParsedFeed> {
     // Try to extract as string first
-    if let Ok(s) = source.extract::<String>() {
+    if let Ok(s) = source.cast::<PyString>() {
+        let s = s.to_str()?;
         // Check if it's a URL
         if s.starts_with("http://") || s.starts_with("https://") {
             // Handle URL - requires http feature
             #[cfg(feature = "http")]
             {
-                let parser_limits = limits.map(|l| l.to_core_limits()).unwrap_or_default();
-                let parsed =
-                    core::parse_url_with_limits(&s, etag, modified, user_agent, parser_limits)
-                        .map_err(convert_feed_error)?;
-                return PyParsedFeed::from_core(py, parsed);
+                return fetch_url(py, s, options, parse_options);
             }
             #[cfg(not(feature = "http"))]
             {
+                let _ = options;
                 return Err(pyo3::exceptions::PyNotImplementedError::new_err(
                     "URL fetching requires the 'http' feature. Build with: maturin develop --features http",
                 ));
@@ -154,38 +345,112 @@ fn parse_internal(
         }
 
         // Parse as content
-        let parser_limits = limits.map(|l| l.to_core_limits()).unwrap_or_default();
-        let parsed =
-            core::parse_with_limits(s.as_bytes(), parser_limits).map_err(convert_feed_error)?;
+        let parsed = catch_panic(|| core::parse_with_options(s.as_bytes(), parse_options))?;
         return PyParsedFeed::from_core(py, parsed);
     }
 
-    // Try to extract as bytes
-    if let Ok(b) = source.extract::<Vec<u8>>() {
-        let parser_limits = limits.map(|l| l.to_core_limits()).unwrap_or_default();
-        let parsed = core::parse_with_limits(&b, parser_limits).map_err(convert_feed_error)?;
-        return PyParsedFeed::from_core(py, parsed);
+    // Anything exposing a byte buffer: bytes, bytearray, memoryview, mmap
+    let parsed = with_buffer(source, |bytes| {
+        catch_panic(|| core::parse_with_options(bytes, parse_options))
+    })??;
+    PyParsedFeed::from_core(py, parsed)
+}
+
+/// Runs `f` on the bytes of a buffer-protocol object
+///
+/// Other objects must export a C-contiguous buffer of unsigned bytes.
+/// `bytes` and read-only buffers (a `memoryview` of `bytes`, a read-only
+/// `mmap`, ...) are borrowed in place. Writable buffers such as `bytearray`
+/// are copied first: the module does not rely on the GIL, so another thread
+/// could modify them mid-parse. Objects without a buffer raise Python's usual
+/// "a bytes-like object is required" `TypeError`.
+fn with_buffer<R>(source: &Bound<'_, PyAny>, f: impl FnOnce(&[u8]) -> R) -> PyResult<R> {
+    if let Ok(b) = source.cast::<PyBytes>() {
+        return Ok(f(b.as_bytes()));
+    }
+
+    let buffer = PyBuffer::<u8>::get(source)?;
+    if !buffer.is_c_contiguous() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(
+            "buffer must be C-contiguous",
+        ));
     }
+    if !buffer.readonly() {
+        let bytes = buffer.to_vec(source.py())?;
+        return Ok(f(&bytes));
+    }
+    // SAFETY: the buffer is C-contiguous and holds `len_bytes()` bytes
+    // starting at `buf_ptr()`. `buffer` keeps the export alive until it is
+    // dropped after `f` returns, which prevents the exporter from resizing or
+    // freeing the memory, and the export is read-only, so nothing can write
+    // to it while the slice exists.
+    #[allow(unsafe_code)]
+    let bytes =
+        unsafe { std::slice::from_raw_parts(buffer.buf_ptr().cast::<u8>(), buffer.len_bytes()) };
+    Ok(f(bytes))
+}
 
-    Err(pyo3::exceptions::PyTypeError::new_err(
-        "source must be str, bytes, or URL",
-    ))
+/// Fetches and parses a URL, releasing the GIL during network I/O
+#[cfg(feature = "http")]
+fn fetch_url(
+    py: Python<'_>,
+    url: &str,
+    options: RequestOptions,
+    parse_options: &core::ParseOptions,
+) -> PyResult<PyParsedFeed> {
+    let parsed = py
+        .detach(|| fetch_feed(url, options, parse_options))
+        .map_err(convert_feed_error)?;
+    PyParsedFeed::from_core(py, parsed)
+}
+
+/// Fetches and parses a URL; called without the GIL held
+#[cfg(feature = "http")]
+fn fetch_feed(
+    url: &str,
+    options: RequestOptions,
+    parse_options: &core::ParseOptions,
+) -> core::Result<core::ParsedFeed> {
+    let mut client = core::FeedHttpClient::new()?;
+    if let Some(agent) = options.agent {
+        client = client.with_user_agent(agent);
+    }
+    if let Some(referrer) = &options.referrer {
+        client = client.with_header("Referer", referrer)?;
+    }
+    for (name, value) in &options.request_headers {
+        client = client.with_header(name, value)?;
+    }
+    client.fetch_feed_with_options(
+        url,
+        options.etag.as_deref(),
+        options.modified.as_deref(),
+        parse_options,
+    )
 }
 
 /// Detect feed format without full parsing
 #[pyfunction]
 #[pyo3(signature = (source, /))]
 fn detect_format(source: &Bound<'_, PyAny>) -> PyResult<String> {
-    let bytes: Vec<u8> = if let Ok(s) = source.extract::<String>() {
-        s.into_bytes()
-    } else if let Ok(b) = source.extract::<Vec<u8>>() {
-        b
-    } else {
-        return Err(pyo3::exceptions::PyTypeError::new_err(
-            "source must be str or bytes",
-        ));
-    };
-    Ok(core::detect_format(&bytes).to_string())
+    if let Ok(s) = source.cast::<PyString>() {
+        return Ok(core::detect_format(s.to_str()?.as_bytes()).to_string());
+    }
+    with_buffer(source, |bytes| core::detect_format(bytes).to_string())
+}
+
+/// Sanitize HTML with the policy `parse()` applies to feed content
+#[pyfunction]
+#[pyo3(signature = (html, /))]
+fn clean_html(html: &str) -> String {
+    core::util::sanitize::clean_html(html)
+}
+
+/// Convert HTML to plain text, decoding entities and dropping scripts
+#[pyfunction]
+#[pyo3(signature = (html, /))]
+fn strip_html(html: &str) -> String {
+    core::util::sanitize::strip_html(html)
 }
 
 /// Parse feed from HTTP/HTTPS URL with conditional GET support
@@ -197,8 +462,11 @@ fn detect_format(source: &Bound<'_, PyAny>) -> PyResult<String> {
 ///
 /// * `url` - HTTP or HTTPS URL to fetch
 /// * `etag` - Optional ETag from previous fetch for conditional GET
-/// * `modified` - Optional Last-Modified timestamp from previous fetch
+/// * `modified` - Optional Last-Modified value from previous fetch, as a
+///   string, `datetime`, or `time.struct_time`
 /// * `user_agent` - Optional custom User-Agent header
+/// * `referrer` - Optional Referer header
+/// * `request_headers` - Optional dict of extra HTTP request headers
 ///
 /// # Returns
 ///
@@ -233,16 +501,26 @@ fn detect_format(source: &Bound<'_, PyAny>) -> PyResult<String> {
 /// ```
 #[cfg(feature = "http")]
 #[pyfunction]
-#[pyo3(signature = (url, etag=None, modified=None, user_agent=None))]
+#[pyo3(signature = (
+    url,
+    etag=None,
+    modified=None,
+    user_agent=None,
+    *,
+    referrer=None,
+    request_headers=None
+))]
 fn parse_url(
     py: Python<'_>,
     url: &str,
-    etag: Option<&str>,
-    modified: Option<&str>,
-    user_agent: Option<&str>,
+    etag: Option<String>,
+    modified: Option<&Bound<'_, PyAny>>,
+    user_agent: Option<String>,
+    referrer: Option<String>,
+    request_headers: Option<HashMap<String, String>>,
 ) -> PyResult<PyParsedFeed> {
-    let parsed = core::parse_url(url, etag, modified, user_agent).map_err(convert_feed_error)?;
-    PyParsedFeed::from_core(py, parsed)
+    let options = RequestOptions::new(etag, modified, user_agent, referrer, request_headers)?;
+    fetch_url(py, url, options, &core::ParseOptions::default())
 }
 
 /// Parse feed from URL with custom resource limits
@@ -262,17 +540,32 @@ fn parse_url(
 /// ```
 #[cfg(feature = "http")]
 #[pyfunction]
-#[pyo3(signature = (url, etag=None, modified=None, user_agent=None, limits=None))]
+#[pyo3(signature = (
+    url,
+    etag=None,
+    modified=None,
+    user_agent=None,
+    limits=None,
+    *,
+    referrer=None,
+    request_headers=None
+))]
+#[allow(clippy::too_many_arguments)]
 fn parse_url_with_limits(
     py: Python<'_>,
     url: &str,
-    etag: Option<&str>,
-    modified: Option<&str>,
-    user_agent: Option<&str>,
+    etag: Option<String>,
+    modified: Option<&Bound<'_, PyAny>>,
+    user_agent: Option<String>,
     limits: Option<&PyParserLimits>,
+    referrer: Option<String>,
+    request_headers: Option<HashMap<String, String>>,
 ) -> PyResult<PyParsedFeed> {
-    let parser_limits = limits.map(|l| l.to_core_limits()).unwrap_or_default();
-    let parsed = core::parse_url_with_limits(url, etag, modified, user_agent, parser_limits)
-        .map_err(convert_feed_error)?;
-    PyParsedFeed::from_core(py, parsed)
+    let options = RequestOptions::new(etag, modified, user_agent, referrer, request_headers)?;
+    fetch_url(
+        py,
+        url,
+        options,
+        &parse_options(limits, true, true, None, false),
+    )
 }
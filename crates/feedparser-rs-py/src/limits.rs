@@ -111,26 +111,17 @@ impl PyParserLimits {
 impl PyParserLimits {
     /// Convert to core ParserLimits
     pub(crate) fn to_core_limits(&self) -> CoreParserLimits {
-        CoreParserLimits {
-            max_feed_size_bytes: self.max_feed_size_bytes,
-            max_entries: self.max_entries,
-            max_links_per_feed: self.max_links_per_feed,
-            max_links_per_entry: self.max_links_per_entry,
-            max_authors: self.max_authors,
-            max_contributors: self.max_contributors,
-            max_tags: self.max_tags,
-            max_content_blocks: self.max_content_blocks,
-            max_enclosures: self.max_enclosures,
-            max_namespaces: 100,               // Use default
-            max_nesting_depth: 100,            // Use default
-            max_text_length: 10 * 1024 * 1024, // 10 MB
-            max_attribute_length: 64 * 1024,   // 64 KB
-            max_podcast_soundbites: 10,        // Use default
-            max_podcast_transcripts: 20,       // Use default
-            max_podcast_funding: 20,           // Use default
-            max_podcast_persons: 50,           // Use default
-            max_value_recipients: 20,          // Use default
-        }
+        // Limits not exposed to Python keep the core defaults
+        CoreParserLimits::default()
+            .with_max_feed_size_bytes(self.max_feed_size_bytes)
+            .with_max_entries(self.max_entries)
+            .with_max_links_per_feed(self.max_links_per_feed)
+            .with_max_links_per_entry(self.max_links_per_entry)
+            .with_max_authors(self.max_authors)
+            .with_max_contributors(self.max_contributors)
+            .with_max_tags(self.max_tags)
+            .with_max_content_blocks(self.max_content_blocks)
+            .with_max_enclosures(self.max_enclosures)
     }
 }
 
@@ -1,8 +1,11 @@
+use super::pickle::{self, Reduced};
+use feedparser_rs::compat::{AliasScope, resolve_alias};
 use feedparser_rs::{
     Content as CoreContent, Enclosure as CoreEnclosure, Generator as CoreGenerator,
-    Image as CoreImage, Link as CoreLink, Person as CorePerson, Source as CoreSource,
-    Tag as CoreTag, TextConstruct as CoreTextConstruct, TextType,
+    Image as CoreImage, LicenseInfo as CoreLicenseInfo, Link as CoreLink, Person as CorePerson,
+    Source as CoreSource, Tag as CoreTag, TextConstruct as CoreTextConstruct, TextType,
 };
+use pyo3::exceptions::PyAttributeError;
 use pyo3::prelude::*;
 
 #[pyclass(name = "TextConstruct", module = "feedparser_rs", from_py_object)]
@@ -19,6 +22,10 @@ impl PyTextConstruct {
 
 #[pymethods]
 impl PyTextConstruct {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "TextConstruct", &self.inner)
+    }
+
     #[getter]
     fn value(&self) -> &str {
         &self.inner.value
@@ -67,6 +74,10 @@ impl PyLink {
 
 #[pymethods]
 impl PyLink {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Link", &self.inner)
+    }
+
     #[getter]
     fn href(&self) -> &str {
         &self.inner.href
@@ -98,6 +109,18 @@ impl PyLink {
         self.inner.hreflang.as_deref()
     }
 
+    /// Resolves feedparser's legacy `url` key to `href`
+    ///
+    /// This method is called by Python when normal attribute lookup fails.
+    fn __getattr__(&self, name: &str) -> PyResult<&str> {
+        match resolve_alias(AliasScope::Link, name) {
+            Some(["href"]) => Ok(&self.inner.href),
+            _ => Err(PyAttributeError::new_err(format!(
+                "'Link' object has no attribute '{name}'"
+            ))),
+        }
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Link(href='{}', rel='{}')",
@@ -121,6 +144,10 @@ impl PyPerson {
 
 #[pymethods]
 impl PyPerson {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Person", &self.inner)
+    }
+
     #[getter]
     fn name(&self) -> Option<&str> {
         self.inner.name.as_deref()
@@ -161,6 +188,10 @@ impl PyTag {
 
 #[pymethods]
 impl PyTag {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Tag", &self.inner)
+    }
+
     #[getter]
     fn term(&self) -> &str {
         &self.inner.term
@@ -195,6 +226,10 @@ impl PyImage {
 
 #[pymethods]
 impl PyImage {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Image", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -244,6 +279,10 @@ impl PyEnclosure {
 
 #[pymethods]
 impl PyEnclosure {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Enclosure", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -283,6 +322,10 @@ impl PyContent {
 
 #[pymethods]
 impl PyContent {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Content", &self.inner)
+    }
+
     #[getter]
     fn value(&self) -> &str {
         &self.inner.value
@@ -327,6 +370,10 @@ impl PyGenerator {
 
 #[pymethods]
 impl PyGenerator {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Generator", &self.inner)
+    }
+
     #[getter]
     fn value(&self) -> &str {
         &self.inner.value
@@ -351,6 +398,57 @@ impl PyGenerator {
     }
 }
 
+#[pyclass(name = "LicenseInfo", module = "feedparser_rs", from_py_object)]
+#[derive(Clone)]
+pub struct PyLicenseInfo {
+    inner: CoreLicenseInfo,
+}
+
+impl PyLicenseInfo {
+    pub fn from_core(core: CoreLicenseInfo) -> Self {
+        Self { inner: core }
+    }
+}
+
+#[pymethods]
+impl PyLicenseInfo {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "LicenseInfo", &self.inner)
+    }
+
+    #[getter]
+    fn url(&self) -> Option<&str> {
+        self.inner.url.as_deref()
+    }
+
+    /// Alias for `url`
+    #[getter]
+    fn href(&self) -> Option<&str> {
+        self.inner.url.as_deref()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<&str> {
+        self.inner.name.as_deref()
+    }
+
+    #[getter]
+    fn spdx_guess(&self) -> Option<&str> {
+        self.inner.spdx_guess.as_deref()
+    }
+
+    fn __repr__(&self) -> String {
+        match (&self.inner.spdx_guess, &self.inner.url) {
+            (Some(spdx), _) => format!("LicenseInfo(spdx_guess='{}')", spdx),
+            (None, Some(url)) => format!("LicenseInfo(url='{}')", url),
+            (None, None) => format!(
+                "LicenseInfo(name='{}')",
+                self.inner.name.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
 #[pyclass(name = "Source", module = "feedparser_rs", from_py_object)]
 #[derive(Clone)]
 pub struct PySource {
@@ -365,6 +463,10 @@ impl PySource {
 
 #[pymethods]
 impl PySource {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Source", &self.inner)
+    }
+
     #[getter]
     fn title(&self) -> Option<&str> {
         self.inner.title.as_deref()
@@ -375,6 +477,18 @@ impl PySource {
         self.inner.link.as_deref()
     }
 
+    /// Alias for `link`, the origin feed URL (Python feedparser's key)
+    #[getter]
+    fn href(&self) -> Option<&str> {
+        self.inner.link.as_deref()
+    }
+
+    /// Alias for `link`, named after the RSS `<source url="...">` attribute
+    #[getter]
+    fn url(&self) -> Option<&str> {
+        self.inner.link.as_deref()
+    }
+
     #[getter]
     fn id(&self) -> Option<&str> {
         self.inner.id.as_deref()
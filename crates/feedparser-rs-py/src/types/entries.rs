@@ -0,0 +1,165 @@
+use feedparser_rs::Entry as CoreEntry;
+use pyo3::exceptions::{PyIndexError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PySlice};
+
+use super::entry::{PyEntry, describe};
+
+/// Entries of a `FeedParserDict`
+///
+/// A read-only list whose `Entry` objects are created the first time they
+/// are accessed and reused afterwards. `len()` and partial iteration of a
+/// large feed skip converting the entries they never touch.
+#[pyclass(name = "Entries", module = "feedparser_rs", sequence)]
+pub struct PyEntries {
+    /// Entries not yet converted; a converted entry leaves a default value
+    entries: Vec<CoreEntry>,
+    /// Python objects of the entries converted so far
+    objects: Vec<Option<Py<PyEntry>>>,
+}
+
+impl PyEntries {
+    pub fn from_core(entries: Vec<CoreEntry>) -> Self {
+        let objects = entries.iter().map(|_| None).collect();
+        Self { entries, objects }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Applies `f` to the core value of every entry, converted or not
+    fn map_core<T>(&self, py: Python<'_>, f: impl Fn(&CoreEntry) -> T) -> Vec<T> {
+        self.entries
+            .iter()
+            .zip(&self.objects)
+            .map(|(entry, object)| match object {
+                Some(object) => f(object.borrow(py).as_core()),
+                None => f(entry),
+            })
+            .collect()
+    }
+
+    /// Core values of every entry, used when exporting or pickling
+    pub fn to_core(&self, py: Python<'_>) -> Vec<CoreEntry> {
+        self.map_core(py, CoreEntry::clone)
+    }
+
+    /// Python object for the entry at `index`, creating it if needed
+    fn get(&mut self, py: Python<'_>, index: usize) -> PyResult<Py<PyEntry>> {
+        if let Some(object) = &self.objects[index] {
+            return Ok(object.clone_ref(py));
+        }
+        let entry = std::mem::take(&mut self.entries[index]);
+        let object = Py::new(py, PyEntry::from_core(entry))?;
+        self.objects[index] = Some(object.clone_ref(py));
+        Ok(object)
+    }
+
+    /// Python objects for every entry
+    fn materialize(&mut self, py: Python<'_>) -> PyResult<Vec<Py<PyEntry>>> {
+        (0..self.entries.len()).map(|i| self.get(py, i)).collect()
+    }
+}
+
+#[pymethods]
+impl PyEntries {
+    /// Pickles as a plain list of entries
+    fn __reduce__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let entries = self.materialize(py)?;
+        let reduced = (py.get_type::<PyList>(), (entries,)).into_pyobject(py)?;
+        Ok(reduced.into_any().unbind())
+    }
+
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the entry at an index, or a list of entries for a slice
+    fn __getitem__(&mut self, py: Python<'_>, index: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Ok(slice) = index.cast::<PySlice>() {
+            let indices = slice.indices(self.entries.len() as isize)?;
+            let mut at = indices.start;
+            let mut entries = Vec::with_capacity(indices.slicelength);
+            for _ in 0..indices.slicelength {
+                entries.push(self.get(py, at as usize)?);
+                at += indices.step;
+            }
+            return Ok(entries.into_pyobject(py)?.into_any().unbind());
+        }
+
+        let index: isize = index
+            .extract()
+            .map_err(|_| PyTypeError::new_err("entry indices must be integers or slices"))?;
+        let position = if index < 0 {
+            index + self.entries.len() as isize
+        } else {
+            index
+        };
+        if position < 0 || position as usize >= self.entries.len() {
+            return Err(PyIndexError::new_err("entry index out of range"));
+        }
+        Ok(self.get(py, position as usize)?.into_any())
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> PyEntriesIterator {
+        PyEntriesIterator {
+            entries: slf.unbind(),
+            index: 0,
+        }
+    }
+
+    /// Compares canonically with another `Entries`, or item by item with a list
+    fn __eq__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let py = slf.py();
+        if slf.is(other) {
+            return Ok(true);
+        }
+        if let Ok(other) = other.cast::<Self>() {
+            let canonical = |entries: &Self| entries.map_core(py, CoreEntry::to_canonical_json);
+            return Ok(canonical(&slf.borrow()) == canonical(&other.borrow()));
+        }
+        let Ok(len) = other.len() else {
+            return Ok(false);
+        };
+        if len != slf.borrow().len() {
+            return Ok(false);
+        }
+        // Release the borrow before running Python comparisons
+        let entries = slf.borrow_mut().materialize(py)?;
+        for (i, entry) in entries.iter().enumerate() {
+            if !entry.bind(py).eq(other.get_item(i)?)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> String {
+        format!("[{}]", self.map_core(py, describe).join(", "))
+    }
+}
+
+/// Iterator over an `Entries` list
+#[pyclass(name = "EntriesIterator", module = "feedparser_rs")]
+pub struct PyEntriesIterator {
+    entries: Py<PyEntries>,
+    index: usize,
+}
+
+#[pymethods]
+impl PyEntriesIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyEntry>>> {
+        let mut entries = self.entries.borrow_mut(py);
+        if self.index >= entries.len() {
+            return Ok(None);
+        }
+        let entry = entries.get(py, self.index)?;
+        self.index += 1;
+        Ok(Some(entry))
+    }
+}
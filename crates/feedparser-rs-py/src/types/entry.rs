@@ -1,12 +1,17 @@
 use feedparser_rs::Entry as CoreEntry;
+use feedparser_rs::compat::{AliasScope, resolve_alias};
 use pyo3::exceptions::{PyAttributeError, PyKeyError};
 use pyo3::prelude::*;
 
-use super::common::{PyContent, PyEnclosure, PyLink, PyPerson, PySource, PyTag, PyTextConstruct};
-use super::compat::ENTRY_FIELD_MAP;
+use super::common::{
+    PyContent, PyEnclosure, PyImage, PyLicenseInfo, PyLink, PyPerson, PySource, PyTag,
+    PyTextConstruct,
+};
 use super::datetime::optional_datetime_to_struct_time;
+use super::export;
 use super::geo::PyGeoLocation;
 use super::media::{PyMediaContent, PyMediaThumbnail};
+use super::pickle::{self, Reduced};
 use super::podcast::{PyItunesEntryMeta, PyPodcastEntryMeta, PyPodcastPerson, PyPodcastTranscript};
 
 #[pyclass(name = "Entry", module = "feedparser_rs", from_py_object)]
@@ -19,10 +24,38 @@ impl PyEntry {
     pub fn from_core(core: CoreEntry) -> Self {
         Self { inner: core }
     }
+
+    pub fn as_core(&self) -> &CoreEntry {
+        &self.inner
+    }
+}
+
+/// `repr()` of an entry, shared with the entries list
+pub fn describe(entry: &CoreEntry) -> String {
+    format!(
+        "Entry(title='{}', id='{}')",
+        entry.title.as_deref().unwrap_or("untitled"),
+        entry.id.as_deref().unwrap_or("no-id")
+    )
 }
 
 #[pymethods]
 impl PyEntry {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "Entry", &self.inner)
+    }
+
+    /// Returns the entry as nested plain dicts and lists
+    fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        export::to_dict(py, &self.inner)
+    }
+
+    /// Returns the entry as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(&self.inner, indent)
+    }
+
     #[getter]
     fn id(&self) -> Option<&str> {
         self.inner.id.as_deref()
@@ -184,6 +217,31 @@ impl PyEntry {
         self.inner.comments.as_deref()
     }
 
+    #[getter]
+    fn comments_count(&self) -> Option<u32> {
+        self.inner.comments_count
+    }
+
+    #[getter]
+    fn thr_total(&self) -> Option<u32> {
+        self.inner.thr_total
+    }
+
+    #[getter]
+    fn slash_comments(&self) -> Option<u32> {
+        self.inner.slash_comments
+    }
+
+    #[getter]
+    fn external_url(&self) -> Option<&str> {
+        self.inner.external_url.as_deref()
+    }
+
+    #[getter]
+    fn banner_image(&self) -> Option<&str> {
+        self.inner.banner_image.as_deref()
+    }
+
     #[getter]
     fn source(&self) -> Option<PySource> {
         self.inner
@@ -237,6 +295,14 @@ impl PyEntry {
         self.inner.license.as_deref()
     }
 
+    #[getter]
+    fn license_detail(&self) -> Option<PyLicenseInfo> {
+        self.inner
+            .license_detail
+            .as_ref()
+            .map(|l| PyLicenseInfo::from_core(l.clone()))
+    }
+
     #[getter]
     fn geo(&self) -> Option<PyGeoLocation> {
         self.inner
@@ -288,6 +354,14 @@ impl PyEntry {
             .collect()
     }
 
+    #[getter]
+    fn image(&self) -> Option<PyImage> {
+        self.inner
+            .image
+            .as_ref()
+            .map(|i| PyImage::from_core(i.clone()))
+    }
+
     #[getter]
     fn podcast(&self) -> Option<PyPodcastEntryMeta> {
         self.inner
@@ -297,11 +371,11 @@ impl PyEntry {
     }
 
     fn __repr__(&self) -> String {
-        format!(
-            "Entry(title='{}', id='{}')",
-            self.inner.title.as_deref().unwrap_or("untitled"),
-            self.inner.id.as_deref().unwrap_or("no-id")
-        )
+        describe(&self.inner)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner.to_canonical_json() == other.inner.to_canonical_json()
     }
 
     /// Provides backward compatibility for deprecated Python feedparser field names.
@@ -316,7 +390,7 @@ impl PyEntry {
     /// This method is called by Python when normal attribute lookup fails.
     fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<Py<PyAny>> {
         // Check if this is a deprecated field name
-        if let Some(new_names) = ENTRY_FIELD_MAP.get(name) {
+        if let Some(new_names) = resolve_alias(AliasScope::Entry, name) {
             // Try each new field name in order
             for new_name in new_names {
                 let value: Option<Py<PyAny>> = match *new_name {
@@ -556,6 +630,33 @@ impl PyEntry {
                 .into_pyobject(py)?
                 .into_any()
                 .unbind()),
+            "comments_count" => Ok(self
+                .inner
+                .comments_count
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "thr_total" => Ok(self.inner.thr_total.into_pyobject(py)?.into_any().unbind()),
+            "slash_comments" => Ok(self
+                .inner
+                .slash_comments
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "external_url" => Ok(self
+                .inner
+                .external_url
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "banner_image" => Ok(self
+                .inner
+                .banner_image
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
             "source" => {
                 if let Some(ref s) = self.inner.source {
                     Ok(Py::new(py, PySource::from_core(s.clone()))?.into_any())
@@ -595,6 +696,13 @@ impl PyEntry {
                 .into_pyobject(py)?
                 .into_any()
                 .unbind()),
+            "license_detail" => {
+                if let Some(ref l) = self.inner.license_detail {
+                    Ok(Py::new(py, PyLicenseInfo::from_core(l.clone()))?.into_any())
+                } else {
+                    Ok(py.None())
+                }
+            }
             "geo" => {
                 if let Some(ref g) = self.inner.geo {
                     Ok(Py::new(py, PyGeoLocation::from_core(g.as_ref().clone()))?.into_any())
@@ -652,6 +760,13 @@ impl PyEntry {
                     .collect();
                 Ok(content.into_pyobject(py)?.into_any().unbind())
             }
+            "image" => {
+                if let Some(ref i) = self.inner.image {
+                    Ok(Py::new(py, PyImage::from_core(i.clone()))?.into_any())
+                } else {
+                    Ok(py.None())
+                }
+            }
             "podcast" => {
                 if let Some(ref p) = self.inner.podcast {
                     Ok(Py::new(py, PyPodcastEntryMeta::from_core(p.as_ref().clone()))?.into_any())
@@ -661,7 +776,7 @@ impl PyEntry {
             }
             // Check for deprecated field name aliases
             _ => {
-                if let Some(new_names) = ENTRY_FIELD_MAP.get(key) {
+                if let Some(new_names) = resolve_alias(AliasScope::Entry, key) {
                     // Try each new field name in order
                     for new_name in new_names {
                         let value: Option<Py<PyAny>> =
@@ -0,0 +1,67 @@
+//! Plain-data export of parsed results
+//!
+//! `to_dict()` and `to_json()` go through the serde JSON representation of
+//! the core value, the same one pickling uses, so the output contains only
+//! dicts, lists, strings, numbers, booleans and `None`. Dates are ISO 8601
+//! strings.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Serializes `value` to a JSON string
+pub fn to_json<T: Serialize>(value: &T, indent: Option<usize>) -> PyResult<String> {
+    let result = match indent {
+        None => serde_json::to_string(value),
+        Some(width) => {
+            let indent = " ".repeat(width);
+            let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+            let mut out = Vec::new();
+            let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+            value
+                .serialize(&mut serializer)
+                .map(|()| String::from_utf8(out).unwrap_or_default())
+        }
+    };
+    result.map_err(|e| PyValueError::new_err(format!("cannot serialize to JSON: {e}")))
+}
+
+/// Converts `value` to nested Python dicts and lists
+pub fn to_dict<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<Py<PyAny>> {
+    let value = serde_json::to_value(value)
+        .map_err(|e| PyValueError::new_err(format!("cannot convert to dict: {e}")))?;
+    Ok(value_to_py(py, &value)?.unbind())
+}
+
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => b.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into_pyobject(py)?.into_any()
+            } else if let Some(u) = n.as_u64() {
+                u.into_pyobject(py)?.into_any()
+            } else {
+                n.as_f64().unwrap_or_default().into_pyobject(py)?.into_any()
+            }
+        }
+        Value::String(s) => s.into_pyobject(py)?.into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(value_to_py(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, value_to_py(py, item)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
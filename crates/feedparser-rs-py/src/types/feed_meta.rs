@@ -1,11 +1,15 @@
 use feedparser_rs::FeedMeta as CoreFeedMeta;
+use feedparser_rs::compat::{AliasScope, resolve_alias};
 use pyo3::exceptions::{PyAttributeError, PyKeyError};
 use pyo3::prelude::*;
 
-use super::common::{PyGenerator, PyImage, PyLink, PyPerson, PyTag, PyTextConstruct};
-use super::compat::FEED_FIELD_MAP;
+use super::common::{
+    PyGenerator, PyImage, PyLicenseInfo, PyLink, PyPerson, PyTag, PyTextConstruct,
+};
 use super::datetime::optional_datetime_to_struct_time;
+use super::export;
 use super::geo::PyGeoLocation;
+use super::pickle::{self, Reduced};
 use super::podcast::{PyItunesFeedMeta, PyPodcastMeta};
 use super::syndication::PySyndicationMeta;
 
@@ -19,10 +23,53 @@ impl PyFeedMeta {
     pub fn from_core(core: CoreFeedMeta) -> Self {
         Self { inner: core }
     }
+
+    pub fn as_core(&self) -> &CoreFeedMeta {
+        &self.inner
+    }
+
+    /// Value of a feedparser-style `sy_*` key, as the string feedparser returns
+    fn syndication_value(&self, py: Python<'_>, key: &str) -> Option<Py<PyAny>> {
+        let sy = self.inner.syndication.as_deref()?;
+        let value = match key {
+            "sy_updateperiod" => sy.update_period.as_ref().map(|p| p.as_str().to_string()),
+            "sy_updatefrequency" => sy.update_frequency.map(|f| f.to_string()),
+            "sy_updatebase" => sy.update_base.clone(),
+            _ => None,
+        }?;
+        value.into_pyobject(py).ok().map(|o| o.into_any().unbind())
+    }
+
+    /// Value of a feedparser-style `blogchannel_*` key
+    fn blog_channel_value(&self, py: Python<'_>, key: &str) -> Option<Py<PyAny>> {
+        let blog_channel = self.inner.blog_channel.as_deref()?;
+        let value = match key {
+            "blogchannel_blogroll" => blog_channel.blog_roll.as_deref(),
+            "blogchannel_mysubscriptions" => blog_channel.my_subscriptions.as_deref(),
+            "blogchannel_blink" => blog_channel.blink.as_deref(),
+            _ => None,
+        }?;
+        value.into_pyobject(py).ok().map(|o| o.into_any().unbind())
+    }
 }
 
 #[pymethods]
 impl PyFeedMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "FeedMeta", &self.inner)
+    }
+
+    /// Returns the feed metadata as nested plain dicts and lists
+    fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        export::to_dict(py, &self.inner)
+    }
+
+    /// Returns the feed metadata as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(&self.inner, indent)
+    }
+
     #[getter]
     fn title(&self) -> Option<&str> {
         self.inner.title.as_deref()
@@ -114,6 +161,11 @@ impl PyFeedMeta {
             .collect()
     }
 
+    #[getter]
+    fn managing_editor(&self) -> Option<&str> {
+        self.inner.managing_editor.as_deref()
+    }
+
     #[getter]
     fn publisher(&self) -> Option<&str> {
         self.inner.publisher.as_deref()
@@ -176,6 +228,21 @@ impl PyFeedMeta {
         self.inner.logo.as_deref()
     }
 
+    #[getter]
+    fn raw_icon(&self) -> Option<&str> {
+        self.inner.raw_icon.as_deref()
+    }
+
+    #[getter]
+    fn raw_logo(&self) -> Option<&str> {
+        self.inner.raw_logo.as_deref()
+    }
+
+    #[getter]
+    fn raw_image_url(&self) -> Option<&str> {
+        self.inner.raw_image_url.as_deref()
+    }
+
     #[getter]
     fn tags(&self) -> Vec<PyTag> {
         self.inner
@@ -195,6 +262,11 @@ impl PyFeedMeta {
         self.inner.ttl
     }
 
+    #[getter]
+    fn expired(&self) -> bool {
+        self.inner.expired
+    }
+
     #[getter]
     fn itunes(&self) -> Option<PyItunesFeedMeta> {
         self.inner
@@ -216,6 +288,14 @@ impl PyFeedMeta {
         self.inner.license.as_deref()
     }
 
+    #[getter]
+    fn license_detail(&self) -> Option<PyLicenseInfo> {
+        self.inner
+            .license_detail
+            .as_ref()
+            .map(|l| PyLicenseInfo::from_core(l.clone()))
+    }
+
     #[getter]
     fn syndication(&self) -> Option<PySyndicationMeta> {
         self.inner
@@ -255,6 +335,10 @@ impl PyFeedMeta {
         )
     }
 
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner.to_canonical_json() == other.inner.to_canonical_json()
+    }
+
     /// Provides backward compatibility for deprecated Python feedparser field names.
     ///
     /// Maps old field names to their modern equivalents:
@@ -268,7 +352,7 @@ impl PyFeedMeta {
     /// This method is called by Python when normal attribute lookup fails.
     fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<Py<PyAny>> {
         // Check if this is a deprecated field name
-        if let Some(new_names) = FEED_FIELD_MAP.get(name) {
+        if let Some(new_names) = resolve_alias(AliasScope::Feed, name) {
             // Try each new field name in order
             for new_name in new_names {
                 let value: Option<Py<PyAny>> = match *new_name {
@@ -327,6 +411,11 @@ impl PyFeedMeta {
                         .link
                         .as_deref()
                         .and_then(|v| v.into_pyobject(py).map(|o| o.unbind().into()).ok()),
+                    "geo" => self
+                        .geo()
+                        .and_then(|g| Py::new(py, g).ok().map(Py::into_any)),
+                    "syndication" => self.syndication_value(py, name),
+                    "blog_channel" => self.blog_channel_value(py, name),
                     _ => None,
                 };
 
@@ -451,6 +540,13 @@ impl PyFeedMeta {
                     .collect();
                 Ok(contributors.into_pyobject(py)?.into_any().unbind())
             }
+            "managing_editor" => Ok(self
+                .inner
+                .managing_editor
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
             "publisher" => Ok(self
                 .inner
                 .publisher
@@ -521,6 +617,27 @@ impl PyFeedMeta {
                 .into_pyobject(py)?
                 .into_any()
                 .unbind()),
+            "raw_icon" => Ok(self
+                .inner
+                .raw_icon
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "raw_logo" => Ok(self
+                .inner
+                .raw_logo
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "raw_image_url" => Ok(self
+                .inner
+                .raw_image_url
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
             "tags" => {
                 let tags: Vec<_> = self
                     .inner
@@ -538,6 +655,13 @@ impl PyFeedMeta {
                 .into_any()
                 .unbind()),
             "ttl" => Ok(self.inner.ttl.into_pyobject(py)?.into_any().unbind()),
+            "expired" => Ok(self
+                .inner
+                .expired
+                .into_pyobject(py)?
+                .to_owned()
+                .into_any()
+                .unbind()),
             "itunes" => {
                 if let Some(ref i) = self.inner.itunes {
                     Ok(Py::new(py, PyItunesFeedMeta::from_core(i.as_ref().clone()))?.into_any())
@@ -559,6 +683,13 @@ impl PyFeedMeta {
                 .into_pyobject(py)?
                 .into_any()
                 .unbind()),
+            "license_detail" => {
+                if let Some(ref l) = self.inner.license_detail {
+                    Ok(Py::new(py, PyLicenseInfo::from_core(l.clone()))?.into_any())
+                } else {
+                    Ok(py.None())
+                }
+            }
             "syndication" => {
                 if let Some(ref s) = self.inner.syndication {
                     Ok(Py::new(py, PySyndicationMeta::from_core(s.as_ref().clone()))?.into_any())
@@ -596,7 +727,7 @@ impl PyFeedMeta {
             }
             // Check for deprecated field name aliases
             _ => {
-                if let Some(new_names) = FEED_FIELD_MAP.get(key) {
+                if let Some(new_names) = resolve_alias(AliasScope::Feed, key) {
                     // Try each new field name in order
                     for new_name in new_names {
                         let value: Option<Py<PyAny>> =
@@ -656,6 +787,11 @@ impl PyFeedMeta {
                                 "link" => self.inner.link.as_deref().and_then(|v| {
                                     v.into_pyobject(py).map(|o| o.unbind().into()).ok()
                                 }),
+                                "geo" => self
+                                    .geo()
+                                    .and_then(|g| Py::new(py, g).ok().map(Py::into_any)),
+                                "syndication" => self.syndication_value(py, key),
+                                "blog_channel" => self.blog_channel_value(py, key),
                                 _ => None,
                             };
 
@@ -1,3 +1,4 @@
+use super::pickle::{self, Reduced};
 use feedparser_rs::namespace::georss::{GeoLocation as CoreGeoLocation, GeoType};
 use pyo3::prelude::*;
 
@@ -19,6 +20,10 @@ impl PyGeoLocation {
 
 #[pymethods]
 impl PyGeoLocation {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "GeoLocation", &self.inner)
+    }
+
     #[getter]
     fn geo_type(&self) -> &str {
         match self.inner.geo_type {
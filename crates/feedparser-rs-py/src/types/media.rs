@@ -1,3 +1,4 @@
+use super::pickle::{self, Reduced};
 use feedparser_rs::{MediaContent as CoreMediaContent, MediaThumbnail as CoreMediaThumbnail};
 use pyo3::prelude::*;
 
@@ -19,6 +20,10 @@ impl PyMediaThumbnail {
 
 #[pymethods]
 impl PyMediaThumbnail {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "MediaThumbnail", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -34,6 +39,18 @@ impl PyMediaThumbnail {
         self.inner.height
     }
 
+    /// Point in the media the still was taken from, as written (NPT)
+    #[getter]
+    fn time(&self) -> Option<&str> {
+        self.inner.time.as_deref()
+    }
+
+    /// `time` as seconds from the start of the media
+    #[getter]
+    fn time_offset(&self) -> Option<f64> {
+        self.inner.time_offset().map(|t| t.as_secs_f64())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "MediaThumbnail(url='{}', width={:?}, height={:?})",
@@ -45,6 +62,7 @@ impl PyMediaThumbnail {
         self.inner.url == other.inner.url
             && self.inner.width == other.inner.width
             && self.inner.height == other.inner.height
+            && self.inner.time == other.inner.time
     }
 }
 
@@ -66,6 +84,10 @@ impl PyMediaContent {
 
 #[pymethods]
 impl PyMediaContent {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "MediaContent", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -97,6 +119,22 @@ impl PyMediaContent {
         self.inner.duration
     }
 
+    /// Preview stills for this rendition, in document order
+    #[getter]
+    fn thumbnails(&self) -> Vec<PyMediaThumbnail> {
+        self.inner
+            .thumbnails
+            .iter()
+            .map(|t| PyMediaThumbnail::from_core(t.clone()))
+            .collect()
+    }
+
+    /// Index of the `media:group` this rendition belongs to
+    #[getter]
+    fn group(&self) -> Option<usize> {
+        self.inner.group
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "MediaContent(url='{}', type='{}')",
@@ -112,5 +150,7 @@ impl PyMediaContent {
             && self.inner.width == other.inner.width
             && self.inner.height == other.inner.height
             && self.inner.duration == other.inner.duration
+            && self.inner.thumbnails == other.inner.thumbnails
+            && self.inner.group == other.inner.group
     }
 }
@@ -1,11 +1,13 @@
 pub mod common;
-pub mod compat;
 pub mod datetime;
+pub mod entries;
 pub mod entry;
+pub mod export;
 pub mod feed_meta;
 pub mod geo;
 pub mod media;
 pub mod parsed_feed;
+pub mod pickle;
 pub mod podcast;
 pub mod syndication;
 
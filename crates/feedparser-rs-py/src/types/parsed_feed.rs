@@ -1,20 +1,25 @@
 use feedparser_rs::ParsedFeed as CoreParsedFeed;
+use feedparser_rs::compat::{AliasScope, resolve_alias};
 use pyo3::exceptions::{PyAttributeError, PyKeyError};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use super::compat::CONTAINER_FIELD_MAP;
-use super::entry::PyEntry;
+use super::entries::PyEntries;
+use super::export;
 use super::feed_meta::PyFeedMeta;
+use super::pickle::{self, Reduced};
+use crate::error::bozo_exception;
 
 #[pyclass(name = "FeedParserDict", module = "feedparser_rs")]
 pub struct PyParsedFeed {
     feed: Py<PyFeedMeta>,
-    entries: Vec<Py<PyEntry>>,
+    entries: Py<PyEntries>,
     bozo: bool,
     bozo_exception: Option<String>,
+    bozo_exception_instance: Option<Py<PyAny>>,
     encoding: String,
     version: String,
+    raw_version: Option<String>,
     namespaces: Py<PyDict>,
     status: Option<u16>,
     href: Option<String>,
@@ -28,11 +33,7 @@ impl PyParsedFeed {
     pub fn from_core(py: Python<'_>, core: CoreParsedFeed) -> PyResult<Self> {
         let feed = Py::new(py, PyFeedMeta::from_core(core.feed))?;
 
-        let entries: PyResult<Vec<_>> = core
-            .entries
-            .into_iter()
-            .map(|e| Py::new(py, PyEntry::from_core(e)))
-            .collect();
+        let entries = Py::new(py, PyEntries::from_core(core.entries))?;
 
         let namespaces = PyDict::new(py);
         for (prefix, uri) in core.namespaces {
@@ -50,13 +51,21 @@ impl PyParsedFeed {
             None
         };
 
+        let bozo_exception_instance = core
+            .bozo_exception
+            .as_deref()
+            .map(|message| bozo_exception(py, message))
+            .transpose()?;
+
         Ok(Self {
             feed,
-            entries: entries?,
+            entries,
             bozo: core.bozo,
             bozo_exception: core.bozo_exception,
+            bozo_exception_instance,
             encoding: core.encoding,
             version: core.version.to_string(),
+            raw_version: core.raw_version,
             namespaces: namespaces.unbind(),
             status: core.status,
             href: core.href,
@@ -66,18 +75,59 @@ impl PyParsedFeed {
             headers,
         })
     }
+
+    /// Rebuilds the core value, used as the pickled state
+    fn to_core(&self, py: Python<'_>) -> PyResult<CoreParsedFeed> {
+        let version = self.version.parse().unwrap_or_default();
+        Ok(CoreParsedFeed {
+            feed: self.feed.borrow(py).as_core().clone(),
+            entries: self.entries.borrow(py).to_core(py),
+            bozo: self.bozo,
+            bozo_exception: self.bozo_exception.clone(),
+            encoding: self.encoding.clone(),
+            version,
+            raw_version: self.raw_version.clone(),
+            namespaces: self.namespaces.bind(py).extract()?,
+            status: self.status,
+            href: self.href.clone(),
+            etag: self.etag.clone(),
+            modified: self.modified.clone(),
+            #[cfg(feature = "http")]
+            headers: self
+                .headers
+                .as_ref()
+                .map(|headers| headers.bind(py).extract())
+                .transpose()?,
+            ..CoreParsedFeed::default()
+        })
+    }
 }
 
 #[pymethods]
 impl PyParsedFeed {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "FeedParserDict", &self.to_core(py)?)
+    }
+
+    /// Returns the parsed result as nested plain dicts and lists
+    fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        export::to_dict(py, &self.to_core(py)?)
+    }
+
+    /// Returns the parsed result as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, py: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(&self.to_core(py)?, indent)
+    }
+
     #[getter]
     fn feed(&self, py: Python<'_>) -> Py<PyFeedMeta> {
         self.feed.clone_ref(py)
     }
 
     #[getter]
-    fn entries(&self, py: Python<'_>) -> Vec<Py<PyEntry>> {
-        self.entries.iter().map(|e| e.clone_ref(py)).collect()
+    fn entries(&self, py: Python<'_>) -> Py<PyEntries> {
+        self.entries.clone_ref(py)
     }
 
     #[getter]
@@ -86,8 +136,10 @@ impl PyParsedFeed {
     }
 
     #[getter]
-    fn bozo_exception(&self) -> Option<&str> {
-        self.bozo_exception.as_deref()
+    fn bozo_exception(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.bozo_exception_instance
+            .as_ref()
+            .map(|exc| exc.clone_ref(py))
     }
 
     #[getter]
@@ -100,6 +152,12 @@ impl PyParsedFeed {
         &self.version
     }
 
+    /// Version exactly as the document declared it, e.g. `"2.0"` or `"0.91"`
+    #[getter]
+    fn raw_version(&self) -> Option<&str> {
+        self.raw_version.as_deref()
+    }
+
     #[getter]
     fn namespaces(&self, py: Python<'_>) -> Py<PyDict> {
         self.namespaces.clone_ref(py)
@@ -131,17 +189,22 @@ impl PyParsedFeed {
         self.headers.as_ref().map(|h| h.clone_ref(py))
     }
 
-    fn __repr__(&self) -> String {
+    fn __repr__(&self, py: Python<'_>) -> String {
         format!(
             "FeedParserDict(version='{}', bozo={}, entries={})",
             self.version,
             self.bozo,
-            self.entries.len()
+            self.entries.borrow(py).len()
         )
     }
 
-    fn __str__(&self) -> String {
-        self.__repr__()
+    fn __str__(&self, py: Python<'_>) -> String {
+        self.__repr__(py)
+    }
+
+    /// Compares the canonical JSON of both results
+    fn __eq__(&self, py: Python<'_>, other: &Self) -> PyResult<bool> {
+        Ok(self.to_core(py)?.to_canonical_json() == other.to_core(py)?.to_canonical_json())
     }
 
     /// Provides backward compatibility for deprecated Python feedparser container names.
@@ -153,20 +216,13 @@ impl PyParsedFeed {
     /// This method is called by Python when normal attribute lookup fails.
     fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<Py<PyAny>> {
         // Check if this is a deprecated container name
-        if let Some(new_name) = CONTAINER_FIELD_MAP.get(name) {
-            match *new_name {
+        if let Some(&[new_name, ..]) = resolve_alias(AliasScope::Container, name) {
+            match new_name {
                 "feed" => {
                     // Convert Py<PyFeedMeta> to Py<PyAny>
                     Ok(self.feed.clone_ref(py).into())
                 }
-                "entries" => {
-                    // Convert Vec<Py<PyEntry>> to Py<PyAny> (as Python list)
-                    let entries: Vec<_> = self.entries.iter().map(|e| e.clone_ref(py)).collect();
-                    match entries.into_pyobject(py) {
-                        Ok(list) => Ok(list.unbind()),
-                        Err(e) => Err(e),
-                    }
-                }
+                "entries" => Ok(self.entries.clone_ref(py).into_any()),
                 _ => Err(PyAttributeError::new_err(format!(
                     "'FeedParserDict' object has no attribute '{}'",
                     name
@@ -195,20 +251,12 @@ impl PyParsedFeed {
         // Check for known fields first
         match key {
             "feed" => Ok(self.feed.clone_ref(py).into()),
-            "entries" => {
-                let entries: Vec<_> = self.entries.iter().map(|e| e.clone_ref(py)).collect();
-                Ok(entries.into_pyobject(py)?.into_any().unbind())
-            }
+            "entries" => Ok(self.entries.clone_ref(py).into_any()),
             "bozo" => {
                 let pybozo = self.bozo.into_pyobject(py)?.to_owned();
                 Ok(pybozo.into_any().unbind())
             }
-            "bozo_exception" => Ok(self
-                .bozo_exception
-                .as_deref()
-                .into_pyobject(py)?
-                .into_any()
-                .unbind()),
+            "bozo_exception" => Ok(self.bozo_exception(py).into_pyobject(py)?.unbind()),
             "encoding" => Ok(self
                 .encoding
                 .as_str()
@@ -216,6 +264,12 @@ impl PyParsedFeed {
                 .into_any()
                 .unbind()),
             "version" => Ok(self.version.as_str().into_pyobject(py)?.into_any().unbind()),
+            "raw_version" => Ok(self
+                .raw_version
+                .as_deref()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
             "namespaces" => Ok(self.namespaces.clone_ref(py).into()),
             "status" => Ok(self.status.into_pyobject(py)?.into_any().unbind()),
             "href" => Ok(self.href.as_deref().into_pyobject(py)?.into_any().unbind()),
@@ -236,14 +290,10 @@ impl PyParsedFeed {
             }
             // Check for deprecated container name aliases
             _ => {
-                if let Some(new_name) = CONTAINER_FIELD_MAP.get(key) {
-                    match *new_name {
+                if let Some(&[new_name, ..]) = resolve_alias(AliasScope::Container, key) {
+                    match new_name {
                         "feed" => Ok(self.feed.clone_ref(py).into()),
-                        "entries" => {
-                            let entries: Vec<_> =
-                                self.entries.iter().map(|e| e.clone_ref(py)).collect();
-                            Ok(entries.into_pyobject(py)?.into_any().unbind())
-                        }
+                        "entries" => Ok(self.entries.clone_ref(py).into_any()),
                         _ => Err(PyKeyError::new_err(format!("'{}'", key))),
                     }
                 } else {
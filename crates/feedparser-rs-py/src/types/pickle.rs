@@ -0,0 +1,98 @@
+//! Pickle, `copy` and `deepcopy` support
+//!
+//! Every wrapper type implements `__reduce__` returning
+//! `(_restore, (type_name, state))`, where `state` is the serde JSON
+//! representation of the wrapped core value. `pickle`, `copy.copy`,
+//! `copy.deepcopy` and `multiprocessing` all go through this path.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::common::{
+    PyContent, PyEnclosure, PyGenerator, PyImage, PyLicenseInfo, PyLink, PyPerson, PySource, PyTag,
+    PyTextConstruct,
+};
+use super::entry::PyEntry;
+use super::feed_meta::PyFeedMeta;
+use super::geo::PyGeoLocation;
+use super::media::{PyMediaContent, PyMediaThumbnail};
+use super::parsed_feed::PyParsedFeed;
+use super::podcast::{
+    PyItunesCategory, PyItunesEntryMeta, PyItunesFeedMeta, PyItunesOwner, PyPodcastChapters,
+    PyPodcastEntryMeta, PyPodcastFunding, PyPodcastMeta, PyPodcastPerson, PyPodcastSoundbite,
+    PyPodcastTranscript,
+};
+use super::syndication::PySyndicationMeta;
+
+/// Return value of `__reduce__`: the restore function and its arguments
+pub type Reduced = (Py<PyAny>, (&'static str, String));
+
+/// Builds the `__reduce__` tuple for a wrapper around `value`
+pub fn reduce<T: Serialize>(
+    py: Python<'_>,
+    type_name: &'static str,
+    value: &T,
+) -> PyResult<Reduced> {
+    let state = serde_json::to_string(value)
+        .map_err(|e| PyValueError::new_err(format!("cannot pickle {type_name}: {e}")))?;
+    let restore = py
+        .import("feedparser_rs._feedparser_rs")?
+        .getattr("_restore")?
+        .unbind();
+    Ok((restore, (type_name, state)))
+}
+
+fn load<T: DeserializeOwned>(type_name: &str, state: &str) -> PyResult<T> {
+    serde_json::from_str(state)
+        .map_err(|e| PyValueError::new_err(format!("cannot unpickle {type_name}: {e}")))
+}
+
+/// Recreates a pickled object from its type name and JSON state
+#[pyfunction]
+#[pyo3(name = "_restore")]
+pub fn restore(py: Python<'_>, type_name: &str, state: &str) -> PyResult<Py<PyAny>> {
+    macro_rules! wrap {
+        ($ty:ty) => {
+            Py::new(py, <$ty>::from_core(load(type_name, state)?))?.into_any()
+        };
+    }
+    Ok(match type_name {
+        "FeedParserDict" => {
+            Py::new(py, PyParsedFeed::from_core(py, load(type_name, state)?)?)?.into_any()
+        }
+        "FeedMeta" => wrap!(PyFeedMeta),
+        "Entry" => wrap!(PyEntry),
+        "TextConstruct" => wrap!(PyTextConstruct),
+        "Link" => wrap!(PyLink),
+        "Person" => wrap!(PyPerson),
+        "Tag" => wrap!(PyTag),
+        "Image" => wrap!(PyImage),
+        "Enclosure" => wrap!(PyEnclosure),
+        "Content" => wrap!(PyContent),
+        "Generator" => wrap!(PyGenerator),
+        "LicenseInfo" => wrap!(PyLicenseInfo),
+        "Source" => wrap!(PySource),
+        "GeoLocation" => wrap!(PyGeoLocation),
+        "MediaThumbnail" => wrap!(PyMediaThumbnail),
+        "MediaContent" => wrap!(PyMediaContent),
+        "ItunesFeedMeta" => wrap!(PyItunesFeedMeta),
+        "ItunesEntryMeta" => wrap!(PyItunesEntryMeta),
+        "ItunesOwner" => wrap!(PyItunesOwner),
+        "ItunesCategory" => wrap!(PyItunesCategory),
+        "PodcastMeta" => wrap!(PyPodcastMeta),
+        "PodcastTranscript" => wrap!(PyPodcastTranscript),
+        "PodcastFunding" => wrap!(PyPodcastFunding),
+        "PodcastPerson" => wrap!(PyPodcastPerson),
+        "PodcastChapters" => wrap!(PyPodcastChapters),
+        "PodcastSoundbite" => wrap!(PyPodcastSoundbite),
+        "PodcastEntryMeta" => wrap!(PyPodcastEntryMeta),
+        "SyndicationMeta" => wrap!(PySyndicationMeta),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "cannot unpickle unknown type {type_name:?}"
+            )));
+        }
+    })
+}
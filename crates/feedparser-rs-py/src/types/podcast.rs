@@ -1,3 +1,4 @@
+use super::pickle::{self, Reduced};
 use feedparser_rs::{
     ItunesCategory as CoreItunesCategory, ItunesEntryMeta as CoreItunesEntryMeta,
     ItunesFeedMeta as CoreItunesFeedMeta, ItunesOwner as CoreItunesOwner,
@@ -22,6 +23,10 @@ impl PyItunesFeedMeta {
 
 #[pymethods]
 impl PyItunesFeedMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "ItunesFeedMeta", &self.inner)
+    }
+
     #[getter]
     fn author(&self) -> Option<&str> {
         self.inner.author.as_deref()
@@ -87,6 +92,10 @@ impl PyItunesEntryMeta {
 
 #[pymethods]
 impl PyItunesEntryMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "ItunesEntryMeta", &self.inner)
+    }
+
     #[getter]
     fn title(&self) -> Option<&str> {
         self.inner.title.as_deref()
@@ -150,6 +159,10 @@ impl PyItunesOwner {
 
 #[pymethods]
 impl PyItunesOwner {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "ItunesOwner", &self.inner)
+    }
+
     #[getter]
     fn name(&self) -> Option<&str> {
         self.inner.name.as_deref()
@@ -183,6 +196,10 @@ impl PyItunesCategory {
 
 #[pymethods]
 impl PyItunesCategory {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "ItunesCategory", &self.inner)
+    }
+
     #[getter]
     fn text(&self) -> &str {
         &self.inner.text
@@ -219,6 +236,10 @@ impl PyPodcastMeta {
 
 #[pymethods]
 impl PyPodcastMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastMeta", &self.inner)
+    }
+
     /// Returns podcast transcripts at feed level.
     ///
     /// Note: Field is named `transcripts` (plural) at feed level,
@@ -279,6 +300,10 @@ impl PyPodcastTranscript {
 
 #[pymethods]
 impl PyPodcastTranscript {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastTranscript", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -323,6 +348,10 @@ impl PyPodcastFunding {
 
 #[pymethods]
 impl PyPodcastFunding {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastFunding", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -352,6 +381,10 @@ impl PyPodcastPerson {
 
 #[pymethods]
 impl PyPodcastPerson {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastPerson", &self.inner)
+    }
+
     #[getter]
     fn name(&self) -> &str {
         &self.inner.name
@@ -400,6 +433,10 @@ impl PyPodcastChapters {
 
 #[pymethods]
 impl PyPodcastChapters {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastChapters", &self.inner)
+    }
+
     #[getter]
     fn url(&self) -> &str {
         &self.inner.url
@@ -433,6 +470,10 @@ impl PyPodcastSoundbite {
 
 #[pymethods]
 impl PyPodcastSoundbite {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastSoundbite", &self.inner)
+    }
+
     #[getter]
     fn start_time(&self) -> f64 {
         self.inner.start_time
@@ -470,6 +511,10 @@ impl PyPodcastEntryMeta {
 
 #[pymethods]
 impl PyPodcastEntryMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "PodcastEntryMeta", &self.inner)
+    }
+
     /// Returns podcast transcripts at entry level.
     ///
     /// Note: Field is named `transcript` (singular) at entry level,
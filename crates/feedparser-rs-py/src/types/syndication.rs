@@ -1,3 +1,4 @@
+use super::pickle::{self, Reduced};
 use feedparser_rs::SyndicationMeta as CoreSyndicationMeta;
 use pyo3::prelude::*;
 
@@ -16,6 +17,10 @@ impl PySyndicationMeta {
 
 #[pymethods]
 impl PySyndicationMeta {
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<Reduced> {
+        pickle::reduce(py, "SyndicationMeta", &self.inner)
+    }
+
     /// Update period (hourly, daily, weekly, monthly, yearly)
     #[getter]
     fn update_period(&self) -> Option<&str> {
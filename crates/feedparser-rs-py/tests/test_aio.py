@@ -0,0 +1,71 @@
+"""Tests for the asyncio API in feedparser_rs.aio"""
+
+import asyncio
+import inspect
+
+import pytest
+
+import feedparser_rs
+from feedparser_rs import aio
+
+
+def test_parse_url_is_coroutine_function():
+    """aio.parse_url returns an awaitable rather than blocking"""
+    assert inspect.iscoroutinefunction(aio.parse_url)
+    assert feedparser_rs.aio is aio
+
+
+def test_parse_url_error_is_raised_on_await():
+    """Fetch errors propagate to the awaiting coroutine"""
+
+    async def fetch():
+        # Loopback addresses are refused by the SSRF guard before any I/O
+        return await aio.parse_url("http://127.0.0.1/feed.xml")
+
+    with pytest.raises(RuntimeError, match="HTTP error"):
+        asyncio.run(fetch())
+
+
+def test_parse_url_does_not_block_event_loop():
+    """Other tasks keep running while a fetch is in flight"""
+
+    async def main():
+        ticks = []
+
+        async def ticker():
+            for _ in range(3):
+                ticks.append(None)
+                await asyncio.sleep(0)
+
+        results = await asyncio.gather(
+            aio.parse_url("http://127.0.0.1/feed.xml"),
+            ticker(),
+            return_exceptions=True,
+        )
+        assert isinstance(results[0], RuntimeError)
+        assert len(ticks) == 3
+
+    asyncio.run(main())
+
+
+def test_parse_url_cancellation():
+    """Cancelling the task does not crash when the fetch later completes"""
+
+    async def main():
+        task = asyncio.ensure_future(aio.parse_url("http://127.0.0.1/feed.xml"))
+        await asyncio.sleep(0)
+        task.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await task
+        # Give the background thread a chance to resolve the cancelled future
+        await asyncio.sleep(0.1)
+
+    asyncio.run(main())
+
+
+def test_parse_url_requires_running_loop():
+    """Calling outside an event loop fails like other asyncio APIs"""
+    coro = aio.parse_url("https://example.com/feed.xml")
+    with pytest.raises(RuntimeError):
+        coro.send(None)
+    coro.close()
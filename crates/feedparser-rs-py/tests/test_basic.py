@@ -71,6 +71,16 @@ def test_parse_from_string():
     assert d.feed.title == "Test"
 
 
+def test_raw_version():
+    """raw_version keeps the version attribute exactly as declared"""
+    d = feedparser_rs.parse('<rss version="0.91"><channel><title>Test</title></channel></rss>')
+    assert d.raw_version == "0.91"
+    assert d["raw_version"] == "0.91"
+
+    d = feedparser_rs.parse("<rss><channel><title>Test</title></channel></rss>")
+    assert d.raw_version is None
+
+
 def test_bozo_flag_malformed():
     """Test that malformed XML sets bozo flag"""
     xml = b"<rss><channel><title>Broken</title></rss>"  # Missing </channel>
@@ -82,6 +92,53 @@ def test_bozo_flag_malformed():
     assert d.bozo_exception is not None
 
 
+def test_bozo_exception_is_exception_instance():
+    """Test that bozo_exception is a feedparser-style exception object"""
+    import xml.sax
+
+    from feedparser_rs.exceptions import BozoException, SAXParseException
+
+    d = feedparser_rs.parse(b"<rss><channel><title>Broken</title></rss>")
+
+    exc = d.bozo_exception
+    assert isinstance(exc, SAXParseException)
+    assert isinstance(exc, xml.sax.SAXParseException)
+    assert isinstance(exc, BozoException)
+    assert "XML parsing error" in exc.getMessage()
+    assert d["bozo_exception"] is exc
+
+
+def test_bozo_exception_json():
+    """Test that malformed JSON Feed reports a JSONParseException"""
+    from feedparser_rs.exceptions import JSONParseException
+
+    d = feedparser_rs.parse(b'{"version": "https://jsonfeed.org/version/1.1", "items": [')
+
+    assert d.bozo
+    assert isinstance(d.bozo_exception, JSONParseException)
+    assert isinstance(d.bozo_exception, ValueError)
+
+
+def test_bozo_exception_limit():
+    """Test that hitting a parser limit reports LimitExceeded"""
+    from feedparser_rs.exceptions import LimitExceeded
+
+    items = "".join(f"<item><title>{i}</title></item>" for i in range(5))
+    xml = f"<rss version='2.0'><channel>{items}</channel></rss>".encode()
+    d = feedparser_rs.parse_with_limits(xml, limits=feedparser_rs.ParserLimits(max_entries=2))
+
+    assert d.bozo
+    assert isinstance(d.bozo_exception, LimitExceeded)
+
+
+def test_bozo_exception_none_when_well_formed():
+    """Test that a well-formed feed has no bozo_exception"""
+    d = feedparser_rs.parse(b"<rss version='2.0'><channel><title>Ok</title></channel></rss>")
+
+    assert not d.bozo
+    assert d.bozo_exception is None
+
+
 def test_datetime_struct_time():
     """Test that published_parsed returns time.struct_time"""
     xml = b"""<?xml version="1.0"?>
@@ -238,3 +295,67 @@ def test_repr_methods():
 
 if __name__ == "__main__":
     pytest.main([__file__, "-v"])
+
+
+def test_parse_bytes_like_sources():
+    """bytearray, memoryview and mmap are parsed through the buffer protocol"""
+    import mmap
+    import tempfile
+
+    xml = b"<rss version='2.0'><channel><title>Buffered</title></channel></rss>"
+
+    assert feedparser_rs.parse(bytearray(xml)).feed.title == "Buffered"
+    assert feedparser_rs.parse(memoryview(xml)).feed.title == "Buffered"
+    assert feedparser_rs.detect_format(memoryview(xml)) == "rss20"
+
+    with tempfile.TemporaryFile() as f:
+        f.write(xml)
+        f.flush()
+        with mmap.mmap(f.fileno(), 0, access=mmap.ACCESS_READ) as m:
+            assert feedparser_rs.parse(m).feed.title == "Buffered"
+
+
+def test_parse_rejects_non_contiguous_buffer():
+    """Strided memoryviews cannot be borrowed in place"""
+    xml = b"<rss version='2.0'><channel><title>T</title></channel></rss>"
+    with pytest.raises(TypeError):
+        feedparser_rs.parse(memoryview(xml)[::2])
+    with pytest.raises(TypeError):
+        feedparser_rs.parse(42)
+
+
+def test_entries_sequence():
+    """Entries support len(), indexing, slicing and iteration"""
+    xml = b"""<rss version="2.0"><channel>
+        <item><title>A</title><guid>1</guid></item>
+        <item><title>B</title><guid>2</guid></item>
+        <item><title>C</title><guid>3</guid></item>
+    </channel></rss>"""
+    d = feedparser_rs.parse(xml)
+
+    assert len(d.entries) == 3
+    assert d.entries[-1].title == "C"
+    assert [e.title for e in d.entries[::2]] == ["A", "C"]
+    assert [e.title for e in d.entries] == ["A", "B", "C"]
+    assert d.entries[0] is d.entries[0]
+    assert d["items"][1] is d.entries[1]
+    assert not feedparser_rs.parse(b"<rss version='2.0'><channel/></rss>").entries
+    with pytest.raises(IndexError):
+        d.entries[3]
+
+
+def test_canonical_equality():
+    """Results, feed metadata and entries compare by canonical representation"""
+    xml = b"""<rss version="2.0"><channel><title>T</title>
+        <item><title>A</title><guid>1</guid></item>
+        <item><title>B</title><guid>2</guid></item>
+    </channel></rss>"""
+    first, second = feedparser_rs.parse(xml), feedparser_rs.parse(xml)
+
+    assert first == second
+    assert first.feed == second.feed
+    assert first.entries == second.entries
+    assert first.entries == list(second.entries)
+    assert first.entries[0] == second.entries[0]
+    assert first.entries[0] != first.entries[1]
+    assert first != feedparser_rs.parse(xml.replace(b"<title>T", b"<title>U"))
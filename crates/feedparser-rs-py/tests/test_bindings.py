@@ -591,3 +591,26 @@ def test_geo_location_repr():
     # Should show actual coordinates for Point, not just count
     assert "45.256" in repr_str
     assert "-71.92" in repr_str
+
+
+def test_entry_source_and_license():
+    """Test entry.source (with feedparser's href alias) and entry.license"""
+    xml = b"""<feed xmlns="http://www.w3.org/2005/Atom">
+        <entry>
+            <title>Reposted</title>
+            <link rel="license" href="https://creativecommons.org/licenses/by/4.0/"/>
+            <source>
+                <id>urn:origin</id>
+                <title>Origin Feed</title>
+                <link href="https://origin.example/feed"/>
+            </source>
+        </entry>
+    </feed>"""
+
+    entry = feedparser_rs.parse(xml).entries[0]
+    assert entry.source.title == "Origin Feed"
+    assert entry.source.id == "urn:origin"
+    assert entry.source.link == "https://origin.example/feed"
+    assert entry.source.href == entry.source.link
+    assert entry.source.url == entry.source.link
+    assert entry.license == "https://creativecommons.org/licenses/by/4.0/"
@@ -5,6 +5,7 @@ Tests that deprecated field names correctly map to their modern equivalents:
 - Feed-level: description, tagline, modified, copyright, date, url
 - Entry-level: guid, description, issued, modified, date
 - Container-level: channel, items
+- Link-level: url
 """
 
 import feedparser_rs
@@ -99,6 +100,22 @@ def test_feed_date_alias_falls_back_to_published():
     assert feed.feed.date_parsed.tm_year == 2024
 
 
+def test_feed_last_build_date_maps_to_updated():
+    """RSS lastBuildDate fills feed.updated while pubDate stays in published"""
+    xml = """<rss version="2.0">
+        <channel>
+            <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate>
+            <lastBuildDate>Tue, 02 Jan 2024 12:00:00 GMT</lastBuildDate>
+        </channel>
+    </rss>"""
+
+    feed = feedparser_rs.parse(xml)
+
+    assert feed.feed.published_parsed.tm_mday == 1
+    assert feed.feed.updated_parsed.tm_mday == 2
+    assert feed.feed.date == feed.feed.updated
+
+
 def test_feed_url_alias():
     """feed.url should map to feed.link"""
     xml = """<rss version="2.0">
@@ -256,6 +273,20 @@ def test_container_items_alias():
     assert d.items[1].title == "Item 2"
 
 
+def test_link_url_alias():
+    """link.url should map to link.href"""
+    xml = """<feed xmlns="http://www.w3.org/2005/Atom">
+        <link rel="alternate" href="https://example.com/"/>
+    </feed>"""
+
+    d = feedparser_rs.parse(xml)
+
+    link = d.feed.links[0]
+    assert link.url == link.href == "https://example.com/"
+    with pytest.raises(AttributeError):
+        _ = link.nonexistent
+
+
 def test_unknown_field_raises_attribute_error():
     """Accessing unknown field should raise AttributeError"""
     xml = """<rss version="2.0">
@@ -601,6 +632,20 @@ def test_dict_access_detail_fields():
     assert entry["description_detail"] is not None
 
 
+
+def test_rss_description_detail_type():
+    """RSS descriptions are typed as HTML only when they contain markup"""
+    xml = """<rss version="2.0"><channel>
+        <item><description>Plain text</description></item>
+        <item><description>&lt;p&gt;Markup&lt;/p&gt;</description></item>
+    </channel></rss>"""
+
+    feed = feedparser_rs.parse(xml)
+
+    assert feed.entries[0].summary_detail.type == "text"
+    assert feed.entries[1].summary_detail.type == "html"
+    assert feed.entries[1].summary == "<p>Markup</p>"
+
 def test_dict_access_list_fields():
     """Test dict access for list fields (links, tags, authors, etc.)"""
     xml = """<feed xmlns="http://www.w3.org/2005/Atom">
@@ -733,3 +778,159 @@ def test_parse_with_limits_accepts_http_params():
         xml, etag="etag", modified="modified", user_agent="TestBot/1.0", limits=limits
     )
     assert feed.feed.title == "Test Feed"
+
+
+def test_parse_accepts_feedparser_http_kwargs():
+    """parse() accepts feedparser's agent/referrer/request_headers kwargs"""
+    xml = """<rss version="2.0">
+        <channel>
+            <title>Test Feed</title>
+        </channel>
+    </rss>"""
+
+    feed = feedparser_rs.parse(
+        xml,
+        etag='"abc"',
+        agent="TestBot/1.0",
+        referrer="https://example.com/",
+        request_headers={"X-Test": "1"},
+    )
+    assert feed.feed.title == "Test Feed"
+
+
+def test_parse_accepts_modified_datetime_and_struct_time():
+    """modified may be a datetime or time.struct_time, like feedparser"""
+    import datetime
+    import time
+
+    xml = "<rss version='2.0'><channel><title>T</title></channel></rss>"
+    aware = datetime.datetime(2024, 1, 1, tzinfo=datetime.timezone.utc)
+    for modified in (aware, datetime.datetime(2024, 1, 1), time.gmtime(0)):
+        feed = feedparser_rs.parse(xml, modified=modified)
+        assert feed.feed.title == "T"
+
+    with pytest.raises(TypeError):
+        feedparser_rs.parse(xml, modified=object())
+
+
+def test_sanitize_html_keyword():
+    """sanitize_html= toggles HTML cleaning like feedparser's SANITIZE_HTML"""
+    xml = """<rss version="2.0"><channel><item>
+        <description><![CDATA[<p>Hi</p><script>alert(1)</script>]]></description>
+    </item></channel></rss>"""
+
+    assert feedparser_rs.parse(xml).entries[0].summary == "<p>Hi</p>"
+    raw = feedparser_rs.parse(xml, sanitize_html=False).entries[0].summary
+    assert "<script>" in raw
+
+    limits = feedparser_rs.ParserLimits()
+    raw = feedparser_rs.parse_with_limits(xml, limits=limits, sanitize_html=False)
+    assert "<script>" in raw.entries[0].summary
+
+
+def test_resolve_relative_uris_keyword():
+    """resolve_relative_uris= toggles xml:base resolution like feedparser"""
+    xml = """<feed xmlns="http://www.w3.org/2005/Atom" xml:base="http://example.com/">
+        <entry><link href="post/1"/></entry>
+    </feed>"""
+
+    assert feedparser_rs.parse(xml).entries[0].link == "http://example.com/post/1"
+    d = feedparser_rs.parse(xml, resolve_relative_uris=False)
+    assert d.entries[0].link == "post/1"
+
+
+def test_base_url_keyword():
+    """base_url= resolves relative links in feeds without xml:base"""
+    xml = """<rss version="2.0"><channel>
+        <item><link>/post/1</link><enclosure url="a.mp3" type="audio/mpeg"/></item>
+    </channel></rss>"""
+
+    d = feedparser_rs.parse(xml, base_url="https://example.com/feeds/main.xml")
+    assert d.entries[0].link == "https://example.com/post/1"
+    assert d.entries[0].enclosures[0].url == "https://example.com/feeds/a.mp3"
+    assert feedparser_rs.parse(xml).entries[0].link == "/post/1"
+
+
+def test_base_url_feed_image():
+    """Relative image URLs are resolved and the raw value is kept"""
+    xml = """<rss version="2.0"><channel>
+        <image><url>img/logo.png</url><title>Logo</title><link>/</link></image>
+    </channel></rss>"""
+
+    d = feedparser_rs.parse(xml, base_url="https://example.com/feeds/main.xml")
+    assert d.feed.image.url == "https://example.com/feeds/img/logo.png"
+    assert d.feed.raw_image_url == "img/logo.png"
+    assert d.feed["raw_image_url"] == "img/logo.png"
+
+
+def test_preserve_whitespace_keyword():
+    """preserve_whitespace= keeps indentation in element text"""
+    xml = """<rss version="2.0"><channel><item>
+        <title>  spaced  </title>
+    </item></channel></rss>"""
+
+    assert feedparser_rs.parse(xml).entries[0].title == "spaced"
+    d = feedparser_rs.parse(xml, preserve_whitespace=True)
+    assert d.entries[0].title == "  spaced  "
+
+
+def test_atom_enclosure_links():
+    """Atom rel="enclosure" links also populate entry.enclosures"""
+    xml = """<feed xmlns="http://www.w3.org/2005/Atom"><entry>
+        <link rel="http://www.iana.org/assignments/relation/enclosure"
+              type="audio/mpeg" length="1234" href="https://example.com/a.mp3"/>
+    </entry></feed>"""
+
+    entry = feedparser_rs.parse(xml).entries[0]
+    assert entry.links[0].rel == "enclosure"
+    assert entry.enclosures[0].url == "https://example.com/a.mp3"
+    assert entry.enclosures[0].length == 1234
+
+
+def test_clean_html_and_strip_html():
+    """clean_html applies the parser's sanitizer; strip_html returns plain text"""
+    html = '<p onclick="x()">Fish &amp; chips</p><script>x()</script>'
+    xml = f"<rss version='2.0'><channel><item><description><![CDATA[{html}]]></description></item></channel></rss>"
+
+    assert feedparser_rs.clean_html(html) == "<p>Fish &amp; chips</p>"
+    assert feedparser_rs.parse(xml).entries[0].summary == feedparser_rs.clean_html(html)
+    assert feedparser_rs.strip_html(html) == "Fish & chips"
+
+
+def test_json_feed_imagery_and_expired():
+    """JSON Feed icon/favicon map to logo/icon and expired is kept"""
+    data = b"""{
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Old Feed",
+        "icon": "https://example.com/icon.png",
+        "favicon": "https://example.com/favicon.png",
+        "expired": true,
+        "items": []
+    }"""
+
+    d = feedparser_rs.parse(data)
+    assert d.feed.logo == "https://example.com/icon.png"
+    assert d.feed.icon == "https://example.com/favicon.png"
+    assert d.feed.image.url == "https://example.com/icon.png"
+    assert d.feed.expired is True
+    assert d.feed["expired"] is True
+
+
+def test_json_feed_item_images_and_external_url():
+    """JSON Feed item image, banner_image and external_url are exposed"""
+    data = b"""{
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Link Blog",
+        "items": [{
+            "id": "1",
+            "external_url": "https://other.example.org/article",
+            "image": "https://example.com/square.png",
+            "banner_image": "https://example.com/banner.png"
+        }]
+    }"""
+
+    entry = feedparser_rs.parse(data).entries[0]
+    assert entry.external_url == "https://other.example.org/article"
+    assert entry["external_url"] == entry.external_url
+    assert entry.media_thumbnails[0].url == "https://example.com/square.png"
+    assert entry.banner_image == "https://example.com/banner.png"
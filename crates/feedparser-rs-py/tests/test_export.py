@@ -0,0 +1,71 @@
+"""
+Test to_dict() and to_json() export of parse results.
+"""
+
+import json
+
+import feedparser_rs
+import pytest
+
+RSS = b"""<?xml version="1.0"?>
+<rss version="2.0">
+    <channel>
+        <title>Export Feed</title>
+        <link>https://example.com/</link>
+        <item>
+            <title>Episode 1</title>
+            <guid>ep-1</guid>
+            <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate>
+            <enclosure url="https://example.com/ep1.mp3" length="123" type="audio/mpeg"/>
+        </item>
+    </channel>
+</rss>"""
+
+
+@pytest.fixture
+def feed():
+    return feedparser_rs.parse(RSS)
+
+
+def _assert_plain(value):
+    if isinstance(value, dict):
+        for key, item in value.items():
+            assert isinstance(key, str)
+            _assert_plain(item)
+    elif isinstance(value, list):
+        for item in value:
+            _assert_plain(item)
+    else:
+        assert value is None or isinstance(value, (str, int, float, bool))
+
+
+def test_to_dict_is_plain_data(feed):
+    data = feed.to_dict()
+    _assert_plain(data)
+    assert data["feed"]["title"] == "Export Feed"
+    assert data["entries"][0]["id"] == "ep-1"
+    assert data["entries"][0]["enclosures"][0]["url"] == "https://example.com/ep1.mp3"
+    assert data["entries"][0]["published"].startswith("2024-01-01T12:00:00")
+    assert data["bozo"] is False
+
+
+def test_to_json_round_trips_through_json(feed):
+    assert json.loads(feed.to_json()) == feed.to_dict()
+
+
+def test_to_json_indent(feed):
+    text = feed.to_json(indent=2)
+    assert "\n  " in text
+    assert json.loads(text) == feed.to_dict()
+
+
+def test_entry_and_feed_to_dict(feed):
+    assert feed.entries[0].to_dict() == feed.to_dict()["entries"][0]
+    assert feed.feed.to_dict() == feed.to_dict()["feed"]
+    assert json.loads(feed.entries[0].to_json())["title"] == "Episode 1"
+
+
+def test_bozo_exception_exported_as_message():
+    d = feedparser_rs.parse(b"<rss><channel><title>Broken</title></rss>")
+    assert d.bozo
+    assert isinstance(d.to_dict()["bozo_exception"], str)
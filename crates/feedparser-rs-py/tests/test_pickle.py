@@ -0,0 +1,76 @@
+"""
+Test pickle, copy and deepcopy support for parse results.
+"""
+
+import copy
+import multiprocessing
+import pickle
+
+import feedparser_rs
+import pytest
+
+RSS = b"""<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    <channel>
+        <title>Pickle Feed</title>
+        <link>https://example.com/</link>
+        <itunes:author>Jane</itunes:author>
+        <item>
+            <title>Episode 1</title>
+            <guid>ep-1</guid>
+            <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate>
+            <enclosure url="https://example.com/ep1.mp3" length="123" type="audio/mpeg"/>
+        </item>
+    </channel>
+</rss>"""
+
+
+@pytest.fixture
+def feed():
+    return feedparser_rs.parse(RSS)
+
+
+@pytest.mark.parametrize("protocol", range(pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_round_trip(feed, protocol):
+    restored = pickle.loads(pickle.dumps(feed, protocol=protocol))
+    assert restored.version == feed.version
+    assert restored.feed.title == "Pickle Feed"
+    assert restored.feed.itunes.author == "Jane"
+    assert restored.entries[0].id == "ep-1"
+    assert restored.entries[0].published_parsed == feed.entries[0].published_parsed
+    assert restored.entries[0].enclosures[0].length == 123
+    assert restored.namespaces == feed.namespaces
+
+
+def test_pickle_nested_types(feed):
+    entry = pickle.loads(pickle.dumps(feed.entries[0]))
+    assert entry.title == "Episode 1"
+    enclosure = pickle.loads(pickle.dumps(feed.entries[0].enclosures[0]))
+    assert enclosure.url == "https://example.com/ep1.mp3"
+    meta = pickle.loads(pickle.dumps(feed.feed))
+    assert meta.link == "https://example.com/"
+
+
+def test_copy_and_deepcopy(feed):
+    shallow = copy.copy(feed)
+    deep = copy.deepcopy(feed)
+    for clone in (shallow, deep):
+        assert clone.feed.title == feed.feed.title
+        assert len(clone.entries) == len(feed.entries)
+    assert deep.entries[0] is not feed.entries[0]
+
+
+def test_bozo_state_survives_pickle():
+    feed = feedparser_rs.parse(b"<rss version='2.0'><channel><title>Broken</title>")
+    restored = pickle.loads(pickle.dumps(feed))
+    assert restored.bozo == feed.bozo
+    assert restored.bozo_exception == feed.bozo_exception
+
+
+def _entry_count(feed):
+    return len(feed.entries)
+
+
+def test_multiprocessing(feed):
+    with multiprocessing.get_context("spawn").Pool(1) as pool:
+        assert pool.apply(_entry_count, (feed,)) == 1
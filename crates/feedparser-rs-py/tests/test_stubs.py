@@ -0,0 +1,72 @@
+"""
+Test that the shipped type stubs match the compiled extension.
+"""
+
+import ast
+import inspect
+import subprocess
+import sys
+from pathlib import Path
+
+import feedparser_rs
+import pytest
+from feedparser_rs import _feedparser_rs
+
+CRATE = Path(__file__).resolve().parent.parent
+STUB = CRATE / "python" / "feedparser_rs" / "_feedparser_rs.pyi"
+
+
+def _stub_module():
+    return ast.parse(STUB.read_text())
+
+
+def _stub_classes():
+    return {
+        node.name: {item.name for item in node.body if isinstance(item, ast.FunctionDef)}
+        for node in _stub_module().body
+        if isinstance(node, ast.ClassDef)
+    }
+
+
+def test_stub_is_up_to_date():
+    result = subprocess.run(
+        [sys.executable, str(CRATE / "scripts" / "generate_stubs.py"), "--check"],
+        capture_output=True,
+        text=True,
+    )
+    assert result.returncode == 0, result.stdout
+
+
+def test_stub_is_shipped_with_py_typed():
+    assert (STUB.parent / "py.typed").exists()
+
+
+def test_every_runtime_class_has_a_stub():
+    runtime = {name for name, value in vars(_feedparser_rs).items() if inspect.isclass(value)}
+    assert runtime == set(_stub_classes())
+
+
+@pytest.mark.parametrize("name", sorted(_stub_classes()))
+def test_stub_members_exist_at_runtime(name):
+    cls = getattr(_feedparser_rs, name)
+    # PyO3 installs __getattr__ as a type slot rather than a class attribute
+    for member in _stub_classes()[name] - {"__getattr__"}:
+        assert hasattr(cls, member), f"{name}.{member}"
+
+
+@pytest.mark.parametrize("name", sorted(_stub_classes()))
+def test_runtime_members_are_stubbed(name):
+    cls = getattr(_feedparser_rs, name)
+    public = {member for member in vars(cls) if not member.startswith("_")}
+    assert public <= _stub_classes()[name]
+
+
+def test_stub_functions_exist_at_runtime():
+    functions = [node.name for node in _stub_module().body if isinstance(node, ast.FunctionDef)]
+    for name in functions:
+        assert callable(getattr(_feedparser_rs, name)), name
+
+
+def test_classes_exported_from_package():
+    for name in _stub_classes():
+        assert getattr(feedparser_rs, name) is getattr(_feedparser_rs, name)
@@ -164,3 +164,55 @@ def test_partial_syndication():
     assert d.feed.syndication.update_period == "weekly"
     assert d.feed.syndication.update_frequency is None
     assert d.feed.syndication.update_base is None
+
+
+def test_feedparser_style_keys():
+    """sy_* and where keys resolve like Python feedparser's flattened names"""
+    feed_xml = b"""<?xml version="1.0"?>
+    <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+             xmlns="http://purl.org/rss/1.0/"
+             xmlns:syn="http://purl.org/rss/1.0/modules/syndication/">
+      <channel>
+        <title>Test Feed</title>
+        <link>https://example.com</link>
+        <syn:updatePeriod>hourly</syn:updatePeriod>
+        <syn:updateFrequency>4</syn:updateFrequency>
+        <syn:updateBase>2024-01-01T00:00:00Z</syn:updateBase>
+      </channel>
+    </rdf:RDF>"""
+
+    d = feedparser_rs.parse(feed_xml)
+    assert d.feed.sy_updateperiod == "hourly"
+    assert d.feed["sy_updatefrequency"] == "4"
+    assert d.feed.sy_updatebase == "2024-01-01T00:00:00Z"
+
+
+def test_feed_where_alias():
+    """feed.where is feedparser's name for the channel GeoRSS location"""
+    feed_xml = b"""<rss version="2.0" xmlns:georss="http://www.georss.org/georss">
+      <channel>
+        <title>Test Feed</title>
+        <georss:point>45.256 -71.92</georss:point>
+      </channel>
+    </rss>"""
+
+    d = feedparser_rs.parse(feed_xml)
+    assert d.feed.where == d.feed.geo
+    assert d.feed["where"].coordinates == [(45.256, -71.92)]
+
+
+def test_blog_channel_keys():
+    """blogchannel_* keys expose the blogChannel module links like feedparser"""
+    feed_xml = b"""<rss version="2.0" xmlns:blogChannel="http://backend.userland.com/blogChannelModule">
+      <channel>
+        <title>Test Feed</title>
+        <blogChannel:blogRoll>http://example.com/blogroll.opml</blogChannel:blogRoll>
+        <blogChannel:mySubscriptions>http://example.com/subs.opml</blogChannel:mySubscriptions>
+        <blogChannel:blink>http://friend.example.org/</blogChannel:blink>
+      </channel>
+    </rss>"""
+
+    d = feedparser_rs.parse(feed_xml)
+    assert d.feed.blogchannel_blogroll == "http://example.com/blogroll.opml"
+    assert d.feed["blogchannel_mysubscriptions"] == "http://example.com/subs.opml"
+    assert d.feed.blogchannel_blink == "http://friend.example.org/"
@@ -0,0 +1,253 @@
+//! Swift and Kotlin bindings for feedparser-rs
+//!
+//! Exposes parsing, format detection and resource limits through
+//! [UniFFI](https://mozilla.github.io/uniffi-rs/), so iOS and Android podcast
+//! and reader apps share the parser used by the Rust, Python and Node.js
+//! packages. The generated Swift and Kotlin sources come from the built
+//! library:
+//!
+//! ```sh
+//! cargo build --release
+//! cargo run --bin uniffi-bindgen -- generate \
+//!     --library target/release/libfeedparser_rs_uniffi.so \
+//!     --language swift --out-dir out/swift
+//! ```
+//!
+//! Feeds are passed in as bytes; fetching them is left to the platform's
+//! HTTP stack.
+
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+
+use feedparser_rs::{self as core, FeedError as CoreFeedError, ParserLimits as CoreParserLimits};
+
+mod types;
+
+pub use types::{
+    Content, Enclosure, Entry, FeedMeta, Image, ItunesCategory, ItunesEntryMeta, ItunesFeedMeta,
+    ItunesOwner, Link, ParsedFeed, Person, PodcastChapters, PodcastPerson, PodcastTranscript, Tag,
+    TextConstruct,
+};
+
+uniffi::setup_scaffolding!();
+
+/// Errors raised to Swift and Kotlin callers
+///
+/// Malformed feeds are not errors: they parse with `bozo` set. These cover
+/// input the parser refuses outright.
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum FeedError {
+    /// Input is larger than `ParserLimits.max_feed_size_bytes`
+    #[error("Feed size ({size} bytes) exceeds maximum allowed ({max} bytes)")]
+    TooLarge {
+        /// Input size in bytes
+        size: u64,
+        /// Configured maximum in bytes
+        max: u64,
+    },
+    /// The parser could not produce a result
+    #[error(transparent)]
+    Parse(#[from] CoreFeedError),
+}
+
+/// Resource limits applied while parsing
+///
+/// Start from [`default_limits`] and lower the values an app cares about,
+/// e.g. `max_entries` for a feed preview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Record)]
+pub struct ParserLimits {
+    /// Maximum feed size in bytes
+    pub max_feed_size_bytes: u64,
+    /// Maximum number of entries
+    pub max_entries: u64,
+    /// Maximum number of feed-level links
+    pub max_links_per_feed: u64,
+    /// Maximum number of links per entry
+    pub max_links_per_entry: u64,
+    /// Maximum number of authors per feed or entry
+    pub max_authors: u64,
+    /// Maximum number of contributors per feed or entry
+    pub max_contributors: u64,
+    /// Maximum number of tags per feed or entry
+    pub max_tags: u64,
+    /// Maximum number of content blocks per entry
+    pub max_content_blocks: u64,
+    /// Maximum number of enclosures per entry
+    pub max_enclosures: u64,
+    /// Maximum number of XML namespaces
+    pub max_namespaces: u64,
+    /// Maximum XML nesting depth
+    pub max_nesting_depth: u64,
+    /// Maximum text field length in bytes
+    pub max_text_length: u64,
+    /// Maximum attribute value length in bytes
+    pub max_attribute_length: u64,
+}
+
+impl From<CoreParserLimits> for ParserLimits {
+    fn from(core: CoreParserLimits) -> Self {
+        let size = |value: usize| value as u64;
+        Self {
+            max_feed_size_bytes: size(core.max_feed_size_bytes),
+            max_entries: size(core.max_entries),
+            max_links_per_feed: size(core.max_links_per_feed),
+            max_links_per_entry: size(core.max_links_per_entry),
+            max_authors: size(core.max_authors),
+            max_contributors: size(core.max_contributors),
+            max_tags: size(core.max_tags),
+            max_content_blocks: size(core.max_content_blocks),
+            max_enclosures: size(core.max_enclosures),
+            max_namespaces: size(core.max_namespaces),
+            max_nesting_depth: size(core.max_nesting_depth),
+            max_text_length: size(core.max_text_length),
+            max_attribute_length: size(core.max_attribute_length),
+        }
+    }
+}
+
+impl From<ParserLimits> for CoreParserLimits {
+    fn from(limits: ParserLimits) -> Self {
+        let size = |value: u64| usize::try_from(value).unwrap_or(usize::MAX);
+        Self {
+            max_feed_size_bytes: size(limits.max_feed_size_bytes),
+            max_entries: size(limits.max_entries),
+            max_links_per_feed: size(limits.max_links_per_feed),
+            max_links_per_entry: size(limits.max_links_per_entry),
+            max_authors: size(limits.max_authors),
+            max_contributors: size(limits.max_contributors),
+            max_tags: size(limits.max_tags),
+            max_content_blocks: size(limits.max_content_blocks),
+            max_enclosures: size(limits.max_enclosures),
+            max_namespaces: size(limits.max_namespaces),
+            max_nesting_depth: size(limits.max_nesting_depth),
+            max_text_length: size(limits.max_text_length),
+            max_attribute_length: size(limits.max_attribute_length),
+            ..Self::default()
+        }
+    }
+}
+
+/// Limits used by [`parse`]
+#[uniffi::export]
+pub fn default_limits() -> ParserLimits {
+    CoreParserLimits::default().into()
+}
+
+/// Parse an RSS, Atom or JSON Feed document with the default limits
+///
+/// # Errors
+///
+/// Returns an error if the input exceeds the size limit or cannot be parsed
+/// at all.
+#[uniffi::export]
+pub fn parse(data: Vec<u8>) -> Result<ParsedFeed, FeedError> {
+    parse_with_limits(data, default_limits())
+}
+
+/// Parse an RSS, Atom or JSON Feed document with custom limits
+///
+/// # Errors
+///
+/// Returns an error if the input exceeds `limits.max_feed_size_bytes` or
+/// cannot be parsed at all.
+#[uniffi::export]
+pub fn parse_with_limits(data: Vec<u8>, limits: ParserLimits) -> Result<ParsedFeed, FeedError> {
+    if data.len() as u64 > limits.max_feed_size_bytes {
+        return Err(FeedError::TooLarge {
+            size: data.len() as u64,
+            max: limits.max_feed_size_bytes,
+        });
+    }
+    let feed = core::parse_with_limits(&data, limits.into())?;
+    Ok(feed.into())
+}
+
+/// Detect the feed format without parsing the whole document
+///
+/// Returns a version string such as `"rss20"`, `"atom10"` or `"json11"`,
+/// or an empty string if the format is not recognized.
+#[uniffi::export]
+pub fn detect_format(data: Vec<u8>) -> String {
+    core::detect_format(&data).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_podcast() {
+        let xml = br#"<rss version="2.0"
+            xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"
+            xmlns:podcast="https://podcastindex.org/namespace/1.0">
+            <channel>
+                <title>Show</title>
+                <itunes:author>Host</itunes:author>
+                <item>
+                    <title>Episode 1</title>
+                    <guid>ep1</guid>
+                    <pubDate>Mon, 01 Jan 2024 10:00:00 +0000</pubDate>
+                    <enclosure url="https://cdn.example.com/ep1.mp3" length="1000" type="audio/mpeg"/>
+                    <itunes:duration>1:00:00</itunes:duration>
+                    <podcast:chapters url="https://cdn.example.com/ep1.json" type="application/json+chapters"/>
+                </item>
+            </channel>
+        </rss>"#;
+        let feed = parse(xml.to_vec()).unwrap();
+
+        assert_eq!(feed.version, "rss20");
+        assert_eq!(feed.feed.title.as_deref(), Some("Show"));
+        let itunes = feed.feed.itunes.as_ref().unwrap();
+        assert_eq!(itunes.author.as_deref(), Some("Host"));
+
+        let entry = &feed.entries[0];
+        assert_eq!(entry.id.as_deref(), Some("ep1"));
+        assert!(entry.published.is_some());
+        assert_eq!(entry.enclosures[0].length, Some(1000));
+        assert_eq!(entry.itunes.as_ref().unwrap().duration, Some(3600));
+        assert_eq!(
+            entry.podcast_chapters.as_ref().unwrap().url,
+            "https://cdn.example.com/ep1.json"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_limits() {
+        let xml = b"<rss version='2.0'><channel><item><title>1</title></item>\
+            <item><title>2</title></item><item><title>3</title></item></channel></rss>"
+            .to_vec();
+        let limits = ParserLimits {
+            max_entries: 2,
+            ..default_limits()
+        };
+        let feed = parse_with_limits(xml.clone(), limits).unwrap();
+        assert_eq!(feed.entries.len(), 2);
+
+        let limits = ParserLimits {
+            max_feed_size_bytes: 10,
+            ..default_limits()
+        };
+        assert!(matches!(
+            parse_with_limits(xml, limits),
+            Err(FeedError::TooLarge { max: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn test_default_limits_round_trip() {
+        let limits = CoreParserLimits::from(default_limits());
+        assert_eq!(ParserLimits::from(limits), default_limits());
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            detect_format(br#"{"version": "https://jsonfeed.org/version/1.1"}"#.to_vec()),
+            "json11"
+        );
+        assert_eq!(
+            detect_format(b"<feed xmlns='http://www.w3.org/2005/Atom'/>".to_vec()),
+            "atom10"
+        );
+    }
+}
@@ -0,0 +1,596 @@
+//! Records returned to Swift and Kotlin
+//!
+//! Mirrors of the core result types with plain strings for URLs and MIME
+//! types and `SystemTime` for dates, which arrive as `Date` in Swift and
+//! `java.time.Instant` in Kotlin.
+
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use feedparser_rs::{
+    Content as CoreContent, Email, Enclosure as CoreEnclosure, Entry as CoreEntry,
+    FeedMeta as CoreFeedMeta, Image as CoreImage, ItunesCategory as CoreItunesCategory,
+    ItunesEntryMeta as CoreItunesEntryMeta, ItunesFeedMeta as CoreItunesFeedMeta,
+    ItunesOwner as CoreItunesOwner, Link as CoreLink, ParsedFeed as CoreParsedFeed,
+    Person as CorePerson, PodcastChapters as CorePodcastChapters,
+    PodcastPerson as CorePodcastPerson, PodcastTranscript as CorePodcastTranscript, Tag as CoreTag,
+    TextConstruct as CoreTextConstruct, TextType, Url,
+};
+
+/// Parsed feed result, analogous to Python feedparser's `FeedParserDict`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ParsedFeed {
+    /// Feed metadata
+    pub feed: FeedMeta,
+    /// Feed entries/items
+    pub entries: Vec<Entry>,
+    /// True if parsing encountered errors
+    pub bozo: bool,
+    /// Description of the parsing error, if `bozo` is true
+    pub bozo_exception: Option<String>,
+    /// Detected or declared encoding
+    pub encoding: String,
+    /// Detected feed format version, e.g. `"rss20"` or `"atom10"`
+    pub version: String,
+    /// Version exactly as the document declared it, e.g. `"2.0"`
+    pub raw_version: Option<String>,
+    /// XML namespaces (prefix -> URI)
+    pub namespaces: HashMap<String, String>,
+}
+
+impl From<CoreParsedFeed> for ParsedFeed {
+    fn from(core: CoreParsedFeed) -> Self {
+        Self {
+            feed: FeedMeta::from(core.feed),
+            entries: core.entries.into_iter().map(Entry::from).collect(),
+            bozo: core.bozo,
+            bozo_exception: core.bozo_exception,
+            encoding: core.encoding,
+            version: core.version.to_string(),
+            raw_version: core.raw_version,
+            namespaces: core.namespaces,
+        }
+    }
+}
+
+/// Feed metadata
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FeedMeta {
+    /// Feed title
+    pub title: Option<String>,
+    /// Detailed title with metadata
+    pub title_detail: Option<TextConstruct>,
+    /// Primary feed link
+    pub link: Option<String>,
+    /// All links associated with this feed
+    pub links: Vec<Link>,
+    /// Feed subtitle/description
+    pub subtitle: Option<String>,
+    /// Detailed subtitle with metadata
+    pub subtitle_detail: Option<TextConstruct>,
+    /// Last update date
+    pub updated: Option<SystemTime>,
+    /// Initial publication date
+    pub published: Option<SystemTime>,
+    /// Primary author name
+    pub author: Option<String>,
+    /// Detailed author information
+    pub author_detail: Option<Person>,
+    /// All authors
+    pub authors: Vec<Person>,
+    /// Contributors
+    pub contributors: Vec<Person>,
+    /// Publisher name
+    pub publisher: Option<String>,
+    /// Feed language as a BCP 47 tag (e.g., "en-US")
+    pub language: Option<String>,
+    /// Copyright/rights statement
+    pub rights: Option<String>,
+    /// Generator name
+    pub generator: Option<String>,
+    /// Feed image
+    pub image: Option<Image>,
+    /// Icon URL (small image)
+    pub icon: Option<String>,
+    /// Logo URL (larger image)
+    pub logo: Option<String>,
+    /// Feed-level tags/categories
+    pub tags: Vec<Tag>,
+    /// Unique feed identifier
+    pub id: Option<String>,
+    /// Time-to-live (update frequency hint) in minutes
+    pub ttl: Option<u32>,
+    /// True if the publisher has stopped updating the feed
+    pub expired: bool,
+    /// License URL, or its name when no URL is given
+    pub license: Option<String>,
+    /// iTunes podcast metadata
+    pub itunes: Option<ItunesFeedMeta>,
+}
+
+impl From<CoreFeedMeta> for FeedMeta {
+    fn from(core: CoreFeedMeta) -> Self {
+        Self {
+            title: core.title,
+            title_detail: core.title_detail.map(TextConstruct::from),
+            link: core.link,
+            links: core.links.into_iter().map(Link::from).collect(),
+            subtitle: core.subtitle,
+            subtitle_detail: core.subtitle_detail.map(TextConstruct::from),
+            updated: core.updated.map(SystemTime::from),
+            published: core.published.map(SystemTime::from),
+            author: core.author.map(|s| s.to_string()),
+            author_detail: core.author_detail.map(Person::from),
+            authors: core.authors.into_iter().map(Person::from).collect(),
+            contributors: core.contributors.into_iter().map(Person::from).collect(),
+            publisher: core.publisher.map(|s| s.to_string()),
+            language: core.language.map(|s| s.to_string()),
+            rights: core.rights,
+            generator: core.generator,
+            image: core.image.map(Image::from),
+            icon: core.icon,
+            logo: core.logo,
+            tags: core.tags.into_iter().map(Tag::from).collect(),
+            id: core.id,
+            ttl: core.ttl,
+            expired: core.expired,
+            license: core.license,
+            itunes: core.itunes.map(|b| ItunesFeedMeta::from(*b)),
+        }
+    }
+}
+
+/// Feed entry/item
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Entry {
+    /// Unique entry identifier
+    pub id: Option<String>,
+    /// Entry title
+    pub title: Option<String>,
+    /// Detailed title with metadata
+    pub title_detail: Option<TextConstruct>,
+    /// Primary link
+    pub link: Option<String>,
+    /// All links associated with this entry
+    pub links: Vec<Link>,
+    /// Short description/summary
+    pub summary: Option<String>,
+    /// Detailed summary with metadata
+    pub summary_detail: Option<TextConstruct>,
+    /// Full content blocks
+    pub content: Vec<Content>,
+    /// Publication date
+    pub published: Option<SystemTime>,
+    /// Last update date
+    pub updated: Option<SystemTime>,
+    /// Primary author name
+    pub author: Option<String>,
+    /// Detailed author information
+    pub author_detail: Option<Person>,
+    /// All authors
+    pub authors: Vec<Person>,
+    /// Contributors
+    pub contributors: Vec<Person>,
+    /// Tags/categories
+    pub tags: Vec<Tag>,
+    /// Media enclosures (audio, video, etc.)
+    pub enclosures: Vec<Enclosure>,
+    /// Comments URL or text
+    pub comments: Option<String>,
+    /// Number of comments
+    pub comments_count: Option<u32>,
+    /// URL of the page the entry is about (JSON Feed `external_url`)
+    pub external_url: Option<String>,
+    /// Representative image, from thumbnails, iTunes artwork, image
+    /// enclosures or the first content `<img>`
+    pub image: Option<Image>,
+    /// iTunes episode metadata
+    pub itunes: Option<ItunesEntryMeta>,
+    /// Podcast 2.0 transcripts
+    pub podcast_transcripts: Vec<PodcastTranscript>,
+    /// Podcast 2.0 persons (hosts, guests, etc.)
+    pub podcast_persons: Vec<PodcastPerson>,
+    /// Podcast 2.0 chapter markers
+    pub podcast_chapters: Option<PodcastChapters>,
+}
+
+impl From<CoreEntry> for Entry {
+    fn from(core: CoreEntry) -> Self {
+        Self {
+            id: core.id.map(|s| s.to_string()),
+            title: core.title,
+            title_detail: core.title_detail.map(TextConstruct::from),
+            link: core.link,
+            links: core.links.into_iter().map(Link::from).collect(),
+            summary: core.summary,
+            summary_detail: core.summary_detail.map(TextConstruct::from),
+            content: core.content.into_iter().map(Content::from).collect(),
+            published: core.published.map(SystemTime::from),
+            updated: core.updated.map(SystemTime::from),
+            author: core.author.map(|s| s.to_string()),
+            author_detail: core.author_detail.map(Person::from),
+            authors: core.authors.into_iter().map(Person::from).collect(),
+            contributors: core.contributors.into_iter().map(Person::from).collect(),
+            tags: core.tags.into_iter().map(Tag::from).collect(),
+            enclosures: core.enclosures.into_iter().map(Enclosure::from).collect(),
+            comments: core.comments,
+            comments_count: core.comments_count,
+            external_url: core.external_url,
+            image: core.image.map(Image::from),
+            itunes: core.itunes.map(|b| ItunesEntryMeta::from(*b)),
+            podcast_transcripts: core
+                .podcast_transcripts
+                .into_iter()
+                .map(PodcastTranscript::from)
+                .collect(),
+            podcast_persons: core
+                .podcast_persons
+                .into_iter()
+                .map(PodcastPerson::from)
+                .collect(),
+            podcast_chapters: core
+                .podcast
+                .and_then(|podcast| podcast.chapters)
+                .map(PodcastChapters::from),
+        }
+    }
+}
+
+/// Text construct with metadata
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TextConstruct {
+    /// Text content
+    pub value: String,
+    /// Content type ("text", "html", "xhtml")
+    pub content_type: String,
+    /// Content language
+    pub language: Option<String>,
+    /// Base URL for relative links
+    pub base: Option<String>,
+}
+
+impl From<CoreTextConstruct> for TextConstruct {
+    fn from(core: CoreTextConstruct) -> Self {
+        Self {
+            value: core.value,
+            content_type: match core.content_type {
+                TextType::Text => "text".to_string(),
+                TextType::Html => "html".to_string(),
+                TextType::Xhtml => "xhtml".to_string(),
+            },
+            language: core.language.map(|s| s.to_string()),
+            base: core.base,
+        }
+    }
+}
+
+/// Link in feed or entry
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Link {
+    /// Link URL
+    pub href: String,
+    /// Link relationship type (e.g., "alternate", "enclosure", "self")
+    pub rel: Option<String>,
+    /// MIME type of the linked resource
+    pub link_type: Option<String>,
+    /// Human-readable link title
+    pub title: Option<String>,
+    /// Length of the linked resource in bytes
+    pub length: Option<u64>,
+    /// Language of the linked resource
+    pub hreflang: Option<String>,
+}
+
+impl From<CoreLink> for Link {
+    fn from(core: CoreLink) -> Self {
+        Self {
+            href: core.href.into_inner(),
+            rel: core.rel.map(|s| s.to_string()),
+            link_type: core.link_type.map(|t| t.to_string()),
+            title: core.title,
+            length: core.length,
+            hreflang: core.hreflang.map(|s| s.to_string()),
+        }
+    }
+}
+
+/// Person (author, contributor, etc.)
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Person {
+    /// Person's name
+    pub name: Option<String>,
+    /// Person's email address
+    pub email: Option<String>,
+    /// Person's URI/website
+    pub uri: Option<String>,
+}
+
+impl From<CorePerson> for Person {
+    fn from(core: CorePerson) -> Self {
+        Self {
+            name: core.name.map(|s| s.to_string()),
+            email: core.email.map(Email::into_inner),
+            uri: core.uri,
+        }
+    }
+}
+
+/// Tag/category
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Tag {
+    /// Tag term/label
+    pub term: String,
+    /// Tag scheme/domain
+    pub scheme: Option<String>,
+    /// Human-readable tag label
+    pub label: Option<String>,
+}
+
+impl From<CoreTag> for Tag {
+    fn from(core: CoreTag) -> Self {
+        Self {
+            term: core.term.to_string(),
+            scheme: core.scheme.map(|s| s.to_string()),
+            label: core.label.map(|s| s.to_string()),
+        }
+    }
+}
+
+/// Image metadata
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Image {
+    /// Image URL
+    pub url: String,
+    /// Image title
+    pub title: Option<String>,
+    /// Link associated with the image
+    pub link: Option<String>,
+    /// Image width in pixels
+    pub width: Option<u32>,
+    /// Image height in pixels
+    pub height: Option<u32>,
+    /// Image description
+    pub description: Option<String>,
+}
+
+impl From<CoreImage> for Image {
+    fn from(core: CoreImage) -> Self {
+        Self {
+            url: core.url.into_inner(),
+            title: core.title,
+            link: core.link,
+            width: core.width,
+            height: core.height,
+            description: core.description,
+        }
+    }
+}
+
+/// Enclosure (attached media file)
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Enclosure {
+    /// Enclosure URL
+    pub url: String,
+    /// File size in bytes
+    pub length: Option<u64>,
+    /// MIME type
+    pub enclosure_type: Option<String>,
+}
+
+impl From<CoreEnclosure> for Enclosure {
+    fn from(core: CoreEnclosure) -> Self {
+        Self {
+            url: core.url.into_inner(),
+            length: core.length,
+            enclosure_type: core.enclosure_type.map(|t| t.to_string()),
+        }
+    }
+}
+
+/// Content block
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Content {
+    /// Content body
+    pub value: String,
+    /// Content MIME type
+    pub content_type: Option<String>,
+    /// Content language
+    pub language: Option<String>,
+    /// Base URL for relative links
+    pub base: Option<String>,
+}
+
+impl From<CoreContent> for Content {
+    fn from(core: CoreContent) -> Self {
+        Self {
+            value: core.value,
+            content_type: core.content_type.map(|t| t.to_string()),
+            language: core.language.map(|s| s.to_string()),
+            base: core.base,
+        }
+    }
+}
+
+/// iTunes podcast feed metadata
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItunesFeedMeta {
+    /// Podcast author
+    pub author: Option<String>,
+    /// Podcast owner information
+    pub owner: Option<ItunesOwner>,
+    /// Podcast categories
+    pub categories: Vec<ItunesCategory>,
+    /// Explicit content flag
+    pub explicit: Option<bool>,
+    /// Podcast artwork URL
+    pub image: Option<String>,
+    /// Podcast keywords
+    pub keywords: Vec<String>,
+    /// Podcast type (episodic/serial)
+    pub podcast_type: Option<String>,
+    /// Podcast completion status
+    pub complete: Option<bool>,
+    /// New feed URL for migrated podcasts
+    pub new_feed_url: Option<String>,
+}
+
+impl From<CoreItunesFeedMeta> for ItunesFeedMeta {
+    fn from(core: CoreItunesFeedMeta) -> Self {
+        Self {
+            author: core.author,
+            owner: core.owner.map(ItunesOwner::from),
+            categories: core
+                .categories
+                .into_iter()
+                .map(ItunesCategory::from)
+                .collect(),
+            explicit: core.explicit,
+            image: core.image.map(Url::into_inner),
+            keywords: core.keywords,
+            podcast_type: core.podcast_type,
+            complete: core.complete,
+            new_feed_url: core.new_feed_url.map(Url::into_inner),
+        }
+    }
+}
+
+/// iTunes owner information
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItunesOwner {
+    /// Owner name
+    pub name: Option<String>,
+    /// Owner email
+    pub email: Option<String>,
+}
+
+impl From<CoreItunesOwner> for ItunesOwner {
+    fn from(core: CoreItunesOwner) -> Self {
+        Self {
+            name: core.name,
+            email: core.email,
+        }
+    }
+}
+
+/// iTunes category
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItunesCategory {
+    /// Category text
+    pub text: String,
+    /// Subcategory
+    pub subcategory: Option<String>,
+}
+
+impl From<CoreItunesCategory> for ItunesCategory {
+    fn from(core: CoreItunesCategory) -> Self {
+        Self {
+            text: core.text,
+            subcategory: core.subcategory,
+        }
+    }
+}
+
+/// iTunes episode metadata
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItunesEntryMeta {
+    /// Episode title override
+    pub title: Option<String>,
+    /// Episode author
+    pub author: Option<String>,
+    /// Episode duration in seconds
+    pub duration: Option<u32>,
+    /// Explicit content flag for this episode
+    pub explicit: Option<bool>,
+    /// Episode-specific artwork URL
+    pub image: Option<String>,
+    /// Episode number
+    pub episode: Option<u32>,
+    /// Season number
+    pub season: Option<u32>,
+    /// Episode type: "full", "trailer", or "bonus"
+    pub episode_type: Option<String>,
+}
+
+impl From<CoreItunesEntryMeta> for ItunesEntryMeta {
+    fn from(core: CoreItunesEntryMeta) -> Self {
+        Self {
+            title: core.title,
+            author: core.author,
+            duration: core.duration,
+            explicit: core.explicit,
+            image: core.image.map(Url::into_inner),
+            episode: core.episode,
+            season: core.season,
+            episode_type: core.episode_type,
+        }
+    }
+}
+
+/// Podcast 2.0 transcript
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PodcastTranscript {
+    /// Transcript URL
+    pub url: String,
+    /// Transcript type (e.g., "text/plain", "application/srt")
+    pub transcript_type: Option<String>,
+    /// Transcript language
+    pub language: Option<String>,
+    /// Relationship type (e.g., "captions")
+    pub rel: Option<String>,
+}
+
+impl From<CorePodcastTranscript> for PodcastTranscript {
+    fn from(core: CorePodcastTranscript) -> Self {
+        Self {
+            url: core.url.into_inner(),
+            transcript_type: core.transcript_type.map(|t| t.to_string()),
+            language: core.language,
+            rel: core.rel,
+        }
+    }
+}
+
+/// Podcast 2.0 person
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PodcastPerson {
+    /// Person's name
+    pub name: String,
+    /// Role: "host", "guest", "editor", etc.
+    pub role: Option<String>,
+    /// Group name
+    pub group: Option<String>,
+    /// Image URL
+    pub img: Option<String>,
+    /// Personal URL/homepage
+    pub href: Option<String>,
+}
+
+impl From<CorePodcastPerson> for PodcastPerson {
+    fn from(core: CorePodcastPerson) -> Self {
+        Self {
+            name: core.name,
+            role: core.role,
+            group: core.group,
+            img: core.img.map(Url::into_inner),
+            href: core.href.map(Url::into_inner),
+        }
+    }
+}
+
+/// Podcast 2.0 chapters
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PodcastChapters {
+    /// Chapters URL
+    pub url: String,
+    /// Chapters MIME type (e.g., "application/json+chapters")
+    pub chapters_type: String,
+}
+
+impl From<CorePodcastChapters> for PodcastChapters {
+    fn from(core: CorePodcastChapters) -> Self {
+        Self {
+            url: core.url.into_inner(),
+            chapters_type: core.type_.to_string(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Generates the Swift and Kotlin sources for this crate's library
+
+fn main() {
+    uniffi::uniffi_bindgen_main();
+}